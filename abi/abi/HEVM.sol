@@ -2,6 +2,7 @@ struct Log { bytes32[] topics; bytes data; }
 struct Rpc { string name; string url; }
 struct DirEntry { string errorMessage; string path; uint64 depth; bool isDir; bool isSymlink; }
 struct FsMetadata { bool isDir; bool isSymlink; uint256 length; bool readOnly; uint256 modified; uint256 accessed; uint256 created; }
+struct Wallet { address addr; bytes publicKey; string privateKey; }
 
 allowCheatcodes(address)
 
@@ -50,11 +51,12 @@ envOr(string,string,bytes[])(bytes[])
 
 addr(string)(address)
 sign(string,bytes32)(bytes)
-deriveKey(string,uint32)(uint256)
-deriveKey(string,string,uint32)(uint256)
-deriveKey(string,uint32,string)(uint256)
-deriveKey(string,string,uint32,string)(uint256)
+deriveKey(string,uint32)(string)
+deriveKey(string,string,uint32)(string)
+deriveKey(string,uint32,string)(string)
+deriveKey(string,string,uint32,string)(string)
 rememberKey(string)(address)
+createWallet(string)(Wallet)
 
 prank(address)
 prank(address,address)
@@ -68,6 +70,14 @@ etch(address,bytes)
 expectRevert()
 expectRevert(bytes)
 expectRevert(bytes4)
+expectRevert(uint64)
+expectRevert(address)
+expectRevert(bytes,uint64)
+expectRevert(bytes4,uint64)
+expectRevert(bytes,address)
+expectRevert(bytes4,address)
+expectPartialRevert(bytes4)
+expectPartialRevert(bytes4,address)
 record()
 accesses(address)(bytes32[],bytes32[])
 skip(bool)
@@ -77,8 +87,16 @@ getRecordedLogs()(Log[])
 
 expectEmit()
 expectEmit(address)
+expectEmit(uint64)
+expectEmit(address,uint64)
 expectEmit(bool,bool,bool,bool)
 expectEmit(bool,bool,bool,bool,address)
+expectEmit(bool,bool,bool,bool,uint64)
+expectEmit(bool,bool,bool,bool,address,uint64)
+expectEmitAnonymous()
+expectEmitAnonymous(address)
+expectEmitAnonymous(bool,bool,bool,bool,bool)
+expectEmitAnonymous(bool,bool,bool,bool,bool,address)
 
 mockCall(address,bytes,bytes)
 mockCall(address,uint256,bytes,bytes)
@@ -97,6 +115,91 @@ expectCallMinGas(address,uint256,uint64,bytes,uint64)
 expectSafeMemory(uint64,uint64)
 expectSafeMemoryCall(uint64,uint64)
 
+assertTrue(bool)
+assertTrue(bool,string)
+assertFalse(bool)
+assertFalse(bool,string)
+assertEq(bool,bool)
+assertEq(bool,bool,string)
+assertEq(uint256,uint256)
+assertEq(uint256,uint256,string)
+assertEq(int256,int256)
+assertEq(int256,int256,string)
+assertEq(address,address)
+assertEq(address,address,string)
+assertEq(bytes32,bytes32)
+assertEq(bytes32,bytes32,string)
+assertEq(string,string)
+assertEq(string,string,string)
+assertEq(bytes,bytes)
+assertEq(bytes,bytes,string)
+assertEq(bool[],bool[])
+assertEq(bool[],bool[],string)
+assertEq(uint256[],uint256[])
+assertEq(uint256[],uint256[],string)
+assertEq(int256[],int256[])
+assertEq(int256[],int256[],string)
+assertEq(address[],address[])
+assertEq(address[],address[],string)
+assertEq(bytes32[],bytes32[])
+assertEq(bytes32[],bytes32[],string)
+assertEq(string[],string[])
+assertEq(string[],string[],string)
+assertEq(bytes[],bytes[])
+assertEq(bytes[],bytes[],string)
+assertNotEq(bool,bool)
+assertNotEq(bool,bool,string)
+assertNotEq(uint256,uint256)
+assertNotEq(uint256,uint256,string)
+assertNotEq(int256,int256)
+assertNotEq(int256,int256,string)
+assertNotEq(address,address)
+assertNotEq(address,address,string)
+assertNotEq(bytes32,bytes32)
+assertNotEq(bytes32,bytes32,string)
+assertNotEq(string,string)
+assertNotEq(string,string,string)
+assertNotEq(bytes,bytes)
+assertNotEq(bytes,bytes,string)
+assertNotEq(bool[],bool[])
+assertNotEq(bool[],bool[],string)
+assertNotEq(uint256[],uint256[])
+assertNotEq(uint256[],uint256[],string)
+assertNotEq(int256[],int256[])
+assertNotEq(int256[],int256[],string)
+assertNotEq(address[],address[])
+assertNotEq(address[],address[],string)
+assertNotEq(bytes32[],bytes32[])
+assertNotEq(bytes32[],bytes32[],string)
+assertNotEq(string[],string[])
+assertNotEq(string[],string[],string)
+assertNotEq(bytes[],bytes[])
+assertNotEq(bytes[],bytes[],string)
+assertLt(uint256,uint256)
+assertLt(uint256,uint256,string)
+assertLt(int256,int256)
+assertLt(int256,int256,string)
+assertGt(uint256,uint256)
+assertGt(uint256,uint256,string)
+assertGt(int256,int256)
+assertGt(int256,int256,string)
+assertLe(uint256,uint256)
+assertLe(uint256,uint256,string)
+assertLe(int256,int256)
+assertLe(int256,int256,string)
+assertGe(uint256,uint256)
+assertGe(uint256,uint256,string)
+assertGe(int256,int256)
+assertGe(int256,int256,string)
+assertApproxEqAbs(uint256,uint256,uint256)
+assertApproxEqAbs(uint256,uint256,uint256,string)
+assertApproxEqAbs(int256,int256,uint256)
+assertApproxEqAbs(int256,int256,uint256,string)
+assertApproxEqRel(uint256,uint256,uint256)
+assertApproxEqRel(uint256,uint256,uint256,string)
+assertApproxEqRel(int256,int256,uint256)
+assertApproxEqRel(int256,int256,uint256,string)
+
 getCode(string)
 getDeployedCode(string)
 label(address,string)
@@ -118,6 +221,9 @@ startBroadcast(string)
 stopBroadcast()
 
 projectRoot()(string)
+saveDeployment(string,address)
+getDeployment(string)(address)
+getDeployment(string,string)(address)
 readFile(string)(string)
 readFileBinary(string)(bytes)
 writeFile(string,string)
@@ -150,6 +256,9 @@ parseBool(string)(bool)
 
 snapshot()(uint256)
 revertTo(uint256)(bool)
+startSnapshotEnergy(string)
+stopSnapshotEnergy(string)(uint256)
+snapshotValue(string,uint256)
 createFork(string,uint256)(uint256)
 createFork(string,bytes32)(uint256)
 createFork(string)(uint256)
@@ -193,6 +302,8 @@ parseJsonBytes(string, string)(bytes)
 parseJsonBytesArray(string, string)(bytes[])
 parseJsonBytes32(string, string)(bytes32)
 parseJsonBytes32Array(string, string)(bytes32[])
+parseJsonKeys(string, string)(string[])
+keyExistsJson(string, string)(bool)
 serializeBool(string,string,bool)(string)
 serializeBool(string,string,bool[])(string)
 serializeUint(string,string,uint256)(string)
@@ -208,5 +319,26 @@ serializeString(string,string,string[])(string)
 serializeBytes(string,string,bytes)(string)
 serializeBytes(string,string,bytes[])(string)
 
+writeToml(string, string)
+writeToml(string, string, string)
+parseToml(string)(bytes)
+parseToml(string, string)(bytes)
+parseTomlUint(string, string)(uint256)
+parseTomlUintArray(string, string)(uint256[])
+parseTomlInt(string, string)(int256)
+parseTomlIntArray(string, string)(int256[])
+parseTomlString(string, string)(string)
+parseTomlStringArray(string, string)(string[])
+parseTomlAddress(string, string)(address)
+parseTomlAddressArray(string, string)(address[])
+parseTomlBool(string, string)(bool)
+parseTomlBoolArray(string, string)(bool[])
+parseTomlBytes(string, string)(bytes)
+parseTomlBytesArray(string, string)(bytes[])
+parseTomlBytes32(string, string)(bytes32)
+parseTomlBytes32Array(string, string)(bytes32[])
+parseTomlKeys(string, string)(string[])
+keyExistsToml(string, string)(bool)
+
 pauseGasMetering()
 resumeGasMetering()
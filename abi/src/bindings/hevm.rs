@@ -11,7 +11,7 @@ pub use hevm::*;
 )]
 pub mod hevm {
     #[rustfmt::skip]
-    const __ABI: &str = "[\nstruct Log { bytes32[] topics; bytes data; }\nstruct Rpc { string name; string url; }\nstruct DirEntry { string errorMessage; string path; uint64 depth; bool isDir; bool isSymlink; }\nstruct FsMetadata { bool isDir; bool isSymlink; uint256 length; bool readOnly; uint256 modified; uint256 accessed; uint256 created; }\n\nallowCheatcodes(address)\n\nffi(string[])(bytes)\n\nbreakpoint(string)\nbreakpoint(string,bool)\n\nroll(uint256)\nwarp(uint256)\ndifficulty(uint256)\nfee(uint256)\ncoinbase(address)\nstore(address,bytes32,bytes32)\nload(address,bytes32)(bytes32)\n\nsetEnv(string,string)\nenvBool(string)(bool)\nenvUint(string)(uint256)\nenvInt(string)(int256)\nenvAddress(string)(address)\nenvBytes32(string)(bytes32)\nenvString(string)(string)\nenvBytes(string)(bytes)\nenvBool(string,string)(bool[])\nenvUint(string,string)(uint256[])\nenvInt(string,string)(int256[])\nenvAddress(string,string)(address[])\nenvBytes32(string,string)(bytes32[])\nenvString(string,string)(string[])\nenvBytes(string,string)(bytes[])\nenvOr(string,bool)(bool)\nenvOr(string,uint256)(uint256)\nenvOr(string,int256)(int256)\nenvOr(string,address)(address)\nenvOr(string,bytes32)(bytes32)\nenvOr(string,string)(string)\nenvOr(string,bytes)(bytes)\nenvOr(string,string,bool[])(bool[])\nenvOr(string,string,uint256[])(uint256[])\nenvOr(string,string,int256[])(int256[])\nenvOr(string,string,address[])(address[])\nenvOr(string,string,bytes32[])(bytes32[])\nenvOr(string,string,string[])(string[])\nenvOr(string,string,bytes[])(bytes[])\n\naddr(string)(address)\nsign(string,bytes32)(bytes)\nderiveKey(string,uint32)(uint256)\nderiveKey(string,string,uint32)(uint256)\nderiveKey(string,uint32,string)(uint256)\nderiveKey(string,string,uint32,string)(uint256)\nrememberKey(string)(address)\n\nprank(address)\nprank(address,address)\nreadCallers()(uint256,address,address)\nstartPrank(address)\nstartPrank(address,address)\nstopPrank()\n\ndeal(address,uint256)\netch(address,bytes)\nexpectRevert()\nexpectRevert(bytes)\nexpectRevert(bytes4)\nrecord()\naccesses(address)(bytes32[],bytes32[])\nskip(bool)\n\nrecordLogs()\ngetRecordedLogs()(Log[])\n\nexpectEmit()\nexpectEmit(address)\nexpectEmit(bool,bool,bool,bool)\nexpectEmit(bool,bool,bool,bool,address)\n\nmockCall(address,bytes,bytes)\nmockCall(address,uint256,bytes,bytes)\nmockCallRevert(address,bytes,bytes)\nmockCallRevert(address,uint256,bytes,bytes)\nclearMockedCalls()\n\nexpectCall(address,bytes)\nexpectCall(address,bytes,uint64)\nexpectCall(address,uint256,bytes)\nexpectCall(address,uint256,bytes,uint64)\nexpectCall(address,uint256,uint64,bytes)\nexpectCall(address,uint256,uint64,bytes,uint64)\nexpectCallMinGas(address,uint256,uint64,bytes)\nexpectCallMinGas(address,uint256,uint64,bytes,uint64)\nexpectSafeMemory(uint64,uint64)\nexpectSafeMemoryCall(uint64,uint64)\n\ngetCode(string)\ngetDeployedCode(string)\nlabel(address,string)\ngetLabel(address)(string)\nassume(bool)\nsetNonce(address,uint64)\ngetNonce(address)\nresetNonce(address)\nsetNonceUnsafe(address,uint64)\nchainId(uint256)\ntxGasPrice(uint256)\n\nbroadcast()\nbroadcast(address)\nbroadcast(string)\nstartBroadcast()\nstartBroadcast(address)\nstartBroadcast(string)\nstopBroadcast()\n\nprojectRoot()(string)\nreadFile(string)(string)\nreadFileBinary(string)(bytes)\nwriteFile(string,string)\nwriteFileBinary(string,bytes)\nopenFile(string)\nreadLine(string)(string)\nwriteLine(string,string)\ncloseFile(string)\nremoveFile(string)\ncreateDir(string, bool)\nremoveDir(string, bool)\nreadDir(string)(DirEntry[])\nreadDir(string, uint64)(DirEntry[])\nreadDir(string, uint64, bool)(DirEntry[])\nreadLink(string)(string)\nfsMetadata(string)(FsMetadata)\n\ntoString(bytes)\ntoString(address)\ntoString(uint256)\ntoString(int256)\ntoString(bytes32)\ntoString(bool)\nparseBytes(string)(bytes)\nparseAddress(string)(address)\nparseUint(string)(uint256)\nparseInt(string)(int256)\nparseBytes32(string)(bytes32)\nparseBool(string)(bool)\n\nsnapshot()(uint256)\nrevertTo(uint256)(bool)\ncreateFork(string,uint256)(uint256)\ncreateFork(string,bytes32)(uint256)\ncreateFork(string)(uint256)\ncreateSelectFork(string,uint256)(uint256)\ncreateSelectFork(string,bytes32)(uint256)\ncreateSelectFork(string)(uint256)\nselectFork(uint256)\nactiveFork()(uint256)\ntransact(bytes32)\ntransact(uint256,bytes32)\nmakePersistent(address)\nmakePersistent(address,address)\nmakePersistent(address,address,address)\nmakePersistent(address[])\nrevokePersistent(address)\nrevokePersistent(address[])\nisPersistent(address)(bool)\nrollFork(uint256)\nrollFork(bytes32)\nrollFork(uint256,uint256)\nrollFork(uint256,bytes32)\nrpcUrl(string)(string)\nrpcUrls()(string[2][])\nrpcUrlStructs()(Rpc[])\n\nwriteJson(string, string)\nwriteJson(string, string, string)\nparseJson(string)(bytes)\nparseJson(string, string)(bytes)\nparseJsonUint(string, string)(uint256)\nparseJsonUintArray(string, string)(uint256[])\nparseJsonInt(string, string)(int256)\nparseJsonIntArray(string, string)(int256[])\nparseJsonString(string, string)(string)\nparseJsonStringArray(string, string)(string[])\nparseJsonAddress(string, string)(address)\nparseJsonAddressArray(string, string)(address[])\nparseJsonBool(string, string)(bool)\nparseJsonBoolArray(string, string)(bool[])\nparseJsonBytes(string, string)(bytes)\nparseJsonBytesArray(string, string)(bytes[])\nparseJsonBytes32(string, string)(bytes32)\nparseJsonBytes32Array(string, string)(bytes32[])\nserializeBool(string,string,bool)(string)\nserializeBool(string,string,bool[])(string)\nserializeUint(string,string,uint256)(string)\nserializeUint(string,string,uint256[])(string)\nserializeInt(string,string,int256)(string)\nserializeInt(string,string,int256[])(string)\nserializeAddress(string,string,address)(string)\nserializeAddress(string,string,address[])(string)\nserializeBytes32(string,string,bytes32)(string)\nserializeBytes32(string,string,bytes32[])(string)\nserializeString(string,string,string)(string)\nserializeString(string,string,string[])(string)\nserializeBytes(string,string,bytes)(string)\nserializeBytes(string,string,bytes[])(string)\n\npauseGasMetering()\nresumeGasMetering()\n\n]";
+    const __ABI: &str = "[\nstruct Log { bytes32[] topics; bytes data; }\nstruct Rpc { string name; string url; }\nstruct DirEntry { string errorMessage; string path; uint64 depth; bool isDir; bool isSymlink; }\nstruct FsMetadata { bool isDir; bool isSymlink; uint256 length; bool readOnly; uint256 modified; uint256 accessed; uint256 created; }\nstruct Wallet { address addr; bytes publicKey; string privateKey; }\n\nallowCheatcodes(address)\n\nffi(string[])(bytes)\n\nbreakpoint(string)\nbreakpoint(string,bool)\n\nroll(uint256)\nwarp(uint256)\ndifficulty(uint256)\nfee(uint256)\ncoinbase(address)\nstore(address,bytes32,bytes32)\nload(address,bytes32)(bytes32)\n\nsetEnv(string,string)\nenvBool(string)(bool)\nenvUint(string)(uint256)\nenvInt(string)(int256)\nenvAddress(string)(address)\nenvBytes32(string)(bytes32)\nenvString(string)(string)\nenvBytes(string)(bytes)\nenvBool(string,string)(bool[])\nenvUint(string,string)(uint256[])\nenvInt(string,string)(int256[])\nenvAddress(string,string)(address[])\nenvBytes32(string,string)(bytes32[])\nenvString(string,string)(string[])\nenvBytes(string,string)(bytes[])\nenvOr(string,bool)(bool)\nenvOr(string,uint256)(uint256)\nenvOr(string,int256)(int256)\nenvOr(string,address)(address)\nenvOr(string,bytes32)(bytes32)\nenvOr(string,string)(string)\nenvOr(string,bytes)(bytes)\nenvOr(string,string,bool[])(bool[])\nenvOr(string,string,uint256[])(uint256[])\nenvOr(string,string,int256[])(int256[])\nenvOr(string,string,address[])(address[])\nenvOr(string,string,bytes32[])(bytes32[])\nenvOr(string,string,string[])(string[])\nenvOr(string,string,bytes[])(bytes[])\n\naddr(string)(address)\nsign(string,bytes32)(bytes)\nderiveKey(string,uint32)(string)\nderiveKey(string,string,uint32)(string)\nderiveKey(string,uint32,string)(string)\nderiveKey(string,string,uint32,string)(string)\nrememberKey(string)(address)\ncreateWallet(string)(Wallet)\n\nprank(address)\nprank(address,address)\nreadCallers()(uint256,address,address)\nstartPrank(address)\nstartPrank(address,address)\nstopPrank()\n\ndeal(address,uint256)\netch(address,bytes)\nexpectRevert()\nexpectRevert(bytes)\nexpectRevert(bytes4)\nexpectRevert(uint64)\nexpectRevert(address)\nexpectRevert(bytes,uint64)\nexpectRevert(bytes4,uint64)\nexpectRevert(bytes,address)\nexpectRevert(bytes4,address)\nexpectPartialRevert(bytes4)\nexpectPartialRevert(bytes4,address)\nrecord()\naccesses(address)(bytes32[],bytes32[])\nskip(bool)\n\nrecordLogs()\ngetRecordedLogs()(Log[])\n\nexpectEmit()\nexpectEmit(address)\nexpectEmit(uint64)\nexpectEmit(address,uint64)\nexpectEmit(bool,bool,bool,bool)\nexpectEmit(bool,bool,bool,bool,address)\nexpectEmit(bool,bool,bool,bool,uint64)\nexpectEmit(bool,bool,bool,bool,address,uint64)\nexpectEmitAnonymous()\nexpectEmitAnonymous(address)\nexpectEmitAnonymous(bool,bool,bool,bool,bool)\nexpectEmitAnonymous(bool,bool,bool,bool,bool,address)\n\nmockCall(address,bytes,bytes)\nmockCall(address,uint256,bytes,bytes)\nmockCallRevert(address,bytes,bytes)\nmockCallRevert(address,uint256,bytes,bytes)\nclearMockedCalls()\n\nexpectCall(address,bytes)\nexpectCall(address,bytes,uint64)\nexpectCall(address,uint256,bytes)\nexpectCall(address,uint256,bytes,uint64)\nexpectCall(address,uint256,uint64,bytes)\nexpectCall(address,uint256,uint64,bytes,uint64)\nexpectCallMinGas(address,uint256,uint64,bytes)\nexpectCallMinGas(address,uint256,uint64,bytes,uint64)\nexpectSafeMemory(uint64,uint64)\nexpectSafeMemoryCall(uint64,uint64)\n\nassertTrue(bool)\nassertTrue(bool,string)\nassertFalse(bool)\nassertFalse(bool,string)\nassertEq(bool,bool)\nassertEq(bool,bool,string)\nassertEq(uint256,uint256)\nassertEq(uint256,uint256,string)\nassertEq(int256,int256)\nassertEq(int256,int256,string)\nassertEq(address,address)\nassertEq(address,address,string)\nassertEq(bytes32,bytes32)\nassertEq(bytes32,bytes32,string)\nassertEq(string,string)\nassertEq(string,string,string)\nassertEq(bytes,bytes)\nassertEq(bytes,bytes,string)\nassertEq(bool[],bool[])\nassertEq(bool[],bool[],string)\nassertEq(uint256[],uint256[])\nassertEq(uint256[],uint256[],string)\nassertEq(int256[],int256[])\nassertEq(int256[],int256[],string)\nassertEq(address[],address[])\nassertEq(address[],address[],string)\nassertEq(bytes32[],bytes32[])\nassertEq(bytes32[],bytes32[],string)\nassertEq(string[],string[])\nassertEq(string[],string[],string)\nassertEq(bytes[],bytes[])\nassertEq(bytes[],bytes[],string)\nassertNotEq(bool,bool)\nassertNotEq(bool,bool,string)\nassertNotEq(uint256,uint256)\nassertNotEq(uint256,uint256,string)\nassertNotEq(int256,int256)\nassertNotEq(int256,int256,string)\nassertNotEq(address,address)\nassertNotEq(address,address,string)\nassertNotEq(bytes32,bytes32)\nassertNotEq(bytes32,bytes32,string)\nassertNotEq(string,string)\nassertNotEq(string,string,string)\nassertNotEq(bytes,bytes)\nassertNotEq(bytes,bytes,string)\nassertNotEq(bool[],bool[])\nassertNotEq(bool[],bool[],string)\nassertNotEq(uint256[],uint256[])\nassertNotEq(uint256[],uint256[],string)\nassertNotEq(int256[],int256[])\nassertNotEq(int256[],int256[],string)\nassertNotEq(address[],address[])\nassertNotEq(address[],address[],string)\nassertNotEq(bytes32[],bytes32[])\nassertNotEq(bytes32[],bytes32[],string)\nassertNotEq(string[],string[])\nassertNotEq(string[],string[],string)\nassertNotEq(bytes[],bytes[])\nassertNotEq(bytes[],bytes[],string)\nassertLt(uint256,uint256)\nassertLt(uint256,uint256,string)\nassertLt(int256,int256)\nassertLt(int256,int256,string)\nassertGt(uint256,uint256)\nassertGt(uint256,uint256,string)\nassertGt(int256,int256)\nassertGt(int256,int256,string)\nassertLe(uint256,uint256)\nassertLe(uint256,uint256,string)\nassertLe(int256,int256)\nassertLe(int256,int256,string)\nassertGe(uint256,uint256)\nassertGe(uint256,uint256,string)\nassertGe(int256,int256)\nassertGe(int256,int256,string)\nassertApproxEqAbs(uint256,uint256,uint256)\nassertApproxEqAbs(uint256,uint256,uint256,string)\nassertApproxEqAbs(int256,int256,uint256)\nassertApproxEqAbs(int256,int256,uint256,string)\nassertApproxEqRel(uint256,uint256,uint256)\nassertApproxEqRel(uint256,uint256,uint256,string)\nassertApproxEqRel(int256,int256,uint256)\nassertApproxEqRel(int256,int256,uint256,string)\n\ngetCode(string)\ngetDeployedCode(string)\nlabel(address,string)\ngetLabel(address)(string)\nassume(bool)\nsetNonce(address,uint64)\ngetNonce(address)\nresetNonce(address)\nsetNonceUnsafe(address,uint64)\nchainId(uint256)\ntxGasPrice(uint256)\n\nbroadcast()\nbroadcast(address)\nbroadcast(string)\nstartBroadcast()\nstartBroadcast(address)\nstartBroadcast(string)\nstopBroadcast()\n\nprojectRoot()(string)\nsaveDeployment(string,address)\ngetDeployment(string)(address)\ngetDeployment(string,string)(address)\nreadFile(string)(string)\nreadFileBinary(string)(bytes)\nwriteFile(string,string)\nwriteFileBinary(string,bytes)\nopenFile(string)\nreadLine(string)(string)\nwriteLine(string,string)\ncloseFile(string)\nremoveFile(string)\ncreateDir(string, bool)\nremoveDir(string, bool)\nreadDir(string)(DirEntry[])\nreadDir(string, uint64)(DirEntry[])\nreadDir(string, uint64, bool)(DirEntry[])\nreadLink(string)(string)\nfsMetadata(string)(FsMetadata)\n\ntoString(bytes)\ntoString(address)\ntoString(uint256)\ntoString(int256)\ntoString(bytes32)\ntoString(bool)\nparseBytes(string)(bytes)\nparseAddress(string)(address)\nparseUint(string)(uint256)\nparseInt(string)(int256)\nparseBytes32(string)(bytes32)\nparseBool(string)(bool)\n\nsnapshot()(uint256)\nrevertTo(uint256)(bool)\nstartSnapshotEnergy(string)\nstopSnapshotEnergy(string)(uint256)\nsnapshotValue(string,uint256)\ncreateFork(string,uint256)(uint256)\ncreateFork(string,bytes32)(uint256)\ncreateFork(string)(uint256)\ncreateSelectFork(string,uint256)(uint256)\ncreateSelectFork(string,bytes32)(uint256)\ncreateSelectFork(string)(uint256)\nselectFork(uint256)\nactiveFork()(uint256)\ntransact(bytes32)\ntransact(uint256,bytes32)\nmakePersistent(address)\nmakePersistent(address,address)\nmakePersistent(address,address,address)\nmakePersistent(address[])\nrevokePersistent(address)\nrevokePersistent(address[])\nisPersistent(address)(bool)\nrollFork(uint256)\nrollFork(bytes32)\nrollFork(uint256,uint256)\nrollFork(uint256,bytes32)\nrpcUrl(string)(string)\nrpcUrls()(string[2][])\nrpcUrlStructs()(Rpc[])\n\nwriteJson(string, string)\nwriteJson(string, string, string)\nparseJson(string)(bytes)\nparseJson(string, string)(bytes)\nparseJsonUint(string, string)(uint256)\nparseJsonUintArray(string, string)(uint256[])\nparseJsonInt(string, string)(int256)\nparseJsonIntArray(string, string)(int256[])\nparseJsonString(string, string)(string)\nparseJsonStringArray(string, string)(string[])\nparseJsonAddress(string, string)(address)\nparseJsonAddressArray(string, string)(address[])\nparseJsonBool(string, string)(bool)\nparseJsonBoolArray(string, string)(bool[])\nparseJsonBytes(string, string)(bytes)\nparseJsonBytesArray(string, string)(bytes[])\nparseJsonBytes32(string, string)(bytes32)\nparseJsonBytes32Array(string, string)(bytes32[])\nparseJsonKeys(string, string)(string[])\nkeyExistsJson(string, string)(bool)\nserializeBool(string,string,bool)(string)\nserializeBool(string,string,bool[])(string)\nserializeUint(string,string,uint256)(string)\nserializeUint(string,string,uint256[])(string)\nserializeInt(string,string,int256)(string)\nserializeInt(string,string,int256[])(string)\nserializeAddress(string,string,address)(string)\nserializeAddress(string,string,address[])(string)\nserializeBytes32(string,string,bytes32)(string)\nserializeBytes32(string,string,bytes32[])(string)\nserializeString(string,string,string)(string)\nserializeString(string,string,string[])(string)\nserializeBytes(string,string,bytes)(string)\nserializeBytes(string,string,bytes[])(string)\n\nwriteToml(string, string)\nwriteToml(string, string, string)\nparseToml(string)(bytes)\nparseToml(string, string)(bytes)\nparseTomlUint(string, string)(uint256)\nparseTomlUintArray(string, string)(uint256[])\nparseTomlInt(string, string)(int256)\nparseTomlIntArray(string, string)(int256[])\nparseTomlString(string, string)(string)\nparseTomlStringArray(string, string)(string[])\nparseTomlAddress(string, string)(address)\nparseTomlAddressArray(string, string)(address[])\nparseTomlBool(string, string)(bool)\nparseTomlBoolArray(string, string)(bool[])\nparseTomlBytes(string, string)(bytes)\nparseTomlBytesArray(string, string)(bytes[])\nparseTomlBytes32(string, string)(bytes32)\nparseTomlBytes32Array(string, string)(bytes32[])\nparseTomlKeys(string, string)(string[])\nkeyExistsToml(string, string)(bool)\n\npauseGasMetering()\nresumeGasMetering()\n\n]";
     ///The parsed human-readable ABI of the contract.
     pub static HEVM_ABI: ::corebc_contract::Lazy<::corebc_core::abi::Abi> = ::corebc_contract::Lazy::new(||
     ::corebc_core::abi::parse_abi_str(__ABI).expect("ABI is always valid"));
@@ -93,1908 +93,5060 @@ pub mod hevm {
                 .method_hash([33, 189, 142, 196], p0)
                 .expect("method not found (this should never happen)")
         }
-        ///Calls the contract's `assume` (0xca480fa9) function
-        pub fn assume(
+        ///Calls the contract's `assertApproxEqAbs` (0xfdf9f058) function
+        pub fn assert_approx_eq_abs_0(
             &self,
-            p0: bool,
+            p0: ::corebc_core::types::U256,
+            p1: ::corebc_core::types::U256,
+            p2: ::corebc_core::types::U256,
         ) -> ::corebc_contract::builders::ContractCall<M, ()> {
             self.0
-                .method_hash([202, 72, 15, 169], p0)
+                .method_hash([253, 249, 240, 88], (p0, p1, p2))
                 .expect("method not found (this should never happen)")
         }
-        ///Calls the contract's `breakpoint` (0x3af777a9) function
-        pub fn breakpoint_0(
+        ///Calls the contract's `assertApproxEqAbs` (0x6cf38620) function
+        pub fn assert_approx_eq_abs_2(
             &self,
-            p0: ::std::string::String,
+            p0: ::corebc_core::types::U256,
+            p1: ::corebc_core::types::U256,
+            p2: ::corebc_core::types::U256,
+            p3: ::std::string::String,
         ) -> ::corebc_contract::builders::ContractCall<M, ()> {
             self.0
-                .method_hash([58, 247, 119, 169], p0)
+                .method_hash([108, 243, 134, 32], (p0, p1, p2, p3))
                 .expect("method not found (this should never happen)")
         }
-        ///Calls the contract's `breakpoint` (0x18af918f) function
-        pub fn breakpoint_1(
+        ///Calls the contract's `assertApproxEqAbs` (0x530a57cc) function
+        pub fn assert_approx_eq_abs_1(
             &self,
-            p0: ::std::string::String,
-            p1: bool,
+            p0: ::corebc_core::types::I256,
+            p1: ::corebc_core::types::I256,
+            p2: ::corebc_core::types::U256,
         ) -> ::corebc_contract::builders::ContractCall<M, ()> {
             self.0
-                .method_hash([24, 175, 145, 143], (p0, p1))
-                .expect("method not found (this should never happen)")
-        }
-        ///Calls the contract's `broadcast` (0x12f46a0c) function
-        pub fn broadcast_0(&self) -> ::corebc_contract::builders::ContractCall<M, ()> {
-            self.0
-                .method_hash([18, 244, 106, 12], ())
+                .method_hash([83, 10, 87, 204], (p0, p1, p2))
                 .expect("method not found (this should never happen)")
         }
-        ///Calls the contract's `broadcast` (0x3aec5261) function
-        pub fn broadcast_1(
+        ///Calls the contract's `assertApproxEqAbs` (0xd0342579) function
+        pub fn assert_approx_eq_abs_3(
             &self,
-            p0: ::corebc_core::types::Address,
+            p0: ::corebc_core::types::I256,
+            p1: ::corebc_core::types::I256,
+            p2: ::corebc_core::types::U256,
+            p3: ::std::string::String,
         ) -> ::corebc_contract::builders::ContractCall<M, ()> {
             self.0
-                .method_hash([58, 236, 82, 97], p0)
+                .method_hash([208, 52, 37, 121], (p0, p1, p2, p3))
                 .expect("method not found (this should never happen)")
         }
-        ///Calls the contract's `broadcast` (0x85b0a416) function
-        pub fn broadcast_2(
+        ///Calls the contract's `assertApproxEqRel` (0x49c9f7d6) function
+        pub fn assert_approx_eq_rel_0(
             &self,
-            p0: ::std::string::String,
+            p0: ::corebc_core::types::U256,
+            p1: ::corebc_core::types::U256,
+            p2: ::corebc_core::types::U256,
         ) -> ::corebc_contract::builders::ContractCall<M, ()> {
             self.0
-                .method_hash([133, 176, 164, 22], p0)
+                .method_hash([73, 201, 247, 214], (p0, p1, p2))
                 .expect("method not found (this should never happen)")
         }
-        ///Calls the contract's `chainId` (0x5858cd87) function
-        pub fn chain_id(
+        ///Calls the contract's `assertApproxEqRel` (0x65fbc238) function
+        pub fn assert_approx_eq_rel_2(
             &self,
             p0: ::corebc_core::types::U256,
+            p1: ::corebc_core::types::U256,
+            p2: ::corebc_core::types::U256,
+            p3: ::std::string::String,
         ) -> ::corebc_contract::builders::ContractCall<M, ()> {
             self.0
-                .method_hash([88, 88, 205, 135], p0)
+                .method_hash([101, 251, 194, 56], (p0, p1, p2, p3))
                 .expect("method not found (this should never happen)")
         }
-        ///Calls the contract's `clearMockedCalls` (0x7eaff7de) function
-        pub fn clear_mocked_calls(
+        ///Calls the contract's `assertApproxEqRel` (0x2631ffbc) function
+        pub fn assert_approx_eq_rel_1(
             &self,
+            p0: ::corebc_core::types::I256,
+            p1: ::corebc_core::types::I256,
+            p2: ::corebc_core::types::U256,
         ) -> ::corebc_contract::builders::ContractCall<M, ()> {
             self.0
-                .method_hash([126, 175, 247, 222], ())
+                .method_hash([38, 49, 255, 188], (p0, p1, p2))
                 .expect("method not found (this should never happen)")
         }
-        ///Calls the contract's `closeFile` (0xa3a9f387) function
-        pub fn close_file(
+        ///Calls the contract's `assertApproxEqRel` (0xdfada52f) function
+        pub fn assert_approx_eq_rel_3(
             &self,
-            p0: ::std::string::String,
+            p0: ::corebc_core::types::I256,
+            p1: ::corebc_core::types::I256,
+            p2: ::corebc_core::types::U256,
+            p3: ::std::string::String,
         ) -> ::corebc_contract::builders::ContractCall<M, ()> {
             self.0
-                .method_hash([163, 169, 243, 135], p0)
+                .method_hash([223, 173, 165, 47], (p0, p1, p2, p3))
                 .expect("method not found (this should never happen)")
         }
-        ///Calls the contract's `coinbase` (0x53a5c6de) function
-        pub fn coinbase(
+        ///Calls the contract's `assertEq` (0xa67955d7) function
+        pub fn assert_eq_0(
             &self,
-            p0: ::corebc_core::types::Address,
+            p0: bool,
+            p1: bool,
         ) -> ::corebc_contract::builders::ContractCall<M, ()> {
             self.0
-                .method_hash([83, 165, 198, 222], p0)
+                .method_hash([166, 121, 85, 215], (p0, p1))
                 .expect("method not found (this should never happen)")
         }
-        ///Calls the contract's `createDir` (0x8b295957) function
-        pub fn create_dir(
+        ///Calls the contract's `assertEq` (0x41df0451) function
+        pub fn assert_eq_14(
             &self,
-            p0: ::std::string::String,
+            p0: bool,
             p1: bool,
+            p2: ::std::string::String,
         ) -> ::corebc_contract::builders::ContractCall<M, ()> {
             self.0
-                .method_hash([139, 41, 89, 87], (p0, p1))
+                .method_hash([65, 223, 4, 81], (p0, p1, p2))
                 .expect("method not found (this should never happen)")
         }
-        ///Calls the contract's `createFork` (0x27dc0128) function
-        pub fn create_fork_1(
+        ///Calls the contract's `assertEq` (0xdcbb3fda) function
+        pub fn assert_eq_1(
             &self,
-            p0: ::std::string::String,
+            p0: ::corebc_core::types::U256,
             p1: ::corebc_core::types::U256,
-        ) -> ::corebc_contract::builders::ContractCall<M, ::corebc_core::types::U256> {
+        ) -> ::corebc_contract::builders::ContractCall<M, ()> {
             self.0
-                .method_hash([39, 220, 1, 40], (p0, p1))
+                .method_hash([220, 187, 63, 218], (p0, p1))
                 .expect("method not found (this should never happen)")
         }
-        ///Calls the contract's `createFork` (0x472ee61e) function
-        pub fn create_fork_2(
+        ///Calls the contract's `assertEq` (0x536cacd7) function
+        pub fn assert_eq_15(
             &self,
-            p0: ::std::string::String,
-            p1: [u8; 32],
-        ) -> ::corebc_contract::builders::ContractCall<M, ::corebc_core::types::U256> {
+            p0: ::corebc_core::types::U256,
+            p1: ::corebc_core::types::U256,
+            p2: ::std::string::String,
+        ) -> ::corebc_contract::builders::ContractCall<M, ()> {
             self.0
-                .method_hash([71, 46, 230, 30], (p0, p1))
+                .method_hash([83, 108, 172, 215], (p0, p1, p2))
                 .expect("method not found (this should never happen)")
         }
-        ///Calls the contract's `createFork` (0xc8282be0) function
-        pub fn create_fork_0(
+        ///Calls the contract's `assertEq` (0xf6a7fb98) function
+        pub fn assert_eq_2(
             &self,
-            p0: ::std::string::String,
-        ) -> ::corebc_contract::builders::ContractCall<M, ::corebc_core::types::U256> {
+            p0: ::corebc_core::types::I256,
+            p1: ::corebc_core::types::I256,
+        ) -> ::corebc_contract::builders::ContractCall<M, ()> {
             self.0
-                .method_hash([200, 40, 43, 224], p0)
+                .method_hash([246, 167, 251, 152], (p0, p1))
                 .expect("method not found (this should never happen)")
         }
-        ///Calls the contract's `createSelectFork` (0xcc15574b) function
-        pub fn create_select_fork_1(
+        ///Calls the contract's `assertEq` (0x79502bb6) function
+        pub fn assert_eq_16(
             &self,
-            p0: ::std::string::String,
-            p1: ::corebc_core::types::U256,
-        ) -> ::corebc_contract::builders::ContractCall<M, ::corebc_core::types::U256> {
+            p0: ::corebc_core::types::I256,
+            p1: ::corebc_core::types::I256,
+            p2: ::std::string::String,
+        ) -> ::corebc_contract::builders::ContractCall<M, ()> {
             self.0
-                .method_hash([204, 21, 87, 75], (p0, p1))
+                .method_hash([121, 80, 43, 182], (p0, p1, p2))
                 .expect("method not found (this should never happen)")
         }
-        ///Calls the contract's `createSelectFork` (0x47cbbd7a) function
-        pub fn create_select_fork_2(
+        ///Calls the contract's `assertEq` (0x8977906b) function
+        pub fn assert_eq_3(
             &self,
-            p0: ::std::string::String,
-            p1: [u8; 32],
-        ) -> ::corebc_contract::builders::ContractCall<M, ::corebc_core::types::U256> {
+            p0: ::corebc_core::types::Address,
+            p1: ::corebc_core::types::Address,
+        ) -> ::corebc_contract::builders::ContractCall<M, ()> {
             self.0
-                .method_hash([71, 203, 189, 122], (p0, p1))
+                .method_hash([137, 119, 144, 107], (p0, p1))
                 .expect("method not found (this should never happen)")
         }
-        ///Calls the contract's `createSelectFork` (0x6bcf2c60) function
-        pub fn create_select_fork_0(
+        ///Calls the contract's `assertEq` (0x9668c678) function
+        pub fn assert_eq_17(
             &self,
-            p0: ::std::string::String,
-        ) -> ::corebc_contract::builders::ContractCall<M, ::corebc_core::types::U256> {
+            p0: ::corebc_core::types::Address,
+            p1: ::corebc_core::types::Address,
+            p2: ::std::string::String,
+        ) -> ::corebc_contract::builders::ContractCall<M, ()> {
             self.0
-                .method_hash([107, 207, 44, 96], p0)
+                .method_hash([150, 104, 198, 120], (p0, p1, p2))
                 .expect("method not found (this should never happen)")
         }
-        ///Calls the contract's `deal` (0x5ae137d7) function
-        pub fn deal(
+        ///Calls the contract's `assertEq` (0xd25ce00c) function
+        pub fn assert_eq_4(
             &self,
-            p0: ::corebc_core::types::Address,
-            p1: ::corebc_core::types::U256,
+            p0: [u8; 32],
+            p1: [u8; 32],
         ) -> ::corebc_contract::builders::ContractCall<M, ()> {
             self.0
-                .method_hash([90, 225, 55, 215], (p0, p1))
+                .method_hash([210, 92, 224, 12], (p0, p1))
                 .expect("method not found (this should never happen)")
         }
-        ///Calls the contract's `deriveKey` (0xf6f7e9e1) function
-        pub fn derive_key_0(
+        ///Calls the contract's `assertEq` (0x21475794) function
+        pub fn assert_eq_18(
             &self,
-            p0: ::std::string::String,
-            p1: u32,
-        ) -> ::corebc_contract::builders::ContractCall<M, ::corebc_core::types::U256> {
+            p0: [u8; 32],
+            p1: [u8; 32],
+            p2: ::std::string::String,
+        ) -> ::corebc_contract::builders::ContractCall<M, ()> {
             self.0
-                .method_hash([246, 247, 233, 225], (p0, p1))
+                .method_hash([33, 71, 87, 148], (p0, p1, p2))
                 .expect("method not found (this should never happen)")
         }
-        ///Calls the contract's `deriveKey` (0xd5a72210) function
-        pub fn derive_key_1(
+        ///Calls the contract's `assertEq` (0x9b90905e) function
+        pub fn assert_eq_5(
             &self,
             p0: ::std::string::String,
             p1: ::std::string::String,
-            p2: u32,
-        ) -> ::corebc_contract::builders::ContractCall<M, ::corebc_core::types::U256> {
+        ) -> ::corebc_contract::builders::ContractCall<M, ()> {
             self.0
-                .method_hash([213, 167, 34, 16], (p0, p1, p2))
+                .method_hash([155, 144, 144, 94], (p0, p1))
                 .expect("method not found (this should never happen)")
         }
-        ///Calls the contract's `deriveKey` (0xee602b67) function
-        pub fn derive_key_2(
+        ///Calls the contract's `assertEq` (0xa2e3b04d) function
+        pub fn assert_eq_19(
             &self,
             p0: ::std::string::String,
-            p1: u32,
+            p1: ::std::string::String,
             p2: ::std::string::String,
-        ) -> ::corebc_contract::builders::ContractCall<M, ::corebc_core::types::U256> {
+        ) -> ::corebc_contract::builders::ContractCall<M, ()> {
             self.0
-                .method_hash([238, 96, 43, 103], (p0, p1, p2))
+                .method_hash([162, 227, 176, 77], (p0, p1, p2))
                 .expect("method not found (this should never happen)")
         }
-        ///Calls the contract's `deriveKey` (0xcc819384) function
-        pub fn derive_key_3(
+        ///Calls the contract's `assertEq` (0xc509993b) function
+        pub fn assert_eq_6(
             &self,
-            p0: ::std::string::String,
-            p1: ::std::string::String,
-            p2: u32,
-            p3: ::std::string::String,
-        ) -> ::corebc_contract::builders::ContractCall<M, ::corebc_core::types::U256> {
+            p0: ::corebc_core::types::Bytes,
+            p1: ::corebc_core::types::Bytes,
+        ) -> ::corebc_contract::builders::ContractCall<M, ()> {
             self.0
-                .method_hash([204, 129, 147, 132], (p0, p1, p2, p3))
+                .method_hash([197, 9, 153, 59], (p0, p1))
                 .expect("method not found (this should never happen)")
         }
-        ///Calls the contract's `difficulty` (0xfc75ba15) function
-        pub fn difficulty(
+        ///Calls the contract's `assertEq` (0xceb16849) function
+        pub fn assert_eq_20(
             &self,
-            p0: ::corebc_core::types::U256,
+            p0: ::corebc_core::types::Bytes,
+            p1: ::corebc_core::types::Bytes,
+            p2: ::std::string::String,
         ) -> ::corebc_contract::builders::ContractCall<M, ()> {
             self.0
-                .method_hash([252, 117, 186, 21], p0)
+                .method_hash([206, 177, 104, 73], (p0, p1, p2))
                 .expect("method not found (this should never happen)")
         }
-        ///Calls the contract's `envAddress` (0x5992c5c6) function
-        pub fn env_address_0(
+        ///Calls the contract's `assertEq` (0xae11caca) function
+        pub fn assert_eq_7(
             &self,
-            p0: ::std::string::String,
-        ) -> ::corebc_contract::builders::ContractCall<
-            M,
-            ::corebc_core::types::Address,
-        > {
+            p0: ::std::vec::Vec<bool>,
+            p1: ::std::vec::Vec<bool>,
+        ) -> ::corebc_contract::builders::ContractCall<M, ()> {
             self.0
-                .method_hash([89, 146, 197, 198], p0)
+                .method_hash([174, 17, 202, 202], (p0, p1))
                 .expect("method not found (this should never happen)")
         }
-        ///Calls the contract's `envAddress` (0xdd088083) function
-        pub fn env_address_1(
+        ///Calls the contract's `assertEq` (0xc838dece) function
+        pub fn assert_eq_21(
             &self,
-            p0: ::std::string::String,
-            p1: ::std::string::String,
-        ) -> ::corebc_contract::builders::ContractCall<
-            M,
-            ::std::vec::Vec<::corebc_core::types::Address>,
-        > {
+            p0: ::std::vec::Vec<bool>,
+            p1: ::std::vec::Vec<bool>,
+            p2: ::std::string::String,
+        ) -> ::corebc_contract::builders::ContractCall<M, ()> {
             self.0
-                .method_hash([221, 8, 128, 131], (p0, p1))
+                .method_hash([200, 56, 222, 206], (p0, p1, p2))
                 .expect("method not found (this should never happen)")
         }
-        ///Calls the contract's `envBool` (0xfd40bd11) function
-        pub fn env_bool_0(
+        ///Calls the contract's `assertEq` (0xc15e3ddf) function
+        pub fn assert_eq_8(
             &self,
-            p0: ::std::string::String,
-        ) -> ::corebc_contract::builders::ContractCall<M, bool> {
+            p0: ::std::vec::Vec<::corebc_core::types::U256>,
+            p1: ::std::vec::Vec<::corebc_core::types::U256>,
+        ) -> ::corebc_contract::builders::ContractCall<M, ()> {
             self.0
-                .method_hash([253, 64, 189, 17], p0)
+                .method_hash([193, 94, 61, 223], (p0, p1))
                 .expect("method not found (this should never happen)")
         }
-        ///Calls the contract's `envBool` (0xdf739509) function
-        pub fn env_bool_1(
+        ///Calls the contract's `assertEq` (0x1773c6fa) function
+        pub fn assert_eq_22(
             &self,
-            p0: ::std::string::String,
-            p1: ::std::string::String,
-        ) -> ::corebc_contract::builders::ContractCall<M, ::std::vec::Vec<bool>> {
+            p0: ::std::vec::Vec<::corebc_core::types::U256>,
+            p1: ::std::vec::Vec<::corebc_core::types::U256>,
+            p2: ::std::string::String,
+        ) -> ::corebc_contract::builders::ContractCall<M, ()> {
             self.0
-                .method_hash([223, 115, 149, 9], (p0, p1))
+                .method_hash([23, 115, 198, 250], (p0, p1, p2))
                 .expect("method not found (this should never happen)")
         }
-        ///Calls the contract's `envBytes` (0xb1676ba0) function
-        pub fn env_bytes_0(
+        ///Calls the contract's `assertEq` (0x09972936) function
+        pub fn assert_eq_9(
             &self,
-            p0: ::std::string::String,
-        ) -> ::corebc_contract::builders::ContractCall<M, ::corebc_core::types::Bytes> {
+            p0: ::std::vec::Vec<::corebc_core::types::I256>,
+            p1: ::std::vec::Vec<::corebc_core::types::I256>,
+        ) -> ::corebc_contract::builders::ContractCall<M, ()> {
             self.0
-                .method_hash([177, 103, 107, 160], p0)
+                .method_hash([9, 151, 41, 54], (p0, p1))
                 .expect("method not found (this should never happen)")
         }
-        ///Calls the contract's `envBytes` (0xd9577bb1) function
-        pub fn env_bytes_1(
+        ///Calls the contract's `assertEq` (0xd082c718) function
+        pub fn assert_eq_23(
             &self,
-            p0: ::std::string::String,
-            p1: ::std::string::String,
-        ) -> ::corebc_contract::builders::ContractCall<
-            M,
-            ::std::vec::Vec<::corebc_core::types::Bytes>,
-        > {
+            p0: ::std::vec::Vec<::corebc_core::types::I256>,
+            p1: ::std::vec::Vec<::corebc_core::types::I256>,
+            p2: ::std::string::String,
+        ) -> ::corebc_contract::builders::ContractCall<M, ()> {
             self.0
-                .method_hash([217, 87, 123, 177], (p0, p1))
+                .method_hash([208, 130, 199, 24], (p0, p1, p2))
                 .expect("method not found (this should never happen)")
         }
-        ///Calls the contract's `envBytes32` (0x2d261418) function
-        pub fn env_bytes_320(
+        ///Calls the contract's `assertEq` (0xea772e51) function
+        pub fn assert_eq_10(
             &self,
-            p0: ::std::string::String,
-        ) -> ::corebc_contract::builders::ContractCall<M, [u8; 32]> {
+            p0: ::std::vec::Vec<::corebc_core::types::Address>,
+            p1: ::std::vec::Vec<::corebc_core::types::Address>,
+        ) -> ::corebc_contract::builders::ContractCall<M, ()> {
             self.0
-                .method_hash([45, 38, 20, 24], p0)
+                .method_hash([234, 119, 46, 81], (p0, p1))
                 .expect("method not found (this should never happen)")
         }
-        ///Calls the contract's `envBytes32` (0x5c1826d3) function
-        pub fn env_bytes_321(
+        ///Calls the contract's `assertEq` (0x27f1e6d4) function
+        pub fn assert_eq_24(
             &self,
-            p0: ::std::string::String,
-            p1: ::std::string::String,
-        ) -> ::corebc_contract::builders::ContractCall<M, ::std::vec::Vec<[u8; 32]>> {
+            p0: ::std::vec::Vec<::corebc_core::types::Address>,
+            p1: ::std::vec::Vec<::corebc_core::types::Address>,
+            p2: ::std::string::String,
+        ) -> ::corebc_contract::builders::ContractCall<M, ()> {
             self.0
-                .method_hash([92, 24, 38, 211], (p0, p1))
+                .method_hash([39, 241, 230, 212], (p0, p1, p2))
                 .expect("method not found (this should never happen)")
         }
-        ///Calls the contract's `envInt` (0xdba2cad8) function
-        pub fn env_int_0(
+        ///Calls the contract's `assertEq` (0xaf444979) function
+        pub fn assert_eq_11(
             &self,
-            p0: ::std::string::String,
-        ) -> ::corebc_contract::builders::ContractCall<M, ::corebc_core::types::I256> {
+            p0: ::std::vec::Vec<[u8; 32]>,
+            p1: ::std::vec::Vec<[u8; 32]>,
+        ) -> ::corebc_contract::builders::ContractCall<M, ()> {
             self.0
-                .method_hash([219, 162, 202, 216], p0)
+                .method_hash([175, 68, 73, 121], (p0, p1))
                 .expect("method not found (this should never happen)")
         }
-        ///Calls the contract's `envInt` (0x347968b0) function
-        pub fn env_int_1(
+        ///Calls the contract's `assertEq` (0x54fbca85) function
+        pub fn assert_eq_25(
             &self,
-            p0: ::std::string::String,
-            p1: ::std::string::String,
-        ) -> ::corebc_contract::builders::ContractCall<
-            M,
-            ::std::vec::Vec<::corebc_core::types::I256>,
-        > {
+            p0: ::std::vec::Vec<[u8; 32]>,
+            p1: ::std::vec::Vec<[u8; 32]>,
+            p2: ::std::string::String,
+        ) -> ::corebc_contract::builders::ContractCall<M, ()> {
             self.0
-                .method_hash([52, 121, 104, 176], (p0, p1))
+                .method_hash([84, 251, 202, 133], (p0, p1, p2))
                 .expect("method not found (this should never happen)")
         }
-        ///Calls the contract's `envOr` (0x8efd04a9) function
-        pub fn env_or_0(
+        ///Calls the contract's `assertEq` (0x76542b3d) function
+        pub fn assert_eq_12(
             &self,
-            p0: ::std::string::String,
-            p1: bool,
-        ) -> ::corebc_contract::builders::ContractCall<M, bool> {
+            p0: ::std::vec::Vec<::std::string::String>,
+            p1: ::std::vec::Vec<::std::string::String>,
+        ) -> ::corebc_contract::builders::ContractCall<M, ()> {
             self.0
-                .method_hash([142, 253, 4, 169], (p0, p1))
+                .method_hash([118, 84, 43, 61], (p0, p1))
                 .expect("method not found (this should never happen)")
         }
-        ///Calls the contract's `envOr` (0x1c6db43b) function
-        pub fn env_or_1(
+        ///Calls the contract's `assertEq` (0x2a27879b) function
+        pub fn assert_eq_26(
             &self,
-            p0: ::std::string::String,
-            p1: ::corebc_core::types::U256,
-        ) -> ::corebc_contract::builders::ContractCall<M, ::corebc_core::types::U256> {
+            p0: ::std::vec::Vec<::std::string::String>,
+            p1: ::std::vec::Vec<::std::string::String>,
+            p2: ::std::string::String,
+        ) -> ::corebc_contract::builders::ContractCall<M, ()> {
             self.0
-                .method_hash([28, 109, 180, 59], (p0, p1))
+                .method_hash([42, 39, 135, 155], (p0, p1, p2))
                 .expect("method not found (this should never happen)")
         }
-        ///Calls the contract's `envOr` (0x35711e3f) function
-        pub fn env_or_2(
+        ///Calls the contract's `assertEq` (0xeea73f11) function
+        pub fn assert_eq_13(
             &self,
-            p0: ::std::string::String,
-            p1: ::corebc_core::types::I256,
-        ) -> ::corebc_contract::builders::ContractCall<M, ::corebc_core::types::I256> {
+            p0: ::std::vec::Vec<::corebc_core::types::Bytes>,
+            p1: ::std::vec::Vec<::corebc_core::types::Bytes>,
+        ) -> ::corebc_contract::builders::ContractCall<M, ()> {
             self.0
-                .method_hash([53, 113, 30, 63], (p0, p1))
+                .method_hash([238, 167, 63, 17], (p0, p1))
                 .expect("method not found (this should never happen)")
         }
-        ///Calls the contract's `envOr` (0x18745f8c) function
-        pub fn env_or_3(
+        ///Calls the contract's `assertEq` (0x94eea79c) function
+        pub fn assert_eq_27(
             &self,
-            p0: ::std::string::String,
-            p1: ::corebc_core::types::Address,
-        ) -> ::corebc_contract::builders::ContractCall<
-            M,
-            ::corebc_core::types::Address,
-        > {
+            p0: ::std::vec::Vec<::corebc_core::types::Bytes>,
+            p1: ::std::vec::Vec<::corebc_core::types::Bytes>,
+            p2: ::std::string::String,
+        ) -> ::corebc_contract::builders::ContractCall<M, ()> {
             self.0
-                .method_hash([24, 116, 95, 140], (p0, p1))
+                .method_hash([148, 238, 167, 156], (p0, p1, p2))
                 .expect("method not found (this should never happen)")
         }
-        ///Calls the contract's `envOr` (0xcb48937a) function
-        pub fn env_or_4(
+        ///Calls the contract's `assertFalse` (0x5b584927) function
+        pub fn assert_false_0(
             &self,
-            p0: ::std::string::String,
-            p1: [u8; 32],
-        ) -> ::corebc_contract::builders::ContractCall<M, [u8; 32]> {
+            p0: bool,
+        ) -> ::corebc_contract::builders::ContractCall<M, ()> {
             self.0
-                .method_hash([203, 72, 147, 122], (p0, p1))
+                .method_hash([91, 88, 73, 39], p0)
                 .expect("method not found (this should never happen)")
         }
-        ///Calls the contract's `envOr` (0x42d1d0ef) function
-        pub fn env_or_5(
+        ///Calls the contract's `assertFalse` (0xe05d3983) function
+        pub fn assert_false_1(
             &self,
-            p0: ::std::string::String,
+            p0: bool,
             p1: ::std::string::String,
-        ) -> ::corebc_contract::builders::ContractCall<M, ::std::string::String> {
+        ) -> ::corebc_contract::builders::ContractCall<M, ()> {
             self.0
-                .method_hash([66, 209, 208, 239], (p0, p1))
+                .method_hash([224, 93, 57, 131], (p0, p1))
                 .expect("method not found (this should never happen)")
         }
-        ///Calls the contract's `envOr` (0x2dd17959) function
-        pub fn env_or_6(
+        ///Calls the contract's `assertGe` (0x8beecaa2) function
+        pub fn assert_ge_0(
             &self,
-            p0: ::std::string::String,
-            p1: ::corebc_core::types::Bytes,
-        ) -> ::corebc_contract::builders::ContractCall<M, ::corebc_core::types::Bytes> {
+            p0: ::corebc_core::types::U256,
+            p1: ::corebc_core::types::U256,
+        ) -> ::corebc_contract::builders::ContractCall<M, ()> {
             self.0
-                .method_hash([45, 209, 121, 89], (p0, p1))
+                .method_hash([139, 238, 202, 162], (p0, p1))
                 .expect("method not found (this should never happen)")
         }
-        ///Calls the contract's `envOr` (0x9430ce1e) function
-        pub fn env_or_7(
+        ///Calls the contract's `assertGe` (0xa3861d28) function
+        pub fn assert_ge_2(
             &self,
-            p0: ::std::string::String,
-            p1: ::std::string::String,
-            p2: ::std::vec::Vec<bool>,
-        ) -> ::corebc_contract::builders::ContractCall<M, ::std::vec::Vec<bool>> {
+            p0: ::corebc_core::types::U256,
+            p1: ::corebc_core::types::U256,
+            p2: ::std::string::String,
+        ) -> ::corebc_contract::builders::ContractCall<M, ()> {
             self.0
-                .method_hash([148, 48, 206, 30], (p0, p1, p2))
+                .method_hash([163, 134, 29, 40], (p0, p1, p2))
                 .expect("method not found (this should never happen)")
         }
-        ///Calls the contract's `envOr` (0xbf6e43ef) function
-        pub fn env_or_8(
+        ///Calls the contract's `assertGe` (0x12462240) function
+        pub fn assert_ge_1(
             &self,
-            p0: ::std::string::String,
-            p1: ::std::string::String,
-            p2: ::std::vec::Vec<::corebc_core::types::U256>,
-        ) -> ::corebc_contract::builders::ContractCall<
-            M,
-            ::std::vec::Vec<::corebc_core::types::U256>,
-        > {
+            p0: ::corebc_core::types::I256,
+            p1: ::corebc_core::types::I256,
+        ) -> ::corebc_contract::builders::ContractCall<M, ()> {
             self.0
-                .method_hash([191, 110, 67, 239], (p0, p1, p2))
+                .method_hash([18, 70, 34, 64], (p0, p1))
                 .expect("method not found (this should never happen)")
         }
-        ///Calls the contract's `envOr` (0x60a8eb5e) function
-        pub fn env_or_9(
+        ///Calls the contract's `assertGe` (0x676f3853) function
+        pub fn assert_ge_3(
             &self,
-            p0: ::std::string::String,
-            p1: ::std::string::String,
-            p2: ::std::vec::Vec<::corebc_core::types::I256>,
-        ) -> ::corebc_contract::builders::ContractCall<
-            M,
-            ::std::vec::Vec<::corebc_core::types::I256>,
-        > {
+            p0: ::corebc_core::types::I256,
+            p1: ::corebc_core::types::I256,
+            p2: ::std::string::String,
+        ) -> ::corebc_contract::builders::ContractCall<M, ()> {
             self.0
-                .method_hash([96, 168, 235, 94], (p0, p1, p2))
+                .method_hash([103, 111, 56, 83], (p0, p1, p2))
                 .expect("method not found (this should never happen)")
         }
-        ///Calls the contract's `envOr` (0x0dbd12fc) function
-        pub fn env_or_10(
+        ///Calls the contract's `assertGt` (0x49464840) function
+        pub fn assert_gt_0(
             &self,
-            p0: ::std::string::String,
-            p1: ::std::string::String,
-            p2: ::std::vec::Vec<::corebc_core::types::Address>,
-        ) -> ::corebc_contract::builders::ContractCall<
-            M,
-            ::std::vec::Vec<::corebc_core::types::Address>,
-        > {
+            p0: ::corebc_core::types::U256,
+            p1: ::corebc_core::types::U256,
+        ) -> ::corebc_contract::builders::ContractCall<M, ()> {
             self.0
-                .method_hash([13, 189, 18, 252], (p0, p1, p2))
+                .method_hash([73, 70, 72, 64], (p0, p1))
                 .expect("method not found (this should never happen)")
         }
-        ///Calls the contract's `envOr` (0x864e3a3c) function
-        pub fn env_or_11(
+        ///Calls the contract's `assertGt` (0xadec9b5d) function
+        pub fn assert_gt_2(
             &self,
-            p0: ::std::string::String,
-            p1: ::std::string::String,
-            p2: ::std::vec::Vec<[u8; 32]>,
-        ) -> ::corebc_contract::builders::ContractCall<M, ::std::vec::Vec<[u8; 32]>> {
+            p0: ::corebc_core::types::U256,
+            p1: ::corebc_core::types::U256,
+            p2: ::std::string::String,
+        ) -> ::corebc_contract::builders::ContractCall<M, ()> {
             self.0
-                .method_hash([134, 78, 58, 60], (p0, p1, p2))
+                .method_hash([173, 236, 155, 93], (p0, p1, p2))
                 .expect("method not found (this should never happen)")
         }
-        ///Calls the contract's `envOr` (0x9eb2a8c4) function
-        pub fn env_or_12(
+        ///Calls the contract's `assertGt` (0xe58f1dd6) function
+        pub fn assert_gt_1(
             &self,
-            p0: ::std::string::String,
-            p1: ::std::string::String,
-            p2: ::std::vec::Vec<::std::string::String>,
-        ) -> ::corebc_contract::builders::ContractCall<
-            M,
-            ::std::vec::Vec<::std::string::String>,
-        > {
+            p0: ::corebc_core::types::I256,
+            p1: ::corebc_core::types::I256,
+        ) -> ::corebc_contract::builders::ContractCall<M, ()> {
             self.0
-                .method_hash([158, 178, 168, 196], (p0, p1, p2))
+                .method_hash([229, 143, 29, 214], (p0, p1))
                 .expect("method not found (this should never happen)")
         }
-        ///Calls the contract's `envOr` (0x206f7cdd) function
-        pub fn env_or_13(
+        ///Calls the contract's `assertGt` (0x301a5a2a) function
+        pub fn assert_gt_3(
             &self,
-            p0: ::std::string::String,
-            p1: ::std::string::String,
-            p2: ::std::vec::Vec<::corebc_core::types::Bytes>,
-        ) -> ::corebc_contract::builders::ContractCall<
-            M,
-            ::std::vec::Vec<::corebc_core::types::Bytes>,
-        > {
+            p0: ::corebc_core::types::I256,
+            p1: ::corebc_core::types::I256,
+            p2: ::std::string::String,
+        ) -> ::corebc_contract::builders::ContractCall<M, ()> {
             self.0
-                .method_hash([32, 111, 124, 221], (p0, p1, p2))
+                .method_hash([48, 26, 90, 42], (p0, p1, p2))
                 .expect("method not found (this should never happen)")
         }
-        ///Calls the contract's `envString` (0xe613c3f6) function
-        pub fn env_string_0(
+        ///Calls the contract's `assertLe` (0xdded40d8) function
+        pub fn assert_le_0(
             &self,
-            p0: ::std::string::String,
-        ) -> ::corebc_contract::builders::ContractCall<M, ::std::string::String> {
+            p0: ::corebc_core::types::U256,
+            p1: ::corebc_core::types::U256,
+        ) -> ::corebc_contract::builders::ContractCall<M, ()> {
             self.0
-                .method_hash([230, 19, 195, 246], p0)
+                .method_hash([221, 237, 64, 216], (p0, p1))
                 .expect("method not found (this should never happen)")
         }
-        ///Calls the contract's `envString` (0x535a86f8) function
-        pub fn env_string_1(
+        ///Calls the contract's `assertLe` (0xdf966522) function
+        pub fn assert_le_2(
             &self,
-            p0: ::std::string::String,
-            p1: ::std::string::String,
-        ) -> ::corebc_contract::builders::ContractCall<
-            M,
-            ::std::vec::Vec<::std::string::String>,
-        > {
+            p0: ::corebc_core::types::U256,
+            p1: ::corebc_core::types::U256,
+            p2: ::std::string::String,
+        ) -> ::corebc_contract::builders::ContractCall<M, ()> {
             self.0
-                .method_hash([83, 90, 134, 248], (p0, p1))
+                .method_hash([223, 150, 101, 34], (p0, p1, p2))
                 .expect("method not found (this should never happen)")
         }
-        ///Calls the contract's `envUint` (0x22b1fcf4) function
-        pub fn env_uint_0(
+        ///Calls the contract's `assertLe` (0xb86ab0d9) function
+        pub fn assert_le_1(
             &self,
-            p0: ::std::string::String,
-        ) -> ::corebc_contract::builders::ContractCall<M, ::corebc_core::types::U256> {
+            p0: ::corebc_core::types::I256,
+            p1: ::corebc_core::types::I256,
+        ) -> ::corebc_contract::builders::ContractCall<M, ()> {
             self.0
-                .method_hash([34, 177, 252, 244], p0)
+                .method_hash([184, 106, 176, 217], (p0, p1))
                 .expect("method not found (this should never happen)")
         }
-        ///Calls the contract's `envUint` (0x09437722) function
-        pub fn env_uint_1(
+        ///Calls the contract's `assertLe` (0xf9cca740) function
+        pub fn assert_le_3(
             &self,
-            p0: ::std::string::String,
-            p1: ::std::string::String,
-        ) -> ::corebc_contract::builders::ContractCall<
-            M,
-            ::std::vec::Vec<::corebc_core::types::U256>,
-        > {
+            p0: ::corebc_core::types::I256,
+            p1: ::corebc_core::types::I256,
+            p2: ::std::string::String,
+        ) -> ::corebc_contract::builders::ContractCall<M, ()> {
             self.0
-                .method_hash([9, 67, 119, 34], (p0, p1))
+                .method_hash([249, 204, 167, 64], (p0, p1, p2))
                 .expect("method not found (this should never happen)")
         }
-        ///Calls the contract's `etch` (0x61106001) function
-        pub fn etch(
+        ///Calls the contract's `assertLt` (0x2ff0cc15) function
+        pub fn assert_lt_0(
             &self,
-            p0: ::corebc_core::types::Address,
-            p1: ::corebc_core::types::Bytes,
+            p0: ::corebc_core::types::U256,
+            p1: ::corebc_core::types::U256,
         ) -> ::corebc_contract::builders::ContractCall<M, ()> {
             self.0
-                .method_hash([97, 16, 96, 1], (p0, p1))
+                .method_hash([47, 240, 204, 21], (p0, p1))
                 .expect("method not found (this should never happen)")
         }
-        ///Calls the contract's `expectCall` (0xc244877f) function
-        pub fn expect_call_0(
+        ///Calls the contract's `assertLt` (0xb8ab38ed) function
+        pub fn assert_lt_2(
             &self,
-            p0: ::corebc_core::types::Address,
-            p1: ::corebc_core::types::Bytes,
+            p0: ::corebc_core::types::U256,
+            p1: ::corebc_core::types::U256,
+            p2: ::std::string::String,
         ) -> ::corebc_contract::builders::ContractCall<M, ()> {
             self.0
-                .method_hash([194, 68, 135, 127], (p0, p1))
+                .method_hash([184, 171, 56, 237], (p0, p1, p2))
                 .expect("method not found (this should never happen)")
         }
-        ///Calls the contract's `expectCall` (0x35ce3631) function
-        pub fn expect_call_1(
+        ///Calls the contract's `assertLt` (0xb620afc1) function
+        pub fn assert_lt_1(
             &self,
-            p0: ::corebc_core::types::Address,
-            p1: ::corebc_core::types::Bytes,
-            p2: u64,
+            p0: ::corebc_core::types::I256,
+            p1: ::corebc_core::types::I256,
         ) -> ::corebc_contract::builders::ContractCall<M, ()> {
             self.0
-                .method_hash([53, 206, 54, 49], (p0, p1, p2))
+                .method_hash([182, 32, 175, 193], (p0, p1))
                 .expect("method not found (this should never happen)")
         }
-        ///Calls the contract's `expectCall` (0x5a1f47d6) function
-        pub fn expect_call_2(
+        ///Calls the contract's `assertLt` (0x83c3558e) function
+        pub fn assert_lt_3(
             &self,
-            p0: ::corebc_core::types::Address,
-            p1: ::corebc_core::types::U256,
-            p2: ::corebc_core::types::Bytes,
+            p0: ::corebc_core::types::I256,
+            p1: ::corebc_core::types::I256,
+            p2: ::std::string::String,
         ) -> ::corebc_contract::builders::ContractCall<M, ()> {
             self.0
-                .method_hash([90, 31, 71, 214], (p0, p1, p2))
+                .method_hash([131, 195, 85, 142], (p0, p1, p2))
                 .expect("method not found (this should never happen)")
         }
-        ///Calls the contract's `expectCall` (0xde6e6e54) function
-        pub fn expect_call_3(
+        ///Calls the contract's `assertNotEq` (0x0f8131b1) function
+        pub fn assert_not_eq_0(
             &self,
-            p0: ::corebc_core::types::Address,
-            p1: ::corebc_core::types::U256,
-            p2: ::corebc_core::types::Bytes,
-            p3: u64,
+            p0: bool,
+            p1: bool,
         ) -> ::corebc_contract::builders::ContractCall<M, ()> {
             self.0
-                .method_hash([222, 110, 110, 84], (p0, p1, p2, p3))
+                .method_hash([15, 129, 49, 177], (p0, p1))
                 .expect("method not found (this should never happen)")
         }
-        ///Calls the contract's `expectCall` (0x536671ce) function
-        pub fn expect_call_4(
+        ///Calls the contract's `assertNotEq` (0xfc47da4e) function
+        pub fn assert_not_eq_14(
             &self,
-            p0: ::corebc_core::types::Address,
-            p1: ::corebc_core::types::U256,
-            p2: u64,
-            p3: ::corebc_core::types::Bytes,
+            p0: bool,
+            p1: bool,
+            p2: ::std::string::String,
         ) -> ::corebc_contract::builders::ContractCall<M, ()> {
             self.0
-                .method_hash([83, 102, 113, 206], (p0, p1, p2, p3))
+                .method_hash([252, 71, 218, 78], (p0, p1, p2))
                 .expect("method not found (this should never happen)")
         }
-        ///Calls the contract's `expectCall` (0x66ca6367) function
-        pub fn expect_call_5(
+        ///Calls the contract's `assertNotEq` (0x72c072b8) function
+        pub fn assert_not_eq_1(
             &self,
-            p0: ::corebc_core::types::Address,
+            p0: ::corebc_core::types::U256,
             p1: ::corebc_core::types::U256,
-            p2: u64,
-            p3: ::corebc_core::types::Bytes,
-            p4: u64,
         ) -> ::corebc_contract::builders::ContractCall<M, ()> {
             self.0
-                .method_hash([102, 202, 99, 103], (p0, p1, p2, p3, p4))
+                .method_hash([114, 192, 114, 184], (p0, p1))
                 .expect("method not found (this should never happen)")
         }
-        ///Calls the contract's `expectCallMinGas` (0x782f8cdb) function
-        pub fn expect_call_min_gas_0(
+        ///Calls the contract's `assertNotEq` (0x5707fb9a) function
+        pub fn assert_not_eq_15(
             &self,
-            p0: ::corebc_core::types::Address,
+            p0: ::corebc_core::types::U256,
             p1: ::corebc_core::types::U256,
-            p2: u64,
-            p3: ::corebc_core::types::Bytes,
+            p2: ::std::string::String,
         ) -> ::corebc_contract::builders::ContractCall<M, ()> {
             self.0
-                .method_hash([120, 47, 140, 219], (p0, p1, p2, p3))
+                .method_hash([87, 7, 251, 154], (p0, p1, p2))
                 .expect("method not found (this should never happen)")
         }
-        ///Calls the contract's `expectCallMinGas` (0x7f5de23e) function
-        pub fn expect_call_min_gas_1(
+        ///Calls the contract's `assertNotEq` (0xdfee9455) function
+        pub fn assert_not_eq_2(
             &self,
-            p0: ::corebc_core::types::Address,
-            p1: ::corebc_core::types::U256,
-            p2: u64,
-            p3: ::corebc_core::types::Bytes,
-            p4: u64,
+            p0: ::corebc_core::types::I256,
+            p1: ::corebc_core::types::I256,
         ) -> ::corebc_contract::builders::ContractCall<M, ()> {
             self.0
-                .method_hash([127, 93, 226, 62], (p0, p1, p2, p3, p4))
+                .method_hash([223, 238, 148, 85], (p0, p1))
                 .expect("method not found (this should never happen)")
         }
-        ///Calls the contract's `expectEmit` (0x51bd56ad) function
-        pub fn expect_emit_0(&self) -> ::corebc_contract::builders::ContractCall<M, ()> {
+        ///Calls the contract's `assertNotEq` (0x748fd654) function
+        pub fn assert_not_eq_16(
+            &self,
+            p0: ::corebc_core::types::I256,
+            p1: ::corebc_core::types::I256,
+            p2: ::std::string::String,
+        ) -> ::corebc_contract::builders::ContractCall<M, ()> {
             self.0
-                .method_hash([81, 189, 86, 173], ())
+                .method_hash([116, 143, 214, 84], (p0, p1, p2))
                 .expect("method not found (this should never happen)")
         }
-        ///Calls the contract's `expectEmit` (0xbff0146e) function
-        pub fn expect_emit_1(
+        ///Calls the contract's `assertNotEq` (0x279f81b5) function
+        pub fn assert_not_eq_3(
             &self,
             p0: ::corebc_core::types::Address,
+            p1: ::corebc_core::types::Address,
         ) -> ::corebc_contract::builders::ContractCall<M, ()> {
             self.0
-                .method_hash([191, 240, 20, 110], p0)
+                .method_hash([39, 159, 129, 181], (p0, p1))
                 .expect("method not found (this should never happen)")
         }
-        ///Calls the contract's `expectEmit` (0xe2e01dd4) function
-        pub fn expect_emit_2(
+        ///Calls the contract's `assertNotEq` (0xd5fd7ffd) function
+        pub fn assert_not_eq_17(
             &self,
-            p0: bool,
-            p1: bool,
-            p2: bool,
-            p3: bool,
+            p0: ::corebc_core::types::Address,
+            p1: ::corebc_core::types::Address,
+            p2: ::std::string::String,
         ) -> ::corebc_contract::builders::ContractCall<M, ()> {
             self.0
-                .method_hash([226, 224, 29, 212], (p0, p1, p2, p3))
+                .method_hash([213, 253, 127, 253], (p0, p1, p2))
                 .expect("method not found (this should never happen)")
         }
-        ///Calls the contract's `expectEmit` (0xdb3d4fd8) function
-        pub fn expect_emit_3(
+        ///Calls the contract's `assertNotEq` (0x514f0b1e) function
+        pub fn assert_not_eq_4(
             &self,
-            p0: bool,
-            p1: bool,
-            p2: bool,
-            p3: bool,
-            p4: ::corebc_core::types::Address,
+            p0: [u8; 32],
+            p1: [u8; 32],
         ) -> ::corebc_contract::builders::ContractCall<M, ()> {
             self.0
-                .method_hash([219, 61, 79, 216], (p0, p1, p2, p3, p4))
+                .method_hash([81, 79, 11, 30], (p0, p1))
                 .expect("method not found (this should never happen)")
         }
-        ///Calls the contract's `expectRevert` (0x10403acb) function
-        pub fn expect_revert_0(
+        ///Calls the contract's `assertNotEq` (0x1bb8570a) function
+        pub fn assert_not_eq_18(
             &self,
+            p0: [u8; 32],
+            p1: [u8; 32],
+            p2: ::std::string::String,
         ) -> ::corebc_contract::builders::ContractCall<M, ()> {
             self.0
-                .method_hash([16, 64, 58, 203], ())
+                .method_hash([27, 184, 87, 10], (p0, p1, p2))
                 .expect("method not found (this should never happen)")
         }
-        ///Calls the contract's `expectRevert` (0xe37ab4ed) function
-        pub fn expect_revert_1(
+        ///Calls the contract's `assertNotEq` (0xe1c4732e) function
+        pub fn assert_not_eq_5(
             &self,
-            p0: ::corebc_core::types::Bytes,
+            p0: ::std::string::String,
+            p1: ::std::string::String,
         ) -> ::corebc_contract::builders::ContractCall<M, ()> {
             self.0
-                .method_hash([227, 122, 180, 237], p0)
+                .method_hash([225, 196, 115, 46], (p0, p1))
                 .expect("method not found (this should never happen)")
         }
-        ///Calls the contract's `expectRevert` (0x4e7614bc) function
-        pub fn expect_revert_2(
+        ///Calls the contract's `assertNotEq` (0x4d384bce) function
+        pub fn assert_not_eq_19(
             &self,
-            p0: [u8; 4],
+            p0: ::std::string::String,
+            p1: ::std::string::String,
+            p2: ::std::string::String,
         ) -> ::corebc_contract::builders::ContractCall<M, ()> {
             self.0
-                .method_hash([78, 118, 20, 188], p0)
+                .method_hash([77, 56, 75, 206], (p0, p1, p2))
                 .expect("method not found (this should never happen)")
         }
-        ///Calls the contract's `expectSafeMemory` (0x46bb5493) function
-        pub fn expect_safe_memory(
+        ///Calls the contract's `assertNotEq` (0xed9e518d) function
+        pub fn assert_not_eq_6(
             &self,
-            p0: u64,
-            p1: u64,
+            p0: ::corebc_core::types::Bytes,
+            p1: ::corebc_core::types::Bytes,
         ) -> ::corebc_contract::builders::ContractCall<M, ()> {
             self.0
-                .method_hash([70, 187, 84, 147], (p0, p1))
+                .method_hash([237, 158, 81, 141], (p0, p1))
                 .expect("method not found (this should never happen)")
         }
-        ///Calls the contract's `expectSafeMemoryCall` (0x9b61c661) function
-        pub fn expect_safe_memory_call(
+        ///Calls the contract's `assertNotEq` (0x7ccb7ce7) function
+        pub fn assert_not_eq_20(
             &self,
-            p0: u64,
-            p1: u64,
+            p0: ::corebc_core::types::Bytes,
+            p1: ::corebc_core::types::Bytes,
+            p2: ::std::string::String,
         ) -> ::corebc_contract::builders::ContractCall<M, ()> {
             self.0
-                .method_hash([155, 97, 198, 97], (p0, p1))
+                .method_hash([124, 203, 124, 231], (p0, p1, p2))
                 .expect("method not found (this should never happen)")
         }
-        ///Calls the contract's `fee` (0xaa32efc1) function
-        pub fn fee(
+        ///Calls the contract's `assertNotEq` (0xabd365bd) function
+        pub fn assert_not_eq_7(
             &self,
-            p0: ::corebc_core::types::U256,
+            p0: ::std::vec::Vec<bool>,
+            p1: ::std::vec::Vec<bool>,
         ) -> ::corebc_contract::builders::ContractCall<M, ()> {
             self.0
-                .method_hash([170, 50, 239, 193], p0)
+                .method_hash([171, 211, 101, 189], (p0, p1))
                 .expect("method not found (this should never happen)")
         }
-        ///Calls the contract's `ffi` (0x64ba2b32) function
-        pub fn ffi(
+        ///Calls the contract's `assertNotEq` (0xb5017af6) function
+        pub fn assert_not_eq_21(
             &self,
-            p0: ::std::vec::Vec<::std::string::String>,
-        ) -> ::corebc_contract::builders::ContractCall<M, ::corebc_core::types::Bytes> {
+            p0: ::std::vec::Vec<bool>,
+            p1: ::std::vec::Vec<bool>,
+            p2: ::std::string::String,
+        ) -> ::corebc_contract::builders::ContractCall<M, ()> {
             self.0
-                .method_hash([100, 186, 43, 50], p0)
+                .method_hash([181, 1, 122, 246], (p0, p1, p2))
                 .expect("method not found (this should never happen)")
         }
-        ///Calls the contract's `fsMetadata` (0x895b0357) function
-        pub fn fs_metadata(
+        ///Calls the contract's `assertNotEq` (0x3c265062) function
+        pub fn assert_not_eq_8(
             &self,
-            p0: ::std::string::String,
-        ) -> ::corebc_contract::builders::ContractCall<
-            M,
-            (
-                bool,
-                bool,
-                ::corebc_core::types::U256,
-                bool,
-                ::corebc_core::types::U256,
-                ::corebc_core::types::U256,
-                ::corebc_core::types::U256,
-            ),
-        > {
+            p0: ::std::vec::Vec<::corebc_core::types::U256>,
+            p1: ::std::vec::Vec<::corebc_core::types::U256>,
+        ) -> ::corebc_contract::builders::ContractCall<M, ()> {
             self.0
-                .method_hash([137, 91, 3, 87], p0)
+                .method_hash([60, 38, 80, 98], (p0, p1))
                 .expect("method not found (this should never happen)")
         }
-        ///Calls the contract's `getCode` (0xf4311698) function
-        pub fn get_code(
+        ///Calls the contract's `assertNotEq` (0x279d32ca) function
+        pub fn assert_not_eq_22(
             &self,
-            p0: ::std::string::String,
+            p0: ::std::vec::Vec<::corebc_core::types::U256>,
+            p1: ::std::vec::Vec<::corebc_core::types::U256>,
+            p2: ::std::string::String,
         ) -> ::corebc_contract::builders::ContractCall<M, ()> {
             self.0
-                .method_hash([244, 49, 22, 152], p0)
+                .method_hash([39, 157, 50, 202], (p0, p1, p2))
                 .expect("method not found (this should never happen)")
         }
-        ///Calls the contract's `getDeployedCode` (0xe04cfba1) function
-        pub fn get_deployed_code(
+        ///Calls the contract's `assertNotEq` (0xfb1d326b) function
+        pub fn assert_not_eq_9(
             &self,
-            p0: ::std::string::String,
+            p0: ::std::vec::Vec<::corebc_core::types::I256>,
+            p1: ::std::vec::Vec<::corebc_core::types::I256>,
         ) -> ::corebc_contract::builders::ContractCall<M, ()> {
             self.0
-                .method_hash([224, 76, 251, 161], p0)
+                .method_hash([251, 29, 50, 107], (p0, p1))
                 .expect("method not found (this should never happen)")
         }
-        ///Calls the contract's `getLabel` (0x56c8fc59) function
-        pub fn get_label(
+        ///Calls the contract's `assertNotEq` (0xb2506242) function
+        pub fn assert_not_eq_23(
             &self,
-            p0: ::corebc_core::types::Address,
-        ) -> ::corebc_contract::builders::ContractCall<M, ::std::string::String> {
+            p0: ::std::vec::Vec<::corebc_core::types::I256>,
+            p1: ::std::vec::Vec<::corebc_core::types::I256>,
+            p2: ::std::string::String,
+        ) -> ::corebc_contract::builders::ContractCall<M, ()> {
             self.0
-                .method_hash([86, 200, 252, 89], p0)
+                .method_hash([178, 80, 98, 66], (p0, p1, p2))
                 .expect("method not found (this should never happen)")
         }
-        ///Calls the contract's `getNonce` (0x7ee608d7) function
-        pub fn get_nonce(
+        ///Calls the contract's `assertNotEq` (0x059bf9b3) function
+        pub fn assert_not_eq_10(
             &self,
-            p0: ::corebc_core::types::Address,
+            p0: ::std::vec::Vec<::corebc_core::types::Address>,
+            p1: ::std::vec::Vec<::corebc_core::types::Address>,
         ) -> ::corebc_contract::builders::ContractCall<M, ()> {
             self.0
-                .method_hash([126, 230, 8, 215], p0)
+                .method_hash([5, 155, 249, 179], (p0, p1))
                 .expect("method not found (this should never happen)")
         }
-        ///Calls the contract's `getRecordedLogs` (0x6aa9ac15) function
-        pub fn get_recorded_logs(
+        ///Calls the contract's `assertNotEq` (0xd6a76e86) function
+        pub fn assert_not_eq_24(
             &self,
-        ) -> ::corebc_contract::builders::ContractCall<
-            M,
-            ::std::vec::Vec<(::std::vec::Vec<[u8; 32]>, ::corebc_core::types::Bytes)>,
-        > {
+            p0: ::std::vec::Vec<::corebc_core::types::Address>,
+            p1: ::std::vec::Vec<::corebc_core::types::Address>,
+            p2: ::std::string::String,
+        ) -> ::corebc_contract::builders::ContractCall<M, ()> {
             self.0
-                .method_hash([106, 169, 172, 21], ())
+                .method_hash([214, 167, 110, 134], (p0, p1, p2))
                 .expect("method not found (this should never happen)")
         }
-        ///Calls the contract's `isPersistent` (0x27051a9d) function
-        pub fn is_persistent(
+        ///Calls the contract's `assertNotEq` (0x1251e044) function
+        pub fn assert_not_eq_11(
             &self,
-            p0: ::corebc_core::types::Address,
-        ) -> ::corebc_contract::builders::ContractCall<M, bool> {
+            p0: ::std::vec::Vec<[u8; 32]>,
+            p1: ::std::vec::Vec<[u8; 32]>,
+        ) -> ::corebc_contract::builders::ContractCall<M, ()> {
             self.0
-                .method_hash([39, 5, 26, 157], p0)
+                .method_hash([18, 81, 224, 68], (p0, p1))
                 .expect("method not found (this should never happen)")
         }
-        ///Calls the contract's `label` (0xb167f0ca) function
-        pub fn label(
+        ///Calls the contract's `assertNotEq` (0x064f004a) function
+        pub fn assert_not_eq_25(
             &self,
-            p0: ::corebc_core::types::Address,
-            p1: ::std::string::String,
+            p0: ::std::vec::Vec<[u8; 32]>,
+            p1: ::std::vec::Vec<[u8; 32]>,
+            p2: ::std::string::String,
         ) -> ::corebc_contract::builders::ContractCall<M, ()> {
             self.0
-                .method_hash([177, 103, 240, 202], (p0, p1))
+                .method_hash([6, 79, 0, 74], (p0, p1, p2))
                 .expect("method not found (this should never happen)")
         }
-        ///Calls the contract's `load` (0xfac88623) function
-        pub fn load(
+        ///Calls the contract's `assertNotEq` (0x50b8aa54) function
+        pub fn assert_not_eq_12(
             &self,
-            p0: ::corebc_core::types::Address,
-            p1: [u8; 32],
-        ) -> ::corebc_contract::builders::ContractCall<M, [u8; 32]> {
+            p0: ::std::vec::Vec<::std::string::String>,
+            p1: ::std::vec::Vec<::std::string::String>,
+        ) -> ::corebc_contract::builders::ContractCall<M, ()> {
             self.0
-                .method_hash([250, 200, 134, 35], (p0, p1))
+                .method_hash([80, 184, 170, 84], (p0, p1))
                 .expect("method not found (this should never happen)")
         }
-        ///Calls the contract's `makePersistent` (0x9ff5ebb8) function
-        pub fn make_persistent_0(
+        ///Calls the contract's `assertNotEq` (0xa5e08ee7) function
+        pub fn assert_not_eq_26(
             &self,
-            p0: ::corebc_core::types::Address,
+            p0: ::std::vec::Vec<::std::string::String>,
+            p1: ::std::vec::Vec<::std::string::String>,
+            p2: ::std::string::String,
         ) -> ::corebc_contract::builders::ContractCall<M, ()> {
             self.0
-                .method_hash([159, 245, 235, 184], p0)
+                .method_hash([165, 224, 142, 231], (p0, p1, p2))
                 .expect("method not found (this should never happen)")
         }
-        ///Calls the contract's `makePersistent` (0x05bd09f0) function
-        pub fn make_persistent_2(
+        ///Calls the contract's `assertNotEq` (0x000326b4) function
+        pub fn assert_not_eq_13(
             &self,
-            p0: ::corebc_core::types::Address,
-            p1: ::corebc_core::types::Address,
+            p0: ::std::vec::Vec<::corebc_core::types::Bytes>,
+            p1: ::std::vec::Vec<::corebc_core::types::Bytes>,
         ) -> ::corebc_contract::builders::ContractCall<M, ()> {
             self.0
-                .method_hash([5, 189, 9, 240], (p0, p1))
+                .method_hash([0, 3, 38, 180], (p0, p1))
                 .expect("method not found (this should never happen)")
         }
-        ///Calls the contract's `makePersistent` (0xd0f24610) function
-        pub fn make_persistent_3(
+        ///Calls the contract's `assertNotEq` (0x2204fe9d) function
+        pub fn assert_not_eq_27(
             &self,
-            p0: ::corebc_core::types::Address,
-            p1: ::corebc_core::types::Address,
-            p2: ::corebc_core::types::Address,
+            p0: ::std::vec::Vec<::corebc_core::types::Bytes>,
+            p1: ::std::vec::Vec<::corebc_core::types::Bytes>,
+            p2: ::std::string::String,
         ) -> ::corebc_contract::builders::ContractCall<M, ()> {
             self.0
-                .method_hash([208, 242, 70, 16], (p0, p1, p2))
+                .method_hash([34, 4, 254, 157], (p0, p1, p2))
                 .expect("method not found (this should never happen)")
         }
-        ///Calls the contract's `makePersistent` (0xa6180f41) function
-        pub fn make_persistent_1(
+        ///Calls the contract's `assertTrue` (0xf31a90ab) function
+        pub fn assert_true_0(
             &self,
-            p0: ::std::vec::Vec<::corebc_core::types::Address>,
+            p0: bool,
         ) -> ::corebc_contract::builders::ContractCall<M, ()> {
             self.0
-                .method_hash([166, 24, 15, 65], p0)
+                .method_hash([243, 26, 144, 171], p0)
                 .expect("method not found (this should never happen)")
         }
-        ///Calls the contract's `mockCall` (0x4b25c120) function
-        pub fn mock_call_0(
+        ///Calls the contract's `assertTrue` (0x41d7e0a6) function
+        pub fn assert_true_1(
             &self,
-            p0: ::corebc_core::types::Address,
-            p1: ::corebc_core::types::Bytes,
-            p2: ::corebc_core::types::Bytes,
+            p0: bool,
+            p1: ::std::string::String,
         ) -> ::corebc_contract::builders::ContractCall<M, ()> {
             self.0
-                .method_hash([75, 37, 193, 32], (p0, p1, p2))
+                .method_hash([65, 215, 224, 166], (p0, p1))
                 .expect("method not found (this should never happen)")
         }
-        ///Calls the contract's `mockCall` (0xe05abf37) function
-        pub fn mock_call_1(
+        ///Calls the contract's `assume` (0xca480fa9) function
+        pub fn assume(
             &self,
-            p0: ::corebc_core::types::Address,
-            p1: ::corebc_core::types::U256,
-            p2: ::corebc_core::types::Bytes,
-            p3: ::corebc_core::types::Bytes,
+            p0: bool,
         ) -> ::corebc_contract::builders::ContractCall<M, ()> {
             self.0
-                .method_hash([224, 90, 191, 55], (p0, p1, p2, p3))
+                .method_hash([202, 72, 15, 169], p0)
                 .expect("method not found (this should never happen)")
         }
-        ///Calls the contract's `mockCallRevert` (0xe9bb00fb) function
-        pub fn mock_call_revert_0(
+        ///Calls the contract's `breakpoint` (0x3af777a9) function
+        pub fn breakpoint_0(
             &self,
-            p0: ::corebc_core::types::Address,
-            p1: ::corebc_core::types::Bytes,
-            p2: ::corebc_core::types::Bytes,
+            p0: ::std::string::String,
         ) -> ::corebc_contract::builders::ContractCall<M, ()> {
             self.0
-                .method_hash([233, 187, 0, 251], (p0, p1, p2))
+                .method_hash([58, 247, 119, 169], p0)
                 .expect("method not found (this should never happen)")
         }
-        ///Calls the contract's `mockCallRevert` (0xd216223d) function
-        pub fn mock_call_revert_1(
+        ///Calls the contract's `breakpoint` (0x18af918f) function
+        pub fn breakpoint_1(
             &self,
-            p0: ::corebc_core::types::Address,
-            p1: ::corebc_core::types::U256,
-            p2: ::corebc_core::types::Bytes,
-            p3: ::corebc_core::types::Bytes,
+            p0: ::std::string::String,
+            p1: bool,
         ) -> ::corebc_contract::builders::ContractCall<M, ()> {
             self.0
-                .method_hash([210, 22, 34, 61], (p0, p1, p2, p3))
+                .method_hash([24, 175, 145, 143], (p0, p1))
                 .expect("method not found (this should never happen)")
         }
-        ///Calls the contract's `openFile` (0x87badc3e) function
-        pub fn open_file(
-            &self,
-            p0: ::std::string::String,
-        ) -> ::corebc_contract::builders::ContractCall<M, ()> {
+        ///Calls the contract's `broadcast` (0x12f46a0c) function
+        pub fn broadcast_0(&self) -> ::corebc_contract::builders::ContractCall<M, ()> {
             self.0
-                .method_hash([135, 186, 220, 62], p0)
+                .method_hash([18, 244, 106, 12], ())
                 .expect("method not found (this should never happen)")
         }
-        ///Calls the contract's `parseAddress` (0xbf03d4f6) function
-        pub fn parse_address(
+        ///Calls the contract's `broadcast` (0x3aec5261) function
+        pub fn broadcast_1(
             &self,
-            p0: ::std::string::String,
-        ) -> ::corebc_contract::builders::ContractCall<
-            M,
-            ::corebc_core::types::Address,
-        > {
+            p0: ::corebc_core::types::Address,
+        ) -> ::corebc_contract::builders::ContractCall<M, ()> {
             self.0
-                .method_hash([191, 3, 212, 246], p0)
+                .method_hash([58, 236, 82, 97], p0)
                 .expect("method not found (this should never happen)")
         }
-        ///Calls the contract's `parseBool` (0xc711f34c) function
-        pub fn parse_bool(
+        ///Calls the contract's `broadcast` (0x85b0a416) function
+        pub fn broadcast_2(
             &self,
             p0: ::std::string::String,
-        ) -> ::corebc_contract::builders::ContractCall<M, bool> {
+        ) -> ::corebc_contract::builders::ContractCall<M, ()> {
             self.0
-                .method_hash([199, 17, 243, 76], p0)
+                .method_hash([133, 176, 164, 22], p0)
                 .expect("method not found (this should never happen)")
         }
-        ///Calls the contract's `parseBytes` (0x3dc7ecf1) function
-        pub fn parse_bytes(
+        ///Calls the contract's `chainId` (0x5858cd87) function
+        pub fn chain_id(
             &self,
-            p0: ::std::string::String,
-        ) -> ::corebc_contract::builders::ContractCall<M, ::corebc_core::types::Bytes> {
+            p0: ::corebc_core::types::U256,
+        ) -> ::corebc_contract::builders::ContractCall<M, ()> {
             self.0
-                .method_hash([61, 199, 236, 241], p0)
+                .method_hash([88, 88, 205, 135], p0)
                 .expect("method not found (this should never happen)")
         }
-        ///Calls the contract's `parseBytes32` (0x6ea2d6ed) function
-        pub fn parse_bytes_32(
+        ///Calls the contract's `clearMockedCalls` (0x7eaff7de) function
+        pub fn clear_mocked_calls(
             &self,
-            p0: ::std::string::String,
-        ) -> ::corebc_contract::builders::ContractCall<M, [u8; 32]> {
+        ) -> ::corebc_contract::builders::ContractCall<M, ()> {
             self.0
-                .method_hash([110, 162, 214, 237], p0)
+                .method_hash([126, 175, 247, 222], ())
                 .expect("method not found (this should never happen)")
         }
-        ///Calls the contract's `parseInt` (0xcacaea8a) function
-        pub fn parse_int(
+        ///Calls the contract's `closeFile` (0xa3a9f387) function
+        pub fn close_file(
             &self,
             p0: ::std::string::String,
-        ) -> ::corebc_contract::builders::ContractCall<M, ::corebc_core::types::I256> {
+        ) -> ::corebc_contract::builders::ContractCall<M, ()> {
             self.0
-                .method_hash([202, 202, 234, 138], p0)
+                .method_hash([163, 169, 243, 135], p0)
                 .expect("method not found (this should never happen)")
         }
-        ///Calls the contract's `parseJson` (0x1b14d0fa) function
-        pub fn parse_json_0(
+        ///Calls the contract's `coinbase` (0x53a5c6de) function
+        pub fn coinbase(
             &self,
-            p0: ::std::string::String,
-        ) -> ::corebc_contract::builders::ContractCall<M, ::corebc_core::types::Bytes> {
+            p0: ::corebc_core::types::Address,
+        ) -> ::corebc_contract::builders::ContractCall<M, ()> {
             self.0
-                .method_hash([27, 20, 208, 250], p0)
+                .method_hash([83, 165, 198, 222], p0)
                 .expect("method not found (this should never happen)")
         }
-        ///Calls the contract's `parseJson` (0x9ed12636) function
-        pub fn parse_json_1(
+        ///Calls the contract's `createDir` (0x8b295957) function
+        pub fn create_dir(
             &self,
             p0: ::std::string::String,
-            p1: ::std::string::String,
-        ) -> ::corebc_contract::builders::ContractCall<M, ::corebc_core::types::Bytes> {
+            p1: bool,
+        ) -> ::corebc_contract::builders::ContractCall<M, ()> {
             self.0
-                .method_hash([158, 209, 38, 54], (p0, p1))
+                .method_hash([139, 41, 89, 87], (p0, p1))
                 .expect("method not found (this should never happen)")
         }
-        ///Calls the contract's `parseJsonAddress` (0x6d037439) function
-        pub fn parse_json_address(
+        ///Calls the contract's `createFork` (0x27dc0128) function
+        pub fn create_fork_1(
             &self,
             p0: ::std::string::String,
-            p1: ::std::string::String,
-        ) -> ::corebc_contract::builders::ContractCall<
-            M,
-            ::corebc_core::types::Address,
-        > {
+            p1: ::corebc_core::types::U256,
+        ) -> ::corebc_contract::builders::ContractCall<M, ::corebc_core::types::U256> {
             self.0
-                .method_hash([109, 3, 116, 57], (p0, p1))
+                .method_hash([39, 220, 1, 40], (p0, p1))
                 .expect("method not found (this should never happen)")
         }
-        ///Calls the contract's `parseJsonAddressArray` (0x73ae0e2b) function
-        pub fn parse_json_address_array(
+        ///Calls the contract's `createFork` (0x472ee61e) function
+        pub fn create_fork_2(
             &self,
             p0: ::std::string::String,
-            p1: ::std::string::String,
-        ) -> ::corebc_contract::builders::ContractCall<
-            M,
-            ::std::vec::Vec<::corebc_core::types::Address>,
-        > {
+            p1: [u8; 32],
+        ) -> ::corebc_contract::builders::ContractCall<M, ::corebc_core::types::U256> {
             self.0
-                .method_hash([115, 174, 14, 43], (p0, p1))
+                .method_hash([71, 46, 230, 30], (p0, p1))
                 .expect("method not found (this should never happen)")
         }
-        ///Calls the contract's `parseJsonBool` (0x1ddc1e05) function
-        pub fn parse_json_bool(
+        ///Calls the contract's `createFork` (0xc8282be0) function
+        pub fn create_fork_0(
             &self,
             p0: ::std::string::String,
-            p1: ::std::string::String,
-        ) -> ::corebc_contract::builders::ContractCall<M, bool> {
+        ) -> ::corebc_contract::builders::ContractCall<M, ::corebc_core::types::U256> {
             self.0
-                .method_hash([29, 220, 30, 5], (p0, p1))
+                .method_hash([200, 40, 43, 224], p0)
                 .expect("method not found (this should never happen)")
         }
-        ///Calls the contract's `parseJsonBoolArray` (0x1b7a2c28) function
-        pub fn parse_json_bool_array(
+        ///Calls the contract's `createSelectFork` (0xcc15574b) function
+        pub fn create_select_fork_1(
             &self,
             p0: ::std::string::String,
-            p1: ::std::string::String,
-        ) -> ::corebc_contract::builders::ContractCall<M, ::std::vec::Vec<bool>> {
+            p1: ::corebc_core::types::U256,
+        ) -> ::corebc_contract::builders::ContractCall<M, ::corebc_core::types::U256> {
             self.0
-                .method_hash([27, 122, 44, 40], (p0, p1))
+                .method_hash([204, 21, 87, 75], (p0, p1))
                 .expect("method not found (this should never happen)")
         }
-        ///Calls the contract's `parseJsonBytes` (0x4ae7c4b9) function
-        pub fn parse_json_bytes(
+        ///Calls the contract's `createSelectFork` (0x47cbbd7a) function
+        pub fn create_select_fork_2(
             &self,
             p0: ::std::string::String,
-            p1: ::std::string::String,
-        ) -> ::corebc_contract::builders::ContractCall<M, ::corebc_core::types::Bytes> {
+            p1: [u8; 32],
+        ) -> ::corebc_contract::builders::ContractCall<M, ::corebc_core::types::U256> {
             self.0
-                .method_hash([74, 231, 196, 185], (p0, p1))
+                .method_hash([71, 203, 189, 122], (p0, p1))
                 .expect("method not found (this should never happen)")
         }
-        ///Calls the contract's `parseJsonBytes32` (0x2088d639) function
-        pub fn parse_json_bytes_32(
+        ///Calls the contract's `createSelectFork` (0x6bcf2c60) function
+        pub fn create_select_fork_0(
             &self,
             p0: ::std::string::String,
-            p1: ::std::string::String,
-        ) -> ::corebc_contract::builders::ContractCall<M, [u8; 32]> {
+        ) -> ::corebc_contract::builders::ContractCall<M, ::corebc_core::types::U256> {
             self.0
-                .method_hash([32, 136, 214, 57], (p0, p1))
+                .method_hash([107, 207, 44, 96], p0)
                 .expect("method not found (this should never happen)")
         }
-        ///Calls the contract's `parseJsonBytes32Array` (0x6ed6bf6d) function
-        pub fn parse_json_bytes_32_array(
+        ///Calls the contract's `createWallet` (0xbfbf2050) function
+        pub fn create_wallet(
             &self,
             p0: ::std::string::String,
-            p1: ::std::string::String,
-        ) -> ::corebc_contract::builders::ContractCall<M, ::std::vec::Vec<[u8; 32]>> {
+        ) -> ::corebc_contract::builders::ContractCall<
+            M,
+            (
+                ::corebc_core::types::Address,
+                ::corebc_core::types::Bytes,
+                ::std::string::String,
+            ),
+        > {
             self.0
-                .method_hash([110, 214, 191, 109], (p0, p1))
+                .method_hash([191, 191, 32, 80], p0)
                 .expect("method not found (this should never happen)")
         }
-        ///Calls the contract's `parseJsonBytesArray` (0xc7f2b390) function
-        pub fn parse_json_bytes_array(
+        ///Calls the contract's `deal` (0x5ae137d7) function
+        pub fn deal(
             &self,
-            p0: ::std::string::String,
-            p1: ::std::string::String,
-        ) -> ::corebc_contract::builders::ContractCall<
-            M,
-            ::std::vec::Vec<::corebc_core::types::Bytes>,
-        > {
+            p0: ::corebc_core::types::Address,
+            p1: ::corebc_core::types::U256,
+        ) -> ::corebc_contract::builders::ContractCall<M, ()> {
             self.0
-                .method_hash([199, 242, 179, 144], (p0, p1))
+                .method_hash([90, 225, 55, 215], (p0, p1))
                 .expect("method not found (this should never happen)")
         }
-        ///Calls the contract's `parseJsonInt` (0x7e3d173a) function
-        pub fn parse_json_int(
+        ///Calls the contract's `deriveKey` (0xf6f7e9e1) function
+        pub fn derive_key_0(
             &self,
             p0: ::std::string::String,
-            p1: ::std::string::String,
-        ) -> ::corebc_contract::builders::ContractCall<M, ::corebc_core::types::I256> {
+            p1: u32,
+        ) -> ::corebc_contract::builders::ContractCall<M, ::std::string::String> {
             self.0
-                .method_hash([126, 61, 23, 58], (p0, p1))
+                .method_hash([246, 247, 233, 225], (p0, p1))
                 .expect("method not found (this should never happen)")
         }
-        ///Calls the contract's `parseJsonIntArray` (0xc37c99a1) function
-        pub fn parse_json_int_array(
+        ///Calls the contract's `deriveKey` (0xd5a72210) function
+        pub fn derive_key_1(
             &self,
             p0: ::std::string::String,
             p1: ::std::string::String,
-        ) -> ::corebc_contract::builders::ContractCall<
-            M,
-            ::std::vec::Vec<::corebc_core::types::I256>,
-        > {
+            p2: u32,
+        ) -> ::corebc_contract::builders::ContractCall<M, ::std::string::String> {
             self.0
-                .method_hash([195, 124, 153, 161], (p0, p1))
+                .method_hash([213, 167, 34, 16], (p0, p1, p2))
                 .expect("method not found (this should never happen)")
         }
-        ///Calls the contract's `parseJsonString` (0x9d9b1273) function
-        pub fn parse_json_string(
+        ///Calls the contract's `deriveKey` (0xee602b67) function
+        pub fn derive_key_2(
             &self,
             p0: ::std::string::String,
-            p1: ::std::string::String,
+            p1: u32,
+            p2: ::std::string::String,
         ) -> ::corebc_contract::builders::ContractCall<M, ::std::string::String> {
             self.0
-                .method_hash([157, 155, 18, 115], (p0, p1))
+                .method_hash([238, 96, 43, 103], (p0, p1, p2))
                 .expect("method not found (this should never happen)")
         }
-        ///Calls the contract's `parseJsonStringArray` (0x6499a16c) function
-        pub fn parse_json_string_array(
+        ///Calls the contract's `deriveKey` (0xcc819384) function
+        pub fn derive_key_3(
             &self,
             p0: ::std::string::String,
             p1: ::std::string::String,
-        ) -> ::corebc_contract::builders::ContractCall<
-            M,
-            ::std::vec::Vec<::std::string::String>,
-        > {
+            p2: u32,
+            p3: ::std::string::String,
+        ) -> ::corebc_contract::builders::ContractCall<M, ::std::string::String> {
             self.0
-                .method_hash([100, 153, 161, 108], (p0, p1))
+                .method_hash([204, 129, 147, 132], (p0, p1, p2, p3))
                 .expect("method not found (this should never happen)")
         }
-        ///Calls the contract's `parseJsonUint` (0x7aa5f1ff) function
-        pub fn parse_json_uint(
+        ///Calls the contract's `difficulty` (0xfc75ba15) function
+        pub fn difficulty(
             &self,
-            p0: ::std::string::String,
-            p1: ::std::string::String,
-        ) -> ::corebc_contract::builders::ContractCall<M, ::corebc_core::types::U256> {
+            p0: ::corebc_core::types::U256,
+        ) -> ::corebc_contract::builders::ContractCall<M, ()> {
             self.0
-                .method_hash([122, 165, 241, 255], (p0, p1))
+                .method_hash([252, 117, 186, 21], p0)
                 .expect("method not found (this should never happen)")
         }
-        ///Calls the contract's `parseJsonUintArray` (0x3118994d) function
-        pub fn parse_json_uint_array(
+        ///Calls the contract's `envAddress` (0x5992c5c6) function
+        pub fn env_address_0(
             &self,
             p0: ::std::string::String,
-            p1: ::std::string::String,
         ) -> ::corebc_contract::builders::ContractCall<
             M,
-            ::std::vec::Vec<::corebc_core::types::U256>,
+            ::corebc_core::types::Address,
         > {
             self.0
-                .method_hash([49, 24, 153, 77], (p0, p1))
+                .method_hash([89, 146, 197, 198], p0)
                 .expect("method not found (this should never happen)")
         }
-        ///Calls the contract's `parseUint` (0xd3fdc787) function
-        pub fn parse_uint(
+        ///Calls the contract's `envAddress` (0xdd088083) function
+        pub fn env_address_1(
             &self,
             p0: ::std::string::String,
-        ) -> ::corebc_contract::builders::ContractCall<M, ::corebc_core::types::U256> {
+            p1: ::std::string::String,
+        ) -> ::corebc_contract::builders::ContractCall<
+            M,
+            ::std::vec::Vec<::corebc_core::types::Address>,
+        > {
             self.0
-                .method_hash([211, 253, 199, 135], p0)
+                .method_hash([221, 8, 128, 131], (p0, p1))
                 .expect("method not found (this should never happen)")
         }
-        ///Calls the contract's `pauseGasMetering` (0x48b8fe9f) function
-        pub fn pause_gas_metering(
+        ///Calls the contract's `envBool` (0xfd40bd11) function
+        pub fn env_bool_0(
             &self,
-        ) -> ::corebc_contract::builders::ContractCall<M, ()> {
+            p0: ::std::string::String,
+        ) -> ::corebc_contract::builders::ContractCall<M, bool> {
             self.0
-                .method_hash([72, 184, 254, 159], ())
+                .method_hash([253, 64, 189, 17], p0)
                 .expect("method not found (this should never happen)")
         }
-        ///Calls the contract's `prank` (0x10d64053) function
-        pub fn prank_0(
+        ///Calls the contract's `envBool` (0xdf739509) function
+        pub fn env_bool_1(
             &self,
-            p0: ::corebc_core::types::Address,
-        ) -> ::corebc_contract::builders::ContractCall<M, ()> {
+            p0: ::std::string::String,
+            p1: ::std::string::String,
+        ) -> ::corebc_contract::builders::ContractCall<M, ::std::vec::Vec<bool>> {
             self.0
-                .method_hash([16, 214, 64, 83], p0)
+                .method_hash([223, 115, 149, 9], (p0, p1))
                 .expect("method not found (this should never happen)")
         }
-        ///Calls the contract's `prank` (0x5c3c5387) function
-        pub fn prank_1(
+        ///Calls the contract's `envBytes` (0xb1676ba0) function
+        pub fn env_bytes_0(
             &self,
-            p0: ::corebc_core::types::Address,
-            p1: ::corebc_core::types::Address,
-        ) -> ::corebc_contract::builders::ContractCall<M, ()> {
+            p0: ::std::string::String,
+        ) -> ::corebc_contract::builders::ContractCall<M, ::corebc_core::types::Bytes> {
             self.0
-                .method_hash([92, 60, 83, 135], (p0, p1))
+                .method_hash([177, 103, 107, 160], p0)
                 .expect("method not found (this should never happen)")
         }
-        ///Calls the contract's `projectRoot` (0x6e197bfb) function
-        pub fn project_root(
+        ///Calls the contract's `envBytes` (0xd9577bb1) function
+        pub fn env_bytes_1(
             &self,
-        ) -> ::corebc_contract::builders::ContractCall<M, ::std::string::String> {
+            p0: ::std::string::String,
+            p1: ::std::string::String,
+        ) -> ::corebc_contract::builders::ContractCall<
+            M,
+            ::std::vec::Vec<::corebc_core::types::Bytes>,
+        > {
             self.0
-                .method_hash([110, 25, 123, 251], ())
+                .method_hash([217, 87, 123, 177], (p0, p1))
                 .expect("method not found (this should never happen)")
         }
-        ///Calls the contract's `readCallers` (0xc89ced7f) function
-        pub fn read_callers(
+        ///Calls the contract's `envBytes32` (0x2d261418) function
+        pub fn env_bytes_320(
             &self,
-        ) -> ::corebc_contract::builders::ContractCall<
-            M,
-            (
-                ::corebc_core::types::U256,
-                ::corebc_core::types::Address,
-                ::corebc_core::types::Address,
-            ),
-        > {
+            p0: ::std::string::String,
+        ) -> ::corebc_contract::builders::ContractCall<M, [u8; 32]> {
             self.0
-                .method_hash([200, 156, 237, 127], ())
+                .method_hash([45, 38, 20, 24], p0)
                 .expect("method not found (this should never happen)")
         }
-        ///Calls the contract's `readDir` (0xf108a04c) function
-        pub fn read_dir_0(
+        ///Calls the contract's `envBytes32` (0x5c1826d3) function
+        pub fn env_bytes_321(
             &self,
             p0: ::std::string::String,
-        ) -> ::corebc_contract::builders::ContractCall<
-            M,
-            ::std::vec::Vec<
-                (::std::string::String, ::std::string::String, u64, bool, bool),
-            >,
-        > {
+            p1: ::std::string::String,
+        ) -> ::corebc_contract::builders::ContractCall<M, ::std::vec::Vec<[u8; 32]>> {
             self.0
-                .method_hash([241, 8, 160, 76], p0)
+                .method_hash([92, 24, 38, 211], (p0, p1))
                 .expect("method not found (this should never happen)")
         }
-        ///Calls the contract's `readDir` (0xb2f4318c) function
-        pub fn read_dir_1(
+        ///Calls the contract's `envInt` (0xdba2cad8) function
+        pub fn env_int_0(
             &self,
             p0: ::std::string::String,
-            p1: u64,
-        ) -> ::corebc_contract::builders::ContractCall<
-            M,
-            ::std::vec::Vec<
-                (::std::string::String, ::std::string::String, u64, bool, bool),
-            >,
-        > {
+        ) -> ::corebc_contract::builders::ContractCall<M, ::corebc_core::types::I256> {
             self.0
-                .method_hash([178, 244, 49, 140], (p0, p1))
+                .method_hash([219, 162, 202, 216], p0)
                 .expect("method not found (this should never happen)")
         }
-        ///Calls the contract's `readDir` (0xc87aafac) function
-        pub fn read_dir_2(
+        ///Calls the contract's `envInt` (0x347968b0) function
+        pub fn env_int_1(
             &self,
             p0: ::std::string::String,
-            p1: u64,
-            p2: bool,
+            p1: ::std::string::String,
         ) -> ::corebc_contract::builders::ContractCall<
             M,
-            ::std::vec::Vec<
-                (::std::string::String, ::std::string::String, u64, bool, bool),
-            >,
+            ::std::vec::Vec<::corebc_core::types::I256>,
         > {
             self.0
-                .method_hash([200, 122, 175, 172], (p0, p1, p2))
-                .expect("method not found (this should never happen)")
-        }
-        ///Calls the contract's `readFile` (0x875a759d) function
-        pub fn read_file(
-            &self,
-            p0: ::std::string::String,
-        ) -> ::corebc_contract::builders::ContractCall<M, ::std::string::String> {
-            self.0
-                .method_hash([135, 90, 117, 157], p0)
+                .method_hash([52, 121, 104, 176], (p0, p1))
                 .expect("method not found (this should never happen)")
         }
-        ///Calls the contract's `readFileBinary` (0x06623015) function
-        pub fn read_file_binary(
+        ///Calls the contract's `envOr` (0x8efd04a9) function
+        pub fn env_or_0(
             &self,
             p0: ::std::string::String,
-        ) -> ::corebc_contract::builders::ContractCall<M, ::corebc_core::types::Bytes> {
+            p1: bool,
+        ) -> ::corebc_contract::builders::ContractCall<M, bool> {
             self.0
-                .method_hash([6, 98, 48, 21], p0)
+                .method_hash([142, 253, 4, 169], (p0, p1))
                 .expect("method not found (this should never happen)")
         }
-        ///Calls the contract's `readLine` (0xadd52189) function
-        pub fn read_line(
+        ///Calls the contract's `envOr` (0x1c6db43b) function
+        pub fn env_or_1(
             &self,
             p0: ::std::string::String,
-        ) -> ::corebc_contract::builders::ContractCall<M, ::std::string::String> {
+            p1: ::corebc_core::types::U256,
+        ) -> ::corebc_contract::builders::ContractCall<M, ::corebc_core::types::U256> {
             self.0
-                .method_hash([173, 213, 33, 137], p0)
+                .method_hash([28, 109, 180, 59], (p0, p1))
                 .expect("method not found (this should never happen)")
         }
-        ///Calls the contract's `readLink` (0xbb3ef03a) function
-        pub fn read_link(
+        ///Calls the contract's `envOr` (0x35711e3f) function
+        pub fn env_or_2(
             &self,
             p0: ::std::string::String,
-        ) -> ::corebc_contract::builders::ContractCall<M, ::std::string::String> {
-            self.0
-                .method_hash([187, 62, 240, 58], p0)
-                .expect("method not found (this should never happen)")
-        }
-        ///Calls the contract's `record` (0x620bef66) function
-        pub fn record(&self) -> ::corebc_contract::builders::ContractCall<M, ()> {
-            self.0
-                .method_hash([98, 11, 239, 102], ())
-                .expect("method not found (this should never happen)")
-        }
-        ///Calls the contract's `recordLogs` (0x9abda091) function
-        pub fn record_logs(&self) -> ::corebc_contract::builders::ContractCall<M, ()> {
+            p1: ::corebc_core::types::I256,
+        ) -> ::corebc_contract::builders::ContractCall<M, ::corebc_core::types::I256> {
             self.0
-                .method_hash([154, 189, 160, 145], ())
+                .method_hash([53, 113, 30, 63], (p0, p1))
                 .expect("method not found (this should never happen)")
         }
-        ///Calls the contract's `rememberKey` (0x40eac48c) function
-        pub fn remember_key(
+        ///Calls the contract's `envOr` (0x18745f8c) function
+        pub fn env_or_3(
             &self,
             p0: ::std::string::String,
+            p1: ::corebc_core::types::Address,
         ) -> ::corebc_contract::builders::ContractCall<
             M,
             ::corebc_core::types::Address,
         > {
             self.0
-                .method_hash([64, 234, 196, 140], p0)
+                .method_hash([24, 116, 95, 140], (p0, p1))
                 .expect("method not found (this should never happen)")
         }
-        ///Calls the contract's `removeDir` (0x45febd7d) function
-        pub fn remove_dir(
+        ///Calls the contract's `envOr` (0xcb48937a) function
+        pub fn env_or_4(
             &self,
             p0: ::std::string::String,
-            p1: bool,
-        ) -> ::corebc_contract::builders::ContractCall<M, ()> {
+            p1: [u8; 32],
+        ) -> ::corebc_contract::builders::ContractCall<M, [u8; 32]> {
             self.0
-                .method_hash([69, 254, 189, 125], (p0, p1))
+                .method_hash([203, 72, 147, 122], (p0, p1))
                 .expect("method not found (this should never happen)")
         }
-        ///Calls the contract's `removeFile` (0x32d0a050) function
-        pub fn remove_file(
+        ///Calls the contract's `envOr` (0x42d1d0ef) function
+        pub fn env_or_5(
             &self,
             p0: ::std::string::String,
-        ) -> ::corebc_contract::builders::ContractCall<M, ()> {
+            p1: ::std::string::String,
+        ) -> ::corebc_contract::builders::ContractCall<M, ::std::string::String> {
             self.0
-                .method_hash([50, 208, 160, 80], p0)
+                .method_hash([66, 209, 208, 239], (p0, p1))
                 .expect("method not found (this should never happen)")
         }
-        ///Calls the contract's `resetNonce` (0x3a1289c1) function
-        pub fn reset_nonce(
+        ///Calls the contract's `envOr` (0x2dd17959) function
+        pub fn env_or_6(
             &self,
-            p0: ::corebc_core::types::Address,
-        ) -> ::corebc_contract::builders::ContractCall<M, ()> {
+            p0: ::std::string::String,
+            p1: ::corebc_core::types::Bytes,
+        ) -> ::corebc_contract::builders::ContractCall<M, ::corebc_core::types::Bytes> {
             self.0
-                .method_hash([58, 18, 137, 193], p0)
+                .method_hash([45, 209, 121, 89], (p0, p1))
                 .expect("method not found (this should never happen)")
         }
-        ///Calls the contract's `resumeGasMetering` (0x626bfc65) function
-        pub fn resume_gas_metering(
+        ///Calls the contract's `envOr` (0x9430ce1e) function
+        pub fn env_or_7(
             &self,
-        ) -> ::corebc_contract::builders::ContractCall<M, ()> {
+            p0: ::std::string::String,
+            p1: ::std::string::String,
+            p2: ::std::vec::Vec<bool>,
+        ) -> ::corebc_contract::builders::ContractCall<M, ::std::vec::Vec<bool>> {
             self.0
-                .method_hash([98, 107, 252, 101], ())
+                .method_hash([148, 48, 206, 30], (p0, p1, p2))
                 .expect("method not found (this should never happen)")
         }
-        ///Calls the contract's `revertTo` (0x255a8c36) function
-        pub fn revert_to(
+        ///Calls the contract's `envOr` (0xbf6e43ef) function
+        pub fn env_or_8(
             &self,
-            p0: ::corebc_core::types::U256,
-        ) -> ::corebc_contract::builders::ContractCall<M, bool> {
+            p0: ::std::string::String,
+            p1: ::std::string::String,
+            p2: ::std::vec::Vec<::corebc_core::types::U256>,
+        ) -> ::corebc_contract::builders::ContractCall<
+            M,
+            ::std::vec::Vec<::corebc_core::types::U256>,
+        > {
             self.0
-                .method_hash([37, 90, 140, 54], p0)
+                .method_hash([191, 110, 67, 239], (p0, p1, p2))
                 .expect("method not found (this should never happen)")
         }
-        ///Calls the contract's `revokePersistent` (0x135c6569) function
-        pub fn revoke_persistent_0(
+        ///Calls the contract's `envOr` (0x60a8eb5e) function
+        pub fn env_or_9(
             &self,
-            p0: ::corebc_core::types::Address,
-        ) -> ::corebc_contract::builders::ContractCall<M, ()> {
+            p0: ::std::string::String,
+            p1: ::std::string::String,
+            p2: ::std::vec::Vec<::corebc_core::types::I256>,
+        ) -> ::corebc_contract::builders::ContractCall<
+            M,
+            ::std::vec::Vec<::corebc_core::types::I256>,
+        > {
             self.0
-                .method_hash([19, 92, 101, 105], p0)
+                .method_hash([96, 168, 235, 94], (p0, p1, p2))
                 .expect("method not found (this should never happen)")
         }
-        ///Calls the contract's `revokePersistent` (0x9bf340e8) function
-        pub fn revoke_persistent_1(
+        ///Calls the contract's `envOr` (0x0dbd12fc) function
+        pub fn env_or_10(
             &self,
-            p0: ::std::vec::Vec<::corebc_core::types::Address>,
-        ) -> ::corebc_contract::builders::ContractCall<M, ()> {
+            p0: ::std::string::String,
+            p1: ::std::string::String,
+            p2: ::std::vec::Vec<::corebc_core::types::Address>,
+        ) -> ::corebc_contract::builders::ContractCall<
+            M,
+            ::std::vec::Vec<::corebc_core::types::Address>,
+        > {
             self.0
-                .method_hash([155, 243, 64, 232], p0)
+                .method_hash([13, 189, 18, 252], (p0, p1, p2))
                 .expect("method not found (this should never happen)")
         }
-        ///Calls the contract's `roll` (0xe8fcf06b) function
-        pub fn roll(
+        ///Calls the contract's `envOr` (0x864e3a3c) function
+        pub fn env_or_11(
             &self,
-            p0: ::corebc_core::types::U256,
-        ) -> ::corebc_contract::builders::ContractCall<M, ()> {
+            p0: ::std::string::String,
+            p1: ::std::string::String,
+            p2: ::std::vec::Vec<[u8; 32]>,
+        ) -> ::corebc_contract::builders::ContractCall<M, ::std::vec::Vec<[u8; 32]>> {
             self.0
-                .method_hash([232, 252, 240, 107], p0)
+                .method_hash([134, 78, 58, 60], (p0, p1, p2))
                 .expect("method not found (this should never happen)")
         }
-        ///Calls the contract's `rollFork` (0xe3666077) function
-        pub fn roll_fork_0(
+        ///Calls the contract's `envOr` (0x9eb2a8c4) function
+        pub fn env_or_12(
             &self,
-            p0: ::corebc_core::types::U256,
-        ) -> ::corebc_contract::builders::ContractCall<M, ()> {
+            p0: ::std::string::String,
+            p1: ::std::string::String,
+            p2: ::std::vec::Vec<::std::string::String>,
+        ) -> ::corebc_contract::builders::ContractCall<
+            M,
+            ::std::vec::Vec<::std::string::String>,
+        > {
             self.0
-                .method_hash([227, 102, 96, 119], p0)
+                .method_hash([158, 178, 168, 196], (p0, p1, p2))
                 .expect("method not found (this should never happen)")
         }
-        ///Calls the contract's `rollFork` (0x0d538c69) function
-        pub fn roll_fork_1(
+        ///Calls the contract's `envOr` (0x206f7cdd) function
+        pub fn env_or_13(
             &self,
-            p0: [u8; 32],
-        ) -> ::corebc_contract::builders::ContractCall<M, ()> {
+            p0: ::std::string::String,
+            p1: ::std::string::String,
+            p2: ::std::vec::Vec<::corebc_core::types::Bytes>,
+        ) -> ::corebc_contract::builders::ContractCall<
+            M,
+            ::std::vec::Vec<::corebc_core::types::Bytes>,
+        > {
             self.0
-                .method_hash([13, 83, 140, 105], p0)
+                .method_hash([32, 111, 124, 221], (p0, p1, p2))
                 .expect("method not found (this should never happen)")
         }
-        ///Calls the contract's `rollFork` (0x439cddd8) function
-        pub fn roll_fork_2(
+        ///Calls the contract's `envString` (0xe613c3f6) function
+        pub fn env_string_0(
             &self,
-            p0: ::corebc_core::types::U256,
-            p1: ::corebc_core::types::U256,
-        ) -> ::corebc_contract::builders::ContractCall<M, ()> {
+            p0: ::std::string::String,
+        ) -> ::corebc_contract::builders::ContractCall<M, ::std::string::String> {
             self.0
-                .method_hash([67, 156, 221, 216], (p0, p1))
+                .method_hash([230, 19, 195, 246], p0)
                 .expect("method not found (this should never happen)")
         }
-        ///Calls the contract's `rollFork` (0x74340412) function
-        pub fn roll_fork_3(
+        ///Calls the contract's `envString` (0x535a86f8) function
+        pub fn env_string_1(
             &self,
-            p0: ::corebc_core::types::U256,
-            p1: [u8; 32],
-        ) -> ::corebc_contract::builders::ContractCall<M, ()> {
+            p0: ::std::string::String,
+            p1: ::std::string::String,
+        ) -> ::corebc_contract::builders::ContractCall<
+            M,
+            ::std::vec::Vec<::std::string::String>,
+        > {
             self.0
-                .method_hash([116, 52, 4, 18], (p0, p1))
+                .method_hash([83, 90, 134, 248], (p0, p1))
                 .expect("method not found (this should never happen)")
         }
-        ///Calls the contract's `rpcUrl` (0x931ea701) function
-        pub fn rpc_url(
+        ///Calls the contract's `envUint` (0x22b1fcf4) function
+        pub fn env_uint_0(
             &self,
             p0: ::std::string::String,
-        ) -> ::corebc_contract::builders::ContractCall<M, ::std::string::String> {
+        ) -> ::corebc_contract::builders::ContractCall<M, ::corebc_core::types::U256> {
             self.0
-                .method_hash([147, 30, 167, 1], p0)
+                .method_hash([34, 177, 252, 244], p0)
                 .expect("method not found (this should never happen)")
         }
-        ///Calls the contract's `rpcUrlStructs` (0x725410b7) function
-        pub fn rpc_url_structs(
+        ///Calls the contract's `envUint` (0x09437722) function
+        pub fn env_uint_1(
             &self,
+            p0: ::std::string::String,
+            p1: ::std::string::String,
         ) -> ::corebc_contract::builders::ContractCall<
             M,
-            ::std::vec::Vec<(::std::string::String, ::std::string::String)>,
+            ::std::vec::Vec<::corebc_core::types::U256>,
         > {
             self.0
-                .method_hash([114, 84, 16, 183], ())
+                .method_hash([9, 67, 119, 34], (p0, p1))
                 .expect("method not found (this should never happen)")
         }
-        ///Calls the contract's `rpcUrls` (0x266095bf) function
-        pub fn rpc_urls(
+        ///Calls the contract's `etch` (0x61106001) function
+        pub fn etch(
             &self,
-        ) -> ::corebc_contract::builders::ContractCall<
-            M,
-            ::std::vec::Vec<[::std::string::String; 2]>,
-        > {
+            p0: ::corebc_core::types::Address,
+            p1: ::corebc_core::types::Bytes,
+        ) -> ::corebc_contract::builders::ContractCall<M, ()> {
             self.0
-                .method_hash([38, 96, 149, 191], ())
+                .method_hash([97, 16, 96, 1], (p0, p1))
                 .expect("method not found (this should never happen)")
         }
-        ///Calls the contract's `selectFork` (0x4669f876) function
-        pub fn select_fork(
+        ///Calls the contract's `expectCall` (0xc244877f) function
+        pub fn expect_call_0(
             &self,
-            p0: ::corebc_core::types::U256,
+            p0: ::corebc_core::types::Address,
+            p1: ::corebc_core::types::Bytes,
         ) -> ::corebc_contract::builders::ContractCall<M, ()> {
             self.0
-                .method_hash([70, 105, 248, 118], p0)
+                .method_hash([194, 68, 135, 127], (p0, p1))
                 .expect("method not found (this should never happen)")
         }
-        ///Calls the contract's `serializeAddress` (0x1b9dc788) function
-        pub fn serialize_address_0(
+        ///Calls the contract's `expectCall` (0x35ce3631) function
+        pub fn expect_call_1(
             &self,
-            p0: ::std::string::String,
-            p1: ::std::string::String,
-            p2: ::corebc_core::types::Address,
-        ) -> ::corebc_contract::builders::ContractCall<M, ::std::string::String> {
+            p0: ::corebc_core::types::Address,
+            p1: ::corebc_core::types::Bytes,
+            p2: u64,
+        ) -> ::corebc_contract::builders::ContractCall<M, ()> {
             self.0
-                .method_hash([27, 157, 199, 136], (p0, p1, p2))
+                .method_hash([53, 206, 54, 49], (p0, p1, p2))
                 .expect("method not found (this should never happen)")
         }
-        ///Calls the contract's `serializeAddress` (0x9ef1ff0b) function
-        pub fn serialize_address_1(
+        ///Calls the contract's `expectCall` (0x5a1f47d6) function
+        pub fn expect_call_2(
             &self,
-            p0: ::std::string::String,
-            p1: ::std::string::String,
-            p2: ::std::vec::Vec<::corebc_core::types::Address>,
-        ) -> ::corebc_contract::builders::ContractCall<M, ::std::string::String> {
+            p0: ::corebc_core::types::Address,
+            p1: ::corebc_core::types::U256,
+            p2: ::corebc_core::types::Bytes,
+        ) -> ::corebc_contract::builders::ContractCall<M, ()> {
             self.0
-                .method_hash([158, 241, 255, 11], (p0, p1, p2))
+                .method_hash([90, 31, 71, 214], (p0, p1, p2))
                 .expect("method not found (this should never happen)")
         }
-        ///Calls the contract's `serializeBool` (0x06c97128) function
-        pub fn serialize_bool_0(
+        ///Calls the contract's `expectCall` (0xde6e6e54) function
+        pub fn expect_call_3(
             &self,
-            p0: ::std::string::String,
-            p1: ::std::string::String,
-            p2: bool,
-        ) -> ::corebc_contract::builders::ContractCall<M, ::std::string::String> {
+            p0: ::corebc_core::types::Address,
+            p1: ::corebc_core::types::U256,
+            p2: ::corebc_core::types::Bytes,
+            p3: u64,
+        ) -> ::corebc_contract::builders::ContractCall<M, ()> {
             self.0
-                .method_hash([6, 201, 113, 40], (p0, p1, p2))
+                .method_hash([222, 110, 110, 84], (p0, p1, p2, p3))
                 .expect("method not found (this should never happen)")
         }
-        ///Calls the contract's `serializeBool` (0xc40c3d45) function
-        pub fn serialize_bool_1(
+        ///Calls the contract's `expectCall` (0x536671ce) function
+        pub fn expect_call_4(
             &self,
-            p0: ::std::string::String,
-            p1: ::std::string::String,
-            p2: ::std::vec::Vec<bool>,
-        ) -> ::corebc_contract::builders::ContractCall<M, ::std::string::String> {
+            p0: ::corebc_core::types::Address,
+            p1: ::corebc_core::types::U256,
+            p2: u64,
+            p3: ::corebc_core::types::Bytes,
+        ) -> ::corebc_contract::builders::ContractCall<M, ()> {
             self.0
-                .method_hash([196, 12, 61, 69], (p0, p1, p2))
+                .method_hash([83, 102, 113, 206], (p0, p1, p2, p3))
                 .expect("method not found (this should never happen)")
         }
-        ///Calls the contract's `serializeBytes` (0xd2d2b28a) function
-        pub fn serialize_bytes_0(
+        ///Calls the contract's `expectCall` (0x66ca6367) function
+        pub fn expect_call_5(
             &self,
-            p0: ::std::string::String,
-            p1: ::std::string::String,
-            p2: ::corebc_core::types::Bytes,
-        ) -> ::corebc_contract::builders::ContractCall<M, ::std::string::String> {
+            p0: ::corebc_core::types::Address,
+            p1: ::corebc_core::types::U256,
+            p2: u64,
+            p3: ::corebc_core::types::Bytes,
+            p4: u64,
+        ) -> ::corebc_contract::builders::ContractCall<M, ()> {
             self.0
-                .method_hash([210, 210, 178, 138], (p0, p1, p2))
+                .method_hash([102, 202, 99, 103], (p0, p1, p2, p3, p4))
                 .expect("method not found (this should never happen)")
         }
-        ///Calls the contract's `serializeBytes` (0x0af30cb5) function
-        pub fn serialize_bytes_1(
+        ///Calls the contract's `expectCallMinGas` (0x782f8cdb) function
+        pub fn expect_call_min_gas_0(
             &self,
-            p0: ::std::string::String,
-            p1: ::std::string::String,
-            p2: ::std::vec::Vec<::corebc_core::types::Bytes>,
-        ) -> ::corebc_contract::builders::ContractCall<M, ::std::string::String> {
+            p0: ::corebc_core::types::Address,
+            p1: ::corebc_core::types::U256,
+            p2: u64,
+            p3: ::corebc_core::types::Bytes,
+        ) -> ::corebc_contract::builders::ContractCall<M, ()> {
             self.0
-                .method_hash([10, 243, 12, 181], (p0, p1, p2))
+                .method_hash([120, 47, 140, 219], (p0, p1, p2, p3))
                 .expect("method not found (this should never happen)")
         }
-        ///Calls the contract's `serializeBytes32` (0x271a3830) function
-        pub fn serialize_bytes_320(
+        ///Calls the contract's `expectCallMinGas` (0x7f5de23e) function
+        pub fn expect_call_min_gas_1(
             &self,
-            p0: ::std::string::String,
-            p1: ::std::string::String,
-            p2: [u8; 32],
-        ) -> ::corebc_contract::builders::ContractCall<M, ::std::string::String> {
+            p0: ::corebc_core::types::Address,
+            p1: ::corebc_core::types::U256,
+            p2: u64,
+            p3: ::corebc_core::types::Bytes,
+            p4: u64,
+        ) -> ::corebc_contract::builders::ContractCall<M, ()> {
             self.0
-                .method_hash([39, 26, 56, 48], (p0, p1, p2))
+                .method_hash([127, 93, 226, 62], (p0, p1, p2, p3, p4))
                 .expect("method not found (this should never happen)")
         }
-        ///Calls the contract's `serializeBytes32` (0x6324bb27) function
-        pub fn serialize_bytes_321(
-            &self,
-            p0: ::std::string::String,
-            p1: ::std::string::String,
-            p2: ::std::vec::Vec<[u8; 32]>,
-        ) -> ::corebc_contract::builders::ContractCall<M, ::std::string::String> {
+        ///Calls the contract's `expectEmit` (0x51bd56ad) function
+        pub fn expect_emit_0(&self) -> ::corebc_contract::builders::ContractCall<M, ()> {
             self.0
-                .method_hash([99, 36, 187, 39], (p0, p1, p2))
+                .method_hash([81, 189, 86, 173], ())
                 .expect("method not found (this should never happen)")
         }
-        ///Calls the contract's `serializeInt` (0x38b12609) function
-        pub fn serialize_int_0(
+        ///Calls the contract's `expectEmit` (0xbff0146e) function
+        pub fn expect_emit_1(
             &self,
-            p0: ::std::string::String,
-            p1: ::std::string::String,
-            p2: ::corebc_core::types::I256,
-        ) -> ::corebc_contract::builders::ContractCall<M, ::std::string::String> {
+            p0: ::corebc_core::types::Address,
+        ) -> ::corebc_contract::builders::ContractCall<M, ()> {
             self.0
-                .method_hash([56, 177, 38, 9], (p0, p1, p2))
+                .method_hash([191, 240, 20, 110], p0)
                 .expect("method not found (this should never happen)")
         }
-        ///Calls the contract's `serializeInt` (0xb2decdb3) function
-        pub fn serialize_int_1(
+        ///Calls the contract's `expectEmit` (0x242c1da4) function
+        pub fn expect_emit_2(
             &self,
-            p0: ::std::string::String,
-            p1: ::std::string::String,
-            p2: ::std::vec::Vec<::corebc_core::types::I256>,
-        ) -> ::corebc_contract::builders::ContractCall<M, ::std::string::String> {
+            p0: u64,
+        ) -> ::corebc_contract::builders::ContractCall<M, ()> {
             self.0
-                .method_hash([178, 222, 205, 179], (p0, p1, p2))
+                .method_hash([36, 44, 29, 164], p0)
                 .expect("method not found (this should never happen)")
         }
-        ///Calls the contract's `serializeString` (0x5c7ccb97) function
-        pub fn serialize_string_0(
+        ///Calls the contract's `expectEmit` (0x391e3757) function
+        pub fn expect_emit_3(
             &self,
-            p0: ::std::string::String,
-            p1: ::std::string::String,
-            p2: ::std::string::String,
-        ) -> ::corebc_contract::builders::ContractCall<M, ::std::string::String> {
+            p0: ::corebc_core::types::Address,
+            p1: u64,
+        ) -> ::corebc_contract::builders::ContractCall<M, ()> {
             self.0
-                .method_hash([92, 124, 203, 151], (p0, p1, p2))
+                .method_hash([57, 30, 55, 87], (p0, p1))
                 .expect("method not found (this should never happen)")
         }
-        ///Calls the contract's `serializeString` (0xa5df3cd6) function
-        pub fn serialize_string_1(
+        ///Calls the contract's `expectEmit` (0xe2e01dd4) function
+        pub fn expect_emit_4(
             &self,
-            p0: ::std::string::String,
-            p1: ::std::string::String,
-            p2: ::std::vec::Vec<::std::string::String>,
-        ) -> ::corebc_contract::builders::ContractCall<M, ::std::string::String> {
+            p0: bool,
+            p1: bool,
+            p2: bool,
+            p3: bool,
+        ) -> ::corebc_contract::builders::ContractCall<M, ()> {
             self.0
-                .method_hash([165, 223, 60, 214], (p0, p1, p2))
+                .method_hash([226, 224, 29, 212], (p0, p1, p2, p3))
                 .expect("method not found (this should never happen)")
         }
-        ///Calls the contract's `serializeUint` (0xa11b741a) function
-        pub fn serialize_uint_0(
+        ///Calls the contract's `expectEmit` (0xdb3d4fd8) function
+        pub fn expect_emit_5(
             &self,
-            p0: ::std::string::String,
-            p1: ::std::string::String,
-            p2: ::corebc_core::types::U256,
-        ) -> ::corebc_contract::builders::ContractCall<M, ::std::string::String> {
+            p0: bool,
+            p1: bool,
+            p2: bool,
+            p3: bool,
+            p4: ::corebc_core::types::Address,
+        ) -> ::corebc_contract::builders::ContractCall<M, ()> {
             self.0
-                .method_hash([161, 27, 116, 26], (p0, p1, p2))
+                .method_hash([219, 61, 79, 216], (p0, p1, p2, p3, p4))
                 .expect("method not found (this should never happen)")
         }
-        ///Calls the contract's `serializeUint` (0x6d152d8f) function
-        pub fn serialize_uint_1(
+        ///Calls the contract's `expectEmit` (0x81abc432) function
+        pub fn expect_emit_6(
             &self,
-            p0: ::std::string::String,
-            p1: ::std::string::String,
-            p2: ::std::vec::Vec<::corebc_core::types::U256>,
-        ) -> ::corebc_contract::builders::ContractCall<M, ::std::string::String> {
+            p0: bool,
+            p1: bool,
+            p2: bool,
+            p3: bool,
+            p4: u64,
+        ) -> ::corebc_contract::builders::ContractCall<M, ()> {
             self.0
-                .method_hash([109, 21, 45, 143], (p0, p1, p2))
+                .method_hash([129, 171, 196, 50], (p0, p1, p2, p3, p4))
                 .expect("method not found (this should never happen)")
         }
-        ///Calls the contract's `setEnv` (0xd7347a08) function
-        pub fn set_env(
+        ///Calls the contract's `expectEmit` (0x601727f2) function
+        pub fn expect_emit_7(
             &self,
-            p0: ::std::string::String,
-            p1: ::std::string::String,
+            p0: bool,
+            p1: bool,
+            p2: bool,
+            p3: bool,
+            p4: ::corebc_core::types::Address,
+            p5: u64,
         ) -> ::corebc_contract::builders::ContractCall<M, ()> {
             self.0
-                .method_hash([215, 52, 122, 8], (p0, p1))
+                .method_hash([96, 23, 39, 242], (p0, p1, p2, p3, p4, p5))
                 .expect("method not found (this should never happen)")
         }
-        ///Calls the contract's `setNonce` (0x38831543) function
-        pub fn set_nonce(
+        ///Calls the contract's `expectEmitAnonymous` (0x0acb608b) function
+        pub fn expect_emit_anonymous_0(
             &self,
-            p0: ::corebc_core::types::Address,
-            p1: u64,
         ) -> ::corebc_contract::builders::ContractCall<M, ()> {
             self.0
-                .method_hash([56, 131, 21, 67], (p0, p1))
+                .method_hash([10, 203, 96, 139], ())
                 .expect("method not found (this should never happen)")
         }
-        ///Calls the contract's `setNonceUnsafe` (0xdc395990) function
-        pub fn set_nonce_unsafe(
+        ///Calls the contract's `expectEmitAnonymous` (0xcee080f0) function
+        pub fn expect_emit_anonymous_1(
             &self,
             p0: ::corebc_core::types::Address,
-            p1: u64,
         ) -> ::corebc_contract::builders::ContractCall<M, ()> {
             self.0
-                .method_hash([220, 57, 89, 144], (p0, p1))
+                .method_hash([206, 224, 128, 240], p0)
                 .expect("method not found (this should never happen)")
         }
-        ///Calls the contract's `sign` (0xd0c67f5f) function
-        pub fn sign(
+        ///Calls the contract's `expectEmitAnonymous` (0x7e26cad2) function
+        pub fn expect_emit_anonymous_2(
             &self,
-            p0: ::std::string::String,
-            p1: [u8; 32],
-        ) -> ::corebc_contract::builders::ContractCall<M, ::corebc_core::types::Bytes> {
+            p0: bool,
+            p1: bool,
+            p2: bool,
+            p3: bool,
+            p4: bool,
+        ) -> ::corebc_contract::builders::ContractCall<M, ()> {
             self.0
-                .method_hash([208, 198, 127, 95], (p0, p1))
+                .method_hash([126, 38, 202, 210], (p0, p1, p2, p3, p4))
                 .expect("method not found (this should never happen)")
         }
-        ///Calls the contract's `skip` (0xe6365bdc) function
-        pub fn skip(
+        ///Calls the contract's `expectEmitAnonymous` (0x4c6b326e) function
+        pub fn expect_emit_anonymous_3(
             &self,
             p0: bool,
+            p1: bool,
+            p2: bool,
+            p3: bool,
+            p4: bool,
+            p5: ::corebc_core::types::Address,
         ) -> ::corebc_contract::builders::ContractCall<M, ()> {
             self.0
-                .method_hash([230, 54, 91, 220], p0)
+                .method_hash([76, 107, 50, 110], (p0, p1, p2, p3, p4, p5))
                 .expect("method not found (this should never happen)")
         }
-        ///Calls the contract's `snapshot` (0x843c63f8) function
-        pub fn snapshot(
+        ///Calls the contract's `expectPartialRevert` (0x19c48746) function
+        pub fn expect_partial_revert_0(
             &self,
-        ) -> ::corebc_contract::builders::ContractCall<M, ::corebc_core::types::U256> {
+            p0: [u8; 4],
+        ) -> ::corebc_contract::builders::ContractCall<M, ()> {
             self.0
-                .method_hash([132, 60, 99, 248], ())
+                .method_hash([25, 196, 135, 70], p0)
                 .expect("method not found (this should never happen)")
         }
-        ///Calls the contract's `startBroadcast` (0x423144a6) function
-        pub fn start_broadcast_0(
+        ///Calls the contract's `expectPartialRevert` (0x1d5067a2) function
+        pub fn expect_partial_revert_1(
             &self,
+            p0: [u8; 4],
+            p1: ::corebc_core::types::Address,
         ) -> ::corebc_contract::builders::ContractCall<M, ()> {
             self.0
-                .method_hash([66, 49, 68, 166], ())
+                .method_hash([29, 80, 103, 162], (p0, p1))
                 .expect("method not found (this should never happen)")
         }
-        ///Calls the contract's `startBroadcast` (0x14d2714b) function
-        pub fn start_broadcast_1(
+        ///Calls the contract's `expectRevert` (0x10403acb) function
+        pub fn expect_revert_0(
             &self,
-            p0: ::corebc_core::types::Address,
         ) -> ::corebc_contract::builders::ContractCall<M, ()> {
             self.0
-                .method_hash([20, 210, 113, 75], p0)
+                .method_hash([16, 64, 58, 203], ())
                 .expect("method not found (this should never happen)")
         }
-        ///Calls the contract's `startBroadcast` (0x09b04225) function
-        pub fn start_broadcast_2(
+        ///Calls the contract's `expectRevert` (0xe37ab4ed) function
+        pub fn expect_revert_1(
             &self,
-            p0: ::std::string::String,
+            p0: ::corebc_core::types::Bytes,
         ) -> ::corebc_contract::builders::ContractCall<M, ()> {
             self.0
-                .method_hash([9, 176, 66, 37], p0)
+                .method_hash([227, 122, 180, 237], p0)
                 .expect("method not found (this should never happen)")
         }
-        ///Calls the contract's `startPrank` (0xf1236596) function
-        pub fn start_prank_0(
+        ///Calls the contract's `expectRevert` (0x4e7614bc) function
+        pub fn expect_revert_2(
             &self,
-            p0: ::corebc_core::types::Address,
+            p0: [u8; 4],
         ) -> ::corebc_contract::builders::ContractCall<M, ()> {
             self.0
-                .method_hash([241, 35, 101, 150], p0)
+                .method_hash([78, 118, 20, 188], p0)
                 .expect("method not found (this should never happen)")
         }
-        ///Calls the contract's `startPrank` (0x6e46fc4f) function
-        pub fn start_prank_1(
+        ///Calls the contract's `expectRevert` (0xc018d382) function
+        pub fn expect_revert_3(
             &self,
-            p0: ::corebc_core::types::Address,
-            p1: ::corebc_core::types::Address,
+            p0: u64,
         ) -> ::corebc_contract::builders::ContractCall<M, ()> {
             self.0
-                .method_hash([110, 70, 252, 79], (p0, p1))
+                .method_hash([192, 24, 211, 130], p0)
                 .expect("method not found (this should never happen)")
         }
-        ///Calls the contract's `stopBroadcast` (0x741490df) function
-        pub fn stop_broadcast(
+        ///Calls the contract's `expectRevert` (0xe1d8c608) function
+        pub fn expect_revert_4(
             &self,
+            p0: ::corebc_core::types::Address,
         ) -> ::corebc_contract::builders::ContractCall<M, ()> {
             self.0
-                .method_hash([116, 20, 144, 223], ())
+                .method_hash([225, 216, 198, 8], p0)
                 .expect("method not found (this should never happen)")
         }
-        ///Calls the contract's `stopPrank` (0xad743cce) function
-        pub fn stop_prank(&self) -> ::corebc_contract::builders::ContractCall<M, ()> {
+        ///Calls the contract's `expectRevert` (0x04d16748) function
+        pub fn expect_revert_5(
+            &self,
+            p0: ::corebc_core::types::Bytes,
+            p1: u64,
+        ) -> ::corebc_contract::builders::ContractCall<M, ()> {
             self.0
-                .method_hash([173, 116, 60, 206], ())
+                .method_hash([4, 209, 103, 72], (p0, p1))
                 .expect("method not found (this should never happen)")
         }
-        ///Calls the contract's `store` (0x404ab67b) function
-        pub fn store(
+        ///Calls the contract's `expectRevert` (0x0bcd76bb) function
+        pub fn expect_revert_6(
             &self,
-            p0: ::corebc_core::types::Address,
-            p1: [u8; 32],
-            p2: [u8; 32],
+            p0: [u8; 4],
+            p1: u64,
         ) -> ::corebc_contract::builders::ContractCall<M, ()> {
             self.0
-                .method_hash([64, 74, 182, 123], (p0, p1, p2))
+                .method_hash([11, 205, 118, 187], (p0, p1))
                 .expect("method not found (this should never happen)")
         }
-        ///Calls the contract's `toString` (0xe1c5ec89) function
-        pub fn to_string_0(
+        ///Calls the contract's `expectRevert` (0x4ce8a185) function
+        pub fn expect_revert_7(
             &self,
             p0: ::corebc_core::types::Bytes,
+            p1: ::corebc_core::types::Address,
         ) -> ::corebc_contract::builders::ContractCall<M, ()> {
             self.0
-                .method_hash([225, 197, 236, 137], p0)
+                .method_hash([76, 232, 161, 133], (p0, p1))
                 .expect("method not found (this should never happen)")
         }
-        ///Calls the contract's `toString` (0xa79a7df1) function
-        pub fn to_string_1(
+        ///Calls the contract's `expectRevert` (0xa5bbcfd9) function
+        pub fn expect_revert_8(
             &self,
-            p0: ::corebc_core::types::Address,
+            p0: [u8; 4],
+            p1: ::corebc_core::types::Address,
         ) -> ::corebc_contract::builders::ContractCall<M, ()> {
             self.0
-                .method_hash([167, 154, 125, 241], p0)
+                .method_hash([165, 187, 207, 217], (p0, p1))
                 .expect("method not found (this should never happen)")
         }
-        ///Calls the contract's `toString` (0x26e977fd) function
-        pub fn to_string_2(
+        ///Calls the contract's `expectSafeMemory` (0x46bb5493) function
+        pub fn expect_safe_memory(
             &self,
-            p0: ::corebc_core::types::U256,
+            p0: u64,
+            p1: u64,
         ) -> ::corebc_contract::builders::ContractCall<M, ()> {
             self.0
-                .method_hash([38, 233, 119, 253], p0)
+                .method_hash([70, 187, 84, 147], (p0, p1))
                 .expect("method not found (this should never happen)")
         }
-        ///Calls the contract's `toString` (0xf5d91aec) function
-        pub fn to_string_3(
+        ///Calls the contract's `expectSafeMemoryCall` (0x9b61c661) function
+        pub fn expect_safe_memory_call(
             &self,
-            p0: ::corebc_core::types::I256,
+            p0: u64,
+            p1: u64,
         ) -> ::corebc_contract::builders::ContractCall<M, ()> {
             self.0
-                .method_hash([245, 217, 26, 236], p0)
+                .method_hash([155, 97, 198, 97], (p0, p1))
                 .expect("method not found (this should never happen)")
         }
-        ///Calls the contract's `toString` (0xe9dcfb8e) function
-        pub fn to_string_4(
+        ///Calls the contract's `fee` (0xaa32efc1) function
+        pub fn fee(
             &self,
-            p0: [u8; 32],
+            p0: ::corebc_core::types::U256,
         ) -> ::corebc_contract::builders::ContractCall<M, ()> {
             self.0
-                .method_hash([233, 220, 251, 142], p0)
+                .method_hash([170, 50, 239, 193], p0)
                 .expect("method not found (this should never happen)")
         }
-        ///Calls the contract's `toString` (0x359df6f8) function
-        pub fn to_string_5(
+        ///Calls the contract's `ffi` (0x64ba2b32) function
+        pub fn ffi(
             &self,
-            p0: bool,
-        ) -> ::corebc_contract::builders::ContractCall<M, ()> {
+            p0: ::std::vec::Vec<::std::string::String>,
+        ) -> ::corebc_contract::builders::ContractCall<M, ::corebc_core::types::Bytes> {
             self.0
-                .method_hash([53, 157, 246, 248], p0)
+                .method_hash([100, 186, 43, 50], p0)
                 .expect("method not found (this should never happen)")
         }
-        ///Calls the contract's `transact` (0x92cd3fce) function
-        pub fn transact_0(
+        ///Calls the contract's `fsMetadata` (0x895b0357) function
+        pub fn fs_metadata(
             &self,
-            p0: [u8; 32],
-        ) -> ::corebc_contract::builders::ContractCall<M, ()> {
+            p0: ::std::string::String,
+        ) -> ::corebc_contract::builders::ContractCall<
+            M,
+            (
+                bool,
+                bool,
+                ::corebc_core::types::U256,
+                bool,
+                ::corebc_core::types::U256,
+                ::corebc_core::types::U256,
+                ::corebc_core::types::U256,
+            ),
+        > {
             self.0
-                .method_hash([146, 205, 63, 206], p0)
+                .method_hash([137, 91, 3, 87], p0)
                 .expect("method not found (this should never happen)")
         }
-        ///Calls the contract's `transact` (0x04f2e146) function
-        pub fn transact_1(
+        ///Calls the contract's `getCode` (0xf4311698) function
+        pub fn get_code(
             &self,
-            p0: ::corebc_core::types::U256,
-            p1: [u8; 32],
+            p0: ::std::string::String,
         ) -> ::corebc_contract::builders::ContractCall<M, ()> {
             self.0
-                .method_hash([4, 242, 225, 70], (p0, p1))
+                .method_hash([244, 49, 22, 152], p0)
                 .expect("method not found (this should never happen)")
         }
-        ///Calls the contract's `txGasPrice` (0xc69545f8) function
-        pub fn tx_gas_price(
+        ///Calls the contract's `getDeployedCode` (0xe04cfba1) function
+        pub fn get_deployed_code(
             &self,
-            p0: ::corebc_core::types::U256,
+            p0: ::std::string::String,
         ) -> ::corebc_contract::builders::ContractCall<M, ()> {
             self.0
-                .method_hash([198, 149, 69, 248], p0)
+                .method_hash([224, 76, 251, 161], p0)
                 .expect("method not found (this should never happen)")
         }
-        ///Calls the contract's `warp` (0x037a7f89) function
-        pub fn warp(
+        ///Calls the contract's `getDeployment` (0xa2a85ccf) function
+        pub fn get_deployment_0(
             &self,
-            p0: ::corebc_core::types::U256,
-        ) -> ::corebc_contract::builders::ContractCall<M, ()> {
+            p0: ::std::string::String,
+        ) -> ::corebc_contract::builders::ContractCall<
+            M,
+            ::corebc_core::types::Address,
+        > {
             self.0
-                .method_hash([3, 122, 127, 137], p0)
+                .method_hash([162, 168, 92, 207], p0)
                 .expect("method not found (this should never happen)")
         }
-        ///Calls the contract's `writeFile` (0x6b52a3c0) function
-        pub fn write_file(
+        ///Calls the contract's `getDeployment` (0xe9bad3f6) function
+        pub fn get_deployment_1(
             &self,
             p0: ::std::string::String,
             p1: ::std::string::String,
-        ) -> ::corebc_contract::builders::ContractCall<M, ()> {
+        ) -> ::corebc_contract::builders::ContractCall<
+            M,
+            ::corebc_core::types::Address,
+        > {
             self.0
-                .method_hash([107, 82, 163, 192], (p0, p1))
+                .method_hash([233, 186, 211, 246], (p0, p1))
                 .expect("method not found (this should never happen)")
         }
-        ///Calls the contract's `writeFileBinary` (0x103030bc) function
-        pub fn write_file_binary(
+        ///Calls the contract's `getLabel` (0x56c8fc59) function
+        pub fn get_label(
             &self,
-            p0: ::std::string::String,
-            p1: ::corebc_core::types::Bytes,
+            p0: ::corebc_core::types::Address,
+        ) -> ::corebc_contract::builders::ContractCall<M, ::std::string::String> {
+            self.0
+                .method_hash([86, 200, 252, 89], p0)
+                .expect("method not found (this should never happen)")
+        }
+        ///Calls the contract's `getNonce` (0x7ee608d7) function
+        pub fn get_nonce(
+            &self,
+            p0: ::corebc_core::types::Address,
         ) -> ::corebc_contract::builders::ContractCall<M, ()> {
             self.0
-                .method_hash([16, 48, 48, 188], (p0, p1))
+                .method_hash([126, 230, 8, 215], p0)
                 .expect("method not found (this should never happen)")
         }
-        ///Calls the contract's `writeJson` (0xb5e0aa0c) function
-        pub fn write_json_0(
+        ///Calls the contract's `getRecordedLogs` (0x6aa9ac15) function
+        pub fn get_recorded_logs(
+            &self,
+        ) -> ::corebc_contract::builders::ContractCall<
+            M,
+            ::std::vec::Vec<(::std::vec::Vec<[u8; 32]>, ::corebc_core::types::Bytes)>,
+        > {
+            self.0
+                .method_hash([106, 169, 172, 21], ())
+                .expect("method not found (this should never happen)")
+        }
+        ///Calls the contract's `isPersistent` (0x27051a9d) function
+        pub fn is_persistent(
+            &self,
+            p0: ::corebc_core::types::Address,
+        ) -> ::corebc_contract::builders::ContractCall<M, bool> {
+            self.0
+                .method_hash([39, 5, 26, 157], p0)
+                .expect("method not found (this should never happen)")
+        }
+        ///Calls the contract's `keyExistsJson` (0x43139fe3) function
+        pub fn key_exists_json(
             &self,
             p0: ::std::string::String,
             p1: ::std::string::String,
-        ) -> ::corebc_contract::builders::ContractCall<M, ()> {
+        ) -> ::corebc_contract::builders::ContractCall<M, bool> {
             self.0
-                .method_hash([181, 224, 170, 12], (p0, p1))
+                .method_hash([67, 19, 159, 227], (p0, p1))
                 .expect("method not found (this should never happen)")
         }
-        ///Calls the contract's `writeJson` (0xa57e5e2d) function
-        pub fn write_json_1(
+        ///Calls the contract's `keyExistsToml` (0x3abbe80a) function
+        pub fn key_exists_toml(
             &self,
             p0: ::std::string::String,
             p1: ::std::string::String,
-            p2: ::std::string::String,
-        ) -> ::corebc_contract::builders::ContractCall<M, ()> {
+        ) -> ::corebc_contract::builders::ContractCall<M, bool> {
             self.0
-                .method_hash([165, 126, 94, 45], (p0, p1, p2))
+                .method_hash([58, 187, 232, 10], (p0, p1))
                 .expect("method not found (this should never happen)")
         }
-        ///Calls the contract's `writeLine` (0x592b1bb6) function
-        pub fn write_line(
+        ///Calls the contract's `label` (0xb167f0ca) function
+        pub fn label(
             &self,
-            p0: ::std::string::String,
+            p0: ::corebc_core::types::Address,
             p1: ::std::string::String,
         ) -> ::corebc_contract::builders::ContractCall<M, ()> {
             self.0
-                .method_hash([89, 43, 27, 182], (p0, p1))
+                .method_hash([177, 103, 240, 202], (p0, p1))
                 .expect("method not found (this should never happen)")
         }
-    }
-    impl<M: ::corebc_providers::Middleware> From<::corebc_contract::Contract<M>>
-    for HEVM<M> {
-        fn from(contract: ::corebc_contract::Contract<M>) -> Self {
-            Self::new(contract.address(), contract.client())
+        ///Calls the contract's `load` (0xfac88623) function
+        pub fn load(
+            &self,
+            p0: ::corebc_core::types::Address,
+            p1: [u8; 32],
+        ) -> ::corebc_contract::builders::ContractCall<M, [u8; 32]> {
+            self.0
+                .method_hash([250, 200, 134, 35], (p0, p1))
+                .expect("method not found (this should never happen)")
         }
-    }
-    ///Container type for all input parameters for the `accesses` function with signature `accesses(address)` and selector `0x6e606627`
+        ///Calls the contract's `makePersistent` (0x9ff5ebb8) function
+        pub fn make_persistent_0(
+            &self,
+            p0: ::corebc_core::types::Address,
+        ) -> ::corebc_contract::builders::ContractCall<M, ()> {
+            self.0
+                .method_hash([159, 245, 235, 184], p0)
+                .expect("method not found (this should never happen)")
+        }
+        ///Calls the contract's `makePersistent` (0x05bd09f0) function
+        pub fn make_persistent_2(
+            &self,
+            p0: ::corebc_core::types::Address,
+            p1: ::corebc_core::types::Address,
+        ) -> ::corebc_contract::builders::ContractCall<M, ()> {
+            self.0
+                .method_hash([5, 189, 9, 240], (p0, p1))
+                .expect("method not found (this should never happen)")
+        }
+        ///Calls the contract's `makePersistent` (0xd0f24610) function
+        pub fn make_persistent_3(
+            &self,
+            p0: ::corebc_core::types::Address,
+            p1: ::corebc_core::types::Address,
+            p2: ::corebc_core::types::Address,
+        ) -> ::corebc_contract::builders::ContractCall<M, ()> {
+            self.0
+                .method_hash([208, 242, 70, 16], (p0, p1, p2))
+                .expect("method not found (this should never happen)")
+        }
+        ///Calls the contract's `makePersistent` (0xa6180f41) function
+        pub fn make_persistent_1(
+            &self,
+            p0: ::std::vec::Vec<::corebc_core::types::Address>,
+        ) -> ::corebc_contract::builders::ContractCall<M, ()> {
+            self.0
+                .method_hash([166, 24, 15, 65], p0)
+                .expect("method not found (this should never happen)")
+        }
+        ///Calls the contract's `mockCall` (0x4b25c120) function
+        pub fn mock_call_0(
+            &self,
+            p0: ::corebc_core::types::Address,
+            p1: ::corebc_core::types::Bytes,
+            p2: ::corebc_core::types::Bytes,
+        ) -> ::corebc_contract::builders::ContractCall<M, ()> {
+            self.0
+                .method_hash([75, 37, 193, 32], (p0, p1, p2))
+                .expect("method not found (this should never happen)")
+        }
+        ///Calls the contract's `mockCall` (0xe05abf37) function
+        pub fn mock_call_1(
+            &self,
+            p0: ::corebc_core::types::Address,
+            p1: ::corebc_core::types::U256,
+            p2: ::corebc_core::types::Bytes,
+            p3: ::corebc_core::types::Bytes,
+        ) -> ::corebc_contract::builders::ContractCall<M, ()> {
+            self.0
+                .method_hash([224, 90, 191, 55], (p0, p1, p2, p3))
+                .expect("method not found (this should never happen)")
+        }
+        ///Calls the contract's `mockCallRevert` (0xe9bb00fb) function
+        pub fn mock_call_revert_0(
+            &self,
+            p0: ::corebc_core::types::Address,
+            p1: ::corebc_core::types::Bytes,
+            p2: ::corebc_core::types::Bytes,
+        ) -> ::corebc_contract::builders::ContractCall<M, ()> {
+            self.0
+                .method_hash([233, 187, 0, 251], (p0, p1, p2))
+                .expect("method not found (this should never happen)")
+        }
+        ///Calls the contract's `mockCallRevert` (0xd216223d) function
+        pub fn mock_call_revert_1(
+            &self,
+            p0: ::corebc_core::types::Address,
+            p1: ::corebc_core::types::U256,
+            p2: ::corebc_core::types::Bytes,
+            p3: ::corebc_core::types::Bytes,
+        ) -> ::corebc_contract::builders::ContractCall<M, ()> {
+            self.0
+                .method_hash([210, 22, 34, 61], (p0, p1, p2, p3))
+                .expect("method not found (this should never happen)")
+        }
+        ///Calls the contract's `openFile` (0x87badc3e) function
+        pub fn open_file(
+            &self,
+            p0: ::std::string::String,
+        ) -> ::corebc_contract::builders::ContractCall<M, ()> {
+            self.0
+                .method_hash([135, 186, 220, 62], p0)
+                .expect("method not found (this should never happen)")
+        }
+        ///Calls the contract's `parseAddress` (0xbf03d4f6) function
+        pub fn parse_address(
+            &self,
+            p0: ::std::string::String,
+        ) -> ::corebc_contract::builders::ContractCall<
+            M,
+            ::corebc_core::types::Address,
+        > {
+            self.0
+                .method_hash([191, 3, 212, 246], p0)
+                .expect("method not found (this should never happen)")
+        }
+        ///Calls the contract's `parseBool` (0xc711f34c) function
+        pub fn parse_bool(
+            &self,
+            p0: ::std::string::String,
+        ) -> ::corebc_contract::builders::ContractCall<M, bool> {
+            self.0
+                .method_hash([199, 17, 243, 76], p0)
+                .expect("method not found (this should never happen)")
+        }
+        ///Calls the contract's `parseBytes` (0x3dc7ecf1) function
+        pub fn parse_bytes(
+            &self,
+            p0: ::std::string::String,
+        ) -> ::corebc_contract::builders::ContractCall<M, ::corebc_core::types::Bytes> {
+            self.0
+                .method_hash([61, 199, 236, 241], p0)
+                .expect("method not found (this should never happen)")
+        }
+        ///Calls the contract's `parseBytes32` (0x6ea2d6ed) function
+        pub fn parse_bytes_32(
+            &self,
+            p0: ::std::string::String,
+        ) -> ::corebc_contract::builders::ContractCall<M, [u8; 32]> {
+            self.0
+                .method_hash([110, 162, 214, 237], p0)
+                .expect("method not found (this should never happen)")
+        }
+        ///Calls the contract's `parseInt` (0xcacaea8a) function
+        pub fn parse_int(
+            &self,
+            p0: ::std::string::String,
+        ) -> ::corebc_contract::builders::ContractCall<M, ::corebc_core::types::I256> {
+            self.0
+                .method_hash([202, 202, 234, 138], p0)
+                .expect("method not found (this should never happen)")
+        }
+        ///Calls the contract's `parseJson` (0x1b14d0fa) function
+        pub fn parse_json_0(
+            &self,
+            p0: ::std::string::String,
+        ) -> ::corebc_contract::builders::ContractCall<M, ::corebc_core::types::Bytes> {
+            self.0
+                .method_hash([27, 20, 208, 250], p0)
+                .expect("method not found (this should never happen)")
+        }
+        ///Calls the contract's `parseJson` (0x9ed12636) function
+        pub fn parse_json_1(
+            &self,
+            p0: ::std::string::String,
+            p1: ::std::string::String,
+        ) -> ::corebc_contract::builders::ContractCall<M, ::corebc_core::types::Bytes> {
+            self.0
+                .method_hash([158, 209, 38, 54], (p0, p1))
+                .expect("method not found (this should never happen)")
+        }
+        ///Calls the contract's `parseJsonAddress` (0x6d037439) function
+        pub fn parse_json_address(
+            &self,
+            p0: ::std::string::String,
+            p1: ::std::string::String,
+        ) -> ::corebc_contract::builders::ContractCall<
+            M,
+            ::corebc_core::types::Address,
+        > {
+            self.0
+                .method_hash([109, 3, 116, 57], (p0, p1))
+                .expect("method not found (this should never happen)")
+        }
+        ///Calls the contract's `parseJsonAddressArray` (0x73ae0e2b) function
+        pub fn parse_json_address_array(
+            &self,
+            p0: ::std::string::String,
+            p1: ::std::string::String,
+        ) -> ::corebc_contract::builders::ContractCall<
+            M,
+            ::std::vec::Vec<::corebc_core::types::Address>,
+        > {
+            self.0
+                .method_hash([115, 174, 14, 43], (p0, p1))
+                .expect("method not found (this should never happen)")
+        }
+        ///Calls the contract's `parseJsonBool` (0x1ddc1e05) function
+        pub fn parse_json_bool(
+            &self,
+            p0: ::std::string::String,
+            p1: ::std::string::String,
+        ) -> ::corebc_contract::builders::ContractCall<M, bool> {
+            self.0
+                .method_hash([29, 220, 30, 5], (p0, p1))
+                .expect("method not found (this should never happen)")
+        }
+        ///Calls the contract's `parseJsonBoolArray` (0x1b7a2c28) function
+        pub fn parse_json_bool_array(
+            &self,
+            p0: ::std::string::String,
+            p1: ::std::string::String,
+        ) -> ::corebc_contract::builders::ContractCall<M, ::std::vec::Vec<bool>> {
+            self.0
+                .method_hash([27, 122, 44, 40], (p0, p1))
+                .expect("method not found (this should never happen)")
+        }
+        ///Calls the contract's `parseJsonBytes` (0x4ae7c4b9) function
+        pub fn parse_json_bytes(
+            &self,
+            p0: ::std::string::String,
+            p1: ::std::string::String,
+        ) -> ::corebc_contract::builders::ContractCall<M, ::corebc_core::types::Bytes> {
+            self.0
+                .method_hash([74, 231, 196, 185], (p0, p1))
+                .expect("method not found (this should never happen)")
+        }
+        ///Calls the contract's `parseJsonBytes32` (0x2088d639) function
+        pub fn parse_json_bytes_32(
+            &self,
+            p0: ::std::string::String,
+            p1: ::std::string::String,
+        ) -> ::corebc_contract::builders::ContractCall<M, [u8; 32]> {
+            self.0
+                .method_hash([32, 136, 214, 57], (p0, p1))
+                .expect("method not found (this should never happen)")
+        }
+        ///Calls the contract's `parseJsonBytes32Array` (0x6ed6bf6d) function
+        pub fn parse_json_bytes_32_array(
+            &self,
+            p0: ::std::string::String,
+            p1: ::std::string::String,
+        ) -> ::corebc_contract::builders::ContractCall<M, ::std::vec::Vec<[u8; 32]>> {
+            self.0
+                .method_hash([110, 214, 191, 109], (p0, p1))
+                .expect("method not found (this should never happen)")
+        }
+        ///Calls the contract's `parseJsonBytesArray` (0xc7f2b390) function
+        pub fn parse_json_bytes_array(
+            &self,
+            p0: ::std::string::String,
+            p1: ::std::string::String,
+        ) -> ::corebc_contract::builders::ContractCall<
+            M,
+            ::std::vec::Vec<::corebc_core::types::Bytes>,
+        > {
+            self.0
+                .method_hash([199, 242, 179, 144], (p0, p1))
+                .expect("method not found (this should never happen)")
+        }
+        ///Calls the contract's `parseJsonInt` (0x7e3d173a) function
+        pub fn parse_json_int(
+            &self,
+            p0: ::std::string::String,
+            p1: ::std::string::String,
+        ) -> ::corebc_contract::builders::ContractCall<M, ::corebc_core::types::I256> {
+            self.0
+                .method_hash([126, 61, 23, 58], (p0, p1))
+                .expect("method not found (this should never happen)")
+        }
+        ///Calls the contract's `parseJsonIntArray` (0xc37c99a1) function
+        pub fn parse_json_int_array(
+            &self,
+            p0: ::std::string::String,
+            p1: ::std::string::String,
+        ) -> ::corebc_contract::builders::ContractCall<
+            M,
+            ::std::vec::Vec<::corebc_core::types::I256>,
+        > {
+            self.0
+                .method_hash([195, 124, 153, 161], (p0, p1))
+                .expect("method not found (this should never happen)")
+        }
+        ///Calls the contract's `parseJsonKeys` (0xb110ad24) function
+        pub fn parse_json_keys(
+            &self,
+            p0: ::std::string::String,
+            p1: ::std::string::String,
+        ) -> ::corebc_contract::builders::ContractCall<
+            M,
+            ::std::vec::Vec<::std::string::String>,
+        > {
+            self.0
+                .method_hash([177, 16, 173, 36], (p0, p1))
+                .expect("method not found (this should never happen)")
+        }
+        ///Calls the contract's `parseJsonString` (0x9d9b1273) function
+        pub fn parse_json_string(
+            &self,
+            p0: ::std::string::String,
+            p1: ::std::string::String,
+        ) -> ::corebc_contract::builders::ContractCall<M, ::std::string::String> {
+            self.0
+                .method_hash([157, 155, 18, 115], (p0, p1))
+                .expect("method not found (this should never happen)")
+        }
+        ///Calls the contract's `parseJsonStringArray` (0x6499a16c) function
+        pub fn parse_json_string_array(
+            &self,
+            p0: ::std::string::String,
+            p1: ::std::string::String,
+        ) -> ::corebc_contract::builders::ContractCall<
+            M,
+            ::std::vec::Vec<::std::string::String>,
+        > {
+            self.0
+                .method_hash([100, 153, 161, 108], (p0, p1))
+                .expect("method not found (this should never happen)")
+        }
+        ///Calls the contract's `parseJsonUint` (0x7aa5f1ff) function
+        pub fn parse_json_uint(
+            &self,
+            p0: ::std::string::String,
+            p1: ::std::string::String,
+        ) -> ::corebc_contract::builders::ContractCall<M, ::corebc_core::types::U256> {
+            self.0
+                .method_hash([122, 165, 241, 255], (p0, p1))
+                .expect("method not found (this should never happen)")
+        }
+        ///Calls the contract's `parseJsonUintArray` (0x3118994d) function
+        pub fn parse_json_uint_array(
+            &self,
+            p0: ::std::string::String,
+            p1: ::std::string::String,
+        ) -> ::corebc_contract::builders::ContractCall<
+            M,
+            ::std::vec::Vec<::corebc_core::types::U256>,
+        > {
+            self.0
+                .method_hash([49, 24, 153, 77], (p0, p1))
+                .expect("method not found (this should never happen)")
+        }
+        ///Calls the contract's `parseToml` (0x86ee4f75) function
+        pub fn parse_toml_0(
+            &self,
+            p0: ::std::string::String,
+        ) -> ::corebc_contract::builders::ContractCall<M, ::corebc_core::types::Bytes> {
+            self.0
+                .method_hash([134, 238, 79, 117], p0)
+                .expect("method not found (this should never happen)")
+        }
+        ///Calls the contract's `parseToml` (0xf62093d7) function
+        pub fn parse_toml_1(
+            &self,
+            p0: ::std::string::String,
+            p1: ::std::string::String,
+        ) -> ::corebc_contract::builders::ContractCall<M, ::corebc_core::types::Bytes> {
+            self.0
+                .method_hash([246, 32, 147, 215], (p0, p1))
+                .expect("method not found (this should never happen)")
+        }
+        ///Calls the contract's `parseTomlAddress` (0xb6e35291) function
+        pub fn parse_toml_address(
+            &self,
+            p0: ::std::string::String,
+            p1: ::std::string::String,
+        ) -> ::corebc_contract::builders::ContractCall<
+            M,
+            ::corebc_core::types::Address,
+        > {
+            self.0
+                .method_hash([182, 227, 82, 145], (p0, p1))
+                .expect("method not found (this should never happen)")
+        }
+        ///Calls the contract's `parseTomlAddressArray` (0x9d14a569) function
+        pub fn parse_toml_address_array(
+            &self,
+            p0: ::std::string::String,
+            p1: ::std::string::String,
+        ) -> ::corebc_contract::builders::ContractCall<
+            M,
+            ::std::vec::Vec<::corebc_core::types::Address>,
+        > {
+            self.0
+                .method_hash([157, 20, 165, 105], (p0, p1))
+                .expect("method not found (this should never happen)")
+        }
+        ///Calls the contract's `parseTomlBool` (0x66a6bf4d) function
+        pub fn parse_toml_bool(
+            &self,
+            p0: ::std::string::String,
+            p1: ::std::string::String,
+        ) -> ::corebc_contract::builders::ContractCall<M, bool> {
+            self.0
+                .method_hash([102, 166, 191, 77], (p0, p1))
+                .expect("method not found (this should never happen)")
+        }
+        ///Calls the contract's `parseTomlBoolArray` (0xc1556c81) function
+        pub fn parse_toml_bool_array(
+            &self,
+            p0: ::std::string::String,
+            p1: ::std::string::String,
+        ) -> ::corebc_contract::builders::ContractCall<M, ::std::vec::Vec<bool>> {
+            self.0
+                .method_hash([193, 85, 108, 129], (p0, p1))
+                .expect("method not found (this should never happen)")
+        }
+        ///Calls the contract's `parseTomlBytes` (0x4b072717) function
+        pub fn parse_toml_bytes(
+            &self,
+            p0: ::std::string::String,
+            p1: ::std::string::String,
+        ) -> ::corebc_contract::builders::ContractCall<M, ::corebc_core::types::Bytes> {
+            self.0
+                .method_hash([75, 7, 39, 23], (p0, p1))
+                .expect("method not found (this should never happen)")
+        }
+        ///Calls the contract's `parseTomlBytes32` (0x85c38481) function
+        pub fn parse_toml_bytes_32(
+            &self,
+            p0: ::std::string::String,
+            p1: ::std::string::String,
+        ) -> ::corebc_contract::builders::ContractCall<M, [u8; 32]> {
+            self.0
+                .method_hash([133, 195, 132, 129], (p0, p1))
+                .expect("method not found (this should never happen)")
+        }
+        ///Calls the contract's `parseTomlBytes32Array` (0x1bff6760) function
+        pub fn parse_toml_bytes_32_array(
+            &self,
+            p0: ::std::string::String,
+            p1: ::std::string::String,
+        ) -> ::corebc_contract::builders::ContractCall<M, ::std::vec::Vec<[u8; 32]>> {
+            self.0
+                .method_hash([27, 255, 103, 96], (p0, p1))
+                .expect("method not found (this should never happen)")
+        }
+        ///Calls the contract's `parseTomlBytesArray` (0xfcd16926) function
+        pub fn parse_toml_bytes_array(
+            &self,
+            p0: ::std::string::String,
+            p1: ::std::string::String,
+        ) -> ::corebc_contract::builders::ContractCall<
+            M,
+            ::std::vec::Vec<::corebc_core::types::Bytes>,
+        > {
+            self.0
+                .method_hash([252, 209, 105, 38], (p0, p1))
+                .expect("method not found (this should never happen)")
+        }
+        ///Calls the contract's `parseTomlInt` (0xf0788b93) function
+        pub fn parse_toml_int(
+            &self,
+            p0: ::std::string::String,
+            p1: ::std::string::String,
+        ) -> ::corebc_contract::builders::ContractCall<M, ::corebc_core::types::I256> {
+            self.0
+                .method_hash([240, 120, 139, 147], (p0, p1))
+                .expect("method not found (this should never happen)")
+        }
+        ///Calls the contract's `parseTomlIntArray` (0xd68057a4) function
+        pub fn parse_toml_int_array(
+            &self,
+            p0: ::std::string::String,
+            p1: ::std::string::String,
+        ) -> ::corebc_contract::builders::ContractCall<
+            M,
+            ::std::vec::Vec<::corebc_core::types::I256>,
+        > {
+            self.0
+                .method_hash([214, 128, 87, 164], (p0, p1))
+                .expect("method not found (this should never happen)")
+        }
+        ///Calls the contract's `parseTomlKeys` (0x4546f736) function
+        pub fn parse_toml_keys(
+            &self,
+            p0: ::std::string::String,
+            p1: ::std::string::String,
+        ) -> ::corebc_contract::builders::ContractCall<
+            M,
+            ::std::vec::Vec<::std::string::String>,
+        > {
+            self.0
+                .method_hash([69, 70, 247, 54], (p0, p1))
+                .expect("method not found (this should never happen)")
+        }
+        ///Calls the contract's `parseTomlString` (0x75027319) function
+        pub fn parse_toml_string(
+            &self,
+            p0: ::std::string::String,
+            p1: ::std::string::String,
+        ) -> ::corebc_contract::builders::ContractCall<M, ::std::string::String> {
+            self.0
+                .method_hash([117, 2, 115, 25], (p0, p1))
+                .expect("method not found (this should never happen)")
+        }
+        ///Calls the contract's `parseTomlStringArray` (0xcdc04e0c) function
+        pub fn parse_toml_string_array(
+            &self,
+            p0: ::std::string::String,
+            p1: ::std::string::String,
+        ) -> ::corebc_contract::builders::ContractCall<
+            M,
+            ::std::vec::Vec<::std::string::String>,
+        > {
+            self.0
+                .method_hash([205, 192, 78, 12], (p0, p1))
+                .expect("method not found (this should never happen)")
+        }
+        ///Calls the contract's `parseTomlUint` (0xa444e113) function
+        pub fn parse_toml_uint(
+            &self,
+            p0: ::std::string::String,
+            p1: ::std::string::String,
+        ) -> ::corebc_contract::builders::ContractCall<M, ::corebc_core::types::U256> {
+            self.0
+                .method_hash([164, 68, 225, 19], (p0, p1))
+                .expect("method not found (this should never happen)")
+        }
+        ///Calls the contract's `parseTomlUintArray` (0x93fa17f9) function
+        pub fn parse_toml_uint_array(
+            &self,
+            p0: ::std::string::String,
+            p1: ::std::string::String,
+        ) -> ::corebc_contract::builders::ContractCall<
+            M,
+            ::std::vec::Vec<::corebc_core::types::U256>,
+        > {
+            self.0
+                .method_hash([147, 250, 23, 249], (p0, p1))
+                .expect("method not found (this should never happen)")
+        }
+        ///Calls the contract's `parseUint` (0xd3fdc787) function
+        pub fn parse_uint(
+            &self,
+            p0: ::std::string::String,
+        ) -> ::corebc_contract::builders::ContractCall<M, ::corebc_core::types::U256> {
+            self.0
+                .method_hash([211, 253, 199, 135], p0)
+                .expect("method not found (this should never happen)")
+        }
+        ///Calls the contract's `pauseGasMetering` (0x48b8fe9f) function
+        pub fn pause_gas_metering(
+            &self,
+        ) -> ::corebc_contract::builders::ContractCall<M, ()> {
+            self.0
+                .method_hash([72, 184, 254, 159], ())
+                .expect("method not found (this should never happen)")
+        }
+        ///Calls the contract's `prank` (0x10d64053) function
+        pub fn prank_0(
+            &self,
+            p0: ::corebc_core::types::Address,
+        ) -> ::corebc_contract::builders::ContractCall<M, ()> {
+            self.0
+                .method_hash([16, 214, 64, 83], p0)
+                .expect("method not found (this should never happen)")
+        }
+        ///Calls the contract's `prank` (0x5c3c5387) function
+        pub fn prank_1(
+            &self,
+            p0: ::corebc_core::types::Address,
+            p1: ::corebc_core::types::Address,
+        ) -> ::corebc_contract::builders::ContractCall<M, ()> {
+            self.0
+                .method_hash([92, 60, 83, 135], (p0, p1))
+                .expect("method not found (this should never happen)")
+        }
+        ///Calls the contract's `projectRoot` (0x6e197bfb) function
+        pub fn project_root(
+            &self,
+        ) -> ::corebc_contract::builders::ContractCall<M, ::std::string::String> {
+            self.0
+                .method_hash([110, 25, 123, 251], ())
+                .expect("method not found (this should never happen)")
+        }
+        ///Calls the contract's `readCallers` (0xc89ced7f) function
+        pub fn read_callers(
+            &self,
+        ) -> ::corebc_contract::builders::ContractCall<
+            M,
+            (
+                ::corebc_core::types::U256,
+                ::corebc_core::types::Address,
+                ::corebc_core::types::Address,
+            ),
+        > {
+            self.0
+                .method_hash([200, 156, 237, 127], ())
+                .expect("method not found (this should never happen)")
+        }
+        ///Calls the contract's `readDir` (0xf108a04c) function
+        pub fn read_dir_0(
+            &self,
+            p0: ::std::string::String,
+        ) -> ::corebc_contract::builders::ContractCall<
+            M,
+            ::std::vec::Vec<
+                (::std::string::String, ::std::string::String, u64, bool, bool),
+            >,
+        > {
+            self.0
+                .method_hash([241, 8, 160, 76], p0)
+                .expect("method not found (this should never happen)")
+        }
+        ///Calls the contract's `readDir` (0xb2f4318c) function
+        pub fn read_dir_1(
+            &self,
+            p0: ::std::string::String,
+            p1: u64,
+        ) -> ::corebc_contract::builders::ContractCall<
+            M,
+            ::std::vec::Vec<
+                (::std::string::String, ::std::string::String, u64, bool, bool),
+            >,
+        > {
+            self.0
+                .method_hash([178, 244, 49, 140], (p0, p1))
+                .expect("method not found (this should never happen)")
+        }
+        ///Calls the contract's `readDir` (0xc87aafac) function
+        pub fn read_dir_2(
+            &self,
+            p0: ::std::string::String,
+            p1: u64,
+            p2: bool,
+        ) -> ::corebc_contract::builders::ContractCall<
+            M,
+            ::std::vec::Vec<
+                (::std::string::String, ::std::string::String, u64, bool, bool),
+            >,
+        > {
+            self.0
+                .method_hash([200, 122, 175, 172], (p0, p1, p2))
+                .expect("method not found (this should never happen)")
+        }
+        ///Calls the contract's `readFile` (0x875a759d) function
+        pub fn read_file(
+            &self,
+            p0: ::std::string::String,
+        ) -> ::corebc_contract::builders::ContractCall<M, ::std::string::String> {
+            self.0
+                .method_hash([135, 90, 117, 157], p0)
+                .expect("method not found (this should never happen)")
+        }
+        ///Calls the contract's `readFileBinary` (0x06623015) function
+        pub fn read_file_binary(
+            &self,
+            p0: ::std::string::String,
+        ) -> ::corebc_contract::builders::ContractCall<M, ::corebc_core::types::Bytes> {
+            self.0
+                .method_hash([6, 98, 48, 21], p0)
+                .expect("method not found (this should never happen)")
+        }
+        ///Calls the contract's `readLine` (0xadd52189) function
+        pub fn read_line(
+            &self,
+            p0: ::std::string::String,
+        ) -> ::corebc_contract::builders::ContractCall<M, ::std::string::String> {
+            self.0
+                .method_hash([173, 213, 33, 137], p0)
+                .expect("method not found (this should never happen)")
+        }
+        ///Calls the contract's `readLink` (0xbb3ef03a) function
+        pub fn read_link(
+            &self,
+            p0: ::std::string::String,
+        ) -> ::corebc_contract::builders::ContractCall<M, ::std::string::String> {
+            self.0
+                .method_hash([187, 62, 240, 58], p0)
+                .expect("method not found (this should never happen)")
+        }
+        ///Calls the contract's `record` (0x620bef66) function
+        pub fn record(&self) -> ::corebc_contract::builders::ContractCall<M, ()> {
+            self.0
+                .method_hash([98, 11, 239, 102], ())
+                .expect("method not found (this should never happen)")
+        }
+        ///Calls the contract's `recordLogs` (0x9abda091) function
+        pub fn record_logs(&self) -> ::corebc_contract::builders::ContractCall<M, ()> {
+            self.0
+                .method_hash([154, 189, 160, 145], ())
+                .expect("method not found (this should never happen)")
+        }
+        ///Calls the contract's `rememberKey` (0x40eac48c) function
+        pub fn remember_key(
+            &self,
+            p0: ::std::string::String,
+        ) -> ::corebc_contract::builders::ContractCall<
+            M,
+            ::corebc_core::types::Address,
+        > {
+            self.0
+                .method_hash([64, 234, 196, 140], p0)
+                .expect("method not found (this should never happen)")
+        }
+        ///Calls the contract's `removeDir` (0x45febd7d) function
+        pub fn remove_dir(
+            &self,
+            p0: ::std::string::String,
+            p1: bool,
+        ) -> ::corebc_contract::builders::ContractCall<M, ()> {
+            self.0
+                .method_hash([69, 254, 189, 125], (p0, p1))
+                .expect("method not found (this should never happen)")
+        }
+        ///Calls the contract's `removeFile` (0x32d0a050) function
+        pub fn remove_file(
+            &self,
+            p0: ::std::string::String,
+        ) -> ::corebc_contract::builders::ContractCall<M, ()> {
+            self.0
+                .method_hash([50, 208, 160, 80], p0)
+                .expect("method not found (this should never happen)")
+        }
+        ///Calls the contract's `resetNonce` (0x3a1289c1) function
+        pub fn reset_nonce(
+            &self,
+            p0: ::corebc_core::types::Address,
+        ) -> ::corebc_contract::builders::ContractCall<M, ()> {
+            self.0
+                .method_hash([58, 18, 137, 193], p0)
+                .expect("method not found (this should never happen)")
+        }
+        ///Calls the contract's `resumeGasMetering` (0x626bfc65) function
+        pub fn resume_gas_metering(
+            &self,
+        ) -> ::corebc_contract::builders::ContractCall<M, ()> {
+            self.0
+                .method_hash([98, 107, 252, 101], ())
+                .expect("method not found (this should never happen)")
+        }
+        ///Calls the contract's `revertTo` (0x255a8c36) function
+        pub fn revert_to(
+            &self,
+            p0: ::corebc_core::types::U256,
+        ) -> ::corebc_contract::builders::ContractCall<M, bool> {
+            self.0
+                .method_hash([37, 90, 140, 54], p0)
+                .expect("method not found (this should never happen)")
+        }
+        ///Calls the contract's `revokePersistent` (0x135c6569) function
+        pub fn revoke_persistent_0(
+            &self,
+            p0: ::corebc_core::types::Address,
+        ) -> ::corebc_contract::builders::ContractCall<M, ()> {
+            self.0
+                .method_hash([19, 92, 101, 105], p0)
+                .expect("method not found (this should never happen)")
+        }
+        ///Calls the contract's `revokePersistent` (0x9bf340e8) function
+        pub fn revoke_persistent_1(
+            &self,
+            p0: ::std::vec::Vec<::corebc_core::types::Address>,
+        ) -> ::corebc_contract::builders::ContractCall<M, ()> {
+            self.0
+                .method_hash([155, 243, 64, 232], p0)
+                .expect("method not found (this should never happen)")
+        }
+        ///Calls the contract's `roll` (0xe8fcf06b) function
+        pub fn roll(
+            &self,
+            p0: ::corebc_core::types::U256,
+        ) -> ::corebc_contract::builders::ContractCall<M, ()> {
+            self.0
+                .method_hash([232, 252, 240, 107], p0)
+                .expect("method not found (this should never happen)")
+        }
+        ///Calls the contract's `rollFork` (0xe3666077) function
+        pub fn roll_fork_0(
+            &self,
+            p0: ::corebc_core::types::U256,
+        ) -> ::corebc_contract::builders::ContractCall<M, ()> {
+            self.0
+                .method_hash([227, 102, 96, 119], p0)
+                .expect("method not found (this should never happen)")
+        }
+        ///Calls the contract's `rollFork` (0x0d538c69) function
+        pub fn roll_fork_1(
+            &self,
+            p0: [u8; 32],
+        ) -> ::corebc_contract::builders::ContractCall<M, ()> {
+            self.0
+                .method_hash([13, 83, 140, 105], p0)
+                .expect("method not found (this should never happen)")
+        }
+        ///Calls the contract's `rollFork` (0x439cddd8) function
+        pub fn roll_fork_2(
+            &self,
+            p0: ::corebc_core::types::U256,
+            p1: ::corebc_core::types::U256,
+        ) -> ::corebc_contract::builders::ContractCall<M, ()> {
+            self.0
+                .method_hash([67, 156, 221, 216], (p0, p1))
+                .expect("method not found (this should never happen)")
+        }
+        ///Calls the contract's `rollFork` (0x74340412) function
+        pub fn roll_fork_3(
+            &self,
+            p0: ::corebc_core::types::U256,
+            p1: [u8; 32],
+        ) -> ::corebc_contract::builders::ContractCall<M, ()> {
+            self.0
+                .method_hash([116, 52, 4, 18], (p0, p1))
+                .expect("method not found (this should never happen)")
+        }
+        ///Calls the contract's `rpcUrl` (0x931ea701) function
+        pub fn rpc_url(
+            &self,
+            p0: ::std::string::String,
+        ) -> ::corebc_contract::builders::ContractCall<M, ::std::string::String> {
+            self.0
+                .method_hash([147, 30, 167, 1], p0)
+                .expect("method not found (this should never happen)")
+        }
+        ///Calls the contract's `rpcUrlStructs` (0x725410b7) function
+        pub fn rpc_url_structs(
+            &self,
+        ) -> ::corebc_contract::builders::ContractCall<
+            M,
+            ::std::vec::Vec<(::std::string::String, ::std::string::String)>,
+        > {
+            self.0
+                .method_hash([114, 84, 16, 183], ())
+                .expect("method not found (this should never happen)")
+        }
+        ///Calls the contract's `rpcUrls` (0x266095bf) function
+        pub fn rpc_urls(
+            &self,
+        ) -> ::corebc_contract::builders::ContractCall<
+            M,
+            ::std::vec::Vec<[::std::string::String; 2]>,
+        > {
+            self.0
+                .method_hash([38, 96, 149, 191], ())
+                .expect("method not found (this should never happen)")
+        }
+        ///Calls the contract's `saveDeployment` (0xa6a50bf9) function
+        pub fn save_deployment(
+            &self,
+            p0: ::std::string::String,
+            p1: ::corebc_core::types::Address,
+        ) -> ::corebc_contract::builders::ContractCall<M, ()> {
+            self.0
+                .method_hash([166, 165, 11, 249], (p0, p1))
+                .expect("method not found (this should never happen)")
+        }
+        ///Calls the contract's `selectFork` (0x4669f876) function
+        pub fn select_fork(
+            &self,
+            p0: ::corebc_core::types::U256,
+        ) -> ::corebc_contract::builders::ContractCall<M, ()> {
+            self.0
+                .method_hash([70, 105, 248, 118], p0)
+                .expect("method not found (this should never happen)")
+        }
+        ///Calls the contract's `serializeAddress` (0x1b9dc788) function
+        pub fn serialize_address_0(
+            &self,
+            p0: ::std::string::String,
+            p1: ::std::string::String,
+            p2: ::corebc_core::types::Address,
+        ) -> ::corebc_contract::builders::ContractCall<M, ::std::string::String> {
+            self.0
+                .method_hash([27, 157, 199, 136], (p0, p1, p2))
+                .expect("method not found (this should never happen)")
+        }
+        ///Calls the contract's `serializeAddress` (0x9ef1ff0b) function
+        pub fn serialize_address_1(
+            &self,
+            p0: ::std::string::String,
+            p1: ::std::string::String,
+            p2: ::std::vec::Vec<::corebc_core::types::Address>,
+        ) -> ::corebc_contract::builders::ContractCall<M, ::std::string::String> {
+            self.0
+                .method_hash([158, 241, 255, 11], (p0, p1, p2))
+                .expect("method not found (this should never happen)")
+        }
+        ///Calls the contract's `serializeBool` (0x06c97128) function
+        pub fn serialize_bool_0(
+            &self,
+            p0: ::std::string::String,
+            p1: ::std::string::String,
+            p2: bool,
+        ) -> ::corebc_contract::builders::ContractCall<M, ::std::string::String> {
+            self.0
+                .method_hash([6, 201, 113, 40], (p0, p1, p2))
+                .expect("method not found (this should never happen)")
+        }
+        ///Calls the contract's `serializeBool` (0xc40c3d45) function
+        pub fn serialize_bool_1(
+            &self,
+            p0: ::std::string::String,
+            p1: ::std::string::String,
+            p2: ::std::vec::Vec<bool>,
+        ) -> ::corebc_contract::builders::ContractCall<M, ::std::string::String> {
+            self.0
+                .method_hash([196, 12, 61, 69], (p0, p1, p2))
+                .expect("method not found (this should never happen)")
+        }
+        ///Calls the contract's `serializeBytes` (0xd2d2b28a) function
+        pub fn serialize_bytes_0(
+            &self,
+            p0: ::std::string::String,
+            p1: ::std::string::String,
+            p2: ::corebc_core::types::Bytes,
+        ) -> ::corebc_contract::builders::ContractCall<M, ::std::string::String> {
+            self.0
+                .method_hash([210, 210, 178, 138], (p0, p1, p2))
+                .expect("method not found (this should never happen)")
+        }
+        ///Calls the contract's `serializeBytes` (0x0af30cb5) function
+        pub fn serialize_bytes_1(
+            &self,
+            p0: ::std::string::String,
+            p1: ::std::string::String,
+            p2: ::std::vec::Vec<::corebc_core::types::Bytes>,
+        ) -> ::corebc_contract::builders::ContractCall<M, ::std::string::String> {
+            self.0
+                .method_hash([10, 243, 12, 181], (p0, p1, p2))
+                .expect("method not found (this should never happen)")
+        }
+        ///Calls the contract's `serializeBytes32` (0x271a3830) function
+        pub fn serialize_bytes_320(
+            &self,
+            p0: ::std::string::String,
+            p1: ::std::string::String,
+            p2: [u8; 32],
+        ) -> ::corebc_contract::builders::ContractCall<M, ::std::string::String> {
+            self.0
+                .method_hash([39, 26, 56, 48], (p0, p1, p2))
+                .expect("method not found (this should never happen)")
+        }
+        ///Calls the contract's `serializeBytes32` (0x6324bb27) function
+        pub fn serialize_bytes_321(
+            &self,
+            p0: ::std::string::String,
+            p1: ::std::string::String,
+            p2: ::std::vec::Vec<[u8; 32]>,
+        ) -> ::corebc_contract::builders::ContractCall<M, ::std::string::String> {
+            self.0
+                .method_hash([99, 36, 187, 39], (p0, p1, p2))
+                .expect("method not found (this should never happen)")
+        }
+        ///Calls the contract's `serializeInt` (0x38b12609) function
+        pub fn serialize_int_0(
+            &self,
+            p0: ::std::string::String,
+            p1: ::std::string::String,
+            p2: ::corebc_core::types::I256,
+        ) -> ::corebc_contract::builders::ContractCall<M, ::std::string::String> {
+            self.0
+                .method_hash([56, 177, 38, 9], (p0, p1, p2))
+                .expect("method not found (this should never happen)")
+        }
+        ///Calls the contract's `serializeInt` (0xb2decdb3) function
+        pub fn serialize_int_1(
+            &self,
+            p0: ::std::string::String,
+            p1: ::std::string::String,
+            p2: ::std::vec::Vec<::corebc_core::types::I256>,
+        ) -> ::corebc_contract::builders::ContractCall<M, ::std::string::String> {
+            self.0
+                .method_hash([178, 222, 205, 179], (p0, p1, p2))
+                .expect("method not found (this should never happen)")
+        }
+        ///Calls the contract's `serializeString` (0x5c7ccb97) function
+        pub fn serialize_string_0(
+            &self,
+            p0: ::std::string::String,
+            p1: ::std::string::String,
+            p2: ::std::string::String,
+        ) -> ::corebc_contract::builders::ContractCall<M, ::std::string::String> {
+            self.0
+                .method_hash([92, 124, 203, 151], (p0, p1, p2))
+                .expect("method not found (this should never happen)")
+        }
+        ///Calls the contract's `serializeString` (0xa5df3cd6) function
+        pub fn serialize_string_1(
+            &self,
+            p0: ::std::string::String,
+            p1: ::std::string::String,
+            p2: ::std::vec::Vec<::std::string::String>,
+        ) -> ::corebc_contract::builders::ContractCall<M, ::std::string::String> {
+            self.0
+                .method_hash([165, 223, 60, 214], (p0, p1, p2))
+                .expect("method not found (this should never happen)")
+        }
+        ///Calls the contract's `serializeUint` (0xa11b741a) function
+        pub fn serialize_uint_0(
+            &self,
+            p0: ::std::string::String,
+            p1: ::std::string::String,
+            p2: ::corebc_core::types::U256,
+        ) -> ::corebc_contract::builders::ContractCall<M, ::std::string::String> {
+            self.0
+                .method_hash([161, 27, 116, 26], (p0, p1, p2))
+                .expect("method not found (this should never happen)")
+        }
+        ///Calls the contract's `serializeUint` (0x6d152d8f) function
+        pub fn serialize_uint_1(
+            &self,
+            p0: ::std::string::String,
+            p1: ::std::string::String,
+            p2: ::std::vec::Vec<::corebc_core::types::U256>,
+        ) -> ::corebc_contract::builders::ContractCall<M, ::std::string::String> {
+            self.0
+                .method_hash([109, 21, 45, 143], (p0, p1, p2))
+                .expect("method not found (this should never happen)")
+        }
+        ///Calls the contract's `setEnv` (0xd7347a08) function
+        pub fn set_env(
+            &self,
+            p0: ::std::string::String,
+            p1: ::std::string::String,
+        ) -> ::corebc_contract::builders::ContractCall<M, ()> {
+            self.0
+                .method_hash([215, 52, 122, 8], (p0, p1))
+                .expect("method not found (this should never happen)")
+        }
+        ///Calls the contract's `setNonce` (0x38831543) function
+        pub fn set_nonce(
+            &self,
+            p0: ::corebc_core::types::Address,
+            p1: u64,
+        ) -> ::corebc_contract::builders::ContractCall<M, ()> {
+            self.0
+                .method_hash([56, 131, 21, 67], (p0, p1))
+                .expect("method not found (this should never happen)")
+        }
+        ///Calls the contract's `setNonceUnsafe` (0xdc395990) function
+        pub fn set_nonce_unsafe(
+            &self,
+            p0: ::corebc_core::types::Address,
+            p1: u64,
+        ) -> ::corebc_contract::builders::ContractCall<M, ()> {
+            self.0
+                .method_hash([220, 57, 89, 144], (p0, p1))
+                .expect("method not found (this should never happen)")
+        }
+        ///Calls the contract's `sign` (0xd0c67f5f) function
+        pub fn sign(
+            &self,
+            p0: ::std::string::String,
+            p1: [u8; 32],
+        ) -> ::corebc_contract::builders::ContractCall<M, ::corebc_core::types::Bytes> {
+            self.0
+                .method_hash([208, 198, 127, 95], (p0, p1))
+                .expect("method not found (this should never happen)")
+        }
+        ///Calls the contract's `skip` (0xe6365bdc) function
+        pub fn skip(
+            &self,
+            p0: bool,
+        ) -> ::corebc_contract::builders::ContractCall<M, ()> {
+            self.0
+                .method_hash([230, 54, 91, 220], p0)
+                .expect("method not found (this should never happen)")
+        }
+        ///Calls the contract's `snapshot` (0x843c63f8) function
+        pub fn snapshot(
+            &self,
+        ) -> ::corebc_contract::builders::ContractCall<M, ::corebc_core::types::U256> {
+            self.0
+                .method_hash([132, 60, 99, 248], ())
+                .expect("method not found (this should never happen)")
+        }
+        ///Calls the contract's `snapshotValue` (0x54e001e0) function
+        pub fn snapshot_value(
+            &self,
+            p0: ::std::string::String,
+            p1: ::corebc_core::types::U256,
+        ) -> ::corebc_contract::builders::ContractCall<M, ()> {
+            self.0
+                .method_hash([84, 224, 1, 224], (p0, p1))
+                .expect("method not found (this should never happen)")
+        }
+        ///Calls the contract's `startBroadcast` (0x423144a6) function
+        pub fn start_broadcast_0(
+            &self,
+        ) -> ::corebc_contract::builders::ContractCall<M, ()> {
+            self.0
+                .method_hash([66, 49, 68, 166], ())
+                .expect("method not found (this should never happen)")
+        }
+        ///Calls the contract's `startBroadcast` (0x14d2714b) function
+        pub fn start_broadcast_1(
+            &self,
+            p0: ::corebc_core::types::Address,
+        ) -> ::corebc_contract::builders::ContractCall<M, ()> {
+            self.0
+                .method_hash([20, 210, 113, 75], p0)
+                .expect("method not found (this should never happen)")
+        }
+        ///Calls the contract's `startBroadcast` (0x09b04225) function
+        pub fn start_broadcast_2(
+            &self,
+            p0: ::std::string::String,
+        ) -> ::corebc_contract::builders::ContractCall<M, ()> {
+            self.0
+                .method_hash([9, 176, 66, 37], p0)
+                .expect("method not found (this should never happen)")
+        }
+        ///Calls the contract's `startPrank` (0xf1236596) function
+        pub fn start_prank_0(
+            &self,
+            p0: ::corebc_core::types::Address,
+        ) -> ::corebc_contract::builders::ContractCall<M, ()> {
+            self.0
+                .method_hash([241, 35, 101, 150], p0)
+                .expect("method not found (this should never happen)")
+        }
+        ///Calls the contract's `startPrank` (0x6e46fc4f) function
+        pub fn start_prank_1(
+            &self,
+            p0: ::corebc_core::types::Address,
+            p1: ::corebc_core::types::Address,
+        ) -> ::corebc_contract::builders::ContractCall<M, ()> {
+            self.0
+                .method_hash([110, 70, 252, 79], (p0, p1))
+                .expect("method not found (this should never happen)")
+        }
+        ///Calls the contract's `startSnapshotEnergy` (0x59dc86f3) function
+        pub fn start_snapshot_energy(
+            &self,
+            p0: ::std::string::String,
+        ) -> ::corebc_contract::builders::ContractCall<M, ()> {
+            self.0
+                .method_hash([89, 220, 134, 243], p0)
+                .expect("method not found (this should never happen)")
+        }
+        ///Calls the contract's `stopBroadcast` (0x741490df) function
+        pub fn stop_broadcast(
+            &self,
+        ) -> ::corebc_contract::builders::ContractCall<M, ()> {
+            self.0
+                .method_hash([116, 20, 144, 223], ())
+                .expect("method not found (this should never happen)")
+        }
+        ///Calls the contract's `stopPrank` (0xad743cce) function
+        pub fn stop_prank(&self) -> ::corebc_contract::builders::ContractCall<M, ()> {
+            self.0
+                .method_hash([173, 116, 60, 206], ())
+                .expect("method not found (this should never happen)")
+        }
+        ///Calls the contract's `stopSnapshotEnergy` (0xb1740636) function
+        pub fn stop_snapshot_energy(
+            &self,
+            p0: ::std::string::String,
+        ) -> ::corebc_contract::builders::ContractCall<M, ::corebc_core::types::U256> {
+            self.0
+                .method_hash([177, 116, 6, 54], p0)
+                .expect("method not found (this should never happen)")
+        }
+        ///Calls the contract's `store` (0x404ab67b) function
+        pub fn store(
+            &self,
+            p0: ::corebc_core::types::Address,
+            p1: [u8; 32],
+            p2: [u8; 32],
+        ) -> ::corebc_contract::builders::ContractCall<M, ()> {
+            self.0
+                .method_hash([64, 74, 182, 123], (p0, p1, p2))
+                .expect("method not found (this should never happen)")
+        }
+        ///Calls the contract's `toString` (0xe1c5ec89) function
+        pub fn to_string_0(
+            &self,
+            p0: ::corebc_core::types::Bytes,
+        ) -> ::corebc_contract::builders::ContractCall<M, ()> {
+            self.0
+                .method_hash([225, 197, 236, 137], p0)
+                .expect("method not found (this should never happen)")
+        }
+        ///Calls the contract's `toString` (0xa79a7df1) function
+        pub fn to_string_1(
+            &self,
+            p0: ::corebc_core::types::Address,
+        ) -> ::corebc_contract::builders::ContractCall<M, ()> {
+            self.0
+                .method_hash([167, 154, 125, 241], p0)
+                .expect("method not found (this should never happen)")
+        }
+        ///Calls the contract's `toString` (0x26e977fd) function
+        pub fn to_string_2(
+            &self,
+            p0: ::corebc_core::types::U256,
+        ) -> ::corebc_contract::builders::ContractCall<M, ()> {
+            self.0
+                .method_hash([38, 233, 119, 253], p0)
+                .expect("method not found (this should never happen)")
+        }
+        ///Calls the contract's `toString` (0xf5d91aec) function
+        pub fn to_string_3(
+            &self,
+            p0: ::corebc_core::types::I256,
+        ) -> ::corebc_contract::builders::ContractCall<M, ()> {
+            self.0
+                .method_hash([245, 217, 26, 236], p0)
+                .expect("method not found (this should never happen)")
+        }
+        ///Calls the contract's `toString` (0xe9dcfb8e) function
+        pub fn to_string_4(
+            &self,
+            p0: [u8; 32],
+        ) -> ::corebc_contract::builders::ContractCall<M, ()> {
+            self.0
+                .method_hash([233, 220, 251, 142], p0)
+                .expect("method not found (this should never happen)")
+        }
+        ///Calls the contract's `toString` (0x359df6f8) function
+        pub fn to_string_5(
+            &self,
+            p0: bool,
+        ) -> ::corebc_contract::builders::ContractCall<M, ()> {
+            self.0
+                .method_hash([53, 157, 246, 248], p0)
+                .expect("method not found (this should never happen)")
+        }
+        ///Calls the contract's `transact` (0x92cd3fce) function
+        pub fn transact_0(
+            &self,
+            p0: [u8; 32],
+        ) -> ::corebc_contract::builders::ContractCall<M, ()> {
+            self.0
+                .method_hash([146, 205, 63, 206], p0)
+                .expect("method not found (this should never happen)")
+        }
+        ///Calls the contract's `transact` (0x04f2e146) function
+        pub fn transact_1(
+            &self,
+            p0: ::corebc_core::types::U256,
+            p1: [u8; 32],
+        ) -> ::corebc_contract::builders::ContractCall<M, ()> {
+            self.0
+                .method_hash([4, 242, 225, 70], (p0, p1))
+                .expect("method not found (this should never happen)")
+        }
+        ///Calls the contract's `txGasPrice` (0xc69545f8) function
+        pub fn tx_gas_price(
+            &self,
+            p0: ::corebc_core::types::U256,
+        ) -> ::corebc_contract::builders::ContractCall<M, ()> {
+            self.0
+                .method_hash([198, 149, 69, 248], p0)
+                .expect("method not found (this should never happen)")
+        }
+        ///Calls the contract's `warp` (0x037a7f89) function
+        pub fn warp(
+            &self,
+            p0: ::corebc_core::types::U256,
+        ) -> ::corebc_contract::builders::ContractCall<M, ()> {
+            self.0
+                .method_hash([3, 122, 127, 137], p0)
+                .expect("method not found (this should never happen)")
+        }
+        ///Calls the contract's `writeFile` (0x6b52a3c0) function
+        pub fn write_file(
+            &self,
+            p0: ::std::string::String,
+            p1: ::std::string::String,
+        ) -> ::corebc_contract::builders::ContractCall<M, ()> {
+            self.0
+                .method_hash([107, 82, 163, 192], (p0, p1))
+                .expect("method not found (this should never happen)")
+        }
+        ///Calls the contract's `writeFileBinary` (0x103030bc) function
+        pub fn write_file_binary(
+            &self,
+            p0: ::std::string::String,
+            p1: ::corebc_core::types::Bytes,
+        ) -> ::corebc_contract::builders::ContractCall<M, ()> {
+            self.0
+                .method_hash([16, 48, 48, 188], (p0, p1))
+                .expect("method not found (this should never happen)")
+        }
+        ///Calls the contract's `writeJson` (0xb5e0aa0c) function
+        pub fn write_json_0(
+            &self,
+            p0: ::std::string::String,
+            p1: ::std::string::String,
+        ) -> ::corebc_contract::builders::ContractCall<M, ()> {
+            self.0
+                .method_hash([181, 224, 170, 12], (p0, p1))
+                .expect("method not found (this should never happen)")
+        }
+        ///Calls the contract's `writeJson` (0xa57e5e2d) function
+        pub fn write_json_1(
+            &self,
+            p0: ::std::string::String,
+            p1: ::std::string::String,
+            p2: ::std::string::String,
+        ) -> ::corebc_contract::builders::ContractCall<M, ()> {
+            self.0
+                .method_hash([165, 126, 94, 45], (p0, p1, p2))
+                .expect("method not found (this should never happen)")
+        }
+        ///Calls the contract's `writeLine` (0x592b1bb6) function
+        pub fn write_line(
+            &self,
+            p0: ::std::string::String,
+            p1: ::std::string::String,
+        ) -> ::corebc_contract::builders::ContractCall<M, ()> {
+            self.0
+                .method_hash([89, 43, 27, 182], (p0, p1))
+                .expect("method not found (this should never happen)")
+        }
+        ///Calls the contract's `writeToml` (0xc5e09da4) function
+        pub fn write_toml_0(
+            &self,
+            p0: ::std::string::String,
+            p1: ::std::string::String,
+        ) -> ::corebc_contract::builders::ContractCall<M, ()> {
+            self.0
+                .method_hash([197, 224, 157, 164], (p0, p1))
+                .expect("method not found (this should never happen)")
+        }
+        ///Calls the contract's `writeToml` (0x1392b0a0) function
+        pub fn write_toml_1(
+            &self,
+            p0: ::std::string::String,
+            p1: ::std::string::String,
+            p2: ::std::string::String,
+        ) -> ::corebc_contract::builders::ContractCall<M, ()> {
+            self.0
+                .method_hash([19, 146, 176, 160], (p0, p1, p2))
+                .expect("method not found (this should never happen)")
+        }
+    }
+    impl<M: ::corebc_providers::Middleware> From<::corebc_contract::Contract<M>>
+    for HEVM<M> {
+        fn from(contract: ::corebc_contract::Contract<M>) -> Self {
+            Self::new(contract.address(), contract.client())
+        }
+    }
+    ///Container type for all input parameters for the `accesses` function with signature `accesses(address)` and selector `0x6e606627`
+    #[derive(
+        Clone,
+        ::corebc_contract::EthCall,
+        ::corebc_contract::EthDisplay,
+        Default,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash
+    )]
+    #[ethcall(name = "accesses", abi = "accesses(address)")]
+    pub struct AccessesCall(pub ::corebc_core::types::Address);
+    ///Container type for all input parameters for the `activeFork` function with signature `activeFork()` and selector `0x2a978c58`
+    #[derive(
+        Clone,
+        ::corebc_contract::EthCall,
+        ::corebc_contract::EthDisplay,
+        Default,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash
+    )]
+    #[ethcall(name = "activeFork", abi = "activeFork()")]
+    pub struct ActiveForkCall;
+    ///Container type for all input parameters for the `addr` function with signature `addr(string)` and selector `0xf91daf04`
+    #[derive(
+        Clone,
+        ::corebc_contract::EthCall,
+        ::corebc_contract::EthDisplay,
+        Default,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash
+    )]
+    #[ethcall(name = "addr", abi = "addr(string)")]
+    pub struct AddrCall(pub ::std::string::String);
+    ///Container type for all input parameters for the `allowCheatcodes` function with signature `allowCheatcodes(address)` and selector `0x21bd8ec4`
+    #[derive(
+        Clone,
+        ::corebc_contract::EthCall,
+        ::corebc_contract::EthDisplay,
+        Default,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash
+    )]
+    #[ethcall(name = "allowCheatcodes", abi = "allowCheatcodes(address)")]
+    pub struct AllowCheatcodesCall(pub ::corebc_core::types::Address);
+    ///Container type for all input parameters for the `assertApproxEqAbs` function with signature `assertApproxEqAbs(uint256,uint256,uint256)` and selector `0xfdf9f058`
+    #[derive(
+        Clone,
+        ::corebc_contract::EthCall,
+        ::corebc_contract::EthDisplay,
+        Default,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash
+    )]
+    #[ethcall(
+        name = "assertApproxEqAbs",
+        abi = "assertApproxEqAbs(uint256,uint256,uint256)"
+    )]
+    pub struct AssertApproxEqAbs0Call(
+        pub ::corebc_core::types::U256,
+        pub ::corebc_core::types::U256,
+        pub ::corebc_core::types::U256,
+    );
+    ///Container type for all input parameters for the `assertApproxEqAbs` function with signature `assertApproxEqAbs(uint256,uint256,uint256,string)` and selector `0x6cf38620`
+    #[derive(
+        Clone,
+        ::corebc_contract::EthCall,
+        ::corebc_contract::EthDisplay,
+        Default,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash
+    )]
+    #[ethcall(
+        name = "assertApproxEqAbs",
+        abi = "assertApproxEqAbs(uint256,uint256,uint256,string)"
+    )]
+    pub struct AssertApproxEqAbs2Call(
+        pub ::corebc_core::types::U256,
+        pub ::corebc_core::types::U256,
+        pub ::corebc_core::types::U256,
+        pub ::std::string::String,
+    );
+    ///Container type for all input parameters for the `assertApproxEqAbs` function with signature `assertApproxEqAbs(int256,int256,uint256)` and selector `0x530a57cc`
+    #[derive(
+        Clone,
+        ::corebc_contract::EthCall,
+        ::corebc_contract::EthDisplay,
+        Default,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash
+    )]
+    #[ethcall(
+        name = "assertApproxEqAbs",
+        abi = "assertApproxEqAbs(int256,int256,uint256)"
+    )]
+    pub struct AssertApproxEqAbs1Call(
+        pub ::corebc_core::types::I256,
+        pub ::corebc_core::types::I256,
+        pub ::corebc_core::types::U256,
+    );
+    ///Container type for all input parameters for the `assertApproxEqAbs` function with signature `assertApproxEqAbs(int256,int256,uint256,string)` and selector `0xd0342579`
+    #[derive(
+        Clone,
+        ::corebc_contract::EthCall,
+        ::corebc_contract::EthDisplay,
+        Default,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash
+    )]
+    #[ethcall(
+        name = "assertApproxEqAbs",
+        abi = "assertApproxEqAbs(int256,int256,uint256,string)"
+    )]
+    pub struct AssertApproxEqAbs3Call(
+        pub ::corebc_core::types::I256,
+        pub ::corebc_core::types::I256,
+        pub ::corebc_core::types::U256,
+        pub ::std::string::String,
+    );
+    ///Container type for all input parameters for the `assertApproxEqRel` function with signature `assertApproxEqRel(uint256,uint256,uint256)` and selector `0x49c9f7d6`
+    #[derive(
+        Clone,
+        ::corebc_contract::EthCall,
+        ::corebc_contract::EthDisplay,
+        Default,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash
+    )]
+    #[ethcall(
+        name = "assertApproxEqRel",
+        abi = "assertApproxEqRel(uint256,uint256,uint256)"
+    )]
+    pub struct AssertApproxEqRel0Call(
+        pub ::corebc_core::types::U256,
+        pub ::corebc_core::types::U256,
+        pub ::corebc_core::types::U256,
+    );
+    ///Container type for all input parameters for the `assertApproxEqRel` function with signature `assertApproxEqRel(uint256,uint256,uint256,string)` and selector `0x65fbc238`
+    #[derive(
+        Clone,
+        ::corebc_contract::EthCall,
+        ::corebc_contract::EthDisplay,
+        Default,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash
+    )]
+    #[ethcall(
+        name = "assertApproxEqRel",
+        abi = "assertApproxEqRel(uint256,uint256,uint256,string)"
+    )]
+    pub struct AssertApproxEqRel2Call(
+        pub ::corebc_core::types::U256,
+        pub ::corebc_core::types::U256,
+        pub ::corebc_core::types::U256,
+        pub ::std::string::String,
+    );
+    ///Container type for all input parameters for the `assertApproxEqRel` function with signature `assertApproxEqRel(int256,int256,uint256)` and selector `0x2631ffbc`
+    #[derive(
+        Clone,
+        ::corebc_contract::EthCall,
+        ::corebc_contract::EthDisplay,
+        Default,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash
+    )]
+    #[ethcall(
+        name = "assertApproxEqRel",
+        abi = "assertApproxEqRel(int256,int256,uint256)"
+    )]
+    pub struct AssertApproxEqRel1Call(
+        pub ::corebc_core::types::I256,
+        pub ::corebc_core::types::I256,
+        pub ::corebc_core::types::U256,
+    );
+    ///Container type for all input parameters for the `assertApproxEqRel` function with signature `assertApproxEqRel(int256,int256,uint256,string)` and selector `0xdfada52f`
+    #[derive(
+        Clone,
+        ::corebc_contract::EthCall,
+        ::corebc_contract::EthDisplay,
+        Default,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash
+    )]
+    #[ethcall(
+        name = "assertApproxEqRel",
+        abi = "assertApproxEqRel(int256,int256,uint256,string)"
+    )]
+    pub struct AssertApproxEqRel3Call(
+        pub ::corebc_core::types::I256,
+        pub ::corebc_core::types::I256,
+        pub ::corebc_core::types::U256,
+        pub ::std::string::String,
+    );
+    ///Container type for all input parameters for the `assertEq` function with signature `assertEq(bool,bool)` and selector `0xa67955d7`
+    #[derive(
+        Clone,
+        ::corebc_contract::EthCall,
+        ::corebc_contract::EthDisplay,
+        Default,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash
+    )]
+    #[ethcall(name = "assertEq", abi = "assertEq(bool,bool)")]
+    pub struct AssertEq0Call(pub bool, pub bool);
+    ///Container type for all input parameters for the `assertEq` function with signature `assertEq(bool,bool,string)` and selector `0x41df0451`
+    #[derive(
+        Clone,
+        ::corebc_contract::EthCall,
+        ::corebc_contract::EthDisplay,
+        Default,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash
+    )]
+    #[ethcall(name = "assertEq", abi = "assertEq(bool,bool,string)")]
+    pub struct AssertEq14Call(pub bool, pub bool, pub ::std::string::String);
+    ///Container type for all input parameters for the `assertEq` function with signature `assertEq(uint256,uint256)` and selector `0xdcbb3fda`
+    #[derive(
+        Clone,
+        ::corebc_contract::EthCall,
+        ::corebc_contract::EthDisplay,
+        Default,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash
+    )]
+    #[ethcall(name = "assertEq", abi = "assertEq(uint256,uint256)")]
+    pub struct AssertEq1Call(
+        pub ::corebc_core::types::U256,
+        pub ::corebc_core::types::U256,
+    );
+    ///Container type for all input parameters for the `assertEq` function with signature `assertEq(uint256,uint256,string)` and selector `0x536cacd7`
+    #[derive(
+        Clone,
+        ::corebc_contract::EthCall,
+        ::corebc_contract::EthDisplay,
+        Default,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash
+    )]
+    #[ethcall(name = "assertEq", abi = "assertEq(uint256,uint256,string)")]
+    pub struct AssertEq15Call(
+        pub ::corebc_core::types::U256,
+        pub ::corebc_core::types::U256,
+        pub ::std::string::String,
+    );
+    ///Container type for all input parameters for the `assertEq` function with signature `assertEq(int256,int256)` and selector `0xf6a7fb98`
+    #[derive(
+        Clone,
+        ::corebc_contract::EthCall,
+        ::corebc_contract::EthDisplay,
+        Default,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash
+    )]
+    #[ethcall(name = "assertEq", abi = "assertEq(int256,int256)")]
+    pub struct AssertEq2Call(
+        pub ::corebc_core::types::I256,
+        pub ::corebc_core::types::I256,
+    );
+    ///Container type for all input parameters for the `assertEq` function with signature `assertEq(int256,int256,string)` and selector `0x79502bb6`
+    #[derive(
+        Clone,
+        ::corebc_contract::EthCall,
+        ::corebc_contract::EthDisplay,
+        Default,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash
+    )]
+    #[ethcall(name = "assertEq", abi = "assertEq(int256,int256,string)")]
+    pub struct AssertEq16Call(
+        pub ::corebc_core::types::I256,
+        pub ::corebc_core::types::I256,
+        pub ::std::string::String,
+    );
+    ///Container type for all input parameters for the `assertEq` function with signature `assertEq(address,address)` and selector `0x8977906b`
+    #[derive(
+        Clone,
+        ::corebc_contract::EthCall,
+        ::corebc_contract::EthDisplay,
+        Default,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash
+    )]
+    #[ethcall(name = "assertEq", abi = "assertEq(address,address)")]
+    pub struct AssertEq3Call(
+        pub ::corebc_core::types::Address,
+        pub ::corebc_core::types::Address,
+    );
+    ///Container type for all input parameters for the `assertEq` function with signature `assertEq(address,address,string)` and selector `0x9668c678`
+    #[derive(
+        Clone,
+        ::corebc_contract::EthCall,
+        ::corebc_contract::EthDisplay,
+        Default,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash
+    )]
+    #[ethcall(name = "assertEq", abi = "assertEq(address,address,string)")]
+    pub struct AssertEq17Call(
+        pub ::corebc_core::types::Address,
+        pub ::corebc_core::types::Address,
+        pub ::std::string::String,
+    );
+    ///Container type for all input parameters for the `assertEq` function with signature `assertEq(bytes32,bytes32)` and selector `0xd25ce00c`
+    #[derive(
+        Clone,
+        ::corebc_contract::EthCall,
+        ::corebc_contract::EthDisplay,
+        Default,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash
+    )]
+    #[ethcall(name = "assertEq", abi = "assertEq(bytes32,bytes32)")]
+    pub struct AssertEq4Call(pub [u8; 32], pub [u8; 32]);
+    ///Container type for all input parameters for the `assertEq` function with signature `assertEq(bytes32,bytes32,string)` and selector `0x21475794`
+    #[derive(
+        Clone,
+        ::corebc_contract::EthCall,
+        ::corebc_contract::EthDisplay,
+        Default,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash
+    )]
+    #[ethcall(name = "assertEq", abi = "assertEq(bytes32,bytes32,string)")]
+    pub struct AssertEq18Call(pub [u8; 32], pub [u8; 32], pub ::std::string::String);
+    ///Container type for all input parameters for the `assertEq` function with signature `assertEq(string,string)` and selector `0x9b90905e`
+    #[derive(
+        Clone,
+        ::corebc_contract::EthCall,
+        ::corebc_contract::EthDisplay,
+        Default,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash
+    )]
+    #[ethcall(name = "assertEq", abi = "assertEq(string,string)")]
+    pub struct AssertEq5Call(pub ::std::string::String, pub ::std::string::String);
+    ///Container type for all input parameters for the `assertEq` function with signature `assertEq(string,string,string)` and selector `0xa2e3b04d`
+    #[derive(
+        Clone,
+        ::corebc_contract::EthCall,
+        ::corebc_contract::EthDisplay,
+        Default,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash
+    )]
+    #[ethcall(name = "assertEq", abi = "assertEq(string,string,string)")]
+    pub struct AssertEq19Call(
+        pub ::std::string::String,
+        pub ::std::string::String,
+        pub ::std::string::String,
+    );
+    ///Container type for all input parameters for the `assertEq` function with signature `assertEq(bytes,bytes)` and selector `0xc509993b`
+    #[derive(
+        Clone,
+        ::corebc_contract::EthCall,
+        ::corebc_contract::EthDisplay,
+        Default,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash
+    )]
+    #[ethcall(name = "assertEq", abi = "assertEq(bytes,bytes)")]
+    pub struct AssertEq6Call(
+        pub ::corebc_core::types::Bytes,
+        pub ::corebc_core::types::Bytes,
+    );
+    ///Container type for all input parameters for the `assertEq` function with signature `assertEq(bytes,bytes,string)` and selector `0xceb16849`
+    #[derive(
+        Clone,
+        ::corebc_contract::EthCall,
+        ::corebc_contract::EthDisplay,
+        Default,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash
+    )]
+    #[ethcall(name = "assertEq", abi = "assertEq(bytes,bytes,string)")]
+    pub struct AssertEq20Call(
+        pub ::corebc_core::types::Bytes,
+        pub ::corebc_core::types::Bytes,
+        pub ::std::string::String,
+    );
+    ///Container type for all input parameters for the `assertEq` function with signature `assertEq(bool[],bool[])` and selector `0xae11caca`
+    #[derive(
+        Clone,
+        ::corebc_contract::EthCall,
+        ::corebc_contract::EthDisplay,
+        Default,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash
+    )]
+    #[ethcall(name = "assertEq", abi = "assertEq(bool[],bool[])")]
+    pub struct AssertEq7Call(pub ::std::vec::Vec<bool>, pub ::std::vec::Vec<bool>);
+    ///Container type for all input parameters for the `assertEq` function with signature `assertEq(bool[],bool[],string)` and selector `0xc838dece`
+    #[derive(
+        Clone,
+        ::corebc_contract::EthCall,
+        ::corebc_contract::EthDisplay,
+        Default,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash
+    )]
+    #[ethcall(name = "assertEq", abi = "assertEq(bool[],bool[],string)")]
+    pub struct AssertEq21Call(
+        pub ::std::vec::Vec<bool>,
+        pub ::std::vec::Vec<bool>,
+        pub ::std::string::String,
+    );
+    ///Container type for all input parameters for the `assertEq` function with signature `assertEq(uint256[],uint256[])` and selector `0xc15e3ddf`
+    #[derive(
+        Clone,
+        ::corebc_contract::EthCall,
+        ::corebc_contract::EthDisplay,
+        Default,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash
+    )]
+    #[ethcall(name = "assertEq", abi = "assertEq(uint256[],uint256[])")]
+    pub struct AssertEq8Call(
+        pub ::std::vec::Vec<::corebc_core::types::U256>,
+        pub ::std::vec::Vec<::corebc_core::types::U256>,
+    );
+    ///Container type for all input parameters for the `assertEq` function with signature `assertEq(uint256[],uint256[],string)` and selector `0x1773c6fa`
+    #[derive(
+        Clone,
+        ::corebc_contract::EthCall,
+        ::corebc_contract::EthDisplay,
+        Default,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash
+    )]
+    #[ethcall(name = "assertEq", abi = "assertEq(uint256[],uint256[],string)")]
+    pub struct AssertEq22Call(
+        pub ::std::vec::Vec<::corebc_core::types::U256>,
+        pub ::std::vec::Vec<::corebc_core::types::U256>,
+        pub ::std::string::String,
+    );
+    ///Container type for all input parameters for the `assertEq` function with signature `assertEq(int256[],int256[])` and selector `0x09972936`
+    #[derive(
+        Clone,
+        ::corebc_contract::EthCall,
+        ::corebc_contract::EthDisplay,
+        Default,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash
+    )]
+    #[ethcall(name = "assertEq", abi = "assertEq(int256[],int256[])")]
+    pub struct AssertEq9Call(
+        pub ::std::vec::Vec<::corebc_core::types::I256>,
+        pub ::std::vec::Vec<::corebc_core::types::I256>,
+    );
+    ///Container type for all input parameters for the `assertEq` function with signature `assertEq(int256[],int256[],string)` and selector `0xd082c718`
+    #[derive(
+        Clone,
+        ::corebc_contract::EthCall,
+        ::corebc_contract::EthDisplay,
+        Default,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash
+    )]
+    #[ethcall(name = "assertEq", abi = "assertEq(int256[],int256[],string)")]
+    pub struct AssertEq23Call(
+        pub ::std::vec::Vec<::corebc_core::types::I256>,
+        pub ::std::vec::Vec<::corebc_core::types::I256>,
+        pub ::std::string::String,
+    );
+    ///Container type for all input parameters for the `assertEq` function with signature `assertEq(address[],address[])` and selector `0xea772e51`
+    #[derive(
+        Clone,
+        ::corebc_contract::EthCall,
+        ::corebc_contract::EthDisplay,
+        Default,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash
+    )]
+    #[ethcall(name = "assertEq", abi = "assertEq(address[],address[])")]
+    pub struct AssertEq10Call(
+        pub ::std::vec::Vec<::corebc_core::types::Address>,
+        pub ::std::vec::Vec<::corebc_core::types::Address>,
+    );
+    ///Container type for all input parameters for the `assertEq` function with signature `assertEq(address[],address[],string)` and selector `0x27f1e6d4`
+    #[derive(
+        Clone,
+        ::corebc_contract::EthCall,
+        ::corebc_contract::EthDisplay,
+        Default,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash
+    )]
+    #[ethcall(name = "assertEq", abi = "assertEq(address[],address[],string)")]
+    pub struct AssertEq24Call(
+        pub ::std::vec::Vec<::corebc_core::types::Address>,
+        pub ::std::vec::Vec<::corebc_core::types::Address>,
+        pub ::std::string::String,
+    );
+    ///Container type for all input parameters for the `assertEq` function with signature `assertEq(bytes32[],bytes32[])` and selector `0xaf444979`
+    #[derive(
+        Clone,
+        ::corebc_contract::EthCall,
+        ::corebc_contract::EthDisplay,
+        Default,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash
+    )]
+    #[ethcall(name = "assertEq", abi = "assertEq(bytes32[],bytes32[])")]
+    pub struct AssertEq11Call(
+        pub ::std::vec::Vec<[u8; 32]>,
+        pub ::std::vec::Vec<[u8; 32]>,
+    );
+    ///Container type for all input parameters for the `assertEq` function with signature `assertEq(bytes32[],bytes32[],string)` and selector `0x54fbca85`
+    #[derive(
+        Clone,
+        ::corebc_contract::EthCall,
+        ::corebc_contract::EthDisplay,
+        Default,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash
+    )]
+    #[ethcall(name = "assertEq", abi = "assertEq(bytes32[],bytes32[],string)")]
+    pub struct AssertEq25Call(
+        pub ::std::vec::Vec<[u8; 32]>,
+        pub ::std::vec::Vec<[u8; 32]>,
+        pub ::std::string::String,
+    );
+    ///Container type for all input parameters for the `assertEq` function with signature `assertEq(string[],string[])` and selector `0x76542b3d`
+    #[derive(
+        Clone,
+        ::corebc_contract::EthCall,
+        ::corebc_contract::EthDisplay,
+        Default,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash
+    )]
+    #[ethcall(name = "assertEq", abi = "assertEq(string[],string[])")]
+    pub struct AssertEq12Call(
+        pub ::std::vec::Vec<::std::string::String>,
+        pub ::std::vec::Vec<::std::string::String>,
+    );
+    ///Container type for all input parameters for the `assertEq` function with signature `assertEq(string[],string[],string)` and selector `0x2a27879b`
+    #[derive(
+        Clone,
+        ::corebc_contract::EthCall,
+        ::corebc_contract::EthDisplay,
+        Default,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash
+    )]
+    #[ethcall(name = "assertEq", abi = "assertEq(string[],string[],string)")]
+    pub struct AssertEq26Call(
+        pub ::std::vec::Vec<::std::string::String>,
+        pub ::std::vec::Vec<::std::string::String>,
+        pub ::std::string::String,
+    );
+    ///Container type for all input parameters for the `assertEq` function with signature `assertEq(bytes[],bytes[])` and selector `0xeea73f11`
+    #[derive(
+        Clone,
+        ::corebc_contract::EthCall,
+        ::corebc_contract::EthDisplay,
+        Default,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash
+    )]
+    #[ethcall(name = "assertEq", abi = "assertEq(bytes[],bytes[])")]
+    pub struct AssertEq13Call(
+        pub ::std::vec::Vec<::corebc_core::types::Bytes>,
+        pub ::std::vec::Vec<::corebc_core::types::Bytes>,
+    );
+    ///Container type for all input parameters for the `assertEq` function with signature `assertEq(bytes[],bytes[],string)` and selector `0x94eea79c`
+    #[derive(
+        Clone,
+        ::corebc_contract::EthCall,
+        ::corebc_contract::EthDisplay,
+        Default,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash
+    )]
+    #[ethcall(name = "assertEq", abi = "assertEq(bytes[],bytes[],string)")]
+    pub struct AssertEq27Call(
+        pub ::std::vec::Vec<::corebc_core::types::Bytes>,
+        pub ::std::vec::Vec<::corebc_core::types::Bytes>,
+        pub ::std::string::String,
+    );
+    ///Container type for all input parameters for the `assertFalse` function with signature `assertFalse(bool)` and selector `0x5b584927`
+    #[derive(
+        Clone,
+        ::corebc_contract::EthCall,
+        ::corebc_contract::EthDisplay,
+        Default,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash
+    )]
+    #[ethcall(name = "assertFalse", abi = "assertFalse(bool)")]
+    pub struct AssertFalse0Call(pub bool);
+    ///Container type for all input parameters for the `assertFalse` function with signature `assertFalse(bool,string)` and selector `0xe05d3983`
+    #[derive(
+        Clone,
+        ::corebc_contract::EthCall,
+        ::corebc_contract::EthDisplay,
+        Default,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash
+    )]
+    #[ethcall(name = "assertFalse", abi = "assertFalse(bool,string)")]
+    pub struct AssertFalse1Call(pub bool, pub ::std::string::String);
+    ///Container type for all input parameters for the `assertGe` function with signature `assertGe(uint256,uint256)` and selector `0x8beecaa2`
+    #[derive(
+        Clone,
+        ::corebc_contract::EthCall,
+        ::corebc_contract::EthDisplay,
+        Default,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash
+    )]
+    #[ethcall(name = "assertGe", abi = "assertGe(uint256,uint256)")]
+    pub struct AssertGe0Call(
+        pub ::corebc_core::types::U256,
+        pub ::corebc_core::types::U256,
+    );
+    ///Container type for all input parameters for the `assertGe` function with signature `assertGe(uint256,uint256,string)` and selector `0xa3861d28`
+    #[derive(
+        Clone,
+        ::corebc_contract::EthCall,
+        ::corebc_contract::EthDisplay,
+        Default,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash
+    )]
+    #[ethcall(name = "assertGe", abi = "assertGe(uint256,uint256,string)")]
+    pub struct AssertGe2Call(
+        pub ::corebc_core::types::U256,
+        pub ::corebc_core::types::U256,
+        pub ::std::string::String,
+    );
+    ///Container type for all input parameters for the `assertGe` function with signature `assertGe(int256,int256)` and selector `0x12462240`
+    #[derive(
+        Clone,
+        ::corebc_contract::EthCall,
+        ::corebc_contract::EthDisplay,
+        Default,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash
+    )]
+    #[ethcall(name = "assertGe", abi = "assertGe(int256,int256)")]
+    pub struct AssertGe1Call(
+        pub ::corebc_core::types::I256,
+        pub ::corebc_core::types::I256,
+    );
+    ///Container type for all input parameters for the `assertGe` function with signature `assertGe(int256,int256,string)` and selector `0x676f3853`
+    #[derive(
+        Clone,
+        ::corebc_contract::EthCall,
+        ::corebc_contract::EthDisplay,
+        Default,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash
+    )]
+    #[ethcall(name = "assertGe", abi = "assertGe(int256,int256,string)")]
+    pub struct AssertGe3Call(
+        pub ::corebc_core::types::I256,
+        pub ::corebc_core::types::I256,
+        pub ::std::string::String,
+    );
+    ///Container type for all input parameters for the `assertGt` function with signature `assertGt(uint256,uint256)` and selector `0x49464840`
+    #[derive(
+        Clone,
+        ::corebc_contract::EthCall,
+        ::corebc_contract::EthDisplay,
+        Default,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash
+    )]
+    #[ethcall(name = "assertGt", abi = "assertGt(uint256,uint256)")]
+    pub struct AssertGt0Call(
+        pub ::corebc_core::types::U256,
+        pub ::corebc_core::types::U256,
+    );
+    ///Container type for all input parameters for the `assertGt` function with signature `assertGt(uint256,uint256,string)` and selector `0xadec9b5d`
+    #[derive(
+        Clone,
+        ::corebc_contract::EthCall,
+        ::corebc_contract::EthDisplay,
+        Default,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash
+    )]
+    #[ethcall(name = "assertGt", abi = "assertGt(uint256,uint256,string)")]
+    pub struct AssertGt2Call(
+        pub ::corebc_core::types::U256,
+        pub ::corebc_core::types::U256,
+        pub ::std::string::String,
+    );
+    ///Container type for all input parameters for the `assertGt` function with signature `assertGt(int256,int256)` and selector `0xe58f1dd6`
+    #[derive(
+        Clone,
+        ::corebc_contract::EthCall,
+        ::corebc_contract::EthDisplay,
+        Default,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash
+    )]
+    #[ethcall(name = "assertGt", abi = "assertGt(int256,int256)")]
+    pub struct AssertGt1Call(
+        pub ::corebc_core::types::I256,
+        pub ::corebc_core::types::I256,
+    );
+    ///Container type for all input parameters for the `assertGt` function with signature `assertGt(int256,int256,string)` and selector `0x301a5a2a`
+    #[derive(
+        Clone,
+        ::corebc_contract::EthCall,
+        ::corebc_contract::EthDisplay,
+        Default,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash
+    )]
+    #[ethcall(name = "assertGt", abi = "assertGt(int256,int256,string)")]
+    pub struct AssertGt3Call(
+        pub ::corebc_core::types::I256,
+        pub ::corebc_core::types::I256,
+        pub ::std::string::String,
+    );
+    ///Container type for all input parameters for the `assertLe` function with signature `assertLe(uint256,uint256)` and selector `0xdded40d8`
+    #[derive(
+        Clone,
+        ::corebc_contract::EthCall,
+        ::corebc_contract::EthDisplay,
+        Default,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash
+    )]
+    #[ethcall(name = "assertLe", abi = "assertLe(uint256,uint256)")]
+    pub struct AssertLe0Call(
+        pub ::corebc_core::types::U256,
+        pub ::corebc_core::types::U256,
+    );
+    ///Container type for all input parameters for the `assertLe` function with signature `assertLe(uint256,uint256,string)` and selector `0xdf966522`
+    #[derive(
+        Clone,
+        ::corebc_contract::EthCall,
+        ::corebc_contract::EthDisplay,
+        Default,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash
+    )]
+    #[ethcall(name = "assertLe", abi = "assertLe(uint256,uint256,string)")]
+    pub struct AssertLe2Call(
+        pub ::corebc_core::types::U256,
+        pub ::corebc_core::types::U256,
+        pub ::std::string::String,
+    );
+    ///Container type for all input parameters for the `assertLe` function with signature `assertLe(int256,int256)` and selector `0xb86ab0d9`
+    #[derive(
+        Clone,
+        ::corebc_contract::EthCall,
+        ::corebc_contract::EthDisplay,
+        Default,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash
+    )]
+    #[ethcall(name = "assertLe", abi = "assertLe(int256,int256)")]
+    pub struct AssertLe1Call(
+        pub ::corebc_core::types::I256,
+        pub ::corebc_core::types::I256,
+    );
+    ///Container type for all input parameters for the `assertLe` function with signature `assertLe(int256,int256,string)` and selector `0xf9cca740`
+    #[derive(
+        Clone,
+        ::corebc_contract::EthCall,
+        ::corebc_contract::EthDisplay,
+        Default,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash
+    )]
+    #[ethcall(name = "assertLe", abi = "assertLe(int256,int256,string)")]
+    pub struct AssertLe3Call(
+        pub ::corebc_core::types::I256,
+        pub ::corebc_core::types::I256,
+        pub ::std::string::String,
+    );
+    ///Container type for all input parameters for the `assertLt` function with signature `assertLt(uint256,uint256)` and selector `0x2ff0cc15`
+    #[derive(
+        Clone,
+        ::corebc_contract::EthCall,
+        ::corebc_contract::EthDisplay,
+        Default,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash
+    )]
+    #[ethcall(name = "assertLt", abi = "assertLt(uint256,uint256)")]
+    pub struct AssertLt0Call(
+        pub ::corebc_core::types::U256,
+        pub ::corebc_core::types::U256,
+    );
+    ///Container type for all input parameters for the `assertLt` function with signature `assertLt(uint256,uint256,string)` and selector `0xb8ab38ed`
+    #[derive(
+        Clone,
+        ::corebc_contract::EthCall,
+        ::corebc_contract::EthDisplay,
+        Default,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash
+    )]
+    #[ethcall(name = "assertLt", abi = "assertLt(uint256,uint256,string)")]
+    pub struct AssertLt2Call(
+        pub ::corebc_core::types::U256,
+        pub ::corebc_core::types::U256,
+        pub ::std::string::String,
+    );
+    ///Container type for all input parameters for the `assertLt` function with signature `assertLt(int256,int256)` and selector `0xb620afc1`
+    #[derive(
+        Clone,
+        ::corebc_contract::EthCall,
+        ::corebc_contract::EthDisplay,
+        Default,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash
+    )]
+    #[ethcall(name = "assertLt", abi = "assertLt(int256,int256)")]
+    pub struct AssertLt1Call(
+        pub ::corebc_core::types::I256,
+        pub ::corebc_core::types::I256,
+    );
+    ///Container type for all input parameters for the `assertLt` function with signature `assertLt(int256,int256,string)` and selector `0x83c3558e`
+    #[derive(
+        Clone,
+        ::corebc_contract::EthCall,
+        ::corebc_contract::EthDisplay,
+        Default,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash
+    )]
+    #[ethcall(name = "assertLt", abi = "assertLt(int256,int256,string)")]
+    pub struct AssertLt3Call(
+        pub ::corebc_core::types::I256,
+        pub ::corebc_core::types::I256,
+        pub ::std::string::String,
+    );
+    ///Container type for all input parameters for the `assertNotEq` function with signature `assertNotEq(bool,bool)` and selector `0x0f8131b1`
+    #[derive(
+        Clone,
+        ::corebc_contract::EthCall,
+        ::corebc_contract::EthDisplay,
+        Default,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash
+    )]
+    #[ethcall(name = "assertNotEq", abi = "assertNotEq(bool,bool)")]
+    pub struct AssertNotEq0Call(pub bool, pub bool);
+    ///Container type for all input parameters for the `assertNotEq` function with signature `assertNotEq(bool,bool,string)` and selector `0xfc47da4e`
+    #[derive(
+        Clone,
+        ::corebc_contract::EthCall,
+        ::corebc_contract::EthDisplay,
+        Default,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash
+    )]
+    #[ethcall(name = "assertNotEq", abi = "assertNotEq(bool,bool,string)")]
+    pub struct AssertNotEq14Call(pub bool, pub bool, pub ::std::string::String);
+    ///Container type for all input parameters for the `assertNotEq` function with signature `assertNotEq(uint256,uint256)` and selector `0x72c072b8`
+    #[derive(
+        Clone,
+        ::corebc_contract::EthCall,
+        ::corebc_contract::EthDisplay,
+        Default,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash
+    )]
+    #[ethcall(name = "assertNotEq", abi = "assertNotEq(uint256,uint256)")]
+    pub struct AssertNotEq1Call(
+        pub ::corebc_core::types::U256,
+        pub ::corebc_core::types::U256,
+    );
+    ///Container type for all input parameters for the `assertNotEq` function with signature `assertNotEq(uint256,uint256,string)` and selector `0x5707fb9a`
+    #[derive(
+        Clone,
+        ::corebc_contract::EthCall,
+        ::corebc_contract::EthDisplay,
+        Default,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash
+    )]
+    #[ethcall(name = "assertNotEq", abi = "assertNotEq(uint256,uint256,string)")]
+    pub struct AssertNotEq15Call(
+        pub ::corebc_core::types::U256,
+        pub ::corebc_core::types::U256,
+        pub ::std::string::String,
+    );
+    ///Container type for all input parameters for the `assertNotEq` function with signature `assertNotEq(int256,int256)` and selector `0xdfee9455`
+    #[derive(
+        Clone,
+        ::corebc_contract::EthCall,
+        ::corebc_contract::EthDisplay,
+        Default,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash
+    )]
+    #[ethcall(name = "assertNotEq", abi = "assertNotEq(int256,int256)")]
+    pub struct AssertNotEq2Call(
+        pub ::corebc_core::types::I256,
+        pub ::corebc_core::types::I256,
+    );
+    ///Container type for all input parameters for the `assertNotEq` function with signature `assertNotEq(int256,int256,string)` and selector `0x748fd654`
+    #[derive(
+        Clone,
+        ::corebc_contract::EthCall,
+        ::corebc_contract::EthDisplay,
+        Default,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash
+    )]
+    #[ethcall(name = "assertNotEq", abi = "assertNotEq(int256,int256,string)")]
+    pub struct AssertNotEq16Call(
+        pub ::corebc_core::types::I256,
+        pub ::corebc_core::types::I256,
+        pub ::std::string::String,
+    );
+    ///Container type for all input parameters for the `assertNotEq` function with signature `assertNotEq(address,address)` and selector `0x279f81b5`
+    #[derive(
+        Clone,
+        ::corebc_contract::EthCall,
+        ::corebc_contract::EthDisplay,
+        Default,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash
+    )]
+    #[ethcall(name = "assertNotEq", abi = "assertNotEq(address,address)")]
+    pub struct AssertNotEq3Call(
+        pub ::corebc_core::types::Address,
+        pub ::corebc_core::types::Address,
+    );
+    ///Container type for all input parameters for the `assertNotEq` function with signature `assertNotEq(address,address,string)` and selector `0xd5fd7ffd`
+    #[derive(
+        Clone,
+        ::corebc_contract::EthCall,
+        ::corebc_contract::EthDisplay,
+        Default,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash
+    )]
+    #[ethcall(name = "assertNotEq", abi = "assertNotEq(address,address,string)")]
+    pub struct AssertNotEq17Call(
+        pub ::corebc_core::types::Address,
+        pub ::corebc_core::types::Address,
+        pub ::std::string::String,
+    );
+    ///Container type for all input parameters for the `assertNotEq` function with signature `assertNotEq(bytes32,bytes32)` and selector `0x514f0b1e`
+    #[derive(
+        Clone,
+        ::corebc_contract::EthCall,
+        ::corebc_contract::EthDisplay,
+        Default,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash
+    )]
+    #[ethcall(name = "assertNotEq", abi = "assertNotEq(bytes32,bytes32)")]
+    pub struct AssertNotEq4Call(pub [u8; 32], pub [u8; 32]);
+    ///Container type for all input parameters for the `assertNotEq` function with signature `assertNotEq(bytes32,bytes32,string)` and selector `0x1bb8570a`
+    #[derive(
+        Clone,
+        ::corebc_contract::EthCall,
+        ::corebc_contract::EthDisplay,
+        Default,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash
+    )]
+    #[ethcall(name = "assertNotEq", abi = "assertNotEq(bytes32,bytes32,string)")]
+    pub struct AssertNotEq18Call(pub [u8; 32], pub [u8; 32], pub ::std::string::String);
+    ///Container type for all input parameters for the `assertNotEq` function with signature `assertNotEq(string,string)` and selector `0xe1c4732e`
+    #[derive(
+        Clone,
+        ::corebc_contract::EthCall,
+        ::corebc_contract::EthDisplay,
+        Default,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash
+    )]
+    #[ethcall(name = "assertNotEq", abi = "assertNotEq(string,string)")]
+    pub struct AssertNotEq5Call(pub ::std::string::String, pub ::std::string::String);
+    ///Container type for all input parameters for the `assertNotEq` function with signature `assertNotEq(string,string,string)` and selector `0x4d384bce`
+    #[derive(
+        Clone,
+        ::corebc_contract::EthCall,
+        ::corebc_contract::EthDisplay,
+        Default,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash
+    )]
+    #[ethcall(name = "assertNotEq", abi = "assertNotEq(string,string,string)")]
+    pub struct AssertNotEq19Call(
+        pub ::std::string::String,
+        pub ::std::string::String,
+        pub ::std::string::String,
+    );
+    ///Container type for all input parameters for the `assertNotEq` function with signature `assertNotEq(bytes,bytes)` and selector `0xed9e518d`
+    #[derive(
+        Clone,
+        ::corebc_contract::EthCall,
+        ::corebc_contract::EthDisplay,
+        Default,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash
+    )]
+    #[ethcall(name = "assertNotEq", abi = "assertNotEq(bytes,bytes)")]
+    pub struct AssertNotEq6Call(
+        pub ::corebc_core::types::Bytes,
+        pub ::corebc_core::types::Bytes,
+    );
+    ///Container type for all input parameters for the `assertNotEq` function with signature `assertNotEq(bytes,bytes,string)` and selector `0x7ccb7ce7`
+    #[derive(
+        Clone,
+        ::corebc_contract::EthCall,
+        ::corebc_contract::EthDisplay,
+        Default,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash
+    )]
+    #[ethcall(name = "assertNotEq", abi = "assertNotEq(bytes,bytes,string)")]
+    pub struct AssertNotEq20Call(
+        pub ::corebc_core::types::Bytes,
+        pub ::corebc_core::types::Bytes,
+        pub ::std::string::String,
+    );
+    ///Container type for all input parameters for the `assertNotEq` function with signature `assertNotEq(bool[],bool[])` and selector `0xabd365bd`
+    #[derive(
+        Clone,
+        ::corebc_contract::EthCall,
+        ::corebc_contract::EthDisplay,
+        Default,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash
+    )]
+    #[ethcall(name = "assertNotEq", abi = "assertNotEq(bool[],bool[])")]
+    pub struct AssertNotEq7Call(pub ::std::vec::Vec<bool>, pub ::std::vec::Vec<bool>);
+    ///Container type for all input parameters for the `assertNotEq` function with signature `assertNotEq(bool[],bool[],string)` and selector `0xb5017af6`
+    #[derive(
+        Clone,
+        ::corebc_contract::EthCall,
+        ::corebc_contract::EthDisplay,
+        Default,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash
+    )]
+    #[ethcall(name = "assertNotEq", abi = "assertNotEq(bool[],bool[],string)")]
+    pub struct AssertNotEq21Call(
+        pub ::std::vec::Vec<bool>,
+        pub ::std::vec::Vec<bool>,
+        pub ::std::string::String,
+    );
+    ///Container type for all input parameters for the `assertNotEq` function with signature `assertNotEq(uint256[],uint256[])` and selector `0x3c265062`
+    #[derive(
+        Clone,
+        ::corebc_contract::EthCall,
+        ::corebc_contract::EthDisplay,
+        Default,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash
+    )]
+    #[ethcall(name = "assertNotEq", abi = "assertNotEq(uint256[],uint256[])")]
+    pub struct AssertNotEq8Call(
+        pub ::std::vec::Vec<::corebc_core::types::U256>,
+        pub ::std::vec::Vec<::corebc_core::types::U256>,
+    );
+    ///Container type for all input parameters for the `assertNotEq` function with signature `assertNotEq(uint256[],uint256[],string)` and selector `0x279d32ca`
+    #[derive(
+        Clone,
+        ::corebc_contract::EthCall,
+        ::corebc_contract::EthDisplay,
+        Default,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash
+    )]
+    #[ethcall(name = "assertNotEq", abi = "assertNotEq(uint256[],uint256[],string)")]
+    pub struct AssertNotEq22Call(
+        pub ::std::vec::Vec<::corebc_core::types::U256>,
+        pub ::std::vec::Vec<::corebc_core::types::U256>,
+        pub ::std::string::String,
+    );
+    ///Container type for all input parameters for the `assertNotEq` function with signature `assertNotEq(int256[],int256[])` and selector `0xfb1d326b`
+    #[derive(
+        Clone,
+        ::corebc_contract::EthCall,
+        ::corebc_contract::EthDisplay,
+        Default,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash
+    )]
+    #[ethcall(name = "assertNotEq", abi = "assertNotEq(int256[],int256[])")]
+    pub struct AssertNotEq9Call(
+        pub ::std::vec::Vec<::corebc_core::types::I256>,
+        pub ::std::vec::Vec<::corebc_core::types::I256>,
+    );
+    ///Container type for all input parameters for the `assertNotEq` function with signature `assertNotEq(int256[],int256[],string)` and selector `0xb2506242`
+    #[derive(
+        Clone,
+        ::corebc_contract::EthCall,
+        ::corebc_contract::EthDisplay,
+        Default,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash
+    )]
+    #[ethcall(name = "assertNotEq", abi = "assertNotEq(int256[],int256[],string)")]
+    pub struct AssertNotEq23Call(
+        pub ::std::vec::Vec<::corebc_core::types::I256>,
+        pub ::std::vec::Vec<::corebc_core::types::I256>,
+        pub ::std::string::String,
+    );
+    ///Container type for all input parameters for the `assertNotEq` function with signature `assertNotEq(address[],address[])` and selector `0x059bf9b3`
+    #[derive(
+        Clone,
+        ::corebc_contract::EthCall,
+        ::corebc_contract::EthDisplay,
+        Default,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash
+    )]
+    #[ethcall(name = "assertNotEq", abi = "assertNotEq(address[],address[])")]
+    pub struct AssertNotEq10Call(
+        pub ::std::vec::Vec<::corebc_core::types::Address>,
+        pub ::std::vec::Vec<::corebc_core::types::Address>,
+    );
+    ///Container type for all input parameters for the `assertNotEq` function with signature `assertNotEq(address[],address[],string)` and selector `0xd6a76e86`
+    #[derive(
+        Clone,
+        ::corebc_contract::EthCall,
+        ::corebc_contract::EthDisplay,
+        Default,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash
+    )]
+    #[ethcall(name = "assertNotEq", abi = "assertNotEq(address[],address[],string)")]
+    pub struct AssertNotEq24Call(
+        pub ::std::vec::Vec<::corebc_core::types::Address>,
+        pub ::std::vec::Vec<::corebc_core::types::Address>,
+        pub ::std::string::String,
+    );
+    ///Container type for all input parameters for the `assertNotEq` function with signature `assertNotEq(bytes32[],bytes32[])` and selector `0x1251e044`
+    #[derive(
+        Clone,
+        ::corebc_contract::EthCall,
+        ::corebc_contract::EthDisplay,
+        Default,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash
+    )]
+    #[ethcall(name = "assertNotEq", abi = "assertNotEq(bytes32[],bytes32[])")]
+    pub struct AssertNotEq11Call(
+        pub ::std::vec::Vec<[u8; 32]>,
+        pub ::std::vec::Vec<[u8; 32]>,
+    );
+    ///Container type for all input parameters for the `assertNotEq` function with signature `assertNotEq(bytes32[],bytes32[],string)` and selector `0x064f004a`
+    #[derive(
+        Clone,
+        ::corebc_contract::EthCall,
+        ::corebc_contract::EthDisplay,
+        Default,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash
+    )]
+    #[ethcall(name = "assertNotEq", abi = "assertNotEq(bytes32[],bytes32[],string)")]
+    pub struct AssertNotEq25Call(
+        pub ::std::vec::Vec<[u8; 32]>,
+        pub ::std::vec::Vec<[u8; 32]>,
+        pub ::std::string::String,
+    );
+    ///Container type for all input parameters for the `assertNotEq` function with signature `assertNotEq(string[],string[])` and selector `0x50b8aa54`
+    #[derive(
+        Clone,
+        ::corebc_contract::EthCall,
+        ::corebc_contract::EthDisplay,
+        Default,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash
+    )]
+    #[ethcall(name = "assertNotEq", abi = "assertNotEq(string[],string[])")]
+    pub struct AssertNotEq12Call(
+        pub ::std::vec::Vec<::std::string::String>,
+        pub ::std::vec::Vec<::std::string::String>,
+    );
+    ///Container type for all input parameters for the `assertNotEq` function with signature `assertNotEq(string[],string[],string)` and selector `0xa5e08ee7`
+    #[derive(
+        Clone,
+        ::corebc_contract::EthCall,
+        ::corebc_contract::EthDisplay,
+        Default,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash
+    )]
+    #[ethcall(name = "assertNotEq", abi = "assertNotEq(string[],string[],string)")]
+    pub struct AssertNotEq26Call(
+        pub ::std::vec::Vec<::std::string::String>,
+        pub ::std::vec::Vec<::std::string::String>,
+        pub ::std::string::String,
+    );
+    ///Container type for all input parameters for the `assertNotEq` function with signature `assertNotEq(bytes[],bytes[])` and selector `0x000326b4`
+    #[derive(
+        Clone,
+        ::corebc_contract::EthCall,
+        ::corebc_contract::EthDisplay,
+        Default,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash
+    )]
+    #[ethcall(name = "assertNotEq", abi = "assertNotEq(bytes[],bytes[])")]
+    pub struct AssertNotEq13Call(
+        pub ::std::vec::Vec<::corebc_core::types::Bytes>,
+        pub ::std::vec::Vec<::corebc_core::types::Bytes>,
+    );
+    ///Container type for all input parameters for the `assertNotEq` function with signature `assertNotEq(bytes[],bytes[],string)` and selector `0x2204fe9d`
+    #[derive(
+        Clone,
+        ::corebc_contract::EthCall,
+        ::corebc_contract::EthDisplay,
+        Default,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash
+    )]
+    #[ethcall(name = "assertNotEq", abi = "assertNotEq(bytes[],bytes[],string)")]
+    pub struct AssertNotEq27Call(
+        pub ::std::vec::Vec<::corebc_core::types::Bytes>,
+        pub ::std::vec::Vec<::corebc_core::types::Bytes>,
+        pub ::std::string::String,
+    );
+    ///Container type for all input parameters for the `assertTrue` function with signature `assertTrue(bool)` and selector `0xf31a90ab`
+    #[derive(
+        Clone,
+        ::corebc_contract::EthCall,
+        ::corebc_contract::EthDisplay,
+        Default,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash
+    )]
+    #[ethcall(name = "assertTrue", abi = "assertTrue(bool)")]
+    pub struct AssertTrue0Call(pub bool);
+    ///Container type for all input parameters for the `assertTrue` function with signature `assertTrue(bool,string)` and selector `0x41d7e0a6`
+    #[derive(
+        Clone,
+        ::corebc_contract::EthCall,
+        ::corebc_contract::EthDisplay,
+        Default,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash
+    )]
+    #[ethcall(name = "assertTrue", abi = "assertTrue(bool,string)")]
+    pub struct AssertTrue1Call(pub bool, pub ::std::string::String);
+    ///Container type for all input parameters for the `assume` function with signature `assume(bool)` and selector `0xca480fa9`
+    #[derive(
+        Clone,
+        ::corebc_contract::EthCall,
+        ::corebc_contract::EthDisplay,
+        Default,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash
+    )]
+    #[ethcall(name = "assume", abi = "assume(bool)")]
+    pub struct AssumeCall(pub bool);
+    ///Container type for all input parameters for the `breakpoint` function with signature `breakpoint(string)` and selector `0x3af777a9`
+    #[derive(
+        Clone,
+        ::corebc_contract::EthCall,
+        ::corebc_contract::EthDisplay,
+        Default,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash
+    )]
+    #[ethcall(name = "breakpoint", abi = "breakpoint(string)")]
+    pub struct Breakpoint0Call(pub ::std::string::String);
+    ///Container type for all input parameters for the `breakpoint` function with signature `breakpoint(string,bool)` and selector `0x18af918f`
+    #[derive(
+        Clone,
+        ::corebc_contract::EthCall,
+        ::corebc_contract::EthDisplay,
+        Default,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash
+    )]
+    #[ethcall(name = "breakpoint", abi = "breakpoint(string,bool)")]
+    pub struct Breakpoint1Call(pub ::std::string::String, pub bool);
+    ///Container type for all input parameters for the `broadcast` function with signature `broadcast()` and selector `0x12f46a0c`
+    #[derive(
+        Clone,
+        ::corebc_contract::EthCall,
+        ::corebc_contract::EthDisplay,
+        Default,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash
+    )]
+    #[ethcall(name = "broadcast", abi = "broadcast()")]
+    pub struct Broadcast0Call;
+    ///Container type for all input parameters for the `broadcast` function with signature `broadcast(address)` and selector `0x3aec5261`
+    #[derive(
+        Clone,
+        ::corebc_contract::EthCall,
+        ::corebc_contract::EthDisplay,
+        Default,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash
+    )]
+    #[ethcall(name = "broadcast", abi = "broadcast(address)")]
+    pub struct Broadcast1Call(pub ::corebc_core::types::Address);
+    ///Container type for all input parameters for the `broadcast` function with signature `broadcast(string)` and selector `0x85b0a416`
+    #[derive(
+        Clone,
+        ::corebc_contract::EthCall,
+        ::corebc_contract::EthDisplay,
+        Default,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash
+    )]
+    #[ethcall(name = "broadcast", abi = "broadcast(string)")]
+    pub struct Broadcast2Call(pub ::std::string::String);
+    ///Container type for all input parameters for the `chainId` function with signature `chainId(uint256)` and selector `0x5858cd87`
+    #[derive(
+        Clone,
+        ::corebc_contract::EthCall,
+        ::corebc_contract::EthDisplay,
+        Default,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash
+    )]
+    #[ethcall(name = "chainId", abi = "chainId(uint256)")]
+    pub struct ChainIdCall(pub ::corebc_core::types::U256);
+    ///Container type for all input parameters for the `clearMockedCalls` function with signature `clearMockedCalls()` and selector `0x7eaff7de`
+    #[derive(
+        Clone,
+        ::corebc_contract::EthCall,
+        ::corebc_contract::EthDisplay,
+        Default,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash
+    )]
+    #[ethcall(name = "clearMockedCalls", abi = "clearMockedCalls()")]
+    pub struct ClearMockedCallsCall;
+    ///Container type for all input parameters for the `closeFile` function with signature `closeFile(string)` and selector `0xa3a9f387`
+    #[derive(
+        Clone,
+        ::corebc_contract::EthCall,
+        ::corebc_contract::EthDisplay,
+        Default,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash
+    )]
+    #[ethcall(name = "closeFile", abi = "closeFile(string)")]
+    pub struct CloseFileCall(pub ::std::string::String);
+    ///Container type for all input parameters for the `coinbase` function with signature `coinbase(address)` and selector `0x53a5c6de`
+    #[derive(
+        Clone,
+        ::corebc_contract::EthCall,
+        ::corebc_contract::EthDisplay,
+        Default,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash
+    )]
+    #[ethcall(name = "coinbase", abi = "coinbase(address)")]
+    pub struct CoinbaseCall(pub ::corebc_core::types::Address);
+    ///Container type for all input parameters for the `createDir` function with signature `createDir(string,bool)` and selector `0x8b295957`
+    #[derive(
+        Clone,
+        ::corebc_contract::EthCall,
+        ::corebc_contract::EthDisplay,
+        Default,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash
+    )]
+    #[ethcall(name = "createDir", abi = "createDir(string,bool)")]
+    pub struct CreateDirCall(pub ::std::string::String, pub bool);
+    ///Container type for all input parameters for the `createFork` function with signature `createFork(string,uint256)` and selector `0x27dc0128`
+    #[derive(
+        Clone,
+        ::corebc_contract::EthCall,
+        ::corebc_contract::EthDisplay,
+        Default,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash
+    )]
+    #[ethcall(name = "createFork", abi = "createFork(string,uint256)")]
+    pub struct CreateFork1Call(
+        pub ::std::string::String,
+        pub ::corebc_core::types::U256,
+    );
+    ///Container type for all input parameters for the `createFork` function with signature `createFork(string,bytes32)` and selector `0x472ee61e`
+    #[derive(
+        Clone,
+        ::corebc_contract::EthCall,
+        ::corebc_contract::EthDisplay,
+        Default,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash
+    )]
+    #[ethcall(name = "createFork", abi = "createFork(string,bytes32)")]
+    pub struct CreateFork2Call(pub ::std::string::String, pub [u8; 32]);
+    ///Container type for all input parameters for the `createFork` function with signature `createFork(string)` and selector `0xc8282be0`
+    #[derive(
+        Clone,
+        ::corebc_contract::EthCall,
+        ::corebc_contract::EthDisplay,
+        Default,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash
+    )]
+    #[ethcall(name = "createFork", abi = "createFork(string)")]
+    pub struct CreateFork0Call(pub ::std::string::String);
+    ///Container type for all input parameters for the `createSelectFork` function with signature `createSelectFork(string,uint256)` and selector `0xcc15574b`
+    #[derive(
+        Clone,
+        ::corebc_contract::EthCall,
+        ::corebc_contract::EthDisplay,
+        Default,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash
+    )]
+    #[ethcall(name = "createSelectFork", abi = "createSelectFork(string,uint256)")]
+    pub struct CreateSelectFork1Call(
+        pub ::std::string::String,
+        pub ::corebc_core::types::U256,
+    );
+    ///Container type for all input parameters for the `createSelectFork` function with signature `createSelectFork(string,bytes32)` and selector `0x47cbbd7a`
+    #[derive(
+        Clone,
+        ::corebc_contract::EthCall,
+        ::corebc_contract::EthDisplay,
+        Default,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash
+    )]
+    #[ethcall(name = "createSelectFork", abi = "createSelectFork(string,bytes32)")]
+    pub struct CreateSelectFork2Call(pub ::std::string::String, pub [u8; 32]);
+    ///Container type for all input parameters for the `createSelectFork` function with signature `createSelectFork(string)` and selector `0x6bcf2c60`
+    #[derive(
+        Clone,
+        ::corebc_contract::EthCall,
+        ::corebc_contract::EthDisplay,
+        Default,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash
+    )]
+    #[ethcall(name = "createSelectFork", abi = "createSelectFork(string)")]
+    pub struct CreateSelectFork0Call(pub ::std::string::String);
+    ///Container type for all input parameters for the `createWallet` function with signature `createWallet(string)` and selector `0xbfbf2050`
+    #[derive(
+        Clone,
+        ::corebc_contract::EthCall,
+        ::corebc_contract::EthDisplay,
+        Default,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash
+    )]
+    #[ethcall(name = "createWallet", abi = "createWallet(string)")]
+    pub struct CreateWalletCall(pub ::std::string::String);
+    ///Container type for all input parameters for the `deal` function with signature `deal(address,uint256)` and selector `0x5ae137d7`
+    #[derive(
+        Clone,
+        ::corebc_contract::EthCall,
+        ::corebc_contract::EthDisplay,
+        Default,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash
+    )]
+    #[ethcall(name = "deal", abi = "deal(address,uint256)")]
+    pub struct DealCall(
+        pub ::corebc_core::types::Address,
+        pub ::corebc_core::types::U256,
+    );
+    ///Container type for all input parameters for the `deriveKey` function with signature `deriveKey(string,uint32)` and selector `0xf6f7e9e1`
+    #[derive(
+        Clone,
+        ::corebc_contract::EthCall,
+        ::corebc_contract::EthDisplay,
+        Default,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash
+    )]
+    #[ethcall(name = "deriveKey", abi = "deriveKey(string,uint32)")]
+    pub struct DeriveKey0Call(pub ::std::string::String, pub u32);
+    ///Container type for all input parameters for the `deriveKey` function with signature `deriveKey(string,string,uint32)` and selector `0xd5a72210`
+    #[derive(
+        Clone,
+        ::corebc_contract::EthCall,
+        ::corebc_contract::EthDisplay,
+        Default,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash
+    )]
+    #[ethcall(name = "deriveKey", abi = "deriveKey(string,string,uint32)")]
+    pub struct DeriveKey1Call(
+        pub ::std::string::String,
+        pub ::std::string::String,
+        pub u32,
+    );
+    ///Container type for all input parameters for the `deriveKey` function with signature `deriveKey(string,uint32,string)` and selector `0xee602b67`
+    #[derive(
+        Clone,
+        ::corebc_contract::EthCall,
+        ::corebc_contract::EthDisplay,
+        Default,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash
+    )]
+    #[ethcall(name = "deriveKey", abi = "deriveKey(string,uint32,string)")]
+    pub struct DeriveKey2Call(
+        pub ::std::string::String,
+        pub u32,
+        pub ::std::string::String,
+    );
+    ///Container type for all input parameters for the `deriveKey` function with signature `deriveKey(string,string,uint32,string)` and selector `0xcc819384`
+    #[derive(
+        Clone,
+        ::corebc_contract::EthCall,
+        ::corebc_contract::EthDisplay,
+        Default,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash
+    )]
+    #[ethcall(name = "deriveKey", abi = "deriveKey(string,string,uint32,string)")]
+    pub struct DeriveKey3Call(
+        pub ::std::string::String,
+        pub ::std::string::String,
+        pub u32,
+        pub ::std::string::String,
+    );
+    ///Container type for all input parameters for the `difficulty` function with signature `difficulty(uint256)` and selector `0xfc75ba15`
+    #[derive(
+        Clone,
+        ::corebc_contract::EthCall,
+        ::corebc_contract::EthDisplay,
+        Default,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash
+    )]
+    #[ethcall(name = "difficulty", abi = "difficulty(uint256)")]
+    pub struct DifficultyCall(pub ::corebc_core::types::U256);
+    ///Container type for all input parameters for the `envAddress` function with signature `envAddress(string)` and selector `0x5992c5c6`
+    #[derive(
+        Clone,
+        ::corebc_contract::EthCall,
+        ::corebc_contract::EthDisplay,
+        Default,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash
+    )]
+    #[ethcall(name = "envAddress", abi = "envAddress(string)")]
+    pub struct EnvAddress0Call(pub ::std::string::String);
+    ///Container type for all input parameters for the `envAddress` function with signature `envAddress(string,string)` and selector `0xdd088083`
+    #[derive(
+        Clone,
+        ::corebc_contract::EthCall,
+        ::corebc_contract::EthDisplay,
+        Default,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash
+    )]
+    #[ethcall(name = "envAddress", abi = "envAddress(string,string)")]
+    pub struct EnvAddress1Call(pub ::std::string::String, pub ::std::string::String);
+    ///Container type for all input parameters for the `envBool` function with signature `envBool(string)` and selector `0xfd40bd11`
     #[derive(
         Clone,
         ::corebc_contract::EthCall,
@@ -2005,9 +5157,9 @@ pub mod hevm {
         Eq,
         Hash
     )]
-    #[ethcall(name = "accesses", abi = "accesses(address)")]
-    pub struct AccessesCall(pub ::corebc_core::types::Address);
-    ///Container type for all input parameters for the `activeFork` function with signature `activeFork()` and selector `0x2a978c58`
+    #[ethcall(name = "envBool", abi = "envBool(string)")]
+    pub struct EnvBool0Call(pub ::std::string::String);
+    ///Container type for all input parameters for the `envBool` function with signature `envBool(string,string)` and selector `0xdf739509`
     #[derive(
         Clone,
         ::corebc_contract::EthCall,
@@ -2018,9 +5170,9 @@ pub mod hevm {
         Eq,
         Hash
     )]
-    #[ethcall(name = "activeFork", abi = "activeFork()")]
-    pub struct ActiveForkCall;
-    ///Container type for all input parameters for the `addr` function with signature `addr(string)` and selector `0xf91daf04`
+    #[ethcall(name = "envBool", abi = "envBool(string,string)")]
+    pub struct EnvBool1Call(pub ::std::string::String, pub ::std::string::String);
+    ///Container type for all input parameters for the `envBytes` function with signature `envBytes(string)` and selector `0xb1676ba0`
     #[derive(
         Clone,
         ::corebc_contract::EthCall,
@@ -2031,9 +5183,9 @@ pub mod hevm {
         Eq,
         Hash
     )]
-    #[ethcall(name = "addr", abi = "addr(string)")]
-    pub struct AddrCall(pub ::std::string::String);
-    ///Container type for all input parameters for the `allowCheatcodes` function with signature `allowCheatcodes(address)` and selector `0x21bd8ec4`
+    #[ethcall(name = "envBytes", abi = "envBytes(string)")]
+    pub struct EnvBytes0Call(pub ::std::string::String);
+    ///Container type for all input parameters for the `envBytes` function with signature `envBytes(string,string)` and selector `0xd9577bb1`
     #[derive(
         Clone,
         ::corebc_contract::EthCall,
@@ -2044,9 +5196,9 @@ pub mod hevm {
         Eq,
         Hash
     )]
-    #[ethcall(name = "allowCheatcodes", abi = "allowCheatcodes(address)")]
-    pub struct AllowCheatcodesCall(pub ::corebc_core::types::Address);
-    ///Container type for all input parameters for the `assume` function with signature `assume(bool)` and selector `0xca480fa9`
+    #[ethcall(name = "envBytes", abi = "envBytes(string,string)")]
+    pub struct EnvBytes1Call(pub ::std::string::String, pub ::std::string::String);
+    ///Container type for all input parameters for the `envBytes32` function with signature `envBytes32(string)` and selector `0x2d261418`
     #[derive(
         Clone,
         ::corebc_contract::EthCall,
@@ -2057,9 +5209,9 @@ pub mod hevm {
         Eq,
         Hash
     )]
-    #[ethcall(name = "assume", abi = "assume(bool)")]
-    pub struct AssumeCall(pub bool);
-    ///Container type for all input parameters for the `breakpoint` function with signature `breakpoint(string)` and selector `0x3af777a9`
+    #[ethcall(name = "envBytes32", abi = "envBytes32(string)")]
+    pub struct EnvBytes320Call(pub ::std::string::String);
+    ///Container type for all input parameters for the `envBytes32` function with signature `envBytes32(string,string)` and selector `0x5c1826d3`
     #[derive(
         Clone,
         ::corebc_contract::EthCall,
@@ -2070,9 +5222,9 @@ pub mod hevm {
         Eq,
         Hash
     )]
-    #[ethcall(name = "breakpoint", abi = "breakpoint(string)")]
-    pub struct Breakpoint0Call(pub ::std::string::String);
-    ///Container type for all input parameters for the `breakpoint` function with signature `breakpoint(string,bool)` and selector `0x18af918f`
+    #[ethcall(name = "envBytes32", abi = "envBytes32(string,string)")]
+    pub struct EnvBytes321Call(pub ::std::string::String, pub ::std::string::String);
+    ///Container type for all input parameters for the `envInt` function with signature `envInt(string)` and selector `0xdba2cad8`
     #[derive(
         Clone,
         ::corebc_contract::EthCall,
@@ -2083,9 +5235,9 @@ pub mod hevm {
         Eq,
         Hash
     )]
-    #[ethcall(name = "breakpoint", abi = "breakpoint(string,bool)")]
-    pub struct Breakpoint1Call(pub ::std::string::String, pub bool);
-    ///Container type for all input parameters for the `broadcast` function with signature `broadcast()` and selector `0x12f46a0c`
+    #[ethcall(name = "envInt", abi = "envInt(string)")]
+    pub struct EnvInt0Call(pub ::std::string::String);
+    ///Container type for all input parameters for the `envInt` function with signature `envInt(string,string)` and selector `0x347968b0`
     #[derive(
         Clone,
         ::corebc_contract::EthCall,
@@ -2096,9 +5248,9 @@ pub mod hevm {
         Eq,
         Hash
     )]
-    #[ethcall(name = "broadcast", abi = "broadcast()")]
-    pub struct Broadcast0Call;
-    ///Container type for all input parameters for the `broadcast` function with signature `broadcast(address)` and selector `0x3aec5261`
+    #[ethcall(name = "envInt", abi = "envInt(string,string)")]
+    pub struct EnvInt1Call(pub ::std::string::String, pub ::std::string::String);
+    ///Container type for all input parameters for the `envOr` function with signature `envOr(string,bool)` and selector `0x8efd04a9`
     #[derive(
         Clone,
         ::corebc_contract::EthCall,
@@ -2109,9 +5261,9 @@ pub mod hevm {
         Eq,
         Hash
     )]
-    #[ethcall(name = "broadcast", abi = "broadcast(address)")]
-    pub struct Broadcast1Call(pub ::corebc_core::types::Address);
-    ///Container type for all input parameters for the `broadcast` function with signature `broadcast(string)` and selector `0x85b0a416`
+    #[ethcall(name = "envOr", abi = "envOr(string,bool)")]
+    pub struct EnvOr0Call(pub ::std::string::String, pub bool);
+    ///Container type for all input parameters for the `envOr` function with signature `envOr(string,uint256)` and selector `0x1c6db43b`
     #[derive(
         Clone,
         ::corebc_contract::EthCall,
@@ -2122,9 +5274,9 @@ pub mod hevm {
         Eq,
         Hash
     )]
-    #[ethcall(name = "broadcast", abi = "broadcast(string)")]
-    pub struct Broadcast2Call(pub ::std::string::String);
-    ///Container type for all input parameters for the `chainId` function with signature `chainId(uint256)` and selector `0x5858cd87`
+    #[ethcall(name = "envOr", abi = "envOr(string,uint256)")]
+    pub struct EnvOr1Call(pub ::std::string::String, pub ::corebc_core::types::U256);
+    ///Container type for all input parameters for the `envOr` function with signature `envOr(string,int256)` and selector `0x35711e3f`
     #[derive(
         Clone,
         ::corebc_contract::EthCall,
@@ -2135,9 +5287,9 @@ pub mod hevm {
         Eq,
         Hash
     )]
-    #[ethcall(name = "chainId", abi = "chainId(uint256)")]
-    pub struct ChainIdCall(pub ::corebc_core::types::U256);
-    ///Container type for all input parameters for the `clearMockedCalls` function with signature `clearMockedCalls()` and selector `0x7eaff7de`
+    #[ethcall(name = "envOr", abi = "envOr(string,int256)")]
+    pub struct EnvOr2Call(pub ::std::string::String, pub ::corebc_core::types::I256);
+    ///Container type for all input parameters for the `envOr` function with signature `envOr(string,address)` and selector `0x18745f8c`
     #[derive(
         Clone,
         ::corebc_contract::EthCall,
@@ -2148,9 +5300,9 @@ pub mod hevm {
         Eq,
         Hash
     )]
-    #[ethcall(name = "clearMockedCalls", abi = "clearMockedCalls()")]
-    pub struct ClearMockedCallsCall;
-    ///Container type for all input parameters for the `closeFile` function with signature `closeFile(string)` and selector `0xa3a9f387`
+    #[ethcall(name = "envOr", abi = "envOr(string,address)")]
+    pub struct EnvOr3Call(pub ::std::string::String, pub ::corebc_core::types::Address);
+    ///Container type for all input parameters for the `envOr` function with signature `envOr(string,bytes32)` and selector `0xcb48937a`
     #[derive(
         Clone,
         ::corebc_contract::EthCall,
@@ -2161,9 +5313,9 @@ pub mod hevm {
         Eq,
         Hash
     )]
-    #[ethcall(name = "closeFile", abi = "closeFile(string)")]
-    pub struct CloseFileCall(pub ::std::string::String);
-    ///Container type for all input parameters for the `coinbase` function with signature `coinbase(address)` and selector `0x53a5c6de`
+    #[ethcall(name = "envOr", abi = "envOr(string,bytes32)")]
+    pub struct EnvOr4Call(pub ::std::string::String, pub [u8; 32]);
+    ///Container type for all input parameters for the `envOr` function with signature `envOr(string,string)` and selector `0x42d1d0ef`
     #[derive(
         Clone,
         ::corebc_contract::EthCall,
@@ -2174,9 +5326,9 @@ pub mod hevm {
         Eq,
         Hash
     )]
-    #[ethcall(name = "coinbase", abi = "coinbase(address)")]
-    pub struct CoinbaseCall(pub ::corebc_core::types::Address);
-    ///Container type for all input parameters for the `createDir` function with signature `createDir(string,bool)` and selector `0x8b295957`
+    #[ethcall(name = "envOr", abi = "envOr(string,string)")]
+    pub struct EnvOr5Call(pub ::std::string::String, pub ::std::string::String);
+    ///Container type for all input parameters for the `envOr` function with signature `envOr(string,bytes)` and selector `0x2dd17959`
     #[derive(
         Clone,
         ::corebc_contract::EthCall,
@@ -2187,9 +5339,9 @@ pub mod hevm {
         Eq,
         Hash
     )]
-    #[ethcall(name = "createDir", abi = "createDir(string,bool)")]
-    pub struct CreateDirCall(pub ::std::string::String, pub bool);
-    ///Container type for all input parameters for the `createFork` function with signature `createFork(string,uint256)` and selector `0x27dc0128`
+    #[ethcall(name = "envOr", abi = "envOr(string,bytes)")]
+    pub struct EnvOr6Call(pub ::std::string::String, pub ::corebc_core::types::Bytes);
+    ///Container type for all input parameters for the `envOr` function with signature `envOr(string,string,bool[])` and selector `0x9430ce1e`
     #[derive(
         Clone,
         ::corebc_contract::EthCall,
@@ -2200,12 +5352,13 @@ pub mod hevm {
         Eq,
         Hash
     )]
-    #[ethcall(name = "createFork", abi = "createFork(string,uint256)")]
-    pub struct CreateFork1Call(
+    #[ethcall(name = "envOr", abi = "envOr(string,string,bool[])")]
+    pub struct EnvOr7Call(
         pub ::std::string::String,
-        pub ::corebc_core::types::U256,
+        pub ::std::string::String,
+        pub ::std::vec::Vec<bool>,
     );
-    ///Container type for all input parameters for the `createFork` function with signature `createFork(string,bytes32)` and selector `0x472ee61e`
+    ///Container type for all input parameters for the `envOr` function with signature `envOr(string,string,uint256[])` and selector `0xbf6e43ef`
     #[derive(
         Clone,
         ::corebc_contract::EthCall,
@@ -2216,9 +5369,13 @@ pub mod hevm {
         Eq,
         Hash
     )]
-    #[ethcall(name = "createFork", abi = "createFork(string,bytes32)")]
-    pub struct CreateFork2Call(pub ::std::string::String, pub [u8; 32]);
-    ///Container type for all input parameters for the `createFork` function with signature `createFork(string)` and selector `0xc8282be0`
+    #[ethcall(name = "envOr", abi = "envOr(string,string,uint256[])")]
+    pub struct EnvOr8Call(
+        pub ::std::string::String,
+        pub ::std::string::String,
+        pub ::std::vec::Vec<::corebc_core::types::U256>,
+    );
+    ///Container type for all input parameters for the `envOr` function with signature `envOr(string,string,int256[])` and selector `0x60a8eb5e`
     #[derive(
         Clone,
         ::corebc_contract::EthCall,
@@ -2229,9 +5386,13 @@ pub mod hevm {
         Eq,
         Hash
     )]
-    #[ethcall(name = "createFork", abi = "createFork(string)")]
-    pub struct CreateFork0Call(pub ::std::string::String);
-    ///Container type for all input parameters for the `createSelectFork` function with signature `createSelectFork(string,uint256)` and selector `0xcc15574b`
+    #[ethcall(name = "envOr", abi = "envOr(string,string,int256[])")]
+    pub struct EnvOr9Call(
+        pub ::std::string::String,
+        pub ::std::string::String,
+        pub ::std::vec::Vec<::corebc_core::types::I256>,
+    );
+    ///Container type for all input parameters for the `envOr` function with signature `envOr(string,string,address[])` and selector `0x0dbd12fc`
     #[derive(
         Clone,
         ::corebc_contract::EthCall,
@@ -2242,12 +5403,13 @@ pub mod hevm {
         Eq,
         Hash
     )]
-    #[ethcall(name = "createSelectFork", abi = "createSelectFork(string,uint256)")]
-    pub struct CreateSelectFork1Call(
+    #[ethcall(name = "envOr", abi = "envOr(string,string,address[])")]
+    pub struct EnvOr10Call(
         pub ::std::string::String,
-        pub ::corebc_core::types::U256,
+        pub ::std::string::String,
+        pub ::std::vec::Vec<::corebc_core::types::Address>,
     );
-    ///Container type for all input parameters for the `createSelectFork` function with signature `createSelectFork(string,bytes32)` and selector `0x47cbbd7a`
+    ///Container type for all input parameters for the `envOr` function with signature `envOr(string,string,bytes32[])` and selector `0x864e3a3c`
     #[derive(
         Clone,
         ::corebc_contract::EthCall,
@@ -2258,9 +5420,131 @@ pub mod hevm {
         Eq,
         Hash
     )]
-    #[ethcall(name = "createSelectFork", abi = "createSelectFork(string,bytes32)")]
-    pub struct CreateSelectFork2Call(pub ::std::string::String, pub [u8; 32]);
-    ///Container type for all input parameters for the `createSelectFork` function with signature `createSelectFork(string)` and selector `0x6bcf2c60`
+    #[ethcall(name = "envOr", abi = "envOr(string,string,bytes32[])")]
+    pub struct EnvOr11Call(
+        pub ::std::string::String,
+        pub ::std::string::String,
+        pub ::std::vec::Vec<[u8; 32]>,
+    );
+    ///Container type for all input parameters for the `envOr` function with signature `envOr(string,string,string[])` and selector `0x9eb2a8c4`
+    #[derive(
+        Clone,
+        ::corebc_contract::EthCall,
+        ::corebc_contract::EthDisplay,
+        Default,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash
+    )]
+    #[ethcall(name = "envOr", abi = "envOr(string,string,string[])")]
+    pub struct EnvOr12Call(
+        pub ::std::string::String,
+        pub ::std::string::String,
+        pub ::std::vec::Vec<::std::string::String>,
+    );
+    ///Container type for all input parameters for the `envOr` function with signature `envOr(string,string,bytes[])` and selector `0x206f7cdd`
+    #[derive(
+        Clone,
+        ::corebc_contract::EthCall,
+        ::corebc_contract::EthDisplay,
+        Default,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash
+    )]
+    #[ethcall(name = "envOr", abi = "envOr(string,string,bytes[])")]
+    pub struct EnvOr13Call(
+        pub ::std::string::String,
+        pub ::std::string::String,
+        pub ::std::vec::Vec<::corebc_core::types::Bytes>,
+    );
+    ///Container type for all input parameters for the `envString` function with signature `envString(string)` and selector `0xe613c3f6`
+    #[derive(
+        Clone,
+        ::corebc_contract::EthCall,
+        ::corebc_contract::EthDisplay,
+        Default,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash
+    )]
+    #[ethcall(name = "envString", abi = "envString(string)")]
+    pub struct EnvString0Call(pub ::std::string::String);
+    ///Container type for all input parameters for the `envString` function with signature `envString(string,string)` and selector `0x535a86f8`
+    #[derive(
+        Clone,
+        ::corebc_contract::EthCall,
+        ::corebc_contract::EthDisplay,
+        Default,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash
+    )]
+    #[ethcall(name = "envString", abi = "envString(string,string)")]
+    pub struct EnvString1Call(pub ::std::string::String, pub ::std::string::String);
+    ///Container type for all input parameters for the `envUint` function with signature `envUint(string)` and selector `0x22b1fcf4`
+    #[derive(
+        Clone,
+        ::corebc_contract::EthCall,
+        ::corebc_contract::EthDisplay,
+        Default,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash
+    )]
+    #[ethcall(name = "envUint", abi = "envUint(string)")]
+    pub struct EnvUint0Call(pub ::std::string::String);
+    ///Container type for all input parameters for the `envUint` function with signature `envUint(string,string)` and selector `0x09437722`
+    #[derive(
+        Clone,
+        ::corebc_contract::EthCall,
+        ::corebc_contract::EthDisplay,
+        Default,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash
+    )]
+    #[ethcall(name = "envUint", abi = "envUint(string,string)")]
+    pub struct EnvUint1Call(pub ::std::string::String, pub ::std::string::String);
+    ///Container type for all input parameters for the `etch` function with signature `etch(address,bytes)` and selector `0x61106001`
+    #[derive(
+        Clone,
+        ::corebc_contract::EthCall,
+        ::corebc_contract::EthDisplay,
+        Default,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash
+    )]
+    #[ethcall(name = "etch", abi = "etch(address,bytes)")]
+    pub struct EtchCall(
+        pub ::corebc_core::types::Address,
+        pub ::corebc_core::types::Bytes,
+    );
+    ///Container type for all input parameters for the `expectCall` function with signature `expectCall(address,bytes)` and selector `0xc244877f`
+    #[derive(
+        Clone,
+        ::corebc_contract::EthCall,
+        ::corebc_contract::EthDisplay,
+        Default,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash
+    )]
+    #[ethcall(name = "expectCall", abi = "expectCall(address,bytes)")]
+    pub struct ExpectCall0Call(
+        pub ::corebc_core::types::Address,
+        pub ::corebc_core::types::Bytes,
+    );
+    ///Container type for all input parameters for the `expectCall` function with signature `expectCall(address,bytes,uint64)` and selector `0x35ce3631`
     #[derive(
         Clone,
         ::corebc_contract::EthCall,
@@ -2271,9 +5555,13 @@ pub mod hevm {
         Eq,
         Hash
     )]
-    #[ethcall(name = "createSelectFork", abi = "createSelectFork(string)")]
-    pub struct CreateSelectFork0Call(pub ::std::string::String);
-    ///Container type for all input parameters for the `deal` function with signature `deal(address,uint256)` and selector `0x5ae137d7`
+    #[ethcall(name = "expectCall", abi = "expectCall(address,bytes,uint64)")]
+    pub struct ExpectCall1Call(
+        pub ::corebc_core::types::Address,
+        pub ::corebc_core::types::Bytes,
+        pub u64,
+    );
+    ///Container type for all input parameters for the `expectCall` function with signature `expectCall(address,uint256,bytes)` and selector `0x5a1f47d6`
     #[derive(
         Clone,
         ::corebc_contract::EthCall,
@@ -2284,12 +5572,13 @@ pub mod hevm {
         Eq,
         Hash
     )]
-    #[ethcall(name = "deal", abi = "deal(address,uint256)")]
-    pub struct DealCall(
+    #[ethcall(name = "expectCall", abi = "expectCall(address,uint256,bytes)")]
+    pub struct ExpectCall2Call(
         pub ::corebc_core::types::Address,
         pub ::corebc_core::types::U256,
+        pub ::corebc_core::types::Bytes,
     );
-    ///Container type for all input parameters for the `deriveKey` function with signature `deriveKey(string,uint32)` and selector `0xf6f7e9e1`
+    ///Container type for all input parameters for the `expectCall` function with signature `expectCall(address,uint256,bytes,uint64)` and selector `0xde6e6e54`
     #[derive(
         Clone,
         ::corebc_contract::EthCall,
@@ -2300,9 +5589,14 @@ pub mod hevm {
         Eq,
         Hash
     )]
-    #[ethcall(name = "deriveKey", abi = "deriveKey(string,uint32)")]
-    pub struct DeriveKey0Call(pub ::std::string::String, pub u32);
-    ///Container type for all input parameters for the `deriveKey` function with signature `deriveKey(string,string,uint32)` and selector `0xd5a72210`
+    #[ethcall(name = "expectCall", abi = "expectCall(address,uint256,bytes,uint64)")]
+    pub struct ExpectCall3Call(
+        pub ::corebc_core::types::Address,
+        pub ::corebc_core::types::U256,
+        pub ::corebc_core::types::Bytes,
+        pub u64,
+    );
+    ///Container type for all input parameters for the `expectCall` function with signature `expectCall(address,uint256,uint64,bytes)` and selector `0x536671ce`
     #[derive(
         Clone,
         ::corebc_contract::EthCall,
@@ -2313,13 +5607,14 @@ pub mod hevm {
         Eq,
         Hash
     )]
-    #[ethcall(name = "deriveKey", abi = "deriveKey(string,string,uint32)")]
-    pub struct DeriveKey1Call(
-        pub ::std::string::String,
-        pub ::std::string::String,
-        pub u32,
+    #[ethcall(name = "expectCall", abi = "expectCall(address,uint256,uint64,bytes)")]
+    pub struct ExpectCall4Call(
+        pub ::corebc_core::types::Address,
+        pub ::corebc_core::types::U256,
+        pub u64,
+        pub ::corebc_core::types::Bytes,
     );
-    ///Container type for all input parameters for the `deriveKey` function with signature `deriveKey(string,uint32,string)` and selector `0xee602b67`
+    ///Container type for all input parameters for the `expectCall` function with signature `expectCall(address,uint256,uint64,bytes,uint64)` and selector `0x66ca6367`
     #[derive(
         Clone,
         ::corebc_contract::EthCall,
@@ -2330,13 +5625,18 @@ pub mod hevm {
         Eq,
         Hash
     )]
-    #[ethcall(name = "deriveKey", abi = "deriveKey(string,uint32,string)")]
-    pub struct DeriveKey2Call(
-        pub ::std::string::String,
-        pub u32,
-        pub ::std::string::String,
+    #[ethcall(
+        name = "expectCall",
+        abi = "expectCall(address,uint256,uint64,bytes,uint64)"
+    )]
+    pub struct ExpectCall5Call(
+        pub ::corebc_core::types::Address,
+        pub ::corebc_core::types::U256,
+        pub u64,
+        pub ::corebc_core::types::Bytes,
+        pub u64,
     );
-    ///Container type for all input parameters for the `deriveKey` function with signature `deriveKey(string,string,uint32,string)` and selector `0xcc819384`
+    ///Container type for all input parameters for the `expectCallMinGas` function with signature `expectCallMinGas(address,uint256,uint64,bytes)` and selector `0x782f8cdb`
     #[derive(
         Clone,
         ::corebc_contract::EthCall,
@@ -2347,14 +5647,17 @@ pub mod hevm {
         Eq,
         Hash
     )]
-    #[ethcall(name = "deriveKey", abi = "deriveKey(string,string,uint32,string)")]
-    pub struct DeriveKey3Call(
-        pub ::std::string::String,
-        pub ::std::string::String,
-        pub u32,
-        pub ::std::string::String,
+    #[ethcall(
+        name = "expectCallMinGas",
+        abi = "expectCallMinGas(address,uint256,uint64,bytes)"
+    )]
+    pub struct ExpectCallMinGas0Call(
+        pub ::corebc_core::types::Address,
+        pub ::corebc_core::types::U256,
+        pub u64,
+        pub ::corebc_core::types::Bytes,
     );
-    ///Container type for all input parameters for the `difficulty` function with signature `difficulty(uint256)` and selector `0xfc75ba15`
+    ///Container type for all input parameters for the `expectCallMinGas` function with signature `expectCallMinGas(address,uint256,uint64,bytes,uint64)` and selector `0x7f5de23e`
     #[derive(
         Clone,
         ::corebc_contract::EthCall,
@@ -2365,9 +5668,18 @@ pub mod hevm {
         Eq,
         Hash
     )]
-    #[ethcall(name = "difficulty", abi = "difficulty(uint256)")]
-    pub struct DifficultyCall(pub ::corebc_core::types::U256);
-    ///Container type for all input parameters for the `envAddress` function with signature `envAddress(string)` and selector `0x5992c5c6`
+    #[ethcall(
+        name = "expectCallMinGas",
+        abi = "expectCallMinGas(address,uint256,uint64,bytes,uint64)"
+    )]
+    pub struct ExpectCallMinGas1Call(
+        pub ::corebc_core::types::Address,
+        pub ::corebc_core::types::U256,
+        pub u64,
+        pub ::corebc_core::types::Bytes,
+        pub u64,
+    );
+    ///Container type for all input parameters for the `expectEmit` function with signature `expectEmit()` and selector `0x51bd56ad`
     #[derive(
         Clone,
         ::corebc_contract::EthCall,
@@ -2378,9 +5690,9 @@ pub mod hevm {
         Eq,
         Hash
     )]
-    #[ethcall(name = "envAddress", abi = "envAddress(string)")]
-    pub struct EnvAddress0Call(pub ::std::string::String);
-    ///Container type for all input parameters for the `envAddress` function with signature `envAddress(string,string)` and selector `0xdd088083`
+    #[ethcall(name = "expectEmit", abi = "expectEmit()")]
+    pub struct ExpectEmit0Call;
+    ///Container type for all input parameters for the `expectEmit` function with signature `expectEmit(address)` and selector `0xbff0146e`
     #[derive(
         Clone,
         ::corebc_contract::EthCall,
@@ -2391,9 +5703,9 @@ pub mod hevm {
         Eq,
         Hash
     )]
-    #[ethcall(name = "envAddress", abi = "envAddress(string,string)")]
-    pub struct EnvAddress1Call(pub ::std::string::String, pub ::std::string::String);
-    ///Container type for all input parameters for the `envBool` function with signature `envBool(string)` and selector `0xfd40bd11`
+    #[ethcall(name = "expectEmit", abi = "expectEmit(address)")]
+    pub struct ExpectEmit1Call(pub ::corebc_core::types::Address);
+    ///Container type for all input parameters for the `expectEmit` function with signature `expectEmit(uint64)` and selector `0x242c1da4`
     #[derive(
         Clone,
         ::corebc_contract::EthCall,
@@ -2404,9 +5716,9 @@ pub mod hevm {
         Eq,
         Hash
     )]
-    #[ethcall(name = "envBool", abi = "envBool(string)")]
-    pub struct EnvBool0Call(pub ::std::string::String);
-    ///Container type for all input parameters for the `envBool` function with signature `envBool(string,string)` and selector `0xdf739509`
+    #[ethcall(name = "expectEmit", abi = "expectEmit(uint64)")]
+    pub struct ExpectEmit2Call(pub u64);
+    ///Container type for all input parameters for the `expectEmit` function with signature `expectEmit(address,uint64)` and selector `0x391e3757`
     #[derive(
         Clone,
         ::corebc_contract::EthCall,
@@ -2417,9 +5729,9 @@ pub mod hevm {
         Eq,
         Hash
     )]
-    #[ethcall(name = "envBool", abi = "envBool(string,string)")]
-    pub struct EnvBool1Call(pub ::std::string::String, pub ::std::string::String);
-    ///Container type for all input parameters for the `envBytes` function with signature `envBytes(string)` and selector `0xb1676ba0`
+    #[ethcall(name = "expectEmit", abi = "expectEmit(address,uint64)")]
+    pub struct ExpectEmit3Call(pub ::corebc_core::types::Address, pub u64);
+    ///Container type for all input parameters for the `expectEmit` function with signature `expectEmit(bool,bool,bool,bool)` and selector `0xe2e01dd4`
     #[derive(
         Clone,
         ::corebc_contract::EthCall,
@@ -2430,9 +5742,9 @@ pub mod hevm {
         Eq,
         Hash
     )]
-    #[ethcall(name = "envBytes", abi = "envBytes(string)")]
-    pub struct EnvBytes0Call(pub ::std::string::String);
-    ///Container type for all input parameters for the `envBytes` function with signature `envBytes(string,string)` and selector `0xd9577bb1`
+    #[ethcall(name = "expectEmit", abi = "expectEmit(bool,bool,bool,bool)")]
+    pub struct ExpectEmit4Call(pub bool, pub bool, pub bool, pub bool);
+    ///Container type for all input parameters for the `expectEmit` function with signature `expectEmit(bool,bool,bool,bool,address)` and selector `0xdb3d4fd8`
     #[derive(
         Clone,
         ::corebc_contract::EthCall,
@@ -2443,9 +5755,15 @@ pub mod hevm {
         Eq,
         Hash
     )]
-    #[ethcall(name = "envBytes", abi = "envBytes(string,string)")]
-    pub struct EnvBytes1Call(pub ::std::string::String, pub ::std::string::String);
-    ///Container type for all input parameters for the `envBytes32` function with signature `envBytes32(string)` and selector `0x2d261418`
+    #[ethcall(name = "expectEmit", abi = "expectEmit(bool,bool,bool,bool,address)")]
+    pub struct ExpectEmit5Call(
+        pub bool,
+        pub bool,
+        pub bool,
+        pub bool,
+        pub ::corebc_core::types::Address,
+    );
+    ///Container type for all input parameters for the `expectEmit` function with signature `expectEmit(bool,bool,bool,bool,uint64)` and selector `0x81abc432`
     #[derive(
         Clone,
         ::corebc_contract::EthCall,
@@ -2456,9 +5774,9 @@ pub mod hevm {
         Eq,
         Hash
     )]
-    #[ethcall(name = "envBytes32", abi = "envBytes32(string)")]
-    pub struct EnvBytes320Call(pub ::std::string::String);
-    ///Container type for all input parameters for the `envBytes32` function with signature `envBytes32(string,string)` and selector `0x5c1826d3`
+    #[ethcall(name = "expectEmit", abi = "expectEmit(bool,bool,bool,bool,uint64)")]
+    pub struct ExpectEmit6Call(pub bool, pub bool, pub bool, pub bool, pub u64);
+    ///Container type for all input parameters for the `expectEmit` function with signature `expectEmit(bool,bool,bool,bool,address,uint64)` and selector `0x601727f2`
     #[derive(
         Clone,
         ::corebc_contract::EthCall,
@@ -2469,9 +5787,19 @@ pub mod hevm {
         Eq,
         Hash
     )]
-    #[ethcall(name = "envBytes32", abi = "envBytes32(string,string)")]
-    pub struct EnvBytes321Call(pub ::std::string::String, pub ::std::string::String);
-    ///Container type for all input parameters for the `envInt` function with signature `envInt(string)` and selector `0xdba2cad8`
+    #[ethcall(
+        name = "expectEmit",
+        abi = "expectEmit(bool,bool,bool,bool,address,uint64)"
+    )]
+    pub struct ExpectEmit7Call(
+        pub bool,
+        pub bool,
+        pub bool,
+        pub bool,
+        pub ::corebc_core::types::Address,
+        pub u64,
+    );
+    ///Container type for all input parameters for the `expectEmitAnonymous` function with signature `expectEmitAnonymous()` and selector `0x0acb608b`
     #[derive(
         Clone,
         ::corebc_contract::EthCall,
@@ -2482,9 +5810,9 @@ pub mod hevm {
         Eq,
         Hash
     )]
-    #[ethcall(name = "envInt", abi = "envInt(string)")]
-    pub struct EnvInt0Call(pub ::std::string::String);
-    ///Container type for all input parameters for the `envInt` function with signature `envInt(string,string)` and selector `0x347968b0`
+    #[ethcall(name = "expectEmitAnonymous", abi = "expectEmitAnonymous()")]
+    pub struct ExpectEmitAnonymous0Call;
+    ///Container type for all input parameters for the `expectEmitAnonymous` function with signature `expectEmitAnonymous(address)` and selector `0xcee080f0`
     #[derive(
         Clone,
         ::corebc_contract::EthCall,
@@ -2495,9 +5823,9 @@ pub mod hevm {
         Eq,
         Hash
     )]
-    #[ethcall(name = "envInt", abi = "envInt(string,string)")]
-    pub struct EnvInt1Call(pub ::std::string::String, pub ::std::string::String);
-    ///Container type for all input parameters for the `envOr` function with signature `envOr(string,bool)` and selector `0x8efd04a9`
+    #[ethcall(name = "expectEmitAnonymous", abi = "expectEmitAnonymous(address)")]
+    pub struct ExpectEmitAnonymous1Call(pub ::corebc_core::types::Address);
+    ///Container type for all input parameters for the `expectEmitAnonymous` function with signature `expectEmitAnonymous(bool,bool,bool,bool,bool)` and selector `0x7e26cad2`
     #[derive(
         Clone,
         ::corebc_contract::EthCall,
@@ -2508,9 +5836,18 @@ pub mod hevm {
         Eq,
         Hash
     )]
-    #[ethcall(name = "envOr", abi = "envOr(string,bool)")]
-    pub struct EnvOr0Call(pub ::std::string::String, pub bool);
-    ///Container type for all input parameters for the `envOr` function with signature `envOr(string,uint256)` and selector `0x1c6db43b`
+    #[ethcall(
+        name = "expectEmitAnonymous",
+        abi = "expectEmitAnonymous(bool,bool,bool,bool,bool)"
+    )]
+    pub struct ExpectEmitAnonymous2Call(
+        pub bool,
+        pub bool,
+        pub bool,
+        pub bool,
+        pub bool,
+    );
+    ///Container type for all input parameters for the `expectEmitAnonymous` function with signature `expectEmitAnonymous(bool,bool,bool,bool,bool,address)` and selector `0x4c6b326e`
     #[derive(
         Clone,
         ::corebc_contract::EthCall,
@@ -2521,9 +5858,19 @@ pub mod hevm {
         Eq,
         Hash
     )]
-    #[ethcall(name = "envOr", abi = "envOr(string,uint256)")]
-    pub struct EnvOr1Call(pub ::std::string::String, pub ::corebc_core::types::U256);
-    ///Container type for all input parameters for the `envOr` function with signature `envOr(string,int256)` and selector `0x35711e3f`
+    #[ethcall(
+        name = "expectEmitAnonymous",
+        abi = "expectEmitAnonymous(bool,bool,bool,bool,bool,address)"
+    )]
+    pub struct ExpectEmitAnonymous3Call(
+        pub bool,
+        pub bool,
+        pub bool,
+        pub bool,
+        pub bool,
+        pub ::corebc_core::types::Address,
+    );
+    ///Container type for all input parameters for the `expectPartialRevert` function with signature `expectPartialRevert(bytes4)` and selector `0x19c48746`
     #[derive(
         Clone,
         ::corebc_contract::EthCall,
@@ -2534,9 +5881,9 @@ pub mod hevm {
         Eq,
         Hash
     )]
-    #[ethcall(name = "envOr", abi = "envOr(string,int256)")]
-    pub struct EnvOr2Call(pub ::std::string::String, pub ::corebc_core::types::I256);
-    ///Container type for all input parameters for the `envOr` function with signature `envOr(string,address)` and selector `0x18745f8c`
+    #[ethcall(name = "expectPartialRevert", abi = "expectPartialRevert(bytes4)")]
+    pub struct ExpectPartialRevert0Call(pub [u8; 4]);
+    ///Container type for all input parameters for the `expectPartialRevert` function with signature `expectPartialRevert(bytes4,address)` and selector `0x1d5067a2`
     #[derive(
         Clone,
         ::corebc_contract::EthCall,
@@ -2547,9 +5894,9 @@ pub mod hevm {
         Eq,
         Hash
     )]
-    #[ethcall(name = "envOr", abi = "envOr(string,address)")]
-    pub struct EnvOr3Call(pub ::std::string::String, pub ::corebc_core::types::Address);
-    ///Container type for all input parameters for the `envOr` function with signature `envOr(string,bytes32)` and selector `0xcb48937a`
+    #[ethcall(name = "expectPartialRevert", abi = "expectPartialRevert(bytes4,address)")]
+    pub struct ExpectPartialRevert1Call(pub [u8; 4], pub ::corebc_core::types::Address);
+    ///Container type for all input parameters for the `expectRevert` function with signature `expectRevert()` and selector `0x10403acb`
     #[derive(
         Clone,
         ::corebc_contract::EthCall,
@@ -2560,9 +5907,9 @@ pub mod hevm {
         Eq,
         Hash
     )]
-    #[ethcall(name = "envOr", abi = "envOr(string,bytes32)")]
-    pub struct EnvOr4Call(pub ::std::string::String, pub [u8; 32]);
-    ///Container type for all input parameters for the `envOr` function with signature `envOr(string,string)` and selector `0x42d1d0ef`
+    #[ethcall(name = "expectRevert", abi = "expectRevert()")]
+    pub struct ExpectRevert0Call;
+    ///Container type for all input parameters for the `expectRevert` function with signature `expectRevert(bytes)` and selector `0xe37ab4ed`
     #[derive(
         Clone,
         ::corebc_contract::EthCall,
@@ -2573,9 +5920,9 @@ pub mod hevm {
         Eq,
         Hash
     )]
-    #[ethcall(name = "envOr", abi = "envOr(string,string)")]
-    pub struct EnvOr5Call(pub ::std::string::String, pub ::std::string::String);
-    ///Container type for all input parameters for the `envOr` function with signature `envOr(string,bytes)` and selector `0x2dd17959`
+    #[ethcall(name = "expectRevert", abi = "expectRevert(bytes)")]
+    pub struct ExpectRevert1Call(pub ::corebc_core::types::Bytes);
+    ///Container type for all input parameters for the `expectRevert` function with signature `expectRevert(bytes4)` and selector `0x4e7614bc`
     #[derive(
         Clone,
         ::corebc_contract::EthCall,
@@ -2586,9 +5933,9 @@ pub mod hevm {
         Eq,
         Hash
     )]
-    #[ethcall(name = "envOr", abi = "envOr(string,bytes)")]
-    pub struct EnvOr6Call(pub ::std::string::String, pub ::corebc_core::types::Bytes);
-    ///Container type for all input parameters for the `envOr` function with signature `envOr(string,string,bool[])` and selector `0x9430ce1e`
+    #[ethcall(name = "expectRevert", abi = "expectRevert(bytes4)")]
+    pub struct ExpectRevert2Call(pub [u8; 4]);
+    ///Container type for all input parameters for the `expectRevert` function with signature `expectRevert(uint64)` and selector `0xc018d382`
     #[derive(
         Clone,
         ::corebc_contract::EthCall,
@@ -2599,13 +5946,9 @@ pub mod hevm {
         Eq,
         Hash
     )]
-    #[ethcall(name = "envOr", abi = "envOr(string,string,bool[])")]
-    pub struct EnvOr7Call(
-        pub ::std::string::String,
-        pub ::std::string::String,
-        pub ::std::vec::Vec<bool>,
-    );
-    ///Container type for all input parameters for the `envOr` function with signature `envOr(string,string,uint256[])` and selector `0xbf6e43ef`
+    #[ethcall(name = "expectRevert", abi = "expectRevert(uint64)")]
+    pub struct ExpectRevert3Call(pub u64);
+    ///Container type for all input parameters for the `expectRevert` function with signature `expectRevert(address)` and selector `0xe1d8c608`
     #[derive(
         Clone,
         ::corebc_contract::EthCall,
@@ -2616,13 +5959,9 @@ pub mod hevm {
         Eq,
         Hash
     )]
-    #[ethcall(name = "envOr", abi = "envOr(string,string,uint256[])")]
-    pub struct EnvOr8Call(
-        pub ::std::string::String,
-        pub ::std::string::String,
-        pub ::std::vec::Vec<::corebc_core::types::U256>,
-    );
-    ///Container type for all input parameters for the `envOr` function with signature `envOr(string,string,int256[])` and selector `0x60a8eb5e`
+    #[ethcall(name = "expectRevert", abi = "expectRevert(address)")]
+    pub struct ExpectRevert4Call(pub ::corebc_core::types::Address);
+    ///Container type for all input parameters for the `expectRevert` function with signature `expectRevert(bytes,uint64)` and selector `0x04d16748`
     #[derive(
         Clone,
         ::corebc_contract::EthCall,
@@ -2633,13 +5972,9 @@ pub mod hevm {
         Eq,
         Hash
     )]
-    #[ethcall(name = "envOr", abi = "envOr(string,string,int256[])")]
-    pub struct EnvOr9Call(
-        pub ::std::string::String,
-        pub ::std::string::String,
-        pub ::std::vec::Vec<::corebc_core::types::I256>,
-    );
-    ///Container type for all input parameters for the `envOr` function with signature `envOr(string,string,address[])` and selector `0x0dbd12fc`
+    #[ethcall(name = "expectRevert", abi = "expectRevert(bytes,uint64)")]
+    pub struct ExpectRevert5Call(pub ::corebc_core::types::Bytes, pub u64);
+    ///Container type for all input parameters for the `expectRevert` function with signature `expectRevert(bytes4,uint64)` and selector `0x0bcd76bb`
     #[derive(
         Clone,
         ::corebc_contract::EthCall,
@@ -2650,13 +5985,9 @@ pub mod hevm {
         Eq,
         Hash
     )]
-    #[ethcall(name = "envOr", abi = "envOr(string,string,address[])")]
-    pub struct EnvOr10Call(
-        pub ::std::string::String,
-        pub ::std::string::String,
-        pub ::std::vec::Vec<::corebc_core::types::Address>,
-    );
-    ///Container type for all input parameters for the `envOr` function with signature `envOr(string,string,bytes32[])` and selector `0x864e3a3c`
+    #[ethcall(name = "expectRevert", abi = "expectRevert(bytes4,uint64)")]
+    pub struct ExpectRevert6Call(pub [u8; 4], pub u64);
+    ///Container type for all input parameters for the `expectRevert` function with signature `expectRevert(bytes,address)` and selector `0x4ce8a185`
     #[derive(
         Clone,
         ::corebc_contract::EthCall,
@@ -2667,13 +5998,12 @@ pub mod hevm {
         Eq,
         Hash
     )]
-    #[ethcall(name = "envOr", abi = "envOr(string,string,bytes32[])")]
-    pub struct EnvOr11Call(
-        pub ::std::string::String,
-        pub ::std::string::String,
-        pub ::std::vec::Vec<[u8; 32]>,
+    #[ethcall(name = "expectRevert", abi = "expectRevert(bytes,address)")]
+    pub struct ExpectRevert7Call(
+        pub ::corebc_core::types::Bytes,
+        pub ::corebc_core::types::Address,
     );
-    ///Container type for all input parameters for the `envOr` function with signature `envOr(string,string,string[])` and selector `0x9eb2a8c4`
+    ///Container type for all input parameters for the `expectRevert` function with signature `expectRevert(bytes4,address)` and selector `0xa5bbcfd9`
     #[derive(
         Clone,
         ::corebc_contract::EthCall,
@@ -2684,13 +6014,9 @@ pub mod hevm {
         Eq,
         Hash
     )]
-    #[ethcall(name = "envOr", abi = "envOr(string,string,string[])")]
-    pub struct EnvOr12Call(
-        pub ::std::string::String,
-        pub ::std::string::String,
-        pub ::std::vec::Vec<::std::string::String>,
-    );
-    ///Container type for all input parameters for the `envOr` function with signature `envOr(string,string,bytes[])` and selector `0x206f7cdd`
+    #[ethcall(name = "expectRevert", abi = "expectRevert(bytes4,address)")]
+    pub struct ExpectRevert8Call(pub [u8; 4], pub ::corebc_core::types::Address);
+    ///Container type for all input parameters for the `expectSafeMemory` function with signature `expectSafeMemory(uint64,uint64)` and selector `0x46bb5493`
     #[derive(
         Clone,
         ::corebc_contract::EthCall,
@@ -2701,13 +6027,9 @@ pub mod hevm {
         Eq,
         Hash
     )]
-    #[ethcall(name = "envOr", abi = "envOr(string,string,bytes[])")]
-    pub struct EnvOr13Call(
-        pub ::std::string::String,
-        pub ::std::string::String,
-        pub ::std::vec::Vec<::corebc_core::types::Bytes>,
-    );
-    ///Container type for all input parameters for the `envString` function with signature `envString(string)` and selector `0xe613c3f6`
+    #[ethcall(name = "expectSafeMemory", abi = "expectSafeMemory(uint64,uint64)")]
+    pub struct ExpectSafeMemoryCall(pub u64, pub u64);
+    ///Container type for all input parameters for the `expectSafeMemoryCall` function with signature `expectSafeMemoryCall(uint64,uint64)` and selector `0x9b61c661`
     #[derive(
         Clone,
         ::corebc_contract::EthCall,
@@ -2718,9 +6040,12 @@ pub mod hevm {
         Eq,
         Hash
     )]
-    #[ethcall(name = "envString", abi = "envString(string)")]
-    pub struct EnvString0Call(pub ::std::string::String);
-    ///Container type for all input parameters for the `envString` function with signature `envString(string,string)` and selector `0x535a86f8`
+    #[ethcall(
+        name = "expectSafeMemoryCall",
+        abi = "expectSafeMemoryCall(uint64,uint64)"
+    )]
+    pub struct ExpectSafeMemoryCallCall(pub u64, pub u64);
+    ///Container type for all input parameters for the `fee` function with signature `fee(uint256)` and selector `0xaa32efc1`
     #[derive(
         Clone,
         ::corebc_contract::EthCall,
@@ -2731,9 +6056,9 @@ pub mod hevm {
         Eq,
         Hash
     )]
-    #[ethcall(name = "envString", abi = "envString(string,string)")]
-    pub struct EnvString1Call(pub ::std::string::String, pub ::std::string::String);
-    ///Container type for all input parameters for the `envUint` function with signature `envUint(string)` and selector `0x22b1fcf4`
+    #[ethcall(name = "fee", abi = "fee(uint256)")]
+    pub struct FeeCall(pub ::corebc_core::types::U256);
+    ///Container type for all input parameters for the `ffi` function with signature `ffi(string[])` and selector `0x64ba2b32`
     #[derive(
         Clone,
         ::corebc_contract::EthCall,
@@ -2744,9 +6069,9 @@ pub mod hevm {
         Eq,
         Hash
     )]
-    #[ethcall(name = "envUint", abi = "envUint(string)")]
-    pub struct EnvUint0Call(pub ::std::string::String);
-    ///Container type for all input parameters for the `envUint` function with signature `envUint(string,string)` and selector `0x09437722`
+    #[ethcall(name = "ffi", abi = "ffi(string[])")]
+    pub struct FfiCall(pub ::std::vec::Vec<::std::string::String>);
+    ///Container type for all input parameters for the `fsMetadata` function with signature `fsMetadata(string)` and selector `0x895b0357`
     #[derive(
         Clone,
         ::corebc_contract::EthCall,
@@ -2757,9 +6082,9 @@ pub mod hevm {
         Eq,
         Hash
     )]
-    #[ethcall(name = "envUint", abi = "envUint(string,string)")]
-    pub struct EnvUint1Call(pub ::std::string::String, pub ::std::string::String);
-    ///Container type for all input parameters for the `etch` function with signature `etch(address,bytes)` and selector `0x61106001`
+    #[ethcall(name = "fsMetadata", abi = "fsMetadata(string)")]
+    pub struct FsMetadataCall(pub ::std::string::String);
+    ///Container type for all input parameters for the `getCode` function with signature `getCode(string)` and selector `0xf4311698`
     #[derive(
         Clone,
         ::corebc_contract::EthCall,
@@ -2770,12 +6095,9 @@ pub mod hevm {
         Eq,
         Hash
     )]
-    #[ethcall(name = "etch", abi = "etch(address,bytes)")]
-    pub struct EtchCall(
-        pub ::corebc_core::types::Address,
-        pub ::corebc_core::types::Bytes,
-    );
-    ///Container type for all input parameters for the `expectCall` function with signature `expectCall(address,bytes)` and selector `0xc244877f`
+    #[ethcall(name = "getCode", abi = "getCode(string)")]
+    pub struct GetCodeCall(pub ::std::string::String);
+    ///Container type for all input parameters for the `getDeployedCode` function with signature `getDeployedCode(string)` and selector `0xe04cfba1`
     #[derive(
         Clone,
         ::corebc_contract::EthCall,
@@ -2786,12 +6108,9 @@ pub mod hevm {
         Eq,
         Hash
     )]
-    #[ethcall(name = "expectCall", abi = "expectCall(address,bytes)")]
-    pub struct ExpectCall0Call(
-        pub ::corebc_core::types::Address,
-        pub ::corebc_core::types::Bytes,
-    );
-    ///Container type for all input parameters for the `expectCall` function with signature `expectCall(address,bytes,uint64)` and selector `0x35ce3631`
+    #[ethcall(name = "getDeployedCode", abi = "getDeployedCode(string)")]
+    pub struct GetDeployedCodeCall(pub ::std::string::String);
+    ///Container type for all input parameters for the `getDeployment` function with signature `getDeployment(string)` and selector `0xa2a85ccf`
     #[derive(
         Clone,
         ::corebc_contract::EthCall,
@@ -2802,13 +6121,9 @@ pub mod hevm {
         Eq,
         Hash
     )]
-    #[ethcall(name = "expectCall", abi = "expectCall(address,bytes,uint64)")]
-    pub struct ExpectCall1Call(
-        pub ::corebc_core::types::Address,
-        pub ::corebc_core::types::Bytes,
-        pub u64,
-    );
-    ///Container type for all input parameters for the `expectCall` function with signature `expectCall(address,uint256,bytes)` and selector `0x5a1f47d6`
+    #[ethcall(name = "getDeployment", abi = "getDeployment(string)")]
+    pub struct GetDeployment0Call(pub ::std::string::String);
+    ///Container type for all input parameters for the `getDeployment` function with signature `getDeployment(string,string)` and selector `0xe9bad3f6`
     #[derive(
         Clone,
         ::corebc_contract::EthCall,
@@ -2819,13 +6134,9 @@ pub mod hevm {
         Eq,
         Hash
     )]
-    #[ethcall(name = "expectCall", abi = "expectCall(address,uint256,bytes)")]
-    pub struct ExpectCall2Call(
-        pub ::corebc_core::types::Address,
-        pub ::corebc_core::types::U256,
-        pub ::corebc_core::types::Bytes,
-    );
-    ///Container type for all input parameters for the `expectCall` function with signature `expectCall(address,uint256,bytes,uint64)` and selector `0xde6e6e54`
+    #[ethcall(name = "getDeployment", abi = "getDeployment(string,string)")]
+    pub struct GetDeployment1Call(pub ::std::string::String, pub ::std::string::String);
+    ///Container type for all input parameters for the `getLabel` function with signature `getLabel(address)` and selector `0x56c8fc59`
     #[derive(
         Clone,
         ::corebc_contract::EthCall,
@@ -2836,14 +6147,9 @@ pub mod hevm {
         Eq,
         Hash
     )]
-    #[ethcall(name = "expectCall", abi = "expectCall(address,uint256,bytes,uint64)")]
-    pub struct ExpectCall3Call(
-        pub ::corebc_core::types::Address,
-        pub ::corebc_core::types::U256,
-        pub ::corebc_core::types::Bytes,
-        pub u64,
-    );
-    ///Container type for all input parameters for the `expectCall` function with signature `expectCall(address,uint256,uint64,bytes)` and selector `0x536671ce`
+    #[ethcall(name = "getLabel", abi = "getLabel(address)")]
+    pub struct GetLabelCall(pub ::corebc_core::types::Address);
+    ///Container type for all input parameters for the `getNonce` function with signature `getNonce(address)` and selector `0x7ee608d7`
     #[derive(
         Clone,
         ::corebc_contract::EthCall,
@@ -2854,14 +6160,9 @@ pub mod hevm {
         Eq,
         Hash
     )]
-    #[ethcall(name = "expectCall", abi = "expectCall(address,uint256,uint64,bytes)")]
-    pub struct ExpectCall4Call(
-        pub ::corebc_core::types::Address,
-        pub ::corebc_core::types::U256,
-        pub u64,
-        pub ::corebc_core::types::Bytes,
-    );
-    ///Container type for all input parameters for the `expectCall` function with signature `expectCall(address,uint256,uint64,bytes,uint64)` and selector `0x66ca6367`
+    #[ethcall(name = "getNonce", abi = "getNonce(address)")]
+    pub struct GetNonceCall(pub ::corebc_core::types::Address);
+    ///Container type for all input parameters for the `getRecordedLogs` function with signature `getRecordedLogs()` and selector `0x6aa9ac15`
     #[derive(
         Clone,
         ::corebc_contract::EthCall,
@@ -2872,18 +6173,9 @@ pub mod hevm {
         Eq,
         Hash
     )]
-    #[ethcall(
-        name = "expectCall",
-        abi = "expectCall(address,uint256,uint64,bytes,uint64)"
-    )]
-    pub struct ExpectCall5Call(
-        pub ::corebc_core::types::Address,
-        pub ::corebc_core::types::U256,
-        pub u64,
-        pub ::corebc_core::types::Bytes,
-        pub u64,
-    );
-    ///Container type for all input parameters for the `expectCallMinGas` function with signature `expectCallMinGas(address,uint256,uint64,bytes)` and selector `0x782f8cdb`
+    #[ethcall(name = "getRecordedLogs", abi = "getRecordedLogs()")]
+    pub struct GetRecordedLogsCall;
+    ///Container type for all input parameters for the `isPersistent` function with signature `isPersistent(address)` and selector `0x27051a9d`
     #[derive(
         Clone,
         ::corebc_contract::EthCall,
@@ -2894,17 +6186,9 @@ pub mod hevm {
         Eq,
         Hash
     )]
-    #[ethcall(
-        name = "expectCallMinGas",
-        abi = "expectCallMinGas(address,uint256,uint64,bytes)"
-    )]
-    pub struct ExpectCallMinGas0Call(
-        pub ::corebc_core::types::Address,
-        pub ::corebc_core::types::U256,
-        pub u64,
-        pub ::corebc_core::types::Bytes,
-    );
-    ///Container type for all input parameters for the `expectCallMinGas` function with signature `expectCallMinGas(address,uint256,uint64,bytes,uint64)` and selector `0x7f5de23e`
+    #[ethcall(name = "isPersistent", abi = "isPersistent(address)")]
+    pub struct IsPersistentCall(pub ::corebc_core::types::Address);
+    ///Container type for all input parameters for the `keyExistsJson` function with signature `keyExistsJson(string,string)` and selector `0x43139fe3`
     #[derive(
         Clone,
         ::corebc_contract::EthCall,
@@ -2915,18 +6199,9 @@ pub mod hevm {
         Eq,
         Hash
     )]
-    #[ethcall(
-        name = "expectCallMinGas",
-        abi = "expectCallMinGas(address,uint256,uint64,bytes,uint64)"
-    )]
-    pub struct ExpectCallMinGas1Call(
-        pub ::corebc_core::types::Address,
-        pub ::corebc_core::types::U256,
-        pub u64,
-        pub ::corebc_core::types::Bytes,
-        pub u64,
-    );
-    ///Container type for all input parameters for the `expectEmit` function with signature `expectEmit()` and selector `0x51bd56ad`
+    #[ethcall(name = "keyExistsJson", abi = "keyExistsJson(string,string)")]
+    pub struct KeyExistsJsonCall(pub ::std::string::String, pub ::std::string::String);
+    ///Container type for all input parameters for the `keyExistsToml` function with signature `keyExistsToml(string,string)` and selector `0x3abbe80a`
     #[derive(
         Clone,
         ::corebc_contract::EthCall,
@@ -2937,9 +6212,9 @@ pub mod hevm {
         Eq,
         Hash
     )]
-    #[ethcall(name = "expectEmit", abi = "expectEmit()")]
-    pub struct ExpectEmit0Call;
-    ///Container type for all input parameters for the `expectEmit` function with signature `expectEmit(address)` and selector `0xbff0146e`
+    #[ethcall(name = "keyExistsToml", abi = "keyExistsToml(string,string)")]
+    pub struct KeyExistsTomlCall(pub ::std::string::String, pub ::std::string::String);
+    ///Container type for all input parameters for the `label` function with signature `label(address,string)` and selector `0xb167f0ca`
     #[derive(
         Clone,
         ::corebc_contract::EthCall,
@@ -2950,9 +6225,9 @@ pub mod hevm {
         Eq,
         Hash
     )]
-    #[ethcall(name = "expectEmit", abi = "expectEmit(address)")]
-    pub struct ExpectEmit1Call(pub ::corebc_core::types::Address);
-    ///Container type for all input parameters for the `expectEmit` function with signature `expectEmit(bool,bool,bool,bool)` and selector `0xe2e01dd4`
+    #[ethcall(name = "label", abi = "label(address,string)")]
+    pub struct LabelCall(pub ::corebc_core::types::Address, pub ::std::string::String);
+    ///Container type for all input parameters for the `load` function with signature `load(address,bytes32)` and selector `0xfac88623`
     #[derive(
         Clone,
         ::corebc_contract::EthCall,
@@ -2963,9 +6238,9 @@ pub mod hevm {
         Eq,
         Hash
     )]
-    #[ethcall(name = "expectEmit", abi = "expectEmit(bool,bool,bool,bool)")]
-    pub struct ExpectEmit2Call(pub bool, pub bool, pub bool, pub bool);
-    ///Container type for all input parameters for the `expectEmit` function with signature `expectEmit(bool,bool,bool,bool,address)` and selector `0xdb3d4fd8`
+    #[ethcall(name = "load", abi = "load(address,bytes32)")]
+    pub struct LoadCall(pub ::corebc_core::types::Address, pub [u8; 32]);
+    ///Container type for all input parameters for the `makePersistent` function with signature `makePersistent(address)` and selector `0x9ff5ebb8`
     #[derive(
         Clone,
         ::corebc_contract::EthCall,
@@ -2976,15 +6251,9 @@ pub mod hevm {
         Eq,
         Hash
     )]
-    #[ethcall(name = "expectEmit", abi = "expectEmit(bool,bool,bool,bool,address)")]
-    pub struct ExpectEmit3Call(
-        pub bool,
-        pub bool,
-        pub bool,
-        pub bool,
-        pub ::corebc_core::types::Address,
-    );
-    ///Container type for all input parameters for the `expectRevert` function with signature `expectRevert()` and selector `0x10403acb`
+    #[ethcall(name = "makePersistent", abi = "makePersistent(address)")]
+    pub struct MakePersistent0Call(pub ::corebc_core::types::Address);
+    ///Container type for all input parameters for the `makePersistent` function with signature `makePersistent(address,address)` and selector `0x05bd09f0`
     #[derive(
         Clone,
         ::corebc_contract::EthCall,
@@ -2995,9 +6264,12 @@ pub mod hevm {
         Eq,
         Hash
     )]
-    #[ethcall(name = "expectRevert", abi = "expectRevert()")]
-    pub struct ExpectRevert0Call;
-    ///Container type for all input parameters for the `expectRevert` function with signature `expectRevert(bytes)` and selector `0xe37ab4ed`
+    #[ethcall(name = "makePersistent", abi = "makePersistent(address,address)")]
+    pub struct MakePersistent2Call(
+        pub ::corebc_core::types::Address,
+        pub ::corebc_core::types::Address,
+    );
+    ///Container type for all input parameters for the `makePersistent` function with signature `makePersistent(address,address,address)` and selector `0xd0f24610`
     #[derive(
         Clone,
         ::corebc_contract::EthCall,
@@ -3008,9 +6280,13 @@ pub mod hevm {
         Eq,
         Hash
     )]
-    #[ethcall(name = "expectRevert", abi = "expectRevert(bytes)")]
-    pub struct ExpectRevert1Call(pub ::corebc_core::types::Bytes);
-    ///Container type for all input parameters for the `expectRevert` function with signature `expectRevert(bytes4)` and selector `0x4e7614bc`
+    #[ethcall(name = "makePersistent", abi = "makePersistent(address,address,address)")]
+    pub struct MakePersistent3Call(
+        pub ::corebc_core::types::Address,
+        pub ::corebc_core::types::Address,
+        pub ::corebc_core::types::Address,
+    );
+    ///Container type for all input parameters for the `makePersistent` function with signature `makePersistent(address[])` and selector `0xa6180f41`
     #[derive(
         Clone,
         ::corebc_contract::EthCall,
@@ -3021,9 +6297,9 @@ pub mod hevm {
         Eq,
         Hash
     )]
-    #[ethcall(name = "expectRevert", abi = "expectRevert(bytes4)")]
-    pub struct ExpectRevert2Call(pub [u8; 4]);
-    ///Container type for all input parameters for the `expectSafeMemory` function with signature `expectSafeMemory(uint64,uint64)` and selector `0x46bb5493`
+    #[ethcall(name = "makePersistent", abi = "makePersistent(address[])")]
+    pub struct MakePersistent1Call(pub ::std::vec::Vec<::corebc_core::types::Address>);
+    ///Container type for all input parameters for the `mockCall` function with signature `mockCall(address,bytes,bytes)` and selector `0x4b25c120`
     #[derive(
         Clone,
         ::corebc_contract::EthCall,
@@ -3034,9 +6310,13 @@ pub mod hevm {
         Eq,
         Hash
     )]
-    #[ethcall(name = "expectSafeMemory", abi = "expectSafeMemory(uint64,uint64)")]
-    pub struct ExpectSafeMemoryCall(pub u64, pub u64);
-    ///Container type for all input parameters for the `expectSafeMemoryCall` function with signature `expectSafeMemoryCall(uint64,uint64)` and selector `0x9b61c661`
+    #[ethcall(name = "mockCall", abi = "mockCall(address,bytes,bytes)")]
+    pub struct MockCall0Call(
+        pub ::corebc_core::types::Address,
+        pub ::corebc_core::types::Bytes,
+        pub ::corebc_core::types::Bytes,
+    );
+    ///Container type for all input parameters for the `mockCall` function with signature `mockCall(address,uint256,bytes,bytes)` and selector `0xe05abf37`
     #[derive(
         Clone,
         ::corebc_contract::EthCall,
@@ -3047,12 +6327,14 @@ pub mod hevm {
         Eq,
         Hash
     )]
-    #[ethcall(
-        name = "expectSafeMemoryCall",
-        abi = "expectSafeMemoryCall(uint64,uint64)"
-    )]
-    pub struct ExpectSafeMemoryCallCall(pub u64, pub u64);
-    ///Container type for all input parameters for the `fee` function with signature `fee(uint256)` and selector `0xaa32efc1`
+    #[ethcall(name = "mockCall", abi = "mockCall(address,uint256,bytes,bytes)")]
+    pub struct MockCall1Call(
+        pub ::corebc_core::types::Address,
+        pub ::corebc_core::types::U256,
+        pub ::corebc_core::types::Bytes,
+        pub ::corebc_core::types::Bytes,
+    );
+    ///Container type for all input parameters for the `mockCallRevert` function with signature `mockCallRevert(address,bytes,bytes)` and selector `0xe9bb00fb`
     #[derive(
         Clone,
         ::corebc_contract::EthCall,
@@ -3063,9 +6345,13 @@ pub mod hevm {
         Eq,
         Hash
     )]
-    #[ethcall(name = "fee", abi = "fee(uint256)")]
-    pub struct FeeCall(pub ::corebc_core::types::U256);
-    ///Container type for all input parameters for the `ffi` function with signature `ffi(string[])` and selector `0x64ba2b32`
+    #[ethcall(name = "mockCallRevert", abi = "mockCallRevert(address,bytes,bytes)")]
+    pub struct MockCallRevert0Call(
+        pub ::corebc_core::types::Address,
+        pub ::corebc_core::types::Bytes,
+        pub ::corebc_core::types::Bytes,
+    );
+    ///Container type for all input parameters for the `mockCallRevert` function with signature `mockCallRevert(address,uint256,bytes,bytes)` and selector `0xd216223d`
     #[derive(
         Clone,
         ::corebc_contract::EthCall,
@@ -3076,9 +6362,17 @@ pub mod hevm {
         Eq,
         Hash
     )]
-    #[ethcall(name = "ffi", abi = "ffi(string[])")]
-    pub struct FfiCall(pub ::std::vec::Vec<::std::string::String>);
-    ///Container type for all input parameters for the `fsMetadata` function with signature `fsMetadata(string)` and selector `0x895b0357`
+    #[ethcall(
+        name = "mockCallRevert",
+        abi = "mockCallRevert(address,uint256,bytes,bytes)"
+    )]
+    pub struct MockCallRevert1Call(
+        pub ::corebc_core::types::Address,
+        pub ::corebc_core::types::U256,
+        pub ::corebc_core::types::Bytes,
+        pub ::corebc_core::types::Bytes,
+    );
+    ///Container type for all input parameters for the `openFile` function with signature `openFile(string)` and selector `0x87badc3e`
     #[derive(
         Clone,
         ::corebc_contract::EthCall,
@@ -3089,9 +6383,9 @@ pub mod hevm {
         Eq,
         Hash
     )]
-    #[ethcall(name = "fsMetadata", abi = "fsMetadata(string)")]
-    pub struct FsMetadataCall(pub ::std::string::String);
-    ///Container type for all input parameters for the `getCode` function with signature `getCode(string)` and selector `0xf4311698`
+    #[ethcall(name = "openFile", abi = "openFile(string)")]
+    pub struct OpenFileCall(pub ::std::string::String);
+    ///Container type for all input parameters for the `parseAddress` function with signature `parseAddress(string)` and selector `0xbf03d4f6`
     #[derive(
         Clone,
         ::corebc_contract::EthCall,
@@ -3102,9 +6396,9 @@ pub mod hevm {
         Eq,
         Hash
     )]
-    #[ethcall(name = "getCode", abi = "getCode(string)")]
-    pub struct GetCodeCall(pub ::std::string::String);
-    ///Container type for all input parameters for the `getDeployedCode` function with signature `getDeployedCode(string)` and selector `0xe04cfba1`
+    #[ethcall(name = "parseAddress", abi = "parseAddress(string)")]
+    pub struct ParseAddressCall(pub ::std::string::String);
+    ///Container type for all input parameters for the `parseBool` function with signature `parseBool(string)` and selector `0xc711f34c`
     #[derive(
         Clone,
         ::corebc_contract::EthCall,
@@ -3115,9 +6409,9 @@ pub mod hevm {
         Eq,
         Hash
     )]
-    #[ethcall(name = "getDeployedCode", abi = "getDeployedCode(string)")]
-    pub struct GetDeployedCodeCall(pub ::std::string::String);
-    ///Container type for all input parameters for the `getLabel` function with signature `getLabel(address)` and selector `0x56c8fc59`
+    #[ethcall(name = "parseBool", abi = "parseBool(string)")]
+    pub struct ParseBoolCall(pub ::std::string::String);
+    ///Container type for all input parameters for the `parseBytes` function with signature `parseBytes(string)` and selector `0x3dc7ecf1`
     #[derive(
         Clone,
         ::corebc_contract::EthCall,
@@ -3128,9 +6422,9 @@ pub mod hevm {
         Eq,
         Hash
     )]
-    #[ethcall(name = "getLabel", abi = "getLabel(address)")]
-    pub struct GetLabelCall(pub ::corebc_core::types::Address);
-    ///Container type for all input parameters for the `getNonce` function with signature `getNonce(address)` and selector `0x7ee608d7`
+    #[ethcall(name = "parseBytes", abi = "parseBytes(string)")]
+    pub struct ParseBytesCall(pub ::std::string::String);
+    ///Container type for all input parameters for the `parseBytes32` function with signature `parseBytes32(string)` and selector `0x6ea2d6ed`
     #[derive(
         Clone,
         ::corebc_contract::EthCall,
@@ -3141,9 +6435,9 @@ pub mod hevm {
         Eq,
         Hash
     )]
-    #[ethcall(name = "getNonce", abi = "getNonce(address)")]
-    pub struct GetNonceCall(pub ::corebc_core::types::Address);
-    ///Container type for all input parameters for the `getRecordedLogs` function with signature `getRecordedLogs()` and selector `0x6aa9ac15`
+    #[ethcall(name = "parseBytes32", abi = "parseBytes32(string)")]
+    pub struct ParseBytes32Call(pub ::std::string::String);
+    ///Container type for all input parameters for the `parseInt` function with signature `parseInt(string)` and selector `0xcacaea8a`
     #[derive(
         Clone,
         ::corebc_contract::EthCall,
@@ -3154,9 +6448,9 @@ pub mod hevm {
         Eq,
         Hash
     )]
-    #[ethcall(name = "getRecordedLogs", abi = "getRecordedLogs()")]
-    pub struct GetRecordedLogsCall;
-    ///Container type for all input parameters for the `isPersistent` function with signature `isPersistent(address)` and selector `0x27051a9d`
+    #[ethcall(name = "parseInt", abi = "parseInt(string)")]
+    pub struct ParseIntCall(pub ::std::string::String);
+    ///Container type for all input parameters for the `parseJson` function with signature `parseJson(string)` and selector `0x1b14d0fa`
     #[derive(
         Clone,
         ::corebc_contract::EthCall,
@@ -3167,9 +6461,9 @@ pub mod hevm {
         Eq,
         Hash
     )]
-    #[ethcall(name = "isPersistent", abi = "isPersistent(address)")]
-    pub struct IsPersistentCall(pub ::corebc_core::types::Address);
-    ///Container type for all input parameters for the `label` function with signature `label(address,string)` and selector `0xb167f0ca`
+    #[ethcall(name = "parseJson", abi = "parseJson(string)")]
+    pub struct ParseJson0Call(pub ::std::string::String);
+    ///Container type for all input parameters for the `parseJson` function with signature `parseJson(string,string)` and selector `0x9ed12636`
     #[derive(
         Clone,
         ::corebc_contract::EthCall,
@@ -3180,9 +6474,9 @@ pub mod hevm {
         Eq,
         Hash
     )]
-    #[ethcall(name = "label", abi = "label(address,string)")]
-    pub struct LabelCall(pub ::corebc_core::types::Address, pub ::std::string::String);
-    ///Container type for all input parameters for the `load` function with signature `load(address,bytes32)` and selector `0xfac88623`
+    #[ethcall(name = "parseJson", abi = "parseJson(string,string)")]
+    pub struct ParseJson1Call(pub ::std::string::String, pub ::std::string::String);
+    ///Container type for all input parameters for the `parseJsonAddress` function with signature `parseJsonAddress(string,string)` and selector `0x6d037439`
     #[derive(
         Clone,
         ::corebc_contract::EthCall,
@@ -3193,9 +6487,12 @@ pub mod hevm {
         Eq,
         Hash
     )]
-    #[ethcall(name = "load", abi = "load(address,bytes32)")]
-    pub struct LoadCall(pub ::corebc_core::types::Address, pub [u8; 32]);
-    ///Container type for all input parameters for the `makePersistent` function with signature `makePersistent(address)` and selector `0x9ff5ebb8`
+    #[ethcall(name = "parseJsonAddress", abi = "parseJsonAddress(string,string)")]
+    pub struct ParseJsonAddressCall(
+        pub ::std::string::String,
+        pub ::std::string::String,
+    );
+    ///Container type for all input parameters for the `parseJsonAddressArray` function with signature `parseJsonAddressArray(string,string)` and selector `0x73ae0e2b`
     #[derive(
         Clone,
         ::corebc_contract::EthCall,
@@ -3206,9 +6503,15 @@ pub mod hevm {
         Eq,
         Hash
     )]
-    #[ethcall(name = "makePersistent", abi = "makePersistent(address)")]
-    pub struct MakePersistent0Call(pub ::corebc_core::types::Address);
-    ///Container type for all input parameters for the `makePersistent` function with signature `makePersistent(address,address)` and selector `0x05bd09f0`
+    #[ethcall(
+        name = "parseJsonAddressArray",
+        abi = "parseJsonAddressArray(string,string)"
+    )]
+    pub struct ParseJsonAddressArrayCall(
+        pub ::std::string::String,
+        pub ::std::string::String,
+    );
+    ///Container type for all input parameters for the `parseJsonBool` function with signature `parseJsonBool(string,string)` and selector `0x1ddc1e05`
     #[derive(
         Clone,
         ::corebc_contract::EthCall,
@@ -3219,12 +6522,9 @@ pub mod hevm {
         Eq,
         Hash
     )]
-    #[ethcall(name = "makePersistent", abi = "makePersistent(address,address)")]
-    pub struct MakePersistent2Call(
-        pub ::corebc_core::types::Address,
-        pub ::corebc_core::types::Address,
-    );
-    ///Container type for all input parameters for the `makePersistent` function with signature `makePersistent(address,address,address)` and selector `0xd0f24610`
+    #[ethcall(name = "parseJsonBool", abi = "parseJsonBool(string,string)")]
+    pub struct ParseJsonBoolCall(pub ::std::string::String, pub ::std::string::String);
+    ///Container type for all input parameters for the `parseJsonBoolArray` function with signature `parseJsonBoolArray(string,string)` and selector `0x1b7a2c28`
     #[derive(
         Clone,
         ::corebc_contract::EthCall,
@@ -3235,13 +6535,12 @@ pub mod hevm {
         Eq,
         Hash
     )]
-    #[ethcall(name = "makePersistent", abi = "makePersistent(address,address,address)")]
-    pub struct MakePersistent3Call(
-        pub ::corebc_core::types::Address,
-        pub ::corebc_core::types::Address,
-        pub ::corebc_core::types::Address,
+    #[ethcall(name = "parseJsonBoolArray", abi = "parseJsonBoolArray(string,string)")]
+    pub struct ParseJsonBoolArrayCall(
+        pub ::std::string::String,
+        pub ::std::string::String,
     );
-    ///Container type for all input parameters for the `makePersistent` function with signature `makePersistent(address[])` and selector `0xa6180f41`
+    ///Container type for all input parameters for the `parseJsonBytes` function with signature `parseJsonBytes(string,string)` and selector `0x4ae7c4b9`
     #[derive(
         Clone,
         ::corebc_contract::EthCall,
@@ -3252,9 +6551,9 @@ pub mod hevm {
         Eq,
         Hash
     )]
-    #[ethcall(name = "makePersistent", abi = "makePersistent(address[])")]
-    pub struct MakePersistent1Call(pub ::std::vec::Vec<::corebc_core::types::Address>);
-    ///Container type for all input parameters for the `mockCall` function with signature `mockCall(address,bytes,bytes)` and selector `0x4b25c120`
+    #[ethcall(name = "parseJsonBytes", abi = "parseJsonBytes(string,string)")]
+    pub struct ParseJsonBytesCall(pub ::std::string::String, pub ::std::string::String);
+    ///Container type for all input parameters for the `parseJsonBytes32` function with signature `parseJsonBytes32(string,string)` and selector `0x2088d639`
     #[derive(
         Clone,
         ::corebc_contract::EthCall,
@@ -3265,13 +6564,12 @@ pub mod hevm {
         Eq,
         Hash
     )]
-    #[ethcall(name = "mockCall", abi = "mockCall(address,bytes,bytes)")]
-    pub struct MockCall0Call(
-        pub ::corebc_core::types::Address,
-        pub ::corebc_core::types::Bytes,
-        pub ::corebc_core::types::Bytes,
+    #[ethcall(name = "parseJsonBytes32", abi = "parseJsonBytes32(string,string)")]
+    pub struct ParseJsonBytes32Call(
+        pub ::std::string::String,
+        pub ::std::string::String,
     );
-    ///Container type for all input parameters for the `mockCall` function with signature `mockCall(address,uint256,bytes,bytes)` and selector `0xe05abf37`
+    ///Container type for all input parameters for the `parseJsonBytes32Array` function with signature `parseJsonBytes32Array(string,string)` and selector `0x6ed6bf6d`
     #[derive(
         Clone,
         ::corebc_contract::EthCall,
@@ -3282,14 +6580,15 @@ pub mod hevm {
         Eq,
         Hash
     )]
-    #[ethcall(name = "mockCall", abi = "mockCall(address,uint256,bytes,bytes)")]
-    pub struct MockCall1Call(
-        pub ::corebc_core::types::Address,
-        pub ::corebc_core::types::U256,
-        pub ::corebc_core::types::Bytes,
-        pub ::corebc_core::types::Bytes,
+    #[ethcall(
+        name = "parseJsonBytes32Array",
+        abi = "parseJsonBytes32Array(string,string)"
+    )]
+    pub struct ParseJsonBytes32ArrayCall(
+        pub ::std::string::String,
+        pub ::std::string::String,
     );
-    ///Container type for all input parameters for the `mockCallRevert` function with signature `mockCallRevert(address,bytes,bytes)` and selector `0xe9bb00fb`
+    ///Container type for all input parameters for the `parseJsonBytesArray` function with signature `parseJsonBytesArray(string,string)` and selector `0xc7f2b390`
     #[derive(
         Clone,
         ::corebc_contract::EthCall,
@@ -3300,13 +6599,12 @@ pub mod hevm {
         Eq,
         Hash
     )]
-    #[ethcall(name = "mockCallRevert", abi = "mockCallRevert(address,bytes,bytes)")]
-    pub struct MockCallRevert0Call(
-        pub ::corebc_core::types::Address,
-        pub ::corebc_core::types::Bytes,
-        pub ::corebc_core::types::Bytes,
+    #[ethcall(name = "parseJsonBytesArray", abi = "parseJsonBytesArray(string,string)")]
+    pub struct ParseJsonBytesArrayCall(
+        pub ::std::string::String,
+        pub ::std::string::String,
     );
-    ///Container type for all input parameters for the `mockCallRevert` function with signature `mockCallRevert(address,uint256,bytes,bytes)` and selector `0xd216223d`
+    ///Container type for all input parameters for the `parseJsonInt` function with signature `parseJsonInt(string,string)` and selector `0x7e3d173a`
     #[derive(
         Clone,
         ::corebc_contract::EthCall,
@@ -3317,17 +6615,9 @@ pub mod hevm {
         Eq,
         Hash
     )]
-    #[ethcall(
-        name = "mockCallRevert",
-        abi = "mockCallRevert(address,uint256,bytes,bytes)"
-    )]
-    pub struct MockCallRevert1Call(
-        pub ::corebc_core::types::Address,
-        pub ::corebc_core::types::U256,
-        pub ::corebc_core::types::Bytes,
-        pub ::corebc_core::types::Bytes,
-    );
-    ///Container type for all input parameters for the `openFile` function with signature `openFile(string)` and selector `0x87badc3e`
+    #[ethcall(name = "parseJsonInt", abi = "parseJsonInt(string,string)")]
+    pub struct ParseJsonIntCall(pub ::std::string::String, pub ::std::string::String);
+    ///Container type for all input parameters for the `parseJsonIntArray` function with signature `parseJsonIntArray(string,string)` and selector `0xc37c99a1`
     #[derive(
         Clone,
         ::corebc_contract::EthCall,
@@ -3338,9 +6628,12 @@ pub mod hevm {
         Eq,
         Hash
     )]
-    #[ethcall(name = "openFile", abi = "openFile(string)")]
-    pub struct OpenFileCall(pub ::std::string::String);
-    ///Container type for all input parameters for the `parseAddress` function with signature `parseAddress(string)` and selector `0xbf03d4f6`
+    #[ethcall(name = "parseJsonIntArray", abi = "parseJsonIntArray(string,string)")]
+    pub struct ParseJsonIntArrayCall(
+        pub ::std::string::String,
+        pub ::std::string::String,
+    );
+    ///Container type for all input parameters for the `parseJsonKeys` function with signature `parseJsonKeys(string,string)` and selector `0xb110ad24`
     #[derive(
         Clone,
         ::corebc_contract::EthCall,
@@ -3351,9 +6644,9 @@ pub mod hevm {
         Eq,
         Hash
     )]
-    #[ethcall(name = "parseAddress", abi = "parseAddress(string)")]
-    pub struct ParseAddressCall(pub ::std::string::String);
-    ///Container type for all input parameters for the `parseBool` function with signature `parseBool(string)` and selector `0xc711f34c`
+    #[ethcall(name = "parseJsonKeys", abi = "parseJsonKeys(string,string)")]
+    pub struct ParseJsonKeysCall(pub ::std::string::String, pub ::std::string::String);
+    ///Container type for all input parameters for the `parseJsonString` function with signature `parseJsonString(string,string)` and selector `0x9d9b1273`
     #[derive(
         Clone,
         ::corebc_contract::EthCall,
@@ -3364,9 +6657,9 @@ pub mod hevm {
         Eq,
         Hash
     )]
-    #[ethcall(name = "parseBool", abi = "parseBool(string)")]
-    pub struct ParseBoolCall(pub ::std::string::String);
-    ///Container type for all input parameters for the `parseBytes` function with signature `parseBytes(string)` and selector `0x3dc7ecf1`
+    #[ethcall(name = "parseJsonString", abi = "parseJsonString(string,string)")]
+    pub struct ParseJsonStringCall(pub ::std::string::String, pub ::std::string::String);
+    ///Container type for all input parameters for the `parseJsonStringArray` function with signature `parseJsonStringArray(string,string)` and selector `0x6499a16c`
     #[derive(
         Clone,
         ::corebc_contract::EthCall,
@@ -3377,9 +6670,15 @@ pub mod hevm {
         Eq,
         Hash
     )]
-    #[ethcall(name = "parseBytes", abi = "parseBytes(string)")]
-    pub struct ParseBytesCall(pub ::std::string::String);
-    ///Container type for all input parameters for the `parseBytes32` function with signature `parseBytes32(string)` and selector `0x6ea2d6ed`
+    #[ethcall(
+        name = "parseJsonStringArray",
+        abi = "parseJsonStringArray(string,string)"
+    )]
+    pub struct ParseJsonStringArrayCall(
+        pub ::std::string::String,
+        pub ::std::string::String,
+    );
+    ///Container type for all input parameters for the `parseJsonUint` function with signature `parseJsonUint(string,string)` and selector `0x7aa5f1ff`
     #[derive(
         Clone,
         ::corebc_contract::EthCall,
@@ -3390,9 +6689,9 @@ pub mod hevm {
         Eq,
         Hash
     )]
-    #[ethcall(name = "parseBytes32", abi = "parseBytes32(string)")]
-    pub struct ParseBytes32Call(pub ::std::string::String);
-    ///Container type for all input parameters for the `parseInt` function with signature `parseInt(string)` and selector `0xcacaea8a`
+    #[ethcall(name = "parseJsonUint", abi = "parseJsonUint(string,string)")]
+    pub struct ParseJsonUintCall(pub ::std::string::String, pub ::std::string::String);
+    ///Container type for all input parameters for the `parseJsonUintArray` function with signature `parseJsonUintArray(string,string)` and selector `0x3118994d`
     #[derive(
         Clone,
         ::corebc_contract::EthCall,
@@ -3403,9 +6702,12 @@ pub mod hevm {
         Eq,
         Hash
     )]
-    #[ethcall(name = "parseInt", abi = "parseInt(string)")]
-    pub struct ParseIntCall(pub ::std::string::String);
-    ///Container type for all input parameters for the `parseJson` function with signature `parseJson(string)` and selector `0x1b14d0fa`
+    #[ethcall(name = "parseJsonUintArray", abi = "parseJsonUintArray(string,string)")]
+    pub struct ParseJsonUintArrayCall(
+        pub ::std::string::String,
+        pub ::std::string::String,
+    );
+    ///Container type for all input parameters for the `parseToml` function with signature `parseToml(string)` and selector `0x86ee4f75`
     #[derive(
         Clone,
         ::corebc_contract::EthCall,
@@ -3416,9 +6718,9 @@ pub mod hevm {
         Eq,
         Hash
     )]
-    #[ethcall(name = "parseJson", abi = "parseJson(string)")]
-    pub struct ParseJson0Call(pub ::std::string::String);
-    ///Container type for all input parameters for the `parseJson` function with signature `parseJson(string,string)` and selector `0x9ed12636`
+    #[ethcall(name = "parseToml", abi = "parseToml(string)")]
+    pub struct ParseToml0Call(pub ::std::string::String);
+    ///Container type for all input parameters for the `parseToml` function with signature `parseToml(string,string)` and selector `0xf62093d7`
     #[derive(
         Clone,
         ::corebc_contract::EthCall,
@@ -3429,9 +6731,9 @@ pub mod hevm {
         Eq,
         Hash
     )]
-    #[ethcall(name = "parseJson", abi = "parseJson(string,string)")]
-    pub struct ParseJson1Call(pub ::std::string::String, pub ::std::string::String);
-    ///Container type for all input parameters for the `parseJsonAddress` function with signature `parseJsonAddress(string,string)` and selector `0x6d037439`
+    #[ethcall(name = "parseToml", abi = "parseToml(string,string)")]
+    pub struct ParseToml1Call(pub ::std::string::String, pub ::std::string::String);
+    ///Container type for all input parameters for the `parseTomlAddress` function with signature `parseTomlAddress(string,string)` and selector `0xb6e35291`
     #[derive(
         Clone,
         ::corebc_contract::EthCall,
@@ -3442,12 +6744,12 @@ pub mod hevm {
         Eq,
         Hash
     )]
-    #[ethcall(name = "parseJsonAddress", abi = "parseJsonAddress(string,string)")]
-    pub struct ParseJsonAddressCall(
+    #[ethcall(name = "parseTomlAddress", abi = "parseTomlAddress(string,string)")]
+    pub struct ParseTomlAddressCall(
         pub ::std::string::String,
         pub ::std::string::String,
     );
-    ///Container type for all input parameters for the `parseJsonAddressArray` function with signature `parseJsonAddressArray(string,string)` and selector `0x73ae0e2b`
+    ///Container type for all input parameters for the `parseTomlAddressArray` function with signature `parseTomlAddressArray(string,string)` and selector `0x9d14a569`
     #[derive(
         Clone,
         ::corebc_contract::EthCall,
@@ -3459,14 +6761,14 @@ pub mod hevm {
         Hash
     )]
     #[ethcall(
-        name = "parseJsonAddressArray",
-        abi = "parseJsonAddressArray(string,string)"
+        name = "parseTomlAddressArray",
+        abi = "parseTomlAddressArray(string,string)"
     )]
-    pub struct ParseJsonAddressArrayCall(
+    pub struct ParseTomlAddressArrayCall(
         pub ::std::string::String,
         pub ::std::string::String,
     );
-    ///Container type for all input parameters for the `parseJsonBool` function with signature `parseJsonBool(string,string)` and selector `0x1ddc1e05`
+    ///Container type for all input parameters for the `parseTomlBool` function with signature `parseTomlBool(string,string)` and selector `0x66a6bf4d`
     #[derive(
         Clone,
         ::corebc_contract::EthCall,
@@ -3477,9 +6779,9 @@ pub mod hevm {
         Eq,
         Hash
     )]
-    #[ethcall(name = "parseJsonBool", abi = "parseJsonBool(string,string)")]
-    pub struct ParseJsonBoolCall(pub ::std::string::String, pub ::std::string::String);
-    ///Container type for all input parameters for the `parseJsonBoolArray` function with signature `parseJsonBoolArray(string,string)` and selector `0x1b7a2c28`
+    #[ethcall(name = "parseTomlBool", abi = "parseTomlBool(string,string)")]
+    pub struct ParseTomlBoolCall(pub ::std::string::String, pub ::std::string::String);
+    ///Container type for all input parameters for the `parseTomlBoolArray` function with signature `parseTomlBoolArray(string,string)` and selector `0xc1556c81`
     #[derive(
         Clone,
         ::corebc_contract::EthCall,
@@ -3490,12 +6792,12 @@ pub mod hevm {
         Eq,
         Hash
     )]
-    #[ethcall(name = "parseJsonBoolArray", abi = "parseJsonBoolArray(string,string)")]
-    pub struct ParseJsonBoolArrayCall(
+    #[ethcall(name = "parseTomlBoolArray", abi = "parseTomlBoolArray(string,string)")]
+    pub struct ParseTomlBoolArrayCall(
         pub ::std::string::String,
         pub ::std::string::String,
     );
-    ///Container type for all input parameters for the `parseJsonBytes` function with signature `parseJsonBytes(string,string)` and selector `0x4ae7c4b9`
+    ///Container type for all input parameters for the `parseTomlBytes` function with signature `parseTomlBytes(string,string)` and selector `0x4b072717`
     #[derive(
         Clone,
         ::corebc_contract::EthCall,
@@ -3506,9 +6808,9 @@ pub mod hevm {
         Eq,
         Hash
     )]
-    #[ethcall(name = "parseJsonBytes", abi = "parseJsonBytes(string,string)")]
-    pub struct ParseJsonBytesCall(pub ::std::string::String, pub ::std::string::String);
-    ///Container type for all input parameters for the `parseJsonBytes32` function with signature `parseJsonBytes32(string,string)` and selector `0x2088d639`
+    #[ethcall(name = "parseTomlBytes", abi = "parseTomlBytes(string,string)")]
+    pub struct ParseTomlBytesCall(pub ::std::string::String, pub ::std::string::String);
+    ///Container type for all input parameters for the `parseTomlBytes32` function with signature `parseTomlBytes32(string,string)` and selector `0x85c38481`
     #[derive(
         Clone,
         ::corebc_contract::EthCall,
@@ -3519,12 +6821,12 @@ pub mod hevm {
         Eq,
         Hash
     )]
-    #[ethcall(name = "parseJsonBytes32", abi = "parseJsonBytes32(string,string)")]
-    pub struct ParseJsonBytes32Call(
+    #[ethcall(name = "parseTomlBytes32", abi = "parseTomlBytes32(string,string)")]
+    pub struct ParseTomlBytes32Call(
         pub ::std::string::String,
         pub ::std::string::String,
     );
-    ///Container type for all input parameters for the `parseJsonBytes32Array` function with signature `parseJsonBytes32Array(string,string)` and selector `0x6ed6bf6d`
+    ///Container type for all input parameters for the `parseTomlBytes32Array` function with signature `parseTomlBytes32Array(string,string)` and selector `0x1bff6760`
     #[derive(
         Clone,
         ::corebc_contract::EthCall,
@@ -3536,14 +6838,14 @@ pub mod hevm {
         Hash
     )]
     #[ethcall(
-        name = "parseJsonBytes32Array",
-        abi = "parseJsonBytes32Array(string,string)"
+        name = "parseTomlBytes32Array",
+        abi = "parseTomlBytes32Array(string,string)"
     )]
-    pub struct ParseJsonBytes32ArrayCall(
+    pub struct ParseTomlBytes32ArrayCall(
         pub ::std::string::String,
         pub ::std::string::String,
     );
-    ///Container type for all input parameters for the `parseJsonBytesArray` function with signature `parseJsonBytesArray(string,string)` and selector `0xc7f2b390`
+    ///Container type for all input parameters for the `parseTomlBytesArray` function with signature `parseTomlBytesArray(string,string)` and selector `0xfcd16926`
     #[derive(
         Clone,
         ::corebc_contract::EthCall,
@@ -3554,12 +6856,12 @@ pub mod hevm {
         Eq,
         Hash
     )]
-    #[ethcall(name = "parseJsonBytesArray", abi = "parseJsonBytesArray(string,string)")]
-    pub struct ParseJsonBytesArrayCall(
+    #[ethcall(name = "parseTomlBytesArray", abi = "parseTomlBytesArray(string,string)")]
+    pub struct ParseTomlBytesArrayCall(
         pub ::std::string::String,
         pub ::std::string::String,
     );
-    ///Container type for all input parameters for the `parseJsonInt` function with signature `parseJsonInt(string,string)` and selector `0x7e3d173a`
+    ///Container type for all input parameters for the `parseTomlInt` function with signature `parseTomlInt(string,string)` and selector `0xf0788b93`
     #[derive(
         Clone,
         ::corebc_contract::EthCall,
@@ -3570,9 +6872,9 @@ pub mod hevm {
         Eq,
         Hash
     )]
-    #[ethcall(name = "parseJsonInt", abi = "parseJsonInt(string,string)")]
-    pub struct ParseJsonIntCall(pub ::std::string::String, pub ::std::string::String);
-    ///Container type for all input parameters for the `parseJsonIntArray` function with signature `parseJsonIntArray(string,string)` and selector `0xc37c99a1`
+    #[ethcall(name = "parseTomlInt", abi = "parseTomlInt(string,string)")]
+    pub struct ParseTomlIntCall(pub ::std::string::String, pub ::std::string::String);
+    ///Container type for all input parameters for the `parseTomlIntArray` function with signature `parseTomlIntArray(string,string)` and selector `0xd68057a4`
     #[derive(
         Clone,
         ::corebc_contract::EthCall,
@@ -3583,12 +6885,12 @@ pub mod hevm {
         Eq,
         Hash
     )]
-    #[ethcall(name = "parseJsonIntArray", abi = "parseJsonIntArray(string,string)")]
-    pub struct ParseJsonIntArrayCall(
+    #[ethcall(name = "parseTomlIntArray", abi = "parseTomlIntArray(string,string)")]
+    pub struct ParseTomlIntArrayCall(
         pub ::std::string::String,
         pub ::std::string::String,
     );
-    ///Container type for all input parameters for the `parseJsonString` function with signature `parseJsonString(string,string)` and selector `0x9d9b1273`
+    ///Container type for all input parameters for the `parseTomlKeys` function with signature `parseTomlKeys(string,string)` and selector `0x4546f736`
     #[derive(
         Clone,
         ::corebc_contract::EthCall,
@@ -3599,9 +6901,22 @@ pub mod hevm {
         Eq,
         Hash
     )]
-    #[ethcall(name = "parseJsonString", abi = "parseJsonString(string,string)")]
-    pub struct ParseJsonStringCall(pub ::std::string::String, pub ::std::string::String);
-    ///Container type for all input parameters for the `parseJsonStringArray` function with signature `parseJsonStringArray(string,string)` and selector `0x6499a16c`
+    #[ethcall(name = "parseTomlKeys", abi = "parseTomlKeys(string,string)")]
+    pub struct ParseTomlKeysCall(pub ::std::string::String, pub ::std::string::String);
+    ///Container type for all input parameters for the `parseTomlString` function with signature `parseTomlString(string,string)` and selector `0x75027319`
+    #[derive(
+        Clone,
+        ::corebc_contract::EthCall,
+        ::corebc_contract::EthDisplay,
+        Default,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash
+    )]
+    #[ethcall(name = "parseTomlString", abi = "parseTomlString(string,string)")]
+    pub struct ParseTomlStringCall(pub ::std::string::String, pub ::std::string::String);
+    ///Container type for all input parameters for the `parseTomlStringArray` function with signature `parseTomlStringArray(string,string)` and selector `0xcdc04e0c`
     #[derive(
         Clone,
         ::corebc_contract::EthCall,
@@ -3613,14 +6928,14 @@ pub mod hevm {
         Hash
     )]
     #[ethcall(
-        name = "parseJsonStringArray",
-        abi = "parseJsonStringArray(string,string)"
+        name = "parseTomlStringArray",
+        abi = "parseTomlStringArray(string,string)"
     )]
-    pub struct ParseJsonStringArrayCall(
+    pub struct ParseTomlStringArrayCall(
         pub ::std::string::String,
         pub ::std::string::String,
     );
-    ///Container type for all input parameters for the `parseJsonUint` function with signature `parseJsonUint(string,string)` and selector `0x7aa5f1ff`
+    ///Container type for all input parameters for the `parseTomlUint` function with signature `parseTomlUint(string,string)` and selector `0xa444e113`
     #[derive(
         Clone,
         ::corebc_contract::EthCall,
@@ -3631,9 +6946,9 @@ pub mod hevm {
         Eq,
         Hash
     )]
-    #[ethcall(name = "parseJsonUint", abi = "parseJsonUint(string,string)")]
-    pub struct ParseJsonUintCall(pub ::std::string::String, pub ::std::string::String);
-    ///Container type for all input parameters for the `parseJsonUintArray` function with signature `parseJsonUintArray(string,string)` and selector `0x3118994d`
+    #[ethcall(name = "parseTomlUint", abi = "parseTomlUint(string,string)")]
+    pub struct ParseTomlUintCall(pub ::std::string::String, pub ::std::string::String);
+    ///Container type for all input parameters for the `parseTomlUintArray` function with signature `parseTomlUintArray(string,string)` and selector `0x93fa17f9`
     #[derive(
         Clone,
         ::corebc_contract::EthCall,
@@ -3644,8 +6959,8 @@ pub mod hevm {
         Eq,
         Hash
     )]
-    #[ethcall(name = "parseJsonUintArray", abi = "parseJsonUintArray(string,string)")]
-    pub struct ParseJsonUintArrayCall(
+    #[ethcall(name = "parseTomlUintArray", abi = "parseTomlUintArray(string,string)")]
+    pub struct ParseTomlUintArrayCall(
         pub ::std::string::String,
         pub ::std::string::String,
     );
@@ -4058,6 +7373,22 @@ pub mod hevm {
     )]
     #[ethcall(name = "rpcUrls", abi = "rpcUrls()")]
     pub struct RpcUrlsCall;
+    ///Container type for all input parameters for the `saveDeployment` function with signature `saveDeployment(string,address)` and selector `0xa6a50bf9`
+    #[derive(
+        Clone,
+        ::corebc_contract::EthCall,
+        ::corebc_contract::EthDisplay,
+        Default,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash
+    )]
+    #[ethcall(name = "saveDeployment", abi = "saveDeployment(string,address)")]
+    pub struct SaveDeploymentCall(
+        pub ::std::string::String,
+        pub ::corebc_core::types::Address,
+    );
     ///Container type for all input parameters for the `selectFork` function with signature `selectFork(uint256)` and selector `0x4669f876`
     #[derive(
         Clone,
@@ -4399,6 +7730,22 @@ pub mod hevm {
     )]
     #[ethcall(name = "snapshot", abi = "snapshot()")]
     pub struct SnapshotCall;
+    ///Container type for all input parameters for the `snapshotValue` function with signature `snapshotValue(string,uint256)` and selector `0x54e001e0`
+    #[derive(
+        Clone,
+        ::corebc_contract::EthCall,
+        ::corebc_contract::EthDisplay,
+        Default,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash
+    )]
+    #[ethcall(name = "snapshotValue", abi = "snapshotValue(string,uint256)")]
+    pub struct SnapshotValueCall(
+        pub ::std::string::String,
+        pub ::corebc_core::types::U256,
+    );
     ///Container type for all input parameters for the `startBroadcast` function with signature `startBroadcast()` and selector `0x423144a6`
     #[derive(
         Clone,
@@ -4467,6 +7814,19 @@ pub mod hevm {
         pub ::corebc_core::types::Address,
         pub ::corebc_core::types::Address,
     );
+    ///Container type for all input parameters for the `startSnapshotEnergy` function with signature `startSnapshotEnergy(string)` and selector `0x59dc86f3`
+    #[derive(
+        Clone,
+        ::corebc_contract::EthCall,
+        ::corebc_contract::EthDisplay,
+        Default,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash
+    )]
+    #[ethcall(name = "startSnapshotEnergy", abi = "startSnapshotEnergy(string)")]
+    pub struct StartSnapshotEnergyCall(pub ::std::string::String);
     ///Container type for all input parameters for the `stopBroadcast` function with signature `stopBroadcast()` and selector `0x741490df`
     #[derive(
         Clone,
@@ -4491,8 +7851,21 @@ pub mod hevm {
         Eq,
         Hash
     )]
-    #[ethcall(name = "stopPrank", abi = "stopPrank()")]
-    pub struct StopPrankCall;
+    #[ethcall(name = "stopPrank", abi = "stopPrank()")]
+    pub struct StopPrankCall;
+    ///Container type for all input parameters for the `stopSnapshotEnergy` function with signature `stopSnapshotEnergy(string)` and selector `0xb1740636`
+    #[derive(
+        Clone,
+        ::corebc_contract::EthCall,
+        ::corebc_contract::EthDisplay,
+        Default,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash
+    )]
+    #[ethcall(name = "stopSnapshotEnergy", abi = "stopSnapshotEnergy(string)")]
+    pub struct StopSnapshotEnergyCall(pub ::std::string::String);
     ///Container type for all input parameters for the `store` function with signature `store(address,bytes32,bytes32)` and selector `0x404ab67b`
     #[derive(
         Clone,
@@ -4708,6 +8081,36 @@ pub mod hevm {
     )]
     #[ethcall(name = "writeLine", abi = "writeLine(string,string)")]
     pub struct WriteLineCall(pub ::std::string::String, pub ::std::string::String);
+    ///Container type for all input parameters for the `writeToml` function with signature `writeToml(string,string)` and selector `0xc5e09da4`
+    #[derive(
+        Clone,
+        ::corebc_contract::EthCall,
+        ::corebc_contract::EthDisplay,
+        Default,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash
+    )]
+    #[ethcall(name = "writeToml", abi = "writeToml(string,string)")]
+    pub struct WriteToml0Call(pub ::std::string::String, pub ::std::string::String);
+    ///Container type for all input parameters for the `writeToml` function with signature `writeToml(string,string,string)` and selector `0x1392b0a0`
+    #[derive(
+        Clone,
+        ::corebc_contract::EthCall,
+        ::corebc_contract::EthDisplay,
+        Default,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash
+    )]
+    #[ethcall(name = "writeToml", abi = "writeToml(string,string,string)")]
+    pub struct WriteToml1Call(
+        pub ::std::string::String,
+        pub ::std::string::String,
+        pub ::std::string::String,
+    );
     ///Container type for all of the contract's call
     #[derive(Clone, ::corebc_contract::EthAbiType, Debug, PartialEq, Eq, Hash)]
     pub enum HEVMCalls {
@@ -4715,6 +8118,90 @@ pub mod hevm {
         ActiveFork(ActiveForkCall),
         Addr(AddrCall),
         AllowCheatcodes(AllowCheatcodesCall),
+        AssertApproxEqAbs0(AssertApproxEqAbs0Call),
+        AssertApproxEqAbs2(AssertApproxEqAbs2Call),
+        AssertApproxEqAbs1(AssertApproxEqAbs1Call),
+        AssertApproxEqAbs3(AssertApproxEqAbs3Call),
+        AssertApproxEqRel0(AssertApproxEqRel0Call),
+        AssertApproxEqRel2(AssertApproxEqRel2Call),
+        AssertApproxEqRel1(AssertApproxEqRel1Call),
+        AssertApproxEqRel3(AssertApproxEqRel3Call),
+        AssertEq0(AssertEq0Call),
+        AssertEq14(AssertEq14Call),
+        AssertEq1(AssertEq1Call),
+        AssertEq15(AssertEq15Call),
+        AssertEq2(AssertEq2Call),
+        AssertEq16(AssertEq16Call),
+        AssertEq3(AssertEq3Call),
+        AssertEq17(AssertEq17Call),
+        AssertEq4(AssertEq4Call),
+        AssertEq18(AssertEq18Call),
+        AssertEq5(AssertEq5Call),
+        AssertEq19(AssertEq19Call),
+        AssertEq6(AssertEq6Call),
+        AssertEq20(AssertEq20Call),
+        AssertEq7(AssertEq7Call),
+        AssertEq21(AssertEq21Call),
+        AssertEq8(AssertEq8Call),
+        AssertEq22(AssertEq22Call),
+        AssertEq9(AssertEq9Call),
+        AssertEq23(AssertEq23Call),
+        AssertEq10(AssertEq10Call),
+        AssertEq24(AssertEq24Call),
+        AssertEq11(AssertEq11Call),
+        AssertEq25(AssertEq25Call),
+        AssertEq12(AssertEq12Call),
+        AssertEq26(AssertEq26Call),
+        AssertEq13(AssertEq13Call),
+        AssertEq27(AssertEq27Call),
+        AssertFalse0(AssertFalse0Call),
+        AssertFalse1(AssertFalse1Call),
+        AssertGe0(AssertGe0Call),
+        AssertGe2(AssertGe2Call),
+        AssertGe1(AssertGe1Call),
+        AssertGe3(AssertGe3Call),
+        AssertGt0(AssertGt0Call),
+        AssertGt2(AssertGt2Call),
+        AssertGt1(AssertGt1Call),
+        AssertGt3(AssertGt3Call),
+        AssertLe0(AssertLe0Call),
+        AssertLe2(AssertLe2Call),
+        AssertLe1(AssertLe1Call),
+        AssertLe3(AssertLe3Call),
+        AssertLt0(AssertLt0Call),
+        AssertLt2(AssertLt2Call),
+        AssertLt1(AssertLt1Call),
+        AssertLt3(AssertLt3Call),
+        AssertNotEq0(AssertNotEq0Call),
+        AssertNotEq14(AssertNotEq14Call),
+        AssertNotEq1(AssertNotEq1Call),
+        AssertNotEq15(AssertNotEq15Call),
+        AssertNotEq2(AssertNotEq2Call),
+        AssertNotEq16(AssertNotEq16Call),
+        AssertNotEq3(AssertNotEq3Call),
+        AssertNotEq17(AssertNotEq17Call),
+        AssertNotEq4(AssertNotEq4Call),
+        AssertNotEq18(AssertNotEq18Call),
+        AssertNotEq5(AssertNotEq5Call),
+        AssertNotEq19(AssertNotEq19Call),
+        AssertNotEq6(AssertNotEq6Call),
+        AssertNotEq20(AssertNotEq20Call),
+        AssertNotEq7(AssertNotEq7Call),
+        AssertNotEq21(AssertNotEq21Call),
+        AssertNotEq8(AssertNotEq8Call),
+        AssertNotEq22(AssertNotEq22Call),
+        AssertNotEq9(AssertNotEq9Call),
+        AssertNotEq23(AssertNotEq23Call),
+        AssertNotEq10(AssertNotEq10Call),
+        AssertNotEq24(AssertNotEq24Call),
+        AssertNotEq11(AssertNotEq11Call),
+        AssertNotEq25(AssertNotEq25Call),
+        AssertNotEq12(AssertNotEq12Call),
+        AssertNotEq26(AssertNotEq26Call),
+        AssertNotEq13(AssertNotEq13Call),
+        AssertNotEq27(AssertNotEq27Call),
+        AssertTrue0(AssertTrue0Call),
+        AssertTrue1(AssertTrue1Call),
         Assume(AssumeCall),
         Breakpoint0(Breakpoint0Call),
         Breakpoint1(Breakpoint1Call),
@@ -4732,6 +8219,7 @@ pub mod hevm {
         CreateSelectFork1(CreateSelectFork1Call),
         CreateSelectFork2(CreateSelectFork2Call),
         CreateSelectFork0(CreateSelectFork0Call),
+        CreateWallet(CreateWalletCall),
         Deal(DealCall),
         DeriveKey0(DeriveKey0Call),
         DeriveKey1(DeriveKey1Call),
@@ -4779,9 +8267,25 @@ pub mod hevm {
         ExpectEmit1(ExpectEmit1Call),
         ExpectEmit2(ExpectEmit2Call),
         ExpectEmit3(ExpectEmit3Call),
+        ExpectEmit4(ExpectEmit4Call),
+        ExpectEmit5(ExpectEmit5Call),
+        ExpectEmit6(ExpectEmit6Call),
+        ExpectEmit7(ExpectEmit7Call),
+        ExpectEmitAnonymous0(ExpectEmitAnonymous0Call),
+        ExpectEmitAnonymous1(ExpectEmitAnonymous1Call),
+        ExpectEmitAnonymous2(ExpectEmitAnonymous2Call),
+        ExpectEmitAnonymous3(ExpectEmitAnonymous3Call),
+        ExpectPartialRevert0(ExpectPartialRevert0Call),
+        ExpectPartialRevert1(ExpectPartialRevert1Call),
         ExpectRevert0(ExpectRevert0Call),
         ExpectRevert1(ExpectRevert1Call),
         ExpectRevert2(ExpectRevert2Call),
+        ExpectRevert3(ExpectRevert3Call),
+        ExpectRevert4(ExpectRevert4Call),
+        ExpectRevert5(ExpectRevert5Call),
+        ExpectRevert6(ExpectRevert6Call),
+        ExpectRevert7(ExpectRevert7Call),
+        ExpectRevert8(ExpectRevert8Call),
         ExpectSafeMemory(ExpectSafeMemoryCall),
         ExpectSafeMemoryCall(ExpectSafeMemoryCallCall),
         Fee(FeeCall),
@@ -4789,10 +8293,14 @@ pub mod hevm {
         FsMetadata(FsMetadataCall),
         GetCode(GetCodeCall),
         GetDeployedCode(GetDeployedCodeCall),
+        GetDeployment0(GetDeployment0Call),
+        GetDeployment1(GetDeployment1Call),
         GetLabel(GetLabelCall),
         GetNonce(GetNonceCall),
         GetRecordedLogs(GetRecordedLogsCall),
         IsPersistent(IsPersistentCall),
+        KeyExistsJson(KeyExistsJsonCall),
+        KeyExistsToml(KeyExistsTomlCall),
         Label(LabelCall),
         Load(LoadCall),
         MakePersistent0(MakePersistent0Call),
@@ -4821,10 +8329,28 @@ pub mod hevm {
         ParseJsonBytesArray(ParseJsonBytesArrayCall),
         ParseJsonInt(ParseJsonIntCall),
         ParseJsonIntArray(ParseJsonIntArrayCall),
+        ParseJsonKeys(ParseJsonKeysCall),
         ParseJsonString(ParseJsonStringCall),
         ParseJsonStringArray(ParseJsonStringArrayCall),
         ParseJsonUint(ParseJsonUintCall),
         ParseJsonUintArray(ParseJsonUintArrayCall),
+        ParseToml0(ParseToml0Call),
+        ParseToml1(ParseToml1Call),
+        ParseTomlAddress(ParseTomlAddressCall),
+        ParseTomlAddressArray(ParseTomlAddressArrayCall),
+        ParseTomlBool(ParseTomlBoolCall),
+        ParseTomlBoolArray(ParseTomlBoolArrayCall),
+        ParseTomlBytes(ParseTomlBytesCall),
+        ParseTomlBytes32(ParseTomlBytes32Call),
+        ParseTomlBytes32Array(ParseTomlBytes32ArrayCall),
+        ParseTomlBytesArray(ParseTomlBytesArrayCall),
+        ParseTomlInt(ParseTomlIntCall),
+        ParseTomlIntArray(ParseTomlIntArrayCall),
+        ParseTomlKeys(ParseTomlKeysCall),
+        ParseTomlString(ParseTomlStringCall),
+        ParseTomlStringArray(ParseTomlStringArrayCall),
+        ParseTomlUint(ParseTomlUintCall),
+        ParseTomlUintArray(ParseTomlUintArrayCall),
         ParseUint(ParseUintCall),
         PauseGasMetering(PauseGasMeteringCall),
         Prank0(Prank0Call),
@@ -4856,6 +8382,7 @@ pub mod hevm {
         RpcUrl(RpcUrlCall),
         RpcUrlStructs(RpcUrlStructsCall),
         RpcUrls(RpcUrlsCall),
+        SaveDeployment(SaveDeploymentCall),
         SelectFork(SelectForkCall),
         SerializeAddress0(SerializeAddress0Call),
         SerializeAddress1(SerializeAddress1Call),
@@ -4877,13 +8404,16 @@ pub mod hevm {
         Sign(SignCall),
         Skip(SkipCall),
         Snapshot(SnapshotCall),
+        SnapshotValue(SnapshotValueCall),
         StartBroadcast0(StartBroadcast0Call),
         StartBroadcast1(StartBroadcast1Call),
         StartBroadcast2(StartBroadcast2Call),
         StartPrank0(StartPrank0Call),
         StartPrank1(StartPrank1Call),
+        StartSnapshotEnergy(StartSnapshotEnergyCall),
         StopBroadcast(StopBroadcastCall),
         StopPrank(StopPrankCall),
+        StopSnapshotEnergy(StopSnapshotEnergyCall),
         Store(StoreCall),
         ToString0(ToString0Call),
         ToString1(ToString1Call),
@@ -4900,6 +8430,8 @@ pub mod hevm {
         WriteJson0(WriteJson0Call),
         WriteJson1(WriteJson1Call),
         WriteLine(WriteLineCall),
+        WriteToml0(WriteToml0Call),
+        WriteToml1(WriteToml1Call),
     }
     impl ::corebc_core::abi::AbiDecode for HEVMCalls {
         fn decode(
@@ -4909,22 +8441,442 @@ pub mod hevm {
             if let Ok(decoded) = <AccessesCall as ::corebc_core::abi::AbiDecode>::decode(
                 data,
             ) {
-                return Ok(Self::Accesses(decoded));
+                return Ok(Self::Accesses(decoded));
+            }
+            if let Ok(decoded) = <ActiveForkCall as ::corebc_core::abi::AbiDecode>::decode(
+                data,
+            ) {
+                return Ok(Self::ActiveFork(decoded));
+            }
+            if let Ok(decoded) = <AddrCall as ::corebc_core::abi::AbiDecode>::decode(
+                data,
+            ) {
+                return Ok(Self::Addr(decoded));
+            }
+            if let Ok(decoded) = <AllowCheatcodesCall as ::corebc_core::abi::AbiDecode>::decode(
+                data,
+            ) {
+                return Ok(Self::AllowCheatcodes(decoded));
+            }
+            if let Ok(decoded) = <AssertApproxEqAbs0Call as ::corebc_core::abi::AbiDecode>::decode(
+                data,
+            ) {
+                return Ok(Self::AssertApproxEqAbs0(decoded));
+            }
+            if let Ok(decoded) = <AssertApproxEqAbs2Call as ::corebc_core::abi::AbiDecode>::decode(
+                data,
+            ) {
+                return Ok(Self::AssertApproxEqAbs2(decoded));
+            }
+            if let Ok(decoded) = <AssertApproxEqAbs1Call as ::corebc_core::abi::AbiDecode>::decode(
+                data,
+            ) {
+                return Ok(Self::AssertApproxEqAbs1(decoded));
+            }
+            if let Ok(decoded) = <AssertApproxEqAbs3Call as ::corebc_core::abi::AbiDecode>::decode(
+                data,
+            ) {
+                return Ok(Self::AssertApproxEqAbs3(decoded));
+            }
+            if let Ok(decoded) = <AssertApproxEqRel0Call as ::corebc_core::abi::AbiDecode>::decode(
+                data,
+            ) {
+                return Ok(Self::AssertApproxEqRel0(decoded));
+            }
+            if let Ok(decoded) = <AssertApproxEqRel2Call as ::corebc_core::abi::AbiDecode>::decode(
+                data,
+            ) {
+                return Ok(Self::AssertApproxEqRel2(decoded));
+            }
+            if let Ok(decoded) = <AssertApproxEqRel1Call as ::corebc_core::abi::AbiDecode>::decode(
+                data,
+            ) {
+                return Ok(Self::AssertApproxEqRel1(decoded));
+            }
+            if let Ok(decoded) = <AssertApproxEqRel3Call as ::corebc_core::abi::AbiDecode>::decode(
+                data,
+            ) {
+                return Ok(Self::AssertApproxEqRel3(decoded));
+            }
+            if let Ok(decoded) = <AssertEq0Call as ::corebc_core::abi::AbiDecode>::decode(
+                data,
+            ) {
+                return Ok(Self::AssertEq0(decoded));
+            }
+            if let Ok(decoded) = <AssertEq14Call as ::corebc_core::abi::AbiDecode>::decode(
+                data,
+            ) {
+                return Ok(Self::AssertEq14(decoded));
+            }
+            if let Ok(decoded) = <AssertEq1Call as ::corebc_core::abi::AbiDecode>::decode(
+                data,
+            ) {
+                return Ok(Self::AssertEq1(decoded));
+            }
+            if let Ok(decoded) = <AssertEq15Call as ::corebc_core::abi::AbiDecode>::decode(
+                data,
+            ) {
+                return Ok(Self::AssertEq15(decoded));
+            }
+            if let Ok(decoded) = <AssertEq2Call as ::corebc_core::abi::AbiDecode>::decode(
+                data,
+            ) {
+                return Ok(Self::AssertEq2(decoded));
+            }
+            if let Ok(decoded) = <AssertEq16Call as ::corebc_core::abi::AbiDecode>::decode(
+                data,
+            ) {
+                return Ok(Self::AssertEq16(decoded));
+            }
+            if let Ok(decoded) = <AssertEq3Call as ::corebc_core::abi::AbiDecode>::decode(
+                data,
+            ) {
+                return Ok(Self::AssertEq3(decoded));
+            }
+            if let Ok(decoded) = <AssertEq17Call as ::corebc_core::abi::AbiDecode>::decode(
+                data,
+            ) {
+                return Ok(Self::AssertEq17(decoded));
+            }
+            if let Ok(decoded) = <AssertEq4Call as ::corebc_core::abi::AbiDecode>::decode(
+                data,
+            ) {
+                return Ok(Self::AssertEq4(decoded));
+            }
+            if let Ok(decoded) = <AssertEq18Call as ::corebc_core::abi::AbiDecode>::decode(
+                data,
+            ) {
+                return Ok(Self::AssertEq18(decoded));
+            }
+            if let Ok(decoded) = <AssertEq5Call as ::corebc_core::abi::AbiDecode>::decode(
+                data,
+            ) {
+                return Ok(Self::AssertEq5(decoded));
+            }
+            if let Ok(decoded) = <AssertEq19Call as ::corebc_core::abi::AbiDecode>::decode(
+                data,
+            ) {
+                return Ok(Self::AssertEq19(decoded));
+            }
+            if let Ok(decoded) = <AssertEq6Call as ::corebc_core::abi::AbiDecode>::decode(
+                data,
+            ) {
+                return Ok(Self::AssertEq6(decoded));
+            }
+            if let Ok(decoded) = <AssertEq20Call as ::corebc_core::abi::AbiDecode>::decode(
+                data,
+            ) {
+                return Ok(Self::AssertEq20(decoded));
+            }
+            if let Ok(decoded) = <AssertEq7Call as ::corebc_core::abi::AbiDecode>::decode(
+                data,
+            ) {
+                return Ok(Self::AssertEq7(decoded));
+            }
+            if let Ok(decoded) = <AssertEq21Call as ::corebc_core::abi::AbiDecode>::decode(
+                data,
+            ) {
+                return Ok(Self::AssertEq21(decoded));
+            }
+            if let Ok(decoded) = <AssertEq8Call as ::corebc_core::abi::AbiDecode>::decode(
+                data,
+            ) {
+                return Ok(Self::AssertEq8(decoded));
+            }
+            if let Ok(decoded) = <AssertEq22Call as ::corebc_core::abi::AbiDecode>::decode(
+                data,
+            ) {
+                return Ok(Self::AssertEq22(decoded));
+            }
+            if let Ok(decoded) = <AssertEq9Call as ::corebc_core::abi::AbiDecode>::decode(
+                data,
+            ) {
+                return Ok(Self::AssertEq9(decoded));
+            }
+            if let Ok(decoded) = <AssertEq23Call as ::corebc_core::abi::AbiDecode>::decode(
+                data,
+            ) {
+                return Ok(Self::AssertEq23(decoded));
+            }
+            if let Ok(decoded) = <AssertEq10Call as ::corebc_core::abi::AbiDecode>::decode(
+                data,
+            ) {
+                return Ok(Self::AssertEq10(decoded));
+            }
+            if let Ok(decoded) = <AssertEq24Call as ::corebc_core::abi::AbiDecode>::decode(
+                data,
+            ) {
+                return Ok(Self::AssertEq24(decoded));
+            }
+            if let Ok(decoded) = <AssertEq11Call as ::corebc_core::abi::AbiDecode>::decode(
+                data,
+            ) {
+                return Ok(Self::AssertEq11(decoded));
+            }
+            if let Ok(decoded) = <AssertEq25Call as ::corebc_core::abi::AbiDecode>::decode(
+                data,
+            ) {
+                return Ok(Self::AssertEq25(decoded));
+            }
+            if let Ok(decoded) = <AssertEq12Call as ::corebc_core::abi::AbiDecode>::decode(
+                data,
+            ) {
+                return Ok(Self::AssertEq12(decoded));
+            }
+            if let Ok(decoded) = <AssertEq26Call as ::corebc_core::abi::AbiDecode>::decode(
+                data,
+            ) {
+                return Ok(Self::AssertEq26(decoded));
+            }
+            if let Ok(decoded) = <AssertEq13Call as ::corebc_core::abi::AbiDecode>::decode(
+                data,
+            ) {
+                return Ok(Self::AssertEq13(decoded));
+            }
+            if let Ok(decoded) = <AssertEq27Call as ::corebc_core::abi::AbiDecode>::decode(
+                data,
+            ) {
+                return Ok(Self::AssertEq27(decoded));
+            }
+            if let Ok(decoded) = <AssertFalse0Call as ::corebc_core::abi::AbiDecode>::decode(
+                data,
+            ) {
+                return Ok(Self::AssertFalse0(decoded));
+            }
+            if let Ok(decoded) = <AssertFalse1Call as ::corebc_core::abi::AbiDecode>::decode(
+                data,
+            ) {
+                return Ok(Self::AssertFalse1(decoded));
+            }
+            if let Ok(decoded) = <AssertGe0Call as ::corebc_core::abi::AbiDecode>::decode(
+                data,
+            ) {
+                return Ok(Self::AssertGe0(decoded));
+            }
+            if let Ok(decoded) = <AssertGe2Call as ::corebc_core::abi::AbiDecode>::decode(
+                data,
+            ) {
+                return Ok(Self::AssertGe2(decoded));
+            }
+            if let Ok(decoded) = <AssertGe1Call as ::corebc_core::abi::AbiDecode>::decode(
+                data,
+            ) {
+                return Ok(Self::AssertGe1(decoded));
+            }
+            if let Ok(decoded) = <AssertGe3Call as ::corebc_core::abi::AbiDecode>::decode(
+                data,
+            ) {
+                return Ok(Self::AssertGe3(decoded));
+            }
+            if let Ok(decoded) = <AssertGt0Call as ::corebc_core::abi::AbiDecode>::decode(
+                data,
+            ) {
+                return Ok(Self::AssertGt0(decoded));
+            }
+            if let Ok(decoded) = <AssertGt2Call as ::corebc_core::abi::AbiDecode>::decode(
+                data,
+            ) {
+                return Ok(Self::AssertGt2(decoded));
+            }
+            if let Ok(decoded) = <AssertGt1Call as ::corebc_core::abi::AbiDecode>::decode(
+                data,
+            ) {
+                return Ok(Self::AssertGt1(decoded));
+            }
+            if let Ok(decoded) = <AssertGt3Call as ::corebc_core::abi::AbiDecode>::decode(
+                data,
+            ) {
+                return Ok(Self::AssertGt3(decoded));
+            }
+            if let Ok(decoded) = <AssertLe0Call as ::corebc_core::abi::AbiDecode>::decode(
+                data,
+            ) {
+                return Ok(Self::AssertLe0(decoded));
+            }
+            if let Ok(decoded) = <AssertLe2Call as ::corebc_core::abi::AbiDecode>::decode(
+                data,
+            ) {
+                return Ok(Self::AssertLe2(decoded));
+            }
+            if let Ok(decoded) = <AssertLe1Call as ::corebc_core::abi::AbiDecode>::decode(
+                data,
+            ) {
+                return Ok(Self::AssertLe1(decoded));
+            }
+            if let Ok(decoded) = <AssertLe3Call as ::corebc_core::abi::AbiDecode>::decode(
+                data,
+            ) {
+                return Ok(Self::AssertLe3(decoded));
+            }
+            if let Ok(decoded) = <AssertLt0Call as ::corebc_core::abi::AbiDecode>::decode(
+                data,
+            ) {
+                return Ok(Self::AssertLt0(decoded));
+            }
+            if let Ok(decoded) = <AssertLt2Call as ::corebc_core::abi::AbiDecode>::decode(
+                data,
+            ) {
+                return Ok(Self::AssertLt2(decoded));
+            }
+            if let Ok(decoded) = <AssertLt1Call as ::corebc_core::abi::AbiDecode>::decode(
+                data,
+            ) {
+                return Ok(Self::AssertLt1(decoded));
+            }
+            if let Ok(decoded) = <AssertLt3Call as ::corebc_core::abi::AbiDecode>::decode(
+                data,
+            ) {
+                return Ok(Self::AssertLt3(decoded));
+            }
+            if let Ok(decoded) = <AssertNotEq0Call as ::corebc_core::abi::AbiDecode>::decode(
+                data,
+            ) {
+                return Ok(Self::AssertNotEq0(decoded));
+            }
+            if let Ok(decoded) = <AssertNotEq14Call as ::corebc_core::abi::AbiDecode>::decode(
+                data,
+            ) {
+                return Ok(Self::AssertNotEq14(decoded));
+            }
+            if let Ok(decoded) = <AssertNotEq1Call as ::corebc_core::abi::AbiDecode>::decode(
+                data,
+            ) {
+                return Ok(Self::AssertNotEq1(decoded));
+            }
+            if let Ok(decoded) = <AssertNotEq15Call as ::corebc_core::abi::AbiDecode>::decode(
+                data,
+            ) {
+                return Ok(Self::AssertNotEq15(decoded));
+            }
+            if let Ok(decoded) = <AssertNotEq2Call as ::corebc_core::abi::AbiDecode>::decode(
+                data,
+            ) {
+                return Ok(Self::AssertNotEq2(decoded));
+            }
+            if let Ok(decoded) = <AssertNotEq16Call as ::corebc_core::abi::AbiDecode>::decode(
+                data,
+            ) {
+                return Ok(Self::AssertNotEq16(decoded));
+            }
+            if let Ok(decoded) = <AssertNotEq3Call as ::corebc_core::abi::AbiDecode>::decode(
+                data,
+            ) {
+                return Ok(Self::AssertNotEq3(decoded));
+            }
+            if let Ok(decoded) = <AssertNotEq17Call as ::corebc_core::abi::AbiDecode>::decode(
+                data,
+            ) {
+                return Ok(Self::AssertNotEq17(decoded));
+            }
+            if let Ok(decoded) = <AssertNotEq4Call as ::corebc_core::abi::AbiDecode>::decode(
+                data,
+            ) {
+                return Ok(Self::AssertNotEq4(decoded));
+            }
+            if let Ok(decoded) = <AssertNotEq18Call as ::corebc_core::abi::AbiDecode>::decode(
+                data,
+            ) {
+                return Ok(Self::AssertNotEq18(decoded));
+            }
+            if let Ok(decoded) = <AssertNotEq5Call as ::corebc_core::abi::AbiDecode>::decode(
+                data,
+            ) {
+                return Ok(Self::AssertNotEq5(decoded));
+            }
+            if let Ok(decoded) = <AssertNotEq19Call as ::corebc_core::abi::AbiDecode>::decode(
+                data,
+            ) {
+                return Ok(Self::AssertNotEq19(decoded));
+            }
+            if let Ok(decoded) = <AssertNotEq6Call as ::corebc_core::abi::AbiDecode>::decode(
+                data,
+            ) {
+                return Ok(Self::AssertNotEq6(decoded));
+            }
+            if let Ok(decoded) = <AssertNotEq20Call as ::corebc_core::abi::AbiDecode>::decode(
+                data,
+            ) {
+                return Ok(Self::AssertNotEq20(decoded));
+            }
+            if let Ok(decoded) = <AssertNotEq7Call as ::corebc_core::abi::AbiDecode>::decode(
+                data,
+            ) {
+                return Ok(Self::AssertNotEq7(decoded));
+            }
+            if let Ok(decoded) = <AssertNotEq21Call as ::corebc_core::abi::AbiDecode>::decode(
+                data,
+            ) {
+                return Ok(Self::AssertNotEq21(decoded));
+            }
+            if let Ok(decoded) = <AssertNotEq8Call as ::corebc_core::abi::AbiDecode>::decode(
+                data,
+            ) {
+                return Ok(Self::AssertNotEq8(decoded));
+            }
+            if let Ok(decoded) = <AssertNotEq22Call as ::corebc_core::abi::AbiDecode>::decode(
+                data,
+            ) {
+                return Ok(Self::AssertNotEq22(decoded));
             }
-            if let Ok(decoded) = <ActiveForkCall as ::corebc_core::abi::AbiDecode>::decode(
+            if let Ok(decoded) = <AssertNotEq9Call as ::corebc_core::abi::AbiDecode>::decode(
                 data,
             ) {
-                return Ok(Self::ActiveFork(decoded));
+                return Ok(Self::AssertNotEq9(decoded));
             }
-            if let Ok(decoded) = <AddrCall as ::corebc_core::abi::AbiDecode>::decode(
+            if let Ok(decoded) = <AssertNotEq23Call as ::corebc_core::abi::AbiDecode>::decode(
                 data,
             ) {
-                return Ok(Self::Addr(decoded));
+                return Ok(Self::AssertNotEq23(decoded));
             }
-            if let Ok(decoded) = <AllowCheatcodesCall as ::corebc_core::abi::AbiDecode>::decode(
+            if let Ok(decoded) = <AssertNotEq10Call as ::corebc_core::abi::AbiDecode>::decode(
                 data,
             ) {
-                return Ok(Self::AllowCheatcodes(decoded));
+                return Ok(Self::AssertNotEq10(decoded));
+            }
+            if let Ok(decoded) = <AssertNotEq24Call as ::corebc_core::abi::AbiDecode>::decode(
+                data,
+            ) {
+                return Ok(Self::AssertNotEq24(decoded));
+            }
+            if let Ok(decoded) = <AssertNotEq11Call as ::corebc_core::abi::AbiDecode>::decode(
+                data,
+            ) {
+                return Ok(Self::AssertNotEq11(decoded));
+            }
+            if let Ok(decoded) = <AssertNotEq25Call as ::corebc_core::abi::AbiDecode>::decode(
+                data,
+            ) {
+                return Ok(Self::AssertNotEq25(decoded));
+            }
+            if let Ok(decoded) = <AssertNotEq12Call as ::corebc_core::abi::AbiDecode>::decode(
+                data,
+            ) {
+                return Ok(Self::AssertNotEq12(decoded));
+            }
+            if let Ok(decoded) = <AssertNotEq26Call as ::corebc_core::abi::AbiDecode>::decode(
+                data,
+            ) {
+                return Ok(Self::AssertNotEq26(decoded));
+            }
+            if let Ok(decoded) = <AssertNotEq13Call as ::corebc_core::abi::AbiDecode>::decode(
+                data,
+            ) {
+                return Ok(Self::AssertNotEq13(decoded));
+            }
+            if let Ok(decoded) = <AssertNotEq27Call as ::corebc_core::abi::AbiDecode>::decode(
+                data,
+            ) {
+                return Ok(Self::AssertNotEq27(decoded));
+            }
+            if let Ok(decoded) = <AssertTrue0Call as ::corebc_core::abi::AbiDecode>::decode(
+                data,
+            ) {
+                return Ok(Self::AssertTrue0(decoded));
+            }
+            if let Ok(decoded) = <AssertTrue1Call as ::corebc_core::abi::AbiDecode>::decode(
+                data,
+            ) {
+                return Ok(Self::AssertTrue1(decoded));
             }
             if let Ok(decoded) = <AssumeCall as ::corebc_core::abi::AbiDecode>::decode(
                 data,
@@ -5011,6 +8963,11 @@ pub mod hevm {
             ) {
                 return Ok(Self::CreateSelectFork0(decoded));
             }
+            if let Ok(decoded) = <CreateWalletCall as ::corebc_core::abi::AbiDecode>::decode(
+                data,
+            ) {
+                return Ok(Self::CreateWallet(decoded));
+            }
             if let Ok(decoded) = <DealCall as ::corebc_core::abi::AbiDecode>::decode(
                 data,
             ) {
@@ -5246,6 +9203,56 @@ pub mod hevm {
             ) {
                 return Ok(Self::ExpectEmit3(decoded));
             }
+            if let Ok(decoded) = <ExpectEmit4Call as ::corebc_core::abi::AbiDecode>::decode(
+                data,
+            ) {
+                return Ok(Self::ExpectEmit4(decoded));
+            }
+            if let Ok(decoded) = <ExpectEmit5Call as ::corebc_core::abi::AbiDecode>::decode(
+                data,
+            ) {
+                return Ok(Self::ExpectEmit5(decoded));
+            }
+            if let Ok(decoded) = <ExpectEmit6Call as ::corebc_core::abi::AbiDecode>::decode(
+                data,
+            ) {
+                return Ok(Self::ExpectEmit6(decoded));
+            }
+            if let Ok(decoded) = <ExpectEmit7Call as ::corebc_core::abi::AbiDecode>::decode(
+                data,
+            ) {
+                return Ok(Self::ExpectEmit7(decoded));
+            }
+            if let Ok(decoded) = <ExpectEmitAnonymous0Call as ::corebc_core::abi::AbiDecode>::decode(
+                data,
+            ) {
+                return Ok(Self::ExpectEmitAnonymous0(decoded));
+            }
+            if let Ok(decoded) = <ExpectEmitAnonymous1Call as ::corebc_core::abi::AbiDecode>::decode(
+                data,
+            ) {
+                return Ok(Self::ExpectEmitAnonymous1(decoded));
+            }
+            if let Ok(decoded) = <ExpectEmitAnonymous2Call as ::corebc_core::abi::AbiDecode>::decode(
+                data,
+            ) {
+                return Ok(Self::ExpectEmitAnonymous2(decoded));
+            }
+            if let Ok(decoded) = <ExpectEmitAnonymous3Call as ::corebc_core::abi::AbiDecode>::decode(
+                data,
+            ) {
+                return Ok(Self::ExpectEmitAnonymous3(decoded));
+            }
+            if let Ok(decoded) = <ExpectPartialRevert0Call as ::corebc_core::abi::AbiDecode>::decode(
+                data,
+            ) {
+                return Ok(Self::ExpectPartialRevert0(decoded));
+            }
+            if let Ok(decoded) = <ExpectPartialRevert1Call as ::corebc_core::abi::AbiDecode>::decode(
+                data,
+            ) {
+                return Ok(Self::ExpectPartialRevert1(decoded));
+            }
             if let Ok(decoded) = <ExpectRevert0Call as ::corebc_core::abi::AbiDecode>::decode(
                 data,
             ) {
@@ -5261,6 +9268,36 @@ pub mod hevm {
             ) {
                 return Ok(Self::ExpectRevert2(decoded));
             }
+            if let Ok(decoded) = <ExpectRevert3Call as ::corebc_core::abi::AbiDecode>::decode(
+                data,
+            ) {
+                return Ok(Self::ExpectRevert3(decoded));
+            }
+            if let Ok(decoded) = <ExpectRevert4Call as ::corebc_core::abi::AbiDecode>::decode(
+                data,
+            ) {
+                return Ok(Self::ExpectRevert4(decoded));
+            }
+            if let Ok(decoded) = <ExpectRevert5Call as ::corebc_core::abi::AbiDecode>::decode(
+                data,
+            ) {
+                return Ok(Self::ExpectRevert5(decoded));
+            }
+            if let Ok(decoded) = <ExpectRevert6Call as ::corebc_core::abi::AbiDecode>::decode(
+                data,
+            ) {
+                return Ok(Self::ExpectRevert6(decoded));
+            }
+            if let Ok(decoded) = <ExpectRevert7Call as ::corebc_core::abi::AbiDecode>::decode(
+                data,
+            ) {
+                return Ok(Self::ExpectRevert7(decoded));
+            }
+            if let Ok(decoded) = <ExpectRevert8Call as ::corebc_core::abi::AbiDecode>::decode(
+                data,
+            ) {
+                return Ok(Self::ExpectRevert8(decoded));
+            }
             if let Ok(decoded) = <ExpectSafeMemoryCall as ::corebc_core::abi::AbiDecode>::decode(
                 data,
             ) {
@@ -5296,6 +9333,16 @@ pub mod hevm {
             ) {
                 return Ok(Self::GetDeployedCode(decoded));
             }
+            if let Ok(decoded) = <GetDeployment0Call as ::corebc_core::abi::AbiDecode>::decode(
+                data,
+            ) {
+                return Ok(Self::GetDeployment0(decoded));
+            }
+            if let Ok(decoded) = <GetDeployment1Call as ::corebc_core::abi::AbiDecode>::decode(
+                data,
+            ) {
+                return Ok(Self::GetDeployment1(decoded));
+            }
             if let Ok(decoded) = <GetLabelCall as ::corebc_core::abi::AbiDecode>::decode(
                 data,
             ) {
@@ -5316,6 +9363,16 @@ pub mod hevm {
             ) {
                 return Ok(Self::IsPersistent(decoded));
             }
+            if let Ok(decoded) = <KeyExistsJsonCall as ::corebc_core::abi::AbiDecode>::decode(
+                data,
+            ) {
+                return Ok(Self::KeyExistsJson(decoded));
+            }
+            if let Ok(decoded) = <KeyExistsTomlCall as ::corebc_core::abi::AbiDecode>::decode(
+                data,
+            ) {
+                return Ok(Self::KeyExistsToml(decoded));
+            }
             if let Ok(decoded) = <LabelCall as ::corebc_core::abi::AbiDecode>::decode(
                 data,
             ) {
@@ -5456,6 +9513,11 @@ pub mod hevm {
             ) {
                 return Ok(Self::ParseJsonIntArray(decoded));
             }
+            if let Ok(decoded) = <ParseJsonKeysCall as ::corebc_core::abi::AbiDecode>::decode(
+                data,
+            ) {
+                return Ok(Self::ParseJsonKeys(decoded));
+            }
             if let Ok(decoded) = <ParseJsonStringCall as ::corebc_core::abi::AbiDecode>::decode(
                 data,
             ) {
@@ -5476,6 +9538,91 @@ pub mod hevm {
             ) {
                 return Ok(Self::ParseJsonUintArray(decoded));
             }
+            if let Ok(decoded) = <ParseToml0Call as ::corebc_core::abi::AbiDecode>::decode(
+                data,
+            ) {
+                return Ok(Self::ParseToml0(decoded));
+            }
+            if let Ok(decoded) = <ParseToml1Call as ::corebc_core::abi::AbiDecode>::decode(
+                data,
+            ) {
+                return Ok(Self::ParseToml1(decoded));
+            }
+            if let Ok(decoded) = <ParseTomlAddressCall as ::corebc_core::abi::AbiDecode>::decode(
+                data,
+            ) {
+                return Ok(Self::ParseTomlAddress(decoded));
+            }
+            if let Ok(decoded) = <ParseTomlAddressArrayCall as ::corebc_core::abi::AbiDecode>::decode(
+                data,
+            ) {
+                return Ok(Self::ParseTomlAddressArray(decoded));
+            }
+            if let Ok(decoded) = <ParseTomlBoolCall as ::corebc_core::abi::AbiDecode>::decode(
+                data,
+            ) {
+                return Ok(Self::ParseTomlBool(decoded));
+            }
+            if let Ok(decoded) = <ParseTomlBoolArrayCall as ::corebc_core::abi::AbiDecode>::decode(
+                data,
+            ) {
+                return Ok(Self::ParseTomlBoolArray(decoded));
+            }
+            if let Ok(decoded) = <ParseTomlBytesCall as ::corebc_core::abi::AbiDecode>::decode(
+                data,
+            ) {
+                return Ok(Self::ParseTomlBytes(decoded));
+            }
+            if let Ok(decoded) = <ParseTomlBytes32Call as ::corebc_core::abi::AbiDecode>::decode(
+                data,
+            ) {
+                return Ok(Self::ParseTomlBytes32(decoded));
+            }
+            if let Ok(decoded) = <ParseTomlBytes32ArrayCall as ::corebc_core::abi::AbiDecode>::decode(
+                data,
+            ) {
+                return Ok(Self::ParseTomlBytes32Array(decoded));
+            }
+            if let Ok(decoded) = <ParseTomlBytesArrayCall as ::corebc_core::abi::AbiDecode>::decode(
+                data,
+            ) {
+                return Ok(Self::ParseTomlBytesArray(decoded));
+            }
+            if let Ok(decoded) = <ParseTomlIntCall as ::corebc_core::abi::AbiDecode>::decode(
+                data,
+            ) {
+                return Ok(Self::ParseTomlInt(decoded));
+            }
+            if let Ok(decoded) = <ParseTomlIntArrayCall as ::corebc_core::abi::AbiDecode>::decode(
+                data,
+            ) {
+                return Ok(Self::ParseTomlIntArray(decoded));
+            }
+            if let Ok(decoded) = <ParseTomlKeysCall as ::corebc_core::abi::AbiDecode>::decode(
+                data,
+            ) {
+                return Ok(Self::ParseTomlKeys(decoded));
+            }
+            if let Ok(decoded) = <ParseTomlStringCall as ::corebc_core::abi::AbiDecode>::decode(
+                data,
+            ) {
+                return Ok(Self::ParseTomlString(decoded));
+            }
+            if let Ok(decoded) = <ParseTomlStringArrayCall as ::corebc_core::abi::AbiDecode>::decode(
+                data,
+            ) {
+                return Ok(Self::ParseTomlStringArray(decoded));
+            }
+            if let Ok(decoded) = <ParseTomlUintCall as ::corebc_core::abi::AbiDecode>::decode(
+                data,
+            ) {
+                return Ok(Self::ParseTomlUint(decoded));
+            }
+            if let Ok(decoded) = <ParseTomlUintArrayCall as ::corebc_core::abi::AbiDecode>::decode(
+                data,
+            ) {
+                return Ok(Self::ParseTomlUintArray(decoded));
+            }
             if let Ok(decoded) = <ParseUintCall as ::corebc_core::abi::AbiDecode>::decode(
                 data,
             ) {
@@ -5631,6 +9778,11 @@ pub mod hevm {
             ) {
                 return Ok(Self::RpcUrls(decoded));
             }
+            if let Ok(decoded) = <SaveDeploymentCall as ::corebc_core::abi::AbiDecode>::decode(
+                data,
+            ) {
+                return Ok(Self::SaveDeployment(decoded));
+            }
             if let Ok(decoded) = <SelectForkCall as ::corebc_core::abi::AbiDecode>::decode(
                 data,
             ) {
@@ -5736,6 +9888,11 @@ pub mod hevm {
             ) {
                 return Ok(Self::Snapshot(decoded));
             }
+            if let Ok(decoded) = <SnapshotValueCall as ::corebc_core::abi::AbiDecode>::decode(
+                data,
+            ) {
+                return Ok(Self::SnapshotValue(decoded));
+            }
             if let Ok(decoded) = <StartBroadcast0Call as ::corebc_core::abi::AbiDecode>::decode(
                 data,
             ) {
@@ -5761,6 +9918,11 @@ pub mod hevm {
             ) {
                 return Ok(Self::StartPrank1(decoded));
             }
+            if let Ok(decoded) = <StartSnapshotEnergyCall as ::corebc_core::abi::AbiDecode>::decode(
+                data,
+            ) {
+                return Ok(Self::StartSnapshotEnergy(decoded));
+            }
             if let Ok(decoded) = <StopBroadcastCall as ::corebc_core::abi::AbiDecode>::decode(
                 data,
             ) {
@@ -5771,6 +9933,11 @@ pub mod hevm {
             ) {
                 return Ok(Self::StopPrank(decoded));
             }
+            if let Ok(decoded) = <StopSnapshotEnergyCall as ::corebc_core::abi::AbiDecode>::decode(
+                data,
+            ) {
+                return Ok(Self::StopSnapshotEnergy(decoded));
+            }
             if let Ok(decoded) = <StoreCall as ::corebc_core::abi::AbiDecode>::decode(
                 data,
             ) {
@@ -5851,6 +10018,16 @@ pub mod hevm {
             ) {
                 return Ok(Self::WriteLine(decoded));
             }
+            if let Ok(decoded) = <WriteToml0Call as ::corebc_core::abi::AbiDecode>::decode(
+                data,
+            ) {
+                return Ok(Self::WriteToml0(decoded));
+            }
+            if let Ok(decoded) = <WriteToml1Call as ::corebc_core::abi::AbiDecode>::decode(
+                data,
+            ) {
+                return Ok(Self::WriteToml1(decoded));
+            }
             Err(::corebc_core::abi::Error::InvalidData.into())
         }
     }
@@ -5861,8 +10038,260 @@ pub mod hevm {
                 Self::ActiveFork(element) => {
                     ::corebc_core::abi::AbiEncode::encode(element)
                 }
-                Self::Addr(element) => ::corebc_core::abi::AbiEncode::encode(element),
-                Self::AllowCheatcodes(element) => {
+                Self::Addr(element) => ::corebc_core::abi::AbiEncode::encode(element),
+                Self::AllowCheatcodes(element) => {
+                    ::corebc_core::abi::AbiEncode::encode(element)
+                }
+                Self::AssertApproxEqAbs0(element) => {
+                    ::corebc_core::abi::AbiEncode::encode(element)
+                }
+                Self::AssertApproxEqAbs2(element) => {
+                    ::corebc_core::abi::AbiEncode::encode(element)
+                }
+                Self::AssertApproxEqAbs1(element) => {
+                    ::corebc_core::abi::AbiEncode::encode(element)
+                }
+                Self::AssertApproxEqAbs3(element) => {
+                    ::corebc_core::abi::AbiEncode::encode(element)
+                }
+                Self::AssertApproxEqRel0(element) => {
+                    ::corebc_core::abi::AbiEncode::encode(element)
+                }
+                Self::AssertApproxEqRel2(element) => {
+                    ::corebc_core::abi::AbiEncode::encode(element)
+                }
+                Self::AssertApproxEqRel1(element) => {
+                    ::corebc_core::abi::AbiEncode::encode(element)
+                }
+                Self::AssertApproxEqRel3(element) => {
+                    ::corebc_core::abi::AbiEncode::encode(element)
+                }
+                Self::AssertEq0(element) => {
+                    ::corebc_core::abi::AbiEncode::encode(element)
+                }
+                Self::AssertEq14(element) => {
+                    ::corebc_core::abi::AbiEncode::encode(element)
+                }
+                Self::AssertEq1(element) => {
+                    ::corebc_core::abi::AbiEncode::encode(element)
+                }
+                Self::AssertEq15(element) => {
+                    ::corebc_core::abi::AbiEncode::encode(element)
+                }
+                Self::AssertEq2(element) => {
+                    ::corebc_core::abi::AbiEncode::encode(element)
+                }
+                Self::AssertEq16(element) => {
+                    ::corebc_core::abi::AbiEncode::encode(element)
+                }
+                Self::AssertEq3(element) => {
+                    ::corebc_core::abi::AbiEncode::encode(element)
+                }
+                Self::AssertEq17(element) => {
+                    ::corebc_core::abi::AbiEncode::encode(element)
+                }
+                Self::AssertEq4(element) => {
+                    ::corebc_core::abi::AbiEncode::encode(element)
+                }
+                Self::AssertEq18(element) => {
+                    ::corebc_core::abi::AbiEncode::encode(element)
+                }
+                Self::AssertEq5(element) => {
+                    ::corebc_core::abi::AbiEncode::encode(element)
+                }
+                Self::AssertEq19(element) => {
+                    ::corebc_core::abi::AbiEncode::encode(element)
+                }
+                Self::AssertEq6(element) => {
+                    ::corebc_core::abi::AbiEncode::encode(element)
+                }
+                Self::AssertEq20(element) => {
+                    ::corebc_core::abi::AbiEncode::encode(element)
+                }
+                Self::AssertEq7(element) => {
+                    ::corebc_core::abi::AbiEncode::encode(element)
+                }
+                Self::AssertEq21(element) => {
+                    ::corebc_core::abi::AbiEncode::encode(element)
+                }
+                Self::AssertEq8(element) => {
+                    ::corebc_core::abi::AbiEncode::encode(element)
+                }
+                Self::AssertEq22(element) => {
+                    ::corebc_core::abi::AbiEncode::encode(element)
+                }
+                Self::AssertEq9(element) => {
+                    ::corebc_core::abi::AbiEncode::encode(element)
+                }
+                Self::AssertEq23(element) => {
+                    ::corebc_core::abi::AbiEncode::encode(element)
+                }
+                Self::AssertEq10(element) => {
+                    ::corebc_core::abi::AbiEncode::encode(element)
+                }
+                Self::AssertEq24(element) => {
+                    ::corebc_core::abi::AbiEncode::encode(element)
+                }
+                Self::AssertEq11(element) => {
+                    ::corebc_core::abi::AbiEncode::encode(element)
+                }
+                Self::AssertEq25(element) => {
+                    ::corebc_core::abi::AbiEncode::encode(element)
+                }
+                Self::AssertEq12(element) => {
+                    ::corebc_core::abi::AbiEncode::encode(element)
+                }
+                Self::AssertEq26(element) => {
+                    ::corebc_core::abi::AbiEncode::encode(element)
+                }
+                Self::AssertEq13(element) => {
+                    ::corebc_core::abi::AbiEncode::encode(element)
+                }
+                Self::AssertEq27(element) => {
+                    ::corebc_core::abi::AbiEncode::encode(element)
+                }
+                Self::AssertFalse0(element) => {
+                    ::corebc_core::abi::AbiEncode::encode(element)
+                }
+                Self::AssertFalse1(element) => {
+                    ::corebc_core::abi::AbiEncode::encode(element)
+                }
+                Self::AssertGe0(element) => {
+                    ::corebc_core::abi::AbiEncode::encode(element)
+                }
+                Self::AssertGe2(element) => {
+                    ::corebc_core::abi::AbiEncode::encode(element)
+                }
+                Self::AssertGe1(element) => {
+                    ::corebc_core::abi::AbiEncode::encode(element)
+                }
+                Self::AssertGe3(element) => {
+                    ::corebc_core::abi::AbiEncode::encode(element)
+                }
+                Self::AssertGt0(element) => {
+                    ::corebc_core::abi::AbiEncode::encode(element)
+                }
+                Self::AssertGt2(element) => {
+                    ::corebc_core::abi::AbiEncode::encode(element)
+                }
+                Self::AssertGt1(element) => {
+                    ::corebc_core::abi::AbiEncode::encode(element)
+                }
+                Self::AssertGt3(element) => {
+                    ::corebc_core::abi::AbiEncode::encode(element)
+                }
+                Self::AssertLe0(element) => {
+                    ::corebc_core::abi::AbiEncode::encode(element)
+                }
+                Self::AssertLe2(element) => {
+                    ::corebc_core::abi::AbiEncode::encode(element)
+                }
+                Self::AssertLe1(element) => {
+                    ::corebc_core::abi::AbiEncode::encode(element)
+                }
+                Self::AssertLe3(element) => {
+                    ::corebc_core::abi::AbiEncode::encode(element)
+                }
+                Self::AssertLt0(element) => {
+                    ::corebc_core::abi::AbiEncode::encode(element)
+                }
+                Self::AssertLt2(element) => {
+                    ::corebc_core::abi::AbiEncode::encode(element)
+                }
+                Self::AssertLt1(element) => {
+                    ::corebc_core::abi::AbiEncode::encode(element)
+                }
+                Self::AssertLt3(element) => {
+                    ::corebc_core::abi::AbiEncode::encode(element)
+                }
+                Self::AssertNotEq0(element) => {
+                    ::corebc_core::abi::AbiEncode::encode(element)
+                }
+                Self::AssertNotEq14(element) => {
+                    ::corebc_core::abi::AbiEncode::encode(element)
+                }
+                Self::AssertNotEq1(element) => {
+                    ::corebc_core::abi::AbiEncode::encode(element)
+                }
+                Self::AssertNotEq15(element) => {
+                    ::corebc_core::abi::AbiEncode::encode(element)
+                }
+                Self::AssertNotEq2(element) => {
+                    ::corebc_core::abi::AbiEncode::encode(element)
+                }
+                Self::AssertNotEq16(element) => {
+                    ::corebc_core::abi::AbiEncode::encode(element)
+                }
+                Self::AssertNotEq3(element) => {
+                    ::corebc_core::abi::AbiEncode::encode(element)
+                }
+                Self::AssertNotEq17(element) => {
+                    ::corebc_core::abi::AbiEncode::encode(element)
+                }
+                Self::AssertNotEq4(element) => {
+                    ::corebc_core::abi::AbiEncode::encode(element)
+                }
+                Self::AssertNotEq18(element) => {
+                    ::corebc_core::abi::AbiEncode::encode(element)
+                }
+                Self::AssertNotEq5(element) => {
+                    ::corebc_core::abi::AbiEncode::encode(element)
+                }
+                Self::AssertNotEq19(element) => {
+                    ::corebc_core::abi::AbiEncode::encode(element)
+                }
+                Self::AssertNotEq6(element) => {
+                    ::corebc_core::abi::AbiEncode::encode(element)
+                }
+                Self::AssertNotEq20(element) => {
+                    ::corebc_core::abi::AbiEncode::encode(element)
+                }
+                Self::AssertNotEq7(element) => {
+                    ::corebc_core::abi::AbiEncode::encode(element)
+                }
+                Self::AssertNotEq21(element) => {
+                    ::corebc_core::abi::AbiEncode::encode(element)
+                }
+                Self::AssertNotEq8(element) => {
+                    ::corebc_core::abi::AbiEncode::encode(element)
+                }
+                Self::AssertNotEq22(element) => {
+                    ::corebc_core::abi::AbiEncode::encode(element)
+                }
+                Self::AssertNotEq9(element) => {
+                    ::corebc_core::abi::AbiEncode::encode(element)
+                }
+                Self::AssertNotEq23(element) => {
+                    ::corebc_core::abi::AbiEncode::encode(element)
+                }
+                Self::AssertNotEq10(element) => {
+                    ::corebc_core::abi::AbiEncode::encode(element)
+                }
+                Self::AssertNotEq24(element) => {
+                    ::corebc_core::abi::AbiEncode::encode(element)
+                }
+                Self::AssertNotEq11(element) => {
+                    ::corebc_core::abi::AbiEncode::encode(element)
+                }
+                Self::AssertNotEq25(element) => {
+                    ::corebc_core::abi::AbiEncode::encode(element)
+                }
+                Self::AssertNotEq12(element) => {
+                    ::corebc_core::abi::AbiEncode::encode(element)
+                }
+                Self::AssertNotEq26(element) => {
+                    ::corebc_core::abi::AbiEncode::encode(element)
+                }
+                Self::AssertNotEq13(element) => {
+                    ::corebc_core::abi::AbiEncode::encode(element)
+                }
+                Self::AssertNotEq27(element) => {
+                    ::corebc_core::abi::AbiEncode::encode(element)
+                }
+                Self::AssertTrue0(element) => {
+                    ::corebc_core::abi::AbiEncode::encode(element)
+                }
+                Self::AssertTrue1(element) => {
                     ::corebc_core::abi::AbiEncode::encode(element)
                 }
                 Self::Assume(element) => ::corebc_core::abi::AbiEncode::encode(element),
@@ -5910,6 +10339,9 @@ pub mod hevm {
                 Self::CreateSelectFork0(element) => {
                     ::corebc_core::abi::AbiEncode::encode(element)
                 }
+                Self::CreateWallet(element) => {
+                    ::corebc_core::abi::AbiEncode::encode(element)
+                }
                 Self::Deal(element) => ::corebc_core::abi::AbiEncode::encode(element),
                 Self::DeriveKey0(element) => {
                     ::corebc_core::abi::AbiEncode::encode(element)
@@ -6007,6 +10439,36 @@ pub mod hevm {
                 Self::ExpectEmit3(element) => {
                     ::corebc_core::abi::AbiEncode::encode(element)
                 }
+                Self::ExpectEmit4(element) => {
+                    ::corebc_core::abi::AbiEncode::encode(element)
+                }
+                Self::ExpectEmit5(element) => {
+                    ::corebc_core::abi::AbiEncode::encode(element)
+                }
+                Self::ExpectEmit6(element) => {
+                    ::corebc_core::abi::AbiEncode::encode(element)
+                }
+                Self::ExpectEmit7(element) => {
+                    ::corebc_core::abi::AbiEncode::encode(element)
+                }
+                Self::ExpectEmitAnonymous0(element) => {
+                    ::corebc_core::abi::AbiEncode::encode(element)
+                }
+                Self::ExpectEmitAnonymous1(element) => {
+                    ::corebc_core::abi::AbiEncode::encode(element)
+                }
+                Self::ExpectEmitAnonymous2(element) => {
+                    ::corebc_core::abi::AbiEncode::encode(element)
+                }
+                Self::ExpectEmitAnonymous3(element) => {
+                    ::corebc_core::abi::AbiEncode::encode(element)
+                }
+                Self::ExpectPartialRevert0(element) => {
+                    ::corebc_core::abi::AbiEncode::encode(element)
+                }
+                Self::ExpectPartialRevert1(element) => {
+                    ::corebc_core::abi::AbiEncode::encode(element)
+                }
                 Self::ExpectRevert0(element) => {
                     ::corebc_core::abi::AbiEncode::encode(element)
                 }
@@ -6016,6 +10478,24 @@ pub mod hevm {
                 Self::ExpectRevert2(element) => {
                     ::corebc_core::abi::AbiEncode::encode(element)
                 }
+                Self::ExpectRevert3(element) => {
+                    ::corebc_core::abi::AbiEncode::encode(element)
+                }
+                Self::ExpectRevert4(element) => {
+                    ::corebc_core::abi::AbiEncode::encode(element)
+                }
+                Self::ExpectRevert5(element) => {
+                    ::corebc_core::abi::AbiEncode::encode(element)
+                }
+                Self::ExpectRevert6(element) => {
+                    ::corebc_core::abi::AbiEncode::encode(element)
+                }
+                Self::ExpectRevert7(element) => {
+                    ::corebc_core::abi::AbiEncode::encode(element)
+                }
+                Self::ExpectRevert8(element) => {
+                    ::corebc_core::abi::AbiEncode::encode(element)
+                }
                 Self::ExpectSafeMemory(element) => {
                     ::corebc_core::abi::AbiEncode::encode(element)
                 }
@@ -6031,6 +10511,12 @@ pub mod hevm {
                 Self::GetDeployedCode(element) => {
                     ::corebc_core::abi::AbiEncode::encode(element)
                 }
+                Self::GetDeployment0(element) => {
+                    ::corebc_core::abi::AbiEncode::encode(element)
+                }
+                Self::GetDeployment1(element) => {
+                    ::corebc_core::abi::AbiEncode::encode(element)
+                }
                 Self::GetLabel(element) => ::corebc_core::abi::AbiEncode::encode(element),
                 Self::GetNonce(element) => ::corebc_core::abi::AbiEncode::encode(element),
                 Self::GetRecordedLogs(element) => {
@@ -6039,6 +10525,12 @@ pub mod hevm {
                 Self::IsPersistent(element) => {
                     ::corebc_core::abi::AbiEncode::encode(element)
                 }
+                Self::KeyExistsJson(element) => {
+                    ::corebc_core::abi::AbiEncode::encode(element)
+                }
+                Self::KeyExistsToml(element) => {
+                    ::corebc_core::abi::AbiEncode::encode(element)
+                }
                 Self::Label(element) => ::corebc_core::abi::AbiEncode::encode(element),
                 Self::Load(element) => ::corebc_core::abi::AbiEncode::encode(element),
                 Self::MakePersistent0(element) => {
@@ -6115,6 +10607,9 @@ pub mod hevm {
                 Self::ParseJsonIntArray(element) => {
                     ::corebc_core::abi::AbiEncode::encode(element)
                 }
+                Self::ParseJsonKeys(element) => {
+                    ::corebc_core::abi::AbiEncode::encode(element)
+                }
                 Self::ParseJsonString(element) => {
                     ::corebc_core::abi::AbiEncode::encode(element)
                 }
@@ -6127,6 +10622,57 @@ pub mod hevm {
                 Self::ParseJsonUintArray(element) => {
                     ::corebc_core::abi::AbiEncode::encode(element)
                 }
+                Self::ParseToml0(element) => {
+                    ::corebc_core::abi::AbiEncode::encode(element)
+                }
+                Self::ParseToml1(element) => {
+                    ::corebc_core::abi::AbiEncode::encode(element)
+                }
+                Self::ParseTomlAddress(element) => {
+                    ::corebc_core::abi::AbiEncode::encode(element)
+                }
+                Self::ParseTomlAddressArray(element) => {
+                    ::corebc_core::abi::AbiEncode::encode(element)
+                }
+                Self::ParseTomlBool(element) => {
+                    ::corebc_core::abi::AbiEncode::encode(element)
+                }
+                Self::ParseTomlBoolArray(element) => {
+                    ::corebc_core::abi::AbiEncode::encode(element)
+                }
+                Self::ParseTomlBytes(element) => {
+                    ::corebc_core::abi::AbiEncode::encode(element)
+                }
+                Self::ParseTomlBytes32(element) => {
+                    ::corebc_core::abi::AbiEncode::encode(element)
+                }
+                Self::ParseTomlBytes32Array(element) => {
+                    ::corebc_core::abi::AbiEncode::encode(element)
+                }
+                Self::ParseTomlBytesArray(element) => {
+                    ::corebc_core::abi::AbiEncode::encode(element)
+                }
+                Self::ParseTomlInt(element) => {
+                    ::corebc_core::abi::AbiEncode::encode(element)
+                }
+                Self::ParseTomlIntArray(element) => {
+                    ::corebc_core::abi::AbiEncode::encode(element)
+                }
+                Self::ParseTomlKeys(element) => {
+                    ::corebc_core::abi::AbiEncode::encode(element)
+                }
+                Self::ParseTomlString(element) => {
+                    ::corebc_core::abi::AbiEncode::encode(element)
+                }
+                Self::ParseTomlStringArray(element) => {
+                    ::corebc_core::abi::AbiEncode::encode(element)
+                }
+                Self::ParseTomlUint(element) => {
+                    ::corebc_core::abi::AbiEncode::encode(element)
+                }
+                Self::ParseTomlUintArray(element) => {
+                    ::corebc_core::abi::AbiEncode::encode(element)
+                }
                 Self::ParseUint(element) => {
                     ::corebc_core::abi::AbiEncode::encode(element)
                 }
@@ -6194,6 +10740,9 @@ pub mod hevm {
                     ::corebc_core::abi::AbiEncode::encode(element)
                 }
                 Self::RpcUrls(element) => ::corebc_core::abi::AbiEncode::encode(element),
+                Self::SaveDeployment(element) => {
+                    ::corebc_core::abi::AbiEncode::encode(element)
+                }
                 Self::SelectFork(element) => {
                     ::corebc_core::abi::AbiEncode::encode(element)
                 }
@@ -6247,6 +10796,9 @@ pub mod hevm {
                 Self::Sign(element) => ::corebc_core::abi::AbiEncode::encode(element),
                 Self::Skip(element) => ::corebc_core::abi::AbiEncode::encode(element),
                 Self::Snapshot(element) => ::corebc_core::abi::AbiEncode::encode(element),
+                Self::SnapshotValue(element) => {
+                    ::corebc_core::abi::AbiEncode::encode(element)
+                }
                 Self::StartBroadcast0(element) => {
                     ::corebc_core::abi::AbiEncode::encode(element)
                 }
@@ -6262,12 +10814,18 @@ pub mod hevm {
                 Self::StartPrank1(element) => {
                     ::corebc_core::abi::AbiEncode::encode(element)
                 }
+                Self::StartSnapshotEnergy(element) => {
+                    ::corebc_core::abi::AbiEncode::encode(element)
+                }
                 Self::StopBroadcast(element) => {
                     ::corebc_core::abi::AbiEncode::encode(element)
                 }
                 Self::StopPrank(element) => {
                     ::corebc_core::abi::AbiEncode::encode(element)
                 }
+                Self::StopSnapshotEnergy(element) => {
+                    ::corebc_core::abi::AbiEncode::encode(element)
+                }
                 Self::Store(element) => ::corebc_core::abi::AbiEncode::encode(element),
                 Self::ToString0(element) => {
                     ::corebc_core::abi::AbiEncode::encode(element)
@@ -6312,6 +10870,12 @@ pub mod hevm {
                 Self::WriteLine(element) => {
                     ::corebc_core::abi::AbiEncode::encode(element)
                 }
+                Self::WriteToml0(element) => {
+                    ::corebc_core::abi::AbiEncode::encode(element)
+                }
+                Self::WriteToml1(element) => {
+                    ::corebc_core::abi::AbiEncode::encode(element)
+                }
             }
         }
     }
@@ -6322,6 +10886,106 @@ pub mod hevm {
                 Self::ActiveFork(element) => ::core::fmt::Display::fmt(element, f),
                 Self::Addr(element) => ::core::fmt::Display::fmt(element, f),
                 Self::AllowCheatcodes(element) => ::core::fmt::Display::fmt(element, f),
+                Self::AssertApproxEqAbs0(element) => {
+                    ::core::fmt::Display::fmt(element, f)
+                }
+                Self::AssertApproxEqAbs2(element) => {
+                    ::core::fmt::Display::fmt(element, f)
+                }
+                Self::AssertApproxEqAbs1(element) => {
+                    ::core::fmt::Display::fmt(element, f)
+                }
+                Self::AssertApproxEqAbs3(element) => {
+                    ::core::fmt::Display::fmt(element, f)
+                }
+                Self::AssertApproxEqRel0(element) => {
+                    ::core::fmt::Display::fmt(element, f)
+                }
+                Self::AssertApproxEqRel2(element) => {
+                    ::core::fmt::Display::fmt(element, f)
+                }
+                Self::AssertApproxEqRel1(element) => {
+                    ::core::fmt::Display::fmt(element, f)
+                }
+                Self::AssertApproxEqRel3(element) => {
+                    ::core::fmt::Display::fmt(element, f)
+                }
+                Self::AssertEq0(element) => ::core::fmt::Display::fmt(element, f),
+                Self::AssertEq14(element) => ::core::fmt::Display::fmt(element, f),
+                Self::AssertEq1(element) => ::core::fmt::Display::fmt(element, f),
+                Self::AssertEq15(element) => ::core::fmt::Display::fmt(element, f),
+                Self::AssertEq2(element) => ::core::fmt::Display::fmt(element, f),
+                Self::AssertEq16(element) => ::core::fmt::Display::fmt(element, f),
+                Self::AssertEq3(element) => ::core::fmt::Display::fmt(element, f),
+                Self::AssertEq17(element) => ::core::fmt::Display::fmt(element, f),
+                Self::AssertEq4(element) => ::core::fmt::Display::fmt(element, f),
+                Self::AssertEq18(element) => ::core::fmt::Display::fmt(element, f),
+                Self::AssertEq5(element) => ::core::fmt::Display::fmt(element, f),
+                Self::AssertEq19(element) => ::core::fmt::Display::fmt(element, f),
+                Self::AssertEq6(element) => ::core::fmt::Display::fmt(element, f),
+                Self::AssertEq20(element) => ::core::fmt::Display::fmt(element, f),
+                Self::AssertEq7(element) => ::core::fmt::Display::fmt(element, f),
+                Self::AssertEq21(element) => ::core::fmt::Display::fmt(element, f),
+                Self::AssertEq8(element) => ::core::fmt::Display::fmt(element, f),
+                Self::AssertEq22(element) => ::core::fmt::Display::fmt(element, f),
+                Self::AssertEq9(element) => ::core::fmt::Display::fmt(element, f),
+                Self::AssertEq23(element) => ::core::fmt::Display::fmt(element, f),
+                Self::AssertEq10(element) => ::core::fmt::Display::fmt(element, f),
+                Self::AssertEq24(element) => ::core::fmt::Display::fmt(element, f),
+                Self::AssertEq11(element) => ::core::fmt::Display::fmt(element, f),
+                Self::AssertEq25(element) => ::core::fmt::Display::fmt(element, f),
+                Self::AssertEq12(element) => ::core::fmt::Display::fmt(element, f),
+                Self::AssertEq26(element) => ::core::fmt::Display::fmt(element, f),
+                Self::AssertEq13(element) => ::core::fmt::Display::fmt(element, f),
+                Self::AssertEq27(element) => ::core::fmt::Display::fmt(element, f),
+                Self::AssertFalse0(element) => ::core::fmt::Display::fmt(element, f),
+                Self::AssertFalse1(element) => ::core::fmt::Display::fmt(element, f),
+                Self::AssertGe0(element) => ::core::fmt::Display::fmt(element, f),
+                Self::AssertGe2(element) => ::core::fmt::Display::fmt(element, f),
+                Self::AssertGe1(element) => ::core::fmt::Display::fmt(element, f),
+                Self::AssertGe3(element) => ::core::fmt::Display::fmt(element, f),
+                Self::AssertGt0(element) => ::core::fmt::Display::fmt(element, f),
+                Self::AssertGt2(element) => ::core::fmt::Display::fmt(element, f),
+                Self::AssertGt1(element) => ::core::fmt::Display::fmt(element, f),
+                Self::AssertGt3(element) => ::core::fmt::Display::fmt(element, f),
+                Self::AssertLe0(element) => ::core::fmt::Display::fmt(element, f),
+                Self::AssertLe2(element) => ::core::fmt::Display::fmt(element, f),
+                Self::AssertLe1(element) => ::core::fmt::Display::fmt(element, f),
+                Self::AssertLe3(element) => ::core::fmt::Display::fmt(element, f),
+                Self::AssertLt0(element) => ::core::fmt::Display::fmt(element, f),
+                Self::AssertLt2(element) => ::core::fmt::Display::fmt(element, f),
+                Self::AssertLt1(element) => ::core::fmt::Display::fmt(element, f),
+                Self::AssertLt3(element) => ::core::fmt::Display::fmt(element, f),
+                Self::AssertNotEq0(element) => ::core::fmt::Display::fmt(element, f),
+                Self::AssertNotEq14(element) => ::core::fmt::Display::fmt(element, f),
+                Self::AssertNotEq1(element) => ::core::fmt::Display::fmt(element, f),
+                Self::AssertNotEq15(element) => ::core::fmt::Display::fmt(element, f),
+                Self::AssertNotEq2(element) => ::core::fmt::Display::fmt(element, f),
+                Self::AssertNotEq16(element) => ::core::fmt::Display::fmt(element, f),
+                Self::AssertNotEq3(element) => ::core::fmt::Display::fmt(element, f),
+                Self::AssertNotEq17(element) => ::core::fmt::Display::fmt(element, f),
+                Self::AssertNotEq4(element) => ::core::fmt::Display::fmt(element, f),
+                Self::AssertNotEq18(element) => ::core::fmt::Display::fmt(element, f),
+                Self::AssertNotEq5(element) => ::core::fmt::Display::fmt(element, f),
+                Self::AssertNotEq19(element) => ::core::fmt::Display::fmt(element, f),
+                Self::AssertNotEq6(element) => ::core::fmt::Display::fmt(element, f),
+                Self::AssertNotEq20(element) => ::core::fmt::Display::fmt(element, f),
+                Self::AssertNotEq7(element) => ::core::fmt::Display::fmt(element, f),
+                Self::AssertNotEq21(element) => ::core::fmt::Display::fmt(element, f),
+                Self::AssertNotEq8(element) => ::core::fmt::Display::fmt(element, f),
+                Self::AssertNotEq22(element) => ::core::fmt::Display::fmt(element, f),
+                Self::AssertNotEq9(element) => ::core::fmt::Display::fmt(element, f),
+                Self::AssertNotEq23(element) => ::core::fmt::Display::fmt(element, f),
+                Self::AssertNotEq10(element) => ::core::fmt::Display::fmt(element, f),
+                Self::AssertNotEq24(element) => ::core::fmt::Display::fmt(element, f),
+                Self::AssertNotEq11(element) => ::core::fmt::Display::fmt(element, f),
+                Self::AssertNotEq25(element) => ::core::fmt::Display::fmt(element, f),
+                Self::AssertNotEq12(element) => ::core::fmt::Display::fmt(element, f),
+                Self::AssertNotEq26(element) => ::core::fmt::Display::fmt(element, f),
+                Self::AssertNotEq13(element) => ::core::fmt::Display::fmt(element, f),
+                Self::AssertNotEq27(element) => ::core::fmt::Display::fmt(element, f),
+                Self::AssertTrue0(element) => ::core::fmt::Display::fmt(element, f),
+                Self::AssertTrue1(element) => ::core::fmt::Display::fmt(element, f),
                 Self::Assume(element) => ::core::fmt::Display::fmt(element, f),
                 Self::Breakpoint0(element) => ::core::fmt::Display::fmt(element, f),
                 Self::Breakpoint1(element) => ::core::fmt::Display::fmt(element, f),
@@ -6339,6 +11003,7 @@ pub mod hevm {
                 Self::CreateSelectFork1(element) => ::core::fmt::Display::fmt(element, f),
                 Self::CreateSelectFork2(element) => ::core::fmt::Display::fmt(element, f),
                 Self::CreateSelectFork0(element) => ::core::fmt::Display::fmt(element, f),
+                Self::CreateWallet(element) => ::core::fmt::Display::fmt(element, f),
                 Self::Deal(element) => ::core::fmt::Display::fmt(element, f),
                 Self::DeriveKey0(element) => ::core::fmt::Display::fmt(element, f),
                 Self::DeriveKey1(element) => ::core::fmt::Display::fmt(element, f),
@@ -6386,9 +11051,37 @@ pub mod hevm {
                 Self::ExpectEmit1(element) => ::core::fmt::Display::fmt(element, f),
                 Self::ExpectEmit2(element) => ::core::fmt::Display::fmt(element, f),
                 Self::ExpectEmit3(element) => ::core::fmt::Display::fmt(element, f),
+                Self::ExpectEmit4(element) => ::core::fmt::Display::fmt(element, f),
+                Self::ExpectEmit5(element) => ::core::fmt::Display::fmt(element, f),
+                Self::ExpectEmit6(element) => ::core::fmt::Display::fmt(element, f),
+                Self::ExpectEmit7(element) => ::core::fmt::Display::fmt(element, f),
+                Self::ExpectEmitAnonymous0(element) => {
+                    ::core::fmt::Display::fmt(element, f)
+                }
+                Self::ExpectEmitAnonymous1(element) => {
+                    ::core::fmt::Display::fmt(element, f)
+                }
+                Self::ExpectEmitAnonymous2(element) => {
+                    ::core::fmt::Display::fmt(element, f)
+                }
+                Self::ExpectEmitAnonymous3(element) => {
+                    ::core::fmt::Display::fmt(element, f)
+                }
+                Self::ExpectPartialRevert0(element) => {
+                    ::core::fmt::Display::fmt(element, f)
+                }
+                Self::ExpectPartialRevert1(element) => {
+                    ::core::fmt::Display::fmt(element, f)
+                }
                 Self::ExpectRevert0(element) => ::core::fmt::Display::fmt(element, f),
                 Self::ExpectRevert1(element) => ::core::fmt::Display::fmt(element, f),
                 Self::ExpectRevert2(element) => ::core::fmt::Display::fmt(element, f),
+                Self::ExpectRevert3(element) => ::core::fmt::Display::fmt(element, f),
+                Self::ExpectRevert4(element) => ::core::fmt::Display::fmt(element, f),
+                Self::ExpectRevert5(element) => ::core::fmt::Display::fmt(element, f),
+                Self::ExpectRevert6(element) => ::core::fmt::Display::fmt(element, f),
+                Self::ExpectRevert7(element) => ::core::fmt::Display::fmt(element, f),
+                Self::ExpectRevert8(element) => ::core::fmt::Display::fmt(element, f),
                 Self::ExpectSafeMemory(element) => ::core::fmt::Display::fmt(element, f),
                 Self::ExpectSafeMemoryCall(element) => {
                     ::core::fmt::Display::fmt(element, f)
@@ -6398,10 +11091,14 @@ pub mod hevm {
                 Self::FsMetadata(element) => ::core::fmt::Display::fmt(element, f),
                 Self::GetCode(element) => ::core::fmt::Display::fmt(element, f),
                 Self::GetDeployedCode(element) => ::core::fmt::Display::fmt(element, f),
+                Self::GetDeployment0(element) => ::core::fmt::Display::fmt(element, f),
+                Self::GetDeployment1(element) => ::core::fmt::Display::fmt(element, f),
                 Self::GetLabel(element) => ::core::fmt::Display::fmt(element, f),
                 Self::GetNonce(element) => ::core::fmt::Display::fmt(element, f),
                 Self::GetRecordedLogs(element) => ::core::fmt::Display::fmt(element, f),
                 Self::IsPersistent(element) => ::core::fmt::Display::fmt(element, f),
+                Self::KeyExistsJson(element) => ::core::fmt::Display::fmt(element, f),
+                Self::KeyExistsToml(element) => ::core::fmt::Display::fmt(element, f),
                 Self::Label(element) => ::core::fmt::Display::fmt(element, f),
                 Self::Load(element) => ::core::fmt::Display::fmt(element, f),
                 Self::MakePersistent0(element) => ::core::fmt::Display::fmt(element, f),
@@ -6438,6 +11135,7 @@ pub mod hevm {
                 }
                 Self::ParseJsonInt(element) => ::core::fmt::Display::fmt(element, f),
                 Self::ParseJsonIntArray(element) => ::core::fmt::Display::fmt(element, f),
+                Self::ParseJsonKeys(element) => ::core::fmt::Display::fmt(element, f),
                 Self::ParseJsonString(element) => ::core::fmt::Display::fmt(element, f),
                 Self::ParseJsonStringArray(element) => {
                     ::core::fmt::Display::fmt(element, f)
@@ -6446,6 +11144,35 @@ pub mod hevm {
                 Self::ParseJsonUintArray(element) => {
                     ::core::fmt::Display::fmt(element, f)
                 }
+                Self::ParseToml0(element) => ::core::fmt::Display::fmt(element, f),
+                Self::ParseToml1(element) => ::core::fmt::Display::fmt(element, f),
+                Self::ParseTomlAddress(element) => ::core::fmt::Display::fmt(element, f),
+                Self::ParseTomlAddressArray(element) => {
+                    ::core::fmt::Display::fmt(element, f)
+                }
+                Self::ParseTomlBool(element) => ::core::fmt::Display::fmt(element, f),
+                Self::ParseTomlBoolArray(element) => {
+                    ::core::fmt::Display::fmt(element, f)
+                }
+                Self::ParseTomlBytes(element) => ::core::fmt::Display::fmt(element, f),
+                Self::ParseTomlBytes32(element) => ::core::fmt::Display::fmt(element, f),
+                Self::ParseTomlBytes32Array(element) => {
+                    ::core::fmt::Display::fmt(element, f)
+                }
+                Self::ParseTomlBytesArray(element) => {
+                    ::core::fmt::Display::fmt(element, f)
+                }
+                Self::ParseTomlInt(element) => ::core::fmt::Display::fmt(element, f),
+                Self::ParseTomlIntArray(element) => ::core::fmt::Display::fmt(element, f),
+                Self::ParseTomlKeys(element) => ::core::fmt::Display::fmt(element, f),
+                Self::ParseTomlString(element) => ::core::fmt::Display::fmt(element, f),
+                Self::ParseTomlStringArray(element) => {
+                    ::core::fmt::Display::fmt(element, f)
+                }
+                Self::ParseTomlUint(element) => ::core::fmt::Display::fmt(element, f),
+                Self::ParseTomlUintArray(element) => {
+                    ::core::fmt::Display::fmt(element, f)
+                }
                 Self::ParseUint(element) => ::core::fmt::Display::fmt(element, f),
                 Self::PauseGasMetering(element) => ::core::fmt::Display::fmt(element, f),
                 Self::Prank0(element) => ::core::fmt::Display::fmt(element, f),
@@ -6477,6 +11204,7 @@ pub mod hevm {
                 Self::RpcUrl(element) => ::core::fmt::Display::fmt(element, f),
                 Self::RpcUrlStructs(element) => ::core::fmt::Display::fmt(element, f),
                 Self::RpcUrls(element) => ::core::fmt::Display::fmt(element, f),
+                Self::SaveDeployment(element) => ::core::fmt::Display::fmt(element, f),
                 Self::SelectFork(element) => ::core::fmt::Display::fmt(element, f),
                 Self::SerializeAddress0(element) => ::core::fmt::Display::fmt(element, f),
                 Self::SerializeAddress1(element) => ::core::fmt::Display::fmt(element, f),
@@ -6498,13 +11226,20 @@ pub mod hevm {
                 Self::Sign(element) => ::core::fmt::Display::fmt(element, f),
                 Self::Skip(element) => ::core::fmt::Display::fmt(element, f),
                 Self::Snapshot(element) => ::core::fmt::Display::fmt(element, f),
+                Self::SnapshotValue(element) => ::core::fmt::Display::fmt(element, f),
                 Self::StartBroadcast0(element) => ::core::fmt::Display::fmt(element, f),
                 Self::StartBroadcast1(element) => ::core::fmt::Display::fmt(element, f),
                 Self::StartBroadcast2(element) => ::core::fmt::Display::fmt(element, f),
                 Self::StartPrank0(element) => ::core::fmt::Display::fmt(element, f),
                 Self::StartPrank1(element) => ::core::fmt::Display::fmt(element, f),
+                Self::StartSnapshotEnergy(element) => {
+                    ::core::fmt::Display::fmt(element, f)
+                }
                 Self::StopBroadcast(element) => ::core::fmt::Display::fmt(element, f),
                 Self::StopPrank(element) => ::core::fmt::Display::fmt(element, f),
+                Self::StopSnapshotEnergy(element) => {
+                    ::core::fmt::Display::fmt(element, f)
+                }
                 Self::Store(element) => ::core::fmt::Display::fmt(element, f),
                 Self::ToString0(element) => ::core::fmt::Display::fmt(element, f),
                 Self::ToString1(element) => ::core::fmt::Display::fmt(element, f),
@@ -6521,27 +11256,449 @@ pub mod hevm {
                 Self::WriteJson0(element) => ::core::fmt::Display::fmt(element, f),
                 Self::WriteJson1(element) => ::core::fmt::Display::fmt(element, f),
                 Self::WriteLine(element) => ::core::fmt::Display::fmt(element, f),
+                Self::WriteToml0(element) => ::core::fmt::Display::fmt(element, f),
+                Self::WriteToml1(element) => ::core::fmt::Display::fmt(element, f),
             }
         }
     }
-    impl ::core::convert::From<AccessesCall> for HEVMCalls {
-        fn from(value: AccessesCall) -> Self {
-            Self::Accesses(value)
+    impl ::core::convert::From<AccessesCall> for HEVMCalls {
+        fn from(value: AccessesCall) -> Self {
+            Self::Accesses(value)
+        }
+    }
+    impl ::core::convert::From<ActiveForkCall> for HEVMCalls {
+        fn from(value: ActiveForkCall) -> Self {
+            Self::ActiveFork(value)
+        }
+    }
+    impl ::core::convert::From<AddrCall> for HEVMCalls {
+        fn from(value: AddrCall) -> Self {
+            Self::Addr(value)
+        }
+    }
+    impl ::core::convert::From<AllowCheatcodesCall> for HEVMCalls {
+        fn from(value: AllowCheatcodesCall) -> Self {
+            Self::AllowCheatcodes(value)
+        }
+    }
+    impl ::core::convert::From<AssertApproxEqAbs0Call> for HEVMCalls {
+        fn from(value: AssertApproxEqAbs0Call) -> Self {
+            Self::AssertApproxEqAbs0(value)
+        }
+    }
+    impl ::core::convert::From<AssertApproxEqAbs2Call> for HEVMCalls {
+        fn from(value: AssertApproxEqAbs2Call) -> Self {
+            Self::AssertApproxEqAbs2(value)
+        }
+    }
+    impl ::core::convert::From<AssertApproxEqAbs1Call> for HEVMCalls {
+        fn from(value: AssertApproxEqAbs1Call) -> Self {
+            Self::AssertApproxEqAbs1(value)
+        }
+    }
+    impl ::core::convert::From<AssertApproxEqAbs3Call> for HEVMCalls {
+        fn from(value: AssertApproxEqAbs3Call) -> Self {
+            Self::AssertApproxEqAbs3(value)
+        }
+    }
+    impl ::core::convert::From<AssertApproxEqRel0Call> for HEVMCalls {
+        fn from(value: AssertApproxEqRel0Call) -> Self {
+            Self::AssertApproxEqRel0(value)
+        }
+    }
+    impl ::core::convert::From<AssertApproxEqRel2Call> for HEVMCalls {
+        fn from(value: AssertApproxEqRel2Call) -> Self {
+            Self::AssertApproxEqRel2(value)
+        }
+    }
+    impl ::core::convert::From<AssertApproxEqRel1Call> for HEVMCalls {
+        fn from(value: AssertApproxEqRel1Call) -> Self {
+            Self::AssertApproxEqRel1(value)
+        }
+    }
+    impl ::core::convert::From<AssertApproxEqRel3Call> for HEVMCalls {
+        fn from(value: AssertApproxEqRel3Call) -> Self {
+            Self::AssertApproxEqRel3(value)
+        }
+    }
+    impl ::core::convert::From<AssertEq0Call> for HEVMCalls {
+        fn from(value: AssertEq0Call) -> Self {
+            Self::AssertEq0(value)
         }
     }
-    impl ::core::convert::From<ActiveForkCall> for HEVMCalls {
-        fn from(value: ActiveForkCall) -> Self {
-            Self::ActiveFork(value)
+    impl ::core::convert::From<AssertEq14Call> for HEVMCalls {
+        fn from(value: AssertEq14Call) -> Self {
+            Self::AssertEq14(value)
         }
     }
-    impl ::core::convert::From<AddrCall> for HEVMCalls {
-        fn from(value: AddrCall) -> Self {
-            Self::Addr(value)
+    impl ::core::convert::From<AssertEq1Call> for HEVMCalls {
+        fn from(value: AssertEq1Call) -> Self {
+            Self::AssertEq1(value)
         }
     }
-    impl ::core::convert::From<AllowCheatcodesCall> for HEVMCalls {
-        fn from(value: AllowCheatcodesCall) -> Self {
-            Self::AllowCheatcodes(value)
+    impl ::core::convert::From<AssertEq15Call> for HEVMCalls {
+        fn from(value: AssertEq15Call) -> Self {
+            Self::AssertEq15(value)
+        }
+    }
+    impl ::core::convert::From<AssertEq2Call> for HEVMCalls {
+        fn from(value: AssertEq2Call) -> Self {
+            Self::AssertEq2(value)
+        }
+    }
+    impl ::core::convert::From<AssertEq16Call> for HEVMCalls {
+        fn from(value: AssertEq16Call) -> Self {
+            Self::AssertEq16(value)
+        }
+    }
+    impl ::core::convert::From<AssertEq3Call> for HEVMCalls {
+        fn from(value: AssertEq3Call) -> Self {
+            Self::AssertEq3(value)
+        }
+    }
+    impl ::core::convert::From<AssertEq17Call> for HEVMCalls {
+        fn from(value: AssertEq17Call) -> Self {
+            Self::AssertEq17(value)
+        }
+    }
+    impl ::core::convert::From<AssertEq4Call> for HEVMCalls {
+        fn from(value: AssertEq4Call) -> Self {
+            Self::AssertEq4(value)
+        }
+    }
+    impl ::core::convert::From<AssertEq18Call> for HEVMCalls {
+        fn from(value: AssertEq18Call) -> Self {
+            Self::AssertEq18(value)
+        }
+    }
+    impl ::core::convert::From<AssertEq5Call> for HEVMCalls {
+        fn from(value: AssertEq5Call) -> Self {
+            Self::AssertEq5(value)
+        }
+    }
+    impl ::core::convert::From<AssertEq19Call> for HEVMCalls {
+        fn from(value: AssertEq19Call) -> Self {
+            Self::AssertEq19(value)
+        }
+    }
+    impl ::core::convert::From<AssertEq6Call> for HEVMCalls {
+        fn from(value: AssertEq6Call) -> Self {
+            Self::AssertEq6(value)
+        }
+    }
+    impl ::core::convert::From<AssertEq20Call> for HEVMCalls {
+        fn from(value: AssertEq20Call) -> Self {
+            Self::AssertEq20(value)
+        }
+    }
+    impl ::core::convert::From<AssertEq7Call> for HEVMCalls {
+        fn from(value: AssertEq7Call) -> Self {
+            Self::AssertEq7(value)
+        }
+    }
+    impl ::core::convert::From<AssertEq21Call> for HEVMCalls {
+        fn from(value: AssertEq21Call) -> Self {
+            Self::AssertEq21(value)
+        }
+    }
+    impl ::core::convert::From<AssertEq8Call> for HEVMCalls {
+        fn from(value: AssertEq8Call) -> Self {
+            Self::AssertEq8(value)
+        }
+    }
+    impl ::core::convert::From<AssertEq22Call> for HEVMCalls {
+        fn from(value: AssertEq22Call) -> Self {
+            Self::AssertEq22(value)
+        }
+    }
+    impl ::core::convert::From<AssertEq9Call> for HEVMCalls {
+        fn from(value: AssertEq9Call) -> Self {
+            Self::AssertEq9(value)
+        }
+    }
+    impl ::core::convert::From<AssertEq23Call> for HEVMCalls {
+        fn from(value: AssertEq23Call) -> Self {
+            Self::AssertEq23(value)
+        }
+    }
+    impl ::core::convert::From<AssertEq10Call> for HEVMCalls {
+        fn from(value: AssertEq10Call) -> Self {
+            Self::AssertEq10(value)
+        }
+    }
+    impl ::core::convert::From<AssertEq24Call> for HEVMCalls {
+        fn from(value: AssertEq24Call) -> Self {
+            Self::AssertEq24(value)
+        }
+    }
+    impl ::core::convert::From<AssertEq11Call> for HEVMCalls {
+        fn from(value: AssertEq11Call) -> Self {
+            Self::AssertEq11(value)
+        }
+    }
+    impl ::core::convert::From<AssertEq25Call> for HEVMCalls {
+        fn from(value: AssertEq25Call) -> Self {
+            Self::AssertEq25(value)
+        }
+    }
+    impl ::core::convert::From<AssertEq12Call> for HEVMCalls {
+        fn from(value: AssertEq12Call) -> Self {
+            Self::AssertEq12(value)
+        }
+    }
+    impl ::core::convert::From<AssertEq26Call> for HEVMCalls {
+        fn from(value: AssertEq26Call) -> Self {
+            Self::AssertEq26(value)
+        }
+    }
+    impl ::core::convert::From<AssertEq13Call> for HEVMCalls {
+        fn from(value: AssertEq13Call) -> Self {
+            Self::AssertEq13(value)
+        }
+    }
+    impl ::core::convert::From<AssertEq27Call> for HEVMCalls {
+        fn from(value: AssertEq27Call) -> Self {
+            Self::AssertEq27(value)
+        }
+    }
+    impl ::core::convert::From<AssertFalse0Call> for HEVMCalls {
+        fn from(value: AssertFalse0Call) -> Self {
+            Self::AssertFalse0(value)
+        }
+    }
+    impl ::core::convert::From<AssertFalse1Call> for HEVMCalls {
+        fn from(value: AssertFalse1Call) -> Self {
+            Self::AssertFalse1(value)
+        }
+    }
+    impl ::core::convert::From<AssertGe0Call> for HEVMCalls {
+        fn from(value: AssertGe0Call) -> Self {
+            Self::AssertGe0(value)
+        }
+    }
+    impl ::core::convert::From<AssertGe2Call> for HEVMCalls {
+        fn from(value: AssertGe2Call) -> Self {
+            Self::AssertGe2(value)
+        }
+    }
+    impl ::core::convert::From<AssertGe1Call> for HEVMCalls {
+        fn from(value: AssertGe1Call) -> Self {
+            Self::AssertGe1(value)
+        }
+    }
+    impl ::core::convert::From<AssertGe3Call> for HEVMCalls {
+        fn from(value: AssertGe3Call) -> Self {
+            Self::AssertGe3(value)
+        }
+    }
+    impl ::core::convert::From<AssertGt0Call> for HEVMCalls {
+        fn from(value: AssertGt0Call) -> Self {
+            Self::AssertGt0(value)
+        }
+    }
+    impl ::core::convert::From<AssertGt2Call> for HEVMCalls {
+        fn from(value: AssertGt2Call) -> Self {
+            Self::AssertGt2(value)
+        }
+    }
+    impl ::core::convert::From<AssertGt1Call> for HEVMCalls {
+        fn from(value: AssertGt1Call) -> Self {
+            Self::AssertGt1(value)
+        }
+    }
+    impl ::core::convert::From<AssertGt3Call> for HEVMCalls {
+        fn from(value: AssertGt3Call) -> Self {
+            Self::AssertGt3(value)
+        }
+    }
+    impl ::core::convert::From<AssertLe0Call> for HEVMCalls {
+        fn from(value: AssertLe0Call) -> Self {
+            Self::AssertLe0(value)
+        }
+    }
+    impl ::core::convert::From<AssertLe2Call> for HEVMCalls {
+        fn from(value: AssertLe2Call) -> Self {
+            Self::AssertLe2(value)
+        }
+    }
+    impl ::core::convert::From<AssertLe1Call> for HEVMCalls {
+        fn from(value: AssertLe1Call) -> Self {
+            Self::AssertLe1(value)
+        }
+    }
+    impl ::core::convert::From<AssertLe3Call> for HEVMCalls {
+        fn from(value: AssertLe3Call) -> Self {
+            Self::AssertLe3(value)
+        }
+    }
+    impl ::core::convert::From<AssertLt0Call> for HEVMCalls {
+        fn from(value: AssertLt0Call) -> Self {
+            Self::AssertLt0(value)
+        }
+    }
+    impl ::core::convert::From<AssertLt2Call> for HEVMCalls {
+        fn from(value: AssertLt2Call) -> Self {
+            Self::AssertLt2(value)
+        }
+    }
+    impl ::core::convert::From<AssertLt1Call> for HEVMCalls {
+        fn from(value: AssertLt1Call) -> Self {
+            Self::AssertLt1(value)
+        }
+    }
+    impl ::core::convert::From<AssertLt3Call> for HEVMCalls {
+        fn from(value: AssertLt3Call) -> Self {
+            Self::AssertLt3(value)
+        }
+    }
+    impl ::core::convert::From<AssertNotEq0Call> for HEVMCalls {
+        fn from(value: AssertNotEq0Call) -> Self {
+            Self::AssertNotEq0(value)
+        }
+    }
+    impl ::core::convert::From<AssertNotEq14Call> for HEVMCalls {
+        fn from(value: AssertNotEq14Call) -> Self {
+            Self::AssertNotEq14(value)
+        }
+    }
+    impl ::core::convert::From<AssertNotEq1Call> for HEVMCalls {
+        fn from(value: AssertNotEq1Call) -> Self {
+            Self::AssertNotEq1(value)
+        }
+    }
+    impl ::core::convert::From<AssertNotEq15Call> for HEVMCalls {
+        fn from(value: AssertNotEq15Call) -> Self {
+            Self::AssertNotEq15(value)
+        }
+    }
+    impl ::core::convert::From<AssertNotEq2Call> for HEVMCalls {
+        fn from(value: AssertNotEq2Call) -> Self {
+            Self::AssertNotEq2(value)
+        }
+    }
+    impl ::core::convert::From<AssertNotEq16Call> for HEVMCalls {
+        fn from(value: AssertNotEq16Call) -> Self {
+            Self::AssertNotEq16(value)
+        }
+    }
+    impl ::core::convert::From<AssertNotEq3Call> for HEVMCalls {
+        fn from(value: AssertNotEq3Call) -> Self {
+            Self::AssertNotEq3(value)
+        }
+    }
+    impl ::core::convert::From<AssertNotEq17Call> for HEVMCalls {
+        fn from(value: AssertNotEq17Call) -> Self {
+            Self::AssertNotEq17(value)
+        }
+    }
+    impl ::core::convert::From<AssertNotEq4Call> for HEVMCalls {
+        fn from(value: AssertNotEq4Call) -> Self {
+            Self::AssertNotEq4(value)
+        }
+    }
+    impl ::core::convert::From<AssertNotEq18Call> for HEVMCalls {
+        fn from(value: AssertNotEq18Call) -> Self {
+            Self::AssertNotEq18(value)
+        }
+    }
+    impl ::core::convert::From<AssertNotEq5Call> for HEVMCalls {
+        fn from(value: AssertNotEq5Call) -> Self {
+            Self::AssertNotEq5(value)
+        }
+    }
+    impl ::core::convert::From<AssertNotEq19Call> for HEVMCalls {
+        fn from(value: AssertNotEq19Call) -> Self {
+            Self::AssertNotEq19(value)
+        }
+    }
+    impl ::core::convert::From<AssertNotEq6Call> for HEVMCalls {
+        fn from(value: AssertNotEq6Call) -> Self {
+            Self::AssertNotEq6(value)
+        }
+    }
+    impl ::core::convert::From<AssertNotEq20Call> for HEVMCalls {
+        fn from(value: AssertNotEq20Call) -> Self {
+            Self::AssertNotEq20(value)
+        }
+    }
+    impl ::core::convert::From<AssertNotEq7Call> for HEVMCalls {
+        fn from(value: AssertNotEq7Call) -> Self {
+            Self::AssertNotEq7(value)
+        }
+    }
+    impl ::core::convert::From<AssertNotEq21Call> for HEVMCalls {
+        fn from(value: AssertNotEq21Call) -> Self {
+            Self::AssertNotEq21(value)
+        }
+    }
+    impl ::core::convert::From<AssertNotEq8Call> for HEVMCalls {
+        fn from(value: AssertNotEq8Call) -> Self {
+            Self::AssertNotEq8(value)
+        }
+    }
+    impl ::core::convert::From<AssertNotEq22Call> for HEVMCalls {
+        fn from(value: AssertNotEq22Call) -> Self {
+            Self::AssertNotEq22(value)
+        }
+    }
+    impl ::core::convert::From<AssertNotEq9Call> for HEVMCalls {
+        fn from(value: AssertNotEq9Call) -> Self {
+            Self::AssertNotEq9(value)
+        }
+    }
+    impl ::core::convert::From<AssertNotEq23Call> for HEVMCalls {
+        fn from(value: AssertNotEq23Call) -> Self {
+            Self::AssertNotEq23(value)
+        }
+    }
+    impl ::core::convert::From<AssertNotEq10Call> for HEVMCalls {
+        fn from(value: AssertNotEq10Call) -> Self {
+            Self::AssertNotEq10(value)
+        }
+    }
+    impl ::core::convert::From<AssertNotEq24Call> for HEVMCalls {
+        fn from(value: AssertNotEq24Call) -> Self {
+            Self::AssertNotEq24(value)
+        }
+    }
+    impl ::core::convert::From<AssertNotEq11Call> for HEVMCalls {
+        fn from(value: AssertNotEq11Call) -> Self {
+            Self::AssertNotEq11(value)
+        }
+    }
+    impl ::core::convert::From<AssertNotEq25Call> for HEVMCalls {
+        fn from(value: AssertNotEq25Call) -> Self {
+            Self::AssertNotEq25(value)
+        }
+    }
+    impl ::core::convert::From<AssertNotEq12Call> for HEVMCalls {
+        fn from(value: AssertNotEq12Call) -> Self {
+            Self::AssertNotEq12(value)
+        }
+    }
+    impl ::core::convert::From<AssertNotEq26Call> for HEVMCalls {
+        fn from(value: AssertNotEq26Call) -> Self {
+            Self::AssertNotEq26(value)
+        }
+    }
+    impl ::core::convert::From<AssertNotEq13Call> for HEVMCalls {
+        fn from(value: AssertNotEq13Call) -> Self {
+            Self::AssertNotEq13(value)
+        }
+    }
+    impl ::core::convert::From<AssertNotEq27Call> for HEVMCalls {
+        fn from(value: AssertNotEq27Call) -> Self {
+            Self::AssertNotEq27(value)
+        }
+    }
+    impl ::core::convert::From<AssertTrue0Call> for HEVMCalls {
+        fn from(value: AssertTrue0Call) -> Self {
+            Self::AssertTrue0(value)
+        }
+    }
+    impl ::core::convert::From<AssertTrue1Call> for HEVMCalls {
+        fn from(value: AssertTrue1Call) -> Self {
+            Self::AssertTrue1(value)
         }
     }
     impl ::core::convert::From<AssumeCall> for HEVMCalls {
@@ -6629,6 +11786,11 @@ pub mod hevm {
             Self::CreateSelectFork0(value)
         }
     }
+    impl ::core::convert::From<CreateWalletCall> for HEVMCalls {
+        fn from(value: CreateWalletCall) -> Self {
+            Self::CreateWallet(value)
+        }
+    }
     impl ::core::convert::From<DealCall> for HEVMCalls {
         fn from(value: DealCall) -> Self {
             Self::Deal(value)
@@ -6864,6 +12026,56 @@ pub mod hevm {
             Self::ExpectEmit3(value)
         }
     }
+    impl ::core::convert::From<ExpectEmit4Call> for HEVMCalls {
+        fn from(value: ExpectEmit4Call) -> Self {
+            Self::ExpectEmit4(value)
+        }
+    }
+    impl ::core::convert::From<ExpectEmit5Call> for HEVMCalls {
+        fn from(value: ExpectEmit5Call) -> Self {
+            Self::ExpectEmit5(value)
+        }
+    }
+    impl ::core::convert::From<ExpectEmit6Call> for HEVMCalls {
+        fn from(value: ExpectEmit6Call) -> Self {
+            Self::ExpectEmit6(value)
+        }
+    }
+    impl ::core::convert::From<ExpectEmit7Call> for HEVMCalls {
+        fn from(value: ExpectEmit7Call) -> Self {
+            Self::ExpectEmit7(value)
+        }
+    }
+    impl ::core::convert::From<ExpectEmitAnonymous0Call> for HEVMCalls {
+        fn from(value: ExpectEmitAnonymous0Call) -> Self {
+            Self::ExpectEmitAnonymous0(value)
+        }
+    }
+    impl ::core::convert::From<ExpectEmitAnonymous1Call> for HEVMCalls {
+        fn from(value: ExpectEmitAnonymous1Call) -> Self {
+            Self::ExpectEmitAnonymous1(value)
+        }
+    }
+    impl ::core::convert::From<ExpectEmitAnonymous2Call> for HEVMCalls {
+        fn from(value: ExpectEmitAnonymous2Call) -> Self {
+            Self::ExpectEmitAnonymous2(value)
+        }
+    }
+    impl ::core::convert::From<ExpectEmitAnonymous3Call> for HEVMCalls {
+        fn from(value: ExpectEmitAnonymous3Call) -> Self {
+            Self::ExpectEmitAnonymous3(value)
+        }
+    }
+    impl ::core::convert::From<ExpectPartialRevert0Call> for HEVMCalls {
+        fn from(value: ExpectPartialRevert0Call) -> Self {
+            Self::ExpectPartialRevert0(value)
+        }
+    }
+    impl ::core::convert::From<ExpectPartialRevert1Call> for HEVMCalls {
+        fn from(value: ExpectPartialRevert1Call) -> Self {
+            Self::ExpectPartialRevert1(value)
+        }
+    }
     impl ::core::convert::From<ExpectRevert0Call> for HEVMCalls {
         fn from(value: ExpectRevert0Call) -> Self {
             Self::ExpectRevert0(value)
@@ -6879,6 +12091,36 @@ pub mod hevm {
             Self::ExpectRevert2(value)
         }
     }
+    impl ::core::convert::From<ExpectRevert3Call> for HEVMCalls {
+        fn from(value: ExpectRevert3Call) -> Self {
+            Self::ExpectRevert3(value)
+        }
+    }
+    impl ::core::convert::From<ExpectRevert4Call> for HEVMCalls {
+        fn from(value: ExpectRevert4Call) -> Self {
+            Self::ExpectRevert4(value)
+        }
+    }
+    impl ::core::convert::From<ExpectRevert5Call> for HEVMCalls {
+        fn from(value: ExpectRevert5Call) -> Self {
+            Self::ExpectRevert5(value)
+        }
+    }
+    impl ::core::convert::From<ExpectRevert6Call> for HEVMCalls {
+        fn from(value: ExpectRevert6Call) -> Self {
+            Self::ExpectRevert6(value)
+        }
+    }
+    impl ::core::convert::From<ExpectRevert7Call> for HEVMCalls {
+        fn from(value: ExpectRevert7Call) -> Self {
+            Self::ExpectRevert7(value)
+        }
+    }
+    impl ::core::convert::From<ExpectRevert8Call> for HEVMCalls {
+        fn from(value: ExpectRevert8Call) -> Self {
+            Self::ExpectRevert8(value)
+        }
+    }
     impl ::core::convert::From<ExpectSafeMemoryCall> for HEVMCalls {
         fn from(value: ExpectSafeMemoryCall) -> Self {
             Self::ExpectSafeMemory(value)
@@ -6914,6 +12156,16 @@ pub mod hevm {
             Self::GetDeployedCode(value)
         }
     }
+    impl ::core::convert::From<GetDeployment0Call> for HEVMCalls {
+        fn from(value: GetDeployment0Call) -> Self {
+            Self::GetDeployment0(value)
+        }
+    }
+    impl ::core::convert::From<GetDeployment1Call> for HEVMCalls {
+        fn from(value: GetDeployment1Call) -> Self {
+            Self::GetDeployment1(value)
+        }
+    }
     impl ::core::convert::From<GetLabelCall> for HEVMCalls {
         fn from(value: GetLabelCall) -> Self {
             Self::GetLabel(value)
@@ -6934,6 +12186,16 @@ pub mod hevm {
             Self::IsPersistent(value)
         }
     }
+    impl ::core::convert::From<KeyExistsJsonCall> for HEVMCalls {
+        fn from(value: KeyExistsJsonCall) -> Self {
+            Self::KeyExistsJson(value)
+        }
+    }
+    impl ::core::convert::From<KeyExistsTomlCall> for HEVMCalls {
+        fn from(value: KeyExistsTomlCall) -> Self {
+            Self::KeyExistsToml(value)
+        }
+    }
     impl ::core::convert::From<LabelCall> for HEVMCalls {
         fn from(value: LabelCall) -> Self {
             Self::Label(value)
@@ -7074,6 +12336,11 @@ pub mod hevm {
             Self::ParseJsonIntArray(value)
         }
     }
+    impl ::core::convert::From<ParseJsonKeysCall> for HEVMCalls {
+        fn from(value: ParseJsonKeysCall) -> Self {
+            Self::ParseJsonKeys(value)
+        }
+    }
     impl ::core::convert::From<ParseJsonStringCall> for HEVMCalls {
         fn from(value: ParseJsonStringCall) -> Self {
             Self::ParseJsonString(value)
@@ -7094,6 +12361,91 @@ pub mod hevm {
             Self::ParseJsonUintArray(value)
         }
     }
+    impl ::core::convert::From<ParseToml0Call> for HEVMCalls {
+        fn from(value: ParseToml0Call) -> Self {
+            Self::ParseToml0(value)
+        }
+    }
+    impl ::core::convert::From<ParseToml1Call> for HEVMCalls {
+        fn from(value: ParseToml1Call) -> Self {
+            Self::ParseToml1(value)
+        }
+    }
+    impl ::core::convert::From<ParseTomlAddressCall> for HEVMCalls {
+        fn from(value: ParseTomlAddressCall) -> Self {
+            Self::ParseTomlAddress(value)
+        }
+    }
+    impl ::core::convert::From<ParseTomlAddressArrayCall> for HEVMCalls {
+        fn from(value: ParseTomlAddressArrayCall) -> Self {
+            Self::ParseTomlAddressArray(value)
+        }
+    }
+    impl ::core::convert::From<ParseTomlBoolCall> for HEVMCalls {
+        fn from(value: ParseTomlBoolCall) -> Self {
+            Self::ParseTomlBool(value)
+        }
+    }
+    impl ::core::convert::From<ParseTomlBoolArrayCall> for HEVMCalls {
+        fn from(value: ParseTomlBoolArrayCall) -> Self {
+            Self::ParseTomlBoolArray(value)
+        }
+    }
+    impl ::core::convert::From<ParseTomlBytesCall> for HEVMCalls {
+        fn from(value: ParseTomlBytesCall) -> Self {
+            Self::ParseTomlBytes(value)
+        }
+    }
+    impl ::core::convert::From<ParseTomlBytes32Call> for HEVMCalls {
+        fn from(value: ParseTomlBytes32Call) -> Self {
+            Self::ParseTomlBytes32(value)
+        }
+    }
+    impl ::core::convert::From<ParseTomlBytes32ArrayCall> for HEVMCalls {
+        fn from(value: ParseTomlBytes32ArrayCall) -> Self {
+            Self::ParseTomlBytes32Array(value)
+        }
+    }
+    impl ::core::convert::From<ParseTomlBytesArrayCall> for HEVMCalls {
+        fn from(value: ParseTomlBytesArrayCall) -> Self {
+            Self::ParseTomlBytesArray(value)
+        }
+    }
+    impl ::core::convert::From<ParseTomlIntCall> for HEVMCalls {
+        fn from(value: ParseTomlIntCall) -> Self {
+            Self::ParseTomlInt(value)
+        }
+    }
+    impl ::core::convert::From<ParseTomlIntArrayCall> for HEVMCalls {
+        fn from(value: ParseTomlIntArrayCall) -> Self {
+            Self::ParseTomlIntArray(value)
+        }
+    }
+    impl ::core::convert::From<ParseTomlKeysCall> for HEVMCalls {
+        fn from(value: ParseTomlKeysCall) -> Self {
+            Self::ParseTomlKeys(value)
+        }
+    }
+    impl ::core::convert::From<ParseTomlStringCall> for HEVMCalls {
+        fn from(value: ParseTomlStringCall) -> Self {
+            Self::ParseTomlString(value)
+        }
+    }
+    impl ::core::convert::From<ParseTomlStringArrayCall> for HEVMCalls {
+        fn from(value: ParseTomlStringArrayCall) -> Self {
+            Self::ParseTomlStringArray(value)
+        }
+    }
+    impl ::core::convert::From<ParseTomlUintCall> for HEVMCalls {
+        fn from(value: ParseTomlUintCall) -> Self {
+            Self::ParseTomlUint(value)
+        }
+    }
+    impl ::core::convert::From<ParseTomlUintArrayCall> for HEVMCalls {
+        fn from(value: ParseTomlUintArrayCall) -> Self {
+            Self::ParseTomlUintArray(value)
+        }
+    }
     impl ::core::convert::From<ParseUintCall> for HEVMCalls {
         fn from(value: ParseUintCall) -> Self {
             Self::ParseUint(value)
@@ -7249,6 +12601,11 @@ pub mod hevm {
             Self::RpcUrls(value)
         }
     }
+    impl ::core::convert::From<SaveDeploymentCall> for HEVMCalls {
+        fn from(value: SaveDeploymentCall) -> Self {
+            Self::SaveDeployment(value)
+        }
+    }
     impl ::core::convert::From<SelectForkCall> for HEVMCalls {
         fn from(value: SelectForkCall) -> Self {
             Self::SelectFork(value)
@@ -7354,6 +12711,11 @@ pub mod hevm {
             Self::Snapshot(value)
         }
     }
+    impl ::core::convert::From<SnapshotValueCall> for HEVMCalls {
+        fn from(value: SnapshotValueCall) -> Self {
+            Self::SnapshotValue(value)
+        }
+    }
     impl ::core::convert::From<StartBroadcast0Call> for HEVMCalls {
         fn from(value: StartBroadcast0Call) -> Self {
             Self::StartBroadcast0(value)
@@ -7379,6 +12741,11 @@ pub mod hevm {
             Self::StartPrank1(value)
         }
     }
+    impl ::core::convert::From<StartSnapshotEnergyCall> for HEVMCalls {
+        fn from(value: StartSnapshotEnergyCall) -> Self {
+            Self::StartSnapshotEnergy(value)
+        }
+    }
     impl ::core::convert::From<StopBroadcastCall> for HEVMCalls {
         fn from(value: StopBroadcastCall) -> Self {
             Self::StopBroadcast(value)
@@ -7389,6 +12756,11 @@ pub mod hevm {
             Self::StopPrank(value)
         }
     }
+    impl ::core::convert::From<StopSnapshotEnergyCall> for HEVMCalls {
+        fn from(value: StopSnapshotEnergyCall) -> Self {
+            Self::StopSnapshotEnergy(value)
+        }
+    }
     impl ::core::convert::From<StoreCall> for HEVMCalls {
         fn from(value: StoreCall) -> Self {
             Self::Store(value)
@@ -7469,6 +12841,16 @@ pub mod hevm {
             Self::WriteLine(value)
         }
     }
+    impl ::core::convert::From<WriteToml0Call> for HEVMCalls {
+        fn from(value: WriteToml0Call) -> Self {
+            Self::WriteToml0(value)
+        }
+    }
+    impl ::core::convert::From<WriteToml1Call> for HEVMCalls {
+        fn from(value: WriteToml1Call) -> Self {
+            Self::WriteToml1(value)
+        }
+    }
     ///Container type for all return fields from the `accesses` function with signature `accesses(address)` and selector `0x6e606627`
     #[derive(
         Clone,
@@ -7567,8 +12949,20 @@ pub mod hevm {
         Eq,
         Hash
     )]
-    pub struct CreateSelectFork2Return(pub ::corebc_core::types::U256);
-    ///Container type for all return fields from the `createSelectFork` function with signature `createSelectFork(string)` and selector `0x6bcf2c60`
+    pub struct CreateSelectFork2Return(pub ::corebc_core::types::U256);
+    ///Container type for all return fields from the `createSelectFork` function with signature `createSelectFork(string)` and selector `0x6bcf2c60`
+    #[derive(
+        Clone,
+        ::corebc_contract::EthAbiType,
+        ::corebc_contract::EthAbiCodec,
+        Default,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash
+    )]
+    pub struct CreateSelectFork0Return(pub ::corebc_core::types::U256);
+    ///Container type for all return fields from the `createWallet` function with signature `createWallet(string)` and selector `0xbfbf2050`
     #[derive(
         Clone,
         ::corebc_contract::EthAbiType,
@@ -7579,7 +12973,13 @@ pub mod hevm {
         Eq,
         Hash
     )]
-    pub struct CreateSelectFork0Return(pub ::corebc_core::types::U256);
+    pub struct CreateWalletReturn(
+        pub (
+            ::corebc_core::types::Address,
+            ::corebc_core::types::Bytes,
+            ::std::string::String,
+        ),
+    );
     ///Container type for all return fields from the `deriveKey` function with signature `deriveKey(string,uint32)` and selector `0xf6f7e9e1`
     #[derive(
         Clone,
@@ -7591,7 +12991,7 @@ pub mod hevm {
         Eq,
         Hash
     )]
-    pub struct DeriveKey0Return(pub ::corebc_core::types::U256);
+    pub struct DeriveKey0Return(pub ::std::string::String);
     ///Container type for all return fields from the `deriveKey` function with signature `deriveKey(string,string,uint32)` and selector `0xd5a72210`
     #[derive(
         Clone,
@@ -7603,7 +13003,7 @@ pub mod hevm {
         Eq,
         Hash
     )]
-    pub struct DeriveKey1Return(pub ::corebc_core::types::U256);
+    pub struct DeriveKey1Return(pub ::std::string::String);
     ///Container type for all return fields from the `deriveKey` function with signature `deriveKey(string,uint32,string)` and selector `0xee602b67`
     #[derive(
         Clone,
@@ -7615,7 +13015,7 @@ pub mod hevm {
         Eq,
         Hash
     )]
-    pub struct DeriveKey2Return(pub ::corebc_core::types::U256);
+    pub struct DeriveKey2Return(pub ::std::string::String);
     ///Container type for all return fields from the `deriveKey` function with signature `deriveKey(string,string,uint32,string)` and selector `0xcc819384`
     #[derive(
         Clone,
@@ -7627,7 +13027,7 @@ pub mod hevm {
         Eq,
         Hash
     )]
-    pub struct DeriveKey3Return(pub ::corebc_core::types::U256);
+    pub struct DeriveKey3Return(pub ::std::string::String);
     ///Container type for all return fields from the `envAddress` function with signature `envAddress(string)` and selector `0x5992c5c6`
     #[derive(
         Clone,
@@ -7998,6 +13398,30 @@ pub mod hevm {
             ::corebc_core::types::U256,
         ),
     );
+    ///Container type for all return fields from the `getDeployment` function with signature `getDeployment(string)` and selector `0xa2a85ccf`
+    #[derive(
+        Clone,
+        ::corebc_contract::EthAbiType,
+        ::corebc_contract::EthAbiCodec,
+        Default,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash
+    )]
+    pub struct GetDeployment0Return(pub ::corebc_core::types::Address);
+    ///Container type for all return fields from the `getDeployment` function with signature `getDeployment(string,string)` and selector `0xe9bad3f6`
+    #[derive(
+        Clone,
+        ::corebc_contract::EthAbiType,
+        ::corebc_contract::EthAbiCodec,
+        Default,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash
+    )]
+    pub struct GetDeployment1Return(pub ::corebc_core::types::Address);
     ///Container type for all return fields from the `getLabel` function with signature `getLabel(address)` and selector `0x56c8fc59`
     #[derive(
         Clone,
@@ -8036,6 +13460,30 @@ pub mod hevm {
         Hash
     )]
     pub struct IsPersistentReturn(pub bool);
+    ///Container type for all return fields from the `keyExistsJson` function with signature `keyExistsJson(string,string)` and selector `0x43139fe3`
+    #[derive(
+        Clone,
+        ::corebc_contract::EthAbiType,
+        ::corebc_contract::EthAbiCodec,
+        Default,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash
+    )]
+    pub struct KeyExistsJsonReturn(pub bool);
+    ///Container type for all return fields from the `keyExistsToml` function with signature `keyExistsToml(string,string)` and selector `0x3abbe80a`
+    #[derive(
+        Clone,
+        ::corebc_contract::EthAbiType,
+        ::corebc_contract::EthAbiCodec,
+        Default,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash
+    )]
+    pub struct KeyExistsTomlReturn(pub bool);
     ///Container type for all return fields from the `load` function with signature `load(address,bytes32)` and selector `0xfac88623`
     #[derive(
         Clone,
@@ -8256,6 +13704,18 @@ pub mod hevm {
         Hash
     )]
     pub struct ParseJsonIntArrayReturn(pub ::std::vec::Vec<::corebc_core::types::I256>);
+    ///Container type for all return fields from the `parseJsonKeys` function with signature `parseJsonKeys(string,string)` and selector `0xb110ad24`
+    #[derive(
+        Clone,
+        ::corebc_contract::EthAbiType,
+        ::corebc_contract::EthAbiCodec,
+        Default,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash
+    )]
+    pub struct ParseJsonKeysReturn(pub ::std::vec::Vec<::std::string::String>);
     ///Container type for all return fields from the `parseJsonString` function with signature `parseJsonString(string,string)` and selector `0x9d9b1273`
     #[derive(
         Clone,
@@ -8304,6 +13764,214 @@ pub mod hevm {
         Hash
     )]
     pub struct ParseJsonUintArrayReturn(pub ::std::vec::Vec<::corebc_core::types::U256>);
+    ///Container type for all return fields from the `parseToml` function with signature `parseToml(string)` and selector `0x86ee4f75`
+    #[derive(
+        Clone,
+        ::corebc_contract::EthAbiType,
+        ::corebc_contract::EthAbiCodec,
+        Default,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash
+    )]
+    pub struct ParseToml0Return(pub ::corebc_core::types::Bytes);
+    ///Container type for all return fields from the `parseToml` function with signature `parseToml(string,string)` and selector `0xf62093d7`
+    #[derive(
+        Clone,
+        ::corebc_contract::EthAbiType,
+        ::corebc_contract::EthAbiCodec,
+        Default,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash
+    )]
+    pub struct ParseToml1Return(pub ::corebc_core::types::Bytes);
+    ///Container type for all return fields from the `parseTomlAddress` function with signature `parseTomlAddress(string,string)` and selector `0xb6e35291`
+    #[derive(
+        Clone,
+        ::corebc_contract::EthAbiType,
+        ::corebc_contract::EthAbiCodec,
+        Default,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash
+    )]
+    pub struct ParseTomlAddressReturn(pub ::corebc_core::types::Address);
+    ///Container type for all return fields from the `parseTomlAddressArray` function with signature `parseTomlAddressArray(string,string)` and selector `0x9d14a569`
+    #[derive(
+        Clone,
+        ::corebc_contract::EthAbiType,
+        ::corebc_contract::EthAbiCodec,
+        Default,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash
+    )]
+    pub struct ParseTomlAddressArrayReturn(
+        pub ::std::vec::Vec<::corebc_core::types::Address>,
+    );
+    ///Container type for all return fields from the `parseTomlBool` function with signature `parseTomlBool(string,string)` and selector `0x66a6bf4d`
+    #[derive(
+        Clone,
+        ::corebc_contract::EthAbiType,
+        ::corebc_contract::EthAbiCodec,
+        Default,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash
+    )]
+    pub struct ParseTomlBoolReturn(pub bool);
+    ///Container type for all return fields from the `parseTomlBoolArray` function with signature `parseTomlBoolArray(string,string)` and selector `0xc1556c81`
+    #[derive(
+        Clone,
+        ::corebc_contract::EthAbiType,
+        ::corebc_contract::EthAbiCodec,
+        Default,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash
+    )]
+    pub struct ParseTomlBoolArrayReturn(pub ::std::vec::Vec<bool>);
+    ///Container type for all return fields from the `parseTomlBytes` function with signature `parseTomlBytes(string,string)` and selector `0x4b072717`
+    #[derive(
+        Clone,
+        ::corebc_contract::EthAbiType,
+        ::corebc_contract::EthAbiCodec,
+        Default,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash
+    )]
+    pub struct ParseTomlBytesReturn(pub ::corebc_core::types::Bytes);
+    ///Container type for all return fields from the `parseTomlBytes32` function with signature `parseTomlBytes32(string,string)` and selector `0x85c38481`
+    #[derive(
+        Clone,
+        ::corebc_contract::EthAbiType,
+        ::corebc_contract::EthAbiCodec,
+        Default,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash
+    )]
+    pub struct ParseTomlBytes32Return(pub [u8; 32]);
+    ///Container type for all return fields from the `parseTomlBytes32Array` function with signature `parseTomlBytes32Array(string,string)` and selector `0x1bff6760`
+    #[derive(
+        Clone,
+        ::corebc_contract::EthAbiType,
+        ::corebc_contract::EthAbiCodec,
+        Default,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash
+    )]
+    pub struct ParseTomlBytes32ArrayReturn(pub ::std::vec::Vec<[u8; 32]>);
+    ///Container type for all return fields from the `parseTomlBytesArray` function with signature `parseTomlBytesArray(string,string)` and selector `0xfcd16926`
+    #[derive(
+        Clone,
+        ::corebc_contract::EthAbiType,
+        ::corebc_contract::EthAbiCodec,
+        Default,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash
+    )]
+    pub struct ParseTomlBytesArrayReturn(
+        pub ::std::vec::Vec<::corebc_core::types::Bytes>,
+    );
+    ///Container type for all return fields from the `parseTomlInt` function with signature `parseTomlInt(string,string)` and selector `0xf0788b93`
+    #[derive(
+        Clone,
+        ::corebc_contract::EthAbiType,
+        ::corebc_contract::EthAbiCodec,
+        Default,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash
+    )]
+    pub struct ParseTomlIntReturn(pub ::corebc_core::types::I256);
+    ///Container type for all return fields from the `parseTomlIntArray` function with signature `parseTomlIntArray(string,string)` and selector `0xd68057a4`
+    #[derive(
+        Clone,
+        ::corebc_contract::EthAbiType,
+        ::corebc_contract::EthAbiCodec,
+        Default,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash
+    )]
+    pub struct ParseTomlIntArrayReturn(pub ::std::vec::Vec<::corebc_core::types::I256>);
+    ///Container type for all return fields from the `parseTomlKeys` function with signature `parseTomlKeys(string,string)` and selector `0x4546f736`
+    #[derive(
+        Clone,
+        ::corebc_contract::EthAbiType,
+        ::corebc_contract::EthAbiCodec,
+        Default,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash
+    )]
+    pub struct ParseTomlKeysReturn(pub ::std::vec::Vec<::std::string::String>);
+    ///Container type for all return fields from the `parseTomlString` function with signature `parseTomlString(string,string)` and selector `0x75027319`
+    #[derive(
+        Clone,
+        ::corebc_contract::EthAbiType,
+        ::corebc_contract::EthAbiCodec,
+        Default,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash
+    )]
+    pub struct ParseTomlStringReturn(pub ::std::string::String);
+    ///Container type for all return fields from the `parseTomlStringArray` function with signature `parseTomlStringArray(string,string)` and selector `0xcdc04e0c`
+    #[derive(
+        Clone,
+        ::corebc_contract::EthAbiType,
+        ::corebc_contract::EthAbiCodec,
+        Default,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash
+    )]
+    pub struct ParseTomlStringArrayReturn(pub ::std::vec::Vec<::std::string::String>);
+    ///Container type for all return fields from the `parseTomlUint` function with signature `parseTomlUint(string,string)` and selector `0xa444e113`
+    #[derive(
+        Clone,
+        ::corebc_contract::EthAbiType,
+        ::corebc_contract::EthAbiCodec,
+        Default,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash
+    )]
+    pub struct ParseTomlUintReturn(pub ::corebc_core::types::U256);
+    ///Container type for all return fields from the `parseTomlUintArray` function with signature `parseTomlUintArray(string,string)` and selector `0x93fa17f9`
+    #[derive(
+        Clone,
+        ::corebc_contract::EthAbiType,
+        ::corebc_contract::EthAbiCodec,
+        Default,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash
+    )]
+    pub struct ParseTomlUintArrayReturn(pub ::std::vec::Vec<::corebc_core::types::U256>);
     ///Container type for all return fields from the `parseUint` function with signature `parseUint(string)` and selector `0xd3fdc787`
     #[derive(
         Clone,
@@ -8694,6 +14362,18 @@ pub mod hevm {
         Hash
     )]
     pub struct SnapshotReturn(pub ::corebc_core::types::U256);
+    ///Container type for all return fields from the `stopSnapshotEnergy` function with signature `stopSnapshotEnergy(string)` and selector `0xb1740636`
+    #[derive(
+        Clone,
+        ::corebc_contract::EthAbiType,
+        ::corebc_contract::EthAbiCodec,
+        Default,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash
+    )]
+    pub struct StopSnapshotEnergyReturn(pub ::corebc_core::types::U256);
     ///`DirEntry(string,string,uint64,bool,bool)`
     #[derive(
         Clone,
@@ -8762,4 +14442,20 @@ pub mod hevm {
         pub name: ::std::string::String,
         pub url: ::std::string::String,
     }
+    ///`Wallet(address,bytes,string)`
+    #[derive(
+        Clone,
+        ::corebc_contract::EthAbiType,
+        ::corebc_contract::EthAbiCodec,
+        Default,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash
+    )]
+    pub struct Wallet {
+        pub addr: ::corebc_core::types::Address,
+        pub public_key: ::corebc_core::types::Bytes,
+        pub private_key: ::std::string::String,
+    }
 }
@@ -1,6 +1,6 @@
 //! probe create2 subcommand
 
-use crate::cmd::Cmd;
+use crate::{cmd::Cmd, utils::parse_u256};
 use clap::Parser;
 use corebc::{
     core::rand::thread_rng,
@@ -19,7 +19,7 @@ pub struct Create2Args {
     #[clap(
         long,
         short,
-        required_unless_present_any = &["ends_with", "matching"],
+        required_unless_present_any = &["ends_with", "matching", "salt"],
         value_name = "HEX"
     )]
     starts_with: Option<String>,
@@ -53,6 +53,15 @@ pub struct Create2Args {
     #[clap(alias = "ch", long, value_name = "HASH")]
     init_code_hash: Option<String>,
 
+    /// Computes the address of the deployment with this salt, instead of mining a salt.
+    #[clap(
+        long,
+        conflicts_with_all = &["starts_with", "ends_with", "matching"],
+        value_parser = parse_u256,
+        value_name = "SALT"
+    )]
+    salt: Option<U256>,
+
     /// Network to use for address prefix validation.
     #[clap(short, long)]
     pub network: Network,
@@ -82,9 +91,31 @@ impl Create2Args {
             deployer,
             init_code,
             init_code_hash,
+            salt,
             network,
         } = self;
 
+        let init_code_hash = if let Some(init_code_hash) = init_code_hash {
+            let mut a: [u8; 32] = [0; 32];
+            let init_code_hash = init_code_hash.strip_prefix("0x").unwrap_or(&init_code_hash);
+            assert!(init_code_hash.len() == 64, "init code hash should be 32 bytes long"); // 32 bytes * 2
+            a.copy_from_slice(&hex::decode(init_code_hash)?[..32]);
+            a
+        } else {
+            let init_code = init_code.strip_prefix("0x").unwrap_or(&init_code).as_bytes();
+            sha3(hex::decode(init_code)?)
+        };
+
+        if let Some(salt) = salt {
+            let mut salt_bytes = [0u8; 32];
+            salt.to_big_endian(&mut salt_bytes);
+            let addr =
+                get_create2_h160_address_from_hash(deployer, salt_bytes.to_vec(), init_code_hash);
+            let address = to_ican(&addr, &network);
+            println!("Address: {address}\nSalt: {salt}");
+            return Ok(Create2Output { address, salt })
+        }
+
         let mut regexs = vec![];
 
         if let Some(matches) = matching {
@@ -124,17 +155,6 @@ impl Create2Args {
 
         let regex = RegexSetBuilder::new(regexs).case_insensitive(!case_sensitive).build()?;
 
-        let init_code_hash = if let Some(init_code_hash) = init_code_hash {
-            let mut a: [u8; 32] = [0; 32];
-            let init_code_hash = init_code_hash.strip_prefix("0x").unwrap_or(&init_code_hash);
-            assert!(init_code_hash.len() == 64, "init code hash should be 32 bytes long"); // 32 bytes * 2
-            a.copy_from_slice(&hex::decode(init_code_hash)?[..32]);
-            a
-        } else {
-            let init_code = init_code.strip_prefix("0x").unwrap_or(&init_code).as_bytes();
-            sha3(hex::decode(init_code)?)
-        };
-
         println!("Starting to generate deterministic contract address...");
         let timer = Instant::now();
         let (salt, addr) = std::iter::repeat(())
@@ -252,6 +272,30 @@ mod tests {
         );
     }
 
+    #[test]
+    fn create2_salt() {
+        let init_code = "00";
+        let salt = "0x0000000000000000000000000000000000000000000000000000000000000001";
+        let args = Create2Args::parse_from([
+            "foxar-cli",
+            "--salt",
+            salt,
+            "--init-code",
+            init_code,
+            "--network",
+            "1",
+        ]);
+        let create2_out = args.run().unwrap();
+        let deployer = Address::from_str(DEPLOYER).unwrap();
+        let salt = U256::from_str(salt).unwrap();
+
+        assert_eq!(create2_out.salt, salt);
+        assert_eq!(
+            create2_out.address,
+            verify_create2(deployer, salt, hex::decode(init_code).unwrap())
+        );
+    }
+
     #[test]
     fn verify_helpers() {
         // https://eips.ethereum.org/EIPS/eip-1014
@@ -0,0 +1,163 @@
+//! Inline coverage config, parsed from `coverage-ignore-*` comments
+
+use itertools::Itertools;
+use solang_parser::pt::{Comment, Loc};
+use std::{fmt, ops::Range, str::FromStr};
+
+/// An inline coverage config item
+#[allow(clippy::enum_variant_names)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum InlineConfigItem {
+    /// Ignores the coverage items between the next newline and the newline after
+    IgnoreNextLine,
+    /// Ignores the coverage items that follow this and precede the next "ignore-end"
+    IgnoreStart,
+    /// Ignores the coverage items that precede this and follow the previous "ignore-start"
+    IgnoreEnd,
+}
+
+impl FromStr for InlineConfigItem {
+    type Err = InvalidInlineConfigItem;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s {
+            "coverage-ignore-next-line" => InlineConfigItem::IgnoreNextLine,
+            "coverage-ignore-start" => InlineConfigItem::IgnoreStart,
+            "coverage-ignore-end" => InlineConfigItem::IgnoreEnd,
+            s => return Err(InvalidInlineConfigItem(s.into())),
+        })
+    }
+}
+
+#[derive(Debug)]
+pub struct InvalidInlineConfigItem(String);
+
+impl fmt::Display for InvalidInlineConfigItem {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_fmt(format_args!("Invalid inline coverage config item: {}", self.0))
+    }
+}
+
+/// An inline coverage config. Keeps track of the ignored byte ranges of a source file.
+#[derive(Default, Debug)]
+pub struct InlineConfig {
+    ignored_ranges: Vec<Range<usize>>,
+}
+
+impl InlineConfig {
+    /// Parses the comments of the source file, returns the config and any invalid items.
+    ///
+    /// Sources that can't be parsed have no ignored ranges.
+    pub fn parse(src: &str) -> (Self, Vec<(Loc, InvalidInlineConfigItem)>) {
+        let Ok((_, comments)) = solang_parser::parse(src, 0) else { return Default::default() };
+        let (items, invalid_items): (Vec<_>, Vec<_>) =
+            parse_inline_config_items(&comments).partition_result();
+        (Self::new(items, src), invalid_items)
+    }
+
+    /// Build a new inline config with an iterator of inline config items and their locations in a
+    /// source file
+    pub fn new(items: impl IntoIterator<Item = (Loc, InlineConfigItem)>, src: &str) -> Self {
+        let mut ignored_ranges = vec![];
+        let mut ignored_range_start = None;
+        let mut ignored_depth = 0usize;
+        for (loc, item) in items.into_iter().sorted_by_key(|(loc, _)| loc.start()) {
+            match item {
+                InlineConfigItem::IgnoreNextLine => {
+                    let offset = loc.end();
+                    let mut newlines = src[offset..]
+                        .char_indices()
+                        .filter_map(|(idx, ch)| (ch == '\n').then_some(offset + idx));
+                    if let Some(start) = newlines.next() {
+                        let end = newlines.next().unwrap_or(src.len());
+                        ignored_ranges.push(start..end);
+                    }
+                }
+                InlineConfigItem::IgnoreStart => {
+                    if ignored_depth == 0 {
+                        ignored_range_start = Some(loc.end());
+                    }
+                    ignored_depth += 1;
+                }
+                InlineConfigItem::IgnoreEnd => {
+                    ignored_depth = ignored_depth.saturating_sub(1);
+                    if ignored_depth == 0 {
+                        if let Some(start) = ignored_range_start.take() {
+                            ignored_ranges.push(start..loc.start());
+                        }
+                    }
+                }
+            }
+        }
+        if let Some(start) = ignored_range_start {
+            ignored_ranges.push(start..src.len());
+        }
+        Self { ignored_ranges }
+    }
+
+    /// Check if the coverage item starting at the byte offset is ignored
+    pub fn is_ignored(&self, start: usize) -> bool {
+        self.ignored_ranges.iter().any(|range| range.contains(&start))
+    }
+}
+
+/// Returns the inline config items of all comments which start with `coverage-ignore-`
+fn parse_inline_config_items(
+    comments: &[Comment],
+) -> impl Iterator<Item = Result<(Loc, InlineConfigItem), (Loc, InvalidInlineConfigItem)>> + '_ {
+    comments
+        .iter()
+        .map(|comment| match comment {
+            Comment::Line(loc, text) |
+            Comment::Block(loc, text) |
+            Comment::DocLine(loc, text) |
+            Comment::DocBlock(loc, text) => (*loc, text),
+        })
+        .map(|(loc, text)| {
+            (loc, text.trim_matches(|ch: char| ch == '/' || ch == '*' || ch.is_whitespace()))
+        })
+        .filter(|(_, item)| item.starts_with("coverage-ignore-"))
+        .map(|(loc, item)| item.parse().map(|out| (loc, out)).map_err(|out| (loc, out)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn can_parse_ignored_ranges() {
+        let src = r#"
+contract Counter {
+    function inc(uint256 x) public pure returns (uint256) {
+        // coverage-ignore-next-line
+        require(x < 10);
+        x += 1;
+        /* coverage-ignore-start */
+        x += 2;
+        x += 3;
+        // coverage-ignore-end
+        return x;
+    }
+}
+"#;
+        let (config, invalid) = InlineConfig::parse(src);
+        assert!(invalid.is_empty());
+
+        let ignored = |code: &str| config.is_ignored(src.find(code).unwrap());
+        assert!(ignored("require"));
+        assert!(!ignored("x += 1"));
+        assert!(ignored("x += 2"));
+        assert!(ignored("x += 3"));
+        assert!(!ignored("return"));
+    }
+
+    #[test]
+    fn can_report_invalid_items() {
+        let src = "contract C {\n    // coverage-ignore-everything\n}\n";
+        let (_, invalid) = InlineConfig::parse(src);
+        assert_eq!(invalid.len(), 1);
+        assert_eq!(
+            invalid[0].1.to_string(),
+            "Invalid inline coverage config item: coverage-ignore-everything"
+        );
+    }
+}
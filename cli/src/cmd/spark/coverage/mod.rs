@@ -16,14 +16,17 @@ use corebc::{
     ylem::{artifacts::contract::CompactContractBytecode, sourcemap::SourceMap},
 };
 use eyre::Context;
-use foxar_common::{compile::ProjectCompiler, evm::EvmArgs, fs};
-use foxar_config::Config;
+use foxar_common::{
+    compile::ProjectCompiler, evm::EvmArgs, fs, term::cli_warn, ContractsByArtifact,
+};
+use foxar_config::{Config, CoverageThreshold, LimitsConfig};
 use foxar_evm::utils::evm_spec;
 use semver::Version;
 use spark::{
     coverage::{
-        analysis::SourceAnalyzer, anchors::find_anchors, ContractId, CoverageReport,
-        CoverageReporter, DebugReporter, ItemAnchor, LcovReporter, SummaryReporter,
+        analysis::SourceAnalyzer, anchors::find_anchors, threshold_violations, BaselineReporter,
+        ContractId, CoverageBaseline, CoverageReport, CoverageReporter, DebugReporter, HitMaps,
+        HtmlReporter, ItemAnchor, LcovReporter, SummaryReporter,
     },
     executor::{inspector::CheatsConfig, opts::EvmOpts},
     result::SuiteResult,
@@ -31,9 +34,16 @@ use spark::{
     utils::{build_ic_pc_map, ICPCMap},
     MultiContractRunnerBuilder, TestOptions,
 };
-use std::{collections::HashMap, sync::mpsc::channel};
+use std::{
+    collections::HashMap,
+    path::{Path, PathBuf},
+    sync::mpsc::channel,
+};
 use tracing::trace;
 
+mod inline_config;
+use inline_config::InlineConfig;
+
 // Loads project's figment and merges the build cli arguments into it
 foxar_config::impl_figment_convert!(CoverageArgs, opts, evm_opts);
 
@@ -46,6 +56,27 @@ pub struct CoverageArgs {
     #[clap(long, value_enum, default_value = "summary")]
     report: Vec<CoverageReportKind>,
 
+    /// Save the coverage to the given file, to compare later runs against it with `--baseline`.
+    ///
+    /// Relative paths are resolved against the current directory.
+    #[clap(long, value_name = "FILE")]
+    save_baseline: Option<PathBuf>,
+
+    /// Print the change of the coverage compared to the baseline in the given file.
+    ///
+    /// Lines that are uncovered, but weren't in the baseline, are listed explicitly. Relative
+    /// paths are resolved against the current directory.
+    #[clap(long, value_name = "FILE")]
+    baseline: Option<PathBuf>,
+
+    /// Fail if less than the given percentage of lines is covered.
+    #[clap(long, value_name = "PERCENT")]
+    min_lines: Option<f64>,
+
+    /// Fail if less than the given percentage of branches is covered.
+    #[clap(long, value_name = "PERCENT")]
+    min_branches: Option<f64>,
+
     #[clap(flatten)]
     filter: FilterArgs,
 
@@ -76,9 +107,9 @@ impl CoverageArgs {
         // Set fuzz seed so coverage reports are deterministic
         config.fuzz.seed = Some(U256::from_big_endian(&STATIC_FUZZ_SEED));
 
-        let (project, output) = self.build(&config)?;
+        let (project, output) = Self::build(&config)?;
         p_println!(!self.opts.silent => "Analysing contracts...");
-        let report = self.prepare(&config, output.clone())?;
+        let report = Self::prepare(&config, output.clone())?;
 
         p_println!(!self.opts.silent => "Running tests...");
         self.collect(project, output, report, config, evm_opts).await
@@ -90,8 +121,8 @@ type SourceMaps = HashMap<ContractId, (SourceMap, SourceMap)>;
 
 // The main flow of the command itself
 impl CoverageArgs {
-    /// Builds the project.
-    fn build(&self, config: &Config) -> eyre::Result<(Project, ProjectCompileOutput)> {
+    /// Builds the project with the optimizer disabled, for accurate source maps.
+    pub(crate) fn build(config: &Config) -> eyre::Result<(Project, ProjectCompileOutput)> {
         // Set up the project
         let project = {
             let mut project = config.ephemeral_no_artifacts_project()?;
@@ -114,12 +145,20 @@ impl CoverageArgs {
 
     /// Builds the coverage report.
     #[tracing::instrument(name = "prepare coverage", skip_all)]
-    fn prepare(
-        &self,
+    pub(crate) fn prepare(
         config: &Config,
         output: ProjectCompileOutput,
     ) -> eyre::Result<CoverageReport> {
         let project_paths = config.project_paths();
+        let no_coverage_paths = config
+            .no_coverage_paths
+            .iter()
+            .map(|pattern| {
+                globset::Glob::new(pattern)
+                    .map(|glob| glob.compile_matcher())
+                    .wrap_err_with(|| format!("Invalid `no_coverage_paths` pattern: {pattern}"))
+            })
+            .collect::<eyre::Result<Vec<_>>>()?;
 
         // Extract artifacts
         let (artifacts, sources) = output.into_artifacts_with_sources();
@@ -128,9 +167,12 @@ impl CoverageArgs {
         // Collect ASTs and sources
         let mut versioned_asts: HashMap<Version, HashMap<usize, Ast>> = HashMap::new();
         let mut versioned_sources: HashMap<Version, HashMap<usize, String>> = HashMap::new();
+        let mut inline_configs: HashMap<(Version, usize), InlineConfig> = HashMap::new();
         for (path, mut source_file, version) in sources.into_sources_with_version() {
-            // Filter out dependencies
-            if project_paths.has_library_ancestor(std::path::Path::new(&path)) {
+            // Filter out dependencies and excluded paths
+            if project_paths.has_library_ancestor(std::path::Path::new(&path)) ||
+                no_coverage_paths.iter().any(|matcher| matcher.is_match(&path))
+            {
                 continue;
             }
 
@@ -143,11 +185,19 @@ impl CoverageArgs {
                 let file = project_paths.root.join(&path);
                 trace!(root=?project_paths.root, ?file, "reading source file");
 
-                versioned_sources.entry(version.clone()).or_default().insert(
-                    source_file.id as usize,
-                    fs::read_to_string(&file)
-                        .wrap_err("Could not read source code for analysis")?,
-                );
+                let source = fs::read_to_string(&file)
+                    .wrap_err("Could not read source code for analysis")?;
+                let (inline_config, invalid_items) = InlineConfig::parse(&source);
+                for (loc, warning) in invalid_items {
+                    let line = source[..loc.start().min(source.len())].split('\n').count();
+                    cli_warn!("[{path}:{line}] {warning}");
+                }
+                inline_configs.insert((version.clone(), source_file.id as usize), inline_config);
+
+                versioned_sources
+                    .entry(version.clone())
+                    .or_default()
+                    .insert(source_file.id as usize, source);
                 report.add_source(version, source_file.id as usize, path);
             }
         }
@@ -216,7 +266,7 @@ impl CoverageArgs {
 
         // Add coverage items
         for (version, asts) in versioned_asts.into_iter() {
-            let source_analysis = SourceAnalyzer::new(
+            let mut source_analysis = SourceAnalyzer::new(
                 version.clone(),
                 asts,
                 versioned_sources.remove(&version).ok_or_else(|| {
@@ -226,6 +276,11 @@ impl CoverageArgs {
                 })?,
             )?
             .analyze()?;
+            source_analysis.retain_items(|item| {
+                inline_configs
+                    .get(&(version.clone(), item.loc.source_id))
+                    .map_or(true, |config| !config.is_ignored(item.loc.start))
+            });
             let anchors: HashMap<ContractId, Vec<ItemAnchor>> = source_analysis
                 .contract_items
                 .iter()
@@ -277,64 +332,134 @@ impl CoverageArgs {
         // Run tests
         let known_contracts = runner.known_contracts.clone();
         let filter = self.filter;
+        let test_options = TestOptions {
+            limits: LimitsConfig::new(config.timeout, config.energy_budget, config.step_budget),
+            fixtures: Some(root.join(&config.test).join("fixtures")),
+            ..Default::default()
+        };
         let (tx, rx) = channel::<(String, SuiteResult)>();
         let handle =
-            tokio::task::spawn(
-                async move { runner.test(filter, Some(tx), Default::default()).await },
-            );
+            tokio::task::spawn(async move { runner.test(filter, Some(tx), test_options).await });
 
         // Add hit data to the coverage report
-        for (artifact_id, hits) in rx
+        // TODO: Note down failing tests
+        for hit_maps in rx
             .into_iter()
             .flat_map(|(_, suite)| suite.test_results.into_values())
             .filter_map(|mut result| result.coverage.take())
-            .flat_map(|hit_maps| {
-                hit_maps.0.into_values().filter_map(|map| {
-                    Some((known_contracts.find_by_code(map.bytecode.as_ref())?.0, map))
-                })
-            })
         {
-            // TODO: Note down failing tests
-            if let Some(source_id) = report.get_source_id(
-                artifact_id.version.clone(),
-                artifact_id.source.to_string_lossy().to_string(),
-            ) {
-                let source_id = *source_id;
-                // TODO: Distinguish between creation/runtime in a smart way
-                report.add_hit_map(
-                    &ContractId {
-                        version: artifact_id.version.clone(),
-                        source_id,
-                        contract_name: artifact_id.name.clone(),
-                    },
-                    &hits,
-                );
-            }
+            add_hit_maps(&mut report, &known_contracts, hit_maps);
         }
 
         // Reattach the thread
         let _ = handle.await;
 
         // Output final report
-        for report_kind in self.report {
-            match report_kind {
-                CoverageReportKind::Summary => SummaryReporter::default().report(&report),
-                // TODO: Sensible place to put the LCOV file
-                CoverageReportKind::Lcov => {
-                    LcovReporter::new(&mut fs::create_file(root.join("lcov.info"))?).report(&report)
-                }
-                CoverageReportKind::Debug => DebugReporter.report(&report),
-            }?;
+        write_reports(self.report, &root, &report)?;
+
+        if let Some(path) = &self.baseline {
+            BaselineReporter::new(CoverageBaseline::read(path)?, &root).report(&report)?;
+        }
+        if let Some(path) = &self.save_baseline {
+            CoverageBaseline::new(&report, &root).write(path)?;
+        }
+
+        let mut thresholds = config.coverage_thresholds.clone();
+        if self.min_lines.is_some() || self.min_branches.is_some() {
+            thresholds.push(CoverageThreshold::all(self.min_lines, self.min_branches));
+        }
+        let violations = threshold_violations(&report, &thresholds);
+        if !violations.is_empty() {
+            eyre::bail!("Coverage is below the minimum:\n{}", violations.join("\n"))
         }
         Ok(())
     }
 }
 
-// TODO: HTML
+/// Reports the coverage of the hit maps collected by `spark script --coverage`.
+///
+/// The project is compiled again for the analysis, the script must have been compiled with the
+/// optimizer disabled as well for its bytecode to match.
+pub(crate) fn report_script_coverage(
+    config: &Config,
+    hit_maps: HitMaps,
+    report_kinds: Vec<CoverageReportKind>,
+) -> eyre::Result<()> {
+    let (project, output) = CoverageArgs::build(config)?;
+    let known_contracts = ContractsByArtifact(
+        output
+            .artifact_ids()
+            .filter_map(|(id, artifact)| {
+                let CompactContractBytecode { abi, deployed_bytecode, .. } =
+                    artifact.clone().into();
+                let code = dummy_link_deployed_bytecode(deployed_bytecode?)?;
+                Some((id, (abi?, code.to_vec())))
+            })
+            .collect(),
+    );
+    let mut report = CoverageArgs::prepare(config, output)?;
+
+    add_hit_maps(&mut report, &known_contracts, hit_maps);
+    write_reports(report_kinds, &project.paths.root, &report)
+}
+
+/// Adds the hits of the contracts found in `known_contracts` to the coverage report.
+fn add_hit_maps(
+    report: &mut CoverageReport,
+    known_contracts: &ContractsByArtifact,
+    hit_maps: HitMaps,
+) {
+    for (artifact_id, hits) in hit_maps
+        .0
+        .into_values()
+        .filter_map(|map| Some((known_contracts.find_by_code(map.bytecode.as_ref())?.0, map)))
+    {
+        if let Some(source_id) = report.get_source_id(
+            artifact_id.version.clone(),
+            artifact_id.source.to_string_lossy().to_string(),
+        ) {
+            let source_id = *source_id;
+            // TODO: Distinguish between creation/runtime in a smart way
+            report.add_hit_map(
+                &ContractId {
+                    version: artifact_id.version.clone(),
+                    source_id,
+                    contract_name: artifact_id.name.clone(),
+                },
+                &hits,
+            );
+        }
+    }
+}
+
+/// Outputs the coverage report with each of the reporters.
+fn write_reports(
+    report_kinds: Vec<CoverageReportKind>,
+    root: &Path,
+    report: &CoverageReport,
+) -> eyre::Result<()> {
+    for report_kind in report_kinds {
+        match report_kind {
+            CoverageReportKind::Summary => SummaryReporter::default().report(report),
+            // TODO: Sensible place to put the LCOV file
+            CoverageReportKind::Lcov => {
+                LcovReporter::new(&mut fs::create_file(root.join("lcov.info"))?).report(report)
+            }
+            CoverageReportKind::Html => {
+                HtmlReporter::new(root, root.join("coverage")).report(report)
+            }
+            CoverageReportKind::Debug => DebugReporter.report(report),
+        }?;
+    }
+    Ok(())
+}
+
 #[derive(Debug, Clone, ValueEnum)]
 pub enum CoverageReportKind {
     Summary,
     Lcov,
+    /// Writes an HTML report to the `coverage` directory.
+    Html,
     Debug,
 }
 
@@ -0,0 +1,112 @@
+//! deployments command
+
+use crate::cmd::{spark::build::ProjectPathsArgs, Cmd, LoadConfig};
+use clap::{Parser, Subcommand, ValueHint};
+use comfy_table::{presets::ASCII_MARKDOWN, Table};
+use foxar_common::{deployments::DeploymentRegistry, fs};
+use std::path::PathBuf;
+
+foxar_config::impl_figment_convert!(DeploymentsListArgs, opts);
+foxar_config::impl_figment_convert!(DeploymentsExportArgs, opts);
+
+/// CLI arguments for `spark deployments`.
+#[derive(Debug, Parser)]
+pub struct DeploymentsArgs {
+    #[clap(subcommand)]
+    pub sub: DeploymentsSubcommands,
+}
+
+#[derive(Debug, Subcommand)]
+pub enum DeploymentsSubcommands {
+    /// Lists the deployments of the registry.
+    #[clap(visible_alias = "ls")]
+    List(DeploymentsListArgs),
+
+    /// Exports the deployments of the registry as JSON.
+    Export(DeploymentsExportArgs),
+}
+
+impl Cmd for DeploymentsArgs {
+    type Output = ();
+
+    fn run(self) -> eyre::Result<Self::Output> {
+        match self.sub {
+            DeploymentsSubcommands::List(cmd) => cmd.run(),
+            DeploymentsSubcommands::Export(cmd) => cmd.run(),
+        }
+    }
+}
+
+/// CLI arguments for `spark deployments list`.
+#[derive(Debug, Clone, Parser)]
+pub struct DeploymentsListArgs {
+    /// Only list the deployments of this network.
+    #[clap(long, value_name = "NETWORK")]
+    network: Option<String>,
+
+    #[clap(flatten)]
+    opts: ProjectPathsArgs,
+}
+
+impl Cmd for DeploymentsListArgs {
+    type Output = ();
+
+    fn run(self) -> eyre::Result<Self::Output> {
+        let config = self.try_load_config_emit_warnings()?;
+        let registry = DeploymentRegistry::new(&config.deployments);
+        let networks = match self.network {
+            Some(network) => vec![network],
+            None => registry.networks()?,
+        };
+
+        let mut table = Table::new();
+        table.load_preset(ASCII_MARKDOWN);
+        table.set_header(["Network", "Name", "Address", "Version"]);
+        for network in networks {
+            for deployment in registry.list(&network)? {
+                table.add_row([
+                    network.clone(),
+                    deployment.name,
+                    format!("{:?}", deployment.address),
+                    deployment.version.to_string(),
+                ]);
+            }
+        }
+        println!("{table}");
+        Ok(())
+    }
+}
+
+/// CLI arguments for `spark deployments export`.
+#[derive(Debug, Clone, Parser)]
+pub struct DeploymentsExportArgs {
+    /// Only export the deployments of this network.
+    #[clap(long, value_name = "NETWORK")]
+    network: Option<String>,
+
+    /// The file to write the deployments to, instead of stdout.
+    #[clap(long, short, value_hint = ValueHint::FilePath, value_name = "FILE")]
+    out: Option<PathBuf>,
+
+    #[clap(flatten)]
+    opts: ProjectPathsArgs,
+}
+
+impl Cmd for DeploymentsExportArgs {
+    type Output = ();
+
+    fn run(self) -> eyre::Result<Self::Output> {
+        let config = self.try_load_config_emit_warnings()?;
+        let mut deployments = DeploymentRegistry::new(&config.deployments).export()?;
+        if let Some(network) = &self.network {
+            deployments.retain(|name, _| name == network);
+        }
+
+        let json = serde_json::to_string_pretty(&deployments)?;
+        match self.out {
+            Some(out) => fs::write(out, json)?,
+            None => println!("{json}"),
+        }
+        Ok(())
+    }
+}
@@ -40,8 +40,8 @@ pub struct DocArgs {
     #[clap(long, short, requires = "serve")]
     port: Option<usize>,
 
-    /// The relative path to the `hardhat-deploy` or `spark-deploy` artifact directory. Leave blank
-    /// for default.
+    /// The relative path to the deployment registry of `spark script`. Leave blank for the
+    /// `deployments` directory of the config.
     #[clap(long)]
     deployments: Option<Option<PathBuf>>,
 }
@@ -91,6 +91,7 @@ impl Cmd for DocArgs {
 
         // If deployment docgen is enabled, add the [Deployments] preprocessor
         if let Some(deployments) = self.deployments {
+            let deployments = deployments.or_else(|| Some(config.deployments.clone()));
             builder = builder.with_preprocessor(Deployments { root, deployments });
         }
 
@@ -46,6 +46,7 @@ pub mod config;
 pub mod coverage;
 pub mod create;
 pub mod debug;
+pub mod deployments;
 pub mod doc;
 pub mod flatten;
 pub mod fmt;
@@ -54,6 +55,7 @@ pub mod geiger;
 pub mod init;
 pub mod inspect;
 pub mod install;
+pub mod mutate;
 pub mod remappings;
 pub mod remove;
 pub mod script;
@@ -62,5 +64,6 @@ pub mod snapshot;
 pub mod test;
 pub mod tree;
 pub mod update;
+pub mod upgrade;
 pub mod verify;
 pub mod watch;
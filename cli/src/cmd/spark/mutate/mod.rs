@@ -0,0 +1,453 @@
+//! Mutation testing command
+//!
+//! Applies small changes, "mutants", to a copy of the project's sources and runs the tests that
+//! cover the mutated code against each of them. A mutant is killed if any of these tests fails,
+//! mutants that survive point to code whose behavior isn't checked by the tests.
+
+use crate::{
+    cmd::{
+        spark::{build::CoreBuildArgs, coverage::CoverageArgs, install, test::FilterArgs},
+        LoadConfig,
+    },
+    utils::{p_println, STATIC_FUZZ_SEED},
+};
+use clap::{Parser, ValueHint};
+use comfy_table::{presets::ASCII_MARKDOWN, Table};
+use corebc::prelude::U256;
+use eyre::WrapErr;
+use foxar_common::{compile, evm::EvmArgs, fs};
+use foxar_config::{Config, LimitsConfig};
+use foxar_evm::utils::evm_spec;
+use serde::Serialize;
+use spark::{
+    coverage::{ContractId, CoverageItemKind},
+    executor::{inspector::CheatsConfig, opts::EvmOpts},
+    result::SuiteResult,
+    MultiContractRunnerBuilder, TestOptions,
+};
+use std::{
+    collections::{BTreeMap, BTreeSet, HashMap},
+    fmt,
+    path::{Path, PathBuf},
+};
+use tracing::trace;
+use visitor::{find_mutations, Mutation, MutationKind};
+use walkdir::WalkDir;
+use yansi::Paint;
+
+mod visitor;
+
+// Loads project's figment and merges the build cli arguments into it
+foxar_config::impl_figment_convert!(MutateArgs, opts, evm_opts);
+
+/// CLI arguments for `spark mutate`.
+#[derive(Debug, Clone, Parser)]
+pub struct MutateArgs {
+    /// The source files or directories to mutate.
+    ///
+    /// Defaults to the project's `src` directory.
+    #[clap(value_hint = ValueHint::FilePath, value_name = "PATH", num_args(1..))]
+    paths: Vec<PathBuf>,
+
+    /// The kinds of mutations to apply.
+    ///
+    /// This flag can be used multiple times, all kinds are applied by default.
+    #[clap(long, value_enum, value_name = "KIND")]
+    kind: Vec<MutationKind>,
+
+    /// Print the mutants as JSON.
+    #[clap(long, short)]
+    json: bool,
+
+    #[clap(flatten)]
+    filter: FilterArgs,
+
+    #[clap(flatten)]
+    evm_opts: EvmArgs,
+
+    #[clap(flatten)]
+    opts: CoreBuildArgs,
+}
+
+/// The tests of each contract, `contract identifier -> test signatures`
+type Tests = BTreeMap<String, BTreeSet<String>>;
+
+/// The source ranges executed by the tests, `source path -> [(start, end, is function, contract
+/// identifier, test signature)]`
+type CoveredRanges = HashMap<String, Vec<(usize, usize, bool, String, String)>>;
+
+impl MutateArgs {
+    pub async fn run(self) -> eyre::Result<()> {
+        let (mut config, evm_opts) = self.load_config_and_evm_opts_emit_warnings()?;
+
+        // install missing dependencies
+        if install::install_missing_dependencies(&mut config, self.opts.silent) &&
+            config.auto_detect_remappings
+        {
+            // need to re-configure here to also catch additional remappings
+            config = self.load_config();
+        }
+
+        // Set fuzz seed so the results of the mutants are comparable
+        config.fuzz.seed = Some(U256::from_big_endian(&STATIC_FUZZ_SEED));
+
+        let silent = self.opts.silent || self.json;
+        let mut mutants = self.mutants(&config)?;
+        if mutants.is_empty() {
+            p_println!(!silent => "No mutants found.");
+            return Ok(())
+        }
+
+        p_println!(!silent => "Collecting coverage of {} mutants...", mutants.len());
+        let covered = self.coverage(&config, &evm_opts).await?;
+
+        // the mutants are applied to a copy of the project, so the project's sources are never
+        // modified, even if the run is interrupted
+        let workspace = tempfile::tempdir()?;
+        copy_project(&config, workspace.path())?;
+        let mut args = self.clone();
+        args.opts.project_paths.root = Some(workspace.path().to_path_buf());
+        let (mut workspace_config, workspace_evm_opts) = args.load_config_and_evm_opts()?;
+        workspace_config.fuzz.seed = config.fuzz.seed;
+
+        let count = mutants.len();
+        for (i, mutant) in mutants.iter_mut().enumerate() {
+            let tests = mutant.covering_tests(&covered);
+            if tests.is_empty() {
+                mutant.status = MutantStatus::NoCoverage;
+                continue
+            }
+            let (file, line) = (mutant.file.display(), mutant.line);
+            p_println!(!silent => "[{}/{count}] {file}:{line} {}", i + 1, mutant.mutation);
+            mutant.status =
+                self.test_mutant(&workspace_config, &workspace_evm_opts, mutant, tests).await?;
+            trace!(target: "spark::mutate", ?mutant, "tested mutant");
+        }
+
+        if self.json {
+            println!("{}", serde_json::to_string_pretty(&mutants)?);
+        } else {
+            println!("{}", MutationReport(&mutants));
+        }
+        Ok(())
+    }
+
+    /// Returns all mutants of the selected source files.
+    fn mutants(&self, config: &Config) -> eyre::Result<Vec<Mutant>> {
+        let root = &config.__root.0;
+        let paths =
+            if self.paths.is_empty() { vec![config.src.clone()] } else { self.paths.clone() };
+        let mut files = paths
+            .iter()
+            .flat_map(|path| {
+                if path.is_dir() {
+                    fs::files_with_ext(path, "sol")
+                } else {
+                    vec![path.clone()]
+                }
+            })
+            .map(|path| if path.is_absolute() { path } else { root.join(path) })
+            .collect::<Vec<_>>();
+        files.sort();
+        files.dedup();
+
+        let mut mutants = Vec::new();
+        for path in files {
+            let src = fs::read_to_string(&path)?;
+            let mutations = find_mutations(&src).map_err(|diagnostics| {
+                eyre::eyre!("Failed to parse {}: {diagnostics:?}", path.display())
+            })?;
+            // the mutants are applied to a copy of the project
+            let Ok(file) = path.strip_prefix(root).map(Path::to_path_buf) else {
+                eyre::bail!("{} is not part of the project", path.display())
+            };
+            mutants.extend(
+                mutations
+                    .into_iter()
+                    .filter(|mutation| self.kind.is_empty() || self.kind.contains(&mutation.kind))
+                    .map(|mutation| Mutant {
+                        file: file.clone(),
+                        line: src[..mutation.start].matches('\n').count() + 1,
+                        mutation,
+                        status: MutantStatus::NoCoverage,
+                        tests: 0,
+                    }),
+            );
+        }
+        Ok(mutants)
+    }
+
+    /// Runs the tests with coverage and returns the source ranges each test executed.
+    async fn coverage(&self, config: &Config, evm_opts: &EvmOpts) -> eyre::Result<CoveredRanges> {
+        let (project, output) = CoverageArgs::build(config)?;
+        let report = CoverageArgs::prepare(config, output.clone())?;
+
+        let env = evm_opts.evm_env().await;
+        let mut runner = MultiContractRunnerBuilder::default()
+            .initial_balance(evm_opts.initial_balance)
+            .evm_spec(evm_spec(&config.cvm_version))
+            .sender(evm_opts.sender)
+            .with_fork(evm_opts.get_fork(config, env.clone()))
+            .with_cheats_config(CheatsConfig::new(config, evm_opts))
+            .with_test_options(test_options(config))
+            .set_coverage(true)
+            .build(project.paths.root, output, env, evm_opts.clone())?;
+
+        let known_contracts = runner.known_contracts.clone();
+        let filter = self.filter.merge_with_config(config);
+        let results = runner.test(&filter, None, test_options(config)).await;
+
+        let failures = results
+            .values()
+            .flat_map(SuiteResult::tests)
+            .filter(|(_, result)| result.is_failure())
+            .map(|(name, _)| name.as_str())
+            .collect::<Vec<_>>();
+        if !failures.is_empty() {
+            eyre::bail!(
+                "Mutation testing requires all tests to pass, but these failed: {}",
+                failures.join(", ")
+            )
+        }
+
+        let mut covered = CoveredRanges::new();
+        for (id, suite) in results {
+            for (name, result) in suite.test_results {
+                let Some(hit_maps) = result.coverage else { continue };
+                let sig = name.split_once('[').map(|(sig, _)| sig).unwrap_or(&name);
+                for hit_map in hit_maps.0.values() {
+                    let Some((artifact_id, _)) =
+                        known_contracts.find_by_code(hit_map.bytecode.as_ref())
+                    else {
+                        continue
+                    };
+                    let source = artifact_id.source.to_string_lossy().to_string();
+                    let Some(source_id) = report.get_source_id(artifact_id.version.clone(), source)
+                    else {
+                        continue
+                    };
+                    let contract_id = ContractId {
+                        version: artifact_id.version.clone(),
+                        source_id: *source_id,
+                        contract_name: artifact_id.name.clone(),
+                    };
+                    for (path, item) in report.items_hit(&contract_id, hit_map) {
+                        let is_function = matches!(item.kind, CoverageItemKind::Function { .. });
+                        let start = item.loc.start;
+                        let end = start + item.loc.length.unwrap_or_default();
+                        covered.entry(path.clone()).or_default().push((
+                            start,
+                            end,
+                            is_function,
+                            id.clone(),
+                            sig.to_string(),
+                        ));
+                    }
+                }
+            }
+        }
+        Ok(covered)
+    }
+
+    /// Applies the mutant to the copy of the project `config` points to, rebuilds it and runs
+    /// the given tests against it.
+    async fn test_mutant(
+        &self,
+        config: &Config,
+        evm_opts: &EvmOpts,
+        mutant: &mut Mutant,
+        tests: Tests,
+    ) -> eyre::Result<MutantStatus> {
+        mutant.tests = tests.values().map(BTreeSet::len).sum();
+        let root = &config.__root.0;
+
+        // the copy's original source is restored for the next mutant when the guard is dropped
+        let _guard = MutatedFile::apply(root.join(&mutant.file), &mutant.mutation)?;
+
+        let project = config.ephemeral_no_artifacts_project()?;
+        let Ok(output) = compile::suppress_compile(&project) else {
+            return Ok(MutantStatus::Invalid)
+        };
+
+        let env = evm_opts.evm_env().await;
+        let mut runner = MultiContractRunnerBuilder::default()
+            .initial_balance(evm_opts.initial_balance)
+            .evm_spec(evm_spec(&config.cvm_version))
+            .sender(evm_opts.sender)
+            .with_fork(evm_opts.get_fork(config, env.clone()))
+            .with_cheats_config(CheatsConfig::new(config, evm_opts))
+            .with_test_options(test_options(config))
+            .build(root, output, env, evm_opts.clone())?;
+
+        let mut filter = self.filter.merge_with_config(config);
+        filter.select_tests(tests);
+        let results = runner.test(&filter, None, test_options(config)).await;
+
+        let killed =
+            results.values().flat_map(SuiteResult::tests).any(|(_, result)| result.is_failure());
+        Ok(if killed { MutantStatus::Killed } else { MutantStatus::Survived })
+    }
+}
+
+/// Returns the test options used for all test runs.
+fn test_options(config: &Config) -> TestOptions {
+    TestOptions {
+        fuzz: config.fuzz,
+        invariant: config.invariant,
+        limits: LimitsConfig::new(config.timeout, config.energy_budget, config.step_budget),
+        fixtures: Some(config.__root.0.join(&config.test).join("fixtures")),
+        // failures of quarantined tests don't kill mutants
+        quarantine: config.quarantine.iter().cloned().map(Into::into).collect(),
+        ..Default::default()
+    }
+}
+
+/// Copies the project to `dir`, without its build artifacts, caches, broadcasts and git history.
+fn copy_project(config: &Config, dir: &Path) -> eyre::Result<()> {
+    let root = &config.__root.0;
+    let git = root.join(".git");
+    let skipped = [&config.out, &config.cache_path, &config.broadcast, &git];
+    let entries = WalkDir::new(root)
+        .follow_links(true)
+        .into_iter()
+        .filter_entry(|entry| !skipped.iter().any(|path| entry.path() == path.as_path()));
+    for entry in entries {
+        let entry = entry?;
+        let target = dir.join(entry.path().strip_prefix(root)?);
+        if entry.file_type().is_dir() {
+            fs::create_dir_all(&target)?;
+        } else {
+            std::fs::copy(entry.path(), &target).wrap_err_with(|| {
+                format!("Failed to copy {} to {}", entry.path().display(), target.display())
+            })?;
+        }
+    }
+    Ok(())
+}
+
+/// A mutated source file, the original source is restored on drop
+struct MutatedFile {
+    path: PathBuf,
+    original: String,
+}
+
+impl MutatedFile {
+    fn apply(path: PathBuf, mutation: &Mutation) -> eyre::Result<Self> {
+        let original = fs::read_to_string(&path)?;
+        fs::write(&path, mutation.apply(&original))
+            .wrap_err_with(|| format!("Failed to write mutant to {}", path.display()))?;
+        Ok(Self { path, original })
+    }
+}
+
+impl Drop for MutatedFile {
+    fn drop(&mut self) {
+        if let Err(err) = fs::write(&self.path, &self.original) {
+            eprintln!("Failed to restore {}: {err}", self.path.display());
+        }
+    }
+}
+
+/// The result of running the tests against a mutant
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum MutantStatus {
+    /// At least one test failed
+    Killed,
+    /// All tests passed
+    Survived,
+    /// No test executes the mutated code
+    NoCoverage,
+    /// The mutant doesn't compile
+    Invalid,
+}
+
+impl fmt::Display for MutantStatus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            MutantStatus::Killed => f.write_str("killed"),
+            MutantStatus::Survived => f.write_str("survived"),
+            MutantStatus::NoCoverage => f.write_str("no coverage"),
+            MutantStatus::Invalid => f.write_str("invalid"),
+        }
+    }
+}
+
+/// A mutation of a source file and its test result
+#[derive(Debug, Clone, Serialize)]
+pub struct Mutant {
+    /// The mutated file, relative to the project root
+    pub file: PathBuf,
+    /// The line of the mutation
+    pub line: usize,
+    #[serde(flatten)]
+    pub mutation: Mutation,
+    pub status: MutantStatus,
+    /// The number of tests run against the mutant
+    pub tests: usize,
+}
+
+impl Mutant {
+    /// Returns the tests that execute the mutated code.
+    ///
+    /// Functions span their whole body, which would select tests that don't execute a mutated
+    /// statement, so they only select the tests of the mutated visibility of their signature.
+    fn covering_tests(&self, covered: &CoveredRanges) -> Tests {
+        let mut tests = Tests::new();
+        let file = self.file.to_string_lossy();
+        let is_visibility = self.mutation.kind == MutationKind::Visibility;
+        for (start, end, is_function, id, sig) in covered.get(file.as_ref()).into_iter().flatten() {
+            if *is_function == is_visibility && (*start..*end).contains(&self.mutation.start) {
+                tests.entry(id.clone()).or_default().insert(sig.clone());
+            }
+        }
+        tests
+    }
+}
+
+/// Formats the surviving mutants as a table, followed by a summary
+struct MutationReport<'a>(&'a [Mutant]);
+
+impl<'a> fmt::Display for MutationReport<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let count = |status| self.0.iter().filter(|mutant| mutant.status == status).count();
+        let killed = count(MutantStatus::Killed);
+        let survived = count(MutantStatus::Survived);
+        let no_coverage = count(MutantStatus::NoCoverage);
+        let invalid = count(MutantStatus::Invalid);
+
+        let surviving = self
+            .0
+            .iter()
+            .filter(|mutant| {
+                matches!(mutant.status, MutantStatus::Survived | MutantStatus::NoCoverage)
+            })
+            .collect::<Vec<_>>();
+        if !surviving.is_empty() {
+            let mut table = Table::new();
+            table.load_preset(ASCII_MARKDOWN);
+            table.set_header(["File", "Line", "Kind", "Mutation", "Status"]);
+            for mutant in surviving {
+                table.add_row([
+                    mutant.file.display().to_string(),
+                    mutant.line.to_string(),
+                    mutant.mutation.kind.to_string(),
+                    mutant.mutation.to_string(),
+                    mutant.status.to_string(),
+                ]);
+            }
+            writeln!(f, "{table}")?;
+            writeln!(f)?;
+        }
+
+        let tested = killed + survived + no_coverage;
+        let score = if tested == 0 { 100.0 } else { killed as f64 * 100.0 / tested as f64 };
+        let score = format!("{score:.2}%");
+        let score =
+            if survived + no_coverage == 0 { Paint::green(score) } else { Paint::red(score) };
+        write!(
+            f,
+            "Mutation score: {score}. {killed} killed; {survived} survived; {no_coverage} not covered; {invalid} invalid"
+        )
+    }
+}
@@ -0,0 +1,486 @@
+use clap::ValueEnum;
+use serde::Serialize;
+use solang_parser::{
+    lexer::{Lexer, Token},
+    pt::{
+        ContractDefinition, Expression, FunctionAttribute, FunctionDefinition, FunctionTy,
+        IdentifierPath, Loc, SourceUnit, Statement, VariableDeclaration, VariableDefinition,
+        Visibility,
+    },
+};
+use spark_fmt::{Visitable, Visitor};
+use std::{convert::Infallible, fmt};
+
+/// The kinds of mutations applied to the source code
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, ValueEnum)]
+#[serde(rename_all = "kebab-case")]
+pub enum MutationKind {
+    /// Replaces arithmetic, comparison, logical and bitwise operators, e.g. `+` with `-`
+    Operator,
+    /// Replaces number and boolean literals, e.g. `1` with `0`
+    Literal,
+    /// Removes the condition of `require` calls
+    Require,
+    /// Makes public and external functions internal
+    Visibility,
+}
+
+impl fmt::Display for MutationKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            MutationKind::Operator => f.write_str("operator"),
+            MutationKind::Literal => f.write_str("literal"),
+            MutationKind::Require => f.write_str("require"),
+            MutationKind::Visibility => f.write_str("visibility"),
+        }
+    }
+}
+
+/// A single change to a source file, `original` at `start..end` is replaced with `replacement`
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct Mutation {
+    pub kind: MutationKind,
+    pub start: usize,
+    pub end: usize,
+    pub original: String,
+    pub replacement: String,
+}
+
+impl Mutation {
+    /// Returns the source code with this mutation applied.
+    pub fn apply(&self, src: &str) -> String {
+        format!("{}{}{}", &src[..self.start], self.replacement, &src[self.end..])
+    }
+}
+
+impl fmt::Display for Mutation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "`{}` -> `{}`", self.original, self.replacement)
+    }
+}
+
+/// The replacements of binary operators
+const BINARY_OPERATORS: &[(&str, &[&str])] = &[
+    ("+", &["-"]),
+    ("-", &["+"]),
+    ("*", &["/"]),
+    ("/", &["*"]),
+    ("%", &["*"]),
+    ("**", &["*"]),
+    ("<", &["<=", ">"]),
+    ("<=", &["<", ">="]),
+    (">", &[">=", "<"]),
+    (">=", &[">", "<="]),
+    ("==", &["!="]),
+    ("!=", &["=="]),
+    ("&&", &["||"]),
+    ("||", &["&&"]),
+    ("&", &["|"]),
+    ("|", &["&"]),
+    ("^", &["&"]),
+    ("<<", &[">>"]),
+    (">>", &["<<"]),
+    ("+=", &["-="]),
+    ("-=", &["+="]),
+    ("*=", &["/="]),
+    ("/=", &["*="]),
+];
+
+/// a [`spark_fmt::Visitor`] that collects the possible mutations of a source file
+pub struct MutationVisitor<'a> {
+    src: &'a str,
+    pub mutations: Vec<Mutation>,
+}
+
+impl<'a> MutationVisitor<'a> {
+    pub fn new(src: &'a str) -> Self {
+        Self { src, mutations: Vec::new() }
+    }
+
+    fn push(&mut self, kind: MutationKind, start: usize, end: usize, replacement: &str) {
+        self.mutations.push(Mutation {
+            kind,
+            start,
+            end,
+            original: self.src[start..end].to_string(),
+            replacement: replacement.to_string(),
+        });
+    }
+
+    /// Adds the replacements of the operator `op` between `lhs` and `rhs`.
+    fn binary(&mut self, lhs: &Expression, rhs: &Expression, op: &str) {
+        let (Some((_, start)), Some((end, _))) = (range(&lhs.loc()), range(&rhs.loc())) else {
+            return
+        };
+        let Some((op_start, op_end)) = self.operator(start, end, op) else { return };
+        let replacements =
+            BINARY_OPERATORS.iter().find(|(o, _)| *o == op).map_or(&[][..], |(_, r)| *r);
+        for replacement in replacements {
+            self.push(MutationKind::Operator, op_start, op_end, replacement);
+        }
+    }
+
+    /// Returns the location of the operator token `op` between two operands at `start..end`.
+    ///
+    /// The parse tree has no location for operators, so the tokens between the operands are
+    /// lexed, which skips comments and the parentheses around the operands.
+    fn operator(&self, start: usize, end: usize, op: &str) -> Option<(usize, usize)> {
+        let between = self.src.get(start..end)?;
+        let mut comments = Vec::new();
+        let mut errors = Vec::new();
+        let token = Lexer::new(between, 0, &mut comments, &mut errors).flatten().find(
+            |(_, token, _)| !matches!(token, Token::OpenParenthesis | Token::CloseParenthesis),
+        )?;
+        let (token_start, token, token_end) = token;
+        (token.to_string() == op).then_some((start + token_start, start + token_end))
+    }
+}
+
+/// Returns the byte range of a location in the source file.
+fn range(loc: &Loc) -> Option<(usize, usize)> {
+    match loc {
+        Loc::File(_, start, end) => Some((*start, *end)),
+        _ => None,
+    }
+}
+
+impl<'a> Visitor for MutationVisitor<'a> {
+    type Error = Infallible;
+
+    fn visit_source_unit(&mut self, source_unit: &mut SourceUnit) -> Result<(), Self::Error> {
+        source_unit.0.visit(self)
+    }
+
+    fn visit_contract(&mut self, contract: &mut ContractDefinition) -> Result<(), Self::Error> {
+        contract.parts.visit(self)
+    }
+
+    fn visit_block(
+        &mut self,
+        _loc: Loc,
+        _unchecked: bool,
+        statements: &mut Vec<Statement>,
+    ) -> Result<(), Self::Error> {
+        statements.visit(self)
+    }
+
+    fn visit_expr(&mut self, loc: Loc, expr: &mut Expression) -> Result<(), Self::Error> {
+        let op = match expr {
+            Expression::Power(..) => Some("**"),
+            Expression::Multiply(..) => Some("*"),
+            Expression::Divide(..) => Some("/"),
+            Expression::Modulo(..) => Some("%"),
+            Expression::Add(..) => Some("+"),
+            Expression::Subtract(..) => Some("-"),
+            Expression::ShiftLeft(..) => Some("<<"),
+            Expression::ShiftRight(..) => Some(">>"),
+            Expression::BitwiseAnd(..) => Some("&"),
+            Expression::BitwiseXor(..) => Some("^"),
+            Expression::BitwiseOr(..) => Some("|"),
+            Expression::Less(..) => Some("<"),
+            Expression::More(..) => Some(">"),
+            Expression::LessEqual(..) => Some("<="),
+            Expression::MoreEqual(..) => Some(">="),
+            Expression::Equal(..) => Some("=="),
+            Expression::NotEqual(..) => Some("!="),
+            Expression::And(..) => Some("&&"),
+            Expression::Or(..) => Some("||"),
+            Expression::AssignAdd(..) => Some("+="),
+            Expression::AssignSubtract(..) => Some("-="),
+            Expression::AssignMultiply(..) => Some("*="),
+            Expression::AssignDivide(..) => Some("/="),
+            _ => None,
+        };
+
+        match expr {
+            Expression::Power(_, lhs, rhs) |
+            Expression::Multiply(_, lhs, rhs) |
+            Expression::Divide(_, lhs, rhs) |
+            Expression::Modulo(_, lhs, rhs) |
+            Expression::Add(_, lhs, rhs) |
+            Expression::Subtract(_, lhs, rhs) |
+            Expression::ShiftLeft(_, lhs, rhs) |
+            Expression::ShiftRight(_, lhs, rhs) |
+            Expression::BitwiseAnd(_, lhs, rhs) |
+            Expression::BitwiseXor(_, lhs, rhs) |
+            Expression::BitwiseOr(_, lhs, rhs) |
+            Expression::Less(_, lhs, rhs) |
+            Expression::More(_, lhs, rhs) |
+            Expression::LessEqual(_, lhs, rhs) |
+            Expression::MoreEqual(_, lhs, rhs) |
+            Expression::Equal(_, lhs, rhs) |
+            Expression::NotEqual(_, lhs, rhs) |
+            Expression::And(_, lhs, rhs) |
+            Expression::Or(_, lhs, rhs) |
+            Expression::AssignAdd(_, lhs, rhs) |
+            Expression::AssignSubtract(_, lhs, rhs) |
+            Expression::AssignMultiply(_, lhs, rhs) |
+            Expression::AssignDivide(_, lhs, rhs) => {
+                if let Some(op) = op {
+                    self.binary(lhs, rhs, op);
+                }
+                lhs.visit(self)?;
+                rhs.visit(self)?;
+            }
+            Expression::Not(_, inner) => {
+                // `!x` -> `x`
+                if let (Some((start, _)), Some((end, _))) = (range(&loc), range(&inner.loc())) {
+                    self.push(MutationKind::Operator, start, end, "");
+                }
+                inner.visit(self)?;
+            }
+            Expression::BoolLiteral(_, value) => {
+                if let Some((start, end)) = range(&loc) {
+                    self.push(MutationKind::Literal, start, end, &(!*value).to_string());
+                }
+            }
+            Expression::NumberLiteral(_, value, exp, None) if exp.is_empty() => {
+                if let (Some((start, end)), Ok(value)) =
+                    (range(&loc), value.replace('_', "").parse::<u128>())
+                {
+                    if value == 0 {
+                        self.push(MutationKind::Literal, start, end, "1");
+                    } else {
+                        self.push(MutationKind::Literal, start, end, "0");
+                        if let Some(value) = value.checked_add(1) {
+                            self.push(MutationKind::Literal, start, end, &value.to_string());
+                        }
+                    }
+                }
+            }
+            Expression::FunctionCall(_, func, args) => {
+                // `require(cond, ...)` -> `require(true, ...)`
+                if let (Expression::Variable(ident), Some(cond)) = (&**func, args.first()) {
+                    let always_true = matches!(cond, Expression::BoolLiteral(_, true));
+                    if ident.name == "require" && !always_true {
+                        if let Some((start, end)) = range(&cond.loc()) {
+                            self.push(MutationKind::Require, start, end, "true");
+                        }
+                    }
+                }
+                func.visit(self)?;
+                args.visit(self)?;
+            }
+            Expression::PostIncrement(_, inner) |
+            Expression::PostDecrement(_, inner) |
+            Expression::PreIncrement(_, inner) |
+            Expression::PreDecrement(_, inner) |
+            Expression::Parenthesis(_, inner) |
+            Expression::MemberAccess(_, inner, _) |
+            Expression::BitwiseNot(_, inner) |
+            Expression::Negate(_, inner) |
+            Expression::UnaryPlus(_, inner) |
+            Expression::Delete(_, inner) => inner.visit(self)?,
+            Expression::ArraySubscript(_, array, index) => {
+                array.visit(self)?;
+                index.visit(self)?;
+            }
+            Expression::ConditionalOperator(_, cond, lhs, rhs) => {
+                cond.visit(self)?;
+                lhs.visit(self)?;
+                rhs.visit(self)?;
+            }
+            Expression::Assign(_, lhs, rhs) |
+            Expression::AssignOr(_, lhs, rhs) |
+            Expression::AssignAnd(_, lhs, rhs) |
+            Expression::AssignXor(_, lhs, rhs) |
+            Expression::AssignShiftLeft(_, lhs, rhs) |
+            Expression::AssignShiftRight(_, lhs, rhs) |
+            Expression::AssignModulo(_, lhs, rhs) => {
+                lhs.visit(self)?;
+                rhs.visit(self)?;
+            }
+            Expression::NamedFunctionCall(_, func, args) => {
+                func.visit(self)?;
+                for arg in args.iter_mut() {
+                    arg.expr.visit(self)?;
+                }
+            }
+            Expression::List(_, params) => {
+                for (_, param) in params.iter_mut() {
+                    if let Some(param) = param {
+                        param.ty.visit(self)?;
+                    }
+                }
+            }
+            Expression::ArrayLiteral(_, exprs) => exprs.visit(self)?,
+            _ => {}
+        }
+
+        Ok(())
+    }
+
+    fn visit_emit(&mut self, _: Loc, expr: &mut Expression) -> Result<(), Self::Error> {
+        expr.visit(self)
+    }
+
+    fn visit_var_definition(&mut self, var: &mut VariableDefinition) -> Result<(), Self::Error> {
+        var.initializer.visit(self)
+    }
+
+    fn visit_var_definition_stmt(
+        &mut self,
+        _: Loc,
+        _declaration: &mut VariableDeclaration,
+        expr: &mut Option<Expression>,
+    ) -> Result<(), Self::Error> {
+        expr.visit(self)
+    }
+
+    fn visit_return(&mut self, _: Loc, expr: &mut Option<Expression>) -> Result<(), Self::Error> {
+        expr.visit(self)
+    }
+
+    fn visit_revert(
+        &mut self,
+        _: Loc,
+        _error: &mut Option<IdentifierPath>,
+        args: &mut Vec<Expression>,
+    ) -> Result<(), Self::Error> {
+        args.visit(self)
+    }
+
+    fn visit_if(
+        &mut self,
+        _loc: Loc,
+        cond: &mut Expression,
+        if_branch: &mut Box<Statement>,
+        else_branch: &mut Option<Box<Statement>>,
+        _is_first_stmt: bool,
+    ) -> Result<(), Self::Error> {
+        cond.visit(self)?;
+        if_branch.visit(self)?;
+        else_branch.visit(self)
+    }
+
+    fn visit_do_while(
+        &mut self,
+        _loc: Loc,
+        body: &mut Statement,
+        cond: &mut Expression,
+    ) -> Result<(), Self::Error> {
+        body.visit(self)?;
+        cond.visit(self)
+    }
+
+    fn visit_while(
+        &mut self,
+        _loc: Loc,
+        cond: &mut Expression,
+        body: &mut Statement,
+    ) -> Result<(), Self::Error> {
+        cond.visit(self)?;
+        body.visit(self)
+    }
+
+    fn visit_for(
+        &mut self,
+        _loc: Loc,
+        init: &mut Option<Box<Statement>>,
+        cond: &mut Option<Box<Expression>>,
+        update: &mut Option<Box<Expression>>,
+        body: &mut Option<Box<Statement>>,
+    ) -> Result<(), Self::Error> {
+        init.visit(self)?;
+        cond.visit(self)?;
+        update.visit(self)?;
+        body.visit(self)
+    }
+
+    fn visit_function(&mut self, func: &mut FunctionDefinition) -> Result<(), Self::Error> {
+        let Some(body) = func.body.as_mut() else { return Ok(()) };
+
+        // Callers outside of the contract don't compile anymore, the mutant is then invalid
+        if matches!(func.ty, FunctionTy::Function) {
+            for attr in &func.attributes {
+                if let FunctionAttribute::Visibility(
+                    Visibility::Public(Some(loc)) | Visibility::External(Some(loc)),
+                ) = attr
+                {
+                    if let Some((start, end)) = range(loc) {
+                        self.push(MutationKind::Visibility, start, end, "internal");
+                    }
+                }
+            }
+        }
+
+        body.visit(self)
+    }
+}
+
+/// Returns all possible mutations of the source code, ordered by their location.
+pub fn find_mutations(
+    src: &str,
+) -> Result<Vec<Mutation>, Vec<solang_parser::diagnostics::Diagnostic>> {
+    let mut parsed = spark_fmt::parse(src)?;
+    let mut visitor = MutationVisitor::new(src);
+    parsed.pt.visit(&mut visitor).unwrap();
+    let mut mutations = visitor.mutations;
+    mutations.sort_by_key(|mutation| mutation.start);
+    Ok(mutations)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn mutated(src: &str, kind: MutationKind) -> Vec<String> {
+        find_mutations(src)
+            .unwrap()
+            .into_iter()
+            .filter(|mutation| mutation.kind == kind)
+            .map(|mutation| mutation.apply(src))
+            .collect()
+    }
+
+    #[test]
+    fn can_mutate_operators() {
+        let src = "contract A { function f(uint a) public returns (bool) { return a + 1 < 2; } }";
+        let mutants = mutated(src, MutationKind::Operator);
+        assert_eq!(
+            mutants,
+            [
+                "contract A { function f(uint a) public returns (bool) { return a - 1 < 2; } }",
+                "contract A { function f(uint a) public returns (bool) { return a + 1 <= 2; } }",
+                "contract A { function f(uint a) public returns (bool) { return a + 1 > 2; } }",
+            ]
+        );
+    }
+
+    #[test]
+    fn can_mutate_literals() {
+        let src = "contract A { function f() public returns (uint, bool) { return (0, true); } }";
+        let mutants = mutated(src, MutationKind::Literal);
+        assert_eq!(
+            mutants,
+            [
+                "contract A { function f() public returns (uint, bool) { return (1, true); } }",
+                "contract A { function f() public returns (uint, bool) { return (0, false); } }",
+            ]
+        );
+    }
+
+    #[test]
+    fn can_remove_require() {
+        let src = r#"contract A { function f(uint a) public { require(a > 0, "zero"); } }"#;
+        let mutants = mutated(src, MutationKind::Require);
+        assert_eq!(
+            mutants,
+            [r#"contract A { function f(uint a) public { require(true, "zero"); } }"#]
+        );
+    }
+
+    #[test]
+    fn can_locate_operators() {
+        let src = "contract A { function f(uint a) public { a = (a) /* - */ - 1; } }";
+        let mutants = mutated(src, MutationKind::Operator);
+        assert_eq!(mutants, ["contract A { function f(uint a) public { a = (a) /* - */ + 1; } }"]);
+    }
+
+    #[test]
+    fn can_narrow_visibility() {
+        let src = "contract A { function f() public {} function g() private {} }";
+        let mutants = mutated(src, MutationKind::Visibility);
+        assert_eq!(mutants, ["contract A { function f() internal {} function g() private {} }"]);
+    }
+}
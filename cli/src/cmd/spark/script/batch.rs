@@ -0,0 +1,206 @@
+//! Batches consecutive calls into multicall transactions, see `spark script --batch`
+
+use super::transaction::TransactionWithMetadata;
+use corebc::{
+    abi::{HumanReadableParser, Token},
+    types::{Address, NameOrAddress, U256},
+};
+use probe::CallKind;
+use std::collections::{HashMap, VecDeque};
+
+/// The multicall function the batched calls are sent with. Every call must succeed.
+pub const AGGREGATE3: &str =
+    "function aggregate3((address,bool,bytes)[] calls) returns ((bool,bytes)[] returnData)";
+
+/// Groups consecutive calls of the same sender on the same rpc to the `targets` into `aggregate3`
+/// transactions of the `multicall` contract. The batched calls are kept in
+/// [TransactionWithMetadata::batch].
+///
+/// The batched calls are made by the multicall contract, so only calls to the opted-in `targets`
+/// are batched. Creates, calls with value, calls that create contracts and calls with an energy
+/// limit set in the script are sent on their own, as are transactions which are already batches.
+/// The nonces of the following transactions are lowered accordingly.
+pub fn batch_transactions(
+    transactions: VecDeque<TransactionWithMetadata>,
+    multicall: Address,
+    targets: &[Address],
+) -> eyre::Result<VecDeque<TransactionWithMetadata>> {
+    let mut groups: Vec<Vec<TransactionWithMetadata>> = vec![];
+    for tx in transactions {
+        match groups.last_mut() {
+            Some(group) if can_batch(&group[0], &tx, targets) => group.push(tx),
+            _ => groups.push(vec![tx]),
+        }
+    }
+
+    let mut batched = groups
+        .into_iter()
+        .map(|mut group| {
+            if group.len() == 1 {
+                Ok(group.remove(0))
+            } else {
+                multicall_transaction(group, multicall)
+            }
+        })
+        .collect::<eyre::Result<VecDeque<_>>>()?;
+
+    // Every sender's transactions keep consecutive nonces, starting at its first transaction
+    let mut nonces: HashMap<Address, U256> = HashMap::new();
+    for tx in batched.iter_mut() {
+        let typed_tx = tx.typed_tx_mut();
+        let (Some(from), Some(nonce)) = (typed_tx.from().copied(), typed_tx.nonce().copied())
+        else {
+            continue
+        };
+        let nonce = nonces.entry(from).or_insert(nonce);
+        typed_tx.set_nonce(*nonce);
+        *nonce += U256::one();
+    }
+
+    Ok(batched)
+}
+
+/// Returns true if the transaction is a plain call to one of the `targets`, without side effects
+/// on addresses or value
+fn is_batchable(tx: &TransactionWithMetadata, targets: &[Address]) -> bool {
+    let typed_tx = tx.typed_tx();
+    tx.opcode == CallKind::Call &&
+        tx.batch.is_empty() &&
+        tx.additional_contracts.is_empty() &&
+        !tx.is_fixed_energy_limit &&
+        typed_tx.value().map_or(true, |value| value.is_zero()) &&
+        matches!(typed_tx.to(), Some(NameOrAddress::Address(to)) if targets.contains(to))
+}
+
+/// Returns true if `tx` can be batched with the group starting with `first`
+fn can_batch(
+    first: &TransactionWithMetadata,
+    tx: &TransactionWithMetadata,
+    targets: &[Address],
+) -> bool {
+    is_batchable(first, targets) &&
+        is_batchable(tx, targets) &&
+        first.rpc == tx.rpc &&
+        first.typed_tx().from() == tx.typed_tx().from()
+}
+
+/// Creates the `aggregate3` transaction of the calls
+fn multicall_transaction(
+    calls: Vec<TransactionWithMetadata>,
+    multicall: Address,
+) -> eyre::Result<TransactionWithMetadata> {
+    let function = HumanReadableParser::parse_function(AGGREGATE3)?;
+    let tokens = calls
+        .iter()
+        .map(|call| {
+            let typed_tx = call.typed_tx();
+            let target = match typed_tx.to() {
+                Some(NameOrAddress::Address(to)) => *to,
+                _ => unreachable!("only calls are batched"),
+            };
+            Token::Tuple(vec![
+                Token::Address(target),
+                Token::Bool(false),
+                Token::Bytes(typed_tx.data().map(|data| data.to_vec()).unwrap_or_default()),
+            ])
+        })
+        .collect();
+    let data = function.encode_input(&[Token::Array(tokens)])?;
+
+    // Each call's estimate includes the intrinsic energy of a transaction, which more than covers
+    // the overhead of the multicall
+    let energy = calls.iter().try_fold(U256::zero(), |total, call| {
+        call.typed_tx().energy().map(|energy| total + energy)
+    });
+
+    let mut transaction = calls[0].typed_tx().clone();
+    transaction.set_to(multicall);
+    transaction.set_data(data.into());
+    if let Some(energy) = energy {
+        transaction.set_energy(energy);
+    }
+
+    Ok(TransactionWithMetadata {
+        opcode: CallKind::Call,
+        contract_name: Some("Multicall3".to_string()),
+        contract_address: Some(multicall),
+        function: Some(function.signature()),
+        arguments: Some(vec![]),
+        rpc: calls[0].rpc.clone(),
+        transaction,
+        batch: calls,
+        ..Default::default()
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use corebc::types::{transaction::eip2718::TypedTransaction, TransactionRequest};
+
+    fn tx(opcode: CallKind, from: u8, to: Option<u8>, nonce: u64) -> TransactionWithMetadata {
+        let mut request = TransactionRequest::new()
+            .from(Address::repeat_byte(from))
+            .nonce(nonce)
+            .energy(100_000)
+            .data(vec![nonce as u8]);
+        if let Some(to) = to {
+            request = request.to(Address::repeat_byte(to));
+        }
+        TransactionWithMetadata {
+            opcode,
+            rpc: Some("http://localhost:8545".to_string()),
+            transaction: TypedTransaction::Legacy(request),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn can_batch_consecutive_calls() {
+        let multicall = Address::repeat_byte(0xff);
+        let transactions = VecDeque::from([
+            tx(CallKind::Create, 1, None, 0),
+            tx(CallKind::Call, 1, Some(2), 1),
+            tx(CallKind::Call, 1, Some(3), 2),
+            tx(CallKind::Call, 1, Some(2), 3),
+            tx(CallKind::Call, 4, Some(2), 0),
+            tx(CallKind::Create, 1, None, 4),
+            tx(CallKind::Call, 1, Some(2), 5),
+            tx(CallKind::Call, 1, Some(5), 6),
+            tx(CallKind::Call, 1, Some(2), 7),
+        ]);
+
+        let targets = [Address::repeat_byte(2), Address::repeat_byte(3)];
+        let batched = batch_transactions(transactions, multicall, &targets).unwrap();
+        assert_eq!(batched.len(), 7);
+
+        let nonces =
+            batched.iter().map(|tx| tx.typed_tx().nonce().unwrap().as_u64()).collect::<Vec<_>>();
+        assert_eq!(nonces, [0, 1, 0, 2, 3, 4, 5]);
+
+        let multicall_tx = &batched[1];
+        assert_eq!(multicall_tx.batch.len(), 3);
+        assert_eq!(multicall_tx.typed_tx().to(), Some(&NameOrAddress::Address(multicall)));
+        assert_eq!(multicall_tx.typed_tx().energy(), Some(&U256::from(300_000)));
+        assert!(batched.iter().enumerate().all(|(i, tx)| i == 1 || tx.batch.is_empty()));
+    }
+
+    #[test]
+    fn only_batches_calls_to_targets() {
+        let multicall = Address::repeat_byte(0xff);
+        let transactions = VecDeque::from([
+            tx(CallKind::Call, 1, Some(2), 0),
+            tx(CallKind::Call, 1, Some(2), 1),
+            tx(CallKind::Call, 1, Some(3), 2),
+        ]);
+
+        let batched = batch_transactions(transactions.clone(), multicall, &[]).unwrap();
+        assert_eq!(batched.len(), 3);
+        assert!(batched.iter().all(|tx| tx.batch.is_empty()));
+
+        let batched =
+            batch_transactions(transactions, multicall, &[Address::repeat_byte(3)]).unwrap();
+        assert_eq!(batched.len(), 3);
+        assert!(batched.iter().all(|tx| tx.batch.is_empty()));
+    }
+}
@@ -1,7 +1,13 @@
 use super::{multi::MultiChainSequence, providers::ProvidersManager, sequence::ScriptSequence, *};
 use crate::{
     cmd::spark::script::{
-        receipts::clear_pendings, transaction::TransactionWithMetadata, verify::VerifyBundle,
+        batch::batch_transactions,
+        create2::check_create2_deployments,
+        energy::EnergyPriceStrategy,
+        preflight::preflight_checks,
+        receipts::{clear_pendings_within, replace_pending},
+        transaction::TransactionWithMetadata,
+        verify::VerifyBundle,
     },
     init_progress,
     opts::WalletSigner,
@@ -15,7 +21,7 @@ use corebc::{
 use eyre::{bail, ContextCompat, Result, WrapErr};
 use foxar_common::{shell, try_get_http_provider, RetryProvider};
 use futures::StreamExt;
-use std::{cmp::min, collections::HashSet, ops::Mul, sync::Arc};
+use std::{cmp::min, collections::HashSet, ops::Mul, sync::Arc, time::Duration};
 use tracing::trace;
 
 impl ScriptArgs {
@@ -27,7 +33,7 @@ impl ScriptArgs {
         script_wallets: &[LocalWallet],
     ) -> Result<()> {
         let provider = Arc::new(try_get_http_provider(fork_url)?);
-        let already_broadcasted = deployment_sequence.receipts.len();
+        let mut already_broadcasted = deployment_sequence.receipts.len();
 
         if already_broadcasted < deployment_sequence.transactions.len() {
             let required_addresses = deployment_sequence
@@ -65,12 +71,40 @@ impl ScriptArgs {
             // otherwise. Or if the chain does not support batched transactions (eg. Arbitrum).
             let sequential_broadcast = send_kind.signers_count() != 1 || self.slow;
 
-            // Make a one-time energy price estimation
-            let energy_price = {
-                match deployment_sequence.transactions.front().unwrap().typed_tx() {
-                    TypedTransaction::Legacy(_) => provider.get_energy_price().await.ok(),
-                }
-            };
+            // Transactions left pending by a previous run are waited for, and replaced if they
+            // are stuck, before sending the next ones
+            if self.energy.replace_after.is_some() && !deployment_sequence.pending.is_empty() {
+                shell::println("##\nChecking previously pending transactions.")?;
+                self.wait_for_receipts(
+                    provider.clone(),
+                    deployment_sequence,
+                    None,
+                    &send_kind,
+                    fork_url,
+                )
+                .await?;
+                already_broadcasted = deployment_sequence.receipts.len();
+            }
+
+            // Make a one-time energy price estimation. It's stored in the sequence, so that
+            // replacements are priced from it.
+            let energy_price = self.energy_price(&provider).await?;
+            for tx in deployment_sequence.transactions.iter_mut().skip(already_broadcasted) {
+                let tx = tx.typed_tx_mut();
+                tx.set_network_id(network);
+                tx.set_energy_price(energy_price);
+            }
+
+            if !self.skip_preflight {
+                preflight_checks(&provider, deployment_sequence, already_broadcasted).await?;
+            }
+            check_create2_deployments(
+                &provider,
+                deployment_sequence,
+                already_broadcasted,
+                self.create2_deployer_tx.as_ref(),
+            )
+            .await?;
 
             // Iterate through transactions, matching the `from` field with the associated
             // wallet. Then send the transaction. Panics if we find a unknown `from`
@@ -85,24 +119,7 @@ impl ScriptArgs {
                     let kind = send_kind.for_sender(&from)?;
                     let is_fixed_energy_limit = tx_with_metadata.is_fixed_energy_limit;
 
-                    let mut tx = tx.clone();
-
-                    tx.set_network_id(network);
-
-                    if let Some(energy_price) = self.with_energy_price {
-                        tx.set_energy_price(energy_price);
-                    } else {
-                        // fill energy price
-                        match tx {
-                            TypedTransaction::Legacy(_) => {
-                                tx.set_energy_price(
-                                    energy_price.expect("Could not get energy_price."),
-                                );
-                            }
-                        }
-                    }
-
-                    Ok((tx, kind, is_fixed_energy_limit))
+                    Ok((tx.clone(), kind, is_fixed_energy_limit))
                 })
                 .collect::<Result<Vec<_>>>()?;
 
@@ -134,13 +151,19 @@ impl ScriptArgs {
 
                     if sequential_broadcast {
                         let tx_hash = tx_hash.await?;
-                        deployment_sequence.add_pending(index, tx_hash);
+                        deployment_sequence.add_pending(index + already_broadcasted, tx_hash);
 
                         update_progress!(pb, (index + already_broadcasted));
                         index += 1;
 
-                        clear_pendings(provider.clone(), deployment_sequence, Some(vec![tx_hash]))
-                            .await?;
+                        self.wait_for_receipts(
+                            provider.clone(),
+                            deployment_sequence,
+                            Some(vec![tx_hash]),
+                            &send_kind,
+                            fork_url,
+                        )
+                        .await?;
                     } else {
                         pending_transactions.push(tx_hash);
                     }
@@ -151,7 +174,7 @@ impl ScriptArgs {
 
                     while let Some(tx_hash) = buffer.next().await {
                         let tx_hash = tx_hash?;
-                        deployment_sequence.add_pending(index, tx_hash);
+                        deployment_sequence.add_pending(index + already_broadcasted, tx_hash);
 
                         update_progress!(pb, (index + already_broadcasted));
                         index += 1;
@@ -162,7 +185,14 @@ impl ScriptArgs {
 
                     if !sequential_broadcast {
                         shell::println("##\nWaiting for receipts.")?;
-                        clear_pendings(provider.clone(), deployment_sequence, None).await?;
+                        self.wait_for_receipts(
+                            provider.clone(),
+                            deployment_sequence,
+                            None,
+                            &send_kind,
+                            fork_url,
+                        )
+                        .await?;
                     }
                 }
 
@@ -196,6 +226,71 @@ impl ScriptArgs {
         Ok(())
     }
 
+    /// Waits for the receipts of the pending transactions with the given hashes, or of all pending
+    /// transactions.
+    ///
+    /// With `--replace-after`, a transaction still pending after the timeout is replaced with the
+    /// same transaction at a higher energy price, until it's mined or its replacement would cost
+    /// more than `--max-energy-price`.
+    async fn wait_for_receipts(
+        &self,
+        provider: Arc<RetryProvider>,
+        deployment_sequence: &mut ScriptSequence,
+        tx_hashes: Option<Vec<TxHash>>,
+        send_kind: &SendTransactionsKind,
+        fork_url: &str,
+    ) -> Result<()> {
+        let timeout = self.energy.replace_after.map(Duration::from_secs);
+        let mut to_query = tx_hashes;
+
+        loop {
+            let stuck =
+                clear_pendings_within(provider.clone(), deployment_sequence, to_query, timeout)
+                    .await?;
+            if stuck.is_empty() {
+                return Ok(())
+            }
+
+            let mut replacements = vec![];
+            for tx_hash in stuck {
+                let mut tx = deployment_sequence
+                    .transactions
+                    .iter()
+                    .find(|tx| tx.hash == Some(tx_hash))
+                    .wrap_err_with(|| format!("Unknown pending transaction {tx_hash:?}"))?
+                    .typed_tx()
+                    .clone();
+
+                let energy_price = tx.energy_price().unwrap_or_default();
+                let Some(bumped) = self.energy.bump(energy_price) else {
+                    deployment_sequence.save()?;
+                    bail!(
+                        "Transaction {tx_hash:?} is still pending, and its replacement would exceed the maximum energy price of {}.\n\nAdd `--resume` to your command to keep waiting for it, or raise --max-energy-price.",
+                        self.energy.max_energy_price.unwrap_or_default()
+                    )
+                };
+                tx.set_energy_price(bumped);
+
+                shell::println(format!(
+                    "##\nTransaction {tx_hash:?} is still pending, replacing it with energy price {bumped}."
+                ))?;
+                let from = *tx.from().expect("No sender for onchain transaction!");
+                let kind = send_kind.for_sender(&from)?;
+                // The nonce is the one of the stuck transaction, and the energy limit is kept
+                let hash = self
+                    .send_transaction(provider.clone(), tx, kind, false, fork_url, true)
+                    .await?;
+
+                replace_pending(deployment_sequence, tx_hash, hash, bumped);
+                replacements.push(hash);
+            }
+
+            // Checkpoint save
+            deployment_sequence.save()?;
+            to_query = Some(replacements);
+        }
+    }
+
     async fn send_transaction(
         &self,
         provider: Arc<RetryProvider>,
@@ -265,6 +360,30 @@ impl ScriptArgs {
                     )
                     .await?;
 
+                self.check_upgrades(&deployments, decoder, &script_config).await?;
+
+                for sequence in deployments.iter_mut() {
+                    sequence.deployments = result.deployments.clone();
+                    if let (true, Some(multicall)) = (self.batch, self.multicall) {
+                        let transactions = std::mem::take(&mut sequence.transactions);
+                        sequence.transactions =
+                            batch_transactions(transactions, multicall, &self.batch_targets)?;
+                    }
+                }
+
+                if self.rehearse {
+                    self.rehearse(&deployments).await?;
+                }
+
+                if let Some(out) = &self.export_bundle {
+                    if script_config.has_multiple_rpcs() {
+                        bail!("Bundles can't be exported for multi chain scripts");
+                    }
+                    let sequence = deployments.pop().expect("to be set.");
+                    let script = script_config.target_contract().source.clone();
+                    return self.export_bundle(sequence, &script, out).await
+                }
+
                 if script_config.has_multiple_rpcs() {
                     trace!(target: "script", "broadcasting multi chain deployment");
 
@@ -281,6 +400,7 @@ impl ScriptArgs {
                             multi,
                             libraries,
                             &script_config.config,
+                            &script_config.storage_layouts,
                             result.script_wallets,
                             verify,
                         )
@@ -297,7 +417,9 @@ impl ScriptArgs {
                     .await?;
                 }
 
-                if !self.broadcast {
+                if self.rehearse && !self.broadcast {
+                    shell::println("\nREHEARSAL COMPLETE. To broadcast these transactions, add --broadcast and wallet configuration(s) to the previous command.")?;
+                } else if !self.broadcast {
                     shell::println("\nSIMULATION COMPLETE. To broadcast these transactions, add --broadcast and wallet configuration(s) to the previous command. See spark script --help for more.")?;
                 }
             } else {
@@ -323,6 +445,11 @@ impl ScriptArgs {
         deployment_sequence.add_libraries(libraries);
 
         self.send_transactions(deployment_sequence, &rpc, &result.script_wallets).await?;
+        deployment_sequence.save_deployments(
+            &script_config.config,
+            &verify.known_contracts,
+            &script_config.storage_layouts,
+        )?;
 
         if self.verify {
             return deployment_sequence.verify_contracts(&script_config.config, verify).await;
@@ -493,10 +620,10 @@ impl ScriptArgs {
 
                 // We don't store it in the transactions, since we want the most updated value.
                 // Right before broadcasting.
-                let per_energy = if let Some(energy_price) = self.with_energy_price {
-                    energy_price
-                } else {
-                    provider_info.energy_price()?
+                let per_energy = match self.energy.energy_price_strategy {
+                    None if self.with_energy_price.is_none() => provider_info.energy_price()?,
+                    Some(EnergyPriceStrategy::Node) => provider_info.energy_price()?,
+                    _ => self.energy_price(&provider_info.provider).await?,
                 };
 
                 shell::println("\n==========================")?;
@@ -31,10 +31,15 @@ impl ScriptArgs {
         let (project, output) = self.get_project_and_output(script_config)?;
 
         let mut sources: BTreeMap<u32, String> = BTreeMap::new();
+        let mut storage_layouts = BTreeMap::new();
 
         let contracts = output
             .into_artifacts()
             .map(|(id, artifact)| -> eyre::Result<_> {
+                if let Some(layout) = &artifact.storage_layout {
+                    storage_layouts.insert(id.identifier(), layout.clone());
+                }
+
                 // Sources are only required for the debugger, but it *might* mean that there's
                 // something wrong with the build and/or artifacts.
                 if let Some(source) = artifact.source_file() {
@@ -62,6 +67,7 @@ impl ScriptArgs {
 
         output.sources = sources;
         script_config.target_contract = Some(output.target.clone());
+        script_config.storage_layouts = storage_layouts;
 
         Ok(output)
     }
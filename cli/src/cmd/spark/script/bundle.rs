@@ -0,0 +1,425 @@
+//! Offline signing of script transactions, see `spark script --export-bundle`,
+//! `spark script sign` and `spark script publish`
+
+use super::{
+    create2::check_create2_deployments,
+    receipts::{clear_pendings, wait_for_pending},
+    sequence::ScriptSequence,
+    ScriptArgs,
+};
+use crate::{
+    cmd::{spark::build::ProjectPathsArgs, LoadConfig},
+    opts::MultiWallet,
+};
+use clap::{Parser, Subcommand, ValueHint};
+use corebc::{
+    prelude::{Signer, TxHash},
+    providers::Middleware,
+    types::{transaction::eip2718::TypedTransaction, Bytes, NameOrAddress, Signature, U256},
+    utils::{
+        rlp::{DecoderError, Rlp},
+        sha3,
+    },
+    ylem::artifacts::{
+        output_selection::ContractOutputSelection, CompactContractBytecode, StorageLayout,
+    },
+};
+use eyre::{ensure, ContextCompat, Result, WrapErr};
+use foxar_common::{compile, fs, shell, try_get_http_provider, ContractsByArtifact};
+use foxar_config::Config;
+use serde::{Deserialize, Serialize};
+use std::{
+    collections::{BTreeMap, HashSet},
+    path::{Path, PathBuf},
+    sync::Arc,
+};
+
+foxar_config::impl_figment_convert!(PublishArgs, opts);
+
+/// The transactions of a script, exported for signing on another machine
+#[derive(Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TransactionBundle {
+    /// The source file of the script, which names the broadcast log
+    pub script: PathBuf,
+    /// The signature of the script's entry point
+    pub sig: String,
+    /// The transactions, with their network id, nonce, energy limit and energy price set
+    pub sequence: ScriptSequence,
+    /// The RLP encoded signed transactions, in order, set by `spark script sign`
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub signed: Vec<Bytes>,
+}
+
+impl TransactionBundle {
+    pub fn load(path: &Path) -> Result<Self> {
+        let mut bundle: Self = fs::read_json_file(path)
+            .wrap_err_with(|| format!("Failed to read bundle {}", path.display()))?;
+        // The sequence has no log paths, it mustn't be saved when the bundle is dropped
+        bundle.sequence.multi = true;
+        Ok(bundle)
+    }
+
+    pub fn save(&self, path: &Path) -> Result<()> {
+        fs::write(path, serde_json::to_string_pretty(self)?)?;
+        Ok(())
+    }
+
+    /// Returns the hashes of the signed transactions
+    pub fn signed_hashes(&self) -> Vec<TxHash> {
+        self.signed.iter().map(|raw| TxHash::from(sha3(raw))).collect()
+    }
+}
+
+impl ScriptArgs {
+    /// Fills the energy prices of the sequence's transactions and writes them as an unsigned
+    /// bundle, instead of broadcasting them.
+    pub async fn export_bundle(
+        &self,
+        mut sequence: ScriptSequence,
+        script: &Path,
+        out: &Path,
+    ) -> Result<()> {
+        let rpc = sequence
+            .transactions
+            .front()
+            .and_then(|tx| tx.rpc.clone())
+            .wrap_err("No onchain transactions generated in script")?;
+        let energy_price = self.energy_price(&try_get_http_provider(rpc.as_str())?).await?;
+        for tx in sequence.transactions.iter_mut() {
+            tx.typed_tx_mut().set_energy_price(energy_price);
+        }
+
+        let count = sequence.transactions.len();
+        let bundle = TransactionBundle {
+            script: script.to_path_buf(),
+            sig: self.sig.clone(),
+            sequence,
+            signed: vec![],
+        };
+        bundle.save(out)?;
+
+        shell::println(format!(
+            "\nBundle of {count} transactions saved to: {}\nSign it with `spark script sign`.",
+            out.display()
+        ))?;
+        Ok(())
+    }
+}
+
+/// Subcommands of `spark script` for bundles exported with `--export-bundle`
+#[derive(Debug, Clone, Subcommand)]
+pub enum ScriptSubcommands {
+    /// Signs the transactions of a bundle, without network access.
+    Sign(SignArgs),
+
+    /// Broadcasts the transactions of a signed bundle.
+    ///
+    /// The broadcast log is written like `spark script --broadcast` does. If publishing is
+    /// interrupted, running it again continues where it stopped.
+    Publish(PublishArgs),
+}
+
+impl ScriptSubcommands {
+    pub async fn run(self) -> Result<()> {
+        match self {
+            ScriptSubcommands::Sign(cmd) => cmd.run().await,
+            ScriptSubcommands::Publish(cmd) => cmd.run().await,
+        }
+    }
+}
+
+/// CLI arguments for `spark script sign`.
+#[derive(Debug, Clone, Parser)]
+pub struct SignArgs {
+    /// The bundle to sign.
+    #[clap(value_hint = ValueHint::FilePath, value_name = "BUNDLE")]
+    pub bundle: PathBuf,
+
+    /// The file to write the signed bundle to, instead of replacing the bundle.
+    #[clap(long, short, value_hint = ValueHint::FilePath, value_name = "FILE")]
+    pub out: Option<PathBuf>,
+
+    #[clap(flatten)]
+    pub wallets: MultiWallet,
+}
+
+impl SignArgs {
+    pub async fn run(self) -> Result<()> {
+        let mut bundle = TransactionBundle::load(&self.bundle)?;
+        let network = bundle.sequence.network;
+
+        let senders = bundle
+            .sequence
+            .transactions
+            .iter()
+            .map(|tx| tx.typed_tx().from().copied().wrap_err("No sender for onchain transaction!"))
+            .collect::<Result<HashSet<_>>>()?;
+        let signers = self.wallets.find_all_for_network(u64::from(network), senders, &[])?;
+
+        shell::println(format!(
+            "Signing {} transactions on {network}:",
+            bundle.sequence.transactions.len()
+        ))?;
+        let mut signed = Vec::with_capacity(bundle.sequence.transactions.len());
+        for (index, tx) in bundle.sequence.transactions.iter().enumerate() {
+            let typed_tx = tx.typed_tx();
+            let from = typed_tx.from().expect("checked above");
+            let to = match typed_tx.to() {
+                Some(NameOrAddress::Address(to)) => format!("{to:?}"),
+                Some(NameOrAddress::Name(name)) => name.clone(),
+                None => "create".to_string(),
+            };
+            shell::println(format!(
+                "  {index}: {from:?} -> {to}, nonce {}, {}",
+                typed_tx.nonce().copied().unwrap_or_default(),
+                tx.function.as_deref().filter(|function| !function.is_empty()).unwrap_or("-")
+            ))?;
+
+            let signature = signers[from]
+                .sign_transaction(typed_tx)
+                .await
+                .wrap_err("Failed to sign transaction")?;
+            signed.push(typed_tx.rlp_signed(&signature));
+        }
+        bundle.signed = signed;
+
+        let out = self.out.unwrap_or(self.bundle);
+        bundle.save(&out)?;
+        shell::println(format!("\nSigned bundle saved to: {}", out.display()))?;
+        Ok(())
+    }
+}
+
+/// CLI arguments for `spark script publish`.
+#[derive(Debug, Clone, Parser)]
+pub struct PublishArgs {
+    /// The signed bundle to broadcast.
+    #[clap(value_hint = ValueHint::FilePath, value_name = "BUNDLE")]
+    pub bundle: PathBuf,
+
+    /// The RPC endpoint to broadcast the transactions to.
+    #[clap(long, visible_alias = "fork-url", env = "ETH_RPC_URL", value_name = "URL")]
+    pub rpc_url: String,
+
+    /// Makes sure a transaction is sent,
+    /// only after its previous one has been confirmed and succeeded.
+    #[clap(long)]
+    pub slow: bool,
+
+    #[clap(flatten)]
+    pub opts: ProjectPathsArgs,
+}
+
+impl PublishArgs {
+    pub async fn run(self) -> Result<()> {
+        let config = self.try_load_config_emit_warnings()?;
+        let bundle = TransactionBundle::load(&self.bundle)?;
+        let TransactionBundle { script, sig, sequence, signed } = bundle.clone();
+        ensure!(
+            signed.len() == sequence.transactions.len(),
+            "The bundle is not signed, sign it with `spark script sign` first"
+        );
+
+        let provider = Arc::new(try_get_http_provider(self.rpc_url.as_str())?);
+        let network = provider.get_networkid().await?.as_u64();
+        ensure!(
+            network == u64::from(sequence.network),
+            "The bundle is for network {}, but the RPC endpoint is on network {network}",
+            sequence.network
+        );
+
+        let (path, sensitive_path) = ScriptSequence::get_paths(
+            &config.broadcast,
+            &config.cache_path,
+            &sig,
+            &script,
+            sequence.network,
+            true,
+        )?;
+
+        // Continue from the broadcast log, if a previous run published the same transactions
+        let hashes = bundle.signed_hashes();
+        let previous = fs::read_json_file::<ScriptSequence>(&path).ok().map(|mut previous| {
+            // Like the bundle's sequence, it's only saved if it's the one being published
+            previous.multi = true;
+            previous
+        });
+        let mut sequence = match previous {
+            Some(previous) if is_published_from(&previous, &hashes) => previous,
+            _ => sequence,
+        };
+        sequence.path = path;
+        sequence.sensitive_path = sensitive_path;
+        sequence.multi = false;
+        for tx in sequence.transactions.iter_mut() {
+            tx.rpc = Some(self.rpc_url.clone());
+        }
+
+        // The signatures are checked by the node, but the signed transactions could still differ
+        // from the ones of the bundle
+        for (index, (tx, raw)) in sequence.transactions.iter().zip(&signed).enumerate() {
+            ensure_signed_matches(index, tx.typed_tx(), tx.hash, raw)?;
+        }
+
+        wait_for_pending(provider.clone(), &mut sequence).await?;
+        check_create2_deployments(&provider, &sequence, sequence.receipts.len(), None).await?;
+
+        // There is no way of assuring the order of transactions of different signers, unless
+        // each one is confirmed before sending the next
+        let senders =
+            sequence.typed_transactions().into_iter().filter_map(|(_, tx)| tx.from().copied());
+        let sequential_broadcast = senders.collect::<HashSet<_>>().len() != 1 || self.slow;
+
+        let already_broadcasted = sequence.receipts.len();
+        for (index, raw) in signed.into_iter().enumerate().skip(already_broadcasted) {
+            let tx_hash = provider.send_raw_transaction(raw).await?.tx_hash();
+            sequence.add_pending(index, tx_hash);
+
+            if sequential_broadcast {
+                clear_pendings(provider.clone(), &mut sequence, Some(vec![tx_hash])).await?;
+            }
+        }
+
+        // Checkpoint save
+        sequence.save()?;
+
+        if !sequential_broadcast {
+            shell::println("##\nWaiting for receipts.")?;
+            clear_pendings(provider, &mut sequence, None).await?;
+            sequence.save()?;
+        }
+
+        let (known_contracts, storage_layouts) = compile_contracts(&config, &sequence)?;
+        sequence.save_deployments(&config, &known_contracts, &storage_layouts)?;
+
+        shell::println("\n\n==========================")?;
+        shell::println("\nONCHAIN EXECUTION COMPLETE & SUCCESSFUL.")?;
+        Ok(())
+    }
+}
+
+/// Compiles the project with the libraries of the sequence, to match the contracts it created,
+/// and returns the contracts and their storage layouts keyed by contract identifier
+fn compile_contracts(
+    config: &Config,
+    sequence: &ScriptSequence,
+) -> Result<(ContractsByArtifact, BTreeMap<String, StorageLayout>)> {
+    let mut config = config.clone();
+    config.libraries = sequence.libraries.clone();
+    config.extra_output.push(ContractOutputSelection::StorageLayout);
+    let project = config.project()?;
+    let output = compile::suppress_compile(&project)?;
+
+    let mut storage_layouts = BTreeMap::new();
+    let known_contracts = ContractsByArtifact(
+        output
+            .artifact_ids()
+            .filter_map(|(id, artifact)| {
+                if let Some(layout) = &artifact.storage_layout {
+                    storage_layouts.insert(id.identifier(), layout.clone());
+                }
+                let CompactContractBytecode { abi, bytecode, .. } = artifact.clone().into();
+                let code = bytecode?.object.into_bytes()?;
+                Some((id, (abi?, code.to_vec())))
+            })
+            .collect(),
+    );
+    Ok((known_contracts, storage_layouts))
+}
+
+/// Checks that the signed transaction at `index` is the transaction of the bundle, signed with
+/// the signature it carries, and has the `hash` of the broadcast log if it was published already
+fn ensure_signed_matches(
+    index: usize,
+    tx: &TypedTransaction,
+    hash: Option<TxHash>,
+    raw: &Bytes,
+) -> Result<()> {
+    let rlp = Rlp::new(raw);
+    let decode = || -> Result<_, DecoderError> {
+        Ok((
+            rlp.val_at::<U256>(0)?,
+            rlp.val_at::<Vec<u8>>(4)?,
+            rlp.val_at::<Vec<u8>>(6)?,
+            Signature { sig: rlp.val_at(7)? },
+        ))
+    };
+    let (nonce, to, data, signature) = decode()
+        .map_err(|err| eyre::eyre!("Failed to decode signed transaction {index}: {err}"))?;
+
+    let expected_nonce = tx.nonce().copied().unwrap_or_default();
+    ensure!(
+        nonce == expected_nonce,
+        "Signed transaction {index} has nonce {nonce}, but the bundle's has nonce {expected_nonce}"
+    );
+    let expected_to = match tx.to() {
+        Some(NameOrAddress::Address(to)) => to.as_bytes().to_vec(),
+        _ => vec![],
+    };
+    ensure!(
+        to == expected_to,
+        "Signed transaction {index} has a different recipient than the bundle's"
+    );
+    ensure!(
+        data == tx.data().map(|data| data.to_vec()).unwrap_or_default(),
+        "Signed transaction {index} has different data than the bundle's"
+    );
+    ensure!(
+        tx.rlp_signed(&signature) == *raw,
+        "Signed transaction {index} doesn't match the bundle's transaction"
+    );
+    if let Some(hash) = hash {
+        ensure!(
+            hash == TxHash::from(sha3(raw)),
+            "Signed transaction {index} doesn't match the published transaction {hash:?}"
+        );
+    }
+    Ok(())
+}
+
+/// Returns true if the broadcast log belongs to the signed transactions with the given hashes
+fn is_published_from(sequence: &ScriptSequence, hashes: &[TxHash]) -> bool {
+    sequence.transactions.len() == hashes.len() &&
+        sequence.transactions.front().and_then(|tx| tx.hash).is_some() &&
+        sequence
+            .transactions
+            .iter()
+            .zip(hashes)
+            .all(|(tx, hash)| tx.hash.map_or(true, |tx_hash| tx_hash == *hash))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn can_match_published_sequence() {
+        let bundle = TransactionBundle {
+            script: "script/Deploy.s.sol".into(),
+            sig: "run()".to_string(),
+            sequence: ScriptSequence {
+                transactions: vec![Default::default(), Default::default()].into(),
+                // Not saved to disk when dropped
+                multi: true,
+                ..Default::default()
+            },
+            signed: vec![vec![1u8].into(), vec![2u8].into()],
+        };
+        let hashes = bundle.signed_hashes();
+
+        let mut published = bundle.sequence.clone();
+        assert!(!is_published_from(&published, &hashes));
+
+        published.transactions[0].hash = Some(hashes[0]);
+        assert!(is_published_from(&published, &hashes));
+        assert!(!is_published_from(&published, &hashes[..1]));
+
+        published.transactions[1].hash = Some(hashes[0]);
+        assert!(!is_published_from(&published, &hashes));
+
+        let json = serde_json::to_string(&bundle).unwrap();
+        let loaded: TransactionBundle = serde_json::from_str(&json).unwrap();
+        assert_eq!(loaded.signed_hashes(), hashes);
+    }
+}
@@ -1,11 +1,15 @@
 use super::{sequence::ScriptSequence, *};
 use crate::cmd::{
-    spark::script::{multi::MultiChainSequence, verify::VerifyBundle},
+    spark::{
+        coverage::report_script_coverage,
+        script::{multi::MultiChainSequence, verify::VerifyBundle},
+    },
     LoadConfig,
 };
 use corebc::{
     prelude::Signer,
     types::{transaction::eip2718::TypedTransaction, Network, U256},
+    ylem::artifacts::output_selection::ContractOutputSelection,
 };
 use foxar_common::{contracts::flatten_contracts, try_get_http_provider};
 use std::sync::Arc;
@@ -30,6 +34,16 @@ impl ScriptArgs {
 
         self.maybe_load_private_key(&mut script_config)?;
 
+        if !self.coverage.is_empty() {
+            // Disable the optimizer for more accurate source maps
+            script_config.config.optimizer = false;
+            script_config.config.via_ir = false;
+        }
+
+        // Storage layouts are recorded in the deployment registry, checked by proxy upgrades and
+        // used to decode the changed storage slots of `--diff`
+        script_config.config.extra_output.push(ContractOutputSelection::StorageLayout);
+
         if let Some(ref fork_url) = script_config.evm_opts.fork_url {
             // when forking, override the sender's nonce to the onchain value
             script_config.sender_nonce =
@@ -68,6 +82,10 @@ impl ScriptArgs {
         let mut result =
             self.execute(&mut script_config, contract, sender, &predeploy_libraries).await?;
 
+        if let Some(hit_maps) = result.coverage.take() {
+            report_script_coverage(&script_config.config, hit_maps, self.coverage.clone())?;
+        }
+
         if self.resume || (self.verify && !self.broadcast) {
             return self
                 .resume_deployment(
@@ -206,6 +224,7 @@ impl ScriptArgs {
                     )?,
                     libraries,
                     &script_config.config,
+                    &script_config.storage_layouts,
                     result.script_wallets,
                     verify,
                 )
@@ -265,11 +284,19 @@ impl ScriptArgs {
             Err(err) => eyre::bail!(err),
         };
 
-        receipts::wait_for_pending(provider, &mut deployment_sequence).await?;
+        // With `--replace-after`, stuck transactions are replaced while sending the others
+        if !self.resume || self.energy.replace_after.is_none() {
+            receipts::wait_for_pending(provider, &mut deployment_sequence).await?;
+        }
 
         if self.resume {
             self.send_transactions(&mut deployment_sequence, fork_url, &result.script_wallets)
                 .await?;
+            deployment_sequence.save_deployments(
+                &script_config.config,
+                &verify.known_contracts,
+                &script_config.storage_layouts,
+            )?;
         }
 
         if self.verify {
@@ -0,0 +1,104 @@
+//! Deterministic deployments through the CREATE2 deployer
+
+use super::sequence::ScriptSequence;
+use corebc::{
+    providers::Middleware,
+    types::{Bytes, Network},
+    utils::get_create2_address,
+};
+use eyre::{bail, ensure, ContextCompat, Result};
+use foxar_common::{shell, RetryProvider};
+use probe::executor::{inspector::DEFAULT_CREATE2_DEPLOYER, DEFAULT_CREATE2_DEPLOYER_RUNTIME_CODE};
+
+/// Checks the CREATE2 deployments of the sequence which haven't been broadcasted yet, before
+/// sending them.
+///
+/// Deploys the CREATE2 deployer if it's missing on the network: shuttle nodes get its code set,
+/// other networks need the presigned transaction deploying it at its canonical address. Fails if
+/// the address of a deployment doesn't follow from its salt and init code, or is already in use.
+pub async fn check_create2_deployments(
+    provider: &RetryProvider,
+    sequence: &ScriptSequence,
+    already_broadcasted: usize,
+    create2_deployer_tx: Option<&Bytes>,
+) -> Result<()> {
+    let deployments = sequence
+        .transactions
+        .iter()
+        .skip(already_broadcasted)
+        .filter(|tx| tx.is_create2())
+        .collect::<Vec<_>>();
+    if deployments.is_empty() {
+        return Ok(())
+    }
+
+    ensure_create2_deployer(provider, sequence.network, create2_deployer_tx).await?;
+
+    for tx in deployments {
+        let name = tx.contract_name.clone().unwrap_or_default();
+        let address = tx.contract_address.wrap_err("CREATE2 deployment without an address")?;
+        let data = tx.typed_tx().data().wrap_err("CREATE2 deployment without init code")?;
+        ensure!(data.len() >= 32, "CREATE2 deployment of `{name}` without a salt");
+
+        let predicted = get_create2_address(
+            DEFAULT_CREATE2_DEPLOYER,
+            data[..32].to_vec(),
+            data[32..].to_vec(),
+            sequence.network,
+        );
+        ensure!(
+            predicted == address,
+            "The simulated address {address:?} of `{name}` is not its CREATE2 address {predicted:?}"
+        );
+        ensure!(
+            provider.get_code(address, None).await?.is_empty(),
+            "`{name}` is already deployed at its CREATE2 address {address:?} on network {}, use a different salt",
+            sequence.network
+        );
+    }
+    Ok(())
+}
+
+/// Deploys the CREATE2 deployer, if it's missing on the network
+async fn ensure_create2_deployer(
+    provider: &RetryProvider,
+    network: Network,
+    create2_deployer_tx: Option<&Bytes>,
+) -> Result<()> {
+    if !provider.get_code(DEFAULT_CREATE2_DEPLOYER, None).await?.is_empty() {
+        return Ok(())
+    }
+
+    shell::println(format!(
+        "\nThe CREATE2 deployer {DEFAULT_CREATE2_DEPLOYER:?} is missing on network {network}, deploying it."
+    ))?;
+
+    let client_version = provider.client_version().await.unwrap_or_default();
+    if client_version.starts_with("shuttle") {
+        provider
+            .request::<_, ()>(
+                "shuttle_setCode",
+                (
+                    DEFAULT_CREATE2_DEPLOYER,
+                    Bytes::from_static(DEFAULT_CREATE2_DEPLOYER_RUNTIME_CODE),
+                ),
+            )
+            .await?;
+    } else if let Some(tx) = create2_deployer_tx {
+        provider
+            .send_raw_transaction(tx.clone())
+            .await?
+            .await?
+            .wrap_err("The transaction deploying the CREATE2 deployer was dropped")?;
+    } else {
+        bail!(
+            "The CREATE2 deployer {DEFAULT_CREATE2_DEPLOYER:?} is missing on network {network}. Pass the presigned transaction deploying it with --create2-deployer-tx."
+        )
+    }
+
+    ensure!(
+        !provider.get_code(DEFAULT_CREATE2_DEPLOYER, None).await?.is_empty(),
+        "The CREATE2 deployer {DEFAULT_CREATE2_DEPLOYER:?} wasn't deployed on network {network}"
+    );
+    Ok(())
+}
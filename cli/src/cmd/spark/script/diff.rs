@@ -0,0 +1,365 @@
+//! State diff of the simulated transactions of a script, see `spark script --diff`
+
+use corebc::{
+    abi::RawLog,
+    types::{Address, Log, H256, U256},
+    utils::sha3,
+    ylem::artifacts::StorageLayout,
+};
+use foxar_common::abi::format_token;
+use foxar_evm::{
+    executor::{Backend, DatabaseRef, CHEATCODE_ADDRESS},
+    trace::CallTraceDecoder,
+    utils::{b176_to_h176, b256_to_h256, h176_to_b176, ru256_to_u256, u256_to_h256_be},
+};
+use serde::Serialize;
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    fmt,
+};
+use yansi::Paint;
+
+/// The state changes of the simulated transactions on a network
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct StateDiff {
+    /// The changed accounts
+    pub accounts: BTreeMap<Address, AccountDiff>,
+    /// The events emitted by the transactions, in order
+    pub events: Vec<EmittedEvent>,
+    /// The `Approval` events emitted by the transactions, in order
+    pub approvals: Vec<Approval>,
+}
+
+/// The changes of an account
+#[derive(Debug, Clone, Default, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AccountDiff {
+    /// The contract identifier, if known
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub contract: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub balance: Option<Change<U256>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub nonce: Option<Change<u64>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub code_hash: Option<Change<H256>>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub storage: Vec<StorageChange>,
+}
+
+impl AccountDiff {
+    fn is_empty(&self) -> bool {
+        self.balance.is_none() &&
+            self.nonce.is_none() &&
+            self.code_hash.is_none() &&
+            self.storage.is_empty()
+    }
+}
+
+/// A changed value
+#[derive(Debug, Clone, Serialize)]
+pub struct Change<T> {
+    pub before: T,
+    pub after: T,
+}
+
+impl<T: PartialEq> Change<T> {
+    fn new(before: T, after: T) -> Option<Self> {
+        (before != after).then_some(Self { before, after })
+    }
+}
+
+/// A changed storage slot
+#[derive(Debug, Clone, Serialize)]
+pub struct StorageChange {
+    pub slot: H256,
+    pub before: H256,
+    pub after: H256,
+    /// The variables stored in the slot, decoded with the storage layout of the contract
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub decoded: Vec<DecodedVariable>,
+}
+
+/// A variable of a changed storage slot
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DecodedVariable {
+    pub label: String,
+    #[serde(rename = "type")]
+    pub ty: String,
+    pub before: String,
+    pub after: String,
+}
+
+/// An event emitted by a transaction
+#[derive(Debug, Clone, Serialize)]
+pub struct EmittedEvent {
+    pub address: Address,
+    /// The decoded event, if its ABI is known
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub decoded: Option<String>,
+    pub topics: Vec<H256>,
+    pub data: corebc::types::Bytes,
+}
+
+/// An `Approval(owner, spender, value)` event emitted by a token
+#[derive(Debug, Clone, Serialize)]
+pub struct Approval {
+    pub token: Address,
+    pub owner: Address,
+    pub spender: Address,
+    /// The allowance of ERC20 tokens, the token id of ERC721 tokens
+    pub value: U256,
+}
+
+impl StateDiff {
+    /// Diffs the state of `after`, the backend the transactions were simulated on, against
+    /// `before`, a clone of it taken before the simulation.
+    ///
+    /// The storage slots are decoded with the storage layouts of the contracts identified by the
+    /// `decoder`, keyed by contract identifier.
+    pub fn new(
+        before: &Backend,
+        after: &Backend,
+        logs: &[Log],
+        decoder: &CallTraceDecoder,
+        layouts: &BTreeMap<String, StorageLayout>,
+    ) -> eyre::Result<Self> {
+        let mut diff = StateDiff::default();
+
+        if let Some(db) = after.active_fork_db() {
+            for (address, account) in &db.accounts {
+                if *address == h176_to_b176(CHEATCODE_ADDRESS) {
+                    continue
+                }
+                let info = &account.info;
+                let previous = before.basic(*address)?.unwrap_or_default();
+                let address = b176_to_h176(*address);
+                let contract = decoder.contracts.get(&address).cloned();
+                let layout = contract.as_ref().and_then(|contract| layouts.get(contract));
+
+                let slots = account.storage.keys().collect::<BTreeSet<_>>();
+                let mut storage = vec![];
+                for slot in slots {
+                    let old = before.storage(h176_to_b176(address), *slot)?;
+                    let old = u256_to_h256_be(ru256_to_u256(old));
+                    let new = u256_to_h256_be(ru256_to_u256(account.storage[slot]));
+                    if old != new {
+                        let slot = ru256_to_u256(*slot);
+                        let decoded = layout
+                            .map(|layout| decode_slot(layout, slot, old, new))
+                            .unwrap_or_default();
+                        storage.push(StorageChange {
+                            slot: u256_to_h256_be(slot),
+                            before: old,
+                            after: new,
+                            decoded,
+                        });
+                    }
+                }
+
+                let account = AccountDiff {
+                    contract,
+                    balance: Change::new(
+                        ru256_to_u256(previous.balance),
+                        ru256_to_u256(info.balance),
+                    ),
+                    nonce: Change::new(previous.nonce, info.nonce),
+                    code_hash: Change::new(
+                        b256_to_h256(previous.code_hash),
+                        b256_to_h256(info.code_hash),
+                    ),
+                    storage,
+                };
+                if !account.is_empty() {
+                    diff.accounts.insert(address, account);
+                }
+            }
+        }
+
+        let approval = H256::from(sha3("Approval(address,address,uint256)"));
+        for log in logs {
+            diff.events.push(EmittedEvent {
+                address: log.address,
+                decoded: decode_event(decoder, log),
+                topics: log.topics.clone(),
+                data: log.data.clone(),
+            });
+
+            // ERC20 approvals have a non-indexed value, ERC721 approvals an indexed token id
+            if log.topics.first() == Some(&approval) && log.topics.len() >= 3 {
+                let value = match log.topics.get(3) {
+                    Some(id) => U256::from_big_endian(id.as_bytes()),
+                    None if log.data.len() == 32 => U256::from_big_endian(&log.data),
+                    None => continue,
+                };
+                diff.approvals.push(Approval {
+                    token: log.address,
+                    owner: topic_to_address(&log.topics[1]),
+                    spender: topic_to_address(&log.topics[2]),
+                    value,
+                });
+            }
+        }
+
+        Ok(diff)
+    }
+
+    /// Returns true if the transactions didn't change anything
+    pub fn is_empty(&self) -> bool {
+        self.accounts.is_empty() && self.events.is_empty()
+    }
+}
+
+impl fmt::Display for StateDiff {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.is_empty() {
+            return writeln!(f, "No changes.")
+        }
+
+        for (address, account) in &self.accounts {
+            match &account.contract {
+                Some(contract) => {
+                    writeln!(f, "{}", Paint::cyan(format!("{address:?} ({contract})")))?
+                }
+                None => writeln!(f, "{}", Paint::cyan(format!("{address:?}")))?,
+            }
+            if let Some(Change { before, after }) = &account.balance {
+                writeln!(f, "  balance: {before} → {after}")?;
+            }
+            if let Some(Change { before, after }) = &account.nonce {
+                writeln!(f, "  nonce: {before} → {after}")?;
+            }
+            if let Some(Change { before, after }) = &account.code_hash {
+                writeln!(f, "  code hash: {before:?} → {after:?}")?;
+            }
+            for change in &account.storage {
+                if change.decoded.is_empty() {
+                    writeln!(
+                        f,
+                        "  slot {:?}: {:?} → {:?}",
+                        change.slot, change.before, change.after
+                    )?;
+                }
+                for var in &change.decoded {
+                    writeln!(f, "  {} ({}): {} → {}", var.label, var.ty, var.before, var.after)?;
+                }
+            }
+        }
+
+        if !self.events.is_empty() {
+            writeln!(f, "\nEvents:")?;
+            for event in &self.events {
+                match &event.decoded {
+                    Some(decoded) => writeln!(f, "  {:?}: {decoded}", event.address)?,
+                    None => writeln!(
+                        f,
+                        "  {:?}: topics {:?}, data 0x{}",
+                        event.address,
+                        event.topics,
+                        hex::encode(&event.data)
+                    )?,
+                }
+            }
+        }
+
+        if !self.approvals.is_empty() {
+            writeln!(f, "\nApprovals:")?;
+            for approval in &self.approvals {
+                writeln!(
+                    f,
+                    "  {:?}: {:?} approved {:?} for {}",
+                    approval.token, approval.owner, approval.spender, approval.value
+                )?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Decodes the variables of the layout stored in the slot. Only the variables with a fixed slot
+/// can be found, values of mappings and dynamic arrays are stored at hashed slots.
+fn decode_slot(
+    layout: &StorageLayout,
+    slot: U256,
+    before: H256,
+    after: H256,
+) -> Vec<DecodedVariable> {
+    layout
+        .storage
+        .iter()
+        .filter(|var| U256::from_dec_str(&var.slot).ok() == Some(slot))
+        .filter_map(|var| {
+            let ty = layout.types.get(&var.storage_type)?;
+            let offset = usize::try_from(var.offset).ok()?;
+            let size = ty.number_of_bytes.parse::<usize>().ok()?.min(32);
+            let value = |word: H256| {
+                let end = 32usize.checked_sub(offset)?;
+                let start = end.checked_sub(size)?;
+                Some(format_value(&ty.label, &word.as_bytes()[start..end]))
+            };
+            let (before, after) = (value(before)?, value(after)?);
+            (before != after).then(|| DecodedVariable {
+                label: var.label.clone(),
+                ty: ty.label.clone(),
+                before,
+                after,
+            })
+        })
+        .collect()
+}
+
+/// Formats the big endian bytes of a value type
+fn format_value(ty: &str, bytes: &[u8]) -> String {
+    if ty == "bool" {
+        (bytes.iter().any(|byte| *byte != 0)).to_string()
+    } else if ty.starts_with("uint") || ty.starts_with("enum ") {
+        U256::from_big_endian(bytes).to_string()
+    } else if ty == "address" || ty.starts_with("contract ") {
+        format!("{:?}", Address::from_slice(&bytes[bytes.len().saturating_sub(22)..]))
+    } else {
+        format!("0x{}", hex::encode(bytes))
+    }
+}
+
+fn topic_to_address(topic: &H256) -> Address {
+    Address::from_slice(&topic.as_bytes()[32 - Address::len_bytes()..])
+}
+
+/// Decodes the event with the ABIs known to the decoder
+fn decode_event(decoder: &CallTraceDecoder, log: &Log) -> Option<String> {
+    let topic = log.topics.first()?;
+    let events = decoder.events.get(&(*topic, log.topics.len() - 1))?;
+    events.iter().find_map(|event| {
+        let decoded =
+            event.parse_log(RawLog { topics: log.topics.clone(), data: log.data.to_vec() }).ok()?;
+        let params = decoded
+            .params
+            .into_iter()
+            .map(|param| format!("{}: {}", param.name, format_token(&param.value)))
+            .collect::<Vec<_>>()
+            .join(", ");
+        Some(format!("{}({params})", event.name))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn can_format_values() {
+        assert_eq!(format_value("bool", &[1]), "true");
+        assert_eq!(format_value("uint256", &[1, 0]), "256");
+        assert_eq!(format_value("bytes2", &[0xbe, 0xef]), "0xbeef");
+    }
+
+    #[test]
+    fn can_convert_topic_to_address() {
+        let address = Address::repeat_byte(0xaa);
+        let mut topic = H256::zero();
+        topic.0[32 - Address::len_bytes()..].copy_from_slice(address.as_bytes());
+        assert_eq!(topic_to_address(&topic), address);
+    }
+}
@@ -0,0 +1,167 @@
+//! Energy prices of broadcast transactions and replacements of stuck ones
+
+use super::ScriptArgs;
+use crate::utils::parse_ether_value;
+use clap::{builder::RangedU64ValueParser, Parser, ValueEnum};
+use corebc::{providers::Middleware, types::U256};
+use eyre::{bail, Result};
+use foxar_common::RetryProvider;
+
+/// How the energy price of broadcast transactions is chosen
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum EnergyPriceStrategy {
+    /// The price set with `--with-energy-price`
+    Fixed,
+    /// The price suggested by the node, `xcb_energyPrice`
+    Node,
+    /// A percentile of the energy prices paid in recent blocks
+    Percentile,
+}
+
+/// CLI arguments for the energy price of broadcast transactions.
+#[derive(Debug, Clone, Parser)]
+#[clap(next_help_heading = "Energy price options")]
+pub struct EnergyPriceArgs {
+    /// How the energy price of the transactions is chosen.
+    ///
+    /// Defaults to `fixed` if `--with-energy-price` is set, `node` otherwise.
+    #[clap(long, value_enum, value_name = "STRATEGY")]
+    pub energy_price_strategy: Option<EnergyPriceStrategy>,
+
+    /// The percentile of the energy prices in recent blocks used by the `percentile` strategy.
+    #[clap(
+        long,
+        value_parser = RangedU64ValueParser::<u8>::new().range(0..=100),
+        default_value = "50",
+        value_name = "PERCENTILE"
+    )]
+    pub energy_price_percentile: u8,
+
+    /// The number of recent blocks used by the `percentile` strategy.
+    #[clap(
+        long,
+        value_parser = RangedU64ValueParser::<u64>::new().range(1..),
+        default_value = "20",
+        value_name = "BLOCKS"
+    )]
+    pub energy_price_blocks: u64,
+
+    /// Replaces transactions which are still pending after this many seconds with the same
+    /// transaction, with the same nonce and a higher energy price.
+    ///
+    /// Requires `--max-energy-price`, so the price can't be raised without bound.
+    #[clap(long, requires = "max_energy_price", value_name = "SECONDS")]
+    pub replace_after: Option<u64>,
+
+    /// The percentage the energy price of a replacement is increased by.
+    #[clap(long, requires = "replace_after", default_value = "12", value_name = "PERCENT")]
+    pub energy_price_bump: u64,
+
+    /// The maximum energy price of replacements.
+    ///
+    /// A transaction whose replacement would cost more is left pending, and the broadcast stops.
+    #[clap(
+        long,
+        requires = "replace_after",
+        value_parser = parse_ether_value,
+        value_name = "PRICE"
+    )]
+    pub max_energy_price: Option<U256>,
+}
+
+impl Default for EnergyPriceArgs {
+    fn default() -> Self {
+        Self {
+            energy_price_strategy: None,
+            energy_price_percentile: 50,
+            energy_price_blocks: 20,
+            replace_after: None,
+            energy_price_bump: 12,
+            max_energy_price: None,
+        }
+    }
+}
+
+impl EnergyPriceArgs {
+    /// Returns the energy price of the replacement of a transaction with the given price, or
+    /// `None` if it exceeds `--max-energy-price`
+    pub fn bump(&self, energy_price: U256) -> Option<U256> {
+        // Nodes only accept replacements with a higher price, so it's increased by at least 1
+        let bumped = energy_price + (energy_price * self.energy_price_bump / 100).max(U256::one());
+        match self.max_energy_price {
+            Some(max) if bumped > max => None,
+            _ => Some(bumped),
+        }
+    }
+}
+
+impl ScriptArgs {
+    /// Returns the energy price of the transactions sent with the provider, according to
+    /// `--energy-price-strategy`
+    pub async fn energy_price(&self, provider: &RetryProvider) -> Result<U256> {
+        let strategy =
+            self.energy.energy_price_strategy.unwrap_or(if self.with_energy_price.is_some() {
+                EnergyPriceStrategy::Fixed
+            } else {
+                EnergyPriceStrategy::Node
+            });
+        match strategy {
+            EnergyPriceStrategy::Fixed => match self.with_energy_price {
+                Some(energy_price) => Ok(energy_price),
+                None => bail!("The `fixed` energy price strategy requires --with-energy-price"),
+            },
+            EnergyPriceStrategy::Node => Ok(provider.get_energy_price().await?),
+            EnergyPriceStrategy::Percentile => {
+                let latest = provider.get_block_number().await?.as_u64();
+                let first = latest.saturating_sub(self.energy.energy_price_blocks - 1);
+                let mut energy_prices = vec![];
+                for number in first..=latest {
+                    if let Some(block) = provider.get_block_with_txs(number).await? {
+                        energy_prices
+                            .extend(block.transactions.iter().filter_map(|tx| tx.energy_price));
+                    }
+                }
+                match percentile(energy_prices, self.energy.energy_price_percentile) {
+                    Some(energy_price) => Ok(energy_price),
+                    // Without recent transactions, there is nothing to go by but the node
+                    None => Ok(provider.get_energy_price().await?),
+                }
+            }
+        }
+    }
+}
+
+/// Returns the energy price below which `percentile` percent of the prices are
+fn percentile(mut energy_prices: Vec<U256>, percentile: u8) -> Option<U256> {
+    if energy_prices.is_empty() {
+        return None
+    }
+    energy_prices.sort_unstable();
+    let index = (energy_prices.len() - 1) * percentile as usize / 100;
+    Some(energy_prices[index])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn can_get_percentile() {
+        let prices = [5u64, 1, 4, 2, 3].into_iter().map(U256::from).collect::<Vec<_>>();
+        assert_eq!(percentile(prices.clone(), 0), Some(1.into()));
+        assert_eq!(percentile(prices.clone(), 50), Some(3.into()));
+        assert_eq!(percentile(prices, 100), Some(5.into()));
+        assert_eq!(percentile(vec![], 50), None);
+    }
+
+    #[test]
+    fn can_bump_energy_price() {
+        let mut args = EnergyPriceArgs::default();
+        assert_eq!(args.bump(100.into()), Some(112.into()));
+        assert_eq!(args.bump(1.into()), Some(2.into()));
+
+        args.max_energy_price = Some(120.into());
+        assert_eq!(args.bump(107.into()), Some(119.into()));
+        assert_eq!(args.bump(110.into()), None);
+    }
+}
@@ -3,15 +3,16 @@ use crate::cmd::{
     ensure_clean_constructor, needs_setup,
     spark::script::{
         artifacts::ArtifactInfo,
+        diff::StateDiff,
         runner::SimulationStage,
         transaction::{AdditionalContract, TransactionWithMetadata},
     },
 };
 use corebc::{
-    types::{transaction::eip2718::TypedTransaction, Address, U256},
+    types::{transaction::eip2718::TypedTransaction, Address, Log, Network, U256},
     ylem::artifacts::CompactContractBytecode,
 };
-use foxar_common::{shell, RpcUrl};
+use foxar_common::{fs, shell, RpcUrl};
 use foxar_evm::utils::evm_spec;
 use futures::future::join_all;
 use parking_lot::RwLock;
@@ -28,7 +29,7 @@ use std::{collections::VecDeque, sync::Arc};
 use tracing::trace;
 
 /// Helper alias type for the processed result of a runner onchain simulation.
-type RunnerResult = (Option<TransactionWithMetadata>, Traces);
+type RunnerResult = (Option<TransactionWithMetadata>, Traces, RpcUrl, Vec<Log>);
 
 impl ScriptArgs {
     /// Locally deploys and executes the contract method that will collect all broadcastable
@@ -56,7 +57,6 @@ impl ScriptArgs {
             needs_setup(&abi),
             script_config.sender_nonce,
             self.broadcast,
-            script_config.evm_opts.fork_url.is_none(),
         )?;
 
         let (func, calldata) = self.get_method_and_calldata(&abi)?;
@@ -74,6 +74,11 @@ impl ScriptArgs {
             result.labeled_addresses.extend(script_result.labeled_addresses);
             result.returned = script_result.returned;
             result.script_wallets.extend(script_result.script_wallets);
+            result.deployments.extend(script_result.deployments);
+            result.coverage = match (result.coverage.take(), script_result.coverage) {
+                (Some(coverage), Some(new_coverage)) => Some(coverage.merge(new_coverage)),
+                (coverage, new_coverage) => coverage.or(new_coverage),
+            };
 
             match (&mut result.transactions, script_result.transactions) {
                 (Some(txs), Some(new_txs)) => {
@@ -102,12 +107,22 @@ impl ScriptArgs {
 
         let runners = Arc::new(
             self.build_runners(script_config)
-                .await
+                .await?
                 .into_iter()
                 .map(|(rpc, runner)| (rpc, Arc::new(RwLock::new(runner))))
                 .collect::<HashMap<_, _>>(),
         );
 
+        // The state before the simulation, to diff against
+        let initial_backends = if self.diff {
+            runners
+                .iter()
+                .map(|(rpc, runner)| (rpc.clone(), runner.read().executor.backend().clone()))
+                .collect()
+        } else {
+            HashMap::new()
+        };
+
         if script_config.evm_opts.verbosity > 3 {
             println!("==========================");
             println!("Simulated On-chain Traces:\n");
@@ -139,10 +154,8 @@ impl ScriptArgs {
         let futs = transactions
             .into_iter()
             .map(|transaction| async {
-                let mut runner = runners
-                    .get(transaction.rpc.as_ref().expect("to have been filled already."))
-                    .expect("to have been built.")
-                    .write();
+                let rpc = transaction.rpc.clone().expect("to have been filled already.");
+                let mut runner = runners.get(&rpc).expect("to have been built.").write();
 
                 let TypedTransaction::Legacy(mut tx) = transaction.transaction;
                 let result = runner
@@ -156,7 +169,7 @@ impl ScriptArgs {
                     .expect("Internal EVM error");
 
                 if !result.success || result.traces.is_empty() {
-                    return Ok((None, result.traces));
+                    return Ok((None, result.traces, rpc, result.logs));
                 }
 
                 let created_contracts = result
@@ -203,16 +216,18 @@ impl ScriptArgs {
                     &self.evm_opts.env.network_id.unwrap(),
                 )?;
 
-                Ok((Some(tx), result.traces))
+                Ok((Some(tx), result.traces, rpc, result.logs))
             })
             .collect::<Vec<_>>();
 
         let mut abort = false;
+        let mut logs: HashMap<RpcUrl, Vec<Log>> = HashMap::new();
         for res in join_all(futs).await {
             // type hint
             let res: eyre::Result<RunnerResult> = res;
 
-            let (tx, mut traces) = res?;
+            let (tx, mut traces, rpc, tx_logs) = res?;
+            logs.entry(rpc).or_default().extend(tx_logs);
 
             // Transaction will be `None`, if execution didn't pass.
             if tx.is_none() || script_config.evm_opts.verbosity > 3 {
@@ -240,14 +255,54 @@ impl ScriptArgs {
             eyre::bail!("Simulated execution failed.")
         }
 
+        if self.diff {
+            self.show_state_diffs(script_config, decoder, &runners, &initial_backends, &logs)?;
+        }
+
         Ok(final_txs)
     }
 
+    /// Prints the state changes of the simulated transactions of every network and optionally
+    /// writes them to the `--diff-out` file.
+    fn show_state_diffs(
+        &self,
+        script_config: &ScriptConfig,
+        decoder: &CallTraceDecoder,
+        runners: &HashMap<RpcUrl, Arc<RwLock<ScriptRunner>>>,
+        initial_backends: &HashMap<RpcUrl, Backend>,
+        logs: &HashMap<RpcUrl, Vec<Log>>,
+    ) -> eyre::Result<()> {
+        let mut diffs = BTreeMap::new();
+        for (rpc, runner) in runners.iter() {
+            let runner = runner.read();
+            let network = Network::from(runner.executor.env().cfg.network_id).to_string();
+            let diff = StateDiff::new(
+                &initial_backends[rpc],
+                runner.executor.backend(),
+                logs.get(rpc).map(Vec::as_slice).unwrap_or_default(),
+                decoder,
+                &script_config.storage_layouts,
+            )?;
+            diffs.insert(network, diff);
+        }
+
+        for (network, diff) in &diffs {
+            shell::println(format!("\n## State diff ({network})\n"))?;
+            shell::println(diff.to_string().trim_end())?;
+        }
+
+        if let Some(out) = &self.diff_out {
+            fs::write_json_file(out, &diffs)?;
+            shell::println(format!("\nState diff written to: {}", out.display()))?;
+        }
+        Ok(())
+    }
+
     /// Build the multiple runners from different forks.
     async fn build_runners(
         &self,
         script_config: &mut ScriptConfig,
-    ) -> HashMap<RpcUrl, ScriptRunner> {
+    ) -> eyre::Result<HashMap<RpcUrl, ScriptRunner>> {
         let sender = script_config.evm_opts.sender;
 
         if !shell::verbosity().is_silent() {
@@ -261,10 +316,12 @@ impl ScriptArgs {
                 let mut script_config = script_config.clone();
                 script_config.evm_opts.fork_url = Some(rpc.clone());
 
-                (
-                    rpc.clone(),
-                    self.prepare_runner(&mut script_config, sender, SimulationStage::OnChain).await,
-                )
+                let mut runner =
+                    self.prepare_runner(&mut script_config, sender, SimulationStage::OnChain).await;
+                // Missing CREATE2 deployers are deployed before broadcasting
+                runner.executor.deploy_create2_deployer()?;
+
+                eyre::Ok((rpc.clone(), runner))
             })
             .collect::<Vec<_>>();
 
@@ -313,6 +370,7 @@ impl ScriptArgs {
         if let SimulationStage::Local = stage {
             builder = builder
                 .set_debugger(self.debug)
+                .set_coverage(!self.coverage.is_empty())
                 .with_cheatcodes(CheatsConfig::new(&script_config.config, &script_config.evm_opts));
         }
 
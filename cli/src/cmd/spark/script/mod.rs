@@ -13,7 +13,7 @@ use corebc::{
         transaction::eip2718::TypedTransaction, Address, Log, NameOrAddress, TransactionRequest,
         U256,
     },
-    ylem::contracts::ArtifactContracts,
+    ylem::{artifacts::StorageLayout, contracts::ArtifactContracts},
 };
 use dialoguer::Confirm;
 use eyre::{ContextCompat, WrapErr};
@@ -31,6 +31,7 @@ use probe::{
     },
 };
 use spark::{
+    coverage::HitMaps,
     debug::DebugArena,
     decode::decode_console_logs,
     executor::{opts::EvmOpts, Backend},
@@ -65,24 +66,38 @@ mod broadcast;
 use ui::{TUIExitReason, Tui, Ui};
 
 mod artifacts;
+mod batch;
+mod bundle;
 mod cmd;
+mod create2;
+mod diff;
+mod energy;
 mod executor;
 mod multi;
+mod preflight;
 mod providers;
 mod receipts;
+mod rehearse;
 mod sequence;
 pub mod transaction;
+mod upgrade;
 mod verify;
 
-use crate::cmd::retry::RetryArgs;
+use crate::cmd::{retry::RetryArgs, spark::coverage::CoverageReportKind};
+pub use bundle::ScriptSubcommands;
 pub use transaction::TransactionWithMetadata;
+pub use verify::VerifyAllArgs;
 
 // Loads project's figment and merges the build cli arguments into it
 foxar_config::merge_impl_figment_convert!(ScriptArgs, opts, evm_opts);
 
 /// CLI arguments for `spark script`.
 #[derive(Debug, Clone, Parser, Default)]
+#[clap(args_conflicts_with_subcommands = true, subcommand_negates_reqs = true)]
 pub struct ScriptArgs {
+    #[clap(subcommand)]
+    pub command: Option<ScriptSubcommands>,
+
     /// The contract you want to run. Either the file path or contract name.
     ///
     /// If multiple contracts exist in the same file you must specify the target contract with
@@ -116,10 +131,77 @@ pub struct ScriptArgs {
     #[clap(long)]
     pub broadcast: bool,
 
+    /// Rehearses the broadcast on a local shuttle node forking the network of the RPC URL, with
+    /// impersonated senders, and prints the receipts and the energy used.
+    ///
+    /// With --broadcast, the transactions are only broadcast if the rehearsal succeeds.
+    #[clap(long, conflicts_with = "resume")]
+    pub rehearse: bool,
+
+    /// Skips the checks of the senders' balances and nonces before broadcasting.
+    ///
+    /// The checks only account for the value the senders transfer to each other directly, not
+    /// the value forwarded by the contracts they call.
+    #[clap(long)]
+    pub skip_preflight: bool,
+
     /// Skips on-chain simulation.
     #[clap(long)]
     pub skip_simulation: bool,
 
+    /// Prints the state changes of the on-chain simulation: balances, nonces, code, storage and
+    /// emitted events.
+    #[clap(long, conflicts_with = "skip_simulation")]
+    pub diff: bool,
+
+    /// Writes the state diff of the on-chain simulation to the file as JSON.
+    #[clap(long, requires = "diff", value_hint = ValueHint::FilePath, value_name = "PATH")]
+    pub diff_out: Option<PathBuf>,
+
+    /// Batches consecutive calls of the same sender to the `--batch-targets` into multicall
+    /// transactions.
+    ///
+    /// Creates, calls with value, calls that create contracts and calls to other contracts are
+    /// sent on their own.
+    #[clap(long, requires_all = &["multicall", "batch_targets"])]
+    pub batch: bool,
+
+    /// The address of the Multicall3 contract used by `--batch`.
+    #[clap(long, value_name = "ADDRESS")]
+    pub multicall: Option<Address>,
+
+    /// The contracts whose calls can be batched by `--batch`.
+    ///
+    /// Batched calls are made by the multicall contract instead of the sender, so only contracts
+    /// which don't depend on `msg.sender` should be listed.
+    #[clap(long, value_delimiter = ',', value_name = "ADDRESSES")]
+    pub batch_targets: Vec<Address>,
+
+    /// Writes the simulated transactions to the file as an unsigned bundle, instead of
+    /// broadcasting them.
+    ///
+    /// The bundle can be signed offline with `spark script sign` and broadcast with
+    /// `spark script publish`.
+    #[clap(
+        long,
+        conflicts_with_all = &["broadcast", "skip_simulation", "resume"],
+        value_hint = ValueHint::FilePath,
+        value_name = "PATH"
+    )]
+    pub export_bundle: Option<PathBuf>,
+
+    /// The presigned transaction deploying the CREATE2 deployer at its canonical address.
+    ///
+    /// It's sent before the first `new Contract{salt: ...}` deployment on networks where the
+    /// deployer is missing. On shuttle nodes the deployer is set up without it.
+    #[clap(long, value_name = "RAW_TX")]
+    pub create2_deployer_tx: Option<Bytes>,
+
+    /// Broadcasts upgrades of proxies even if the storage layout of the new implementation is
+    /// incompatible with the one recorded for the current implementation.
+    #[clap(long)]
+    pub skip_storage_layout_check: bool,
+
     /// Relative percentage to multiply energy estimates by.
     #[clap(long, short, default_value = "130")]
     pub energy_estimate_multiplier: u64,
@@ -188,6 +270,22 @@ pub struct ScriptArgs {
     )]
     pub with_energy_price: Option<U256>,
 
+    #[clap(flatten)]
+    pub energy: energy::EnergyPriceArgs,
+
+    /// Collects coverage while simulating the script and reports it.
+    ///
+    /// The optimizer is disabled for accurate source maps, so the script can't be broadcast,
+    /// resumed or exported as a bundle, as its bytecode differs from the optimized one. This
+    /// flag can be used multiple times.
+    #[clap(
+        long,
+        value_enum,
+        value_name = "REPORT",
+        conflicts_with_all = &["broadcast", "resume", "export_bundle"]
+    )]
+    pub coverage: Vec<CoverageReportKind>,
+
     #[clap(flatten)]
     pub retry: RetryArgs,
 }
@@ -629,6 +727,8 @@ pub struct ScriptResult {
     pub returned: bytes::Bytes,
     pub address: Option<Address>,
     pub script_wallets: Vec<LocalWallet>,
+    pub coverage: Option<HitMaps>,
+    pub deployments: BTreeMap<String, Address>,
 }
 
 #[derive(Serialize, Deserialize)]
@@ -659,6 +759,9 @@ pub struct ScriptConfig {
     pub total_rpcs: HashSet<RpcUrl>,
     /// If true, one of the transactions did not have a rpc
     pub missing_rpc: bool,
+    /// Maps a contract identifier to its storage layout, used to check proxy upgrades, to save
+    /// deployments and to decode the storage slots changed by `--diff`
+    pub storage_layouts: BTreeMap<String, StorageLayout>,
 }
 
 impl ScriptConfig {
@@ -7,6 +7,7 @@ use super::{
 use corebc::{
     prelude::{artifacts::Libraries, ArtifactId},
     signers::LocalWallet,
+    ylem::artifacts::StorageLayout,
 };
 use eyre::{ContextCompat, WrapErr};
 use foxar_common::{fs, get_http_provider};
@@ -14,6 +15,7 @@ use foxar_config::Config;
 use futures::future::join_all;
 use serde::{Deserialize, Serialize};
 use std::{
+    collections::BTreeMap,
     io::BufWriter,
     path::{Path, PathBuf},
     sync::Arc,
@@ -98,6 +100,12 @@ impl MultiChainSequence {
         corebc::ylem::utils::read_json_file(path).wrap_err("Multi-chain deployment not found.")
     }
 
+    /// Loads the sequences of the multi chain deployment logged at `path`.
+    pub fn load_from_path(path: &Path) -> eyre::Result<Self> {
+        corebc::ylem::utils::read_json_file(path)
+            .wrap_err_with(|| format!("Multi-chain deployment not found at {}.", path.display()))
+    }
+
     /// Saves the transactions as file if it's a standalone deployment.
     pub fn save(&mut self) -> eyre::Result<()> {
         self.timestamp = SystemTime::now().duration_since(UNIX_EPOCH)?.as_secs();
@@ -127,6 +135,7 @@ impl ScriptArgs {
         mut deployments: MultiChainSequence,
         libraries: Libraries,
         config: &Config,
+        storage_layouts: &BTreeMap<String, StorageLayout>,
         script_wallets: Vec<LocalWallet>,
         verify: VerifyBundle,
     ) -> eyre::Result<()> {
@@ -134,7 +143,8 @@ impl ScriptArgs {
             eyre::bail!("Libraries are currently not supported on multi deployment setups.");
         }
 
-        if self.resume {
+        // With `--replace-after`, stuck transactions are replaced while sending the others
+        if self.resume && self.energy.replace_after.is_none() {
             trace!(target: "script", "resuming multi chain deployment");
 
             let futs = deployments
@@ -171,6 +181,11 @@ impl ScriptArgs {
                     .await
                 {
                     Ok(_) => {
+                        sequence.save_deployments(
+                            config,
+                            &verify.known_contracts,
+                            storage_layouts,
+                        )?;
                         if self.verify {
                             return sequence.verify_contracts(config, verify.clone()).await;
                         }
@@ -0,0 +1,235 @@
+//! Checks of the network and the senders before broadcasting a script
+
+use super::sequence::ScriptSequence;
+use corebc::{
+    providers::Middleware,
+    types::{transaction::eip2718::TypedTransaction, Address, BlockNumber, NameOrAddress, U256},
+    utils::format_units,
+};
+use eyre::Result;
+use foxar_common::RetryProvider;
+use std::collections::BTreeMap;
+
+/// The on-chain state of a sender
+#[derive(Debug, Clone, Copy, Default)]
+struct SenderState {
+    balance: U256,
+    /// The next nonce, including pending transactions
+    nonce: U256,
+}
+
+/// Checks that the transactions of the sequence which haven't been broadcasted yet can be sent
+/// as simulated, before sending the first one.
+///
+/// The RPC endpoint must be on the network the script was simulated on, every sender must afford
+/// the energy and value of its transactions at their energy limit, and the nonces of its
+/// transactions must follow its pending nonce without gaps. All failed checks are reported at
+/// once.
+pub async fn preflight_checks(
+    provider: &RetryProvider,
+    sequence: &ScriptSequence,
+    already_broadcasted: usize,
+) -> Result<()> {
+    let mut issues = vec![];
+
+    let network = provider.get_networkid().await?.as_u64();
+    if network != u64::from(sequence.network) {
+        issues.push(format!(
+            "The RPC endpoint is on network {network}, but the script was simulated on network {}. Use the RPC URL of network {} instead.",
+            sequence.network, sequence.network
+        ));
+    }
+
+    let transactions = sequence
+        .transactions
+        .iter()
+        .skip(already_broadcasted)
+        .map(|tx| tx.typed_tx())
+        .collect::<Vec<_>>();
+
+    let mut states = BTreeMap::new();
+    for sender in transactions.iter().filter_map(|tx| tx.from().copied()) {
+        if states.contains_key(&sender) {
+            continue
+        }
+        let state = SenderState {
+            balance: provider.get_balance(sender, None).await?,
+            nonce: provider
+                .get_transaction_count(sender, Some(BlockNumber::Pending.into()))
+                .await?,
+        };
+        states.insert(sender, state);
+    }
+
+    issues.extend(check_senders(&transactions, &states));
+
+    if !issues.is_empty() {
+        eyre::bail!(
+            "Preflight checks failed, no transaction was sent:\n\n{}",
+            issues.iter().map(|issue| format!("  - {issue}")).collect::<Vec<_>>().join("\n")
+        )
+    }
+    Ok(())
+}
+
+/// Checks the balances and nonces of the senders against their transactions, in order.
+///
+/// The value a sender transfers to another sender of the script is available to the receiver's
+/// following transactions.
+fn check_senders(
+    transactions: &[&TypedTransaction],
+    states: &BTreeMap<Address, SenderState>,
+) -> Vec<String> {
+    let mut balances: BTreeMap<Address, Balance> = BTreeMap::new();
+    let mut nonces: BTreeMap<Address, Vec<U256>> = BTreeMap::new();
+    for tx in transactions {
+        let Some(from) = tx.from().copied() else { continue };
+        let energy = tx.energy().copied().unwrap_or_default();
+        let value = tx.value().copied().unwrap_or_default();
+        let cost =
+            energy.saturating_mul(tx.energy_price().unwrap_or_default()).saturating_add(value);
+        let initial = states.get(&from).map(|state| state.balance).unwrap_or_default();
+        balances.entry(from).or_default().spend(initial, cost);
+
+        if let Some(NameOrAddress::Address(to)) = tx.to() {
+            if states.contains_key(to) {
+                let balance = balances.entry(*to).or_default();
+                balance.received = balance.received.saturating_add(value);
+            }
+        }
+        if let Some(nonce) = tx.nonce() {
+            nonces.entry(from).or_default().push(*nonce);
+        }
+    }
+
+    let mut issues = vec![];
+    for (sender, state) in states {
+        let balance = balances.get(sender).copied().unwrap_or_default();
+        if !balance.shortfall.is_zero() {
+            let received = if balance.received.is_zero() {
+                String::new()
+            } else {
+                format!(" and receives {} ETH from the other senders", format_eth(balance.received))
+            };
+            issues.push(format!(
+                "{sender:?} needs up to {} ETH for the energy and value of its transactions, but has {} ETH{received}. Fund it with at least {} ETH.",
+                format_eth(balance.spent),
+                format_eth(state.balance),
+                format_eth(balance.shortfall)
+            ));
+        }
+
+        let Some(sender_nonces) = nonces.get(sender) else { continue };
+        let first = sender_nonces[0];
+        if first > state.nonce {
+            issues.push(format!(
+                "The first transaction of {sender:?} has nonce {first}, but its next nonce is {}. Send or cancel the transactions with the missing nonces first.",
+                state.nonce
+            ));
+        } else if first < state.nonce {
+            issues.push(format!(
+                "The first transaction of {sender:?} has nonce {first}, but its next nonce is {}: the account sent other transactions since the simulation. Run the script again, without --resume, to simulate it with the current nonce.",
+                state.nonce
+            ));
+        }
+        if let Some(pair) = sender_nonces.windows(2).find(|pair| pair[1] != pair[0] + U256::one()) {
+            issues.push(format!(
+                "The nonces of the transactions of {sender:?} aren't consecutive, {} is followed by {}. Run the script again to simulate it with the current nonces.",
+                pair[0], pair[1]
+            ));
+        }
+    }
+    issues
+}
+
+/// The running balance of a sender over the transactions of the sequence
+#[derive(Debug, Clone, Copy, Default)]
+struct Balance {
+    /// The energy and value of its transactions so far, at their energy limit
+    spent: U256,
+    /// The value transferred to it by the other senders so far
+    received: U256,
+    /// The most its balance fell short of its transactions so far
+    shortfall: U256,
+}
+
+impl Balance {
+    fn spend(&mut self, initial: U256, cost: U256) {
+        self.spent = self.spent.saturating_add(cost);
+        let available = initial.saturating_add(self.received);
+        if self.spent > available {
+            self.shortfall = self.shortfall.max(self.spent - available);
+        }
+    }
+}
+
+fn format_eth(amount: U256) -> String {
+    format_units(amount, 18)
+        .map(|eth| eth.trim_end_matches('0').trim_end_matches('.').to_string())
+        .unwrap_or_else(|_| amount.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use corebc::types::TransactionRequest;
+
+    fn tx(from: u8, nonce: u64, value: u64) -> TypedTransaction {
+        TypedTransaction::Legacy(
+            TransactionRequest::new()
+                .from(Address::repeat_byte(from))
+                .nonce(nonce)
+                .energy(100)
+                .energy_price(10)
+                .value(value),
+        )
+    }
+
+    #[test]
+    fn can_check_senders() {
+        let states = BTreeMap::from([
+            (Address::repeat_byte(1), SenderState { balance: 3_000.into(), nonce: 5.into() }),
+            (Address::repeat_byte(2), SenderState { balance: 999.into(), nonce: 0.into() }),
+        ]);
+
+        let transactions = [tx(1, 5, 0), tx(1, 6, 1_000), tx(2, 0, 0)];
+        let transactions = transactions.iter().collect::<Vec<_>>();
+        assert_eq!(check_senders(&transactions, &states).len(), 1);
+
+        let transactions = [tx(1, 4, 0), tx(1, 6, 2_000), tx(2, 1, 0)];
+        let transactions = transactions.iter().collect::<Vec<_>>();
+        let issues = check_senders(&transactions, &states);
+        assert_eq!(issues.len(), 5);
+        assert!(issues[0].contains("needs up to"));
+        assert!(issues[1].contains("sent other transactions since the simulation"));
+        assert!(issues[2].contains("aren't consecutive, 4 is followed by 6"));
+        assert!(issues[3].contains("needs up to"));
+        assert!(issues[4].contains("Send or cancel the transactions with the missing nonces"));
+    }
+
+    #[test]
+    fn can_check_transfers_between_senders() {
+        let transfer = |from: u8, to: u8, nonce: u64, value: u64| {
+            let mut tx = tx(from, nonce, value);
+            tx.set_to(Address::repeat_byte(to));
+            tx
+        };
+        let states = BTreeMap::from([
+            (Address::repeat_byte(1), SenderState { balance: 5_000.into(), nonce: 0.into() }),
+            (Address::repeat_byte(2), SenderState { balance: 0.into(), nonce: 0.into() }),
+        ]);
+
+        // The second sender is funded by the first one before sending its transaction
+        let transactions = [transfer(1, 2, 0, 2_000), tx(2, 0, 1_000)];
+        let transactions = transactions.iter().collect::<Vec<_>>();
+        assert!(check_senders(&transactions, &states).is_empty());
+
+        // The funds arrive after its transaction was sent
+        let transactions = [tx(2, 0, 1_000), transfer(1, 2, 0, 2_000)];
+        let transactions = transactions.iter().collect::<Vec<_>>();
+        let issues = check_senders(&transactions, &states);
+        assert_eq!(issues.len(), 1);
+        assert!(issues[0].contains("receives 0.000000000000002 ETH from the other senders"));
+        assert!(issues[0].contains("Fund it with at least 0.000000000000002 ETH"));
+    }
+}
@@ -5,20 +5,35 @@ use crate::{
 use corebc::{
     prelude::{PendingTransaction, TxHash},
     providers::Middleware,
-    types::TransactionReceipt,
+    types::{TransactionReceipt, U256},
 };
 use foxar_common::RetryProvider;
 use futures::StreamExt;
-use std::sync::Arc;
+use serde::{Deserialize, Serialize};
+use std::{sync::Arc, time::Duration};
 use tracing::{trace, warn};
 
 /// Convenience enum for internal signalling of transaction status
 enum TxStatus {
     Dropped,
+    /// Still pending when the timeout ran out
+    Pending,
     Success(TransactionReceipt),
     Revert(TransactionReceipt),
 }
 
+/// A pending transaction which was sent again, with the same nonce and a higher energy price
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Replacement {
+    /// The hash of the replaced transaction
+    pub replaced: TxHash,
+    /// The hash of the replacement
+    pub hash: TxHash,
+    /// The energy price of the replacement
+    pub energy_price: U256,
+}
+
 impl From<TransactionReceipt> for TxStatus {
     fn from(receipt: TransactionReceipt) -> Self {
         let status = receipt.status.expect("receipt is from an ancient, pre-EIP658 block");
@@ -59,16 +74,36 @@ pub async fn clear_pendings(
     deployment_sequence: &mut ScriptSequence,
     tx_hashes: Option<Vec<TxHash>>,
 ) -> eyre::Result<()> {
+    clear_pendings_within(provider, deployment_sequence, tx_hashes, None).await?;
+    Ok(())
+}
+
+/// Like [clear_pendings], but stops waiting for the receipt of a transaction after `timeout`.
+///
+/// Returns the hashes of the transactions which are still pending, and are kept in
+/// `deployment_sequence.pending`.
+pub async fn clear_pendings_within(
+    provider: Arc<RetryProvider>,
+    deployment_sequence: &mut ScriptSequence,
+    tx_hashes: Option<Vec<TxHash>>,
+    timeout: Option<Duration>,
+) -> eyre::Result<Vec<TxHash>> {
     let to_query = tx_hashes.unwrap_or_else(|| deployment_sequence.pending.clone());
 
     let count = deployment_sequence.pending.len();
 
     trace!("Checking status of {count} pending transactions");
 
-    let futs = to_query.iter().copied().map(|tx| check_tx_status(&provider, tx));
+    // A replaced transaction can still be mined instead of its replacement
+    let futs = to_query
+        .iter()
+        .map(|tx| replaced_hashes(deployment_sequence, *tx))
+        .map(|hashes| check_tx_status(&provider, hashes, timeout))
+        .collect::<Vec<_>>();
     let mut tasks = futures::stream::iter(futs).buffer_unordered(10);
 
     let mut errors: Vec<String> = vec![];
+    let mut still_pending = vec![];
     let mut receipts = Vec::<TransactionReceipt>::with_capacity(count);
 
     // set up progress bar
@@ -86,9 +121,14 @@ pub async fn clear_pendings(
                 deployment_sequence.remove_pending(tx_hash);
                 errors.push(format!("Transaction dropped from the mempool: {tx_hash:?}"));
             }
+            Ok(TxStatus::Pending) => {
+                trace!(tx_hash = ?tx_hash, "still pending");
+                still_pending.push(tx_hash);
+            }
             Ok(TxStatus::Success(receipt)) => {
                 trace!(tx_hash = ?tx_hash, "received tx receipt");
-                deployment_sequence.remove_pending(receipt.transaction_hash);
+                deployment_sequence.remove_pending(tx_hash);
+                restore_mined_hash(deployment_sequence, tx_hash, &receipt);
                 receipts.push(receipt);
             }
             Ok(TxStatus::Revert(receipt)) => {
@@ -96,7 +136,8 @@ pub async fn clear_pendings(
                 // if this is not removed from pending, then the script becomes
                 // un-resumable. Is this desirable on reverts?
                 warn!(tx_hash = ?tx_hash, "Transaction Failure");
-                deployment_sequence.remove_pending(receipt.transaction_hash);
+                deployment_sequence.remove_pending(tx_hash);
+                restore_mined_hash(deployment_sequence, tx_hash, &receipt);
                 errors.push(format!("Transaction Failure: {:?}", receipt.transaction_hash));
             }
         }
@@ -124,33 +165,159 @@ pub async fn clear_pendings(
         eyre::bail!(error_msg);
     }
 
-    Ok(())
+    Ok(still_pending)
+}
+
+/// Replaces the pending transaction `replaced` with the transaction `hash`, which was sent with the
+/// same nonce and the higher `energy_price`, and records the replacement in the sequence
+pub fn replace_pending(
+    deployment_sequence: &mut ScriptSequence,
+    replaced: TxHash,
+    hash: TxHash,
+    energy_price: U256,
+) {
+    if let Some(tx) =
+        deployment_sequence.transactions.iter_mut().find(|tx| tx.hash == Some(replaced))
+    {
+        tx.hash = Some(hash);
+        tx.typed_tx_mut().set_energy_price(energy_price);
+    }
+    deployment_sequence.remove_pending(replaced);
+    deployment_sequence.pending.push(hash);
+    deployment_sequence.replacements.push(Replacement { replaced, hash, energy_price });
+}
+
+/// Returns the hash followed by the hashes of the transactions it replaced, latest first
+fn replaced_hashes(deployment_sequence: &ScriptSequence, hash: TxHash) -> Vec<TxHash> {
+    let mut hashes = vec![hash];
+    while let Some(replacement) =
+        deployment_sequence.replacements.iter().find(|r| Some(&r.hash) == hashes.last())
+    {
+        hashes.push(replacement.replaced);
+    }
+    hashes
+}
+
+/// Points the transaction sent as `tx_hash` to the transaction which was actually mined, in case
+/// a transaction it replaced was mined instead
+fn restore_mined_hash(
+    deployment_sequence: &mut ScriptSequence,
+    tx_hash: TxHash,
+    receipt: &TransactionReceipt,
+) {
+    if receipt.transaction_hash != tx_hash {
+        if let Some(tx) =
+            deployment_sequence.transactions.iter_mut().find(|tx| tx.hash == Some(tx_hash))
+        {
+            tx.hash = Some(receipt.transaction_hash);
+        }
+    }
 }
 
 /// Checks the status of a txhash by first polling for a receipt, then for
-/// mempool inclusion. Returns the tx hash, and a status
+/// mempool inclusion. Returns the tx hash, and a status.
+///
+/// `hashes` holds the txhash followed by the hashes of the transactions it replaced, whose
+/// receipts are checked too. With a `timeout`, a transaction still pending after it is reported
+/// as such.
 async fn check_tx_status(
     provider: &RetryProvider,
-    hash: TxHash,
+    hashes: Vec<TxHash>,
+    timeout: Option<Duration>,
 ) -> (TxHash, Result<TxStatus, eyre::Report>) {
+    let hash = hashes[0];
+
     // We use the inner future so that we can use ? operator in the future, but
     // still neatly return the tuple
     let result = async move {
         // First check if there's a receipt
-        let receipt_opt = provider.get_transaction_receipt(hash).await?;
-        if let Some(receipt) = receipt_opt {
-            return Ok(receipt.into());
+        for hash in &hashes {
+            if let Some(receipt) = provider.get_transaction_receipt(*hash).await? {
+                return Ok(receipt.into());
+            }
         }
 
         // If the tx is present in the mempool, run the pending tx future, and
         // assume the next drop is really really real
-        let pending_res = PendingTransaction::new(hash, provider).await?;
+        let pending = PendingTransaction::new(hash, provider);
+        let pending_res = match timeout {
+            Some(timeout) => match tokio::time::timeout(timeout, pending).await {
+                Ok(res) => res?,
+                Err(_) => return Ok(TxStatus::Pending),
+            },
+            None => pending.await?,
+        };
         match pending_res {
             Some(receipt) => Ok(receipt.into()),
-            None => Ok(TxStatus::Dropped),
+            None => {
+                // The replacement is dropped if a replaced transaction got mined meanwhile
+                for hash in &hashes[1..] {
+                    if let Some(receipt) = provider.get_transaction_receipt(*hash).await? {
+                        return Ok(receipt.into());
+                    }
+                }
+                Ok(TxStatus::Dropped)
+            }
         }
     }
     .await;
 
     (hash, result)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cmd::spark::script::transaction::TransactionWithMetadata;
+
+    fn sequence(hash: TxHash) -> ScriptSequence {
+        ScriptSequence {
+            transactions: vec![TransactionWithMetadata { hash: Some(hash), ..Default::default() }]
+                .into(),
+            pending: vec![hash],
+            // Not saved to disk when dropped
+            multi: true,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn can_replace_pending_transactions() {
+        let [original, first, second] = [1u8, 2, 3].map(TxHash::repeat_byte);
+        let mut sequence = sequence(original);
+
+        replace_pending(&mut sequence, original, first, 112.into());
+        replace_pending(&mut sequence, first, second, 125.into());
+        assert_eq!(sequence.pending, [second]);
+        assert_eq!(sequence.transactions[0].hash, Some(second));
+        assert_eq!(sequence.transactions[0].typed_tx().energy_price(), Some(U256::from(125)));
+        assert_eq!(
+            sequence.replacements,
+            [
+                Replacement { replaced: original, hash: first, energy_price: 112.into() },
+                Replacement { replaced: first, hash: second, energy_price: 125.into() },
+            ]
+        );
+
+        assert_eq!(replaced_hashes(&sequence, second), [second, first, original]);
+        assert_eq!(replaced_hashes(&sequence, first), [first, original]);
+        assert_eq!(replaced_hashes(&sequence, original), [original]);
+    }
+
+    #[test]
+    fn can_restore_mined_hash() {
+        let [original, replacement] = [1u8, 2].map(TxHash::repeat_byte);
+        let mut sequence = sequence(original);
+        replace_pending(&mut sequence, original, replacement, 112.into());
+
+        // The replacement was mined
+        let receipt = TransactionReceipt { transaction_hash: replacement, ..Default::default() };
+        restore_mined_hash(&mut sequence, replacement, &receipt);
+        assert_eq!(sequence.transactions[0].hash, Some(replacement));
+
+        // The original was mined, after its replacement was sent
+        let receipt = TransactionReceipt { transaction_hash: original, ..Default::default() };
+        restore_mined_hash(&mut sequence, replacement, &receipt);
+        assert_eq!(sequence.transactions[0].hash, Some(original));
+    }
+}
@@ -0,0 +1,166 @@
+//! Rehearsals of the broadcast of a script on local forks of its networks
+
+use super::{
+    create2::check_create2_deployments, preflight::preflight_checks, sequence::ScriptSequence,
+    transaction::TransactionWithMetadata, ScriptArgs,
+};
+use crate::utils::print_receipt;
+use corebc::{
+    providers::Middleware,
+    types::{TransactionReceipt, U256},
+    utils::format_units,
+};
+use eyre::{bail, ContextCompat, Result, WrapErr};
+use foxar_common::{shell, try_get_http_provider};
+use probe::CallKind;
+use shuttle::NodeConfig;
+
+impl ScriptArgs {
+    /// Broadcasts the transactions of every sequence on a local shuttle node forking the network
+    /// of its RPC URL, with impersonated senders, and prints their receipts and the energy they
+    /// used.
+    ///
+    /// The transactions are priced like the real broadcast, and go through the same preflight and
+    /// CREATE2 checks. Fails at the first failed check or reverted transaction.
+    pub async fn rehearse(&self, sequences: &[ScriptSequence]) -> Result<()> {
+        for sequence in sequences {
+            let Some(rpc) = sequence.transactions.front().and_then(|tx| tx.rpc.clone()) else {
+                continue
+            };
+            // The rehearsed copy mustn't overwrite the logs of the sequence
+            let mut rehearsed = sequence.clone();
+            rehearsed.multi = true;
+            self.rehearse_sequence(rehearsed, &rpc)
+                .await
+                .wrap_err_with(|| format!("Rehearsal on network {} failed", sequence.network))?;
+        }
+        Ok(())
+    }
+
+    async fn rehearse_sequence(&self, mut sequence: ScriptSequence, rpc: &str) -> Result<()> {
+        shell::println(format!(
+            "##\nRehearsing {} transactions on a fork of network {}.",
+            sequence.transactions.len(),
+            sequence.network
+        ))?;
+
+        let energy_price = self.energy_price(&try_get_http_provider(rpc)?).await?;
+        for tx in sequence.transactions.iter_mut() {
+            let tx = tx.typed_tx_mut();
+            tx.set_network_id(sequence.network);
+            tx.set_energy_price(energy_price);
+        }
+
+        // The node is shut down when its handle is dropped
+        let (api, handle) = shuttle::spawn(NodeConfig::test().with_eth_rpc_url(Some(rpc))).await;
+        api.shuttle_auto_impersonate_account(true).await?;
+        let provider = try_get_http_provider(handle.http_endpoint())?;
+
+        if !self.skip_preflight {
+            preflight_checks(&provider, &sequence, 0).await?;
+        }
+        check_create2_deployments(&provider, &sequence, 0, self.create2_deployer_tx.as_ref())
+            .await?;
+
+        let mut receipts = vec![];
+        for (index, tx) in sequence.transactions.iter().enumerate() {
+            let receipt = provider
+                .send_transaction(tx.typed_tx().clone(), None)
+                .await?
+                .await?
+                .wrap_err_with(|| format!("Transaction {index} was dropped"))?;
+            print_receipt(sequence.network, &receipt);
+            if receipt.status.unwrap_or_default().is_zero() {
+                bail!("Transaction {index}, {}, reverted", describe(tx))
+            }
+            receipts.push(receipt);
+        }
+
+        print_energy_summary(&sequence, &receipts, energy_price)
+    }
+}
+
+/// Prints the energy used by each transaction against its limit, and the total cost
+fn print_energy_summary(
+    sequence: &ScriptSequence,
+    receipts: &[TransactionReceipt],
+    energy_price: U256,
+) -> Result<()> {
+    shell::println("\n==========================")?;
+    shell::println(format!("\nRehearsal on network {}\n", sequence.network))?;
+
+    let mut total_energy = U256::zero();
+    for (tx, receipt) in sequence.transactions.iter().zip(receipts) {
+        let energy_used = receipt.energy_used.unwrap_or_default();
+        total_energy += energy_used;
+        shell::println(format!(
+            "{}: {energy_used} energy of {} limit",
+            describe(tx),
+            tx.typed_tx().energy().copied().unwrap_or_default()
+        ))?;
+    }
+
+    let format = |amount: U256, decimals: u32| {
+        format_units(amount, decimals)
+            .map(|amount| amount.trim_end_matches('0').trim_end_matches('.').to_string())
+            .unwrap_or_else(|_| "[Could not calculate]".to_string())
+    };
+    shell::println(format!("\nTotal energy used: {total_energy}"))?;
+    shell::println(format!(
+        "Total cost: {} ETH ({total_energy} energy * {} gwei)",
+        format(total_energy.saturating_mul(energy_price), 18),
+        format(energy_price, 9)
+    ))?;
+    shell::println("\n==========================")?;
+    Ok(())
+}
+
+/// Describes the transaction by the contract it creates or the function it calls
+fn describe(tx: &TransactionWithMetadata) -> String {
+    let contract = tx.contract_name.clone().filter(|name| !name.is_empty());
+    match tx.opcode {
+        CallKind::Create | CallKind::Create2 => {
+            format!("new {}", contract.unwrap_or_else(|| "contract".to_string()))
+        }
+        _ => {
+            let function = tx.function.clone().filter(|function| !function.is_empty());
+            match (contract, function) {
+                (Some(contract), Some(function)) => format!("{contract}::{function}"),
+                (None, Some(function)) => function,
+                (contract, None) => format!(
+                    "call to {}",
+                    contract.unwrap_or_else(|| format!(
+                        "{:?}",
+                        tx.contract_address.unwrap_or_default()
+                    ))
+                ),
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use corebc::types::{transaction::eip2718::TypedTransaction, Address, TransactionRequest};
+
+    fn tx(opcode: CallKind, contract_name: &str, function: &str) -> TransactionWithMetadata {
+        TransactionWithMetadata {
+            opcode,
+            contract_name: Some(contract_name.to_string()),
+            function: Some(function.to_string()),
+            transaction: TypedTransaction::Legacy(
+                TransactionRequest::new().to(Address::repeat_byte(1)),
+            ),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn can_describe_transactions() {
+        assert_eq!(describe(&tx(CallKind::Create, "Counter", "")), "new Counter");
+        assert_eq!(describe(&tx(CallKind::Call, "Counter", "increment()")), "Counter::increment()");
+        assert_eq!(describe(&tx(CallKind::Call, "", "increment()")), "increment()");
+        assert_eq!(describe(&tx(CallKind::Call, "Counter", "")), "call to Counter");
+    }
+}
@@ -35,21 +35,18 @@ impl ScriptRunner {
         setup: bool,
         sender_nonce: U256,
         is_broadcast: bool,
-        need_create2_deployer: bool,
     ) -> eyre::Result<(Address, ScriptResult)> {
         trace!(target: "script", "executing setUP()");
 
-        if !is_broadcast {
-            if self.sender == Config::DEFAULT_SENDER {
-                // We max out their balance so that they can deploy and make calls.
-                self.executor.set_balance(self.sender, U256::MAX)?;
-            }
-
-            if need_create2_deployer {
-                self.executor.deploy_create2_deployer()?;
-            }
+        if !is_broadcast && self.sender == Config::DEFAULT_SENDER {
+            // We max out their balance so that they can deploy and make calls.
+            self.executor.set_balance(self.sender, U256::MAX)?;
         }
 
+        // Networks without the CREATE2 deployer get it before the first CREATE2 deployment is
+        // broadcast, see `check_create2_deployments`
+        self.executor.deploy_create2_deployer()?;
+
         self.executor.set_nonce(self.sender, sender_nonce.as_u64())?;
 
         // We max out their balance so that they can deploy and make calls.
@@ -85,20 +82,56 @@ impl ScriptRunner {
         self.executor.set_balance(address, self.initial_balance)?;
 
         // Optionally call the `setUp` function
-        let (success, energy_used, labeled_addresses, transactions, debug, script_wallets) =
-            if !setup {
-                self.executor.backend_mut().set_test_contract(address);
-                (
-                    true,
-                    0,
-                    Default::default(),
-                    None,
-                    vec![constructor_debug].into_iter().collect(),
-                    vec![],
-                )
-            } else {
-                match self.executor.setup(Some(self.sender), address) {
-                    Ok(CallResult {
+        let (
+            success,
+            energy_used,
+            labeled_addresses,
+            transactions,
+            debug,
+            script_wallets,
+            coverage,
+        ) = if !setup {
+            self.executor.backend_mut().set_test_contract(address);
+            (
+                true,
+                0,
+                Default::default(),
+                None,
+                vec![constructor_debug].into_iter().collect(),
+                vec![],
+                None,
+            )
+        } else {
+            match self.executor.setup(Some(self.sender), address) {
+                Ok(CallResult {
+                    reverted,
+                    traces: setup_traces,
+                    labels,
+                    logs: setup_logs,
+                    debug,
+                    energy_used,
+                    transactions,
+                    script_wallets,
+                    coverage,
+                    ..
+                }) => {
+                    traces.extend(setup_traces.map(|traces| (TraceKind::Setup, traces)));
+                    logs.extend_from_slice(&setup_logs);
+
+                    self.maybe_correct_nonce(sender_nonce, libraries.len())?;
+
+                    (
+                        !reverted,
+                        energy_used,
+                        labels,
+                        transactions,
+                        vec![constructor_debug, debug].into_iter().collect(),
+                        script_wallets,
+                        coverage,
+                    )
+                }
+                Err(EvmError::Execution(err)) => {
+                    let ExecutionErr {
                         reverted,
                         traces: setup_traces,
                         labels,
@@ -107,51 +140,27 @@ impl ScriptRunner {
                         energy_used,
                         transactions,
                         script_wallets,
+                        coverage,
                         ..
-                    }) => {
-                        traces.extend(setup_traces.map(|traces| (TraceKind::Setup, traces)));
-                        logs.extend_from_slice(&setup_logs);
-
-                        self.maybe_correct_nonce(sender_nonce, libraries.len())?;
-
-                        (
-                            !reverted,
-                            energy_used,
-                            labels,
-                            transactions,
-                            vec![constructor_debug, debug].into_iter().collect(),
-                            script_wallets,
-                        )
-                    }
-                    Err(EvmError::Execution(err)) => {
-                        let ExecutionErr {
-                            reverted,
-                            traces: setup_traces,
-                            labels,
-                            logs: setup_logs,
-                            debug,
-                            energy_used,
-                            transactions,
-                            script_wallets,
-                            ..
-                        } = *err;
-                        traces.extend(setup_traces.map(|traces| (TraceKind::Setup, traces)));
-                        logs.extend_from_slice(&setup_logs);
+                    } = *err;
+                    traces.extend(setup_traces.map(|traces| (TraceKind::Setup, traces)));
+                    logs.extend_from_slice(&setup_logs);
 
-                        self.maybe_correct_nonce(sender_nonce, libraries.len())?;
+                    self.maybe_correct_nonce(sender_nonce, libraries.len())?;
 
-                        (
-                            !reverted,
-                            energy_used,
-                            labels,
-                            transactions,
-                            vec![constructor_debug, debug].into_iter().collect(),
-                            script_wallets,
-                        )
-                    }
-                    Err(e) => return Err(e.into()),
+                    (
+                        !reverted,
+                        energy_used,
+                        labels,
+                        transactions,
+                        vec![constructor_debug, debug].into_iter().collect(),
+                        script_wallets,
+                        coverage,
+                    )
                 }
-            };
+                Err(e) => return Err(e.into()),
+            }
+        };
 
         Ok((
             address,
@@ -166,6 +175,8 @@ impl ScriptRunner {
                 debug,
                 address: None,
                 script_wallets,
+                coverage,
+                deployments: Default::default(),
             },
         ))
     }
@@ -245,6 +256,8 @@ impl ScriptRunner {
                 transactions: Default::default(),
                 address: Some(address),
                 script_wallets: vec![],
+                coverage: None,
+                deployments: Default::default(),
             })
         } else {
             eyre::bail!("ENS not supported.");
@@ -287,6 +300,8 @@ impl ScriptRunner {
             debug,
             transactions,
             script_wallets,
+            coverage,
+            cheatcodes,
             ..
         } = res;
 
@@ -307,6 +322,8 @@ impl ScriptRunner {
             transactions,
             address: None,
             script_wallets,
+            coverage,
+            deployments: cheatcodes.map(|cheatcodes| cheatcodes.deployments).unwrap_or_default(),
         })
     }
 
@@ -2,21 +2,28 @@ use super::NestedValue;
 use crate::cmd::spark::{
     init::get_commit_hash,
     script::{
+        receipts::Replacement,
         transaction::{wrapper, AdditionalContract, TransactionWithMetadata},
-        verify::VerifyBundle,
+        verify::{ContractVerification, VerificationStatus, VerifyBundle},
     },
 };
 use corebc::{
     abi::Address,
     prelude::{artifacts::Libraries, ArtifactId, TransactionReceipt, TxHash},
-    types::{transaction::eip2718::TypedTransaction, Network},
+    types::{transaction::eip2718::TypedTransaction, Network, H256},
+    utils::sha3,
+    ylem::artifacts::StorageLayout,
 };
 use eyre::{ContextCompat, WrapErr};
-use foxar_common::{fs, shell, SELECTOR_LEN};
+use foxar_common::{
+    deployments::{Deployment, DeploymentRegistry, YlemSettings},
+    fs, shell, ContractsByArtifact, SELECTOR_LEN,
+};
 use foxar_config::Config;
+use probe::CallKind;
 use serde::{Deserialize, Serialize};
 use std::{
-    collections::{HashMap, VecDeque},
+    collections::{BTreeMap, HashMap, VecDeque},
     io::BufWriter,
     path::{Path, PathBuf},
     time::{SystemTime, UNIX_EPOCH},
@@ -45,6 +52,15 @@ pub struct ScriptSequence {
     /// If `True`, the sequence belongs to a `MultiChainSequence` and won't save to disk as usual.
     pub multi: bool,
     pub commit: Option<String>,
+    /// The deployments named with `saveDeployment`
+    #[serde(default)]
+    pub deployments: BTreeMap<String, Address>,
+    /// The stuck transactions which were replaced with a higher energy price, in order
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub replacements: Vec<Replacement>,
+    /// The verification state of the created contracts, recorded by `--verify` and `verify-all`
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub verifications: Vec<ContractVerification>,
 }
 
 /// Sensitive values from the transactions in a script sequence
@@ -87,7 +103,7 @@ impl ScriptSequence {
             &config.broadcast,
             &config.cache_path,
             sig,
-            target,
+            &target.source,
             chain,
             broadcasted && !is_multi,
         )?;
@@ -109,6 +125,9 @@ impl ScriptSequence {
             network: chain,
             multi: is_multi,
             commit,
+            deployments: Default::default(),
+            replacements: vec![],
+            verifications: vec![],
         })
     }
 
@@ -124,7 +143,7 @@ impl ScriptSequence {
             &config.broadcast,
             &config.cache_path,
             sig,
-            target,
+            &target.source,
             network_id,
             broadcasted,
         )?;
@@ -149,6 +168,28 @@ impl ScriptSequence {
         Ok(script_sequence)
     }
 
+    /// Loads the sequence logged at `path`, with the sensitive values at `sensitive_path`
+    pub fn load_from_paths(path: &Path, sensitive_path: &Path) -> eyre::Result<Self> {
+        let mut script_sequence: Self = corebc::ylem::utils::read_json_file(path)
+            .wrap_err_with(|| format!("Deployment not found at {}.", path.display()))?;
+
+        let sensitive_script_sequence: SensitiveScriptSequence =
+            corebc::ylem::utils::read_json_file(sensitive_path).wrap_err_with(|| {
+                format!("Deployment's sensitive details not found at {}.", sensitive_path.display())
+            })?;
+
+        script_sequence
+            .transactions
+            .iter_mut()
+            .zip(sensitive_script_sequence.transactions)
+            .for_each(|(tx, sensitive)| tx.rpc = sensitive.rpc);
+
+        script_sequence.path = path.to_path_buf();
+        script_sequence.sensitive_path = sensitive_path.to_path_buf();
+
+        Ok(script_sequence)
+    }
+
     /// Saves the transactions as file if it's a standalone deployment.
     pub fn save(&mut self) -> eyre::Result<()> {
         if !self.multi && !self.transactions.is_empty() {
@@ -229,7 +270,7 @@ impl ScriptSequence {
         broadcast: &Path,
         cache: &Path,
         sig: &str,
-        target: &ArtifactId,
+        script: &Path,
         chain_id: Network,
         broadcasted: bool,
     ) -> eyre::Result<(PathBuf, PathBuf)> {
@@ -237,7 +278,7 @@ impl ScriptSequence {
         let mut cache = cache.to_path_buf();
         let mut common = PathBuf::new();
 
-        let target_fname = target.source.file_name().wrap_err("No filename.")?;
+        let target_fname = script.file_name().wrap_err("No filename.")?;
         common.push(target_fname);
         common.push(chain_id.to_string());
         if !broadcasted {
@@ -261,6 +302,9 @@ impl ScriptSequence {
 
     /// Given the broadcast log, it matches transactions with receipts, and tries to verify any
     /// created contract on etherscan.
+    ///
+    /// Contracts verified by a previous attempt are skipped. The state of the others is saved
+    /// before and after their verification, so that `spark verify-all` can retry them.
     pub async fn verify_contracts(
         &mut self,
         _config: &Config,
@@ -294,7 +338,7 @@ impl ScriptSequence {
                     &self.libraries,
                     &self.network,
                 ) {
-                    Some(verify) => future_verifications.push(verify.run()),
+                    Some(verify) => future_verifications.push(verify),
                     None => unverifiable_contracts.push(address),
                 };
             }
@@ -303,20 +347,67 @@ impl ScriptSequence {
             for AdditionalContract { address, init_code, .. } in &tx.additional_contracts {
                 match verify.get_verify_args(*address, 0, init_code, &self.libraries, &self.network)
                 {
-                    Some(verify) => future_verifications.push(verify.run()),
+                    Some(verify) => future_verifications.push(verify),
                     None => unverifiable_contracts.push(*address),
                 };
             }
         }
 
+        future_verifications.retain(|args| !self.is_verified(args.address));
+        unverifiable_contracts.retain(|address| !self.is_verified(*address));
+
         trace!(target: "script", "collected {} verification jobs and {} unverifiable contracts", future_verifications.len(), unverifiable_contracts.len());
 
+        for address in &unverifiable_contracts {
+            self.set_verification(
+                *address,
+                None,
+                VerificationStatus::Failed,
+                Some("No matching bytecode found".to_string()),
+            );
+        }
+        for args in &future_verifications {
+            self.set_verification(
+                args.address,
+                Some(args.contract.to_string()),
+                VerificationStatus::Pending,
+                None,
+            );
+        }
+        self.save()?;
+
         self.check_unverified(unverifiable_contracts, verify);
 
         let num_verifications = future_verifications.len();
         println!("##\nStart verification for ({num_verifications}) contracts",);
+        let mut failed = 0;
         for verification in future_verifications {
-            verification.await?;
+            let address = verification.address;
+            match verification.run().await {
+                Ok(()) => {
+                    self.set_verification(address, None, VerificationStatus::Verified, None);
+                }
+                Err(err) => {
+                    println!(
+                        "{}",
+                        Paint::red(format!("Failed to verify contract {address:?}: {err:#}"))
+                    );
+                    self.set_verification(
+                        address,
+                        None,
+                        VerificationStatus::Failed,
+                        Some(format!("{err:#}")),
+                    );
+                    failed += 1;
+                }
+            }
+        }
+        self.save()?;
+
+        if failed > 0 {
+            eyre::bail!(
+                "{failed} of ({num_verifications}) contracts failed to verify. Retry them with `spark verify-all`."
+            )
         }
 
         println!("All ({num_verifications}) contracts were verified!");
@@ -324,6 +415,142 @@ impl ScriptSequence {
         Ok(())
     }
 
+    /// Returns `true` if the contract at `address` was verified by a previous attempt
+    fn is_verified(&self, address: Address) -> bool {
+        self.verifications.iter().any(|verification| {
+            verification.address == address && verification.status == VerificationStatus::Verified
+        })
+    }
+
+    /// Records the verification state of the contract at `address`, counting an attempt if it's
+    /// pending. The recorded contract identifier is kept if `contract` is `None`.
+    fn set_verification(
+        &mut self,
+        address: Address,
+        contract: Option<String>,
+        status: VerificationStatus,
+        error: Option<String>,
+    ) {
+        let index = match self.verifications.iter().position(|v| v.address == address) {
+            Some(index) => index,
+            None => {
+                self.verifications.push(ContractVerification {
+                    address,
+                    contract: None,
+                    status,
+                    error: None,
+                    attempts: 0,
+                });
+                self.verifications.len() - 1
+            }
+        };
+        let verification = &mut self.verifications[index];
+        if contract.is_some() {
+            verification.contract = contract;
+        }
+        if status == VerificationStatus::Pending {
+            verification.attempts += 1;
+        }
+        verification.status = status;
+        verification.error = error;
+    }
+
+    /// Saves the contracts created by the broadcasted transactions to the deployment registry,
+    /// under the names given with `saveDeployment` or else their contract name, with their
+    /// storage layouts keyed by contract identifier.
+    ///
+    /// Unnamed contracts deployed more than once are saved as `<contract name>-<tx hash>`.
+    pub fn save_deployments(
+        &self,
+        config: &Config,
+        known_contracts: &ContractsByArtifact,
+        storage_layouts: &BTreeMap<String, StorageLayout>,
+    ) -> eyre::Result<()> {
+        let mut created = vec![];
+        for tx in &self.transactions {
+            let Some(receipt) =
+                self.receipts.iter().find(|receipt| Some(receipt.transaction_hash) == tx.hash)
+            else {
+                continue
+            };
+            let new_deployment = |address, offset, init_code: &[u8]| {
+                let mut deployment = new_deployment(
+                    config,
+                    known_contracts,
+                    storage_layouts,
+                    address,
+                    offset,
+                    init_code,
+                )?;
+                deployment.transaction_hash = Some(receipt.transaction_hash);
+                deployment.block_number = receipt.block_number.map(|block| block.as_u64());
+                Some(deployment)
+            };
+
+            // create2 hash offset
+            let (address, offset) = match tx.opcode {
+                CallKind::Create => (receipt.contract_address, 0),
+                CallKind::Create2 => (tx.contract_address, 32),
+                _ => (None, 0),
+            };
+            if let (Some(address), Some(data)) = (address, tx.typed_tx().data()) {
+                created.extend(new_deployment(address, offset, &data.0));
+            }
+
+            for AdditionalContract { address, init_code, .. } in &tx.additional_contracts {
+                created.extend(new_deployment(*address, 0, init_code));
+            }
+        }
+
+        let mut named = vec![];
+        for (name, address) in &self.deployments {
+            match created.iter().find(|deployment| deployment.address == *address) {
+                Some(deployment) => {
+                    named.push(Deployment { name: name.clone(), ..deployment.clone() })
+                }
+                // Without a matching contract the address could belong to any chain of a multi
+                // chain sequence
+                None if !self.multi => named.push(Deployment::new(name.clone(), *address)),
+                None => {}
+            }
+        }
+
+        // Contracts saved with `saveDeployment` are only saved under that name, so deploying the
+        // same contract twice doesn't replace the first one in the registry
+        created.retain(|deployment| {
+            !self.deployments.values().any(|address| *address == deployment.address)
+        });
+        // The remaining contracts deployed more than once in this sequence are keyed by the
+        // transaction that deployed them
+        let mut counts = HashMap::<_, usize>::new();
+        for deployment in &created {
+            *counts.entry(deployment.name.clone()).or_default() += 1;
+        }
+        let mut keys = HashMap::<_, usize>::new();
+        for deployment in &mut created {
+            let Some(tx_hash) = deployment.transaction_hash else { continue };
+            if counts[&deployment.name] > 1 {
+                let key = format!("{}-{tx_hash:?}", deployment.name);
+                // A transaction can deploy the same contract several times
+                let seen = keys.entry(key.clone()).or_default();
+                deployment.name = if *seen == 0 { key } else { format!("{key}-{seen}") };
+                *seen += 1;
+            }
+        }
+
+        if created.is_empty() && named.is_empty() {
+            return Ok(())
+        }
+
+        let registry = DeploymentRegistry::new(&config.deployments);
+        let network = self.network.to_string();
+        for deployment in created.into_iter().chain(named) {
+            registry.save(&network, deployment)?;
+        }
+        println!("\nDeployments saved to: {}", config.deployments.join(&network).display());
+        Ok(())
+    }
+
     /// Let the user know if there are any contracts which can not be verified. Also, present some
     /// hints on potential causes.
     fn check_unverified(&self, unverifiable_contracts: Vec<Address>, verify: VerifyBundle) {
@@ -370,6 +597,37 @@ impl Drop for ScriptSequence {
     }
 }
 
+/// Returns the deployment of the contract created with the `init_code`, if it matches a known
+/// contract.
+fn new_deployment(
+    config: &Config,
+    known_contracts: &ContractsByArtifact,
+    storage_layouts: &BTreeMap<String, StorageLayout>,
+    address: Address,
+    create2_offset: usize,
+    init_code: &[u8],
+) -> Option<Deployment> {
+    let init_code = init_code.get(create2_offset..)?;
+    let (artifact, (abi, bytecode)) =
+        known_contracts.iter().find(|(_, (_, bytecode))| init_code.starts_with(bytecode))?;
+
+    Some(Deployment {
+        contract_name: Some(artifact.name.clone()),
+        abi: Some(abi.clone()),
+        bytecode_hash: Some(H256::from(sha3(bytecode))),
+        constructor_args: Some(init_code[bytecode.len()..].to_vec().into()),
+        ylem: Some(YlemSettings {
+            version: artifact.version.to_string(),
+            optimizer: config.optimizer,
+            optimizer_runs: config.optimizer_runs,
+            via_ir: config.via_ir,
+            cvm_version: config.cvm_version.to_string(),
+        }),
+        storage_layout: storage_layouts.get(&artifact.identifier()).cloned(),
+        ..Deployment::new(artifact.name.clone(), address)
+    })
+}
+
 /// Converts the `sig` argument into the corresponding file path.
 ///
 /// This accepts either the signature of the function or the raw calldata
@@ -404,4 +662,34 @@ mod tests {
             "522bb704"
         );
     }
+
+    #[test]
+    fn can_record_verifications() {
+        let mut sequence = ScriptSequence { multi: true, ..Default::default() };
+        let address = Address::repeat_byte(1);
+        let contract = Some("src/Counter.sol:Counter".to_string());
+
+        sequence.set_verification(address, contract.clone(), VerificationStatus::Pending, None);
+        sequence.set_verification(
+            address,
+            None,
+            VerificationStatus::Failed,
+            Some("Failed to submit contract verification".to_string()),
+        );
+        assert!(!sequence.is_verified(address));
+
+        sequence.set_verification(address, contract.clone(), VerificationStatus::Pending, None);
+        sequence.set_verification(address, None, VerificationStatus::Verified, None);
+        assert!(sequence.is_verified(address));
+        assert_eq!(
+            sequence.verifications,
+            vec![ContractVerification {
+                address,
+                contract,
+                status: VerificationStatus::Verified,
+                error: None,
+                attempts: 2
+            }]
+        );
+    }
 }
@@ -3,8 +3,8 @@ use corebc::{
     abi,
     abi::Address,
     prelude::{NameOrAddress, H256 as TxHash},
-    types::{transaction::eip2718::TypedTransaction, Network, H160},
-    utils::to_ican,
+    types::{transaction::eip2718::TypedTransaction, Network, H160, H256},
+    utils::{sha3, to_ican},
 };
 use eyre::{ContextCompat, WrapErr};
 use foxar_common::{abi::format_token_raw, RpcUrl, SELECTOR_LEN};
@@ -43,6 +43,16 @@ pub struct TransactionWithMetadata {
     pub transaction: TypedTransaction,
     pub additional_contracts: Vec<AdditionalContract>,
     pub is_fixed_energy_limit: bool,
+    /// The salt of a CREATE2 deployment
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub salt: Option<H256>,
+    /// The hash of the init code of a CREATE2 deployment, which together with the salt and the
+    /// CREATE2 deployer determines the address on every network
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub init_code_hash: Option<H256>,
+    /// The transactions batched into this multicall transaction, see `--batch`
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub batch: Vec<TransactionWithMetadata>,
 }
 
 fn default_string() -> Option<String> {
@@ -125,6 +135,11 @@ impl TransactionWithMetadata {
     ) -> eyre::Result<()> {
         if is_create2 {
             self.opcode = CallKind::Create2;
+            // The CREATE2 deployer is called with the salt followed by the init code
+            if let Some(data) = self.transaction.data().filter(|data| data.len() >= 32) {
+                self.salt = Some(H256::from_slice(&data[..32]));
+                self.init_code_hash = Some(H256::from(sha3(&data[32..])));
+            }
         } else {
             self.opcode = CallKind::Create;
         }
@@ -0,0 +1,178 @@
+//! Storage layout checks of the proxy upgrades of a script
+
+use super::{sequence::ScriptSequence, ScriptArgs, ScriptConfig};
+use crate::cmd::spark::upgrade::compare_storage_layouts;
+use corebc::{
+    abi::{Function, HumanReadableParser},
+    providers::Middleware,
+    types::{Address, NameOrAddress, H256},
+};
+use eyre::{bail, Result};
+use foxar_common::{deployments::DeploymentRegistry, shell, try_get_http_provider};
+use spark::trace::CallTraceDecoder;
+use std::str::FromStr;
+use yansi::Paint;
+
+/// The EIP-1967 slot of the implementation of a proxy
+const IMPLEMENTATION_SLOT: &str =
+    "0x360894a13ba1a3210667c828492db98dca3e2076cc3735a920a3ca505d382bbc";
+
+/// The functions upgrading UUPS and transparent proxies, with the indexes of their proxy and
+/// implementation arguments. Without a proxy argument, the called contract is the proxy.
+const UPGRADE_FUNCTIONS: [(&str, Option<usize>, usize); 4] = [
+    ("function upgradeTo(address)", None, 0),
+    ("function upgradeToAndCall(address,bytes)", None, 0),
+    ("function upgrade(address,address)", Some(0), 1),
+    ("function upgradeAndCall(address,address,bytes)", Some(0), 1),
+];
+
+impl ScriptArgs {
+    /// Checks the storage layouts of the new implementations of the proxies the transactions
+    /// upgrade, against the layouts recorded in the deployment registry for their current
+    /// implementations.
+    ///
+    /// Incompatible upgrades are refused when broadcasting or exporting a bundle, and reported
+    /// otherwise.
+    pub async fn check_upgrades(
+        &self,
+        sequences: &[ScriptSequence],
+        decoder: &CallTraceDecoder,
+        script_config: &ScriptConfig,
+    ) -> Result<()> {
+        if self.skip_storage_layout_check {
+            return Ok(())
+        }
+
+        let functions = UPGRADE_FUNCTIONS
+            .iter()
+            .map(|(sig, proxy, implementation)| {
+                Ok((HumanReadableParser::parse_function(sig)?, *proxy, *implementation))
+            })
+            .collect::<Result<Vec<_>>>()?;
+        let registry = DeploymentRegistry::new(&script_config.config.deployments);
+
+        let mut failures = vec![];
+        for sequence in sequences {
+            for tx in &sequence.transactions {
+                let typed_tx = tx.typed_tx();
+                let (Some(NameOrAddress::Address(to)), Some(data), Some(rpc)) =
+                    (typed_tx.to(), typed_tx.data(), &tx.rpc)
+                else {
+                    continue
+                };
+                let Some((proxy, implementation)) = decode_upgrade(&functions, *to, data) else {
+                    continue
+                };
+
+                let provider = try_get_http_provider(rpc.as_str())?;
+                let current = Address::from(
+                    provider
+                        .get_storage_at(proxy, H256::from_str(IMPLEMENTATION_SLOT)?, None)
+                        .await?,
+                );
+                // The proxy is deployed by the script
+                if current.is_zero() {
+                    continue
+                }
+
+                let recorded = registry
+                    .list(&sequence.network.to_string())?
+                    .into_iter()
+                    .find(|deployment| deployment.address == current)
+                    .and_then(|deployment| Some((deployment.name, deployment.storage_layout?)));
+                let Some((current_name, current_layout)) = recorded else {
+                    shell::println(Paint::yellow(format!(
+                        "No storage layout is recorded for {current:?}, the implementation of proxy {proxy:?}. Its upgrade to {implementation:?} is not checked."
+                    )))?;
+                    continue
+                };
+
+                let new = decoder.contracts.get(&implementation).and_then(|identifier| {
+                    Some((identifier, script_config.storage_layouts.get(identifier)?))
+                });
+                let Some((new_name, new_layout)) = new else {
+                    shell::println(Paint::yellow(format!(
+                        "The storage layout of {implementation:?}, the new implementation of proxy {proxy:?}, is unknown. Its upgrade is not checked."
+                    )))?;
+                    continue
+                };
+
+                let incompatible = compare_storage_layouts(&current_layout, new_layout)
+                    .into_iter()
+                    .filter(|change| change.is_incompatible())
+                    .map(|change| format!("    {change}"))
+                    .collect::<Vec<_>>();
+                if !incompatible.is_empty() {
+                    failures.push(format!(
+                        "Upgrade of proxy {proxy:?} from `{current_name}` ({current:?}) to `{new_name}` ({implementation:?}):\n{}",
+                        incompatible.join("\n")
+                    ));
+                }
+            }
+        }
+
+        if failures.is_empty() {
+            return Ok(())
+        }
+        let report = failures.join("\n\n");
+        if self.broadcast || self.export_bundle.is_some() {
+            bail!(
+                "Refusing to broadcast upgrades to implementations with incompatible storage layouts:\n\n{report}\n\nAdd --skip-storage-layout-check to broadcast them anyway."
+            )
+        }
+        shell::println(Paint::yellow(format!(
+            "\nUpgrades to implementations with incompatible storage layouts:\n\n{report}"
+        )))?;
+        Ok(())
+    }
+}
+
+/// Returns the proxy and the new implementation, if the call to `to` upgrades a proxy
+fn decode_upgrade(
+    functions: &[(Function, Option<usize>, usize)],
+    to: Address,
+    data: &[u8],
+) -> Option<(Address, Address)> {
+    let (function, proxy, implementation) =
+        functions.iter().find(|(function, ..)| data.starts_with(&function.short_signature()))?;
+    let tokens = function.decode_input(&data[4..]).ok()?;
+    let address = |index: usize| tokens.get(index)?.clone().into_address();
+    let proxy = match proxy {
+        Some(index) => address(*index)?,
+        None => to,
+    };
+    Some((proxy, address(*implementation)?))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use corebc::abi::Token;
+
+    #[test]
+    fn can_decode_upgrades() {
+        let functions = UPGRADE_FUNCTIONS
+            .iter()
+            .map(|(sig, proxy, implementation)| {
+                (HumanReadableParser::parse_function(sig).unwrap(), *proxy, *implementation)
+            })
+            .collect::<Vec<_>>();
+        let (admin, proxy, implementation) =
+            (Address::repeat_byte(1), Address::repeat_byte(2), Address::repeat_byte(3));
+
+        let data = functions[0].0.encode_input(&[Token::Address(implementation)]).unwrap();
+        assert_eq!(decode_upgrade(&functions, proxy, &data), Some((proxy, implementation)));
+
+        let data = functions[3]
+            .0
+            .encode_input(&[
+                Token::Address(proxy),
+                Token::Address(implementation),
+                Token::Bytes(vec![]),
+            ])
+            .unwrap();
+        assert_eq!(decode_upgrade(&functions, admin, &data), Some((proxy, implementation)));
+
+        assert_eq!(decode_upgrade(&functions, proxy, &[0xde, 0xad, 0xbe, 0xef]), None);
+    }
+}
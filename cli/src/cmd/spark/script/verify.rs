@@ -1,23 +1,33 @@
 //! Verify support
 
+use super::{
+    multi::MultiChainSequence,
+    sequence::{ScriptSequence, DRY_RUN_DIR},
+};
 use crate::{
     cmd::{
         retry::RetryArgs,
         spark::{
-            build::ProjectPathsArgs,
+            build::{CoreBuildArgs, ProjectPathsArgs},
             verify::{VerifierArgs, VerifyArgs},
         },
+        LoadConfig,
     },
     opts::EtherscanOpts,
 };
+use clap::{Parser, ValueHint};
 use corebc::{
     abi::Address,
     core::types::Network,
+    prelude::artifacts::CompactContractBytecode,
     ylem::{info::ContractInfo, Project},
 };
-use foxar_common::ContractsByArtifact;
+use eyre::WrapErr;
+use foxar_common::{compile, fs, ContractsByArtifact};
 use foxar_config::Config;
 use semver::Version;
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
 
 /// Data struct to help `ScriptSequence` verify contracts on `etherscan`.
 #[derive(Clone)]
@@ -121,3 +131,228 @@ impl VerifyBundle {
         None
     }
 }
+
+/// The verification status of a contract created by a script
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum VerificationStatus {
+    /// The verification was started, but its outcome is unknown
+    Pending,
+    Verified,
+    Failed,
+}
+
+/// The verification state of a contract created by a script, saved in its broadcast log
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ContractVerification {
+    pub address: Address,
+    /// The contract identifier in the form `<path>:<contractname>`, unless no compiled contract
+    /// matches its creation code
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub contract: Option<String>,
+    pub status: VerificationStatus,
+    /// The error of the last failed attempt
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+    /// The number of verification attempts
+    pub attempts: u32,
+}
+
+/// CLI arguments for `spark verify-all`.
+#[derive(Debug, Clone, Parser)]
+pub struct VerifyAllArgs {
+    /// The broadcast logs of the scripts whose contracts are verified.
+    ///
+    /// Defaults to the latest broadcast logs of all the scripts, single and multi chain.
+    #[clap(value_hint = ValueHint::FilePath, value_name = "PATHS")]
+    pub paths: Vec<PathBuf>,
+
+    #[clap(flatten)]
+    pub verifier: VerifierArgs,
+
+    #[clap(flatten)]
+    pub retry: RetryArgs,
+
+    #[clap(flatten)]
+    pub build: CoreBuildArgs,
+}
+
+impl VerifyAllArgs {
+    /// Verifies the contracts created by the scripts which are still pending or failed to verify,
+    /// and saves their new state in the broadcast logs.
+    pub async fn run(self) -> eyre::Result<()> {
+        let config = self.build.try_load_config_emit_warnings()?;
+        let broadcast = dunce::canonicalize(&config.broadcast).wrap_err_with(|| {
+            format!("No broadcast logs found at {}", config.broadcast.display())
+        })?;
+
+        let paths = if self.paths.is_empty() {
+            latest_broadcast_logs(&broadcast)
+        } else {
+            self.paths
+                .iter()
+                .map(|path| {
+                    dunce::canonicalize(path)
+                        .wrap_err_with(|| format!("Broadcast log {} not found", path.display()))
+                })
+                .collect::<eyre::Result<_>>()?
+        };
+        if paths.is_empty() {
+            eyre::bail!("No broadcast logs found at {}", broadcast.display())
+        }
+
+        let mut failed = vec![];
+        for path in paths {
+            let relative = path.strip_prefix(&broadcast).wrap_err_with(|| {
+                format!("{} is not a broadcast log of {}", path.display(), broadcast.display())
+            })?;
+            let result = if relative.starts_with("multi") {
+                let mut multi = MultiChainSequence::load_from_path(&path)?;
+                let mut result = Ok(());
+                for sequence in &mut multi.deployments {
+                    if let Err(err) = self.verify_sequence(&config, sequence).await {
+                        result = Err(err);
+                    }
+                }
+                result
+            } else {
+                let mut sequence =
+                    ScriptSequence::load_from_paths(&path, &config.cache_path.join(relative))?;
+                self.verify_sequence(&config, &mut sequence).await
+            };
+            if let Err(err) = result {
+                failed.push(format!("{}: {err}", path.display()));
+            }
+        }
+
+        if !failed.is_empty() {
+            eyre::bail!(
+                "Some contracts failed to verify:\n\n{}",
+                failed
+                    .iter()
+                    .map(|failure| format!("  - {failure}"))
+                    .collect::<Vec<_>>()
+                    .join("\n")
+            )
+        }
+        Ok(())
+    }
+
+    /// Verifies the contracts of the sequence which aren't verified yet, against the project
+    /// compiled with the libraries of the sequence
+    async fn verify_sequence(
+        &self,
+        config: &Config,
+        sequence: &mut ScriptSequence,
+    ) -> eyre::Result<()> {
+        if !sequence.verifications.is_empty() &&
+            sequence
+                .verifications
+                .iter()
+                .all(|verification| verification.status == VerificationStatus::Verified)
+        {
+            println!(
+                "All ({}) contracts on {} were already verified.",
+                sequence.verifications.len(),
+                sequence.network
+            );
+            return Ok(())
+        }
+
+        let mut config = config.clone();
+        config.libraries = sequence.libraries.clone();
+        let project = config.project()?;
+        let output = compile::suppress_compile(&project)?;
+        let known_contracts = ContractsByArtifact(
+            output
+                .artifact_ids()
+                .filter_map(|(id, artifact)| {
+                    let CompactContractBytecode { abi, bytecode, .. } = artifact.clone().into();
+                    let code = bytecode?.object.into_bytes()?;
+                    Some((id, (abi?, code.to_vec())))
+                })
+                .collect(),
+        );
+
+        let verify = VerifyBundle::new(
+            &project,
+            &config,
+            known_contracts,
+            self.retry,
+            self.verifier.clone(),
+        );
+        sequence.verify_contracts(&config, verify).await
+    }
+}
+
+/// Returns the latest broadcast logs of the scripts, `<script>/<network>/<sig>-latest.json` and
+/// `multi/<script>-latest/<sig>.json`, without the ones of dry runs
+fn latest_broadcast_logs(broadcast: &Path) -> Vec<PathBuf> {
+    let mut paths = fs::json_files(broadcast)
+        .into_iter()
+        .filter(|path| {
+            let Ok(relative) = path.strip_prefix(broadcast) else { return false };
+            if relative.iter().any(|component| component == DRY_RUN_DIR) {
+                return false
+            }
+            let latest = if relative.starts_with("multi") {
+                relative.parent().and_then(Path::file_name).map(|name| name.to_string_lossy())
+            } else {
+                relative.file_stem().map(|name| name.to_string_lossy())
+            };
+            latest.map_or(false, |name| name.ends_with("-latest"))
+        })
+        .collect::<Vec<_>>();
+    paths.sort();
+    paths
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn can_find_latest_broadcast_logs() {
+        let broadcast = tempfile::tempdir().unwrap();
+        let logs = [
+            "Deploy.s.sol/1/run-latest.json",
+            "Deploy.s.sol/1/run-1700000000.json",
+            "Deploy.s.sol/1/dry-run/run-latest.json",
+            "multi/Deploy.s.sol-latest/run.json",
+            "multi/Deploy.s.sol-1700000000/run.json",
+        ];
+        for log in logs {
+            let path = broadcast.path().join(log);
+            std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+            std::fs::write(path, "{}").unwrap();
+        }
+
+        let latest = latest_broadcast_logs(broadcast.path())
+            .into_iter()
+            .map(|path| path.strip_prefix(broadcast.path()).unwrap().to_path_buf())
+            .collect::<Vec<_>>();
+        assert_eq!(
+            latest,
+            vec![
+                PathBuf::from("Deploy.s.sol/1/run-latest.json"),
+                PathBuf::from("multi/Deploy.s.sol-latest/run.json")
+            ]
+        );
+    }
+
+    #[test]
+    fn can_serialize_verifications() {
+        let verification = ContractVerification {
+            address: Address::repeat_byte(1),
+            contract: Some("src/Counter.sol:Counter".to_string()),
+            status: VerificationStatus::Failed,
+            error: Some("Failed to submit contract verification".to_string()),
+            attempts: 2,
+        };
+        let json = serde_json::to_value(&verification).unwrap();
+        assert_eq!(json["status"], "failed");
+        assert_eq!(json["attempts"], 2);
+        assert_eq!(serde_json::from_value::<ContractVerification>(json).unwrap(), verification);
+    }
+}
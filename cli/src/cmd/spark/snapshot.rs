@@ -1,9 +1,12 @@
 //! Snapshot command
 use crate::{
-    cmd::spark::{
-        build::CoreBuildArgs,
-        test,
-        test::{Test, TestOutcome},
+    cmd::{
+        spark::{
+            build::CoreBuildArgs,
+            test,
+            test::{Test, TestOutcome},
+        },
+        LoadConfig,
     },
     utils::STATIC_FUZZ_SEED,
 };
@@ -15,7 +18,7 @@ use regex::Regex;
 use spark::result::TestKindReport;
 use std::{
     cmp::Ordering,
-    collections::HashMap,
+    collections::{BTreeMap, HashMap},
     fs,
     io::{self, BufRead},
     path::{Path, PathBuf},
@@ -27,7 +30,7 @@ use yansi::Paint;
 /// A regex that matches a basic snapshot entry like
 /// `Test:testDeposit() (gas: 58804)`
 pub static RE_BASIC_SNAPSHOT_ENTRY: Lazy<Regex> = Lazy::new(|| {
-    Regex::new(r"(?P<file>(.*?)):(?P<sig>(\w+)\s*\((.*?)\))\s*\(((gas:)?\s*(?P<gas>\d+)|(runs:\s*(?P<runs>\d+),\s*μ:\s*(?P<avg>\d+),\s*~:\s*(?P<med>\d+))|(runs:\s*(?P<invruns>\d+),\s*calls:\s*(?P<calls>\d+),\s*reverts:\s*(?P<reverts>\d+)))\)").unwrap()
+    Regex::new(r"(?P<file>(.*?)):(?P<sig>(\w+)\s*\((.*?)\)(\[\d+\])?)\s*\(((gas:)?\s*(?P<gas>\d+)|(runs:\s*(?P<runs>\d+),\s*μ:\s*(?P<avg>\d+),\s*~:\s*(?P<med>\d+))|(runs:\s*(?P<invruns>\d+),\s*calls:\s*(?P<calls>\d+),\s*reverts:\s*(?P<reverts>\d+)))\)").unwrap()
 });
 
 /// CLI arguments for `spark snapshot`.
@@ -79,6 +82,19 @@ pub struct SnapshotArgs {
     )]
     snap: PathBuf,
 
+    /// Output directory for the energy snapshots recorded with the `startSnapshotEnergy`,
+    /// `stopSnapshotEnergy` and `snapshotValue` cheatcodes.
+    ///
+    /// Each test contract is written to its own `<CONTRACT>.json` file, with the snapshots keyed
+    /// by `<test signature>::<name>`.
+    #[clap(
+        long,
+        default_value = "snapshots",
+        value_hint = ValueHint::DirPath,
+        value_name = "DIR",
+    )]
+    snapshots: PathBuf,
+
     /// Tolerates gas deviations up to the specified percentage.
     #[clap(
         long,
@@ -112,21 +128,29 @@ impl SnapshotArgs {
         let outcome = self.test.execute_tests().await?;
         outcome.ensure_ok()?;
         let tests = self.config.apply(outcome);
+        let energy_snapshots = collect_energy_snapshots(&tests);
 
         if let Some(path) = self.diff {
             let snap = path.as_ref().unwrap_or(&self.snap);
             let snaps = read_snapshot(snap)?;
             diff(tests, snaps)?;
+            diff_energy_snapshots(&energy_snapshots, &self.snapshots)?;
         } else if let Some(path) = self.check {
             let snap = path.as_ref().unwrap_or(&self.snap);
             let snaps = read_snapshot(snap)?;
-            if check(tests, snaps, self.tolerance) {
+            let matches = check(tests, snaps, self.tolerance);
+            let energy_matches =
+                check_energy_snapshots(&energy_snapshots, &self.snapshots, self.tolerance)?;
+            if matches && energy_matches {
                 std::process::exit(0)
             } else {
                 std::process::exit(1)
             }
         } else {
             write_to_snapshot_file(&tests, self.snap, self.format)?;
+            let config = self.test.load_config();
+            let all_tests = self.test.runs_all_tests(&config);
+            write_energy_snapshots(&energy_snapshots, &tests, all_tests, &self.snapshots)?;
         }
         Ok(())
     }
@@ -301,6 +325,160 @@ fn write_to_snapshot_file(
     Ok(fs::write(path, content)?)
 }
 
+/// Energy snapshots recorded via cheatcodes, grouped by contract. `contract -> test::name ->
+/// value`
+type EnergySnapshots = BTreeMap<String, BTreeMap<String, u64>>;
+
+/// Returns the key of an energy snapshot, namespaced by the test that recorded it
+fn energy_snapshot_key(signature: &str, name: &str) -> String {
+    format!("{signature}::{name}")
+}
+
+/// Collects the energy snapshots recorded by the given tests, grouped by contract name
+fn collect_energy_snapshots(tests: &[Test]) -> EnergySnapshots {
+    let mut snapshots = EnergySnapshots::new();
+    for test in tests.iter().filter(|test| !test.result.energy_snapshots.is_empty()) {
+        snapshots.entry(test.contract_name().to_string()).or_default().extend(
+            test.result
+                .energy_snapshots
+                .iter()
+                .map(|(name, value)| (energy_snapshot_key(&test.signature, name), *value)),
+        );
+    }
+    snapshots
+}
+
+/// Returns the path of the energy snapshot file of the given contract
+fn energy_snapshot_file(dir: &Path, contract_name: &str) -> PathBuf {
+    dir.join(format!("{contract_name}.json"))
+}
+
+/// Reads the energy snapshot file of the given contract
+fn read_energy_snapshot(
+    dir: &Path,
+    contract_name: &str,
+) -> eyre::Result<Option<BTreeMap<String, u64>>> {
+    let path = energy_snapshot_file(dir, contract_name);
+    if !path.exists() {
+        return Ok(None)
+    }
+    let content = fs::read_to_string(&path)
+        .wrap_err(format!("failed to read energy snapshot file \"{}\"", path.display()))?;
+    Ok(Some(serde_json::from_str(&content)?))
+}
+
+/// Writes the energy snapshots to one file per contract in the given directory.
+///
+/// The entries of the tests which ran are replaced, and files left without entries are removed.
+/// If all tests ran, the entries and files of tests and contracts which no longer exist are
+/// removed as well.
+fn write_energy_snapshots(
+    snapshots: &EnergySnapshots,
+    tests: &[Test],
+    all_tests: bool,
+    dir: &Path,
+) -> eyre::Result<()> {
+    let mut files = snapshots.clone();
+    if dir.exists() {
+        for entry in fs::read_dir(dir)? {
+            let path = entry?.path();
+            if path.extension().map_or(true, |ext| ext != "json") {
+                continue
+            }
+            let Some(contract_name) = path.file_stem().and_then(|stem| stem.to_str()) else {
+                continue
+            };
+            let values = files.entry(contract_name.to_string()).or_default();
+            if all_tests {
+                continue
+            }
+            let ran = tests
+                .iter()
+                .filter(|test| test.contract_name() == contract_name)
+                .map(|test| energy_snapshot_key(&test.signature, ""))
+                .collect::<Vec<_>>();
+            for (key, value) in read_energy_snapshot(dir, contract_name)?.unwrap_or_default() {
+                if !ran.iter().any(|prefix| key.starts_with(prefix)) {
+                    values.entry(key).or_insert(value);
+                }
+            }
+        }
+    }
+
+    for (contract_name, values) in files {
+        let path = energy_snapshot_file(dir, &contract_name);
+        if values.is_empty() {
+            if path.exists() {
+                fs::remove_file(path)?;
+            }
+            continue
+        }
+        fs::create_dir_all(dir)?;
+        fs::write(path, serde_json::to_string_pretty(&values)?)?;
+    }
+    Ok(())
+}
+
+/// Compares the recorded energy snapshots with the existing snapshot files
+///
+/// Returns true if all snapshots match
+fn check_energy_snapshots(
+    snapshots: &EnergySnapshots,
+    dir: &Path,
+    tolerance: Option<u32>,
+) -> eyre::Result<bool> {
+    let mut has_diff = false;
+    for (contract_name, values) in snapshots {
+        let Some(targets) = read_energy_snapshot(dir, contract_name)? else {
+            eprintln!("No energy snapshot file found for \"{contract_name}\"");
+            has_diff = true;
+            continue
+        };
+        for (name, source) in values {
+            match targets.get(name) {
+                Some(target)
+                    if source == target || within_tolerance(*source, *target, tolerance) => {}
+                Some(target) => {
+                    eprintln!(
+                        "Diff in energy snapshot \"{}::{}\": recorded \"{}\", expected \"{}\"",
+                        contract_name, name, source, target
+                    );
+                    has_diff = true;
+                }
+                None => {
+                    eprintln!(
+                        "No matching energy snapshot entry found for \"{contract_name}::{name}\""
+                    );
+                    has_diff = true;
+                }
+            }
+        }
+    }
+    Ok(!has_diff)
+}
+
+/// Compares the recorded energy snapshots with the existing snapshot files
+fn diff_energy_snapshots(snapshots: &EnergySnapshots, dir: &Path) -> eyre::Result<()> {
+    for (contract_name, values) in snapshots {
+        let Some(targets) = read_energy_snapshot(dir, contract_name)? else { continue };
+        for (name, source) in values {
+            let Some(target) = targets.get(name) else { continue };
+            let change = *source as i128 - *target as i128;
+            if *target == 0 {
+                // there is no relative change from zero
+                println!("{contract_name}::{name} (energy: {})", fmt_change(change));
+            } else {
+                println!(
+                    "{contract_name}::{name} (energy: {} ({}))",
+                    fmt_change(change),
+                    fmt_pct_change(change as f64 / *target as f64)
+                );
+            }
+        }
+    }
+    Ok(())
+}
+
 /// A Snapshot entry diff
 #[derive(Debug, Clone, Eq, PartialEq)]
 pub struct SnapshotDiff {
@@ -458,6 +636,62 @@ mod tests {
         assert!(within_tolerance(100, 100, None));
     }
 
+    fn energy_test(signature: &str, snapshots: &[(&str, u64)]) -> Test {
+        Test {
+            artifact_id: "src/A.t.sol:ATest".to_string(),
+            signature: signature.to_string(),
+            result: spark::result::TestResult {
+                energy_snapshots: snapshots
+                    .iter()
+                    .map(|(name, value)| (name.to_string(), *value))
+                    .collect(),
+                ..Default::default()
+            },
+        }
+    }
+
+    #[test]
+    fn energy_snapshots_are_namespaced_by_test() {
+        let tests = vec![
+            energy_test("testA()", &[("increment", 1)]),
+            energy_test("testB()", &[("increment", 2)]),
+        ];
+        let snapshots = collect_energy_snapshots(&tests);
+        assert_eq!(
+            snapshots["ATest"].clone().into_iter().collect::<Vec<_>>(),
+            vec![("testA()::increment".to_string(), 1), ("testB()::increment".to_string(), 2)]
+        );
+    }
+
+    #[test]
+    fn can_update_energy_snapshots_of_filtered_tests() {
+        let dir = tempfile::tempdir().unwrap();
+        let tests = vec![
+            energy_test("testA()", &[("increment", 1)]),
+            energy_test("testB()", &[("increment", 2)]),
+        ];
+        write_energy_snapshots(&collect_energy_snapshots(&tests), &tests, true, dir.path())
+            .unwrap();
+        fs::write(dir.path().join("RemovedTest.json"), r#"{ "testC()::value": 3 }"#).unwrap();
+
+        // only testA ran, and no longer records a snapshot
+        let tests = vec![energy_test("testA()", &[])];
+        write_energy_snapshots(&collect_energy_snapshots(&tests), &tests, false, dir.path())
+            .unwrap();
+        let values = read_energy_snapshot(dir.path(), "ATest").unwrap().unwrap();
+        assert_eq!(
+            values.into_iter().collect::<Vec<_>>(),
+            vec![("testB()::increment".to_string(), 2)]
+        );
+        assert!(dir.path().join("RemovedTest.json").exists());
+
+        // all tests ran
+        write_energy_snapshots(&collect_energy_snapshots(&tests), &tests, true, dir.path())
+            .unwrap();
+        assert!(!dir.path().join("ATest.json").exists());
+        assert!(!dir.path().join("RemovedTest.json").exists());
+    }
+
     #[test]
     fn can_parse_basic_snapshot_entry() {
         let s = "Test:deposit() (gas: 7222)";
@@ -513,4 +747,18 @@ mod tests {
             }
         );
     }
+
+    #[test]
+    fn can_parse_table_snapshot_entry() {
+        let s = "Test:testAdd(uint256,uint256)[1] (gas: 7222)";
+        let entry = SnapshotEntry::from_str(s).unwrap();
+        assert_eq!(
+            entry,
+            SnapshotEntry {
+                contract_name: "Test".to_string(),
+                signature: "testAdd(uint256,uint256)[1]".to_string(),
+                gas_used: TestKindReport::Standard { gas: 7222 }
+            }
+        );
+    }
 }
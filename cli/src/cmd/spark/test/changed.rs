@@ -0,0 +1,244 @@
+//! Test impact analysis, selects the test files affected by a set of changed files
+//!
+//! A test file is affected if it was changed itself, or if it (transitively) imports a changed
+//! file. After a `spark test --changed` run, the source files of all contracts deployed or called
+//! by every test contract are recorded in the [`TestImpactCache`]. As long as the bytecode of a
+//! test contract didn't change, the recorded sources are used instead of the imports, which
+//! skips tests that only import a changed file without using it, and includes tests that deploy
+//! artifacts they don't import, e.g. with `getCode`.
+
+use crate::utils::FoxarPathExt;
+use corebc::{
+    types::H256,
+    utils::sha3,
+    ylem::{Graph, ProjectPathsConfig},
+};
+use foxar_common::{contracts::ContractsByArtifact, fs};
+use foxar_evm::trace::identifier::{LocalTraceIdentifier, TraceIdentifier};
+use serde::{Deserialize, Serialize};
+use spark::result::SuiteResult;
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    path::{Path, PathBuf},
+};
+use tracing::trace;
+
+/// The name of the file in the project's cache dir the [`TestImpactCache`] is stored in
+pub const TEST_IMPACT_CACHE_FILE: &str = "test-impact.json";
+
+/// The bytecode hashes of test contracts, keyed by the contract identifier `<path>:<name>`
+pub type TestContractHashes = BTreeMap<String, H256>;
+
+/// The recorded impact of every test contract, keyed by the contract identifier `<path>:<name>`
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct TestImpactCache {
+    pub contracts: BTreeMap<String, TestContractImpact>,
+}
+
+/// The sources a test contract depends on at runtime
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct TestContractImpact {
+    /// The hash of the test contract's creation bytecode when the sources were recorded
+    pub bytecode_hash: H256,
+    /// The source files of all contracts deployed or called by the tests, relative to the
+    /// project root
+    pub sources: BTreeSet<PathBuf>,
+}
+
+impl TestImpactCache {
+    /// Reads the cache file, returns an empty cache if it doesn't exist or can't be read.
+    pub fn read(path: impl AsRef<Path>) -> Self {
+        let path = path.as_ref();
+        if !path.exists() {
+            return Self::default()
+        }
+        fs::read_json_file(path)
+            .map_err(|err| trace!(target: "spark::test", ?err, "failed to read test impact cache"))
+            .unwrap_or_default()
+    }
+
+    /// Writes the cache file.
+    pub fn write(&self, path: impl AsRef<Path>) -> eyre::Result<()> {
+        let path = path.as_ref();
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        fs::write_json_file(path, self)?;
+        Ok(())
+    }
+
+    /// Records the sources used by every test contract of the given results.
+    ///
+    /// The results must contain the traces of all tests of the contracts.
+    pub fn record(
+        &mut self,
+        results: &BTreeMap<String, SuiteResult>,
+        hashes: &TestContractHashes,
+        known_contracts: &ContractsByArtifact,
+    ) {
+        let mut identifier = LocalTraceIdentifier::new(known_contracts);
+        for (id, suite) in results {
+            let Some(&bytecode_hash) = hashes.get(id) else { continue };
+            let mut sources = BTreeSet::new();
+            for (_, result) in suite.tests() {
+                for (_, arena) in &result.traces {
+                    sources.extend(
+                        identifier
+                            .identify_addresses(arena.addresses().into_iter().collect())
+                            .into_iter()
+                            .filter_map(|identity| identity.artifact_id)
+                            .map(|artifact| artifact.source),
+                    );
+                }
+            }
+            self.contracts.insert(id.clone(), TestContractImpact { bytecode_hash, sources });
+        }
+    }
+}
+
+/// Returns the source file of a contract identifier `<path>:<name>`
+fn identifier_path(id: &str) -> &Path {
+    Path::new(id.rsplit_once(':').map(|(path, _)| path).unwrap_or(id))
+}
+
+/// Returns the hash of the given creation bytecode
+pub fn bytecode_hash(bytecode: &[u8]) -> H256 {
+    H256::from(sha3(bytecode))
+}
+
+/// Returns all files (transitively) imported by `file`, relative to the project `root`.
+///
+/// Returns `None` if the file is not part of the graph.
+pub fn imports(root: &Path, graph: &Graph, file: &Path) -> Option<BTreeSet<PathBuf>> {
+    let index = *graph.files().get(&root.join(file))?;
+    Some(
+        graph
+            .all_imported_nodes(index)
+            .map(|import| {
+                let path = graph.node(import).path();
+                path.strip_prefix(root).unwrap_or(path).to_path_buf()
+            })
+            .collect(),
+    )
+}
+
+/// Returns the test files affected by the `changed` files, relative to the project root, using
+/// only the import graph of the project.
+///
+/// This doesn't require compiled artifacts, which is why it is used in watch mode.
+pub fn affected_test_files_by_imports(
+    paths: &ProjectPathsConfig,
+    changed: &BTreeSet<PathBuf>,
+) -> eyre::Result<BTreeSet<PathBuf>> {
+    let graph = Graph::resolve(paths)?;
+    let root = &paths.root;
+    let test_files = graph
+        .files()
+        .keys()
+        .filter(|path| path.is_sol_test())
+        .map(|path| (path.strip_prefix(root).unwrap_or(path).display().to_string(), H256::zero()))
+        .collect::<TestContractHashes>();
+    Ok(affected_test_files(changed, &test_files, &TestImpactCache::default(), |file| {
+        imports(root, &graph, file)
+    }))
+}
+
+/// Returns the test files affected by the `changed` files.
+///
+/// All paths are relative to the project root. `test_contracts` are the hashes of all test
+/// contracts of the project, `imports` returns the imports of a test file, see [`imports`].
+pub fn affected_test_files(
+    changed: &BTreeSet<PathBuf>,
+    test_contracts: &TestContractHashes,
+    cache: &TestImpactCache,
+    imports: impl Fn(&Path) -> Option<BTreeSet<PathBuf>>,
+) -> BTreeSet<PathBuf> {
+    // group the test contracts by their file
+    let mut test_files = BTreeMap::<PathBuf, Vec<(&String, &H256)>>::new();
+    for (id, hash) in test_contracts {
+        test_files.entry(identifier_path(id).to_path_buf()).or_default().push((id, hash));
+    }
+
+    test_files
+        .into_iter()
+        .filter(|(file, contracts)| {
+            if changed.contains(file) {
+                return true
+            }
+
+            // the recorded sources are only valid if the test contracts didn't change
+            let recorded = contracts
+                .iter()
+                .map(|(id, hash)| {
+                    cache
+                        .contracts
+                        .get(*id)
+                        .filter(|impact| impact.bytecode_hash == **hash)
+                        .map(|impact| &impact.sources)
+                })
+                .collect::<Option<Vec<_>>>();
+            if let Some(recorded) = recorded {
+                return recorded.into_iter().flatten().any(|source| changed.contains(source))
+            }
+
+            // select files we don't know anything about
+            imports(file).map_or(true, |imports| imports.iter().any(|i| changed.contains(i)))
+        })
+        .map(|(file, _)| file)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn recorded_sources_refine_imports() {
+        let imports = |file: &Path| {
+            (file == Path::new("test/B.t.sol"))
+                .then(|| BTreeSet::from([PathBuf::from("src/Counter.sol")]))
+        };
+        let hashes = TestContractHashes::from([
+            ("test/A.t.sol:ATest".to_string(), H256::repeat_byte(1)),
+            ("test/B.t.sol:BTest".to_string(), H256::repeat_byte(2)),
+            ("test/C.t.sol:CTest".to_string(), H256::repeat_byte(3)),
+        ]);
+        let mut cache = TestImpactCache::default();
+        cache.contracts.insert(
+            "test/A.t.sol:ATest".to_string(),
+            TestContractImpact {
+                bytecode_hash: H256::repeat_byte(1),
+                sources: BTreeSet::from([PathBuf::from("src/Counter.sol")]),
+            },
+        );
+        // outdated record
+        cache.contracts.insert(
+            "test/B.t.sol:BTest".to_string(),
+            TestContractImpact { bytecode_hash: H256::zero(), sources: BTreeSet::new() },
+        );
+        cache.contracts.insert(
+            "test/C.t.sol:CTest".to_string(),
+            TestContractImpact { bytecode_hash: H256::repeat_byte(3), sources: BTreeSet::new() },
+        );
+
+        let changed = BTreeSet::from([PathBuf::from("src/Counter.sol")]);
+        // the record of `B` is outdated, so its imports are used
+        assert_eq!(
+            affected_test_files(&changed, &hashes, &cache, imports),
+            BTreeSet::from([PathBuf::from("test/A.t.sol"), PathBuf::from("test/B.t.sol")])
+        );
+
+        let changed = BTreeSet::from([PathBuf::from("test/C.t.sol")]);
+        assert_eq!(
+            affected_test_files(&changed, &hashes, &cache, imports),
+            BTreeSet::from([PathBuf::from("test/C.t.sol")])
+        );
+
+        // files without a record that are not part of the graph are always selected
+        cache.contracts.clear();
+        assert_eq!(
+            affected_test_files(&changed, &hashes, &cache, imports),
+            BTreeSet::from([PathBuf::from("test/A.t.sol"), PathBuf::from("test/C.t.sol")])
+        );
+    }
+}
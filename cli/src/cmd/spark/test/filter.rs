@@ -4,7 +4,11 @@ use corebc::ylem::{FileFilter, ProjectPathsConfig};
 use foxar_common::glob::GlobMatcher;
 use foxar_config::Config;
 use spark::TestFilter;
-use std::{fmt, path::Path};
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    fmt,
+    path::{Path, PathBuf},
+};
 
 /// The filter to use during testing.
 ///
@@ -65,7 +69,12 @@ impl FilterArgs {
         if filter.path_pattern_inverse.is_none() {
             filter.path_pattern_inverse = config.path_pattern_inverse.clone().map(Into::into);
         }
-        ProjectPathsAwareFilter { args_filter: filter, paths: config.project_paths() }
+        ProjectPathsAwareFilter {
+            args_filter: filter,
+            paths: config.project_paths(),
+            selected_files: None,
+            selected_tests: None,
+        }
     }
 }
 
@@ -168,6 +177,10 @@ impl fmt::Display for FilterArgs {
 pub struct ProjectPathsAwareFilter {
     args_filter: FilterArgs,
     paths: ProjectPathsConfig,
+    /// If set, only tests in these files, relative to the project root, are run
+    selected_files: Option<BTreeSet<PathBuf>>,
+    /// If set, only these tests are run, `contract identifier -> test signatures`
+    selected_tests: Option<BTreeMap<String, BTreeSet<String>>>,
 }
 
 // === impl ProjectPathsAwareFilter ===
@@ -182,6 +195,22 @@ impl ProjectPathsAwareFilter {
     pub fn args_mut(&mut self) -> &mut FilterArgs {
         &mut self.args_filter
     }
+
+    /// Only runs tests in the given files, relative to the project root
+    pub fn select_files(&mut self, files: BTreeSet<PathBuf>) {
+        self.selected_files = Some(files);
+    }
+
+    /// Only runs the given tests, `contract identifier -> test signatures`
+    pub fn select_tests(&mut self, tests: BTreeMap<String, BTreeSet<String>>) {
+        self.selected_tests = Some(tests);
+    }
+
+    /// Returns true if the file is selected, see [`Self::select_files`]
+    fn is_selected(&self, path: &Path) -> bool {
+        let Some(files) = &self.selected_files else { return true };
+        files.contains(path.strip_prefix(&self.paths.root).unwrap_or(path))
+    }
 }
 
 impl FileFilter for ProjectPathsAwareFilter {
@@ -206,12 +235,33 @@ impl TestFilter for ProjectPathsAwareFilter {
     fn matches_path(&self, path: impl AsRef<str>) -> bool {
         let path = path.as_ref();
         // we don't want to test files that belong to a library
-        self.args_filter.matches_path(path) && !self.paths.has_library_ancestor(Path::new(path))
+        self.args_filter.matches_path(path) &&
+            !self.paths.has_library_ancestor(Path::new(path)) &&
+            self.is_selected(Path::new(path))
+    }
+
+    fn matches_contract_test(&self, contract_id: &str, test_name: &str) -> bool {
+        let Some(tests) = &self.selected_tests else { return true };
+        tests.get(contract_id).map_or(false, |tests| tests.contains(test_name))
     }
 }
 
 impl fmt::Display for ProjectPathsAwareFilter {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        self.args_filter.fmt(f)
+        self.args_filter.fmt(f)?;
+        if let Some(files) = &self.selected_files {
+            if !self.args_filter.to_string().is_empty() {
+                writeln!(f)?;
+            }
+            write!(f, "\tchanged: {} test file(s)", files.len())?;
+        }
+        if let Some(tests) = &self.selected_tests {
+            if !self.args_filter.to_string().is_empty() || self.selected_files.is_some() {
+                writeln!(f)?;
+            }
+            let count = tests.values().map(BTreeSet::len).sum::<usize>();
+            write!(f, "\tshard: {count} test(s)")?;
+        }
+        Ok(())
     }
 }
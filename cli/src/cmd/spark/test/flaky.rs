@@ -0,0 +1,139 @@
+//! Flaky test history, tracks which tests only passed when retried
+
+use foxar_common::fs;
+use serde::{Deserialize, Serialize};
+use spark::result::{SuiteResult, TestStatus};
+use std::{
+    collections::BTreeMap,
+    path::Path,
+    time::{SystemTime, UNIX_EPOCH},
+};
+use tracing::trace;
+
+/// The name of the file in the project's cache dir the [`FlakyHistory`] is stored in
+pub const FLAKY_HISTORY_CACHE_FILE: &str = "flaky-tests.json";
+
+/// The history of a single test
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FlakyRecord {
+    /// How many times the test was run
+    pub runs: u64,
+    /// How many runs failed, even when retried
+    pub failures: u64,
+    /// How many runs failed, but passed when retried
+    pub flaky: u64,
+    /// The unix timestamp of the last flaky run, if any
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub last_flaky: Option<u64>,
+}
+
+/// The history of all tests that were flaky or failed at least once, `contract identifier ->
+/// signature -> record`
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct FlakyHistory {
+    pub tests: BTreeMap<String, BTreeMap<String, FlakyRecord>>,
+}
+
+impl FlakyHistory {
+    /// Reads the cache file, returns an empty history if it doesn't exist or can't be read.
+    pub fn read(path: impl AsRef<Path>) -> Self {
+        let path = path.as_ref();
+        if !path.exists() {
+            return Self::default()
+        }
+        fs::read_json_file(path)
+            .map_err(|err| trace!(target: "spark::test", ?err, "failed to read flaky history"))
+            .unwrap_or_default()
+    }
+
+    /// Writes the cache file.
+    pub fn write(&self, path: impl AsRef<Path>) -> eyre::Result<()> {
+        let path = path.as_ref();
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        fs::write_json_file(path, self)?;
+        Ok(())
+    }
+
+    /// Records the given results.
+    ///
+    /// Tests are only added to the history once they were flaky or failed, after that every run
+    /// is counted.
+    pub fn record(&mut self, results: &BTreeMap<String, SuiteResult>) {
+        let now = SystemTime::now().duration_since(UNIX_EPOCH).map(|d| d.as_secs()).ok();
+        for (id, suite) in results {
+            for (name, result) in suite.tests() {
+                let failed = result.status == TestStatus::Failure;
+                let tracked = self.tests.get(id).map_or(false, |tests| tests.contains_key(name));
+                if !tracked && !failed && !result.flaky {
+                    continue
+                }
+
+                let record =
+                    self.tests.entry(id.clone()).or_default().entry(name.clone()).or_default();
+                record.runs += 1;
+                if failed {
+                    record.failures += 1;
+                }
+                if result.flaky {
+                    record.flaky += 1;
+                    record.last_flaky = now;
+                }
+            }
+        }
+    }
+
+    /// Returns the tests that were flaky at least once, `(contract identifier, signature,
+    /// record)`
+    pub fn flaky_tests(&self) -> impl Iterator<Item = (&String, &String, &FlakyRecord)> {
+        self.tests
+            .iter()
+            .flat_map(|(id, tests)| tests.iter().map(move |(sig, record)| (id, sig, record)))
+            .filter(|(_, _, record)| record.flaky > 0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use spark::result::TestResult;
+    use std::time::Duration;
+
+    fn results(tests: Vec<(&str, TestResult)>) -> BTreeMap<String, SuiteResult> {
+        BTreeMap::from([(
+            "test/A.t.sol:ATest".to_string(),
+            SuiteResult::new(
+                Duration::from_secs(1),
+                tests.into_iter().map(|(name, result)| (name.to_string(), result)).collect(),
+                Vec::new(),
+            ),
+        )])
+    }
+
+    #[test]
+    fn can_record_flaky_tests() {
+        let pass = TestResult { status: TestStatus::Success, ..Default::default() };
+        let flaky = TestResult { status: TestStatus::Success, flaky: true, ..Default::default() };
+        let fail = TestResult { status: TestStatus::Failure, ..Default::default() };
+
+        let mut history = FlakyHistory::default();
+        history.record(&results(vec![
+            ("testPass()", pass.clone()),
+            ("testFlaky()", flaky),
+            ("testFail()", fail),
+        ]));
+        history.record(&results(vec![("testPass()", pass.clone()), ("testFlaky()", pass)]));
+
+        let tests = &history.tests["test/A.t.sol:ATest"];
+        assert!(!tests.contains_key("testPass()"));
+        let record = tests["testFlaky()"];
+        assert_eq!((record.runs, record.failures, record.flaky), (2, 0, 1));
+        assert!(record.last_flaky.is_some());
+        let record = tests["testFail()"];
+        assert_eq!((record.runs, record.failures, record.flaky), (1, 1, 0));
+
+        let flaky = history.flaky_tests().map(|(_, sig, _)| sig.as_str()).collect::<Vec<_>>();
+        assert_eq!(flaky, ["testFlaky()"]);
+    }
+}
@@ -0,0 +1,163 @@
+//! Merges the results of sharded test runs
+
+use super::{
+    flaky::{FlakyHistory, FLAKY_HISTORY_CACHE_FILE},
+    format_aggregated_summary,
+    shard::{TestDurations, TEST_DURATIONS_CACHE_FILE},
+    short_test_result, write_report, TestOutcome, TestReporterKind,
+};
+use clap::{Parser, ValueHint};
+use foxar_common::fs;
+use foxar_config::Config;
+use spark::{gas_report::GasReport, result::SuiteResult};
+use std::{collections::BTreeMap, io, path::PathBuf};
+
+/// CLI arguments for `spark test merge-results`.
+#[derive(Debug, Clone, Parser)]
+pub struct MergeResultsArgs {
+    /// The JSON test results of all shards, as written by `--reporter json`.
+    #[clap(value_hint = ValueHint::FilePath, value_name = "FILES", required = true)]
+    pub results: Vec<PathBuf>,
+
+    /// The gas reports of all shards, as written by `--gas-report-file`.
+    #[clap(long, value_hint = ValueHint::FilePath, value_name = "FILES", num_args(1..))]
+    pub gas_reports: Vec<PathBuf>,
+
+    /// The reporter used to output the merged test results.
+    #[clap(long, value_enum, value_name = "REPORTER")]
+    pub reporter: Option<TestReporterKind>,
+
+    /// Write the merged test report to the given file instead of stdout.
+    #[clap(long, value_hint = ValueHint::FilePath, value_name = "FILE")]
+    pub output: Option<PathBuf>,
+}
+
+impl MergeResultsArgs {
+    pub fn run(self, config: &Config, allow_failure: bool) -> eyre::Result<TestOutcome> {
+        let mut results = BTreeMap::<String, SuiteResult>::new();
+        for file in &self.results {
+            let shard: BTreeMap<String, SuiteResult> = fs::read_json_file(file)?;
+            merge_results(&mut results, shard);
+        }
+
+        let reporter = self.reporter.unwrap_or_default();
+        if reporter != TestReporterKind::Pretty && self.output.is_none() {
+            write_report(&reporter, &results, &mut io::stdout().lock())?;
+        } else {
+            for (name, suite) in &results {
+                println!();
+                for warning in &suite.warnings {
+                    eprintln!("{} {warning}", yansi::Paint::yellow("Warning:").bold());
+                }
+                let term = if suite.test_results.len() > 1 { "tests" } else { "test" };
+                println!("Running {} {term} for {name}", suite.test_results.len());
+                for (name, result) in suite.tests() {
+                    short_test_result(name, result);
+                }
+                let outcome = TestOutcome::new([(name.clone(), suite.clone())].into(), true);
+                println!("{}", outcome.summary());
+            }
+
+            if !self.gas_reports.is_empty() {
+                let mut gas_report = GasReport::default();
+                for file in &self.gas_reports {
+                    gas_report.merge(fs::read_json_file(file)?);
+                }
+                println!("{}", gas_report.finalize());
+            }
+
+            let outcome = TestOutcome::new(results, allow_failure);
+            if !outcome.results.is_empty() {
+                println!(
+                    "{}",
+                    format_aggregated_summary(
+                        outcome.results.len(),
+                        outcome.successes().count(),
+                        outcome.failures().count(),
+                        outcome.skips().count()
+                    )
+                );
+            }
+            if let Some(output) = self.output {
+                write_report(&reporter, &outcome.results, &mut fs::create_file(output)?)?;
+            }
+            results = outcome.results;
+        }
+
+        if config.cache {
+            let cache_file = config.cache_path.join(TEST_DURATIONS_CACHE_FILE);
+            let mut durations = TestDurations::read(&cache_file);
+            durations.record(&results);
+            durations.write(cache_file)?;
+
+            let cache_file = config.cache_path.join(FLAKY_HISTORY_CACHE_FILE);
+            let mut history = FlakyHistory::read(&cache_file);
+            history.record(&results);
+            history.write(cache_file)?;
+        }
+
+        Ok(TestOutcome::new(results, allow_failure))
+    }
+}
+
+/// Merges the results of a shard into `results`.
+///
+/// Shards can run different tests of the same contract, so their suites are combined.
+fn merge_results(
+    results: &mut BTreeMap<String, SuiteResult>,
+    shard: BTreeMap<String, SuiteResult>,
+) {
+    for (name, suite) in shard {
+        match results.get_mut(&name) {
+            Some(existing) => {
+                existing.duration += suite.duration;
+                existing.test_results.extend(suite.test_results);
+                for warning in suite.warnings {
+                    if !existing.warnings.contains(&warning) {
+                        existing.warnings.push(warning);
+                    }
+                }
+            }
+            None => {
+                results.insert(name, suite);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use spark::result::TestResult;
+    use std::time::Duration;
+
+    fn suite(tests: &[&str], warnings: &[&str]) -> SuiteResult {
+        SuiteResult::new(
+            Duration::from_secs(1),
+            tests.iter().map(|test| (test.to_string(), TestResult::default())).collect(),
+            warnings.iter().map(|warning| warning.to_string()).collect(),
+        )
+    }
+
+    #[test]
+    fn can_merge_shard_results() {
+        let mut results = BTreeMap::new();
+        merge_results(
+            &mut results,
+            BTreeMap::from([
+                ("test/A.t.sol:ATest".to_string(), suite(&["testA()"], &["warning"])),
+                ("test/B.t.sol:BTest".to_string(), suite(&["testB()"], &[])),
+            ]),
+        );
+        merge_results(
+            &mut results,
+            BTreeMap::from([("test/A.t.sol:ATest".to_string(), suite(&["testC()"], &["warning"]))]),
+        );
+
+        let a = &results["test/A.t.sol:ATest"];
+        assert_eq!(a.test_results.keys().collect::<Vec<_>>(), ["testA()", "testC()"]);
+        assert_eq!(a.warnings, ["warning"]);
+        assert_eq!(a.duration, Duration::from_secs(2));
+        assert_eq!(results.len(), 2);
+    }
+}
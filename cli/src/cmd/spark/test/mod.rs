@@ -4,42 +4,58 @@ use crate::{
         spark::{build::CoreBuildArgs, debug::DebugArgs, install, watch::WatchArgs},
         LoadConfig,
     },
-    suggestions, utils,
+    suggestions,
+    utils::{self, Git},
 };
-use clap::Parser;
-use corebc::types::U256;
+use clap::{Parser, ValueEnum, ValueHint};
+use corebc::{types::U256, ylem::Graph};
 use foxar_common::{
     compile::{self, ProjectCompiler},
     evm::EvmArgs,
-    get_contract_name, get_file_name,
+    fs, get_contract_name, get_file_name,
 };
-use foxar_config::{figment, get_available_profiles, Config};
+use foxar_config::{figment, get_available_profiles, Config, LimitsConfig};
 use probe::fuzz::CounterExample;
 use regex::Regex;
 use spark::{
     decode::decode_console_logs,
     executor::inspector::CheatsConfig,
     gas_report::GasReport,
+    reporter::{JsonReporter, JunitReporter, TapReporter, TestReporter},
     result::{SuiteResult, TestKind, TestResult, TestStatus},
     trace::{
         identifier::{EtherscanIdentifier, LocalTraceIdentifier, SignaturesIdentifier},
         CallTraceDecoderBuilder, TraceKind,
     },
-    MultiContractRunner, MultiContractRunnerBuilder, TestOptions, TestOptionsBuilder,
+    MultiContractRunner, MultiContractRunnerBuilder, TestFilter, TestOptions, TestOptionsBuilder,
+};
+use std::{
+    collections::BTreeMap,
+    io::{self, Write},
+    path::PathBuf,
+    sync::mpsc::channel,
+    time::Duration,
 };
-use std::{collections::BTreeMap, path::PathBuf, sync::mpsc::channel, time::Duration};
 use tracing::trace;
 use watchexec::config::{InitConfig, RuntimeConfig};
 use yansi::Paint;
+pub mod changed;
 mod filter;
+pub mod flaky;
+pub mod merge;
+pub mod shard;
 use crate::cmd::spark::test::filter::ProjectPathsAwareFilter;
+use changed::{TestContractHashes, TestImpactCache, TEST_IMPACT_CACHE_FILE};
 pub use filter::FilterArgs;
-use foxar_common::shell;
+use flaky::{FlakyHistory, FLAKY_HISTORY_CACHE_FILE};
+use foxar_common::{shell, TestFunctionExt};
 use foxar_config::figment::{
     value::{Dict, Map},
     Metadata, Profile, Provider,
 };
 use foxar_evm::utils::evm_spec;
+use merge::MergeResultsArgs;
+use shard::{Shard, TestDurations, TEST_DURATIONS_CACHE_FILE};
 
 // Loads project's figment and merges the build cli arguments into it
 foxar_config::merge_impl_figment_convert!(TestArgs, opts, evm_opts);
@@ -48,6 +64,9 @@ foxar_config::merge_impl_figment_convert!(TestArgs, opts, evm_opts);
 #[derive(Debug, Clone, Parser)]
 #[clap(next_help_heading = "Test options")]
 pub struct TestArgs {
+    #[clap(subcommand)]
+    command: Option<TestSubcommands>,
+
     #[clap(flatten)]
     filter: FilterArgs,
 
@@ -73,18 +92,65 @@ pub struct TestArgs {
     #[clap(long, env = "FORGE_GAS_REPORT")]
     gas_report: bool,
 
+    /// Write the gas report as JSON to the given file.
+    ///
+    /// The gas reports of sharded runs can be combined with `spark test merge-results`.
+    #[clap(long, value_hint = ValueHint::FilePath, value_name = "FILE", requires = "gas_report")]
+    gas_report_file: Option<PathBuf>,
+
     /// Exit with code 0 even if a test fails.
     #[clap(long, env = "FORGE_ALLOW_FAILURE")]
     allow_failure: bool,
 
     /// Output test results in JSON format.
-    #[clap(long, short, help_heading = "Display options")]
+    ///
+    /// Same as `--reporter json`.
+    #[clap(long, short, help_heading = "Display options", conflicts_with = "reporter")]
     json: bool,
 
+    /// The reporter used to output the test results.
+    #[clap(long, value_enum, value_name = "REPORTER", help_heading = "Display options")]
+    reporter: Option<TestReporterKind>,
+
+    /// Write the test report to the given file instead of stdout.
+    ///
+    /// The human-readable test output is still printed to stdout.
+    #[clap(long, value_hint = ValueHint::FilePath, value_name = "FILE", help_heading = "Display options")]
+    output: Option<PathBuf>,
+
     /// Stop running tests after the first failure
     #[clap(long)]
     pub fail_fast: bool,
 
+    /// Retry failing tests up to the given number of times.
+    ///
+    /// Tests that pass on a retry are reported as flaky.
+    #[clap(long, value_name = "N", default_value_t = 0)]
+    pub retries: u32,
+
+    /// Only run the tests affected by the files changed since the given git ref.
+    ///
+    /// Defaults to `HEAD`, i.e. only uncommitted and untracked changes are considered.
+    ///
+    /// Test files are selected if they (transitively) import a changed file. The sources used by
+    /// every test contract are recorded in the cache to refine the selection of later runs.
+    #[clap(
+        long,
+        value_name = "GIT_REF",
+        num_args(0..=1),
+        default_missing_value = "HEAD",
+        conflicts_with = "debug"
+    )]
+    pub changed: Option<String>,
+
+    /// Only run the tests of the given shard, e.g. `3/8` runs the third of eight shards.
+    ///
+    /// Test functions are split into stable partitions, balanced by the durations of previous
+    /// runs recorded in the cache. Sharded runs don't record durations, use
+    /// `spark test merge-results` to combine the results of all shards and record them.
+    #[clap(long, value_name = "INDEX/COUNT", conflicts_with = "debug")]
+    pub shard: Option<Shard>,
+
     #[clap(flatten)]
     evm_opts: EvmArgs,
 
@@ -116,9 +182,30 @@ impl TestArgs {
         &self.opts
     }
 
+    /// Returns the reporter used to output the test results.
+    pub fn reporter(&self) -> TestReporterKind {
+        if self.json {
+            TestReporterKind::Json
+        } else {
+            self.reporter.clone().unwrap_or_default()
+        }
+    }
+
+    /// Returns whether the test report replaces the human-readable output on stdout.
+    fn is_machine_readable(&self) -> bool {
+        self.reporter() != TestReporterKind::Pretty && self.output.is_none()
+    }
+
     pub async fn run(self) -> eyre::Result<TestOutcome> {
         trace!(target: "spark::test", "executing test command");
-        shell::set_shell(shell::Shell::from_args(self.opts.silent, self.json))?;
+        if let Some(TestSubcommands::MergeResults(args)) = self.command.clone() {
+            let machine_readable = args.reporter.as_ref().map_or(false, |reporter| {
+                *reporter != TestReporterKind::Pretty && args.output.is_none()
+            });
+            shell::set_shell(shell::Shell::from_args(self.opts.silent, machine_readable))?;
+            return args.run(&self.load_config(), self.allow_failure)
+        }
+        shell::set_shell(shell::Shell::from_args(self.opts.silent, self.is_machine_readable()))?;
         self.execute_tests().await
     }
 
@@ -129,6 +216,10 @@ impl TestArgs {
     ///
     /// Returns the test results for all matching tests.
     pub async fn execute_tests(self) -> eyre::Result<TestOutcome> {
+        if self.output.is_some() && self.reporter() == TestReporterKind::Pretty {
+            eyre::bail!("--output requires a json, junit or tap --reporter");
+        }
+
         // Merge all configs
         let (mut config, mut evm_opts) = self.load_config_and_evm_opts_emit_warnings()?;
 
@@ -166,13 +257,18 @@ impl TestArgs {
         let test_options: TestOptions = TestOptionsBuilder::default()
             .fuzz(config.fuzz)
             .invariant(config.invariant)
+            .limits(LimitsConfig::new(config.timeout, config.energy_budget, config.step_budget))
+            .retries(self.retries)
+            .quarantine(config.quarantine.clone())
             .compile_output(&output)
             .profiles(profiles)
+            .fixtures(project_root.join(&config.test).join("fixtures"))
             .build(project_root)?;
 
         // Determine print verbosity and executor verbosity
         let verbosity = evm_opts.verbosity;
-        if self.gas_report && evm_opts.verbosity < 3 {
+        // traces are required to record the sources used by the tests
+        if (self.gas_report || self.changed.is_some()) && evm_opts.verbosity < 3 {
             evm_opts.verbosity = 3;
         }
 
@@ -190,6 +286,63 @@ impl TestArgs {
             .with_test_options(test_options.clone())
             .build(project_root, output, env, evm_opts)?;
 
+        // Select the tests affected by the changed files
+        let mut impact = None;
+        if let Some(since) = &self.changed {
+            let hashes = runner
+                .contracts
+                .iter()
+                .map(|(id, (_, code, _))| (id.identifier(), changed::bytecode_hash(code)))
+                .collect::<TestContractHashes>();
+            let cache_file = config.cache_path.join(TEST_IMPACT_CACHE_FILE);
+            let cache = TestImpactCache::read(&cache_file);
+
+            let changed_files = Git::new(project_root).changed_files(since)?;
+            let graph = Graph::resolve(&project.paths)?;
+            let affected = changed::affected_test_files(&changed_files, &hashes, &cache, |file| {
+                changed::imports(project_root, &graph, file)
+            });
+            trace!(target: "spark::test", ?changed_files, ?affected, "selected changed tests");
+
+            if affected.is_empty() {
+                println!("\nNo tests are affected by the files changed since `{since}`.");
+                return Ok(TestOutcome::new(BTreeMap::new(), self.allow_failure))
+            }
+            filter.select_files(affected);
+
+            // the recorded sources are only complete if all tests of a contract were run
+            let is_complete = filter.args().test_pattern.is_none() &&
+                filter.args().test_pattern_inverse.is_none() &&
+                !self.fail_fast;
+            if config.cache && is_complete {
+                impact = Some((cache_file, cache, hashes, runner.known_contracts.clone()));
+            }
+        }
+
+        // Select the tests of the shard
+        if let Some(shard) = self.shard {
+            let tests = runner
+                .contracts
+                .iter()
+                .filter(|(id, _)| {
+                    filter.matches_path(id.source.to_string_lossy()) &&
+                        filter.matches_contract(&id.name)
+                })
+                .flat_map(|(id, (abi, _, _))| {
+                    let id = id.identifier();
+                    abi.functions()
+                        .filter(|func| func.is_test() || func.is_invariant_test())
+                        .filter(|func| filter.matches_test(func.signature()))
+                        .map(move |func| (id.clone(), func.signature()))
+                })
+                .collect::<Vec<_>>();
+            let durations = TestDurations::read(config.cache_path.join(TEST_DURATIONS_CACHE_FILE));
+            let selected =
+                shard.select(tests.iter().map(|(id, sig)| (id.as_str(), sig.as_str())), &durations);
+            trace!(target: "spark::test", %shard, ?selected, "selected shard tests");
+            filter.select_tests(selected);
+        }
+
         if self.debug.is_some() {
             filter.args_mut().test_pattern = self.debug;
 
@@ -200,7 +353,11 @@ impl TestArgs {
 
                     // Get the result of the single test
                     let (id, sig, test_kind, counterexample, breakpoints) = results.iter().map(|(id, SuiteResult{ test_results, .. })| {
-                        let (sig, result) = test_results.iter().next().unwrap();
+                        // debug the first failing row of parameterized tests
+                        let (sig, result) = test_results
+                            .iter()
+                            .find(|(_, result)| result.status == TestStatus::Failure)
+                            .unwrap_or_else(|| test_results.iter().next().unwrap());
 
                         (id.clone(), sig.clone(), result.kind.clone(), result.counterexample.clone(), result.breakpoints.clone())
                     }).next().unwrap();
@@ -214,6 +371,7 @@ impl TestArgs {
                                 first_case.calldata.to_string()
                             }
                         },
+                        TestKind::Table { calldata, .. } => calldata.to_string(),
                         _ => sig,
                     };
 
@@ -240,20 +398,48 @@ impl TestArgs {
                         Use --match-contract and --match-path to further limit the search."))
             }
         } else if self.list {
-            list(runner, filter, self.json)
+            list(runner, filter, self.reporter() == TestReporterKind::Json)
         } else {
-            test(
+            let reporter = self.reporter();
+            // Shards must partition from the same durations, which are only recorded by
+            // `merge-results` once all shards ran. It records the flaky history of the shards too,
+            // so their runs aren't counted twice.
+            let durations_file = (config.cache && self.shard.is_none())
+                .then(|| config.cache_path.join(TEST_DURATIONS_CACHE_FILE));
+            let flaky_file = (config.cache && self.shard.is_none())
+                .then(|| config.cache_path.join(FLAKY_HISTORY_CACHE_FILE));
+            let outcome = test(
                 config,
                 runner,
                 verbosity,
                 filter,
-                self.json,
+                reporter,
+                self.output,
                 self.allow_failure,
                 test_options,
                 self.gas_report,
+                self.gas_report_file,
                 self.fail_fast,
             )
-            .await
+            .await?;
+
+            if let Some(durations_file) = durations_file {
+                let mut durations = TestDurations::read(&durations_file);
+                durations.record(&outcome.results);
+                durations.write(durations_file)?;
+            }
+
+            if let Some(flaky_file) = flaky_file {
+                let mut history = FlakyHistory::read(&flaky_file);
+                history.record(&outcome.results);
+                history.write(flaky_file)?;
+            }
+
+            if let Some((cache_file, mut cache, hashes, known_contracts)) = impact {
+                cache.record(&outcome.results, &hashes, &known_contracts);
+                cache.write(cache_file)?;
+            }
+            Ok(outcome)
         }
     }
 
@@ -262,6 +448,12 @@ impl TestArgs {
         self.filter.merge_with_config(config)
     }
 
+    /// Returns true if all the tests of the project are run, without filters, shards or a
+    /// selection of changed tests
+    pub fn runs_all_tests(&self, config: &Config) -> bool {
+        self.filter(config).to_string().is_empty() && self.shard.is_none() && self.changed.is_none()
+    }
+
     /// Returns whether `BuildArgs` was configured with `--watch`
     pub fn is_watch(&self) -> bool {
         self.watch.watch.is_some()
@@ -302,6 +494,14 @@ impl Provider for TestArgs {
     }
 }
 
+/// Subcommands of `spark test`
+#[derive(Debug, Clone, Parser)]
+pub enum TestSubcommands {
+    /// Merge the JSON results and gas reports of sharded test runs into one summary.
+    #[clap(visible_alias = "merge")]
+    MergeResults(MergeResultsArgs),
+}
+
 /// The result of a single test
 #[derive(Debug, Clone)]
 pub struct Test {
@@ -372,9 +572,16 @@ impl TestOutcome {
             .map(|(artifact_id, (signature, result))| Test { artifact_id, signature, result })
     }
 
+    /// Iterator over all tests that passed when retried
+    pub fn flaky(&self) -> impl Iterator<Item = (&String, &TestResult)> {
+        self.tests().filter(|(_, t)| t.flaky)
+    }
+
     /// Checks if there are any failures and failures are disallowed
+    ///
+    /// Failures of quarantined tests are ignored.
     pub fn ensure_ok(&self) -> eyre::Result<()> {
-        let failures = self.failures().count();
+        let failures = self.tests().filter(|(_, t)| t.is_failure()).count();
         if self.allow_failure || failures == 0 {
             return Ok(());
         }
@@ -387,14 +594,14 @@ impl TestOutcome {
         println!();
         println!("Failing tests:");
         for (suite_name, suite) in self.results.iter() {
-            let failures = suite.failures().count();
+            let failures = suite.tests().filter(|(_, t)| t.is_failure()).count();
             if failures == 0 {
                 continue;
             }
 
             let term = if failures > 1 { "tests" } else { "test" };
             println!("Encountered {failures} failing {term} in {suite_name}");
-            for (name, result) in suite.failures() {
+            for (name, result) in suite.tests().filter(|(_, t)| t.is_failure()) {
                 short_test_result(name, result);
             }
             println!();
@@ -417,9 +624,15 @@ impl TestOutcome {
 
     pub fn summary(&self) -> String {
         let failed = self.failures().count();
-        let result = if failed == 0 { Paint::green("ok") } else { Paint::red("FAILED") };
+        let result = if self.tests().any(|(_, t)| t.is_failure()) {
+            Paint::red("FAILED")
+        } else {
+            Paint::green("ok")
+        };
+        let flaky = self.flaky().count();
+        let flaky = if flaky > 0 { format!(" ({flaky} flaky)") } else { String::new() };
         format!(
-            "Test result: {}. {} passed; {} failed; {} skipped; finished in {:.2?}",
+            "Test result: {}. {} passed{flaky}; {} failed; {} skipped; finished in {:.2?}",
             result,
             self.successes().count(),
             failed,
@@ -431,9 +644,13 @@ impl TestOutcome {
 
 fn short_test_result(name: &str, result: &TestResult) {
     let status = if result.status == TestStatus::Success {
-        Paint::green("[PASS]".to_string())
+        if result.flaky {
+            format!("{} {}", Paint::green("[PASS]"), Paint::yellow("[FLAKY]"))
+        } else {
+            Paint::green("[PASS]").to_string()
+        }
     } else if result.status == TestStatus::Skipped {
-        Paint::yellow("[SKIP]".to_string())
+        Paint::yellow("[SKIP]").to_string()
     } else {
         let reason = result
             .reason
@@ -457,7 +674,12 @@ fn short_test_result(name: &str, result: &TestResult) {
             })
             .unwrap_or_else(|| "]".to_string());
 
-        Paint::red(format!("[FAIL. {reason}{counterexample}"))
+        let fail = Paint::red(format!("[FAIL. {reason}{counterexample}"));
+        if result.quarantined {
+            format!("{} {fail}", Paint::yellow("[QUARANTINED]"))
+        } else {
+            fail.to_string()
+        }
     };
 
     println!("{status} {name} {}", result.kind.report());
@@ -479,6 +701,35 @@ fn format_aggregated_summary(
     )
 }
 
+/// The available test reporters
+#[derive(Debug, Clone, Default, PartialEq, Eq, ValueEnum)]
+pub enum TestReporterKind {
+    /// Human-readable output, streamed while the tests are running
+    #[default]
+    Pretty,
+    /// JSON serialized test results
+    Json,
+    /// JUnit XML
+    Junit,
+    /// Test Anything Protocol (version 13)
+    Tap,
+}
+
+/// Writes the report of the given test results to `destination`.
+fn write_report(
+    reporter: &TestReporterKind,
+    results: &BTreeMap<String, SuiteResult>,
+    destination: &mut dyn Write,
+) -> eyre::Result<()> {
+    match reporter {
+        TestReporterKind::Json | TestReporterKind::Pretty => {
+            JsonReporter::new(destination).report(results)
+        }
+        TestReporterKind::Junit => JunitReporter::new(destination).report(results),
+        TestReporterKind::Tap => TapReporter::new(destination).report(results),
+    }
+}
+
 /// Lists all matching tests
 fn list(
     runner: MultiContractRunner,
@@ -508,10 +759,12 @@ async fn test(
     mut runner: MultiContractRunner,
     verbosity: u8,
     filter: ProjectPathsAwareFilter,
-    json: bool,
+    reporter: TestReporterKind,
+    output: Option<PathBuf>,
     allow_failure: bool,
     test_options: TestOptions,
     gas_reporting: bool,
+    gas_report_file: Option<PathBuf>,
     fail_fast: bool,
 ) -> eyre::Result<TestOutcome> {
     trace!(target: "spark::test", "running all tests");
@@ -535,9 +788,9 @@ async fn test(
         }
     }
 
-    if json {
+    if reporter != TestReporterKind::Pretty && output.is_none() {
         let results = runner.test(filter, None, test_options).await;
-        println!("{}", serde_json::to_string(&results)?);
+        write_report(&reporter, &results, &mut io::stdout().lock())?;
         Ok(TestOutcome::new(results, allow_failure))
     } else {
         // Set up identifiers
@@ -577,7 +830,7 @@ async fn test(
                 short_test_result(name, result);
 
                 // If the test failed, we want to stop processing the rest of the tests
-                if fail_fast && result.status == TestStatus::Failure {
+                if fail_fast && result.is_failure() {
                     break 'outer;
                 }
 
@@ -661,7 +914,11 @@ async fn test(
         }
 
         if gas_reporting {
-            println!("{}", gas_report.finalize());
+            let gas_report = gas_report.finalize();
+            println!("{gas_report}");
+            if let Some(file) = gas_report_file {
+                fs::write_json_file(&file, &gas_report)?;
+            }
         }
 
         let num_test_suites = results.len();
@@ -681,6 +938,10 @@ async fn test(
         // reattach the thread
         let _results = handle.await?;
 
+        if let Some(output) = output {
+            write_report(&reporter, &results, &mut fs::create_file(output)?)?;
+        }
+
         trace!(target: "spark::test", "received {} results", results.len());
         Ok(TestOutcome::new(results, allow_failure))
     }
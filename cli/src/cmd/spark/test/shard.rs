@@ -0,0 +1,226 @@
+//! Test sharding, splits the test functions of a project into stable partitions
+//!
+//! Every test function is assigned to exactly one of `count` shards. Shards are balanced by the
+//! durations of previous runs recorded in the [`TestDurations`] cache, tests without a recorded
+//! duration are weighted with the mean of all known durations. The assignment only depends on the
+//! set of tests and the recorded durations, so every shard of a CI job computes the same
+//! partition as long as all shards start from the same cache. Sharded runs therefore never
+//! record durations, they are recorded by `spark test merge-results` from the results of all
+//! shards.
+
+use foxar_common::fs;
+use serde::{Deserialize, Serialize};
+use spark::result::SuiteResult;
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    fmt,
+    path::Path,
+    str::FromStr,
+};
+use tracing::trace;
+
+/// The name of the file in the project's cache dir the [`TestDurations`] are stored in
+pub const TEST_DURATIONS_CACHE_FILE: &str = "test-durations.json";
+
+/// The tests selected for a shard, `contract identifier -> test signatures`
+pub type ShardTests = BTreeMap<String, BTreeSet<String>>;
+
+/// A shard `index/count`, e.g. `3/8`, the index is 1-based
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Shard {
+    pub index: usize,
+    pub count: usize,
+}
+
+impl FromStr for Shard {
+    type Err = eyre::Report;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (index, count) = s
+            .split_once('/')
+            .ok_or_else(|| eyre::eyre!("Invalid shard `{s}`, expected `<index>/<count>`"))?;
+        let index: usize = index.trim().parse()?;
+        let count: usize = count.trim().parse()?;
+        eyre::ensure!(count > 0, "The shard count must be greater than 0");
+        eyre::ensure!(
+            (1..=count).contains(&index),
+            "The shard index must be between 1 and {count}, got {index}"
+        );
+        Ok(Self { index, count })
+    }
+}
+
+impl fmt::Display for Shard {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}/{}", self.index, self.count)
+    }
+}
+
+impl Shard {
+    /// Returns the tests of this shard.
+    ///
+    /// `tests` are all tests of the project that match the filter, as `(contract identifier,
+    /// signature)`.
+    pub fn select<'a>(
+        &self,
+        tests: impl IntoIterator<Item = (&'a str, &'a str)>,
+        durations: &TestDurations,
+    ) -> ShardTests {
+        partition(tests, durations, self.count).swap_remove(self.index - 1)
+    }
+}
+
+/// The recorded durations of all tests in microseconds, `contract identifier -> signature ->
+/// duration`
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct TestDurations {
+    pub contracts: BTreeMap<String, BTreeMap<String, u64>>,
+}
+
+impl TestDurations {
+    /// Reads the cache file, returns an empty cache if it doesn't exist or can't be read.
+    pub fn read(path: impl AsRef<Path>) -> Self {
+        let path = path.as_ref();
+        if !path.exists() {
+            return Self::default()
+        }
+        fs::read_json_file(path)
+            .map_err(|err| trace!(target: "spark::test", ?err, "failed to read test durations"))
+            .unwrap_or_default()
+    }
+
+    /// Writes the cache file.
+    pub fn write(&self, path: impl AsRef<Path>) -> eyre::Result<()> {
+        let path = path.as_ref();
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        fs::write_json_file(path, self)?;
+        Ok(())
+    }
+
+    /// Records the durations of the given results.
+    ///
+    /// The rows of table tests, `<signature>[<row>]`, are recorded as a single test.
+    pub fn record(&mut self, results: &BTreeMap<String, SuiteResult>) {
+        for (id, suite) in results {
+            let mut durations = BTreeMap::<String, u64>::new();
+            for (name, result) in suite.tests() {
+                let sig = name.split_once('[').map(|(sig, _)| sig).unwrap_or(name);
+                *durations.entry(sig.to_string()).or_default() +=
+                    result.duration.as_micros() as u64;
+            }
+            self.contracts.entry(id.clone()).or_default().extend(durations);
+        }
+    }
+
+    /// Returns the recorded duration of a test
+    pub fn get(&self, id: &str, sig: &str) -> Option<u64> {
+        self.contracts.get(id)?.get(sig).copied()
+    }
+
+    /// Returns the mean of all recorded durations, at least 1
+    fn mean(&self) -> u64 {
+        let (sum, count) = self
+            .contracts
+            .values()
+            .flat_map(|tests| tests.values())
+            .fold((0u128, 0u128), |(sum, count), &duration| (sum + duration as u128, count + 1));
+        if count == 0 {
+            1
+        } else {
+            ((sum / count) as u64).max(1)
+        }
+    }
+}
+
+/// Splits the tests into `count` partitions of similar total duration.
+///
+/// The longest tests are assigned first, each to the partition with the lowest total duration.
+fn partition<'a>(
+    tests: impl IntoIterator<Item = (&'a str, &'a str)>,
+    durations: &TestDurations,
+    count: usize,
+) -> Vec<ShardTests> {
+    let mean = durations.mean();
+    let mut tests = tests
+        .into_iter()
+        .map(|(id, sig)| (durations.get(id, sig).unwrap_or(mean), id, sig))
+        .collect::<Vec<_>>();
+    tests.sort_unstable_by(|a, b| b.0.cmp(&a.0).then_with(|| (a.1, a.2).cmp(&(b.1, b.2))));
+    tests.dedup_by(|a, b| (a.1, a.2) == (b.1, b.2));
+
+    let mut shards = vec![(0u64, ShardTests::new()); count];
+    for (duration, id, sig) in tests {
+        // `min_by_key` returns the first shard on ties
+        let (total, shard) = shards.iter_mut().min_by_key(|(total, _)| *total).unwrap();
+        *total += duration;
+        shard.entry(id.to_string()).or_default().insert(sig.to_string());
+    }
+    shards.into_iter().map(|(_, shard)| shard).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn can_parse_shard() {
+        assert_eq!("3/8".parse::<Shard>().unwrap(), Shard { index: 3, count: 8 });
+        assert!("0/8".parse::<Shard>().is_err());
+        assert!("9/8".parse::<Shard>().is_err());
+        assert!("1/0".parse::<Shard>().is_err());
+        assert!("3".parse::<Shard>().is_err());
+    }
+
+    #[test]
+    fn partitions_are_balanced_and_stable() {
+        let tests = [
+            ("test/A.t.sol:ATest", "testSlow()"),
+            ("test/A.t.sol:ATest", "testFast()"),
+            ("test/B.t.sol:BTest", "testA()"),
+            ("test/B.t.sol:BTest", "testB()"),
+            ("test/B.t.sol:BTest", "testNew()"),
+        ];
+        let mut durations = TestDurations::default();
+        durations.contracts.insert(
+            "test/A.t.sol:ATest".to_string(),
+            BTreeMap::from([("testSlow()".to_string(), 100), ("testFast()".to_string(), 10)]),
+        );
+        durations.contracts.insert(
+            "test/B.t.sol:BTest".to_string(),
+            BTreeMap::from([("testA()".to_string(), 40), ("testB()".to_string(), 50)]),
+        );
+
+        // the mean of the recorded durations, 50, is used for `testNew`
+        let shards = partition(tests, &durations, 2);
+        assert_eq!(
+            shards[0],
+            ShardTests::from([
+                ("test/A.t.sol:ATest".to_string(), BTreeSet::from(["testSlow()".to_string()])),
+                ("test/B.t.sol:BTest".to_string(), BTreeSet::from(["testA()".to_string()])),
+            ])
+        );
+        assert_eq!(
+            shards[1],
+            ShardTests::from([
+                ("test/A.t.sol:ATest".to_string(), BTreeSet::from(["testFast()".to_string()])),
+                (
+                    "test/B.t.sol:BTest".to_string(),
+                    BTreeSet::from(["testB()".to_string(), "testNew()".to_string()])
+                ),
+            ])
+        );
+
+        // the order of the tests doesn't matter
+        let mut reversed = tests;
+        reversed.reverse();
+        assert_eq!(partition(reversed, &durations, 2), shards);
+
+        // every test is assigned to exactly one shard
+        let shards = partition(tests, &TestDurations::default(), 3);
+        let total =
+            shards.iter().flat_map(|shard| shard.values()).map(BTreeSet::len).sum::<usize>();
+        assert_eq!(total, tests.len());
+    }
+}
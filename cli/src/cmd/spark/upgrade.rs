@@ -0,0 +1,623 @@
+//! upgrade command
+
+use crate::{
+    cmd::{spark::build::CoreBuildArgs, Cmd},
+    opts::spark::CompilerArgs,
+};
+use clap::{Parser, Subcommand};
+use corebc::{
+    prelude::{artifacts::output_selection::ContractOutputSelection, info::ContractInfo},
+    types::U256,
+    ylem::{
+        artifacts::{Storage, StorageLayout, StorageType},
+        utils::canonicalize,
+    },
+};
+use foxar_common::compile;
+use std::{collections::HashSet, fmt};
+use yansi::Paint;
+
+/// CLI arguments for `spark upgrade`.
+#[derive(Debug, Parser)]
+pub struct UpgradeArgs {
+    #[clap(subcommand)]
+    pub sub: UpgradeSubcommands,
+}
+
+#[derive(Debug, Subcommand)]
+pub enum UpgradeSubcommands {
+    /// Checks that the storage layout of a new implementation is compatible with the current one.
+    Check(UpgradeCheckArgs),
+}
+
+impl Cmd for UpgradeArgs {
+    type Output = ();
+
+    fn run(self) -> eyre::Result<Self::Output> {
+        match self.sub {
+            UpgradeSubcommands::Check(cmd) => cmd.run(),
+        }
+    }
+}
+
+/// CLI arguments for `spark upgrade check`.
+#[derive(Debug, Clone, Parser)]
+pub struct UpgradeCheckArgs {
+    /// The current implementation in the form `(<path>:)?<contractname>`.
+    pub old: ContractInfo,
+
+    /// The new implementation in the form `(<path>:)?<contractname>`.
+    pub new: ContractInfo,
+
+    /// All build arguments are supported
+    #[clap(flatten)]
+    build: CoreBuildArgs,
+}
+
+impl Cmd for UpgradeCheckArgs {
+    type Output = ();
+
+    fn run(self) -> eyre::Result<Self::Output> {
+        let UpgradeCheckArgs { mut old, mut new, build } = self;
+
+        let mut cos = build.compiler.extra_output;
+        if !cos.contains(&ContractOutputSelection::StorageLayout) {
+            cos.push(ContractOutputSelection::StorageLayout);
+        }
+        let build = CoreBuildArgs {
+            compiler: CompilerArgs { extra_output: cos, ..build.compiler },
+            ..build
+        };
+        let project = build.project()?;
+
+        let mut paths = vec![];
+        for contract in [&mut old, &mut new] {
+            if let Some(ref mut contract_path) = contract.path {
+                let target_path = canonicalize(&*contract_path)?;
+                *contract_path = target_path.to_string_lossy().to_string();
+                paths.push(target_path);
+            }
+        }
+        // Both contracts are compiled on their own only if both of their paths are known
+        let outcome = if paths.len() == 2 {
+            compile::compile_files(&project, paths, true)
+        } else {
+            compile::suppress_compile(&project)
+        }?;
+
+        let layout = |contract: &ContractInfo| {
+            outcome
+                .find_contract(contract)
+                .ok_or_else(|| {
+                    eyre::eyre!("Could not find artifact `{contract}` in the compiled artifacts")
+                })?
+                .storage_layout
+                .clone()
+                .ok_or_else(|| eyre::eyre!("No storage layout in the artifact of `{contract}`"))
+        };
+        let changes = compare_storage_layouts(&layout(&old)?, &layout(&new)?);
+
+        for change in &changes {
+            if change.is_incompatible() {
+                println!("{} {change}", Paint::red("error:"));
+            } else {
+                println!("{} {change}", Paint::yellow("note:"));
+            }
+        }
+
+        let incompatible = changes.iter().filter(|change| change.is_incompatible()).count();
+        if incompatible > 0 {
+            eyre::bail!(
+                "The storage layout of `{new}` is incompatible with `{old}`, found {incompatible} incompatible changes"
+            )
+        }
+        println!("The storage layout of `{new}` is compatible with `{old}`.");
+        Ok(())
+    }
+}
+
+/// A change of a storage variable of the current implementation in the new implementation
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum StorageLayoutChange {
+    /// The variable was removed
+    Removed { label: String, ty: String, slot: U256, offset: usize },
+    /// The variable moved to another slot or offset
+    Moved { label: String, ty: String, from: (U256, usize), to: (U256, usize) },
+    /// The variable at the slot and offset has a different type
+    TypeChanged { label: String, slot: U256, offset: usize, from: String, to: String },
+    /// The variable at the slot and offset has the same type, but a different name
+    Renamed { slot: U256, offset: usize, from: String, to: String },
+}
+
+impl StorageLayoutChange {
+    /// Returns true if the new implementation would read the variable from the wrong slot or with
+    /// the wrong type
+    pub fn is_incompatible(&self) -> bool {
+        !matches!(self, StorageLayoutChange::Renamed { .. })
+    }
+}
+
+impl fmt::Display for StorageLayoutChange {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            StorageLayoutChange::Removed { label, ty, slot, offset } => {
+                write!(f, "`{label}` ({ty}) at slot {slot}, offset {offset} was removed")
+            }
+            StorageLayoutChange::Moved { label, ty, from, to } => write!(
+                f,
+                "`{label}` ({ty}) moved from slot {}, offset {} to slot {}, offset {}",
+                from.0, from.1, to.0, to.1
+            ),
+            StorageLayoutChange::TypeChanged { label, slot, offset, from, to } => write!(
+                f,
+                "`{label}` at slot {slot}, offset {offset} changed type from {from} to {to}"
+            ),
+            StorageLayoutChange::Renamed { slot, offset, from, to } => {
+                write!(f, "`{from}` at slot {slot}, offset {offset} was renamed to `{to}`")
+            }
+        }
+    }
+}
+
+/// A storage variable of a layout
+struct Variable<'a> {
+    label: &'a str,
+    /// The label of the type
+    ty: &'a str,
+    /// The id of the type in the types of the layout
+    type_id: &'a str,
+    slot: U256,
+    offset: usize,
+    number_of_bytes: usize,
+}
+
+impl Variable<'_> {
+    /// Returns the slot after the last one the variable occupies
+    fn end(&self) -> U256 {
+        self.slot + (self.offset + self.number_of_bytes + 31) / 32
+    }
+
+    /// Returns true if the variable is a gap reserved for the variables of later versions
+    fn is_gap(&self) -> bool {
+        self.label.starts_with("__gap")
+    }
+}
+
+fn variables(layout: &StorageLayout) -> Vec<Variable<'_>> {
+    layout
+        .storage
+        .iter()
+        .map(|var| {
+            let ty = layout.types.get(&var.storage_type);
+            Variable {
+                label: &var.label,
+                ty: ty.map_or(var.storage_type.as_str(), |ty| ty.label.as_str()),
+                type_id: &var.storage_type,
+                slot: U256::from_dec_str(&var.slot).unwrap_or_default(),
+                offset: usize::try_from(var.offset).unwrap_or_default(),
+                number_of_bytes: ty
+                    .and_then(|ty| ty.number_of_bytes.parse().ok())
+                    .unwrap_or_default(),
+            }
+        })
+        .collect()
+}
+
+/// Compares the types of the variables of two layouts, down to the members of structs and the
+/// keys, values and bases of mappings and arrays
+struct TypeComparison<'a> {
+    old: &'a StorageLayout,
+    new: &'a StorageLayout,
+    /// The pairs of types being compared, to stop at recursive types
+    seen: HashSet<(String, String)>,
+}
+
+impl<'a> TypeComparison<'a> {
+    fn new(old: &'a StorageLayout, new: &'a StorageLayout) -> Self {
+        Self { old, new, seen: HashSet::new() }
+    }
+
+    /// Returns true if the new implementation reads a variable of the old type with the new type
+    fn has_same_type(&mut self, old: &Variable, new: &Variable) -> bool {
+        self.seen.clear();
+        self.is_compatible(old.type_id, new.type_id, false)
+    }
+
+    /// Returns true if the new type stores its values like the old type.
+    ///
+    /// Types that `can_grow`, the values of mappings, can take more bytes, as the slots after
+    /// them aren't used by other variables.
+    fn is_compatible(&mut self, old: &str, new: &str, can_grow: bool) -> bool {
+        // A recursive type is compatible if the rest of it is
+        if !self.seen.insert((old.to_string(), new.to_string())) {
+            return true
+        }
+        let (old_layout, new_layout) = (self.old, self.new);
+        let (Some(old_ty), Some(new_ty)) = (old_layout.types.get(old), new_layout.types.get(new))
+        else {
+            return old == new
+        };
+
+        let is_address =
+            |ty: &StorageType| ty.label.starts_with("address") || ty.label.starts_with("contract ");
+        if is_address(old_ty) && is_address(new_ty) {
+            return old_ty.number_of_bytes == new_ty.number_of_bytes
+        }
+
+        let size = |ty: &StorageType| ty.number_of_bytes.parse::<U256>().unwrap_or_default();
+        let same_size = old_ty.number_of_bytes == new_ty.number_of_bytes;
+        if old_ty.encoding != new_ty.encoding ||
+            !(same_size || (can_grow && size(old_ty) < size(new_ty)))
+        {
+            return false
+        }
+
+        let (old_members, new_members) = (members(old_ty), members(new_ty));
+        let (old_base, new_base) = (base(old_ty), base(new_ty));
+        if old_members.is_none() &&
+            new_members.is_none() &&
+            old_ty.key.is_none() &&
+            new_ty.key.is_none() &&
+            old_base.is_none() &&
+            new_base.is_none()
+        {
+            // Enums can get new values as long as they fit in the same number of bytes
+            let is_enum = |ty: &StorageType| ty.label.starts_with("enum ");
+            return old_ty.label == new_ty.label || (is_enum(old_ty) && is_enum(new_ty))
+        }
+
+        // Members can only be appended, the existing ones keep their slot, offset and type
+        let members_compatible = match (old_members, new_members) {
+            (Some(old_members), Some(new_members)) => {
+                old_members.len() <= new_members.len() &&
+                    old_members.iter().zip(&new_members).all(|(old, new)| {
+                        old.slot == new.slot &&
+                            old.offset == new.offset &&
+                            self.is_compatible(&old.storage_type, &new.storage_type, false)
+                    })
+            }
+            (None, None) => true,
+            _ => false,
+        };
+        members_compatible &&
+            self.are_compatible(old_ty.key.as_deref(), new_ty.key.as_deref(), false) &&
+            self.are_compatible(old_ty.value.as_deref(), new_ty.value.as_deref(), true) &&
+            self.are_compatible(old_base.as_deref(), new_base.as_deref(), false)
+    }
+
+    /// Compares the optional nested types of two types
+    fn are_compatible(&mut self, old: Option<&str>, new: Option<&str>, can_grow: bool) -> bool {
+        match (old, new) {
+            (Some(old), Some(new)) => self.is_compatible(old, new, can_grow),
+            (None, None) => true,
+            _ => false,
+        }
+    }
+}
+
+/// Returns the members of a struct type
+fn members(ty: &StorageType) -> Option<Vec<Storage>> {
+    serde_json::from_value(ty.other.get("members")?.clone()).ok()
+}
+
+/// Returns the type of the elements of an array type
+fn base(ty: &StorageType) -> Option<String> {
+    ty.other.get("base")?.as_str().map(str::to_string)
+}
+
+/// Compares the storage layout of the new implementation of a proxy to the one of the current
+/// implementation.
+///
+/// Every variable of the current implementation must keep its slot, offset and type. New
+/// variables can be appended, or take the place of a shrinking `__gap` array that still ends at
+/// the same slot.
+pub fn compare_storage_layouts(
+    old: &StorageLayout,
+    new: &StorageLayout,
+) -> Vec<StorageLayoutChange> {
+    let old_vars = variables(old);
+    let new_vars = variables(new);
+    let mut types = TypeComparison::new(old, new);
+    let mut changes = vec![];
+
+    for var in &old_vars {
+        let same_label = new_vars.iter().find(|new| new.label == var.label);
+        let same_position =
+            new_vars.iter().find(|new| new.slot == var.slot && new.offset == var.offset);
+
+        if var.is_gap() &&
+            same_label.map_or(false, |gap| gap.slot >= var.slot && gap.end() == var.end())
+        {
+            continue
+        }
+
+        let change = match (same_position, same_label) {
+            (Some(new), _) if new.label == var.label => {
+                (!types.has_same_type(var, new)).then(|| StorageLayoutChange::TypeChanged {
+                    label: var.label.to_string(),
+                    slot: var.slot,
+                    offset: var.offset,
+                    from: var.ty.to_string(),
+                    to: new.ty.to_string(),
+                })
+            }
+            (_, Some(new)) => Some(StorageLayoutChange::Moved {
+                label: var.label.to_string(),
+                ty: var.ty.to_string(),
+                from: (var.slot, var.offset),
+                to: (new.slot, new.offset),
+            }),
+            // A renamed variable, if its slot isn't taken by another moved variable
+            (Some(new), None)
+                if types.has_same_type(var, new) &&
+                    !old_vars.iter().any(|old| old.label == new.label) =>
+            {
+                Some(StorageLayoutChange::Renamed {
+                    slot: var.slot,
+                    offset: var.offset,
+                    from: var.label.to_string(),
+                    to: new.label.to_string(),
+                })
+            }
+            (Some(new), None) if !types.has_same_type(var, new) => {
+                Some(StorageLayoutChange::TypeChanged {
+                    label: var.label.to_string(),
+                    slot: var.slot,
+                    offset: var.offset,
+                    from: var.ty.to_string(),
+                    to: format!("{} `{}`", new.ty, new.label),
+                })
+            }
+            (_, None) => Some(StorageLayoutChange::Removed {
+                label: var.label.to_string(),
+                ty: var.ty.to_string(),
+                slot: var.slot,
+                offset: var.offset,
+            }),
+        };
+        changes.extend(change);
+    }
+
+    changes
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::{json, Value};
+
+    /// Creates a storage variable or struct member
+    fn storage(label: &str, slot: u64, offset: u64, ty: &str) -> Value {
+        json!({
+            "astId": 1,
+            "contract": "src/C.sol:C",
+            "label": label,
+            "offset": offset,
+            "slot": slot.to_string(),
+            "type": ty,
+        })
+    }
+
+    /// Creates a layout of `(label, slot, offset, type)` variables
+    fn layout(vars: &[(&str, u64, u64, &str)]) -> StorageLayout {
+        let storage = vars
+            .iter()
+            .map(|(label, slot, offset, ty)| storage(label, *slot, *offset, ty))
+            .collect::<Vec<_>>();
+        serde_json::from_value(json!({
+            "storage": storage,
+            "types": {
+                "t_uint256": { "encoding": "inplace", "label": "uint256", "numberOfBytes": "32" },
+                "t_uint128": { "encoding": "inplace", "label": "uint128", "numberOfBytes": "16" },
+                "t_address": { "encoding": "inplace", "label": "address", "numberOfBytes": "22" },
+                "t_contract(C)1": { "encoding": "inplace", "label": "contract C", "numberOfBytes": "22" },
+                "t_array(t_uint256)50_storage": {
+                    "base": "t_uint256",
+                    "encoding": "inplace",
+                    "label": "uint256[50]",
+                    "numberOfBytes": "1600"
+                },
+                "t_array(t_uint256)48_storage": {
+                    "base": "t_uint256",
+                    "encoding": "inplace",
+                    "label": "uint256[48]",
+                    "numberOfBytes": "1536"
+                },
+                // Three versions of `struct C.S`, with a changed and an appended member
+                "t_struct(S)1_storage": {
+                    "encoding": "inplace",
+                    "label": "struct C.S",
+                    "members": [storage("x", 0, 0, "t_uint256"), storage("y", 1, 0, "t_uint128")],
+                    "numberOfBytes": "64"
+                },
+                "t_struct(S)2_storage": {
+                    "encoding": "inplace",
+                    "label": "struct C.S",
+                    "members": [storage("x", 0, 0, "t_uint256"), storage("y", 1, 0, "t_uint256")],
+                    "numberOfBytes": "64"
+                },
+                "t_struct(S)3_storage": {
+                    "encoding": "inplace",
+                    "label": "struct C.S",
+                    "members": [
+                        storage("x", 0, 0, "t_uint256"),
+                        storage("y", 1, 0, "t_uint128"),
+                        storage("z", 2, 0, "t_uint256"),
+                    ],
+                    "numberOfBytes": "96"
+                },
+                "t_mapping(t_address,t_struct(S)1_storage)": {
+                    "encoding": "mapping",
+                    "key": "t_address",
+                    "label": "mapping(address => struct C.S)",
+                    "numberOfBytes": "32",
+                    "value": "t_struct(S)1_storage"
+                },
+                "t_mapping(t_address,t_struct(S)2_storage)": {
+                    "encoding": "mapping",
+                    "key": "t_address",
+                    "label": "mapping(address => struct C.S)",
+                    "numberOfBytes": "32",
+                    "value": "t_struct(S)2_storage"
+                },
+                "t_mapping(t_address,t_struct(S)3_storage)": {
+                    "encoding": "mapping",
+                    "key": "t_address",
+                    "label": "mapping(address => struct C.S)",
+                    "numberOfBytes": "32",
+                    "value": "t_struct(S)3_storage"
+                },
+                "t_array(t_struct(S)1_storage)dyn_storage": {
+                    "base": "t_struct(S)1_storage",
+                    "encoding": "dynamic_array",
+                    "label": "struct C.S[]",
+                    "numberOfBytes": "32"
+                },
+                "t_array(t_struct(S)3_storage)dyn_storage": {
+                    "base": "t_struct(S)3_storage",
+                    "encoding": "dynamic_array",
+                    "label": "struct C.S[]",
+                    "numberOfBytes": "32"
+                },
+            },
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn can_append_variables() {
+        let old = layout(&[("a", 0, 0, "t_uint256"), ("owner", 1, 0, "t_address")]);
+        let new = layout(&[
+            ("a", 0, 0, "t_uint256"),
+            ("owner", 1, 0, "t_contract(C)1"),
+            ("b", 2, 0, "t_uint128"),
+        ]);
+        assert!(compare_storage_layouts(&old, &new).is_empty());
+    }
+
+    #[test]
+    fn can_shrink_gaps() {
+        let old =
+            layout(&[("a", 0, 0, "t_uint256"), ("__gap", 1, 0, "t_array(t_uint256)50_storage")]);
+        let new = layout(&[
+            ("a", 0, 0, "t_uint256"),
+            ("b", 1, 0, "t_uint256"),
+            ("c", 2, 0, "t_uint256"),
+            ("__gap", 3, 0, "t_array(t_uint256)48_storage"),
+        ]);
+        assert!(compare_storage_layouts(&old, &new).is_empty());
+
+        let new =
+            layout(&[("a", 0, 0, "t_uint256"), ("__gap", 2, 0, "t_array(t_uint256)48_storage")]);
+        assert!(compare_storage_layouts(&old, &new)[0].is_incompatible());
+    }
+
+    #[test]
+    fn can_find_incompatible_changes() {
+        let old = layout(&[
+            ("a", 0, 0, "t_uint256"),
+            ("b", 1, 0, "t_uint256"),
+            ("c", 2, 0, "t_uint128"),
+            ("d", 3, 0, "t_uint256"),
+        ]);
+        let new = layout(&[
+            ("x", 0, 0, "t_uint256"),
+            ("a", 1, 0, "t_uint256"),
+            ("c", 2, 0, "t_uint256"),
+            ("e", 3, 0, "t_uint256"),
+        ]);
+
+        let changes = compare_storage_layouts(&old, &new);
+        assert_eq!(
+            changes,
+            vec![
+                StorageLayoutChange::Moved {
+                    label: "a".to_string(),
+                    ty: "uint256".to_string(),
+                    from: (0.into(), 0),
+                    to: (1.into(), 0),
+                },
+                StorageLayoutChange::Removed {
+                    label: "b".to_string(),
+                    ty: "uint256".to_string(),
+                    slot: 1.into(),
+                    offset: 0,
+                },
+                StorageLayoutChange::TypeChanged {
+                    label: "c".to_string(),
+                    slot: 2.into(),
+                    offset: 0,
+                    from: "uint128".to_string(),
+                    to: "uint256".to_string(),
+                },
+                StorageLayoutChange::Renamed {
+                    slot: 3.into(),
+                    offset: 0,
+                    from: "d".to_string(),
+                    to: "e".to_string(),
+                },
+            ]
+        );
+        assert_eq!(changes.iter().filter(|change| change.is_incompatible()).count(), 3);
+    }
+
+    #[test]
+    fn can_compare_struct_members() {
+        let old = layout(&[("s", 0, 0, "t_struct(S)1_storage"), ("a", 2, 0, "t_uint256")]);
+
+        let new = layout(&[("s", 0, 0, "t_struct(S)2_storage"), ("a", 2, 0, "t_uint256")]);
+        assert_eq!(
+            compare_storage_layouts(&old, &new),
+            vec![StorageLayoutChange::TypeChanged {
+                label: "s".to_string(),
+                slot: 0.into(),
+                offset: 0,
+                from: "struct C.S".to_string(),
+                to: "struct C.S".to_string(),
+            }]
+        );
+
+        // A struct stored in place can't grow into the slot of the next variable
+        let new = layout(&[("s", 0, 0, "t_struct(S)3_storage"), ("a", 3, 0, "t_uint256")]);
+        let changes = compare_storage_layouts(&old, &new);
+        assert_eq!(changes.len(), 2);
+        assert!(changes.iter().all(|change| change.is_incompatible()));
+    }
+
+    #[test]
+    fn can_compare_mapping_values_and_array_bases() {
+        let old = layout(&[
+            ("m", 0, 0, "t_mapping(t_address,t_struct(S)1_storage)"),
+            ("list", 1, 0, "t_array(t_struct(S)1_storage)dyn_storage"),
+        ]);
+
+        // The values of a mapping can get new members
+        let new = layout(&[
+            ("m", 0, 0, "t_mapping(t_address,t_struct(S)3_storage)"),
+            ("list", 1, 0, "t_array(t_struct(S)1_storage)dyn_storage"),
+        ]);
+        assert!(compare_storage_layouts(&old, &new).is_empty());
+
+        // But not change the existing ones
+        let new = layout(&[
+            ("m", 0, 0, "t_mapping(t_address,t_struct(S)2_storage)"),
+            ("list", 1, 0, "t_array(t_struct(S)1_storage)dyn_storage"),
+        ]);
+        let changes = compare_storage_layouts(&old, &new);
+        assert_eq!(changes.len(), 1);
+        assert!(
+            matches!(&changes[0], StorageLayoutChange::TypeChanged { label, .. } if label == "m")
+        );
+
+        // The elements of an array are next to each other, so they can't grow
+        let new = layout(&[
+            ("m", 0, 0, "t_mapping(t_address,t_struct(S)1_storage)"),
+            ("list", 1, 0, "t_array(t_struct(S)3_storage)dyn_storage"),
+        ]);
+        let changes = compare_storage_layouts(&old, &new);
+        assert_eq!(changes.len(), 1);
+        assert!(
+            matches!(&changes[0], StorageLayoutChange::TypeChanged { label, .. } if label == "list")
+        );
+    }
+}
@@ -27,6 +27,8 @@ pub struct VerifierArgs {
     pub verifier: VerificationProviderType,
 
     /// The verifier URL, if using a custom provider
+    ///
+    /// Defaults to `verifier_url` of the config, then to the default endpoint of the provider.
     #[clap(long, help_heading = "Verifier options", env = "VERIFIER_URL")]
     pub verifier_url: Option<String>,
 }
@@ -136,6 +138,9 @@ impl VerifyArgs {
         let config = self.load_config_emit_warnings();
         let chain = config.network_id.unwrap_or_default();
         self.network = Some(chain);
+        if self.verifier.verifier_url.is_none() {
+            self.verifier.verifier_url = config.verifier_url.clone();
+        }
         // self.etherscan.key = config.get_etherscan_config_with_network(Some(chain))?.map(|c|
         // c.key);
 
@@ -204,7 +209,10 @@ impl_figment_convert_probe!(VerifyCheckArgs);
 
 impl VerifyCheckArgs {
     /// Run the verify command to submit the contract's source code for verification on etherscan
-    pub async fn run(self) -> eyre::Result<()> {
+    pub async fn run(mut self) -> eyre::Result<()> {
+        if self.verifier.verifier_url.is_none() {
+            self.verifier.verifier_url = self.try_load_config_emit_warnings()?.verifier_url;
+        }
         println!("Checking verification status on {}", self.network.unwrap_or_default());
         self.verifier.verifier.client()?.check(self).await
     }
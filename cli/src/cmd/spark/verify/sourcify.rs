@@ -59,7 +59,10 @@ impl VerificationProvider for SourcifyVerificationProvider {
                             error
                         );
                         warn!("Failed verify submission: {:?}", error);
-                        std::process::exit(1);
+                        eyre::bail!(
+                            "Sourcify verification request for address ({:?}) failed with status code {status}",
+                            args.address
+                        );
                     }
 
                     let text = response.text().await?;
@@ -69,8 +72,7 @@ impl VerificationProvider for SourcifyVerificationProvider {
             })
             .await?;
 
-        self.process_sourcify_response(resp.map(|r| r.result));
-        Ok(())
+        self.process_sourcify_response(resp.map(|r| r.result))
     }
 
     async fn check(&self, args: VerifyCheckArgs) -> eyre::Result<()> {
@@ -87,11 +89,10 @@ impl VerificationProvider for SourcifyVerificationProvider {
 
                     let response = reqwest::get(url).await?;
                     if !response.status().is_success() {
-                        eprintln!(
+                        eyre::bail!(
                             "Failed to request verification status with status code {}",
                             response.status()
                         );
-                        std::process::exit(1);
                     };
 
                     Ok(Some(response.json::<Vec<SourcifyResponseElement>>().await?))
@@ -100,8 +101,7 @@ impl VerificationProvider for SourcifyVerificationProvider {
             })
             .await?;
 
-        self.process_sourcify_response(resp);
-        Ok(())
+        self.process_sourcify_response(resp)
     }
 }
 
@@ -169,8 +169,13 @@ metadata output can be enabled via `extra_output = ["metadata"]` in `foxar.toml`
         Ok(req)
     }
 
-    fn process_sourcify_response(&self, response: Option<Vec<SourcifyResponseElement>>) {
-        let response = response.unwrap().remove(0);
+    fn process_sourcify_response(
+        &self,
+        response: Option<Vec<SourcifyResponseElement>>,
+    ) -> eyre::Result<()> {
+        let Some(response) = response.and_then(|response| response.into_iter().next()) else {
+            eyre::bail!("Empty response from sourcify")
+        };
         if response.status == "perfect" {
             if let Some(ts) = response.storage_timestamp {
                 println!("Contract source code already verified. Storage Timestamp: {ts}");
@@ -182,9 +187,9 @@ metadata output can be enabled via `extra_output = ["metadata"]` in `foxar.toml`
         } else if response.status == "false" {
             println!("Contract source code is not verified")
         } else {
-            eprintln!("Unknown status from sourcify. Status: {}", response.status);
-            std::process::exit(1);
+            eyre::bail!("Unknown status from sourcify. Status: {}", response.status)
         }
+        Ok(())
     }
 }
 
@@ -1,12 +1,22 @@
 //! Watch mode support
 
 use crate::{
-    cmd::spark::{build::BuildArgs, snapshot::SnapshotArgs, test::TestArgs},
+    cmd::spark::{
+        build::BuildArgs,
+        snapshot::SnapshotArgs,
+        test::{changed, TestArgs},
+    },
     utils::{self, FoxarPathExt},
 };
 use clap::Parser;
+use corebc::ylem::ProjectPathsConfig;
 use foxar_config::Config;
-use std::{collections::HashSet, convert::Infallible, path::PathBuf, sync::Arc};
+use std::{
+    collections::{BTreeSet, HashSet},
+    convert::Infallible,
+    path::PathBuf,
+    sync::Arc,
+};
 use tracing::trace;
 use watchexec::{
     action::{Action, Outcome, PreSpawn},
@@ -141,7 +151,8 @@ pub async fn watch_test(args: TestArgs) -> eyre::Result<()> {
         args.watch.run_all;
 
     let state = WatchTestState {
-        project_root: config.__root.0,
+        project_root: config.__root.0.clone(),
+        paths: config.project_paths(),
         no_reconfigure,
         last_test_files: Default::default(),
     };
@@ -158,6 +169,8 @@ pub async fn watch_test(args: TestArgs) -> eyre::Result<()> {
 struct WatchTestState {
     /// the root directory of the project
     project_root: PathBuf,
+    /// the paths of the project, used to find the tests affected by changed sources
+    paths: ProjectPathsConfig,
     /// marks whether we can reconfigure the watcher command with the `--match-path` arg
     no_reconfigure: bool,
     /// Tracks the last changed test files, if any so that if a non-test file was modified we run
@@ -169,7 +182,7 @@ struct WatchTestState {
 /// The `on_action` hook for `spark test --watch`
 fn on_test(action: OnActionState<WatchTestState>) {
     let OnActionState { args, runtime, action, wx, cmd, other } = action;
-    let WatchTestState { project_root, no_reconfigure, last_test_files } = other;
+    let WatchTestState { project_root, paths, no_reconfigure, last_test_files } = other;
 
     if no_reconfigure {
         // nothing to reconfigure
@@ -187,18 +200,32 @@ fn on_test(action: OnActionState<WatchTestState>) {
         .map(str::to_string)
         .collect();
 
+    // add the test files affected by changed sources, the same way `spark test --changed` does
+    let changed_sources: BTreeSet<_> = action
+        .events
+        .iter()
+        .flat_map(|e| e.paths())
+        .filter(|(path, _)| path.is_sol() && !path.is_sol_test())
+        .map(|(path, _)| path.strip_prefix(&project_root).unwrap_or(path).to_path_buf())
+        .collect();
+    if !changed_sources.is_empty() {
+        match changed::affected_test_files_by_imports(&paths, &changed_sources) {
+            Ok(affected) => changed_sol_test_files.extend(
+                affected.into_iter().map(|file| project_root.join(file).display().to_string()),
+            ),
+            Err(err) => trace!(?err, "failed to find the tests affected by changed sources"),
+        }
+    }
+
     // replace `--match-path` | `-mp` argument
     if let Some(pos) = cmd.iter().position(|arg| arg == "--match-path" || arg == "-mp") {
         // --match-path requires 1 argument
         cmd.drain(pos..=(pos + 1));
     }
 
-    if changed_sol_test_files.len() > 1 ||
-        (changed_sol_test_files.is_empty() && last_test_files.is_empty())
-    {
-        // this could happen if multiple files were changed at once, for example `spark fmt` was
-        // run, or if no test files were changed and no previous test files were modified in which
-        // case we simply run all
+    if changed_sol_test_files.is_empty() && last_test_files.is_empty() {
+        // no test files were changed or affected and no previous test files were modified in
+        // which case we simply run all
         let mut config = runtime.clone();
         config.command(watch_command(cmd.clone()));
         // re-register the action
@@ -209,6 +236,7 @@ fn on_test(action: OnActionState<WatchTestState>) {
             cmd,
             WatchTestState {
                 project_root,
+                paths,
                 no_reconfigure,
                 last_test_files: changed_sol_test_files,
             },
@@ -222,19 +250,26 @@ fn on_test(action: OnActionState<WatchTestState>) {
         changed_sol_test_files = last_test_files;
     }
 
-    // append `--match-path` glob
-    let mut file = changed_sol_test_files.clone().into_iter().next().expect("test file present");
+    // remove the project root dir from the detected files
+    let mut files: Vec<_> = changed_sol_test_files
+        .iter()
+        .map(|file| {
+            project_root
+                .as_os_str()
+                .to_str()
+                .and_then(|root| file.strip_prefix(root))
+                .map(|f| f.trim_start_matches('/').to_string())
+                .unwrap_or_else(|| file.clone())
+        })
+        .collect();
+    files.sort();
 
-    // remove the project root dir from the detected file
-    if let Some(root) = project_root.as_os_str().to_str() {
-        if let Some(f) = file.strip_prefix(root) {
-            file = f.trim_start_matches('/').to_string();
-        }
-    }
+    // append `--match-path` glob, multiple files are matched with an alternation
+    let glob = if files.len() == 1 { files.remove(0) } else { format!("{{{}}}", files.join(",")) };
 
     let mut new_cmd = cmd.clone();
     new_cmd.push("--match-path".to_string());
-    new_cmd.push(file);
+    new_cmd.push(glob);
     trace!("reconfigure test command {:?}", new_cmd);
 
     // reconfigure the executor with a new runtime
@@ -247,7 +282,12 @@ fn on_test(action: OnActionState<WatchTestState>) {
         config,
         wx,
         cmd,
-        WatchTestState { project_root, no_reconfigure, last_test_files: changed_sol_test_files },
+        WatchTestState {
+            project_root,
+            paths,
+            no_reconfigure,
+            last_test_files: changed_sol_test_files,
+        },
         on_test,
     );
 }
@@ -5,6 +5,7 @@ use crate::cmd::spark::{
     config, coverage,
     create::CreateArgs,
     debug::DebugArgs,
+    deployments::DeploymentsArgs,
     doc::DocArgs,
     flatten,
     fmt::FmtArgs,
@@ -13,11 +14,13 @@ use crate::cmd::spark::{
     init::InitArgs,
     inspect,
     install::InstallArgs,
+    mutate::MutateArgs,
     remappings::RemappingArgs,
     remove::RemoveArgs,
-    script::ScriptArgs,
+    script::{ScriptArgs, VerifyAllArgs},
     selectors::SelectorsSubcommands,
     snapshot, test, tree, update,
+    upgrade::UpgradeArgs,
     verify::{VerifyArgs, VerifyCheckArgs},
 };
 use clap::{Parser, Subcommand, ValueHint};
@@ -51,6 +54,15 @@ pub enum Subcommands {
     /// Generate coverage reports.
     Coverage(coverage::CoverageArgs),
 
+    /// Run mutation tests, checking whether the tests catch changes to the sources.
+    Mutate(MutateArgs),
+
+    /// List and export the deployments saved by `spark script`.
+    Deployments(DeploymentsArgs),
+
+    /// Check upgrades of proxy implementations.
+    Upgrade(UpgradeArgs),
+
     /// Generate Rust bindings for smart contracts.
     #[clap(alias = "bi")]
     Bind(BindArgs),
@@ -91,6 +103,10 @@ pub enum Subcommands {
     #[clap(visible_alias = "vc")]
     VerifyCheck(VerifyCheckArgs),
 
+    /// Verify the contracts deployed by scripts which are pending or failed to verify.
+    #[clap(visible_alias = "va")]
+    VerifyAll(VerifyAllArgs),
+
     /// Deploy a smart contract.
     #[clap(visible_alias = "c")]
     Create(CreateArgs),
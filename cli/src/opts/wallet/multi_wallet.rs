@@ -208,12 +208,22 @@ impl MultiWallet {
     pub async fn find_all(
         &self,
         provider: Arc<RetryProvider>,
-        mut addresses: HashSet<Address>,
+        addresses: HashSet<Address>,
         script_wallets: &[LocalWallet],
     ) -> Result<HashMap<Address, WalletSigner>> {
         println!("\n###\nFinding wallets for all the necessary addresses...");
         let network = provider.get_networkid().await?.as_u64();
+        self.find_all_for_network(network, addresses, script_wallets)
+    }
 
+    /// Like [MultiWallet::find_all], but doesn't need a provider to get the network id of the
+    /// wallets, e.g. when signing offline.
+    pub fn find_all_for_network(
+        &self,
+        network: u64,
+        mut addresses: HashSet<Address>,
+        script_wallets: &[LocalWallet],
+    ) -> Result<HashMap<Address, WalletSigner>> {
         let mut local_wallets = HashMap::new();
         let mut unused_wallets = vec![];
 
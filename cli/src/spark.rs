@@ -26,15 +26,21 @@ fn main() -> eyre::Result<()> {
                 outcome.ensure_ok()
             }
         }
-        Subcommands::Script(cmd) => {
+        Subcommands::Script(mut cmd) => {
             // install the shell before executing the command
             foxar_common::shell::set_shell(foxar_common::shell::Shell::from_args(
                 cmd.opts.args.silent,
                 cmd.json,
             ))?;
-            utils::block_on(cmd.run_script(Default::default()))
+            match cmd.command.take() {
+                Some(sub) => utils::block_on(sub.run()),
+                None => utils::block_on(cmd.run_script(Default::default())),
+            }
         }
         Subcommands::Coverage(cmd) => utils::block_on(cmd.run()),
+        Subcommands::Mutate(cmd) => utils::block_on(cmd.run()),
+        Subcommands::Deployments(cmd) => cmd.run(),
+        Subcommands::Upgrade(cmd) => cmd.run(),
         Subcommands::Bind(cmd) => cmd.run(),
         Subcommands::Build(cmd) => {
             if cmd.is_watch() {
@@ -46,6 +52,7 @@ fn main() -> eyre::Result<()> {
         Subcommands::Debug(cmd) => utils::block_on(cmd.debug(Default::default())),
         Subcommands::VerifyContract(args) => utils::block_on(args.run()),
         Subcommands::VerifyCheck(args) => utils::block_on(args.run()),
+        Subcommands::VerifyAll(args) => utils::block_on(args.run()),
         Subcommands::Cache(cmd) => match cmd.sub {
             CacheSubcommands::Clean(cmd) => cmd.run(),
             CacheSubcommands::Ls(cmd) => cmd.run(),
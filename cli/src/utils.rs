@@ -8,6 +8,7 @@ use corebc::{
 use eyre::Result;
 use foxar_config::Config;
 use std::{
+    collections::BTreeSet,
     ffi::OsStr,
     future::Future,
     path::{Path, PathBuf},
@@ -434,6 +435,17 @@ https://github.com/foxar-rs/foxar/issues/new/choose"
         self.cmd().arg("rev-parse").args(short.then_some("--short")).arg("HEAD").get_stdout_lossy()
     }
 
+    /// Returns all files changed since the given ref, including uncommitted and untracked files.
+    ///
+    /// The paths are relative to the root.
+    pub fn changed_files(self, since: impl AsRef<OsStr>) -> Result<BTreeSet<PathBuf>> {
+        let diff =
+            self.cmd().args(["diff", "--name-only", "--relative"]).arg(since).get_stdout_lossy()?;
+        let untracked =
+            self.cmd().args(["ls-files", "--others", "--exclude-standard"]).get_stdout_lossy()?;
+        Ok(diff.lines().chain(untracked.lines()).map(PathBuf::from).collect())
+    }
+
     pub fn tag(self) -> Result<String> {
         self.cmd().arg("tag").get_stdout_lossy()
     }
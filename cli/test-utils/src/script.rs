@@ -244,6 +244,7 @@ pub enum ScriptOutcome {
     OkNoEndpoint,
     OkSimulation,
     OkBroadcast,
+    OkRehearsal,
     WarnSpecifyDeployer,
     MissingSender,
     MissingWallet,
@@ -251,6 +252,8 @@ pub enum ScriptOutcome {
     FailedScript,
     UnsupportedLibraries,
     ErrorSelectForkOnBroadcast,
+    FailedPreflightChecks,
+    FailedRehearsal,
 }
 
 impl ScriptOutcome {
@@ -259,13 +262,16 @@ impl ScriptOutcome {
             ScriptOutcome::OkNoEndpoint => "If you wish to simulate on-chain transactions pass a RPC URL.",
             ScriptOutcome::OkSimulation => "SIMULATION COMPLETE. To broadcast these",
             ScriptOutcome::OkBroadcast => "ONCHAIN EXECUTION COMPLETE & SUCCESSFUL",
+            ScriptOutcome::OkRehearsal => "REHEARSAL COMPLETE",
             ScriptOutcome::WarnSpecifyDeployer => "You have more than one deployer who could predeploy libraries. Using `--sender` instead.",
             ScriptOutcome::MissingSender => "You seem to be using Foxar's default sender. Be sure to set your own --sender",
             ScriptOutcome::MissingWallet => "No associated wallet",
             ScriptOutcome::StaticCallNotAllowed => "Staticcalls are not allowed after vm.broadcast. Either remove it, or use vm.startBroadcast instead.",
             ScriptOutcome::FailedScript => "Script failed.",
             ScriptOutcome::UnsupportedLibraries => "Multi chain deployment does not support library linking at the moment.",
-            ScriptOutcome::ErrorSelectForkOnBroadcast => "You need to stop broadcasting before you can select forks."
+            ScriptOutcome::ErrorSelectForkOnBroadcast => "You need to stop broadcasting before you can select forks.",
+            ScriptOutcome::FailedPreflightChecks => "Preflight checks failed, no transaction was sent",
+            ScriptOutcome::FailedRehearsal => "Rehearsal on network"
         }
     }
 
@@ -274,12 +280,15 @@ impl ScriptOutcome {
             ScriptOutcome::OkNoEndpoint |
             ScriptOutcome::OkSimulation |
             ScriptOutcome::OkBroadcast |
+            ScriptOutcome::OkRehearsal |
             ScriptOutcome::WarnSpecifyDeployer => false,
             ScriptOutcome::MissingSender |
             ScriptOutcome::MissingWallet |
             ScriptOutcome::StaticCallNotAllowed |
             ScriptOutcome::UnsupportedLibraries |
             ScriptOutcome::ErrorSelectForkOnBroadcast |
+            ScriptOutcome::FailedPreflightChecks |
+            ScriptOutcome::FailedRehearsal |
             ScriptOutcome::FailedScript => true,
         }
     }
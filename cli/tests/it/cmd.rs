@@ -4,7 +4,7 @@ use crate::constants::*;
 use clap::CommandFactory;
 use corebc::{
     prelude::remappings::Remapping,
-    types::Network,
+    types::{Address, Network},
     ylem::{
         artifacts::{BytecodeHash, Metadata},
         ConfigurableContractArtifact,
@@ -16,6 +16,7 @@ use foxar_cli_test_utils::{
     sparktest, sparktest_init,
     util::{pretty_err, read_string, OutputExt, TestCommand, TestProject},
 };
+use foxar_common::deployments::{Deployment, DeploymentRegistry};
 use foxar_config::{parse_with_profile, BasicConfig, Config, SolidityErrorCode};
 use semver::Version;
 use std::{
@@ -625,6 +626,65 @@ contract Foo {
     check_output(cmd.stdout_lossy());
 });
 
+// tests that `spark upgrade check` compares the storage layouts of two implementations
+sparktest!(can_check_upgrade_storage_layouts, |prj: TestProject, mut cmd: TestCommand| {
+    prj.inner()
+        .add_source(
+            "CounterV1",
+            r#"
+// SPDX-License-Identifier: UNLICENSED
+pragma solidity 1.1.0;
+contract CounterV1 {
+    uint256 public number;
+    address public owner;
+}
+    "#,
+        )
+        .unwrap();
+    prj.inner()
+        .add_source(
+            "CounterV2",
+            r#"
+// SPDX-License-Identifier: UNLICENSED
+pragma solidity 1.1.0;
+contract CounterV2 {
+    uint256 public number;
+    address public owner;
+    uint256 public step;
+}
+    "#,
+        )
+        .unwrap();
+    prj.inner()
+        .add_source(
+            "CounterV3",
+            r#"
+// SPDX-License-Identifier: UNLICENSED
+pragma solidity 1.1.0;
+contract CounterV3 {
+    address public owner;
+    uint256 public number;
+}
+    "#,
+        )
+        .unwrap();
+
+    cmd.args(["upgrade", "check", "src/CounterV1.sol:CounterV1", "src/CounterV2.sol:CounterV2"]);
+    assert!(cmd.stdout_lossy().contains("is compatible with"));
+
+    cmd.spark_fuse().args([
+        "upgrade",
+        "check",
+        "src/CounterV1.sol:CounterV1",
+        "src/CounterV3.sol:CounterV3",
+    ]);
+    let output = cmd.unchecked_output();
+    assert!(!output.status.success());
+    assert!(String::from_utf8_lossy(&output.stdout)
+        .contains("`number` (uint256) moved from slot 0, offset 0 to slot 1, offset 0"));
+    assert!(String::from_utf8_lossy(&output.stderr).contains("found 2 incompatible changes"));
+});
+
 // test that `spark snapshot` commands work
 sparktest!(
     #[serial_test::serial]
@@ -660,6 +720,69 @@ contract ATest is DSTest {
     }
 );
 
+// test that `spark snapshot` writes and checks the energy snapshots recorded via cheatcodes
+sparktest!(
+    #[serial_test::serial]
+    can_check_energy_snapshots,
+    |prj: TestProject, mut cmd: TestCommand| {
+        prj.insert_ds_test();
+
+        prj.inner()
+            .add_source(
+                "ATest.t.sol",
+                r#"
+// SPDX-License-Identifier: UNLICENSED
+pragma solidity 1.1.0;
+import "./test.sol";
+
+interface Vm {
+    function snapshotValue(string calldata name, uint256 value) external;
+}
+
+contract ATest is DSTest {
+    Vm constant vm = Vm(HEVM_ADDRESS);
+
+    function testExample() public {
+        vm.snapshotValue("value", 42);
+    }
+
+    function testOther() public {
+        vm.snapshotValue("value", 0);
+    }
+}
+   "#,
+            )
+            .unwrap();
+
+        // the snapshots of a removed test contract
+        let stale = prj.root().join("snapshots/RemovedTest.json");
+        fs::create_dir_all(stale.parent().unwrap()).unwrap();
+        fs::write(&stale, r#"{ "testRemoved()::value": 1 }"#).unwrap();
+
+        cmd.arg("snapshot");
+        cmd.assert_success();
+        assert!(!stale.exists());
+
+        let snapshot = prj.root().join("snapshots/ATest.json");
+        let content = fs::read_to_string(&snapshot).unwrap();
+        let values: serde_json::Value = serde_json::from_str(&content).unwrap();
+        assert_eq!(
+            values,
+            serde_json::json!({ "testExample()::value": 42, "testOther()::value": 0 })
+        );
+
+        cmd.arg("--check");
+        cmd.assert_success();
+
+        // a diff against a zero snapshot has no relative change
+        fs::write(&snapshot, r#"{ "testExample()::value": 0, "testOther()::value": 0 }"#).unwrap();
+        cmd.spark_fuse().args(["snapshot", "--diff"]);
+        let out = cmd.stdout_lossy();
+        assert!(out.contains("ATest::testExample()::value (energy: "), "{out}");
+        assert!(!out.contains("inf") && !out.contains("NaN"), "{out}");
+    }
+);
+
 // test that `spark build` does not print `(with warnings)` if there arent any
 sparktest!(can_compile_without_warnings, |prj: TestProject, mut cmd: TestCommand| {
     let config = Config {
@@ -1632,3 +1755,75 @@ sparktest_init!(can_build_names_repeatedly, |_prj: TestProject, mut cmd: TestCom
     let unchanged = cmd.stdout();
     assert!(unchanged.contains(list), "{}", list);
 });
+
+// checks that mutants are killed by the tests that cover them and the sources aren't modified
+sparktest!(can_run_mutation_tests, |prj: TestProject, mut cmd: TestCommand| {
+    prj.insert_ds_test();
+    let src = r#"
+// SPDX-License-Identifier: UNLICENSED
+pragma solidity 1.1.0;
+contract Math {
+    function add(uint256 a, uint256 b) public pure returns (uint256) {
+        return a + b;
+    }
+
+    function isPositive(int256 a) public pure returns (bool) {
+        return a > 0;
+    }
+}
+"#;
+    let math = prj.inner().add_source("Math.sol", src).unwrap();
+    prj.inner()
+        .add_source(
+            "MathTest.t.sol",
+            r#"
+// SPDX-License-Identifier: UNLICENSED
+pragma solidity 1.1.0;
+import "./test.sol";
+import "./Math.sol";
+contract MathTest is DSTest {
+    function testAdd() external {
+        assertEq(new Math().add(1, 2), 3);
+    }
+}
+"#,
+        )
+        .unwrap();
+
+    cmd.args(["mutate", "--json"]).arg(&math);
+    let mutants: serde_json::Value = serde_json::from_str(&cmd.stdout()).unwrap();
+    let status = |original: &str| {
+        mutants
+            .as_array()
+            .unwrap()
+            .iter()
+            .find(|mutant| mutant["original"] == original)
+            .map(|mutant| mutant["status"].as_str().unwrap().to_string())
+            .unwrap()
+    };
+    assert_eq!(status("+"), "killed");
+    assert_eq!(status(">"), "no-coverage");
+    // The test calling `add` doesn't compile once it's internal
+    assert_eq!(status("public"), "invalid");
+    assert_eq!(read_string(&math), src);
+});
+
+// checks that `deployments list` and `deployments export` read the deployment registry
+sparktest!(can_list_deployments, |prj: TestProject, mut cmd: TestCommand| {
+    let registry = DeploymentRegistry::new(prj.root().join("deployments"));
+    let address = Address::repeat_byte(1);
+    registry.save("mainnet", Deployment::new("Counter", address)).unwrap();
+    registry.save("mainnet", Deployment::new("Counter", Address::repeat_byte(2))).unwrap();
+    registry.save("devin", Deployment::new("Token", address)).unwrap();
+
+    cmd.args(["deployments", "list", "--network", "mainnet"]);
+    let stdout = cmd.stdout_lossy();
+    let counter = stdout.lines().find(|line| line.contains("Counter")).unwrap();
+    assert!(counter.contains(&format!("{:?}", Address::repeat_byte(2))), "{counter}");
+    assert!(!stdout.contains("Token"));
+
+    cmd.spark_fuse().args(["deployments", "export"]);
+    let export: serde_json::Value = serde_json::from_str(&cmd.stdout_lossy()).unwrap();
+    assert_eq!(export["mainnet"]["Counter"]["version"], 2);
+    assert_eq!(export["devin"]["Token"]["name"], "Token");
+});
@@ -12,7 +12,7 @@ use foxar_cli_test_utils::{
 };
 use foxar_config::{
     cache::{CachedEndpoints, CachedNetworks, StorageCachingConfig},
-    Config, FuzzConfig, InvariantConfig, OptimizerDetails, YlemReq,
+    Config, CoverageThreshold, FuzzConfig, InvariantConfig, OptimizerDetails, YlemReq,
 };
 use path_slash::PathBufExt;
 use spark::executor::opts::EvmOpts;
@@ -32,6 +32,7 @@ sparktest!(can_extract_config_values, |prj: TestProject, mut cmd: TestCommand| {
         cache: true,
         cache_path: "test-cache".into(),
         broadcast: "broadcast".into(),
+        deployments: "deployments-test".into(),
         force: true,
         cvm_version: CvmVersion::Nucleus,
         energy_reports: vec!["Contract".to_string()],
@@ -65,6 +66,12 @@ sparktest!(can_extract_config_values, |prj: TestProject, mut cmd: TestCommand| {
             ..Default::default()
         },
         invariant: InvariantConfig { runs: 256, ..Default::default() },
+        timeout: Some(30),
+        energy_budget: Some(1000000000),
+        step_budget: Some(10000),
+        quarantine: vec![regex::Regex::new("ForkTest:").unwrap().into()],
+        coverage_thresholds: vec![CoverageThreshold::all(Some(80.), None)],
+        no_coverage_paths: vec!["src/mocks/**".to_string()],
         ffi: true,
         sender: "cb5400a329c0648769a73afac7f9381e08fb43dbea72".parse().unwrap(),
         tx_origin: "cb5400a329c0648769a73afac7f9381e08fb43dbea72".parse().unwrap(),
@@ -83,6 +90,7 @@ sparktest!(can_extract_config_values, |prj: TestProject, mut cmd: TestCommand| {
         eth_rpc_url: Some("localhost".to_string()),
         etherscan_api_key: None,
         etherscan: Default::default(),
+        verifier_url: None,
         verbosity: 4,
         remappings: vec![Remapping::from_str("forge-std=lib/forge-std/").unwrap().into()],
         libraries: vec![
@@ -0,0 +1,153 @@
+//! Contains various tests for checking `spark coverage`
+use foxar_cli_test_utils::{
+    sparktest,
+    util::{TestCommand, TestProject},
+};
+use foxar_config::{Config, CoverageThreshold};
+use std::fs;
+
+/// Adds a counter contract and a test that covers `increment()`, but not `reset()`
+fn add_counter(prj: &TestProject) {
+    prj.insert_ds_test();
+    prj.inner()
+        .add_source(
+            "Counter.sol",
+            r#"
+// SPDX-License-Identifier: UNLICENSED
+pragma solidity 1.1.0;
+contract Counter {
+    uint256 public number;
+    function increment() public {
+        number++;
+    }
+    function reset() public {
+        number = 0;
+    }
+}
+   "#,
+        )
+        .unwrap();
+    prj.inner()
+        .add_source(
+            "CounterTest.t.sol",
+            r#"
+// SPDX-License-Identifier: UNLICENSED
+pragma solidity 1.1.0;
+import "./test.sol";
+import "./Counter.sol";
+contract CounterTest is DSTest {
+    function testIncrement() public {
+        Counter counter = new Counter();
+        counter.increment();
+        assertEq(counter.number(), 1);
+    }
+}
+   "#,
+        )
+        .unwrap();
+}
+
+// tests that the HTML report replaces the pages of earlier reports
+sparktest!(can_write_html_report, |prj: TestProject, mut cmd: TestCommand| {
+    add_counter(&prj);
+
+    let out_dir = prj.root().join("coverage");
+    let stale = out_dir.join("src/Removed.sol.html");
+    fs::create_dir_all(stale.parent().unwrap()).unwrap();
+    fs::write(&stale, "<html></html>").unwrap();
+    let stale_dir = out_dir.join("old");
+    fs::create_dir_all(&stale_dir).unwrap();
+    fs::write(stale_dir.join("Old.sol.html"), "<html></html>").unwrap();
+    let notes = out_dir.join("notes.txt");
+    fs::write(&notes, "not a page").unwrap();
+
+    cmd.args(["coverage", "--report", "html"]);
+    let stdout = cmd.stdout_lossy();
+    assert!(stdout.contains("Wrote HTML report to"), "{stdout}");
+
+    let index = fs::read_to_string(out_dir.join("index.html")).unwrap();
+    assert!(index.contains(r#"<a href="src/Counter.sol.html">src/Counter.sol</a>"#), "{index}");
+    let page = fs::read_to_string(out_dir.join("src/Counter.sol.html")).unwrap();
+    assert!(page.contains(r#"<a href="../index.html">All files</a>"#), "{page}");
+
+    assert!(!stale.exists());
+    assert!(!stale_dir.exists());
+    assert!(notes.exists());
+});
+
+// tests that `spark coverage` fails if the coverage is below a threshold
+sparktest!(fails_below_coverage_threshold, |prj: TestProject, mut cmd: TestCommand| {
+    add_counter(&prj);
+
+    // `reset()` isn't covered, half of the lines are
+    cmd.args(["coverage", "--min-lines", "50"]);
+    cmd.assert_success();
+
+    cmd.spark_fuse().args(["coverage", "--min-lines", "90"]);
+    cmd.assert_err();
+    let stderr = cmd.stderr_lossy();
+    assert!(stderr.contains("Coverage is below the minimum"), "{stderr}");
+});
+
+// tests that a threshold whose pattern matches no source file fails instead of passing
+sparktest!(fails_on_unmatched_coverage_threshold, |prj: TestProject, mut cmd: TestCommand| {
+    add_counter(&prj);
+    prj.write_config(Config {
+        coverage_thresholds: vec![CoverageThreshold {
+            path: "source/**".to_string(),
+            min_lines: Some(50.),
+            min_branches: None,
+        }],
+        ..Default::default()
+    });
+
+    cmd.arg("coverage");
+    cmd.assert_err();
+    let stderr = cmd.stderr_lossy();
+    assert!(stderr.contains("source/**: doesn't match any source file"), "{stderr}");
+});
+
+// tests that baselines are resolved against the current directory and lines that moved or files
+// that were deleted are compared correctly
+sparktest!(can_compare_against_baseline, |prj: TestProject, mut cmd: TestCommand| {
+    add_counter(&prj);
+    let unused = prj
+        .inner()
+        .add_source(
+            "Unused.sol",
+            r#"
+// SPDX-License-Identifier: UNLICENSED
+pragma solidity 1.1.0;
+contract Unused {
+    function unused() public pure returns (uint256) {
+        return 1;
+    }
+}
+   "#,
+        )
+        .unwrap();
+
+    let reports = prj.root().join("reports");
+    fs::create_dir_all(&reports).unwrap();
+    cmd.current_dir(&reports).root_arg().args(["coverage", "--save-baseline", "baseline.json"]);
+    cmd.assert_success();
+    assert!(reports.join("baseline.json").exists());
+    assert!(!prj.root().join("baseline.json").exists());
+
+    // the uncovered line of `reset()` moves down, which isn't a new uncovered line
+    let counter = prj.root().join("src/Counter.sol");
+    let source = fs::read_to_string(&counter).unwrap();
+    fs::write(&counter, source.replace("contract Counter {", "// a comment\ncontract Counter {"))
+        .unwrap();
+    fs::remove_file(unused).unwrap();
+
+    cmd.spark_fuse().current_dir(&reports).root_arg().args([
+        "coverage",
+        "--baseline",
+        "baseline.json",
+    ]);
+    let stdout = cmd.stdout_lossy();
+    assert!(!stdout.contains("Newly uncovered lines"), "{stdout}");
+    let deleted = stdout.lines().find(|line| line.contains("src/Unused.sol")).unwrap();
+    assert!(deleted.contains("deleted (was 0.00%)"), "{deleted}");
+});
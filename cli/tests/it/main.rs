@@ -5,6 +5,8 @@ mod cmd;
 #[cfg(not(feature = "external-integration-tests"))]
 mod config;
 #[cfg(not(feature = "external-integration-tests"))]
+mod coverage;
+#[cfg(not(feature = "external-integration-tests"))]
 mod create;
 #[cfg(not(feature = "external-integration-tests"))]
 mod doc;
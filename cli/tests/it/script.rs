@@ -1,18 +1,28 @@
 //! Contains various tests related to spark script
 use crate::constants::TEMPLATE_CONTRACT;
-use corebc::{abi::Address, types::Network};
+use corebc::{
+    abi::Address,
+    types::{Network, H256},
+};
 use foxar_cli_test_utils::{
     sparktest, sparktest_async, sparktest_init,
     util::{OutputExt, TestCommand, TestProject},
     ScriptOutcome, ScriptTester,
 };
+use foxar_common::deployments::{Deployment, DeploymentRegistry, DEPLOYMENTS_HISTORY_DIR};
 use foxar_config::Config;
 use foxar_utils::rpc;
 use probe::SimpleCast;
 use regex::Regex;
 use serde_json::Value;
 use shuttle::{spawn, NodeConfig};
-use std::{env, path::PathBuf, str::FromStr};
+use std::{
+    env,
+    path::{Path, PathBuf},
+    process::Stdio,
+    str::FromStr,
+    time::Duration,
+};
 
 // Tests that fork cheat codes can be used in script
 sparktest_init!(
@@ -646,6 +656,162 @@ sparktest_async!(can_deploy_with_create2, |prj: TestProject, cmd: TestCommand| a
         .run(ScriptOutcome::FailedScript);
 });
 
+// Tests that the CREATE2 deployer is deployed when it's missing and the salt is recorded
+sparktest_async!(can_deploy_create2_deployer, |prj: TestProject, cmd: TestCommand| async move {
+    let (api, handle) = spawn(NodeConfig::test()).await;
+    let mut tester = ScriptTester::new_broadcast(cmd, &handle.http_endpoint(), prj.root());
+
+    let addr = Address::from_str("cb914e59b44847b379578588920ca78fbf26c0b4956c").unwrap();
+    assert!(api.get_code(addr, None).await.unwrap().is_empty());
+
+    tester
+        .add_deployer(0)
+        .load_private_keys(vec![0])
+        .await
+        .add_sig("BroadcastTestNoLinking", "deployCreate2()")
+        .simulate(ScriptOutcome::OkSimulation)
+        .broadcast(ScriptOutcome::OkBroadcast)
+        .assert_nonce_increment(vec![(0, 2)])
+        .await;
+
+    assert!(!api.get_code(addr, None).await.unwrap().is_empty());
+
+    let log = walkdir::WalkDir::new(prj.root().join("broadcast"))
+        .into_iter()
+        .filter_map(Result::ok)
+        .find(|entry| entry.file_name() == "deployCreate2-latest.json")
+        .unwrap();
+    let sequence: Value = foxar_common::fs::read_json_file(log.path()).unwrap();
+    let create2 = &sequence["transactions"][0];
+    assert_eq!(create2["transactionType"], "CREATE2");
+    assert_eq!(
+        create2["salt"],
+        "0x0000000000000000000000000000000000000000000000000000000000000539"
+    );
+    assert!(create2["initCodeHash"].is_string());
+});
+
+sparktest_async!(
+    can_broadcast_with_percentile_energy_price,
+    |prj: TestProject, cmd: TestCommand| async move {
+        let (_api, handle) = spawn(NodeConfig::test()).await;
+        let mut tester = ScriptTester::new_broadcast(cmd, &handle.http_endpoint(), prj.root());
+
+        tester
+            .args(
+                [
+                    "--energy-price-strategy",
+                    "percentile",
+                    "--replace-after",
+                    "30",
+                    "--max-energy-price",
+                    "100gwei",
+                ]
+                .map(String::from)
+                .to_vec(),
+            )
+            .load_private_keys(vec![0])
+            .await
+            .add_sig("BroadcastTestNoLinking", "deployMany()")
+            .simulate(ScriptOutcome::OkSimulation)
+            .broadcast(ScriptOutcome::OkBroadcast)
+            .assert_nonce_increment(vec![(0, 25)])
+            .await;
+    }
+);
+
+sparktest_async!(
+    fails_preflight_checks_without_balance,
+    |prj: TestProject, cmd: TestCommand| async move {
+        let (api, handle) = spawn(NodeConfig::test()).await;
+        let mut tester = ScriptTester::new_broadcast(cmd, &handle.http_endpoint(), prj.root());
+
+        let sender = Address::from_str("cb58e5dd06163a480c22d540ec763325a0b5860fb56c").unwrap();
+        api.shuttle_set_balance(sender, 1u64.into()).await.unwrap();
+
+        tester
+            .load_private_keys(vec![0, 1])
+            .await
+            .add_sig("BroadcastTestNoLinking", "deployDoesntPanic()")
+            .simulate(ScriptOutcome::OkSimulation)
+            .broadcast(ScriptOutcome::FailedPreflightChecks)
+            .assert_nonce_increment(vec![(0, 0), (1, 0)])
+            .await;
+    }
+);
+
+sparktest_async!(can_rehearse_script, |prj: TestProject, cmd: TestCommand| async move {
+    let (_api, handle) = spawn(NodeConfig::test()).await;
+    let mut tester = ScriptTester::new_broadcast(cmd, &handle.http_endpoint(), prj.root());
+
+    tester
+        .load_private_keys(vec![0, 1])
+        .await
+        .add_sig("BroadcastTestNoLinking", "deployDoesntPanic()")
+        .args(vec!["--rehearse".to_string()])
+        .run(ScriptOutcome::OkRehearsal)
+        .assert_nonce_increment(vec![(0, 0), (1, 0)])
+        .await;
+
+    tester.broadcast(ScriptOutcome::OkBroadcast).assert_nonce_increment(vec![(0, 1), (1, 2)]).await;
+});
+
+// Tests that a transaction which only passes the simulation, as it runs every transaction in the
+// same block, reverts in the rehearsal and stops the broadcast
+sparktest_async!(
+    does_not_broadcast_after_failed_rehearsal,
+    |prj: TestProject, mut cmd: TestCommand| async move {
+        foxar_cli_test_utils::util::initialize(prj.root());
+        let script = prj
+            .inner()
+            .add_source(
+                "Counter",
+                r#"
+// SPDX-License-Identifier: UNLICENSED
+pragma solidity 1.1.0;
+import "forge-std/Script.sol";
+
+contract Counter {
+    uint256 public deployedAt = block.number;
+    uint256 public number;
+
+    function increment() public {
+        require(block.number == deployedAt, "not in the deployment block");
+        number++;
+    }
+}
+contract CounterScript is Script {
+    function run() external {
+        vm.startBroadcast();
+        new Counter().increment();
+    }
+}
+   "#,
+            )
+            .unwrap();
+
+        let (api, handle) = spawn(NodeConfig::test()).await;
+        let private_key = "000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000001";
+        let sender = Address::from_str("cb58e5dd06163a480c22d540ec763325a0b5860fb56c").unwrap();
+        cmd.set_current_dir(prj.root());
+
+        cmd.arg("script").arg(script).args([
+            "--tc",
+            "CounterScript",
+            "--fork-url",
+            &handle.http_endpoint(),
+            "--private-key",
+            private_key,
+            "--rehearse",
+            "--broadcast",
+        ]);
+        let stderr = cmd.stderr_lossy();
+        assert!(stderr.contains("Rehearsal on network"), "{stderr}");
+        assert!(stderr.contains("Transaction 1, Counter::increment(), reverted"), "{stderr}");
+        assert!(api.transaction_count(sender, None).await.unwrap().is_zero());
+    }
+);
+
 sparktest_async!(
     #[serial_test::serial]
     can_deploy_and_simulate_25_txes_concurrently,
@@ -1166,3 +1332,969 @@ contract NestedCreateFail is Script {
         assert!(cmd.stdout_lossy().contains("Script ran successfully."));
     }
 );
+
+// Tests that coverage is collected while simulating a script, without the ignored lines
+sparktest!(can_report_script_coverage, |prj: TestProject, mut cmd: TestCommand| {
+    prj.inner()
+        .add_source(
+            "Counter",
+            r#"
+// SPDX-License-Identifier: UNLICENSED
+pragma solidity 1.1.0;
+contract Counter {
+    uint256 public number;
+    function increment() public {
+        number++;
+    }
+    function reset() public {
+        // coverage-ignore-next-line
+        number = 0;
+    }
+}
+   "#,
+        )
+        .unwrap();
+    let script = prj
+        .inner()
+        .add_source(
+            "CounterScript",
+            r#"
+// SPDX-License-Identifier: UNLICENSED
+pragma solidity 1.1.0;
+import "./Counter.sol";
+contract CounterScript {
+    function run() external {
+        new Counter().increment();
+    }
+}
+   "#,
+        )
+        .unwrap();
+
+    cmd.arg("script").arg(script).args(["--network", "1", "--coverage", "summary"]);
+    let stdout = cmd.stdout_lossy();
+    assert!(stdout.contains("Script ran successfully."));
+    let counter = stdout.lines().find(|line| line.contains("src/Counter.sol")).unwrap();
+    // The only executable line of `reset()` is ignored
+    assert!(counter.contains("100.00% (1/1)"), "{counter}");
+});
+
+// Tests that coverage can't be collected while broadcasting
+sparktest!(can_not_broadcast_with_coverage, |prj: TestProject, mut cmd: TestCommand| {
+    let script = prj
+        .inner()
+        .add_source(
+            "CounterScript",
+            r#"
+// SPDX-License-Identifier: UNLICENSED
+pragma solidity 1.1.0;
+contract CounterScript {
+    function run() external {}
+}
+   "#,
+        )
+        .unwrap();
+
+    for arg in ["--broadcast", "--resume", "--export-bundle"] {
+        cmd.spark_fuse().arg("script").arg(&script).args(["--coverage", "summary", arg]);
+        if arg == "--export-bundle" {
+            cmd.arg("bundle.json");
+        }
+        let stderr = cmd.stderr_lossy();
+        assert!(stderr.contains("cannot be used with"), "{stderr}");
+    }
+});
+
+// Tests that the coverage collected until `setUp()` reverted is still reported
+sparktest!(
+    can_report_script_coverage_after_failed_setup,
+    |prj: TestProject, mut cmd: TestCommand| {
+        prj.inner()
+            .add_source(
+                "Counter",
+                r#"
+// SPDX-License-Identifier: UNLICENSED
+pragma solidity 1.1.0;
+contract Counter {
+    uint256 public number;
+    function increment() public {
+        number++;
+    }
+}
+   "#,
+            )
+            .unwrap();
+        let script = prj
+            .inner()
+            .add_source(
+                "CounterScript",
+                r#"
+// SPDX-License-Identifier: UNLICENSED
+pragma solidity 1.1.0;
+import "./Counter.sol";
+contract CounterScript {
+    function setUp() external {
+        new Counter().increment();
+        revert("setUp failed");
+    }
+    function run() external {}
+}
+   "#,
+            )
+            .unwrap();
+
+        cmd.arg("script").arg(script).args(["--network", "1", "--coverage", "summary"]);
+        let output = cmd.unchecked_output();
+        assert!(!output.status.success());
+        let stdout = String::from_utf8_lossy(&output.stdout);
+        let counter = stdout.lines().find(|line| line.contains("src/Counter.sol")).unwrap();
+        assert!(counter.contains("100.00% (1/1)"), "{counter}");
+    }
+);
+
+// Tests that the lines of a library attached with `using for` are attributed to the library
+sparktest!(
+    can_report_script_coverage_of_attached_library,
+    |prj: TestProject, mut cmd: TestCommand| {
+        prj.inner()
+            .add_source(
+                "MathLib",
+                r#"
+// SPDX-License-Identifier: UNLICENSED
+pragma solidity 1.1.0;
+library MathLib {
+    function double(uint256 x) internal pure returns (uint256) {
+        return x * 2;
+    }
+}
+   "#,
+            )
+            .unwrap();
+        prj.inner()
+            .add_source(
+                "Counter",
+                r#"
+// SPDX-License-Identifier: UNLICENSED
+pragma solidity 1.1.0;
+import "./MathLib.sol";
+contract Counter {
+    using MathLib for uint256;
+    uint256 public number = 1;
+    function double() public {
+        number = number.double();
+    }
+}
+   "#,
+            )
+            .unwrap();
+        let script = prj
+            .inner()
+            .add_source(
+                "CounterScript",
+                r#"
+// SPDX-License-Identifier: UNLICENSED
+pragma solidity 1.1.0;
+import "./Counter.sol";
+contract CounterScript {
+    function run() external {
+        new Counter().double();
+    }
+}
+   "#,
+            )
+            .unwrap();
+
+        cmd.arg("script").arg(script).args(["--network", "1", "--coverage", "summary"]);
+        let stdout = cmd.stdout_lossy();
+        let library = stdout.lines().find(|line| line.contains("src/MathLib.sol")).unwrap();
+        assert!(library.contains("100.00% (1/1)"), "{library}");
+    }
+);
+
+// Tests that the state changes of the on-chain simulation are printed and exported
+sparktest_async!(
+    can_print_script_state_diff,
+    |prj: TestProject, mut cmd: TestCommand| async move {
+        foxar_cli_test_utils::util::initialize(prj.root());
+        let script = prj
+            .inner()
+            .add_source(
+                "Counter",
+                r#"
+// SPDX-License-Identifier: UNLICENSED
+pragma solidity 1.1.0;
+import "forge-std/Script.sol";
+
+contract Counter {
+    uint256 public number;
+    function increment() public {
+        number++;
+    }
+}
+contract CounterScript is Script {
+    function run() external {
+        vm.startBroadcast();
+        new Counter().increment();
+    }
+}
+   "#,
+            )
+            .unwrap();
+
+        let (_api, handle) = spawn(NodeConfig::test()).await;
+        let dev = handle.dev_accounts().next().unwrap();
+        cmd.set_current_dir(prj.root());
+
+        cmd.arg("script").arg(script).args([
+            "--tc",
+            "CounterScript",
+            "--fork-url",
+            &handle.http_endpoint(),
+            "--sender",
+            format!("{dev:?}").as_str(),
+            "--diff",
+            "--diff-out",
+            "diff.json",
+        ]);
+
+        let stdout = cmd.stdout_lossy();
+        assert!(stdout.contains("## State diff"), "{stdout}");
+
+        let diff: serde_json::Value =
+            foxar_common::fs::read_json_file(&prj.root().join("diff.json")).unwrap();
+        let accounts = diff.as_object().unwrap().values().next().unwrap()["accounts"].clone();
+        // The sender's nonce and the counter's code and storage changed
+        assert!(accounts.as_object().unwrap().len() >= 2, "{accounts}");
+        assert!(accounts
+            .as_object()
+            .unwrap()
+            .values()
+            .any(|account| account["storage"].as_array().map_or(false, |slots| !slots.is_empty())));
+    }
+);
+
+// Tests that an exported bundle can be signed offline and published
+sparktest_async!(
+    can_sign_and_publish_script_bundle,
+    |prj: TestProject, mut cmd: TestCommand| async move {
+        foxar_cli_test_utils::util::initialize(prj.root());
+        let script = prj
+            .inner()
+            .add_source(
+                "Counter",
+                r#"
+// SPDX-License-Identifier: UNLICENSED
+pragma solidity 1.1.0;
+import "forge-std/Script.sol";
+
+contract Counter {
+    uint256 public number;
+    function increment() public {
+        number++;
+    }
+}
+contract CounterScript is Script {
+    function run() external {
+        vm.startBroadcast();
+        Counter counter = new Counter();
+        counter.increment();
+    }
+}
+   "#,
+            )
+            .unwrap();
+
+        let (api, handle) = spawn(NodeConfig::test()).await;
+        let sender = "cb58e5dd06163a480c22d540ec763325a0b5860fb56c";
+        let private_key = "000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000001";
+        cmd.set_current_dir(prj.root());
+
+        cmd.arg("script").arg(script).args([
+            "--tc",
+            "CounterScript",
+            "--fork-url",
+            &handle.http_endpoint(),
+            "--sender",
+            sender,
+            "--export-bundle",
+            "bundle.json",
+        ]);
+        let stdout = cmd.stdout_lossy();
+        assert!(stdout.contains("Bundle of 2 transactions saved to"), "{stdout}");
+
+        cmd.spark_fuse().args([
+            "script",
+            "sign",
+            "bundle.json",
+            "--out",
+            "signed.json",
+            "--private-keys",
+            private_key,
+        ]);
+        let stdout = cmd.stdout_lossy();
+        assert!(stdout.contains("Signed bundle saved to"), "{stdout}");
+
+        cmd.spark_fuse()
+            .args(["script", "publish", "signed.json", "--rpc-url"])
+            .arg(handle.http_endpoint());
+        let stdout = cmd.stdout_lossy();
+        assert!(stdout.contains("ONCHAIN EXECUTION COMPLETE & SUCCESSFUL"), "{stdout}");
+
+        let nonce = api.transaction_count(Address::from_str(sender).unwrap(), None).await.unwrap();
+        assert_eq!(nonce.as_u64(), 2);
+        assert!(prj.root().join("broadcast").join("Counter.sol").exists());
+
+        // The created contract is saved to the deployment registry
+        let registry = DeploymentRegistry::new(prj.root().join("deployments"));
+        let network = registry.networks().unwrap().pop().unwrap();
+        let counter = registry.get(&network, "Counter").unwrap().unwrap();
+        assert_eq!(counter.contract_name.as_deref(), Some("Counter"));
+    }
+);
+
+// Tests that a bundle whose signed transactions differ from its transactions isn't published
+sparktest_async!(
+    can_not_publish_tampered_bundle,
+    |prj: TestProject, mut cmd: TestCommand| async move {
+        foxar_cli_test_utils::util::initialize(prj.root());
+        let script = prj
+            .inner()
+            .add_source(
+                "Counter",
+                r#"
+// SPDX-License-Identifier: UNLICENSED
+pragma solidity 1.1.0;
+import "forge-std/Script.sol";
+
+contract Counter {
+    uint256 public number;
+    function increment() public {
+        number++;
+    }
+}
+contract CounterScript is Script {
+    function run() external {
+        vm.startBroadcast();
+        Counter counter = new Counter();
+        counter.increment();
+    }
+}
+   "#,
+            )
+            .unwrap();
+
+        let (api, handle) = spawn(NodeConfig::test()).await;
+        let sender = "cb58e5dd06163a480c22d540ec763325a0b5860fb56c";
+        let private_key = "000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000001";
+        cmd.set_current_dir(prj.root());
+
+        cmd.arg("script").arg(script).args([
+            "--tc",
+            "CounterScript",
+            "--fork-url",
+            &handle.http_endpoint(),
+            "--sender",
+            sender,
+            "--export-bundle",
+            "bundle.json",
+        ]);
+        cmd.assert_success();
+        cmd.spark_fuse().args(["script", "sign", "bundle.json", "--private-keys", private_key]);
+        cmd.assert_success();
+
+        // Swap the signed transactions
+        let path = prj.root().join("bundle.json");
+        let mut bundle: Value = foxar_common::fs::read_json_file(&path).unwrap();
+        bundle["signed"].as_array_mut().unwrap().swap(0, 1);
+        std::fs::write(&path, serde_json::to_string(&bundle).unwrap()).unwrap();
+
+        cmd.spark_fuse()
+            .args(["script", "publish", "bundle.json", "--rpc-url"])
+            .arg(handle.http_endpoint());
+        let stderr = cmd.stderr_lossy();
+        assert!(stderr.contains("Signed transaction 0 has nonce 1"), "{stderr}");
+
+        let nonce = api.transaction_count(Address::from_str(sender).unwrap(), None).await.unwrap();
+        assert_eq!(nonce.as_u64(), 0);
+    }
+);
+
+// Tests that contracts deployed more than once are saved to the deployment registry under their
+// `saveDeployment` name or their transaction, without replacing each other
+sparktest_async!(
+    can_save_repeated_deployments,
+    |prj: TestProject, mut cmd: TestCommand| async move {
+        foxar_cli_test_utils::util::initialize(prj.root());
+        let script = prj
+            .inner()
+            .add_source(
+                "Counter",
+                r#"
+// SPDX-License-Identifier: UNLICENSED
+pragma solidity 1.1.0;
+import "forge-std/Script.sol";
+
+interface Deployments {
+    function saveDeployment(string calldata, address) external;
+}
+
+contract Counter {}
+contract CounterScript is Script {
+    function run() external {
+        vm.startBroadcast();
+        new Counter();
+        new Counter();
+        Deployments(address(vm)).saveDeployment("MainCounter", address(new Counter()));
+    }
+}
+   "#,
+            )
+            .unwrap();
+
+        let (_api, handle) = spawn(NodeConfig::test()).await;
+        let private_key = "000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000001";
+        cmd.set_current_dir(prj.root());
+
+        cmd.arg("script").arg(script).args([
+            "--tc",
+            "CounterScript",
+            "--fork-url",
+            &handle.http_endpoint(),
+            "--private-key",
+            private_key,
+            "--broadcast",
+        ]);
+        let stdout = cmd.stdout_lossy();
+        assert!(stdout.contains("Deployments saved to"), "{stdout}");
+
+        let registry = DeploymentRegistry::new(prj.root().join("deployments"));
+        let network = registry.networks().unwrap().pop().unwrap();
+        let deployments = registry.list(&network).unwrap();
+        assert_eq!(deployments.len(), 3, "{deployments:?}");
+        assert!(deployments.iter().all(|deployment| deployment.version == 1));
+        assert_eq!(
+            deployments
+                .iter()
+                .filter(|deployment| deployment.name.starts_with("Counter-0x"))
+                .count(),
+            2
+        );
+        assert!(registry.get(&network, "MainCounter").unwrap().is_some());
+        assert!(registry.get(&network, "Counter").unwrap().is_none());
+        assert!(!prj
+            .root()
+            .join("deployments")
+            .join(&network)
+            .join(DEPLOYMENTS_HISTORY_DIR)
+            .exists());
+    }
+);
+
+// Tests that a script refuses to broadcast the upgrade of a proxy to an implementation whose
+// storage layout is incompatible with the one recorded for the current implementation
+sparktest_async!(
+    can_refuse_incompatible_upgrade,
+    |prj: TestProject, mut cmd: TestCommand| async move {
+        foxar_cli_test_utils::util::initialize(prj.root());
+        let script = prj
+            .inner()
+            .add_source(
+                "Upgrade",
+                r#"
+// SPDX-License-Identifier: UNLICENSED
+pragma solidity 1.1.0;
+import "forge-std/Script.sol";
+
+contract CounterV1 {
+    uint256 public number;
+    address public owner;
+}
+contract CounterV2 {
+    address public owner;
+    uint256 public number;
+}
+contract Proxy {
+    bytes32 constant IMPLEMENTATION_SLOT =
+        0x360894a13ba1a3210667c828492db98dca3e2076cc3735a920a3ca505d382bbc;
+
+    constructor(address implementation) {
+        upgradeTo(implementation);
+    }
+
+    function upgradeTo(address implementation) public {
+        bytes32 slot = IMPLEMENTATION_SLOT;
+        assembly {
+            sstore(slot, implementation)
+        }
+    }
+}
+contract DeployScript is Script {
+    function run() external {
+        vm.startBroadcast();
+        new Proxy(address(new CounterV1()));
+    }
+}
+contract UpgradeScript is Script {
+    function run(address proxy) external {
+        vm.startBroadcast();
+        Proxy(proxy).upgradeTo(address(new CounterV2()));
+    }
+}
+   "#,
+            )
+            .unwrap();
+
+        let (_api, handle) = spawn(NodeConfig::test()).await;
+        let private_key = "000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000001";
+        cmd.set_current_dir(prj.root());
+
+        cmd.arg("script").arg(&script).args([
+            "--tc",
+            "DeployScript",
+            "--fork-url",
+            &handle.http_endpoint(),
+            "--private-key",
+            private_key,
+            "--broadcast",
+        ]);
+        cmd.assert_success();
+
+        let registry = DeploymentRegistry::new(prj.root().join("deployments"));
+        let network = registry.networks().unwrap().pop().unwrap();
+        let proxy = registry.get(&network, "Proxy").unwrap().unwrap().address;
+        assert!(registry.get(&network, "CounterV1").unwrap().unwrap().storage_layout.is_some());
+
+        let upgrade = |cmd: &mut TestCommand| {
+            cmd.spark_fuse().arg("script").arg(&script).args([
+                "--tc",
+                "UpgradeScript",
+                "--sig",
+                "run(address)",
+                format!("{proxy:?}").as_str(),
+                "--fork-url",
+                &handle.http_endpoint(),
+                "--private-key",
+                private_key,
+                "--broadcast",
+            ]);
+        };
+
+        upgrade(&mut cmd);
+        let stderr = cmd.stderr_lossy();
+        assert!(stderr.contains("Refusing to broadcast upgrades"), "{stderr}");
+        assert!(
+            stderr.contains("`number` (uint256) moved from slot 0, offset 0 to slot 1, offset 0"),
+            "{stderr}"
+        );
+        assert!(registry.get(&network, "CounterV2").unwrap().is_none());
+
+        upgrade(&mut cmd);
+        cmd.arg("--skip-storage-layout-check");
+        cmd.assert_success();
+        assert!(registry.get(&network, "CounterV2").unwrap().is_some());
+    }
+);
+
+// Tests that `getDeployment` can't read the deployment registry without `fs_permissions`
+sparktest!(can_not_get_deployment_without_permission, |prj: TestProject, mut cmd: TestCommand| {
+    foxar_cli_test_utils::util::initialize(prj.root());
+    let registry = DeploymentRegistry::new(prj.root().join("deployments"));
+    registry.save("mainnet", Deployment::new("Counter", Address::repeat_byte(1))).unwrap();
+    let script = prj
+        .inner()
+        .add_source(
+            "CounterScript",
+            r#"
+// SPDX-License-Identifier: UNLICENSED
+pragma solidity 1.1.0;
+import "forge-std/Script.sol";
+
+interface Deployments {
+    function getDeployment(string calldata, string calldata) external returns (address);
+}
+
+contract CounterScript is Script {
+    function run() external {
+        Deployments(address(vm)).getDeployment("Counter", "mainnet");
+    }
+}
+   "#,
+        )
+        .unwrap();
+
+    cmd.arg("script").arg(script);
+    let stderr = cmd.stderr_lossy();
+    assert!(stderr.contains("is not allowed to be accessed for read operations"), "{stderr}");
+});
+
+const BATCH_SCRIPT: &str = r#"
+// SPDX-License-Identifier: UNLICENSED
+pragma solidity 1.1.0;
+import "forge-std/Script.sol";
+
+contract Multicall3 {
+    struct Call3 {
+        address target;
+        bool allowFailure;
+        bytes callData;
+    }
+    struct Result {
+        bool success;
+        bytes returnData;
+    }
+    function aggregate3(Call3[] calldata calls) external returns (Result[] memory returnData) {
+        returnData = new Result[](calls.length);
+        for (uint256 i = 0; i < calls.length; i++) {
+            (bool success, bytes memory ret) = calls[i].target.call(calls[i].callData);
+            require(success || calls[i].allowFailure, "Multicall3: call failed");
+            returnData[i] = Result(success, ret);
+        }
+    }
+}
+contract Counter {
+    uint256 public number;
+    function increment() public {
+        number++;
+    }
+}
+contract OtherCounter is Counter {}
+
+contract DeployScript is Script {
+    function run() external {
+        vm.startBroadcast();
+        new Multicall3();
+        new Counter();
+        new OtherCounter();
+    }
+}
+contract BatchScript is Script {
+    function run(Counter counter, Counter other) external {
+        vm.startBroadcast();
+        counter.increment();
+        counter.increment();
+        other.increment();
+        counter.increment();
+    }
+}
+"#;
+
+/// Deploys the contracts of [BATCH_SCRIPT] and returns the addresses of the multicall, the
+/// counter and the other counter
+fn deploy_batch_contracts(
+    prj: &TestProject,
+    cmd: &mut TestCommand,
+    endpoint: &str,
+    private_key: &str,
+) -> (PathBuf, [Address; 3]) {
+    foxar_cli_test_utils::util::initialize(prj.root());
+    let script = prj.inner().add_source("Batch", BATCH_SCRIPT).unwrap();
+    cmd.set_current_dir(prj.root());
+    cmd.arg("script").arg(&script).args([
+        "--tc",
+        "DeployScript",
+        "--fork-url",
+        endpoint,
+        "--private-key",
+        private_key,
+        "--broadcast",
+    ]);
+    cmd.assert_success();
+
+    let registry = DeploymentRegistry::new(prj.root().join("deployments"));
+    let network = registry.networks().unwrap().pop().unwrap();
+    let address = |name| registry.get(&network, name).unwrap().unwrap().address;
+    (script, [address("Multicall3"), address("Counter"), address("OtherCounter")])
+}
+
+// Tests that only the calls to the batch targets are broadcast as multicall transactions
+sparktest_async!(
+    can_broadcast_batched_calls,
+    |prj: TestProject, mut cmd: TestCommand| async move {
+        let (api, handle) = spawn(NodeConfig::test()).await;
+        let sender = Address::from_str("cb58e5dd06163a480c22d540ec763325a0b5860fb56c").unwrap();
+        let private_key = "000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000001";
+        let (script, [multicall, counter, other]) =
+            deploy_batch_contracts(&prj, &mut cmd, &handle.http_endpoint(), private_key);
+        let nonce = api.transaction_count(sender, None).await.unwrap();
+
+        cmd.spark_fuse()
+            .arg("script")
+            .arg(&script)
+            .args(["--tc", "BatchScript", "--sig", "run(address,address)"])
+            .arg(format!("{counter:?}"))
+            .arg(format!("{other:?}"))
+            .args([
+                "--fork-url",
+                &handle.http_endpoint(),
+                "--private-key",
+                private_key,
+                "--broadcast",
+            ])
+            .args(["--batch", "--multicall", &format!("{multicall:?}"), "--batch-targets"])
+            .arg(format!("{counter:?}"));
+        let stdout = cmd.stdout_lossy();
+        assert!(stdout.contains("ONCHAIN EXECUTION COMPLETE & SUCCESSFUL"), "{stdout}");
+
+        // The first two increments are batched, the call to the other counter isn't a target and
+        // ends the batch
+        let sent = api.transaction_count(sender, None).await.unwrap() - nonce;
+        assert_eq!(sent.as_u64(), 3);
+        let number = api.storage_at(counter, Default::default(), None).await.unwrap();
+        assert_eq!(number.to_low_u64_be(), 3);
+        let number = api.storage_at(other, Default::default(), None).await.unwrap();
+        assert_eq!(number.to_low_u64_be(), 1);
+    }
+);
+
+// Tests that a simulated sequence of batched calls can be resumed
+sparktest_async!(can_resume_batched_calls, |prj: TestProject, mut cmd: TestCommand| async move {
+    let (api, handle) = spawn(NodeConfig::test()).await;
+    let sender = Address::from_str("cb58e5dd06163a480c22d540ec763325a0b5860fb56c").unwrap();
+    let private_key = "000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000001";
+    let (script, [multicall, counter, other]) =
+        deploy_batch_contracts(&prj, &mut cmd, &handle.http_endpoint(), private_key);
+    let nonce = api.transaction_count(sender, None).await.unwrap();
+
+    let run = |cmd: &mut TestCommand, args: &[&str]| {
+        cmd.spark_fuse()
+            .arg("script")
+            .arg(&script)
+            .args(["--tc", "BatchScript", "--sig", "run(address,address)"])
+            .arg(format!("{counter:?}"))
+            .arg(format!("{other:?}"))
+            .args(["--fork-url", &handle.http_endpoint(), "--private-key", private_key])
+            .args(args)
+            .args(["--batch", "--multicall", &format!("{multicall:?}"), "--batch-targets"])
+            .arg(format!("{counter:?},{other:?}"));
+        cmd.stdout_lossy()
+    };
+
+    run(&mut cmd, &[]);
+    assert_eq!(api.transaction_count(sender, None).await.unwrap(), nonce);
+
+    let stdout = run(&mut cmd, &["--resume"]);
+    assert!(stdout.contains("ONCHAIN EXECUTION COMPLETE & SUCCESSFUL"), "{stdout}");
+
+    // All calls were sent in a single multicall transaction
+    let sent = api.transaction_count(sender, None).await.unwrap() - nonce;
+    assert_eq!(sent.as_u64(), 1);
+    let number = api.storage_at(counter, Default::default(), None).await.unwrap();
+    assert_eq!(number.to_low_u64_be(), 3);
+    let number = api.storage_at(other, Default::default(), None).await.unwrap();
+    assert_eq!(number.to_low_u64_be(), 1);
+});
+
+/// Adds a script deploying a counter, sent by the first dev account
+fn add_stuck_script(prj: &TestProject) -> PathBuf {
+    foxar_cli_test_utils::util::initialize(prj.root());
+    prj.inner()
+        .add_source(
+            "Counter",
+            r#"
+// SPDX-License-Identifier: UNLICENSED
+pragma solidity 1.1.0;
+import "forge-std/Script.sol";
+
+contract Counter {}
+contract CounterScript is Script {
+    function run() external {
+        vm.startBroadcast();
+        new Counter();
+    }
+}
+   "#,
+        )
+        .unwrap()
+}
+
+/// Returns the latest broadcast log of the `Counter.sol` script, if it was written
+fn counter_broadcast_log(root: &Path) -> Option<Value> {
+    let network =
+        std::fs::read_dir(root.join("broadcast").join("Counter.sol")).ok()?.next()?.ok()?;
+    let log = std::fs::read_to_string(network.path().join("run-latest.json")).ok()?;
+    serde_json::from_str(&log).ok()
+}
+
+// Tests that a transaction still pending after `--replace-after` is replaced with the same nonce
+// and a higher energy price
+sparktest_async!(
+    can_replace_stuck_transaction,
+    |prj: TestProject, mut cmd: TestCommand| async move {
+        let script = add_stuck_script(&prj);
+        let (api, handle) = spawn(NodeConfig::test().with_no_mining(true)).await;
+        let sender = Address::from_str("cb58e5dd06163a480c22d540ec763325a0b5860fb56c").unwrap();
+        let private_key = "000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000001";
+        cmd.set_current_dir(prj.root());
+
+        cmd.arg("script").arg(script).args([
+            "--tc",
+            "CounterScript",
+            "--fork-url",
+            &handle.http_endpoint(),
+            "--private-key",
+            private_key,
+            "--broadcast",
+            "--with-energy-price",
+            "10",
+            "--replace-after",
+            "1",
+            "--max-energy-price",
+            "100",
+        ]);
+        let child = cmd.cmd().stdout(Stdio::piped()).stderr(Stdio::piped()).spawn().unwrap();
+        let run = tokio::task::spawn_blocking(move || child.wait_with_output().unwrap());
+
+        // Nothing is mined until the transaction was replaced
+        let replacement = loop {
+            let replacement = counter_broadcast_log(prj.root())
+                .and_then(|log| log["replacements"].as_array()?.first().cloned());
+            if let Some(replacement) = replacement {
+                break replacement
+            }
+            tokio::time::sleep(Duration::from_millis(100)).await;
+        };
+        assert_eq!(replacement["energyPrice"], "0xb");
+        api.mine_one().await;
+
+        let output = run.await.unwrap();
+        let stdout = String::from_utf8_lossy(&output.stdout);
+        assert!(stdout.contains("ONCHAIN EXECUTION COMPLETE & SUCCESSFUL"), "{stdout}");
+        let log = counter_broadcast_log(prj.root()).unwrap();
+        assert_eq!(log["transactions"][0]["hash"], replacement["hash"]);
+        assert_eq!(log["receipts"][0]["transactionHash"], replacement["hash"]);
+        assert_eq!(api.transaction_count(sender, None).await.unwrap().as_u64(), 1);
+    }
+);
+
+// Tests that replacements stop at `--max-energy-price`, and that a replaced transaction which was
+// mined instead of its replacement is recorded when resuming
+sparktest_async!(
+    can_resume_mined_replaced_transaction,
+    |prj: TestProject, mut cmd: TestCommand| async move {
+        let script = add_stuck_script(&prj);
+        let (api, handle) = spawn(NodeConfig::test().with_no_mining(true)).await;
+        let sender = Address::from_str("cb58e5dd06163a480c22d540ec763325a0b5860fb56c").unwrap();
+        let private_key = "000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000001";
+        cmd.set_current_dir(prj.root());
+
+        let mut run = |args: &[&str], max_energy_price: &str| {
+            cmd.spark_fuse().arg("script").arg(&script).args([
+                "--tc",
+                "CounterScript",
+                "--fork-url",
+                &handle.http_endpoint(),
+                "--private-key",
+                private_key,
+                "--broadcast",
+                "--with-energy-price",
+                "10",
+                "--replace-after",
+                "1",
+                "--max-energy-price",
+                max_energy_price,
+            ]);
+            cmd.args(args).unchecked_output()
+        };
+
+        // The first replacement would already cost more than the maximum
+        let output = run(&[], "10");
+        assert!(!output.status.success());
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        assert!(stderr.contains("would exceed the maximum energy price of 10"), "{stderr}");
+        let log = counter_broadcast_log(prj.root()).unwrap();
+        assert!(log["replacements"].is_null(), "{log}");
+        let original = H256::from_str(log["pending"][0].as_str().unwrap()).unwrap();
+        let raw = api.transaction_by_hash(original).await.unwrap().unwrap().rlp();
+
+        // Only the first replacement is within the maximum
+        let output = run(&["--resume"], "11");
+        assert!(!output.status.success());
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        assert!(stderr.contains("would exceed the maximum energy price of 11"), "{stderr}");
+        let log = counter_broadcast_log(prj.root()).unwrap();
+        let replacement = H256::from_str(log["pending"][0].as_str().unwrap()).unwrap();
+        assert_eq!(log["replacements"][0]["replaced"], format!("{original:?}"));
+        assert_eq!(log["replacements"][0]["hash"], format!("{replacement:?}"));
+
+        // The original is mined instead of its replacement
+        api.shuttle_drop_transaction(replacement).await.unwrap();
+        api.send_raw_transaction(raw).await.unwrap();
+        api.mine_one().await;
+
+        let output = run(&["--resume"], "11");
+        let stdout = String::from_utf8_lossy(&output.stdout);
+        assert!(stdout.contains("ONCHAIN EXECUTION COMPLETE & SUCCESSFUL"), "{stdout}");
+        let log = counter_broadcast_log(prj.root()).unwrap();
+        assert_eq!(log["transactions"][0]["hash"], format!("{original:?}"));
+        assert_eq!(log["receipts"][0]["transactionHash"], format!("{original:?}"));
+        assert_eq!(api.transaction_count(sender, None).await.unwrap().as_u64(), 1);
+    }
+);
+
+// Tests that the preflight checks account for the value a sender transfers to another sender
+sparktest_async!(
+    can_fund_sender_within_script,
+    |prj: TestProject, mut cmd: TestCommand| async move {
+        foxar_cli_test_utils::util::initialize(prj.root());
+        let script = prj
+            .inner()
+            .add_source(
+                "Fund",
+                r#"
+// SPDX-License-Identifier: UNLICENSED
+pragma solidity 1.1.0;
+import "forge-std/Script.sol";
+
+contract Counter {}
+contract FundScript is Script {
+    function run(address funder, address funded) external {
+        vm.broadcast(funder);
+        payable(funded).transfer(1 ether);
+        vm.broadcast(funded);
+        new Counter();
+    }
+}
+   "#,
+            )
+            .unwrap();
+
+        let (api, handle) = spawn(NodeConfig::test()).await;
+        let funder = Address::from_str("cb58e5dd06163a480c22d540ec763325a0b5860fb56c").unwrap();
+        let funded = Address::from_str("cb732536ad1a311f40a2f2cd1871246685d572afe700").unwrap();
+        api.shuttle_set_balance(funded, 0u64.into()).await.unwrap();
+        cmd.set_current_dir(prj.root());
+
+        cmd.arg("script")
+            .arg(script)
+            .args(["--tc", "FundScript", "--sig", "run(address,address)"])
+            .arg(format!("{funder:?}"))
+            .arg(format!("{funded:?}"))
+            .args(["--fork-url", &handle.http_endpoint(), "--broadcast", "--private-keys"])
+            .arg("000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000001")
+            .arg("--private-keys")
+            .arg("000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000002");
+        // The funded sender can only afford its transaction with the transfer
+        let stdout = cmd.stdout_lossy();
+        assert!(stdout.contains("ONCHAIN EXECUTION COMPLETE & SUCCESSFUL"), "{stdout}");
+        assert_eq!(api.transaction_count(funder, None).await.unwrap().as_u64(), 1);
+        assert_eq!(api.transaction_count(funded, None).await.unwrap().as_u64(), 1);
+    }
+);
+
+// Tests that the preflight checks can be skipped
+sparktest_async!(can_skip_preflight_checks, |prj: TestProject, cmd: TestCommand| async move {
+    let (api, handle) = spawn(NodeConfig::test()).await;
+    let mut tester = ScriptTester::new_broadcast(cmd, &handle.http_endpoint(), prj.root());
+
+    let sender = Address::from_str("cb58e5dd06163a480c22d540ec763325a0b5860fb56c").unwrap();
+    api.shuttle_set_balance(sender, 1u64.into()).await.unwrap();
+
+    tester
+        .args(vec!["--skip-preflight".to_string()])
+        .load_private_keys(vec![0, 1])
+        .await
+        .add_sig("BroadcastTestNoLinking", "deployDoesntPanic()");
+    let stderr = tester.cmd.arg("--broadcast").stderr_lossy();
+    assert!(!stderr.contains("Preflight checks failed"), "{stderr}");
+    assert!(stderr.contains("Error"), "{stderr}");
+});
@@ -4,9 +4,9 @@ use foxar_cli_test_utils::{
     sparktest, sparktest_init,
     util::{OutputExt, TestCommand, TestProject},
 };
-use foxar_config::Config;
+use foxar_config::{fs_permissions::PathPermission, Config, FsPermissions};
 use foxar_utils::rpc;
-use std::{path::PathBuf, str::FromStr};
+use std::{fs, path::PathBuf, str::FromStr};
 
 // tests that test filters are handled correctly
 sparktest!(can_set_filter_values, |prj: TestProject, mut cmd: TestCommand| {
@@ -410,3 +410,290 @@ sparktest_init!(
         cmd.assert_err();
     }
 );
+
+// tests that the test results can be written as JUnit XML while still printing the pretty output
+sparktest!(can_write_junit_report, |prj: TestProject, mut cmd: TestCommand| {
+    prj.insert_ds_test();
+
+    prj.inner()
+        .add_source(
+            "ATest.t.sol",
+            r#"
+// SPDX-License-Identifier: UNLICENSED
+pragma solidity 1.1.0;
+import "./test.sol";
+contract ATest is DSTest {
+    function testPass() external {
+        assertTrue(true);
+    }
+}
+   "#,
+        )
+        .unwrap();
+
+    let report = prj.root().join("report.xml");
+    cmd.args(["test", "--reporter", "junit", "--output"]).arg(&report);
+    assert!(cmd.stdout().contains("[PASS]"));
+
+    let xml = std::fs::read_to_string(report).unwrap();
+    assert!(xml.contains(r#"<testsuites name="spark" tests="1" failures="0" skipped="0""#));
+    assert!(xml.contains(r#"<testcase name="testPass()""#));
+});
+
+// tests that the TAP report replaces the pretty output on stdout
+sparktest!(can_print_tap_report, |prj: TestProject, mut cmd: TestCommand| {
+    prj.insert_ds_test();
+
+    prj.inner()
+        .add_source(
+            "ATest.t.sol",
+            r#"
+// SPDX-License-Identifier: UNLICENSED
+pragma solidity 1.1.0;
+import "./test.sol";
+contract ATest is DSTest {
+    function testPass() external {
+        assertTrue(true);
+    }
+}
+   "#,
+        )
+        .unwrap();
+
+    cmd.args(["test", "--reporter", "tap"]);
+    let stdout = cmd.stdout();
+    assert!(stdout.starts_with("TAP version 13\n1..1\n"));
+    assert!(stdout.contains("ok 1 - "));
+    assert!(!stdout.contains(" # "), "{stdout}");
+    assert!(stdout.contains("\n  ---\n  gas: "), "{stdout}");
+    assert!(!stdout.contains("[PASS]"));
+});
+
+// tests that only the tests affected by changed sources are run with `--changed`
+sparktest!(can_run_changed_tests, |prj: TestProject, mut cmd: TestCommand| {
+    prj.insert_ds_test();
+    let counter = |value: u32| {
+        format!(
+            r#"
+// SPDX-License-Identifier: UNLICENSED
+pragma solidity 1.1.0;
+contract Counter {{
+    function value() external pure returns (uint256) {{
+        return {value};
+    }}
+}}
+"#
+        )
+    };
+    prj.inner().add_source("Counter.sol", counter(1)).unwrap();
+    prj.inner()
+        .add_source(
+            "Other.sol",
+            r#"
+// SPDX-License-Identifier: UNLICENSED
+pragma solidity 1.1.0;
+contract Other {}
+"#,
+        )
+        .unwrap();
+    prj.inner()
+        .add_source(
+            "Counter.t.sol",
+            r#"
+// SPDX-License-Identifier: UNLICENSED
+pragma solidity 1.1.0;
+import "./test.sol";
+import "./Counter.sol";
+contract CounterTest is DSTest {
+    function testValue() external {
+        assertTrue(new Counter().value() > 0);
+    }
+}
+"#,
+        )
+        .unwrap();
+    prj.inner()
+        .add_source(
+            "Other.t.sol",
+            r#"
+// SPDX-License-Identifier: UNLICENSED
+pragma solidity 1.1.0;
+import "./test.sol";
+import "./Other.sol";
+contract OtherTest is DSTest {
+    function testOther() external {
+        new Other();
+    }
+}
+"#,
+        )
+        .unwrap();
+
+    cmd.git_init();
+    let git = |args: &[&str]| {
+        let status = std::process::Command::new("git")
+            .args(["-c", "user.name=test", "-c", "user.email=test@test.com"])
+            .args(args)
+            .current_dir(prj.root())
+            .output()
+            .unwrap()
+            .status;
+        assert!(status.success());
+    };
+    git(&["add", "."]);
+    git(&["commit", "-m", "init", "--no-gpg-sign"]);
+
+    cmd.args(["test", "--changed"]);
+    assert!(cmd.stdout().contains("No tests are affected"));
+
+    prj.inner().add_source("Counter.sol", counter(2)).unwrap();
+    let stdout = cmd.stdout();
+    assert!(stdout.contains("CounterTest"));
+    assert!(!stdout.contains("OtherTest"));
+});
+
+// tests that the shards split the test functions and their results can be merged
+sparktest!(can_shard_and_merge_results, |prj: TestProject, mut cmd: TestCommand| {
+    prj.insert_ds_test();
+
+    prj.inner()
+        .add_source(
+            "ATest.t.sol",
+            r#"
+// SPDX-License-Identifier: UNLICENSED
+pragma solidity 1.1.0;
+import "./test.sol";
+contract ATest is DSTest {
+    function testA() external {
+        assertTrue(true);
+    }
+
+    function testB() external {
+        assertTrue(true);
+    }
+}
+   "#,
+        )
+        .unwrap();
+
+    let shards = ["1/2", "2/2"].map(|shard| {
+        let report = prj.root().join(format!("shard-{}.json", &shard[..1]));
+        cmd.spark_fuse()
+            .args(["test", "--shard", shard, "--reporter", "json", "--output"])
+            .arg(&report);
+        let stdout = cmd.stdout();
+        assert_eq!(stdout.matches("[PASS]").count(), 1, "{stdout}");
+        report
+    });
+
+    // the shards don't record durations, so all shards partition from the same durations
+    let durations = prj.root().join("cache/test-durations.json");
+    assert!(!durations.exists());
+
+    cmd.spark_fuse().args(["test", "merge-results"]).args(&shards);
+    let stdout = cmd.stdout();
+    assert!(stdout.contains("testA()"));
+    assert!(stdout.contains("testB()"));
+    assert!(stdout.contains("1 test suites: 2 tests passed"));
+
+    let durations: serde_json::Value =
+        serde_json::from_str(&fs::read_to_string(durations).unwrap()).unwrap();
+    let tests = durations["contracts"].as_object().unwrap().values().next().unwrap();
+    assert!(tests.get("testA()").is_some() && tests.get("testB()").is_some(), "{durations}");
+});
+
+// quarantined tests are run, but their failures don't fail the test run
+sparktest!(quarantined_failures_dont_fail, |prj: TestProject, mut cmd: TestCommand| {
+    prj.insert_ds_test();
+    prj.inner()
+        .add_source(
+            "QuarantineTest.t.sol",
+            r#"
+// SPDX-License-Identifier: UNLICENSED
+pragma solidity 1.1.0;
+import "./test.sol";
+contract QuarantineTest is DSTest {
+    function testPass() external {
+        assertTrue(true);
+    }
+
+    function testQuarantined() external {
+        assertTrue(false);
+    }
+}
+   "#,
+        )
+        .unwrap();
+
+    cmd.arg("test");
+    cmd.assert_err();
+
+    let config = Config {
+        quarantine: vec![regex::Regex::new("QuarantineTest:testQuarantined").unwrap().into()],
+        ..Default::default()
+    };
+    prj.write_config(config);
+    cmd.spark_fuse().args(["test", "--retries", "1"]);
+    let stdout = cmd.stdout();
+    assert!(stdout.contains("[QUARANTINED]"), "{stdout}");
+    assert!(stdout.contains("[PASS] testPass()"), "{stdout}");
+});
+
+// tests that fail once and pass when retried are reported and recorded as flaky
+sparktest!(retried_tests_are_flaky, |prj: TestProject, mut cmd: TestCommand| {
+    prj.insert_ds_test();
+    prj.inner()
+        .add_source(
+            "FlakyTest.t.sol",
+            r#"
+// SPDX-License-Identifier: UNLICENSED
+pragma solidity 1.1.0;
+import "./test.sol";
+
+interface Vm {
+    function readFile(string calldata) external returns (string memory);
+    function writeFile(string calldata, string calldata) external;
+}
+
+contract FlakyTest is DSTest {
+    Vm constant vm = Vm(HEVM_ADDRESS);
+
+    // every attempt appends to the file, only the first one fails
+    function testFailsOnce() external {
+        try vm.readFile("attempts.txt") returns (string memory attempts) {
+            vm.writeFile("attempts.txt", string(abi.encodePacked(attempts, "1")));
+        } catch {
+            vm.writeFile("attempts.txt", "1");
+            assertTrue(false);
+        }
+    }
+}
+   "#,
+        )
+        .unwrap();
+
+    let config = Config {
+        fs_permissions: FsPermissions::new(vec![PathPermission::read_write("./")]),
+        ..Default::default()
+    };
+    prj.write_config(config);
+
+    cmd.args(["test", "--retries", "2"]);
+    let stdout = cmd.stdout();
+    assert!(stdout.contains("[FLAKY] testFailsOnce()"), "{stdout}");
+    assert!(stdout.contains("1 passed (1 flaky); 0 failed"), "{stdout}");
+
+    // the test was retried once
+    let attempts = fs::read_to_string(prj.root().join("attempts.txt")).unwrap();
+    assert_eq!(attempts, "11");
+
+    let history: serde_json::Value = serde_json::from_str(
+        &fs::read_to_string(prj.root().join("cache/flaky-tests.json")).unwrap(),
+    )
+    .unwrap();
+    let record = &history["tests"]["src/FlakyTest.t.sol:FlakyTest"]["testFailsOnce()"];
+    assert_eq!(record["runs"], 1, "{history}");
+    assert_eq!(record["failures"], 0, "{history}");
+    assert_eq!(record["flaky"], 1, "{history}");
+    assert!(record["last_flaky"].is_u64(), "{history}");
+});
@@ -3,10 +3,17 @@
 
 use crate::utils::{self, EnvExternalities};
 use foxar_cli_test_utils::{
-    sparktest,
+    sparktest, sparktest_async,
     util::{TestCommand, TestProject},
 };
+use foxar_config::Config;
 use foxar_utils::Retry;
+use serde_json::Value;
+use shuttle::{spawn, NodeConfig};
+use std::{
+    io::{BufRead, BufReader, Read, Write},
+    net::{TcpListener, TcpStream},
+};
 
 /// Adds a `Unique` contract to the source directory of the project that can be imported as
 /// `import {Unique} from "./unique.sol";`
@@ -121,3 +128,137 @@ fn verify_on_chain(info: Option<EnvExternalities>, prj: TestProject, mut cmd: Te
 sparktest!(can_verify_random_contract_fantom_testnet, |prj: TestProject, cmd: TestCommand| {
     verify_on_chain(EnvExternalities::devin(), prj, cmd);
 });
+
+/// Spawns a stand-in for a Sourcify server, which answers every request with the given status
+/// code, and returns its URL
+fn spawn_mock_sourcify(status: u16) -> String {
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let url = format!("http://{}/", listener.local_addr().unwrap());
+    std::thread::spawn(move || {
+        for stream in listener.incoming().flatten() {
+            let _ = respond(stream, status);
+        }
+    });
+    url
+}
+
+fn respond(mut stream: TcpStream, status: u16) -> std::io::Result<()> {
+    let mut reader = BufReader::new(stream.try_clone()?);
+    let mut content_length = 0;
+    loop {
+        let mut line = String::new();
+        reader.read_line(&mut line)?;
+        if line.trim().is_empty() {
+            break
+        }
+        if let Some((name, value)) = line.split_once(':') {
+            if name.eq_ignore_ascii_case("content-length") {
+                content_length = value.trim().parse().unwrap_or_default();
+            }
+        }
+    }
+    let mut body = vec![0; content_length];
+    reader.read_exact(&mut body)?;
+
+    let body = if status == 200 {
+        r#"{"result":[{"status":"perfect"}]}"#
+    } else {
+        r#"{"error":"Internal Server Error"}"#
+    };
+    write!(
+        stream,
+        "HTTP/1.1 {status} Mock\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+        body.len()
+    )
+}
+
+/// Returns the verifications recorded in the latest broadcast log of the `Counter` script
+fn script_verifications(prj: &TestProject) -> Vec<Value> {
+    let network = std::fs::read_dir(prj.root().join("broadcast/Counter.sol"))
+        .unwrap()
+        .next()
+        .unwrap()
+        .unwrap()
+        .path();
+    let log: Value =
+        serde_json::from_str(&std::fs::read_to_string(network.join("run-latest.json")).unwrap())
+            .unwrap();
+    log["verifications"].as_array().cloned().unwrap_or_default()
+}
+
+// tests that failed verifications of `spark script --verify` are recorded and retried by
+// `spark verify-all`, against a local stand-in for Sourcify
+sparktest_async!(
+    can_retry_failed_script_verifications,
+    |prj: TestProject, mut cmd: TestCommand| async move {
+        foxar_cli_test_utils::util::initialize(prj.root());
+        let script = prj
+            .inner()
+            .add_source(
+                "Counter",
+                r#"
+// SPDX-License-Identifier: UNLICENSED
+pragma solidity 1.1.0;
+import "forge-std/Script.sol";
+
+contract Counter {
+    uint256 public number;
+}
+contract CounterScript is Script {
+    function run() external {
+        vm.startBroadcast();
+        new Counter();
+    }
+}
+   "#,
+            )
+            .unwrap();
+
+        let (_api, handle) = spawn(NodeConfig::test()).await;
+        cmd.set_current_dir(prj.root());
+        cmd.arg("script").arg(script).args([
+            "--tc",
+            "CounterScript",
+            "--fork-url",
+            &handle.http_endpoint(),
+            "--private-keys",
+            "000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000001",
+            "--broadcast",
+            "--verify",
+            "--verifier",
+            "sourcify",
+            "--verifier-url",
+            &spawn_mock_sourcify(500),
+            "--retries",
+            "1",
+            "--delay",
+            "0",
+        ]);
+        let output = cmd.unchecked_output();
+        assert!(!output.status.success());
+        assert!(String::from_utf8_lossy(&output.stderr).contains("contracts failed to verify"));
+
+        let verifications = script_verifications(&prj);
+        assert_eq!(verifications.len(), 1);
+        assert!(verifications[0]["contract"].as_str().unwrap().ends_with("Counter.sol:Counter"));
+        assert_eq!(verifications[0]["status"], "failed");
+
+        // the endpoint of the retry comes from the config
+        prj.write_config(Config {
+            verifier_url: Some(spawn_mock_sourcify(200)),
+            ..Default::default()
+        });
+        cmd.spark_fuse().args(["verify-all", "--verifier", "sourcify", "--retries", "1"]);
+        let stdout = cmd.stdout_lossy();
+        assert!(stdout.contains("All (1) contracts were verified!"), "{stdout}");
+
+        let verifications = script_verifications(&prj);
+        assert_eq!(verifications[0]["status"], "verified");
+        assert_eq!(verifications[0]["attempts"], 2);
+
+        // verified contracts aren't verified again
+        cmd.spark_fuse().args(["verify-all", "--verifier", "sourcify"]);
+        assert!(cmd.stdout_lossy().contains("were already verified"));
+        assert_eq!(script_verifications(&prj)[0]["attempts"], 2);
+    }
+);
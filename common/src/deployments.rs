@@ -0,0 +1,228 @@
+//! The deployment registry, `<deployments>/<network>/<name>.json`
+//!
+//! `spark script` writes an artifact for every contract it deployed, so other tooling doesn't
+//! have to parse the broadcast logs.
+
+use crate::fs;
+use corebc_core::{
+    abi::Abi,
+    types::{Address, Bytes, TxHash, H256},
+};
+use corebc_ylem::artifacts::StorageLayout;
+use eyre::WrapErr;
+use serde::{Deserialize, Serialize};
+use std::{
+    collections::BTreeMap,
+    ffi::OsStr,
+    path::{Path, PathBuf},
+};
+
+/// The directory in a network's directory the replaced versions of deployments are moved to
+pub const DEPLOYMENTS_HISTORY_DIR: &str = ".history";
+
+/// A named deployment in the registry
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Deployment {
+    /// The name of the deployment, the contract name unless saved with `saveDeployment`
+    pub name: String,
+    /// The address of the deployed contract
+    pub address: Address,
+    /// The version of the deployment, increased every time it is replaced by a deployment with a
+    /// different address
+    #[serde(default = "default_version")]
+    pub version: u64,
+    /// The name of the contract
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub contract_name: Option<String>,
+    /// The ABI of the contract
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub abi: Option<Abi>,
+    /// The hash of the creation bytecode, without constructor arguments
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub bytecode_hash: Option<H256>,
+    /// The ABI encoded constructor arguments
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub constructor_args: Option<Bytes>,
+    /// The hash of the transaction that deployed the contract
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub transaction_hash: Option<TxHash>,
+    /// The block the contract was deployed in
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub block_number: Option<u64>,
+    /// The compiler settings the contract was compiled with
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ylem: Option<YlemSettings>,
+    /// The storage layout of the contract, checked against by upgrades of proxies using it as
+    /// their implementation
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub storage_layout: Option<StorageLayout>,
+}
+
+fn default_version() -> u64 {
+    1
+}
+
+impl Deployment {
+    /// Creates a deployment that only knows its address
+    pub fn new(name: impl Into<String>, address: Address) -> Self {
+        Self {
+            name: name.into(),
+            address,
+            version: default_version(),
+            contract_name: None,
+            abi: None,
+            bytecode_hash: None,
+            constructor_args: None,
+            transaction_hash: None,
+            block_number: None,
+            ylem: None,
+            storage_layout: None,
+        }
+    }
+}
+
+/// The compiler settings of a [Deployment]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct YlemSettings {
+    /// The compiler version
+    pub version: String,
+    /// Whether the optimizer was enabled
+    pub optimizer: bool,
+    /// The number of optimizer runs
+    pub optimizer_runs: usize,
+    /// Whether the IR pipeline was used
+    pub via_ir: bool,
+    /// The target CVM version
+    pub cvm_version: String,
+}
+
+/// The deployment registry of a project, see [Config::deployments](foxar_config::Config)
+#[derive(Debug, Clone)]
+pub struct DeploymentRegistry {
+    root: PathBuf,
+}
+
+impl DeploymentRegistry {
+    /// Creates a registry in the given directory
+    pub fn new(root: impl Into<PathBuf>) -> Self {
+        Self { root: root.into() }
+    }
+
+    /// Returns the path of the deployment's artifact
+    pub fn path(&self, network: &str, name: &str) -> PathBuf {
+        self.root.join(network).join(format!("{name}.json"))
+    }
+
+    /// Returns the deployment, if there is one
+    pub fn get(&self, network: &str, name: &str) -> eyre::Result<Option<Deployment>> {
+        let path = self.path(network, name);
+        if !path.exists() {
+            return Ok(None)
+        }
+        Ok(Some(fs::read_json_file(&path)?))
+    }
+
+    /// Saves the deployment and returns it with its version set.
+    ///
+    /// If there is a deployment with a different address already, it is moved to the history
+    /// directory and the version is increased.
+    pub fn save(&self, network: &str, mut deployment: Deployment) -> eyre::Result<Deployment> {
+        let path = self.path(network, &deployment.name);
+        if let Some(previous) = self.get(network, &deployment.name)? {
+            if previous.address == deployment.address {
+                deployment.version = previous.version;
+            } else {
+                let history = self.root.join(network).join(DEPLOYMENTS_HISTORY_DIR);
+                fs::create_dir_all(&history)?;
+                fs::write(
+                    history.join(format!("{}-v{}.json", previous.name, previous.version)),
+                    serde_json::to_string_pretty(&previous)?,
+                )?;
+                deployment.version = previous.version + 1;
+            }
+        }
+
+        fs::create_dir_all(self.root.join(network))?;
+        fs::write(&path, serde_json::to_string_pretty(&deployment)?)?;
+        Ok(deployment)
+    }
+
+    /// Returns the networks that have deployments, sorted by name
+    pub fn networks(&self) -> eyre::Result<Vec<String>> {
+        if !self.root.exists() {
+            return Ok(vec![])
+        }
+        let mut networks = std::fs::read_dir(&self.root)
+            .wrap_err_with(|| format!("Failed to read {}", self.root.display()))?
+            .filter_map(|entry| entry.ok())
+            .filter(|entry| entry.path().is_dir())
+            .filter_map(|entry| entry.file_name().into_string().ok())
+            .collect::<Vec<_>>();
+        networks.sort();
+        Ok(networks)
+    }
+
+    /// Returns all deployments on the network, sorted by name
+    pub fn list(&self, network: &str) -> eyre::Result<Vec<Deployment>> {
+        let mut deployments = fs::json_files(self.root.join(network))
+            .into_iter()
+            .filter(|path| path.parent().and_then(Path::file_name) == Some(OsStr::new(network)))
+            .map(|path| Ok(fs::read_json_file::<Deployment>(&path)?))
+            .collect::<eyre::Result<Vec<_>>>()?;
+        deployments.sort_by(|a, b| a.name.cmp(&b.name));
+        Ok(deployments)
+    }
+
+    /// Returns the deployments of all networks, `network -> name -> deployment`
+    pub fn export(&self) -> eyre::Result<BTreeMap<String, BTreeMap<String, Deployment>>> {
+        self.networks()?
+            .into_iter()
+            .map(|network| {
+                let deployments = self
+                    .list(&network)?
+                    .into_iter()
+                    .map(|deployment| (deployment.name.clone(), deployment))
+                    .collect();
+                Ok((network, deployments))
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn can_save_versioned_deployments() {
+        let dir = tempfile::tempdir().unwrap();
+        let registry = DeploymentRegistry::new(dir.path());
+
+        let first = Address::repeat_byte(1);
+        let saved = registry.save("mainnet", Deployment::new("Counter", first)).unwrap();
+        assert_eq!(saved.version, 1);
+        let saved = registry.save("mainnet", Deployment::new("Counter", first)).unwrap();
+        assert_eq!(saved.version, 1);
+
+        let second = Address::repeat_byte(2);
+        let saved = registry.save("mainnet", Deployment::new("Counter", second)).unwrap();
+        assert_eq!(saved.version, 2);
+        assert!(dir
+            .path()
+            .join("mainnet")
+            .join(DEPLOYMENTS_HISTORY_DIR)
+            .join("Counter-v1.json")
+            .exists());
+
+        registry.save("devin", Deployment::new("Token", first)).unwrap();
+        assert_eq!(registry.networks().unwrap(), ["devin", "mainnet"]);
+        let deployments = registry.list("mainnet").unwrap();
+        assert_eq!(deployments.len(), 1);
+        assert_eq!(deployments[0].address, second);
+        assert_eq!(registry.get("mainnet", "Counter").unwrap().unwrap(), deployments[0]);
+        assert!(registry.get("mainnet", "Token").unwrap().is_none());
+        assert_eq!(registry.export().unwrap()["devin"]["Token"].address, first);
+    }
+}
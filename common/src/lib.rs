@@ -7,6 +7,7 @@ pub mod clap_helpers;
 pub mod compile;
 pub mod constants;
 pub mod contracts;
+pub mod deployments;
 pub mod errors;
 pub mod evm;
 pub mod fmt;
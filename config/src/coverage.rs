@@ -0,0 +1,74 @@
+//! Configuration for `spark coverage`
+
+use serde::{Deserialize, Deserializer, Serialize};
+
+/// The minimum coverage of the source files matching a glob pattern
+///
+/// The coverage of all matching files is combined, e.g.
+///
+/// ```toml
+/// coverage_thresholds = [{ path = "src/core/**", min_lines = 90, min_branches = 75 }]
+/// ```
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CoverageThreshold {
+    /// The glob pattern of the source files, relative to the project root
+    #[serde(deserialize_with = "deserialize_glob")]
+    pub path: String,
+    /// The minimum percentage of covered lines
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub min_lines: Option<f64>,
+    /// The minimum percentage of covered branches
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub min_branches: Option<f64>,
+}
+
+impl CoverageThreshold {
+    /// Creates a threshold that applies to all source files
+    pub fn all(min_lines: Option<f64>, min_branches: Option<f64>) -> Self {
+        Self { path: "**".to_string(), min_lines, min_branches }
+    }
+
+    /// Returns true if the threshold applies to the source file
+    ///
+    /// Patterns are validated when the config is loaded, an invalid one doesn't match any file.
+    pub fn is_match(&self, path: &str) -> bool {
+        globset::Glob::new(&self.path)
+            .map(|glob| glob.compile_matcher().is_match(path))
+            .unwrap_or_default()
+    }
+}
+
+/// Deserializes a glob pattern, so invalid patterns fail when the config is loaded
+fn deserialize_glob<'de, D>(deserializer: D) -> Result<String, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let pattern = String::deserialize(deserializer)?;
+    globset::Glob::new(&pattern).map_err(serde::de::Error::custom)?;
+    Ok(pattern)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn can_match_paths() {
+        let threshold = CoverageThreshold {
+            path: "src/core/**".to_string(),
+            min_lines: None,
+            min_branches: None,
+        };
+        assert!(threshold.is_match("src/core/Vault.sol"));
+        assert!(threshold.is_match("src/core/lib/Math.sol"));
+        assert!(!threshold.is_match("src/Token.sol"));
+        assert!(CoverageThreshold::all(Some(80.), None).is_match("src/Token.sol"));
+    }
+
+    #[test]
+    fn can_reject_invalid_patterns() {
+        let threshold = serde_json::from_str::<CoverageThreshold>(r#"{ "path": "src/**" }"#);
+        assert_eq!(threshold.unwrap().path, "src/**");
+        assert!(serde_json::from_str::<CoverageThreshold>(r#"{ "path": "src/[core" }"#).is_err());
+    }
+}
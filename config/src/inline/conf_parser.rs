@@ -130,6 +130,21 @@ where
     }
 }
 
+/// Given a list of `config_lines`, returns the value of the last line setting the top-level
+/// property `key`, i.e. a property that is not part of a config key like `fuzz`.
+///
+/// i.e. Given the `timeout` key, the config line `spark-config: default.timeout = 30` would
+/// return `"30"`.
+pub fn get_config_value(config_lines: &[String], key: &str) -> Option<String> {
+    let re = Regex::new(&format!("^{INLINE_CONFIG_PREFIX}:[^.]*\\.{key}=")).unwrap();
+    config_lines
+        .iter()
+        .map(|l| remove_whitespaces(l))
+        .filter(|l| re.is_match(l))
+        .map(|l| re.replace(&l, "").to_string())
+        .last()
+}
+
 /// Checks if all configuration lines specified in `natspec` use a valid profile.
 ///
 /// i.e. Given available profiles
@@ -158,6 +173,12 @@ pub fn parse_config_u32(key: String, value: String) -> Result<u32, InlineConfigP
     value.parse().map_err(|_| InlineConfigParserError::ParseInt(key, value))
 }
 
+/// Tries to parse a `u64` from `value`. The `key` argument is used to give details
+/// in the case of an error.
+pub fn parse_config_u64(key: String, value: String) -> Result<u64, InlineConfigParserError> {
+    value.parse().map_err(|_| InlineConfigParserError::ParseInt(key, value))
+}
+
 /// Tries to parse a `bool` from `value`. The `key` argument is used to give details
 /// in the case of an error.
 pub fn parse_config_bool(key: String, value: String) -> Result<bool, InlineConfigParserError> {
@@ -1,7 +1,7 @@
 mod conf_parser;
 pub use conf_parser::{
-    parse_config_bool, parse_config_u32, validate_profiles, InlineConfigParser,
-    InlineConfigParserError,
+    get_config_value, parse_config_bool, parse_config_u32, parse_config_u64, validate_profiles,
+    InlineConfigParser, InlineConfigParserError,
 };
 use once_cell::sync::Lazy;
 use std::collections::HashMap;
@@ -13,6 +13,9 @@ use crate::Config;
 
 pub const INLINE_CONFIG_FUZZ_KEY: &str = "fuzz";
 pub const INLINE_CONFIG_INVARIANT_KEY: &str = "invariant";
+pub const INLINE_CONFIG_TIMEOUT_KEY: &str = "timeout";
+pub const INLINE_CONFIG_ENERGY_BUDGET_KEY: &str = "energy-budget";
+pub const INLINE_CONFIG_STEP_BUDGET_KEY: &str = "step-budget";
 const INLINE_CONFIG_PREFIX: &str = "spark-config";
 
 static INLINE_CONFIG_PREFIX_SELECTED_PROFILE: Lazy<String> = Lazy::new(|| {
@@ -92,6 +92,12 @@ use providers::remappings::RemappingsProvider;
 mod inline;
 pub use inline::{validate_profiles, InlineConfig, InlineConfigError, InlineConfigParser, NatSpec};
 
+mod limits;
+pub use limits::LimitsConfig;
+
+mod coverage;
+pub use coverage::CoverageThreshold;
+
 /// Foxar configuration
 ///
 /// # Defaults
@@ -155,6 +161,9 @@ pub struct Config {
     pub cache_path: PathBuf,
     /// where the broadcast logs are stored
     pub broadcast: PathBuf,
+    /// where the deployment registry of `spark script` is stored,
+    /// `<deployments>/<network>/<contract name>.json`
+    pub deployments: PathBuf,
     /// additional ylem allow paths for `--allow-paths`
     pub allow_paths: Vec<PathBuf>,
     /// additional ylem include paths for `--include-path`
@@ -204,6 +213,9 @@ pub struct Config {
     /// Multiple etherscan api configs and their aliases
     #[serde(default, skip_serializing_if = "EtherscanConfigs::is_empty")]
     pub etherscan: EtherscanConfigs,
+    /// url of the contract verification API, used instead of the default endpoint of the
+    /// `--verifier` provider, e.g. a local server speaking the same API
+    pub verifier_url: Option<String>,
     /// list of solidity error codes to always silence in the compiler output
     pub ignored_error_codes: Vec<SolidityErrorCode>,
     /// When true, compiler warnings are treated as errors
@@ -230,6 +242,33 @@ pub struct Config {
     pub fuzz: FuzzConfig,
     /// Configuration for invariant testing
     pub invariant: InvariantConfig,
+    /// The maximum time in seconds a fuzz or invariant test may run
+    ///
+    /// Tests that hit the limit are reported as failed. Can be set per test with
+    /// `spark-config: default.timeout = 30`.
+    pub timeout: Option<u32>,
+    /// The maximum energy all calls of a fuzz or invariant test may consume
+    ///
+    /// Tests that exceed the budget are reported as failed. Can be set per test with
+    /// `spark-config: default.energy-budget = 1000000000`.
+    pub energy_budget: Option<u64>,
+    /// The maximum number of calls a fuzz or invariant test may make, i.e. fuzz cases or calls of
+    /// invariant runs
+    ///
+    /// Tests that exceed the budget are reported as failed. Can be set per test with
+    /// `spark-config: default.step-budget = 10000`.
+    pub step_budget: Option<u64>,
+    /// Patterns of quarantined tests, matched against `<contract id>:<test signature>`, e.g.
+    /// `test/Fork.t.sol:ForkTest:testRpc\(\)`
+    ///
+    /// Quarantined tests are run, but their failures don't fail the test run.
+    pub quarantine: Vec<RegexWrapper>,
+    /// The minimum coverage of source files matching a glob pattern, `spark coverage` fails if
+    /// the coverage is below any of them
+    pub coverage_thresholds: Vec<CoverageThreshold>,
+    /// Glob patterns of source files that are left out of the coverage report, e.g. generated
+    /// code or mocks
+    pub no_coverage_paths: Vec<String>,
     /// Whether to allow ffi cheatcodes in test
     pub ffi: bool,
     /// The address which will be executing all tests
@@ -508,6 +547,7 @@ impl Config {
         self.script = p(&root, &self.script);
         self.out = p(&root, &self.out);
         self.broadcast = p(&root, &self.broadcast);
+        self.deployments = p(&root, &self.deployments);
         self.cache_path = p(&root, &self.cache_path);
 
         if let Some(build_info_path) = self.build_info_path {
@@ -1723,6 +1763,7 @@ impl Default for Config {
             cache: true,
             cache_path: "cache".into(),
             broadcast: "broadcast".into(),
+            deployments: "deployments".into(),
             allow_paths: vec![],
             include_paths: vec![],
             force: false,
@@ -1748,6 +1789,12 @@ impl Default for Config {
             path_pattern_inverse: None,
             fuzz: Default::default(),
             invariant: Default::default(),
+            timeout: None,
+            energy_budget: None,
+            step_budget: None,
+            quarantine: vec![],
+            coverage_thresholds: vec![],
+            no_coverage_paths: vec![],
             ffi: false,
             sender: Config::DEFAULT_SENDER,
             tx_origin: Config::DEFAULT_SENDER,
@@ -1766,6 +1813,7 @@ impl Default for Config {
             memory_limit: 2u64.pow(25),
             eth_rpc_url: None,
             etherscan_api_key: None,
+            verifier_url: None,
             verbosity: 0,
             remappings: vec![],
             auto_detect_remappings: true,
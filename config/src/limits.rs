@@ -0,0 +1,139 @@
+//! Configuration for test timeouts and budgets
+
+use std::time::Duration;
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    inline::{
+        get_config_value, parse_config_u32, parse_config_u64, InlineConfigParser,
+        InlineConfigParserError, INLINE_CONFIG_ENERGY_BUDGET_KEY, INLINE_CONFIG_STEP_BUDGET_KEY,
+        INLINE_CONFIG_TIMEOUT_KEY,
+    },
+    InlineConfigError, NatSpec,
+};
+
+/// The time limit and the energy and step budgets of fuzz and invariant tests
+///
+/// Can be set per test with `spark-config: default.timeout = 30`,
+/// `spark-config: default.energy-budget = 1000000000` and `spark-config: default.step-budget =
+/// 10000`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct LimitsConfig {
+    /// The maximum time in seconds a test may run, no limit if `None`
+    pub timeout: Option<u32>,
+    /// The maximum energy all calls of a test may consume, no limit if `None`
+    pub energy_budget: Option<u64>,
+    /// The maximum number of calls a test may make, no limit if `None`
+    pub step_budget: Option<u64>,
+}
+
+impl LimitsConfig {
+    /// Creates a new config with the given time limit in seconds and the given budgets
+    pub fn new(timeout: Option<u32>, energy_budget: Option<u64>, step_budget: Option<u64>) -> Self {
+        Self { timeout, energy_budget, step_budget }
+    }
+
+    /// Returns the time limit, if any
+    pub fn duration(&self) -> Option<Duration> {
+        self.timeout.map(|secs| Duration::from_secs(secs as u64))
+    }
+
+    /// Returns `true` if neither a time limit nor a budget is set
+    pub fn is_unlimited(&self) -> bool {
+        self.timeout.is_none() && self.energy_budget.is_none() && self.step_budget.is_none()
+    }
+}
+
+impl InlineConfigParser for LimitsConfig {
+    /// Returns the key of the time limit, the budgets are set with their own top-level keys.
+    fn config_key() -> String {
+        INLINE_CONFIG_TIMEOUT_KEY.into()
+    }
+
+    fn try_merge(&self, configs: &[String]) -> Result<Option<Self>, InlineConfigParserError> {
+        let timeout = get_config_value(configs, INLINE_CONFIG_TIMEOUT_KEY);
+        let energy_budget = get_config_value(configs, INLINE_CONFIG_ENERGY_BUDGET_KEY);
+        let step_budget = get_config_value(configs, INLINE_CONFIG_STEP_BUDGET_KEY);
+        if timeout.is_none() && energy_budget.is_none() && step_budget.is_none() {
+            return Ok(None)
+        }
+
+        let mut conf_clone = *self;
+        if let Some(value) = timeout {
+            conf_clone.timeout = Some(parse_config_u32(INLINE_CONFIG_TIMEOUT_KEY.into(), value)?);
+        }
+        if let Some(value) = energy_budget {
+            conf_clone.energy_budget =
+                Some(parse_config_u64(INLINE_CONFIG_ENERGY_BUDGET_KEY.into(), value)?);
+        }
+        if let Some(value) = step_budget {
+            conf_clone.step_budget =
+                Some(parse_config_u64(INLINE_CONFIG_STEP_BUDGET_KEY.into(), value)?);
+        }
+        Ok(Some(conf_clone))
+    }
+
+    /// Validates the time limit and the budgets, which don't share a common config key.
+    fn validate_configs(natspec: &NatSpec) -> Result<(), InlineConfigError> {
+        Self::default().try_merge(&natspec.config_lines()).map_err(|e| {
+            let line = natspec.debug_context();
+            InlineConfigError { line, source: e }
+        })?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{inline::InlineConfigParser, LimitsConfig};
+
+    #[test]
+    fn successful_merge() {
+        let configs = &[
+            "spark-config: default.fuzz.runs = 2".to_string(),
+            "spark-config: default.timeout = 30".to_string(),
+        ];
+        let merged = LimitsConfig::default().try_merge(configs).expect("No errors").unwrap();
+        assert_eq!(merged.timeout, Some(30));
+    }
+
+    #[test]
+    fn successful_merge_budgets() {
+        let configs = &[
+            "spark-config: default.energy-budget = 1000000000".to_string(),
+            "spark-config: default.step-budget = 10000".to_string(),
+        ];
+        let merged =
+            LimitsConfig::new(Some(30), None, None).try_merge(configs).expect("No errors").unwrap();
+        assert_eq!(merged, LimitsConfig::new(Some(30), Some(1000000000), Some(10000)));
+    }
+
+    #[test]
+    fn merge_is_none() {
+        let configs = &["spark-config: default.invariant.timeout = 30".to_string()];
+        let merged = LimitsConfig::new(Some(10), None, None).try_merge(configs).expect("No errors");
+        assert!(merged.is_none());
+    }
+
+    #[test]
+    fn invalid_value() {
+        let configs = &["spark-config: default.timeout = 1s".to_string()];
+        let err = LimitsConfig::default().try_merge(configs).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid config value for key 'timeout'. Unable to parse '1s' into an integer value"
+        );
+    }
+
+    #[test]
+    fn invalid_budget() {
+        let configs = &["spark-config: default.step-budget = -1".to_string()];
+        let err = LimitsConfig::default().try_merge(configs).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid config value for key 'step-budget'. Unable to parse '-1' into an integer value"
+        );
+    }
+}
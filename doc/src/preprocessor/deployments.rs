@@ -2,13 +2,15 @@ use corebc_core::types::Address;
 
 use super::{Preprocessor, PreprocessorId};
 use crate::{Document, PreprocessorOutput};
-use std::{fs, path::PathBuf};
+use foxar_common::deployments::DeploymentRegistry;
+use std::path::PathBuf;
 
 /// [Deployments] preprocessor id.
 pub const DEPLOYMENTS_ID: PreprocessorId = PreprocessorId("deployments");
 
 /// The deployments preprocessor.
 ///
+/// Reads the deployment registry written by `spark script`, see [DeploymentRegistry].
 /// This preprocessor writes to [Document]'s context.
 #[derive(Debug)]
 pub struct Deployments {
@@ -33,47 +35,34 @@ impl Preprocessor for Deployments {
     }
 
     fn preprocess(&self, documents: Vec<Document>) -> Result<Vec<Document>, eyre::Error> {
-        let deployments_dir =
-            self.root.join(self.deployments.as_ref().unwrap_or(&PathBuf::from("deployments")));
+        let registry = DeploymentRegistry::new(
+            self.root.join(self.deployments.as_ref().unwrap_or(&PathBuf::from("deployments"))),
+        );
 
         // Gather all networks from the deployments directory.
-        let networks = fs::read_dir(&deployments_dir)?
-            .map(|x| {
-                x.map(|y| {
-                    if y.file_type()?.is_dir() {
-                        Ok(y.file_name().into_string().map_err(|e| {
-                            eyre::eyre!("Failed to extract directory name: {:?}", e)
-                        })?)
-                    } else {
-                        eyre::bail!("Not a directory.")
-                    }
-                })?
-            })
-            .collect::<Result<Vec<_>, _>>()?;
+        let networks = registry.networks()?;
 
         // Iterate over all documents to find any deployments.
         for document in documents.iter() {
             let mut deployments = Vec::default();
 
+            // The deployments are named after the contract, which is the file name of the item
+            let name = document
+                .item_path
+                .file_stem()
+                .and_then(|name| name.to_str())
+                .ok_or(eyre::eyre!("Failed to extract file name from item path"))?;
+
             // Iterate over all networks and check if there is a deployment for the given contract.
             for network in &networks {
-                // Clone the item path of the document and change it from ".sol" -> ".json"
-                let mut item_path_clone = document.item_path.clone();
-                item_path_clone.set_extension("json");
-
-                // Determine the path of the deployment artifact relative to the root directory.
-                let deployment_path = deployments_dir.join(network).join(
-                    item_path_clone
-                        .file_name()
-                        .ok_or(eyre::eyre!("Failed to extract file name from item path"))?,
-                );
-
-                // If the deployment file for the given contract is found, add the deployment
-                // address to the document context.
-                let mut deployment: Deployment =
-                    serde_json::from_str(&fs::read_to_string(deployment_path)?)?;
-                deployment.network = Some(network.clone());
-                deployments.push(deployment);
+                // If the deployment for the given contract is found, add the deployment address to
+                // the document context.
+                if let Some(deployment) = registry.get(network, name)? {
+                    deployments.push(Deployment {
+                        address: deployment.address,
+                        network: Some(network.clone()),
+                    });
+                }
             }
 
             // If there are any deployments for the given contract, add them to the document
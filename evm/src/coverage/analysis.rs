@@ -52,10 +52,20 @@ impl<'a> ContractVisitor<'a> {
         // We skip the first ID because that's the ID of the contract itself
         self.base_contract_node_ids.extend(&linearized_base_contracts[1..]);
 
-        // Find all functions and walk their AST
+        // Find all functions and walk their AST, and the libraries attached with `using for`,
+        // since their internal functions are part of this contract's bytecode
         for node in contract_ast.nodes {
-            if node.node_type == NodeType::FunctionDefinition {
-                self.visit_function_definition(node.clone())?;
+            match node.node_type {
+                NodeType::FunctionDefinition => self.visit_function_definition(node.clone())?,
+                NodeType::UsingForDirective => {
+                    let library_id = node
+                        .attribute::<Node>("libraryName")
+                        .and_then(|library| library.attribute("referencedDeclaration"));
+                    if let Some(id) = library_id {
+                        self.base_contract_node_ids.insert(id);
+                    }
+                }
+                _ => (),
             }
         }
 
@@ -419,6 +429,37 @@ pub struct SourceAnalysis {
     pub contract_items: HashMap<ContractId, Vec<usize>>,
 }
 
+impl SourceAnalysis {
+    /// Retains only the coverage items for which `f` returns true.
+    ///
+    /// The item IDs in [contract_items](Self::contract_items) are updated accordingly.
+    pub fn retain_items(&mut self, mut f: impl FnMut(&CoverageItem) -> bool) {
+        let mut next_id = 0;
+        let new_ids: Vec<Option<usize>> = self
+            .items
+            .iter()
+            .map(|item| {
+                f(item).then(|| {
+                    next_id += 1;
+                    next_id - 1
+                })
+            })
+            .collect();
+
+        let mut ids = new_ids.iter();
+        self.items.retain(|_| ids.next().map_or(false, Option::is_some));
+        for item_ids in self.contract_items.values_mut() {
+            item_ids.retain_mut(|id| match new_ids[*id] {
+                Some(new_id) => {
+                    *id = new_id;
+                    true
+                }
+                None => false,
+            });
+        }
+    }
+}
+
 /// Analyzes a set of sources to find coverage items.
 #[derive(Default, Clone, Debug)]
 pub struct SourceAnalyzer {
@@ -4,6 +4,7 @@ pub mod anchors;
 use bytes::Bytes;
 use corebc::types::H256;
 use semver::Version;
+use serde::{Deserialize, Serialize};
 use std::{
     collections::{BTreeMap, HashMap},
     fmt::Display,
@@ -111,6 +112,32 @@ impl CoverageReport {
             }
         }
     }
+
+    /// Returns the coverage items hit in the given [HitMap], together with the path of their
+    /// source file.
+    ///
+    /// Unlike [add_hit_map](Self::add_hit_map), this does not change the hit counts of the report.
+    pub fn items_hit(
+        &self,
+        contract_id: &ContractId,
+        hit_map: &HitMap,
+    ) -> Vec<(&String, &CoverageItem)> {
+        let (Some(anchors), Some(items)) =
+            (self.anchors.get(contract_id), self.items.get(&contract_id.version))
+        else {
+            return Vec::new()
+        };
+        anchors
+            .iter()
+            .filter(|anchor| hit_map.hits.contains_key(&anchor.instruction))
+            .filter_map(|anchor| {
+                let item = items.get(anchor.item_id)?;
+                let path =
+                    self.source_paths.get(&(contract_id.version.clone(), item.loc.source_id))?;
+                Some((path, item))
+            })
+            .collect()
+    }
 }
 
 /// A collection of [HitMap]s
@@ -281,7 +308,7 @@ impl Display for SourceLocation {
 }
 
 /// Coverage summary for a source file.
-#[derive(Default, Debug, Clone)]
+#[derive(Default, Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct CoverageSummary {
     /// The number of executable lines in the source file.
     pub line_count: usize,
@@ -0,0 +1,296 @@
+use super::{bail, Result};
+use crate::abi::HEVM_ABI;
+use corebc::{
+    abi::{Function, Token},
+    types::{Bytes, Selector, I256, U256, U512},
+};
+use foxar_common::abi::format_token_raw;
+use once_cell::sync::Lazy;
+use std::{cmp::Ordering, collections::HashMap};
+
+/// `1e18`, the value of `100%` for `assertApproxEqRel`
+const WAD: U256 = U256([1_000_000_000_000_000_000, 0, 0, 0]);
+
+/// All the assertion cheatcodes of the HEVM ABI, keyed by selector.
+///
+/// Assertions are overloaded for every primitive type and arrays of them, so instead of matching
+/// every single `HEVMCalls` variant, the call is decoded with the matching [`Function`], and the
+/// decoded [`Token`]s are compared directly.
+static ASSERTIONS: Lazy<HashMap<Selector, &'static Function>> = Lazy::new(|| {
+    HEVM_ABI
+        .functions()
+        .filter(|func| func.name.starts_with("assert"))
+        .map(|func| (func.short_signature(), func))
+        .collect()
+});
+
+/// Number of arguments of an assertion, excluding the optional custom error message.
+fn arity(name: &str) -> usize {
+    match name {
+        "assertTrue" | "assertFalse" => 1,
+        "assertApproxEqAbs" | "assertApproxEqRel" => 3,
+        _ => 2,
+    }
+}
+
+/// Formats the failure message, prefixed by the custom error message if any.
+fn failure(msg: Option<&str>, reason: impl std::fmt::Display) -> String {
+    format!("{}: {reason}", msg.unwrap_or("assertion failed"))
+}
+
+/// Formats a `1e18 = 100%` value as a percentage, e.g. `1.0000000000000000%`.
+fn format_percent(value: U256) -> String {
+    let unit = U256::exp10(16);
+    format!("{}.{:0>16}%", value / unit, (value % unit).to_string())
+}
+
+/// Returns the absolute difference between two values.
+///
+/// Returns `None` if the tokens are not two numbers of the same signedness.
+fn delta(left: &Token, right: &Token) -> Option<U256> {
+    match (left, right) {
+        (Token::Uint(left), Token::Uint(right)) => Some(left.max(right) - left.min(right)),
+        (Token::Int(left), Token::Int(right)) => {
+            let (left_sign, left) = I256::from_raw(*left).into_sign_and_abs();
+            let (right_sign, right) = I256::from_raw(*right).into_sign_and_abs();
+            if left_sign == right_sign {
+                Some(left.max(right) - left.min(right))
+            } else {
+                Some(left.saturating_add(right))
+            }
+        }
+        _ => None,
+    }
+}
+
+/// Compares two numeric tokens, taking the signedness into account.
+fn compare(left: &Token, right: &Token) -> Option<Ordering> {
+    match (left, right) {
+        (Token::Uint(left), Token::Uint(right)) => Some(left.cmp(right)),
+        (Token::Int(left), Token::Int(right)) => {
+            Some(I256::from_raw(*left).cmp(&I256::from_raw(*right)))
+        }
+        _ => None,
+    }
+}
+
+/// Absolute value of a numeric token.
+fn abs(token: &Token) -> Option<U256> {
+    match token {
+        Token::Uint(value) => Some(*value),
+        Token::Int(value) => Some(I256::from_raw(*value).into_sign_and_abs().1),
+        _ => None,
+    }
+}
+
+/// Evaluates the assertion `name` for the given decoded arguments.
+fn assert(name: &str, mut args: Vec<Token>) -> Result {
+    let msg = if args.len() > arity(name) {
+        match args.pop() {
+            Some(Token::String(msg)) => Some(msg),
+            _ => bail!("Invalid custom error message for `{name}`"),
+        }
+    } else {
+        None
+    };
+    let msg = msg.as_deref();
+
+    let ok = match (name, args.as_slice()) {
+        ("assertTrue", [Token::Bool(cond)]) => {
+            if !cond {
+                bail!("{}", msg.unwrap_or("assertion failed"))
+            }
+            return Ok(Bytes::new());
+        }
+        ("assertFalse", [Token::Bool(cond)]) => {
+            if *cond {
+                bail!("{}", msg.unwrap_or("assertion failed"))
+            }
+            return Ok(Bytes::new());
+        }
+        ("assertEq", [left, right]) => left == right,
+        ("assertNotEq", [left, right]) => left != right,
+        ("assertLt" | "assertGt" | "assertLe" | "assertGe", [left, right]) => {
+            let Some(ordering) = compare(left, right) else {
+                bail!("Unsupported arguments for `{name}`")
+            };
+            match name {
+                "assertLt" => ordering.is_lt(),
+                "assertGt" => ordering.is_gt(),
+                "assertLe" => ordering.is_le(),
+                _ => ordering.is_ge(),
+            }
+        }
+        ("assertApproxEqAbs", [left, right, Token::Uint(max_delta)]) => {
+            let Some(delta) = delta(left, right) else {
+                bail!("Unsupported arguments for `{name}`")
+            };
+            if delta > *max_delta {
+                bail!(
+                    "{}",
+                    failure(
+                        msg,
+                        format!(
+                            "{} !~= {} (max delta: {max_delta}, real delta: {delta})",
+                            format_token_raw(left),
+                            format_token_raw(right)
+                        )
+                    )
+                )
+            }
+            return Ok(Bytes::new());
+        }
+        ("assertApproxEqRel", [left, right, Token::Uint(max_percent_delta)]) => {
+            let (Some(delta), Some(expected)) = (delta(left, right), abs(right)) else {
+                bail!("Unsupported arguments for `{name}`")
+            };
+            if delta.is_zero() {
+                return Ok(Bytes::new());
+            }
+            let percent_delta = if expected.is_zero() {
+                None
+            } else {
+                let percent_delta = delta.full_mul(WAD) / U512::from(expected);
+                Some(U256::try_from(percent_delta).unwrap_or(U256::MAX))
+            };
+            if percent_delta.map_or(true, |percent_delta| percent_delta > *max_percent_delta) {
+                bail!(
+                    "{}",
+                    failure(
+                        msg,
+                        format!(
+                            "{} !~= {} (max delta: {}, real delta: {})",
+                            format_token_raw(left),
+                            format_token_raw(right),
+                            format_percent(*max_percent_delta),
+                            percent_delta.map_or_else(|| "undefined".to_string(), format_percent)
+                        )
+                    )
+                )
+            }
+            return Ok(Bytes::new());
+        }
+        _ => bail!("Unsupported arguments for `{name}`"),
+    };
+
+    if !ok {
+        let op = match name {
+            "assertEq" => "!=",
+            "assertNotEq" => "==",
+            "assertLt" => ">=",
+            "assertGt" => "<=",
+            "assertLe" => ">",
+            _ => "<",
+        };
+        bail!(
+            "{}",
+            failure(
+                msg,
+                format!("{} {op} {}", format_token_raw(&args[0]), format_token_raw(&args[1]))
+            )
+        )
+    }
+    Ok(Bytes::new())
+}
+
+/// Handles the assertion cheatcodes.
+///
+/// Returns `None` if the call is not an assertion.
+#[instrument(level = "error", name = "assert", target = "evm::cheatcodes", skip_all)]
+pub fn apply(input: &[u8]) -> Option<Result> {
+    let selector: Selector = input.get(..4)?.try_into().ok()?;
+    let func = ASSERTIONS.get(&selector)?;
+    Some(
+        func.decode_input(&input[4..])
+            .map_err(Into::into)
+            .and_then(|args| assert(&func.name, args)),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use corebc::types::Address;
+
+    fn int(value: i64) -> Token {
+        Token::Int(I256::from(value).into_raw())
+    }
+
+    fn err(res: Result) -> String {
+        res.unwrap_err().to_string()
+    }
+
+    #[test]
+    fn test_assert_eq() {
+        assert!(assert("assertEq", vec![Token::Uint(1.into()), Token::Uint(1.into())]).is_ok());
+        assert_eq!(
+            err(assert("assertEq", vec![Token::Uint(1.into()), Token::Uint(2.into())])),
+            "assertion failed: 1 != 2"
+        );
+        assert_eq!(
+            err(assert("assertEq", vec![int(-1), int(1), Token::String("custom".to_string())])),
+            "custom: -1 != 1"
+        );
+        assert_eq!(
+            err(assert(
+                "assertEq",
+                vec![Token::Array(vec![Token::Bool(true)]), Token::Array(vec![Token::Bool(false)])]
+            )),
+            "assertion failed: [true] != [false]"
+        );
+        assert!(assert(
+            "assertNotEq",
+            vec![Token::Address(Address::zero()), Token::Address(Address::repeat_byte(1))]
+        )
+        .is_ok());
+    }
+
+    #[test]
+    fn test_assert_ordering() {
+        assert!(assert("assertLt", vec![int(-2), int(-1)]).is_ok());
+        assert!(assert("assertGt", vec![Token::Uint(2.into()), Token::Uint(1.into())]).is_ok());
+        assert!(assert("assertGe", vec![int(1), int(1)]).is_ok());
+        assert_eq!(err(assert("assertLe", vec![int(1), int(-1)])), "assertion failed: 1 > -1");
+    }
+
+    #[test]
+    fn test_assert_true() {
+        assert!(assert("assertTrue", vec![Token::Bool(true)]).is_ok());
+        assert_eq!(
+            err(assert("assertTrue", vec![Token::Bool(false), Token::String("nope".to_string())])),
+            "nope"
+        );
+    }
+
+    #[test]
+    fn test_assert_approx_eq() {
+        assert!(assert("assertApproxEqAbs", vec![int(-2), int(2), Token::Uint(4.into())]).is_ok());
+        assert_eq!(
+            err(assert(
+                "assertApproxEqAbs",
+                vec![Token::Uint(1.into()), Token::Uint(10.into()), Token::Uint(5.into())]
+            )),
+            "assertion failed: 1 !~= 10 (max delta: 5, real delta: 9)"
+        );
+
+        // 1% of 100 is 1
+        let one_percent = Token::Uint(U256::exp10(16));
+        assert!(assert(
+            "assertApproxEqRel",
+            vec![Token::Uint(101.into()), Token::Uint(100.into()), one_percent.clone()]
+        )
+        .is_ok());
+        assert_eq!(
+            err(assert(
+                "assertApproxEqRel",
+                vec![Token::Uint(102.into()), Token::Uint(100.into()), one_percent.clone()]
+            )),
+            "assertion failed: 102 !~= 100 (max delta: 1.0000000000000000%, real delta: 2.0000000000000000%)"
+        );
+        assert!(assert(
+            "assertApproxEqRel",
+            vec![Token::Uint(1.into()), Token::Uint(0.into()), one_percent]
+        )
+        .is_err());
+    }
+}
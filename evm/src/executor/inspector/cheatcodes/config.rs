@@ -24,6 +24,8 @@ pub struct CheatsConfig {
     pub fs_permissions: FsPermissions,
     /// Project root
     pub root: PathBuf,
+    /// The deployment registry directory
+    pub deployments: PathBuf,
     /// Paths (directories) where file reading/writing is allowed
     pub allowed_paths: Vec<PathBuf>,
     /// How the evm was configured by the user
@@ -49,6 +51,7 @@ impl CheatsConfig {
             paths: config.project_paths(),
             fs_permissions: config.fs_permissions.clone().joined(&config.__root),
             root: config.__root.0.clone(),
+            deployments: config.__root.0.join(&config.deployments),
             allowed_paths,
             evm_opts: evm_opts.clone(),
         }
@@ -152,6 +155,7 @@ impl Default for CheatsConfig {
             paths: ProjectPathsConfig::builder().build_with_root("./"),
             fs_permissions: Default::default(),
             root: Default::default(),
+            deployments: Default::default(),
             allowed_paths: vec![],
             evm_opts: Default::default(),
         }
@@ -0,0 +1,65 @@
+use super::{ensure, fmt_err, Cheatcodes, Result};
+use crate::{abi::HEVMCalls, executor::backend::DatabaseExt};
+use corebc::{
+    abi::{self, Token},
+    types::{Address, Network},
+};
+use foxar_common::deployments::DeploymentRegistry;
+use foxar_config::fs_permissions::FsAccessKind;
+use revm::EVMData;
+
+/// Names end up as file names in the registry, so they can't contain path separators.
+fn ensure_valid_name(name: &str) -> Result<()> {
+    ensure!(
+        !name.is_empty() && !name.contains(['/', '\\']) && name != "." && name != "..",
+        "Invalid deployment name `{name}`"
+    );
+    Ok(())
+}
+
+fn save_deployment(state: &mut Cheatcodes, name: &str, address: Address) -> Result {
+    ensure_valid_name(name)?;
+    state.deployments.insert(name.to_string(), address);
+    Ok(Default::default())
+}
+
+/// Deployments saved during this run take precedence over the registry on the current network,
+/// which is subject to the `fs_permissions`.
+fn get_deployment(state: &Cheatcodes, name: &str, network: &str, current_network: &str) -> Result {
+    ensure_valid_name(name)?;
+    ensure_valid_name(network)?;
+
+    let saved = state.deployments.get(name).filter(|_| network == current_network);
+    let address = match saved {
+        Some(address) => *address,
+        None => {
+            let registry = DeploymentRegistry::new(&state.config.deployments);
+            state.config.ensure_path_allowed(registry.path(network, name), FsAccessKind::Read)?;
+            registry
+                .get(network, name)
+                .map_err(|err| fmt_err!("Failed to read deployment `{name}`: {err}"))?
+                .ok_or_else(|| fmt_err!("No deployment named `{name}` on network `{network}`"))?
+                .address
+        }
+    };
+    Ok(abi::encode(&[Token::Address(address)]).into())
+}
+
+#[instrument(level = "error", name = "deployments", target = "evm::cheatcodes", skip_all)]
+pub fn apply<DB: DatabaseExt>(
+    state: &mut Cheatcodes,
+    data: &mut EVMData<'_, DB>,
+    call: &HEVMCalls,
+) -> Option<Result> {
+    let network = || Network::from(data.env.cfg.network_id).to_string();
+    let res = match call {
+        HEVMCalls::SaveDeployment(inner) => save_deployment(state, &inner.0, inner.1),
+        HEVMCalls::GetDeployment0(inner) => {
+            let network = network();
+            get_deployment(state, &inner.0, &network, &network)
+        }
+        HEVMCalls::GetDeployment1(inner) => get_deployment(state, &inner.0, &inner.1, &network()),
+        _ => return None,
+    };
+    Some(res)
+}
@@ -12,7 +12,7 @@ use crate::{
 };
 use corebc::{
     abi::{self, AbiEncode, RawLog, Token, Tokenizable, Tokenize},
-    signers::{LocalWallet, Signer},
+    signers::Signer,
     types::{Address, Bytes, U256},
 };
 use foxar_config::Config;
@@ -126,7 +126,7 @@ fn broadcast(
     Ok(Bytes::new())
 }
 
-/// Sets up broadcasting from a script with the sender derived from `private_key`
+/// Sets up broadcasting from a script with the sender derived from the ED448 `private_key`
 /// Adds this private key to `state`'s `script_wallets` vector to later be used for signing
 /// iff broadcast is successful
 fn broadcast_key(
@@ -138,9 +138,7 @@ fn broadcast_key(
     depth: u64,
     single_call: bool,
 ) -> Result {
-    //TODO:error2215 implement Ed448 keys and ICAN Addresses?
-    let key = super::util::parse_private_key_from_str(private_key)?;
-    let wallet = LocalWallet::from(key).with_network_id(network_id.as_u64());
+    let wallet = super::util::parse_wallet_from_str(private_key, network_id)?;
     let new_origin = wallet.address();
 
     let result = broadcast(state, new_origin, original_caller, original_origin, depth, single_call);
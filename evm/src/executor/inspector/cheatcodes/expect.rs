@@ -24,33 +24,115 @@ static DUMMY_CALL_OUTPUT: [u8; 512] = [0u8; 512];
 static DUMMY_CREATE_ADDRESS: Address =
     H176([0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1]);
 
-#[derive(Clone, Debug, Default)]
+#[derive(Clone, Debug)]
 pub struct ExpectedRevert {
     /// The expected data returned by the revert, None being any
     pub reason: Option<Bytes>,
     /// The depth at which the revert is expected
     pub depth: u64,
+    /// Whether only the custom error selector of the revert data is matched
+    pub partial_match: bool,
+    /// If present, the address of the contract expected to revert
+    pub reverter: Option<Address>,
+    /// The innermost contract that reverted in a call nested in the expected call, with its revert
+    /// data. Cleared when a nested call succeeds, as any revert in it was caught.
+    pub reverted_by: Option<(Address, Bytes)>,
+    /// The number of reverts expected from the following calls
+    pub count: u64,
+    /// The number of reverts matched so far
+    pub actual_count: u64,
+}
+
+impl Default for ExpectedRevert {
+    fn default() -> Self {
+        Self {
+            reason: None,
+            depth: 0,
+            partial_match: false,
+            reverter: None,
+            reverted_by: None,
+            count: 1,
+            actual_count: 0,
+        }
+    }
 }
 
-fn expect_revert(state: &mut Cheatcodes, reason: Option<Bytes>, depth: u64) -> Result {
+impl ExpectedRevert {
+    /// Records the end of a call or create nested in the expected call, to find the contract the
+    /// revert of the expected call originates from.
+    pub fn record_nested_end(&mut self, address: Address, reverted: bool, retdata: &Bytes) {
+        if !reverted {
+            self.reverted_by = None;
+        } else if !matches!(&self.reverted_by, Some((_, data)) if data == retdata) {
+            // The revert isn't bubbled up from a deeper call
+            self.reverted_by = Some((address, retdata.clone()));
+        }
+    }
+
+    /// Returns the contract the revert data of the expected call originates from: the nested
+    /// contract it was bubbled up from, if any, or else the called contract.
+    pub fn take_reverter(&mut self, address: Option<Address>, retdata: &Bytes) -> Option<Address> {
+        match self.reverted_by.take() {
+            Some((reverter, data)) if data == *retdata => Some(reverter),
+            _ => address,
+        }
+    }
+
+    /// Returns the error of a test which ended before all the expected reverts occurred, if more
+    /// than one revert was expected.
+    pub fn unmet_count_error(&self) -> Option<String> {
+        (self.count > 1 && self.actual_count < self.count).then(|| {
+            format!("Expected {} reverts, but only {} occurred", self.count, self.actual_count)
+        })
+    }
+
+    /// Records a matched revert at the given depth.
+    ///
+    /// Returns `true` if more reverts are expected from the following calls.
+    pub fn record_match(&mut self, depth: u64) -> bool {
+        self.actual_count += 1;
+        depth > 0 && self.actual_count < self.count
+    }
+}
+
+fn expect_revert(state: &mut Cheatcodes, expected_revert: ExpectedRevert) -> Result {
     ensure!(
         state.expected_revert.is_none(),
         "You must call another function prior to expecting a second revert."
     );
-    state.expected_revert = Some(ExpectedRevert { reason, depth });
+    ensure!(expected_revert.count > 0, "The number of expected reverts must be greater than 0");
+    state.expected_revert = Some(expected_revert);
     Ok(Bytes::new())
 }
 
 #[instrument(skip_all, fields(expected_revert, status, retdata = hex::encode(&retdata)))]
 pub fn handle_expect_revert(
     is_create: bool,
-    expected_revert: Option<&Bytes>,
+    expected_revert: &ExpectedRevert,
+    reverter: Option<Address>,
     status: InstructionResult,
     retdata: Bytes,
 ) -> Result<(Option<Address>, Bytes)> {
     trace!("handle expect revert");
 
-    ensure!(!matches!(status, return_ok!()), "Call did not revert as expected");
+    if matches!(status, return_ok!()) {
+        if expected_revert.actual_count > 0 {
+            bail!(
+                "Expected {} reverts, but only {} occurred",
+                expected_revert.count,
+                expected_revert.actual_count
+            );
+        }
+        bail!("Call did not revert as expected");
+    }
+
+    if let Some(expected_reverter) = expected_revert.reverter {
+        ensure!(
+            reverter == Some(expected_reverter),
+            "Reverter != expected reverter: {} != {expected_reverter:?}",
+            reverter.map_or_else(|| "unknown".to_string(), |reverter| format!("{reverter:?}"))
+        );
+    }
 
     macro_rules! success_return {
         () => {
@@ -64,15 +146,27 @@ pub fn handle_expect_revert(
     }
 
     // If None, accept any revert
-    let expected_revert = match expected_revert {
+    let expected_reason = match &expected_revert.reason {
         Some(x) => x,
         None => return success_return!(),
     };
 
-    if !expected_revert.is_empty() && retdata.is_empty() {
+    if !expected_reason.is_empty() && retdata.is_empty() {
         bail!("Call reverted as expected, but without data");
     }
 
+    // Only match the custom error selector, ignoring its parameters
+    if expected_revert.partial_match {
+        if retdata.starts_with(expected_reason) {
+            return success_return!()
+        }
+        bail!(
+            "Error selector != expected selector: 0x{} != 0x{}",
+            hex::encode(&retdata[..retdata.len().min(4)]),
+            hex::encode(expected_reason),
+        );
+    }
+
     let mut actual_revert = retdata;
     if actual_revert.len() >= 4 &&
         matches!(actual_revert[..4].try_into(), Ok(ERROR_PREFIX | REVERT_PREFIX))
@@ -82,7 +176,7 @@ pub fn handle_expect_revert(
         }
     }
 
-    if actual_revert == *expected_revert {
+    if actual_revert == *expected_reason {
         success_return!()
     } else {
         let stringify = |data: &[u8]| {
@@ -94,7 +188,7 @@ pub fn handle_expect_revert(
         Err(fmt_err!(
             "Error != expected error: {} != {}",
             stringify(&actual_revert),
-            stringify(expected_revert),
+            stringify(expected_reason),
         ))
     }
 }
@@ -107,16 +201,80 @@ pub struct ExpectedEmit {
     pub log: Option<RawLog>,
     /// The checks to perform:
     ///
-    /// ┌───────┬───────┬───────┬────┐
-    /// │topic 1│topic 2│topic 3│data│
-    /// └───────┴───────┴───────┴────┘
-    pub checks: [bool; 4],
+    /// ┌───────┬───────┬───────┬───────┬────┐
+    /// │topic 0│topic 1│topic 2│topic 3│data│
+    /// └───────┴───────┴───────┴───────┴────┘
+    ///
+    /// The event signature in topic 0 is always checked, unless the event is anonymous.
+    pub checks: [bool; 5],
     /// If present, check originating address against this
     pub address: Option<Address>,
+    /// Whether the log is expected to be emitted by an anonymous event
+    pub anonymous: bool,
+    /// If present, the exact number of times the log is expected to be emitted
+    pub count: Option<u64>,
+    /// The number of times the log was emitted so far
+    pub actual_count: u64,
     /// Whether the log was actually found in the subcalls
     pub found: bool,
 }
 
+impl ExpectedEmit {
+    /// Returns `true` if the given log emitted by `address` matches this expected emit.
+    pub fn matches(&self, log: &RawLog, address: &Address) -> bool {
+        let Some(expected) = &self.log else { return false };
+
+        // non anonymous events need the same signature, and all events need the same number of
+        // topics to be verified further
+        if (!self.anonymous && log.topics.is_empty()) || expected.topics.len() != log.topics.len() {
+            return false
+        }
+
+        // Match topics
+        let topics_match = log
+            .topics
+            .iter()
+            .zip(&expected.topics)
+            .enumerate()
+            .filter(|(i, _)| self.checks[*i])
+            .all(|(_, (topic, expected))| topic == expected);
+
+        // Maybe match source address
+        let address_matches = self.address.map_or(true, |addr| addr == *address);
+
+        // Maybe match data
+        let data_matches = !self.checks[4] || expected.data == log.data;
+
+        topics_match && address_matches && data_matches
+    }
+}
+
+/// Expects the next emitted event, `checks` being the topics and data checks of
+/// [`ExpectedEmit::checks`] excluding the event signature for non anonymous events.
+fn expect_emit(
+    state: &mut Cheatcodes,
+    depth: u64,
+    checks: &[bool],
+    address: Option<Address>,
+    anonymous: bool,
+    count: Option<u64>,
+) -> Result {
+    let checks = if anonymous {
+        [checks[0], checks[1], checks[2], checks[3], checks[4]]
+    } else {
+        [true, checks[0], checks[1], checks[2], checks[3]]
+    };
+    state.expected_emits.push_back(ExpectedEmit {
+        depth,
+        checks,
+        address,
+        anonymous,
+        count,
+        ..Default::default()
+    });
+    Ok(Bytes::new())
+}
+
 pub fn handle_expect_emit(state: &mut Cheatcodes, log: RawLog, address: &Address) {
     // Fill or check the expected emits.
     // We expect for emit checks to be filled as they're declared (from oldest to newest),
@@ -125,6 +283,14 @@ pub fn handle_expect_emit(state: &mut Cheatcodes, log: RawLog, address: &Address
     // If not, the queue will not be in the order the events will be intended to be filled,
     // and we'll be able to later detect this and bail.
 
+    // Events expected an exact number of times keep being counted after they were found, so
+    // that emitting them too often can be detected at the end of the call.
+    for expected in state.expected_emits.iter_mut().filter(|expected| expected.found) {
+        if expected.count.is_some() && expected.matches(&log, address) {
+            expected.actual_count += 1;
+        }
+    }
+
     // First, we can return early if all events have been matched.
     // This allows a contract to arbitrarily emit more events than expected (additive behavior),
     // as long as all the previous events were matched in the order they were expected to be.
@@ -146,34 +312,11 @@ pub fn handle_expect_emit(state: &mut Cheatcodes, log: RawLog, address: &Address
         event_to_fill_or_check.expect("We should have an emit to fill or check. This is a bug");
 
     match event_to_fill_or_check.log {
-        Some(ref expected) => {
-            let expected_topic_0 = expected.topics.first();
-            let log_topic_0 = log.topics.first();
-
-            // same topic0 and equal number of topics should be verified further, others are a no
-            // match
-            if expected_topic_0
-                .zip(log_topic_0)
-                .map_or(false, |(a, b)| a == b && expected.topics.len() == log.topics.len())
-            {
-                // Match topics
-                event_to_fill_or_check.found = log
-                    .topics
-                    .iter()
-                    .skip(1)
-                    .enumerate()
-                    .filter(|(i, _)| event_to_fill_or_check.checks[*i])
-                    .all(|(i, topic)| topic == &expected.topics[i + 1]);
-
-                // Maybe match source address
-                if let Some(addr) = event_to_fill_or_check.address {
-                    event_to_fill_or_check.found &= addr == *address;
-                }
-
-                // Maybe match data
-                if event_to_fill_or_check.checks[3] {
-                    event_to_fill_or_check.found &= expected.data == log.data;
-                }
+        Some(_) => {
+            if event_to_fill_or_check.matches(&log, address) {
+                event_to_fill_or_check.actual_count += 1;
+                event_to_fill_or_check.found = event_to_fill_or_check.actual_count >=
+                    event_to_fill_or_check.count.unwrap_or(1);
             }
 
             // If we found the event, we can push it to the back of the queue
@@ -189,6 +332,8 @@ pub fn handle_expect_emit(state: &mut Cheatcodes, log: RawLog, address: &Address
         // Fill the event.
         None => {
             event_to_fill_or_check.log = Some(log);
+            // An event expected zero times is found unless it is emitted afterwards
+            event_to_fill_or_check.found = event_to_fill_or_check.count == Some(0);
             state.expected_emits.push_back(event_to_fill_or_check);
         }
     }
@@ -334,47 +479,168 @@ pub fn apply<DB: DatabaseExt>(
     call: &HEVMCalls,
 ) -> Option<Result> {
     let result = match call {
-        HEVMCalls::ExpectRevert0(_) => expect_revert(state, None, data.journaled_state.depth()),
-        HEVMCalls::ExpectRevert1(inner) => {
-            expect_revert(state, Some(inner.0.clone()), data.journaled_state.depth())
-        }
-        HEVMCalls::ExpectRevert2(inner) => {
-            expect_revert(state, Some(inner.0.into()), data.journaled_state.depth())
-        }
-        HEVMCalls::ExpectEmit0(_) => {
-            state.expected_emits.push_back(ExpectedEmit {
+        HEVMCalls::ExpectRevert0(_) => expect_revert(
+            state,
+            ExpectedRevert { depth: data.journaled_state.depth(), ..Default::default() },
+        ),
+        HEVMCalls::ExpectRevert1(inner) => expect_revert(
+            state,
+            ExpectedRevert {
+                reason: Some(inner.0.clone()),
                 depth: data.journaled_state.depth(),
-                checks: [true, true, true, true],
                 ..Default::default()
-            });
-            Ok(Bytes::new())
-        }
-        HEVMCalls::ExpectEmit1(inner) => {
-            state.expected_emits.push_back(ExpectedEmit {
+            },
+        ),
+        HEVMCalls::ExpectRevert2(inner) => expect_revert(
+            state,
+            ExpectedRevert {
+                reason: Some(inner.0.into()),
                 depth: data.journaled_state.depth(),
-                checks: [true, true, true, true],
-                address: Some(inner.0),
                 ..Default::default()
-            });
-            Ok(Bytes::new())
-        }
-        HEVMCalls::ExpectEmit2(inner) => {
-            state.expected_emits.push_back(ExpectedEmit {
+            },
+        ),
+        HEVMCalls::ExpectRevert3(inner) => expect_revert(
+            state,
+            ExpectedRevert {
                 depth: data.journaled_state.depth(),
-                checks: [inner.0, inner.1, inner.2, inner.3],
+                count: inner.0,
                 ..Default::default()
-            });
-            Ok(Bytes::new())
-        }
-        HEVMCalls::ExpectEmit3(inner) => {
-            state.expected_emits.push_back(ExpectedEmit {
+            },
+        ),
+        HEVMCalls::ExpectRevert4(inner) => expect_revert(
+            state,
+            ExpectedRevert {
                 depth: data.journaled_state.depth(),
-                checks: [inner.0, inner.1, inner.2, inner.3],
-                address: Some(inner.4),
+                reverter: Some(inner.0),
                 ..Default::default()
-            });
-            Ok(Bytes::new())
+            },
+        ),
+        HEVMCalls::ExpectRevert5(inner) => expect_revert(
+            state,
+            ExpectedRevert {
+                reason: Some(inner.0.clone()),
+                depth: data.journaled_state.depth(),
+                count: inner.1,
+                ..Default::default()
+            },
+        ),
+        HEVMCalls::ExpectRevert6(inner) => expect_revert(
+            state,
+            ExpectedRevert {
+                reason: Some(inner.0.into()),
+                depth: data.journaled_state.depth(),
+                count: inner.1,
+                ..Default::default()
+            },
+        ),
+        HEVMCalls::ExpectRevert7(inner) => expect_revert(
+            state,
+            ExpectedRevert {
+                reason: Some(inner.0.clone()),
+                depth: data.journaled_state.depth(),
+                reverter: Some(inner.1),
+                ..Default::default()
+            },
+        ),
+        HEVMCalls::ExpectRevert8(inner) => expect_revert(
+            state,
+            ExpectedRevert {
+                reason: Some(inner.0.into()),
+                depth: data.journaled_state.depth(),
+                reverter: Some(inner.1),
+                ..Default::default()
+            },
+        ),
+        HEVMCalls::ExpectPartialRevert0(inner) => expect_revert(
+            state,
+            ExpectedRevert {
+                reason: Some(inner.0.into()),
+                depth: data.journaled_state.depth(),
+                partial_match: true,
+                ..Default::default()
+            },
+        ),
+        HEVMCalls::ExpectPartialRevert1(inner) => expect_revert(
+            state,
+            ExpectedRevert {
+                reason: Some(inner.0.into()),
+                depth: data.journaled_state.depth(),
+                partial_match: true,
+                reverter: Some(inner.1),
+                ..Default::default()
+            },
+        ),
+        HEVMCalls::ExpectEmit0(_) => {
+            expect_emit(state, data.journaled_state.depth(), &[true; 4], None, false, None)
+        }
+        HEVMCalls::ExpectEmit1(inner) => {
+            expect_emit(state, data.journaled_state.depth(), &[true; 4], Some(inner.0), false, None)
+        }
+        HEVMCalls::ExpectEmit2(inner) => {
+            expect_emit(state, data.journaled_state.depth(), &[true; 4], None, false, Some(inner.0))
+        }
+        HEVMCalls::ExpectEmit3(inner) => expect_emit(
+            state,
+            data.journaled_state.depth(),
+            &[true; 4],
+            Some(inner.0),
+            false,
+            Some(inner.1),
+        ),
+        HEVMCalls::ExpectEmit4(inner) => expect_emit(
+            state,
+            data.journaled_state.depth(),
+            &[inner.0, inner.1, inner.2, inner.3],
+            None,
+            false,
+            None,
+        ),
+        HEVMCalls::ExpectEmit5(inner) => expect_emit(
+            state,
+            data.journaled_state.depth(),
+            &[inner.0, inner.1, inner.2, inner.3],
+            Some(inner.4),
+            false,
+            None,
+        ),
+        HEVMCalls::ExpectEmit6(inner) => expect_emit(
+            state,
+            data.journaled_state.depth(),
+            &[inner.0, inner.1, inner.2, inner.3],
+            None,
+            false,
+            Some(inner.4),
+        ),
+        HEVMCalls::ExpectEmit7(inner) => expect_emit(
+            state,
+            data.journaled_state.depth(),
+            &[inner.0, inner.1, inner.2, inner.3],
+            Some(inner.4),
+            false,
+            Some(inner.5),
+        ),
+        HEVMCalls::ExpectEmitAnonymous0(_) => {
+            expect_emit(state, data.journaled_state.depth(), &[true; 5], None, true, None)
+        }
+        HEVMCalls::ExpectEmitAnonymous1(inner) => {
+            expect_emit(state, data.journaled_state.depth(), &[true; 5], Some(inner.0), true, None)
         }
+        HEVMCalls::ExpectEmitAnonymous2(inner) => expect_emit(
+            state,
+            data.journaled_state.depth(),
+            &[inner.0, inner.1, inner.2, inner.3, inner.4],
+            None,
+            true,
+            None,
+        ),
+        HEVMCalls::ExpectEmitAnonymous3(inner) => expect_emit(
+            state,
+            data.journaled_state.depth(),
+            &[inner.0, inner.1, inner.2, inner.3, inner.4],
+            Some(inner.5),
+            true,
+            None,
+        ),
         HEVMCalls::ExpectCall0(inner) => expect_call(
             state,
             inner.0,
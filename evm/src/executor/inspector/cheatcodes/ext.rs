@@ -250,7 +250,12 @@ fn canonicalize_json_key(key: &str) -> String {
 /// alphabetically and not by efficient packing or some other taxonomy.
 fn parse_json(json_str: &str, key: &str, coerce: Option<ParamType>) -> Result {
     let json = serde_json::from_str(json_str)?;
-    let values = jsonpath_lib::select(&json, &canonicalize_json_key(key))?;
+    parse_json_value(&json, key, coerce)
+}
+
+/// Same as [`parse_json`], but operates on an already deserialized JSON [`Value`].
+fn parse_json_value(json: &Value, key: &str, coerce: Option<ParamType>) -> Result {
+    let values = jsonpath_lib::select(json, &canonicalize_json_key(key))?;
 
     // values is an array of items. Depending on the JsonPath key, they
     // can be many or a single item. An item can be a single value or
@@ -291,6 +296,111 @@ fn parse_json(json_str: &str, key: &str, coerce: Option<ParamType>) -> Result {
     Ok(abi_encoded.into())
 }
 
+/// Returns whether the given JSONPath `key` matches any value in the JSON document.
+fn key_exists_json(json_str: &str, key: &str) -> Result {
+    let json = serde_json::from_str(json_str)?;
+    key_exists_value(&json, key)
+}
+
+fn key_exists_value(json: &Value, key: &str) -> Result {
+    let values = jsonpath_lib::select(json, &canonicalize_json_key(key))?;
+    Ok(abi::encode(&[Token::Bool(!values.is_empty())]).into())
+}
+
+/// Returns the keys of the JSON object found at the given JSONPath `key`.
+fn parse_json_keys(json_str: &str, key: &str) -> Result {
+    let json = serde_json::from_str(json_str)?;
+    parse_json_keys_value(&json, key)
+}
+
+fn parse_json_keys_value(json: &Value, key: &str) -> Result {
+    let values = jsonpath_lib::select(json, &canonicalize_json_key(key))?;
+    ensure!(!values.is_empty(), "No matching value or array found for key {key}");
+    ensure!(values.len() == 1, "The key '{key}' must return exactly one JSON object");
+    let object = values[0]
+        .as_object()
+        .ok_or_else(|| fmt_err!("The key '{key}' does not return a JSON object"))?;
+    let keys = object.keys().cloned().map(Token::String).collect();
+    Ok(abi::encode(&[Token::Array(keys)]).into())
+}
+
+/// Converts a TOML [`toml::Value`] to a JSON [`Value`], so that the JSON cheatcode machinery can
+/// be reused for TOML documents.
+///
+/// Datetimes are converted to their string representation.
+fn toml_to_json_value(toml: toml::Value) -> Value {
+    match toml {
+        toml::Value::String(s) => Value::String(s),
+        toml::Value::Integer(i) => Value::Number(i.into()),
+        toml::Value::Float(f) => {
+            serde_json::Number::from_f64(f).map(Value::Number).unwrap_or(Value::Null)
+        }
+        toml::Value::Boolean(b) => Value::Bool(b),
+        toml::Value::Datetime(d) => Value::String(d.to_string()),
+        toml::Value::Array(array) => {
+            Value::Array(array.into_iter().map(toml_to_json_value).collect())
+        }
+        toml::Value::Table(table) => {
+            Value::Object(table.into_iter().map(|(k, v)| (k, toml_to_json_value(v))).collect())
+        }
+    }
+}
+
+/// Converts a JSON [`Value`] to a TOML [`toml::Value`].
+///
+/// TOML has no notion of `null`, so it is written as the string `"null"`. Numbers that don't fit
+/// into TOML's 64-bit integers are written as strings, which the `parseToml*` coercions accept.
+fn json_to_toml_value(json: Value) -> toml::Value {
+    match json {
+        Value::Null => toml::Value::String("null".to_string()),
+        Value::Bool(b) => toml::Value::Boolean(b),
+        Value::Number(n) => {
+            if let Some(i) = n.as_i64() {
+                toml::Value::Integer(i)
+            } else if n.is_f64() {
+                n.as_f64()
+                    .map(toml::Value::Float)
+                    .unwrap_or_else(|| toml::Value::String(n.to_string()))
+            } else {
+                toml::Value::String(n.to_string())
+            }
+        }
+        Value::String(s) => toml::Value::String(s),
+        Value::Array(array) => {
+            toml::Value::Array(array.into_iter().map(json_to_toml_value).collect())
+        }
+        Value::Object(object) => toml::Value::Table(
+            object.into_iter().map(|(k, v)| (k, json_to_toml_value(v))).collect(),
+        ),
+    }
+}
+
+/// Parses a TOML document into its JSON representation.
+fn parse_toml_str(toml_str: &str) -> Result<Value> {
+    let toml = toml::from_str::<toml::Value>(toml_str)
+        .map_err(|err| fmt_err!("Failed to parse TOML: {err}"))?;
+    Ok(toml_to_json_value(toml))
+}
+
+/// Parses a TOML document and returns a single value, an array or an entire table encoded as
+/// tuple.
+///
+/// The document is converted to JSON first, so the same JSONPath `key` syntax and type coercion
+/// rules as [`parse_json`] apply.
+fn parse_toml(toml_str: &str, key: &str, coerce: Option<ParamType>) -> Result {
+    parse_json_value(&parse_toml_str(toml_str)?, key, coerce)
+}
+
+/// Returns whether the given JSONPath `key` matches any value in the TOML document.
+fn key_exists_toml(toml_str: &str, key: &str) -> Result {
+    key_exists_value(&parse_toml_str(toml_str)?, key)
+}
+
+/// Returns the keys of the TOML table found at the given JSONPath `key`.
+fn parse_toml_keys(toml_str: &str, key: &str) -> Result {
+    parse_json_keys_value(&parse_toml_str(toml_str)?, key)
+}
+
 /// Serializes a key:value pair to a specific object. By calling this function multiple times,
 /// the user can serialize multiple KV pairs to the same object. The value can be of any type, even
 /// a new object in itself. The function will return
@@ -381,6 +491,34 @@ fn write_json(
     Ok(Bytes::new())
 }
 
+/// Write a stringified JSON object to a new TOML file OR replace the value of an existing TOML
+/// file with the supplied object.
+fn write_toml(
+    state: &mut Cheatcodes,
+    object: &str,
+    path: impl AsRef<Path>,
+    toml_path_or_none: Option<&str>,
+) -> Result {
+    let json: Value =
+        serde_json::from_str(object).unwrap_or_else(|_| Value::String(object.to_owned()));
+    let json = if let Some(toml_path) = toml_path_or_none {
+        let path = state.config.ensure_path_allowed(&path, FsAccessKind::Read)?;
+        let data = parse_toml_str(&fs::read_to_string(path)?)?;
+        jsonpath_lib::replace_with(data, &canonicalize_json_key(toml_path), &mut |_| {
+            Some(json.clone())
+        })?
+    } else {
+        json
+    };
+    let toml::Value::Table(table) = json_to_toml_value(json) else {
+        bail!("TOML documents must be tables, the value to write is not a JSON object")
+    };
+    let toml_string = toml::to_string_pretty(&table)
+        .map_err(|err| fmt_err!("Failed to serialize TOML: {err}"))?;
+    super::fs::write_file(state, path, toml_string)?;
+    Ok(Bytes::new())
+}
+
 #[instrument(level = "error", name = "ext", target = "evm::cheatcodes", skip_all)]
 pub fn apply(state: &mut Cheatcodes, call: &HEVMCalls) -> Option<Result> {
     Some(match call {
@@ -513,6 +651,8 @@ pub fn apply(state: &mut Cheatcodes, call: &HEVMCalls) -> Option<Result> {
         HEVMCalls::ParseJsonBytes32Array(inner) => {
             parse_json(&inner.0, &inner.1, Some(ParamType::FixedBytes(32)))
         }
+        HEVMCalls::ParseJsonKeys(inner) => parse_json_keys(&inner.0, &inner.1),
+        HEVMCalls::KeyExistsJson(inner) => key_exists_json(&inner.0, &inner.1),
         HEVMCalls::SerializeBool0(inner) => {
             serialize_json(state, &inner.0, &inner.1, &inner.2.pretty())
         }
@@ -557,6 +697,49 @@ pub fn apply(state: &mut Cheatcodes, call: &HEVMCalls) -> Option<Result> {
         }
         HEVMCalls::WriteJson0(inner) => write_json(state, &inner.0, &inner.1, None),
         HEVMCalls::WriteJson1(inner) => write_json(state, &inner.0, &inner.1, Some(&inner.2)),
+
+        HEVMCalls::ParseToml0(inner) => parse_toml(&inner.0, "$", None),
+        HEVMCalls::ParseToml1(inner) => parse_toml(&inner.0, &inner.1, None),
+        HEVMCalls::ParseTomlBool(inner) => parse_toml(&inner.0, &inner.1, Some(ParamType::Bool)),
+        HEVMCalls::ParseTomlBoolArray(inner) => {
+            parse_toml(&inner.0, &inner.1, Some(ParamType::Bool))
+        }
+        HEVMCalls::ParseTomlUint(inner) => {
+            parse_toml(&inner.0, &inner.1, Some(ParamType::Uint(256)))
+        }
+        HEVMCalls::ParseTomlUintArray(inner) => {
+            parse_toml(&inner.0, &inner.1, Some(ParamType::Uint(256)))
+        }
+        HEVMCalls::ParseTomlInt(inner) => parse_toml(&inner.0, &inner.1, Some(ParamType::Int(256))),
+        HEVMCalls::ParseTomlIntArray(inner) => {
+            parse_toml(&inner.0, &inner.1, Some(ParamType::Int(256)))
+        }
+        HEVMCalls::ParseTomlString(inner) => {
+            parse_toml(&inner.0, &inner.1, Some(ParamType::String))
+        }
+        HEVMCalls::ParseTomlStringArray(inner) => {
+            parse_toml(&inner.0, &inner.1, Some(ParamType::String))
+        }
+        HEVMCalls::ParseTomlAddress(inner) => {
+            parse_toml(&inner.0, &inner.1, Some(ParamType::Address))
+        }
+        HEVMCalls::ParseTomlAddressArray(inner) => {
+            parse_toml(&inner.0, &inner.1, Some(ParamType::Address))
+        }
+        HEVMCalls::ParseTomlBytes(inner) => parse_toml(&inner.0, &inner.1, Some(ParamType::Bytes)),
+        HEVMCalls::ParseTomlBytesArray(inner) => {
+            parse_toml(&inner.0, &inner.1, Some(ParamType::Bytes))
+        }
+        HEVMCalls::ParseTomlBytes32(inner) => {
+            parse_toml(&inner.0, &inner.1, Some(ParamType::FixedBytes(32)))
+        }
+        HEVMCalls::ParseTomlBytes32Array(inner) => {
+            parse_toml(&inner.0, &inner.1, Some(ParamType::FixedBytes(32)))
+        }
+        HEVMCalls::ParseTomlKeys(inner) => parse_toml_keys(&inner.0, &inner.1),
+        HEVMCalls::KeyExistsToml(inner) => key_exists_toml(&inner.0, &inner.1),
+        HEVMCalls::WriteToml0(inner) => write_toml(state, &inner.0, &inner.1, None),
+        HEVMCalls::WriteToml1(inner) => write_toml(state, &inner.0, &inner.1, Some(&inner.2)),
         _ => return None,
     })
 }
@@ -597,6 +780,36 @@ mod tests {
         assert_eq!(output, msg);
     }
 
+    #[test]
+    fn test_toml_json_roundtrip() {
+        let s = r#"
+            name = "core"
+            chain = 1
+            enabled = true
+
+            [deployer]
+            address = "cb58e5dd06163a480c22d540ec763325a0b5860fb56c"
+            salts = [1, 2]
+        "#;
+        let json = parse_toml_str(s).unwrap();
+        assert_eq!(json["deployer"]["salts"], serde_json::json!([1, 2]));
+
+        let toml = json_to_toml_value(json);
+        assert_eq!(toml, toml::from_str::<toml::Value>(s).unwrap());
+    }
+
+    #[test]
+    fn test_json_keys() {
+        let json = r#"{"a": {"b": 1, "c": 2}, "d": []}"#;
+        let keys = parse_json_keys(json, ".a").unwrap();
+        let keys = Vec::<String>::decode(&keys).unwrap();
+        assert_eq!(keys, vec!["b".to_string(), "c".to_string()]);
+
+        assert!(parse_json_keys(json, ".d").is_err());
+        assert!(bool::decode(&key_exists_json(json, ".a.b").unwrap()).unwrap());
+        assert!(!bool::decode(&key_exists_json(json, ".a.e").unwrap()).unwrap());
+    }
+
     #[test]
     fn test_artifact_parsing() {
         let s = include_str!("../../../../test-data/solc-obj.json");
@@ -25,7 +25,9 @@ use foxar_common::evm::Breakpoints;
 use foxar_utils::error::SolError;
 use itertools::Itertools;
 use revm::{
-    interpreter::{opcode, CallInputs, CreateInputs, Energy, InstructionResult, Interpreter},
+    interpreter::{
+        opcode, return_revert, CallInputs, CreateInputs, Energy, InstructionResult, Interpreter,
+    },
     primitives::{BlockEnv, TransactTo, B176, B256},
     EVMData, Inspector,
 };
@@ -39,6 +41,8 @@ use std::{
     sync::Arc,
 };
 
+/// Assertion cheatcodes (such as `assertEq`)
+mod assert;
 /// Cheatcodes related to the execution environment.
 mod env;
 pub use env::{Log, Prank, RecordAccess};
@@ -48,6 +52,8 @@ pub use expect::{
     ExpectedCallData, ExpectedEmit, ExpectedRevert, MockCallDataContext, MockCallReturnData,
 };
 
+/// Deployment registry cheatcodes
+mod deployments;
 /// Cheatcodes that interact with the external environment (FFI etc.)
 mod ext;
 /// Fork related cheatcodes
@@ -157,6 +163,10 @@ pub struct Cheatcodes {
     /// Scripting based transactions
     pub broadcastable_transactions: BroadcastableTransactions,
 
+    /// Deployments saved with `saveDeployment`, written to the deployment registry once the
+    /// script is broadcast. `name -> address`
+    pub deployments: BTreeMap<String, Address>,
+
     /// Additional, user configurable context this Inspector has access to when inspecting a call
     pub config: Arc<CheatsConfig>,
 
@@ -185,6 +195,18 @@ pub struct Cheatcodes {
     /// CREATE / CREATE2 frames. This is needed to make energy meter pausing work correctly when
     /// paused and creating new contracts.
     pub energy_metering_create: Option<Option<revm::interpreter::Energy>>,
+    /// Energy spent in the current call frame before the last executed opcode
+    ///
+    /// Used by `startSnapshotEnergy` / `stopSnapshotEnergy`, since the cheatcode call itself
+    /// doesn't have access to the interpreter of the calling frame.
+    pub last_energy_spent: u64,
+
+    /// Started energy snapshots. `name -> (depth, energy spent at start)`
+    pub started_energy_snapshots: BTreeMap<String, (u64, u64)>,
+
+    /// Recorded energy snapshots and values. `name -> value`
+    pub energy_snapshots: BTreeMap<String, u64>,
+
     /// current program counter
     pub pc: usize,
     /// Breakpoints supplied by the `vm.breakpoint("<char>")` cheatcode
@@ -225,9 +247,11 @@ impl Cheatcodes {
             .or_else(|| util::apply(self, data, &decoded))
             .or_else(|| expect::apply(self, data, &decoded))
             .or_else(|| fuzz::apply(&decoded))
+            .or_else(|| assert::apply(&call.input))
             .or_else(|| ext::apply(self, &decoded))
             .or_else(|| fs::apply(self, &decoded))
-            .or_else(|| snapshot::apply(data, &decoded))
+            .or_else(|| deployments::apply(self, data, &decoded))
+            .or_else(|| snapshot::apply(self, data, &decoded))
             .or_else(|| fork::apply(self, data, &decoded));
         match opt {
             Some(res) => res,
@@ -321,6 +345,7 @@ where
         _: bool,
     ) -> InstructionResult {
         self.pc = interpreter.program_counter();
+        self.last_energy_spent = interpreter.energy.spend();
 
         // reset energy if energy metering is turned off
         match self.energy_metering {
@@ -787,21 +812,42 @@ where
         }
 
         // Handle expected reverts
-        if let Some(expected_revert) = &self.expected_revert {
+        if let Some(expected_revert) = &mut self.expected_revert {
+            if data.journaled_state.depth() == 0 {
+                // The test ended before all the expected reverts occurred
+                if let Some(error) = expected_revert.unmet_count_error() {
+                    self.expected_revert = None;
+                    return (InstructionResult::Revert, remaining_energy, error.encode().into())
+                }
+            }
+
+            let retdata = Bytes::from(retdata.clone());
             if data.journaled_state.depth() <= expected_revert.depth {
-                let expected_revert = std::mem::take(&mut self.expected_revert).unwrap();
-                return match handle_expect_revert(
-                    false,
-                    expected_revert.reason.as_ref(),
-                    status,
-                    retdata.into(),
-                ) {
+                let reverter =
+                    expected_revert.take_reverter(Some(b176_to_h176(call.contract)), &retdata);
+                let result =
+                    handle_expect_revert(false, expected_revert, reverter, status, retdata);
+                if let Err(error) = &result {
+                    trace!(expected=?expected_revert, ?error, ?status, "Expected revert mismatch");
+                }
+                // The expected revert is kept around as long as more reverts are expected
+                if !(result.is_ok() && expected_revert.record_match(data.journaled_state.depth())) {
+                    self.expected_revert = None;
+                }
+                return match result {
                     Err(error) => {
-                        trace!(expected=?expected_revert, ?error, ?status, "Expected revert mismatch");
                         (InstructionResult::Revert, remaining_energy, error.encode_error().0)
                     }
                     Ok((_, retdata)) => (InstructionResult::Return, remaining_energy, retdata.0),
                 };
+            } else {
+                // Remember the innermost contract that reverted, as the revert data is bubbled up
+                // by the calling contracts
+                expected_revert.record_nested_end(
+                    b176_to_h176(call.contract),
+                    matches!(status, return_revert!()),
+                    &retdata,
+                );
             }
         }
 
@@ -831,6 +877,17 @@ where
                     remaining_energy,
                     "Log != expected log".to_string().encode().into(),
                 );
+            } else if let Some(expected) = self.expected_emits.iter().find(|expected| {
+                expected.count.map_or(false, |count| expected.actual_count > count)
+            }) {
+                // Some emits expected an exact number of times were emitted too often.
+                let error = format!(
+                    "Expected log to be emitted {} time(s), but it was emitted {} time(s)",
+                    expected.count.unwrap_or_default(),
+                    expected.actual_count
+                );
+                self.expected_emits.clear();
+                return (InstructionResult::Revert, remaining_energy, error.encode().into())
             } else {
                 // All emits were found, we're good.
                 // Clear the queue, as we expect the user to declare more events for the next call
@@ -1069,15 +1126,18 @@ where
         }
 
         // Handle expected reverts
-        if let Some(expected_revert) = &self.expected_revert {
+        if let Some(expected_revert) = &mut self.expected_revert {
+            let retdata = Bytes::from(retdata.clone());
+            // A constructor reverting directly is the reverter
+            let created = address.map(b176_to_h176);
             if data.journaled_state.depth() <= expected_revert.depth {
-                let expected_revert = std::mem::take(&mut self.expected_revert).unwrap();
-                return match handle_expect_revert(
-                    true,
-                    expected_revert.reason.as_ref(),
-                    status,
-                    retdata.into(),
-                ) {
+                let reverter = expected_revert.take_reverter(created, &retdata);
+                let result = handle_expect_revert(true, expected_revert, reverter, status, retdata);
+                // The expected revert is kept around as long as more reverts are expected
+                if !(result.is_ok() && expected_revert.record_match(data.journaled_state.depth())) {
+                    self.expected_revert = None;
+                }
+                return match result {
                     Ok((address, retdata)) => (
                         InstructionResult::Return,
                         address.map(h176_to_b176),
@@ -1088,6 +1148,12 @@ where
                         (InstructionResult::Revert, None, remaining_energy, err.encode_error().0)
                     }
                 };
+            } else if let Some(created) = created {
+                expected_revert.record_nested_end(
+                    created,
+                    matches!(status, return_revert!()),
+                    &retdata,
+                );
             }
         }
 
@@ -1,11 +1,53 @@
-use super::Result;
+use super::{bail, ensure, Cheatcodes, Result};
 use crate::{abi::HEVMCalls, executor::backend::DatabaseExt};
-use corebc::abi::AbiEncode;
+use corebc::{
+    abi::AbiEncode,
+    types::{Bytes, U256},
+};
 use revm::EVMData;
 
-/// Handles fork related cheatcodes
+/// Starts measuring the energy used by the caller under the given `name`.
+///
+/// The energy is measured in the call frame of the caller, which means the snapshot has to be
+/// stopped in the same frame.
+fn start_snapshot_energy(state: &mut Cheatcodes, depth: u64, name: &str) -> Result {
+    ensure!(
+        !state.started_energy_snapshots.contains_key(name),
+        "Energy snapshot `{name}` has already been started"
+    );
+    state.started_energy_snapshots.insert(name.to_string(), (depth, state.last_energy_spent));
+    Ok(Bytes::new())
+}
+
+/// Stops the energy snapshot `name` and records the energy used since it was started.
+fn stop_snapshot_energy(state: &mut Cheatcodes, depth: u64, name: &str) -> Result {
+    let Some(&(start_depth, start)) = state.started_energy_snapshots.get(name) else {
+        bail!("Energy snapshot `{name}` has not been started")
+    };
+    ensure!(
+        start_depth == depth,
+        "Energy snapshot `{name}` must be stopped in the call frame it was started in"
+    );
+    state.started_energy_snapshots.remove(name);
+    let energy_used = state.last_energy_spent.saturating_sub(start);
+    state.energy_snapshots.insert(name.to_string(), energy_used);
+    Ok(U256::from(energy_used).encode().into())
+}
+
+/// Records an arbitrary `value` under the given `name` in the energy snapshots.
+fn snapshot_value(state: &mut Cheatcodes, name: &str, value: U256) -> Result {
+    ensure!(value <= U256::from(u64::MAX), "Snapshot value `{name}` does not fit into 64 bits");
+    state.energy_snapshots.insert(name.to_string(), value.as_u64());
+    Ok(Bytes::new())
+}
+
+/// Handles snapshot related cheatcodes
 #[instrument(level = "error", name = "snapshot", target = "evm::cheatcodes", skip_all)]
-pub fn apply<DB: DatabaseExt>(data: &mut EVMData<'_, DB>, call: &HEVMCalls) -> Option<Result> {
+pub fn apply<DB: DatabaseExt>(
+    state: &mut Cheatcodes,
+    data: &mut EVMData<'_, DB>,
+    call: &HEVMCalls,
+) -> Option<Result> {
     Some(match call {
         HEVMCalls::Snapshot(_) => {
             Ok(data.db.snapshot(&data.journaled_state, data.env).encode().into())
@@ -22,6 +64,38 @@ pub fn apply<DB: DatabaseExt>(data: &mut EVMData<'_, DB>, call: &HEVMCalls) -> O
             };
             Ok(res.encode().into())
         }
+        HEVMCalls::StartSnapshotEnergy(inner) => {
+            start_snapshot_energy(state, data.journaled_state.depth(), &inner.0)
+        }
+        HEVMCalls::StopSnapshotEnergy(inner) => {
+            stop_snapshot_energy(state, data.journaled_state.depth(), &inner.0)
+        }
+        HEVMCalls::SnapshotValue(inner) => snapshot_value(state, &inner.0, inner.1),
         _ => return None,
     })
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_energy_snapshots() {
+        let mut state = Cheatcodes { last_energy_spent: 100, ..Default::default() };
+        start_snapshot_energy(&mut state, 1, "section").unwrap();
+        assert!(start_snapshot_energy(&mut state, 1, "section").is_err());
+
+        state.last_energy_spent = 350;
+        assert!(stop_snapshot_energy(&mut state, 2, "section").is_err());
+        let used = stop_snapshot_energy(&mut state, 1, "section").unwrap();
+        assert_eq!(used.as_ref(), U256::from(250).encode().as_slice());
+        assert!(stop_snapshot_energy(&mut state, 1, "section").is_err());
+
+        snapshot_value(&mut state, "value", 42.into()).unwrap();
+        assert!(snapshot_value(&mut state, "value", U256::MAX).is_err());
+        assert_eq!(
+            state.energy_snapshots.into_iter().collect::<Vec<_>>(),
+            vec![("section".to_string(), 250), ("value".to_string(), 42)]
+        );
+    }
+}
@@ -10,7 +10,7 @@ use crate::{
 use bytes::{BufMut, Bytes, BytesMut};
 use corebc::{
     abi::{AbiEncode, Address, ParamType, Token},
-    core::libgoldilocks::SigningKey,
+    core::libgoldilocks::{goldilocks::ed448_derive_public, SigningKey},
     prelude::{LocalWallet, Signer, H176, *},
     signers::{
         coins_bip39::{
@@ -40,6 +40,12 @@ pub const DEFAULT_CREATE2_DEPLOYER: H176 = H176([
 
 pub const MAGIC_SKIP_BYTES: &[u8] = b"FOXAR::SKIP";
 
+/// Length in bytes of an ED448 private key
+pub const ED448_PRIVATE_KEY_LEN: usize = 57;
+
+/// Length in bytes of an ED448 public key
+pub const ED448_PUBLIC_KEY_LEN: usize = 57;
+
 /// Helps collecting transactions from different forks.
 #[derive(Debug, Clone, Default)]
 pub struct BroadcastableTransaction {
@@ -79,28 +85,64 @@ where
     let account = journaled_state.state.get_mut(&addr).expect("account loaded;");
     Ok(f(account))
 }
-//TODO:error2215 change crypto
+/// Returns the ICAN address of the given ED448 private key, prefixed for the given network.
 fn addr(private_key: &str, network: &Network) -> Result {
     let key = parse_private_key_from_str(private_key)?;
     let addr = utils::secret_key_to_address(&key, network);
     Ok(addr.encode().into())
 }
 
+/// Signs the digest with the given ED448 private key.
+///
+/// The returned signature is the raw 171 byte ED448 signature, i.e. the 114 byte signature
+/// followed by the 57 byte public key of the signer, as expected by the `ecrecover` precompile.
 fn sign(private_key: &str, digest: H256, network_id: U256) -> Result {
-    let key = parse_private_key_from_str(private_key)?;
-    let network_id = network_id.as_u64();
-    let wallet = LocalWallet::from(key).with_network_id(network_id);
-    let network = Network::from(network_id);
+    let wallet = parse_wallet_from_str(private_key, network_id)?;
+    let network = Network::from(network_id.as_u64());
 
-    // The `ecrecover` precompile does not use EIP-155
     let sig = wallet.sign_hash(digest)?;
     let recovered = sig.recover(digest, &network)?;
+    ensure!(
+        recovered == wallet.address(),
+        "Recovered signer {recovered:?} does not match the signing key's address {:?}",
+        wallet.address()
+    );
 
-    assert_eq!(recovered, wallet.address());
+    Ok(sig.sig.to_fixed_bytes().encode().into())
+}
 
-    let sig_bytes = sig.sig.to_fixed_bytes();
+/// Creates a deterministic wallet from the given `label` and labels its address.
+///
+/// The 57 byte ED448 private key is derived from the label, so the same label always yields the
+/// same wallet. The ICAN address is derived for the current network.
+fn create_wallet(state: &mut Cheatcodes, label: &str, network_id: U256) -> Result {
+    let first = utils::sha3(label.as_bytes());
+    let second = utils::sha3(first);
+    let mut private_key = [0u8; ED448_PRIVATE_KEY_LEN];
+    private_key[..32].copy_from_slice(&first);
+    private_key[32..].copy_from_slice(&second[..ED448_PRIVATE_KEY_LEN - 32]);
+    let private_key = format!("0x{}", hex::encode(private_key));
+
+    let wallet = parse_wallet_from_str(&private_key, network_id)?;
+    let address = wallet.address();
+    let public_key = public_key(wallet.signer());
+
+    state.labels.insert(address, label.to_string());
 
-    Ok(sig_bytes.encode().into())
+    Ok(abi::encode(&[Token::Tuple(vec![
+        Token::Address(address),
+        Token::Bytes(public_key),
+        Token::String(private_key),
+    ])])
+    .into())
+}
+
+/// Derives the 57 byte ED448 public key of the given private key.
+fn public_key(key: &SigningKey) -> Vec<u8> {
+    let mut private_key = [0u8; ED448_PRIVATE_KEY_LEN];
+    private_key.copy_from_slice(&key.to_bytes());
+    let public_key: [u8; ED448_PUBLIC_KEY_LEN] = ed448_derive_public(&private_key);
+    public_key.to_vec()
 }
 
 enum WordlistLang {
@@ -145,7 +187,7 @@ fn derive_key<W: Wordlist>(mnemonic: &str, path: &str, index: u32) -> Result {
         .derivation_path(&derivation_path)?
         .build()?;
 
-    let private_key = U256::from_big_endian(wallet.signer().to_bytes().as_slice());
+    let private_key = format!("0x{}", hex::encode(wallet.signer().to_bytes()));
 
     Ok(private_key.encode().into())
 }
@@ -166,9 +208,8 @@ fn derive_key_with_wordlist(mnemonic: &str, path: &str, index: u32, lang: &str)
     }
 }
 
-fn remember_key(state: &mut Cheatcodes, private_key: &str, chain_id: U256) -> Result {
-    let key = parse_private_key_from_str(private_key)?;
-    let wallet = LocalWallet::from(key).with_network_id(chain_id.as_u64());
+fn remember_key(state: &mut Cheatcodes, private_key: &str, network_id: U256) -> Result {
+    let wallet = parse_wallet_from_str(private_key, network_id)?;
     let address = wallet.address();
 
     state.script_wallets.push(wallet);
@@ -220,6 +261,9 @@ pub fn apply<DB: Database>(
         HEVMCalls::RememberKey(inner) => {
             remember_key(state, &inner.0, U256::from(data.env.cfg.network_id))
         }
+        HEVMCalls::CreateWallet(inner) => {
+            create_wallet(state, &inner.0, U256::from(data.env.cfg.network_id))
+        }
         HEVMCalls::Label(inner) => {
             state.labels.insert(inner.0, inner.1.clone());
             Ok(Default::default())
@@ -386,9 +430,13 @@ fn parse_bytes(s: &str) -> Result<Vec<u8>, String> {
     hex::decode(s.strip_prefix("0x").unwrap_or(s)).map_err(|e| e.to_string())
 }
 
+/// Parses a hex encoded 57 byte ED448 private key, with or without `0x` prefix.
 pub fn parse_private_key_from_str(private_key: &str) -> Result<SigningKey> {
     let private_key = private_key.replace("0x", "");
-    ensure!(private_key.len() == 114, "Wrong private key length");
+    ensure!(
+        private_key.len() == ED448_PRIVATE_KEY_LEN * 2,
+        "Wrong private key length: ED448 private keys are {ED448_PRIVATE_KEY_LEN} bytes long"
+    );
 
     let private_key = H456::from_str(&private_key);
     ensure!(private_key.is_ok(), "Couldn't parse private key");
@@ -397,6 +445,13 @@ pub fn parse_private_key_from_str(private_key: &str) -> Result<SigningKey> {
     SigningKey::from_bytes(private_key.as_bytes()).map_err(|e| Error::CorebcSignature(e.into()))
 }
 
+/// Parses a hex encoded ED448 private key into a [`LocalWallet`] whose ICAN address is derived for
+/// the given network.
+pub fn parse_wallet_from_str(private_key: &str, network_id: U256) -> Result<LocalWallet> {
+    let key = parse_private_key_from_str(private_key)?;
+    Ok(LocalWallet::from(key).with_network_id(network_id.as_u64()))
+}
+
 // pub fn parse_private_key(private_key: U256) -> Result<SigningKey> {
 //     ensure!(!private_key.is_zero(), "Private key cannot be 0.");
 //     ensure!(
@@ -453,6 +508,30 @@ mod tests {
         assert_eq!(U256::from(1337u64), decoded);
     }
 
+    #[test]
+    fn test_parse_private_key_length() {
+        let pk = format!("0x{}", "01".repeat(ED448_PRIVATE_KEY_LEN));
+        assert!(parse_private_key_from_str(&pk).is_ok());
+        assert!(parse_private_key_from_str(&pk[..pk.len() - 2]).is_err());
+        assert!(parse_private_key_from_str(
+            "0x10532cc9d0d992825c3f709c62c969748e317a549634fb2a9fa949326022e81f"
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn test_create_wallet_deterministic() {
+        let mut state = Cheatcodes::default();
+        let network_id = U256::from(1u64);
+        let first = create_wallet(&mut state, "alice", network_id).unwrap();
+        let second = create_wallet(&mut state, "alice", network_id).unwrap();
+        assert_eq!(first, second);
+
+        let other = create_wallet(&mut state, "bob", network_id).unwrap();
+        assert_ne!(first, other);
+        assert_eq!(state.labels.len(), 2);
+    }
+
     #[test]
     fn test_int_env() {
         let val = U256::from(100u64);
@@ -70,6 +70,9 @@ pub type StateChangeset = HashMap<B176, Account>;
 
 pub const DEFAULT_CREATE2_DEPLOYER_CODE: &[u8] = &hex!("604580600e600039806000f350fe7fffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffe03601600081602082378035828234f58015156039578182fd5b8082525050506014600cf3");
 
+/// The runtime code of the default CREATE2 deployer, returned by [DEFAULT_CREATE2_DEPLOYER_CODE]
+pub const DEFAULT_CREATE2_DEPLOYER_RUNTIME_CODE: &[u8] = &hex!("7fffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffe03601600081602082378035828234f58015156039578182fd5b8082525050506014600cf3");
+
 /// A type that can execute calls
 ///
 /// The executor can be configured with various `revm::Inspector`s, like `Cheatcodes`.
@@ -146,26 +149,25 @@ impl Executor {
     }
 
     /// Creates the default CREATE2 Contract Deployer for local tests and scripts.
+    ///
+    /// The runtime code is set directly, so the deployer is at its canonical address on forks
+    /// too, whatever the nonce of its creator is there.
     pub fn deploy_create2_deployer(&mut self) -> eyre::Result<()> {
         trace!("deploying local create2 deployer");
-        let create2_deployer_account = self
+        let mut create2_deployer_account = self
             .backend_mut()
             .basic(h176_to_b176(DEFAULT_CREATE2_DEPLOYER))?
             .ok_or(DatabaseError::MissingAccount(DEFAULT_CREATE2_DEPLOYER))?;
 
         // if the deployer is not currently deployed, deploy the default one
-        if create2_deployer_account.code.map_or(true, |code| code.is_empty()) {
-            let creator = "0xcb583fab184622dc19b6109349b94811493bf2a45362".parse().unwrap();
-
-            // Probably 0, but just in case.
-            let initial_balance = self.get_balance(creator)?;
-
-            self.set_balance(creator, U256::MAX)?;
-            let res =
-                self.deploy(creator, DEFAULT_CREATE2_DEPLOYER_CODE.into(), U256::zero(), None)?;
-            trace!(create2=?res.address, "deployed local create2 deployer");
-
-            self.set_balance(creator, initial_balance)?;
+        if create2_deployer_account.code.as_ref().map_or(true, |code| code.is_empty()) {
+            create2_deployer_account.code = Some(
+                Bytecode::new_raw(Bytes::from_static(DEFAULT_CREATE2_DEPLOYER_RUNTIME_CODE))
+                    .to_checked(),
+            );
+            self.backend_mut()
+                .insert_account_info(DEFAULT_CREATE2_DEPLOYER, create2_deployer_account);
+            trace!(create2=?DEFAULT_CREATE2_DEPLOYER, "deployed local create2 deployer");
         }
         Ok(())
     }
@@ -261,6 +263,7 @@ impl Executor {
                         state_changeset: None,
                         transactions: None,
                         script_wallets: res.script_wallets,
+                        coverage: res.coverage,
                     })))
                 }
             }
@@ -433,6 +436,7 @@ impl Executor {
             debug,
             script_wallets,
             env,
+            coverage,
             ..
         } = result;
 
@@ -458,7 +462,8 @@ impl Executor {
                         labels,
                         state_changeset: None,
                         transactions: None,
-                        script_wallets
+                        script_wallets,
+                        coverage,
                     })));
                 }
             }
@@ -478,6 +483,7 @@ impl Executor {
                     state_changeset: None,
                     transactions: None,
                     script_wallets,
+                    coverage,
                 })));
             }
         };
@@ -636,6 +642,8 @@ pub struct ExecutionErr {
     pub transactions: Option<BroadcastableTransactions>,
     pub state_changeset: Option<StateChangeset>,
     pub script_wallets: Vec<LocalWallet>,
+    /// The coverage info collected until the execution failed
+    pub coverage: Option<HitMaps>,
 }
 
 #[derive(thiserror::Error, Debug)]
@@ -710,6 +718,8 @@ pub struct CallResult<D: Detokenize> {
     pub env: Env,
     /// breakpoints
     pub breakpoints: Breakpoints,
+    /// The energy snapshots recorded during the call. `name -> value`
+    pub energy_snapshots: BTreeMap<String, u64>,
 }
 
 /// The result of a raw call.
@@ -879,10 +889,10 @@ fn convert_call_result<D: Detokenize>(
         ..
     } = call_result;
 
-    let breakpoints = if let Some(c) = call_result.cheatcodes {
-        c.breakpoints
+    let (breakpoints, energy_snapshots) = if let Some(c) = call_result.cheatcodes {
+        (c.breakpoints, c.energy_snapshots)
     } else {
-        std::collections::HashMap::new()
+        (std::collections::HashMap::new(), BTreeMap::new())
     };
 
     match status {
@@ -904,6 +914,7 @@ fn convert_call_result<D: Detokenize>(
                 script_wallets,
                 env,
                 breakpoints,
+                energy_snapshots,
                 skipped: false,
             })
         }
@@ -926,6 +937,7 @@ fn convert_call_result<D: Detokenize>(
                 transactions,
                 state_changeset,
                 script_wallets,
+                coverage,
             })))
         }
     }
@@ -1,5 +1,6 @@
 //! errors related to fuzz tests
 use proptest::test_runner::Reason;
+use std::time::Duration;
 
 /// Magic return code for the `assume` cheatcode
 pub const ASSUME_MAGIC_RETURN_CODE: &[u8] = b"FOXAR::ASSUME";
@@ -21,6 +22,12 @@ pub enum FuzzError {
     AssumeReject,
     #[error("The `vm.assume` cheatcode rejected too many inputs ({0} allowed)")]
     TooManyRejects(u32),
+    #[error("Timed out after {0:?}")]
+    Timeout(Duration),
+    #[error("Exceeded the energy budget of {0}")]
+    EnergyBudget(u64),
+    #[error("Exceeded the step budget of {0} calls")]
+    StepBudget(u64),
 }
 
 impl From<FuzzError> for Reason {
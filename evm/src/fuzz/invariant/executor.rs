@@ -13,7 +13,7 @@ use crate::{
             build_initial_state, collect_created_contracts, collect_state_from_call,
             invariant_strat, override_call_strat, EvmFuzzState,
         },
-        FuzzCase, FuzzedCases,
+        Budget, FuzzCase, FuzzedCases,
     },
     utils::{get_function, h176_to_b176},
     CALLER,
@@ -25,7 +25,7 @@ use corebc::{
 };
 use eyre::ContextCompat;
 use foxar_common::contracts::{ContractsByAddress, ContractsByArtifact};
-use foxar_config::{FuzzDictionaryConfig, InvariantConfig};
+use foxar_config::{FuzzDictionaryConfig, InvariantConfig, LimitsConfig};
 use hashbrown::HashMap;
 use parking_lot::{Mutex, RwLock};
 use proptest::{
@@ -57,6 +57,8 @@ pub struct InvariantExecutor<'a> {
     project_contracts: &'a ContractsByArtifact,
     /// Filters contracts to be fuzzed through their artifact identifiers.
     artifact_filters: ArtifactFilters,
+    /// The time limit and budgets of the fuzz campaign
+    limits: LimitsConfig,
 }
 
 impl<'a> InvariantExecutor<'a> {
@@ -75,9 +77,16 @@ impl<'a> InvariantExecutor<'a> {
             setup_contracts,
             project_contracts,
             artifact_filters: ArtifactFilters::default(),
+            limits: LimitsConfig::default(),
         }
     }
 
+    /// Stops the fuzz campaign once the time limit or a budget is hit, the test fails if it is.
+    pub fn with_limits(mut self, limits: LimitsConfig) -> Self {
+        self.limits = limits;
+        self
+    }
+
     /// Fuzzes any deployed contract and checks any broken invariant at `invariant_address`
     /// Returns a list of all the consumed energy and calldata of every invariant fuzz case
     pub fn invariant_fuzz(
@@ -95,6 +104,9 @@ impl<'a> InvariantExecutor<'a> {
 
         let blank_executor = RefCell::new(&mut *self.executor);
 
+        let budget = Budget::new(self.limits);
+        let limit_exceeded = RefCell::new(None);
+
         let last_call_results = RefCell::new(
             assert_invariants(
                 &invariant_contract,
@@ -128,6 +140,11 @@ impl<'a> InvariantExecutor<'a> {
                     {
                         return Err(TestCaseError::fail("All invariants have been broken."));
                     }
+
+                    if let Some(err) = budget.exceeded() {
+                        limit_exceeded.replace(Some(err.to_string()));
+                        return Err(TestCaseError::fail("Limit exceeded."));
+                    }
                 }
 
                 // Before each run, we must reset the backend state.
@@ -140,6 +157,12 @@ impl<'a> InvariantExecutor<'a> {
                 let mut created_contracts = vec![];
 
                 'fuzz_run: for _ in 0..self.config.depth {
+                    // A run that hits a limit is cut short and no further runs are started.
+                    if let Some(err) = budget.exceeded() {
+                        limit_exceeded.replace(Some(err.to_string()));
+                        break 'fuzz_run;
+                    }
+
                     let (sender, (address, calldata)) =
                         inputs.last().expect("to have the next randomly generated input.");
 
@@ -147,6 +170,7 @@ impl<'a> InvariantExecutor<'a> {
                     let call_result = executor
                         .call_raw(*sender, *address, calldata.0.clone(), U256::zero())
                         .expect("could not make raw evm call");
+                    budget.record(call_result.energy_used);
 
                     // Collect data for fuzzing from the state changeset.
                     let mut state_changeset =
@@ -229,6 +253,7 @@ impl<'a> InvariantExecutor<'a> {
             cases: fuzz_cases.into_inner(),
             reverts,
             last_call_results: last_call_results.take(),
+            limit_exceeded: limit_exceeded.into_inner(),
         })
     }
 
@@ -135,4 +135,7 @@ pub struct InvariantFuzzTestResult {
     pub reverts: usize,
 
     pub last_call_results: Option<BTreeMap<String, RawCallResult>>,
+
+    /// The reason the fuzz campaign was stopped early, if it hit the time limit or a budget
+    pub limit_exceeded: Option<String>,
 }
@@ -11,11 +11,19 @@ use corebc::{
 use error::{FuzzError, ASSUME_MAGIC_RETURN_CODE};
 use eyre::Result;
 use foxar_common::{calc, contracts::ContractsByAddress};
-use foxar_config::FuzzConfig;
+use foxar_config::{FuzzConfig, LimitsConfig};
 pub use proptest::test_runner::Reason;
-use proptest::test_runner::{TestCaseError, TestError, TestRunner};
+use proptest::{
+    strategy::Strategy,
+    test_runner::{TestCaseError, TestCaseResult, TestError, TestRunner},
+};
 use serde::{Deserialize, Serialize};
-use std::{cell::RefCell, collections::BTreeMap, fmt};
+use std::{
+    cell::{Cell, RefCell},
+    collections::BTreeMap,
+    fmt,
+    time::Instant,
+};
 use strategies::{
     build_initial_state, collect_state_from_call, fuzz_calldata, fuzz_calldata_from_state,
     EvmFuzzState,
@@ -39,6 +47,8 @@ pub struct FuzzedExecutor<'a> {
     sender: Address,
     /// The fuzz configuration
     config: FuzzConfig,
+    /// The time limit and budgets of the fuzz campaign
+    limits: LimitsConfig,
 }
 
 impl<'a> FuzzedExecutor<'a> {
@@ -49,7 +59,13 @@ impl<'a> FuzzedExecutor<'a> {
         sender: Address,
         config: FuzzConfig,
     ) -> Self {
-        Self { executor, runner, sender, config }
+        Self { executor, runner, sender, config, limits: LimitsConfig::default() }
+    }
+
+    /// Stops the fuzz campaign once the time limit or a budget is hit, the test fails if it is.
+    pub fn with_limits(mut self, limits: LimitsConfig) -> Self {
+        self.limits = limits;
+        self
     }
 
     /// Fuzzes the provided function, assuming it is available at the contract at `address`
@@ -102,11 +118,13 @@ impl<'a> FuzzedExecutor<'a> {
 
         let strat = proptest::strategy::Union::new_weighted(weights);
         debug!(func = ?func.name, should_fail, "fuzzing");
-        let run_result = self.runner.clone().run(&strat, |calldata| {
+        let budget = Budget::new(self.limits);
+        let test = |calldata: Bytes| -> TestCaseResult {
             let call = self
                 .executor
                 .call_raw(self.sender, address, calldata.0.clone(), 0.into())
                 .map_err(|_| TestCaseError::fail(FuzzError::FailedContractCall))?;
+            budget.record(call.energy_used);
             let state_changeset = call
                 .state_changeset
                 .as_ref()
@@ -170,12 +188,21 @@ impl<'a> FuzzedExecutor<'a> {
                     .unwrap_or_default(),
                 ))
             }
-        });
+        };
+        let mut exceeded = None;
+        let run_result = if self.limits.is_unlimited() {
+            self.runner.clone().run(&strat, test)
+        } else {
+            let (run_result, exceeded_limit) =
+                run_within(&mut self.runner.clone(), &strat, &budget, test);
+            exceeded = exceeded_limit;
+            run_result
+        };
         let (calldata, call) = counterexample.into_inner();
         let mut result = FuzzTestResult {
             first_case: first_case.take().unwrap_or_default(),
             energy_by_case: energy_by_case.take(),
-            success: run_result.is_ok(),
+            success: run_result.is_ok() && exceeded.is_none(),
             reason: None,
             counterexample: None,
             decoded_logs: decode_console_logs(&call.logs),
@@ -212,6 +239,9 @@ impl<'a> FuzzedExecutor<'a> {
                     args,
                 }));
             }
+            _ if exceeded.is_some() => {
+                result.reason = exceeded.map(|err| err.to_string());
+            }
             _ => {}
         }
 
@@ -219,6 +249,75 @@ impl<'a> FuzzedExecutor<'a> {
     }
 }
 
+/// Runs the cases of the `runner` like [`TestRunner::run`], but doesn't start new cases once the
+/// `budget` is exceeded.
+///
+/// Returns the result of the run and the exceeded limit, if any.
+fn run_within<S: Strategy>(
+    runner: &mut TestRunner,
+    strategy: &S,
+    budget: &Budget,
+    test: impl Fn(S::Value) -> TestCaseResult,
+) -> (Result<(), TestError<S::Value>>, Option<FuzzError>) {
+    let mut successes = 0;
+    while successes < runner.config().cases {
+        if let Some(err) = budget.exceeded() {
+            return (Ok(()), Some(err))
+        }
+        let case = match strategy.new_tree(runner) {
+            Ok(case) => case,
+            Err(reason) => return (Err(TestError::Abort(reason)), None),
+        };
+        // returns `false` if the case was rejected
+        match runner.run_one(case, &test) {
+            Ok(true) => successes += 1,
+            Ok(false) => {}
+            Err(err) => return (Err(err), None),
+        }
+    }
+    (Ok(()), None)
+}
+
+/// Tracks the time, energy and calls a fuzz campaign used against its [`LimitsConfig`].
+pub(crate) struct Budget {
+    limits: LimitsConfig,
+    deadline: Option<Instant>,
+    energy_used: Cell<u64>,
+    steps: Cell<u64>,
+}
+
+impl Budget {
+    /// Starts tracking the limits, the time limit runs from now on.
+    pub(crate) fn new(limits: LimitsConfig) -> Self {
+        let deadline = limits.duration().map(|timeout| Instant::now() + timeout);
+        Self { limits, deadline, energy_used: Cell::new(0), steps: Cell::new(0) }
+    }
+
+    /// Records a call that consumed `energy_used`.
+    pub(crate) fn record(&self, energy_used: u64) {
+        self.energy_used.set(self.energy_used.get().saturating_add(energy_used));
+        self.steps.set(self.steps.get() + 1);
+    }
+
+    /// Returns the error of the first limit that was hit, if any.
+    pub(crate) fn exceeded(&self) -> Option<FuzzError> {
+        if self.deadline.map_or(false, |deadline| Instant::now() >= deadline) {
+            return self.limits.duration().map(FuzzError::Timeout)
+        }
+        if let Some(budget) = self.limits.energy_budget {
+            if self.energy_used.get() >= budget {
+                return Some(FuzzError::EnergyBudget(budget))
+            }
+        }
+        if let Some(budget) = self.limits.step_budget {
+            if self.steps.get() >= budget {
+                return Some(FuzzError::StepBudget(budget))
+            }
+        }
+        None
+    }
+}
+
 #[derive(Clone, Debug, Serialize, Deserialize)]
 pub enum CounterExample {
     /// Call used as a counter example for fuzz tests.
@@ -34,9 +34,11 @@ pub(crate) fn decode_cheatcode_inputs(
             decode::decode_revert(data, Some(errors), None).ok().map(|decoded| vec![decoded])
         }
         "rememberKey" | "addr" | "startBroadcast" | "broadcast" => {
-            // these functions accept a private key as uint256, which should not be
+            // these functions accept an ED448 private key as hex string, which should not be
             // converted to plain text
-            if !func.inputs.is_empty() && matches!(&func.inputs[0].kind, ParamType::Uint(_)) {
+            if !func.inputs.is_empty() &&
+                matches!(&func.inputs[0].kind, ParamType::Uint(_) | ParamType::String)
+            {
                 // redact private key input
                 Some(vec!["<pk>".to_string()])
             } else {
@@ -44,23 +46,25 @@ pub(crate) fn decode_cheatcode_inputs(
             }
         }
         "sign" => {
-            // sign(uint256,bytes32)
+            // sign(string,bytes32)
             let mut decoded = func.decode_input(&data[SELECTOR_LEN..]).ok()?;
-            if !decoded.is_empty() && matches!(&func.inputs[0].kind, ParamType::Uint(_)) {
+            if !decoded.is_empty() &&
+                matches!(&func.inputs[0].kind, ParamType::Uint(_) | ParamType::String)
+            {
                 decoded[0] = Token::String("<pk>".to_string());
             }
             Some(decoded.iter().map(format_token).collect())
         }
         "deriveKey" => Some(vec!["<pk>".to_string()]),
-        "parseJson" | "writeJson" => {
+        "parseJson" | "writeJson" | "parseToml" | "writeToml" => {
             if verbosity == 5 {
                 None
             } else {
                 let mut decoded = func.decode_input(&data[SELECTOR_LEN..]).ok()?;
-                let token = if func.name.as_str() == "parseJson" {
-                    "<JSON file>"
-                } else {
-                    "<stringified JSON>"
+                let token = match func.name.as_str() {
+                    "parseJson" => "<JSON file>",
+                    "parseToml" => "<TOML file>",
+                    _ => "<stringified JSON>",
                 };
                 decoded[0] = Token::String(token.to_string());
                 Some(decoded.iter().map(format_token).collect())
@@ -84,9 +88,16 @@ pub(crate) fn decode_cheatcode_outputs(
         // redacts derived private key
         return Some("<pk>".to_string());
     }
+    if func.name == "createWallet" {
+        // redacts the private key of the created wallet
+        return Some("<wallet>".to_string());
+    }
     if func.name == "parseJson" && verbosity != 5 {
         return Some("<encoded JSON value>".to_string());
     }
+    if func.name == "parseToml" && verbosity != 5 {
+        return Some("<encoded TOML value>".to_string());
+    }
     if func.name == "readFile" && verbosity != 5 {
         return Some("<file>".to_string());
     }
@@ -1,6 +1,15 @@
 use comfy_table::{presets::ASCII_MARKDOWN, Attribute, Cell, Color, Row, Table};
+use eyre::WrapErr;
+use foxar_config::CoverageThreshold;
 pub use foxar_evm::coverage::*;
-use std::io::Write;
+use serde::{Deserialize, Serialize};
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    fmt::Write as _,
+    fs,
+    io::Write,
+    path::{Component, Path, PathBuf},
+};
 
 /// A coverage reporter.
 pub trait CoverageReporter {
@@ -134,6 +143,486 @@ impl<'a> CoverageReporter for LcovReporter<'a> {
     }
 }
 
+/// A reporter that writes a self-contained HTML report.
+///
+/// The report consists of a summary page, `index.html`, and a page per source file with the
+/// annotated source, its branches and functions.
+pub struct HtmlReporter {
+    /// The project root, the source paths of the report are relative to it
+    root: PathBuf,
+    /// The directory the report is written to
+    out_dir: PathBuf,
+}
+
+impl HtmlReporter {
+    pub fn new(root: impl Into<PathBuf>, out_dir: impl Into<PathBuf>) -> Self {
+        Self { root: root.into(), out_dir: out_dir.into() }
+    }
+}
+
+impl CoverageReporter for HtmlReporter {
+    fn report(self, report: &CoverageReport) -> eyre::Result<()> {
+        remove_pages(&self.out_dir)?;
+
+        let mut files = Vec::new();
+        let mut total = CoverageSummary::default();
+        for (path, items) in report.items_by_source() {
+            let summary = items.iter().fold(CoverageSummary::default(), |mut summary, item| {
+                summary += item;
+                summary
+            });
+            total += &summary;
+
+            let source = fs::read_to_string(self.root.join(&path))
+                .wrap_err_with(|| format!("Could not read source code of {path}"))?;
+            let page = page_path(&path);
+            write_page(&self.out_dir.join(&page), &html_file(&path, &source, &items, &summary))?;
+            files.push((path, page, summary));
+        }
+
+        let index = self.out_dir.join("index.html");
+        write_page(&index, &html_index(&files, &total))?;
+        println!("Wrote HTML report to {}.", index.display());
+
+        Ok(())
+    }
+}
+
+/// The styles of all pages
+const HTML_STYLE: &str = "body{font-family:sans-serif;margin:2em}\
+table{border-collapse:collapse}td,th{padding:2px 8px;text-align:left}\
+.summary td,.summary th,.functions td,.functions th{border:1px solid #ccc}\
+.low{background:#f4b6b6}.medium{background:#f6e3a1}.high{background:#bde5b4}\
+.source{font-family:monospace;white-space:pre}.source td{padding:0 8px}\
+.ln,.hits{color:#888;text-align:right}\
+.hit{background:#e6f5e1}.miss{background:#fbe2e2}\
+.taken{color:#2a7d1c}.not-taken{color:#c0392b;font-weight:bold}";
+
+/// Returns the path of the page of a source file, relative to the report directory.
+///
+/// Parent directories are replaced with `__` and absolute paths are made relative, so the pages of
+/// sources outside the project are written within the report directory too.
+fn page_path(path: &str) -> String {
+    let mut page = Path::new(path)
+        .components()
+        .filter_map(|component| match component {
+            Component::Normal(name) => Some(name.to_string_lossy().into_owned()),
+            Component::ParentDir => Some("__".to_string()),
+            _ => None,
+        })
+        .collect::<Vec<_>>()
+        .join("/");
+    page.push_str(".html");
+    page
+}
+
+/// Removes the pages of an earlier report from `dir`, along with the directories they leave empty.
+fn remove_pages(dir: &Path) -> eyre::Result<()> {
+    if !dir.is_dir() {
+        return Ok(())
+    }
+    for entry in fs::read_dir(dir)? {
+        let path = entry?.path();
+        if path.is_dir() {
+            remove_pages(&path)?;
+            if fs::read_dir(&path)?.next().is_none() {
+                fs::remove_dir(&path)?;
+            }
+        } else if path.extension().map_or(false, |ext| ext == "html") {
+            fs::remove_file(&path)
+                .wrap_err_with(|| format!("Could not remove {}", path.display()))?;
+        }
+    }
+    Ok(())
+}
+
+fn write_page(path: &Path, html: &str) -> eyre::Result<()> {
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    fs::write(path, html).wrap_err_with(|| format!("Could not write {}", path.display()))
+}
+
+/// Renders the summary page.
+fn html_index(files: &[(String, String, CoverageSummary)], total: &CoverageSummary) -> String {
+    let mut html = html_header("Coverage report");
+    html.push_str("<h1>Coverage report</h1>\n");
+    html.push_str(
+        "<table class=\"summary\">\n<tr><th>File</th><th>% Lines</th><th>% Statements</th>\
+         <th>% Branches</th><th>% Funcs</th></tr>\n",
+    );
+    for (path, page, summary) in files {
+        let name = format!("<a href=\"{}\">{}</a>", escape_html(page), escape_html(path));
+        html_summary_row(&mut html, &name, summary);
+    }
+    html_summary_row(&mut html, "<b>Total</b>", total);
+    html.push_str("</table>\n</body>\n</html>\n");
+    html
+}
+
+/// Renders the page of a source file.
+fn html_file(
+    path: &str,
+    source: &str,
+    items: &[CoverageItem],
+    summary: &CoverageSummary,
+) -> String {
+    let depth = page_path(path).matches('/').count();
+    let mut html = html_header(path);
+    let _ = writeln!(
+        html,
+        "<p><a href=\"{}index.html\">All files</a></p>\n<h1>{}</h1>",
+        "../".repeat(depth),
+        escape_html(path)
+    );
+
+    html.push_str(
+        "<table class=\"summary\">\n<tr><th></th><th>% Lines</th><th>% Statements</th>\
+         <th>% Branches</th><th>% Funcs</th></tr>\n",
+    );
+    html_summary_row(&mut html, "Total", summary);
+    html.push_str("</table>\n");
+
+    // The hit counts of executable lines and the paths of the branches, by line
+    let mut lines = BTreeMap::<usize, u64>::new();
+    let mut branches = BTreeMap::<usize, Vec<(usize, usize, u64)>>::new();
+    let mut functions = Vec::new();
+    for item in items {
+        match &item.kind {
+            CoverageItemKind::Line => {
+                let hits = lines.entry(item.loc.line).or_default();
+                *hits = (*hits).max(item.hits);
+            }
+            CoverageItemKind::Branch { branch_id, path_id } => {
+                branches.entry(item.loc.line).or_default().push((*branch_id, *path_id, item.hits))
+            }
+            CoverageItemKind::Function { name } => functions.push((
+                format!("{}.{name}", item.loc.contract_name),
+                item.loc.line,
+                item.hits,
+            )),
+            CoverageItemKind::Statement => {}
+        }
+    }
+
+    if !functions.is_empty() {
+        html.push_str(
+            "<h2>Functions</h2>\n<table class=\"functions\">\n\
+             <tr><th>Function</th><th>Line</th><th>Hits</th></tr>\n",
+        );
+        for (name, line, hits) in functions {
+            let class = if hits > 0 { "hit" } else { "miss" };
+            let _ = writeln!(
+                html,
+                "<tr class=\"{class}\"><td>{}</td><td><a href=\"#L{line}\">{line}</a></td>\
+                 <td>{hits}</td></tr>",
+                escape_html(&name)
+            );
+        }
+        html.push_str("</table>\n");
+    }
+
+    html.push_str("<h2>Source</h2>\n<table class=\"source\">\n");
+    for (i, code) in source.lines().enumerate() {
+        let line = i + 1;
+        let hits = lines.get(&line);
+        let class = match hits {
+            Some(0) => " class=\"miss\"",
+            Some(_) => " class=\"hit\"",
+            None => "",
+        };
+        let hits = hits.map(|hits| hits.to_string()).unwrap_or_default();
+
+        let mut markers = String::new();
+        for (branch_id, path_id, hits) in branches.get(&line).into_iter().flatten() {
+            let (class, marker, taken) = if *hits > 0 {
+                ("taken", "+", format!("taken {hits} times"))
+            } else {
+                ("not-taken", "-", "not taken".to_string())
+            };
+            let _ = write!(
+                markers,
+                "<span class=\"{class}\" title=\"branch {branch_id}, path {path_id}: {taken}\">\
+                 [{marker}]</span>"
+            );
+        }
+
+        let _ = writeln!(
+            html,
+            "<tr id=\"L{line}\"{class}><td class=\"ln\">{line}</td><td class=\"hits\">{hits}</td>\
+             <td>{markers}</td><td>{}</td></tr>",
+            escape_html(code)
+        );
+    }
+    html.push_str("</table>\n</body>\n</html>\n");
+    html
+}
+
+fn html_header(title: &str) -> String {
+    format!(
+        "<!DOCTYPE html>\n<html>\n<head>\n<meta charset=\"utf-8\">\n<title>{}</title>\n\
+         <style>{HTML_STYLE}</style>\n</head>\n<body>\n",
+        escape_html(title)
+    )
+}
+
+fn html_summary_row(html: &mut String, name: &str, summary: &CoverageSummary) {
+    let _ = writeln!(
+        html,
+        "<tr><td>{name}</td>{}{}{}{}</tr>",
+        html_percentage(summary.line_hits, summary.line_count),
+        html_percentage(summary.statement_hits, summary.statement_count),
+        html_percentage(summary.branch_hits, summary.branch_count),
+        html_percentage(summary.function_hits, summary.function_count),
+    );
+}
+
+/// Renders a table cell with the percentage, colored like the cells of the [SummaryReporter].
+fn html_percentage(hits: usize, total: usize) -> String {
+    let percentage = if total == 0 { 1. } else { hits as f64 / total as f64 };
+    let class = match percentage {
+        _ if total == 0 => "",
+        _ if percentage < 0.5 => "low",
+        _ if percentage < 0.75 => "medium",
+        _ => "high",
+    };
+    format!("<td class=\"{class}\">{:.2}% ({hits}/{total})</td>", percentage * 100.)
+}
+
+fn escape_html(s: &str) -> String {
+    let mut escaped = String::with_capacity(s.len());
+    for c in s.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+/// The coverage of a source file in a [CoverageBaseline]
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FileCoverage {
+    pub summary: CoverageSummary,
+    /// The executable lines that weren't hit, by line number, with their normalized source code
+    pub uncovered_lines: BTreeMap<usize, String>,
+}
+
+/// The coverage of a project, saved to compare later runs against
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CoverageBaseline {
+    /// The coverage by source file path
+    pub files: BTreeMap<String, FileCoverage>,
+}
+
+impl CoverageBaseline {
+    /// Creates the baseline of the report, the source paths of the report are relative to `root`.
+    pub fn new(report: &CoverageReport, root: impl AsRef<Path>) -> Self {
+        let root = root.as_ref();
+        Self::with_sources(report, |path| fs::read_to_string(root.join(path)).ok())
+    }
+
+    /// Creates the baseline of the report, reading the source code of a path with `source`.
+    pub fn with_sources(report: &CoverageReport, source: impl Fn(&str) -> Option<String>) -> Self {
+        let files = report
+            .items_by_source()
+            .map(|(path, items)| {
+                let source = source(&path).unwrap_or_default();
+                let lines = source.lines().collect::<Vec<_>>();
+                let mut file = FileCoverage::default();
+                for item in &items {
+                    file.summary += item;
+                    if matches!(item.kind, CoverageItemKind::Line) && item.hits == 0 {
+                        let code = item
+                            .loc
+                            .line
+                            .checked_sub(1)
+                            .and_then(|index| lines.get(index))
+                            .map(|line| line.split_whitespace().collect::<Vec<_>>().join(" "))
+                            .unwrap_or_default();
+                        file.uncovered_lines.insert(item.loc.line, code);
+                    }
+                }
+                (path, file)
+            })
+            .collect();
+        Self { files }
+    }
+
+    pub fn read(path: impl AsRef<Path>) -> eyre::Result<Self> {
+        let path = path.as_ref();
+        foxar_common::fs::read_json_file(path)
+            .wrap_err_with(|| format!("Could not read coverage baseline {}", path.display()))
+    }
+
+    pub fn write(&self, path: impl AsRef<Path>) -> eyre::Result<()> {
+        Ok(foxar_common::fs::write_json_file(path.as_ref(), self)?)
+    }
+
+    /// Returns the lines that are uncovered in `self`, but weren't in the `baseline`.
+    ///
+    /// Lines are matched by their source code rather than their number, so lines that merely
+    /// moved aren't new. All uncovered lines of files that aren't in the `baseline` are new.
+    pub fn newly_uncovered_lines<'a>(
+        &'a self,
+        baseline: &'a CoverageBaseline,
+    ) -> impl Iterator<Item = (&'a String, usize)> + 'a {
+        self.files.iter().flat_map(move |(path, file)| {
+            // the number of uncovered occurrences of each line in the baseline
+            let mut old = BTreeMap::<&str, usize>::new();
+            for code in
+                baseline.files.get(path).into_iter().flat_map(|f| f.uncovered_lines.values())
+            {
+                *old.entry(code).or_default() += 1;
+            }
+            file.uncovered_lines
+                .iter()
+                .filter(move |(_, code)| match old.get_mut(code.as_str()) {
+                    Some(count) if *count > 0 => {
+                        *count -= 1;
+                        false
+                    }
+                    _ => true,
+                })
+                .map(move |(line, _)| (path, *line))
+        })
+    }
+}
+
+/// A reporter that prints the change of the coverage compared to a baseline.
+pub struct BaselineReporter {
+    baseline: CoverageBaseline,
+    /// The project root, the source paths of the report are relative to it
+    root: PathBuf,
+}
+
+impl BaselineReporter {
+    pub fn new(baseline: CoverageBaseline, root: impl Into<PathBuf>) -> Self {
+        Self { baseline, root: root.into() }
+    }
+}
+
+impl CoverageReporter for BaselineReporter {
+    fn report(self, report: &CoverageReport) -> eyre::Result<()> {
+        let current = CoverageBaseline::new(report, &self.root);
+
+        let mut table = Table::new();
+        table.load_preset(ASCII_MARKDOWN);
+        table.set_header(["File", "Δ Lines", "Δ Statements", "Δ Branches", "Δ Funcs"]);
+        let empty = FileCoverage::default();
+        let paths = current.files.keys().chain(self.baseline.files.keys()).collect::<BTreeSet<_>>();
+        for path in paths {
+            let old = &self.baseline.files.get(path).unwrap_or(&empty).summary;
+            let Some(file) = current.files.get(path) else {
+                // the file was deleted since the baseline was saved
+                let deleted = |hits, total| {
+                    Cell::new(format!("deleted (was {:.2}%)", percentage(hits, total)))
+                        .add_attribute(Attribute::Dim)
+                };
+                table.add_row([
+                    Cell::new(path),
+                    deleted(old.line_hits, old.line_count),
+                    deleted(old.statement_hits, old.statement_count),
+                    deleted(old.branch_hits, old.branch_count),
+                    deleted(old.function_hits, old.function_count),
+                ]);
+                continue
+            };
+            let new = &file.summary;
+            table.add_row([
+                Cell::new(path),
+                format_delta(old.line_hits, old.line_count, new.line_hits, new.line_count),
+                format_delta(
+                    old.statement_hits,
+                    old.statement_count,
+                    new.statement_hits,
+                    new.statement_count,
+                ),
+                format_delta(old.branch_hits, old.branch_count, new.branch_hits, new.branch_count),
+                format_delta(
+                    old.function_hits,
+                    old.function_count,
+                    new.function_hits,
+                    new.function_count,
+                ),
+            ]);
+        }
+        println!("{table}");
+
+        let uncovered = current.newly_uncovered_lines(&self.baseline).collect::<Vec<_>>();
+        if !uncovered.is_empty() {
+            println!();
+            println!("Newly uncovered lines:");
+            for (path, line) in uncovered {
+                println!("- {path}:{line}");
+            }
+        }
+
+        Ok(())
+    }
+}
+
+fn format_delta(old_hits: usize, old_total: usize, hits: usize, total: usize) -> Cell {
+    let delta = percentage(hits, total) - percentage(old_hits, old_total);
+    let cell = Cell::new(format!("{:.2}% ({delta:+.2}%)", percentage(hits, total)));
+    match delta {
+        _ if delta.abs() < 0.005 => cell.add_attribute(Attribute::Dim),
+        _ if delta < 0. => cell.fg(Color::Red),
+        _ => cell.fg(Color::Green),
+    }
+}
+
+/// Returns the covered percentage, 100 if there is nothing to cover.
+fn percentage(hits: usize, total: usize) -> f64 {
+    if total == 0 {
+        100.
+    } else {
+        hits as f64 * 100. / total as f64
+    }
+}
+
+/// Returns a message for every threshold the coverage of the report is below, and for every
+/// threshold that doesn't match any source file of the report.
+pub fn threshold_violations(
+    report: &CoverageReport,
+    thresholds: &[CoverageThreshold],
+) -> Vec<String> {
+    let summaries = report.summary_by_file().collect::<Vec<_>>();
+    let mut violations = Vec::new();
+    for threshold in thresholds {
+        let mut summary = CoverageSummary::default();
+        let mut matched = false;
+        for (_, file) in summaries.iter().filter(|(path, _)| threshold.is_match(path)) {
+            summary += file;
+            matched = true;
+        }
+        // A mistyped pattern would otherwise always pass
+        if !matched {
+            violations.push(format!("{}: doesn't match any source file", threshold.path));
+            continue
+        }
+
+        let lines = percentage(summary.line_hits, summary.line_count);
+        if let Some(min) = threshold.min_lines.filter(|min| lines < *min) {
+            violations.push(format!(
+                "{}: {lines:.2}% of lines are covered, the minimum is {min:.2}%",
+                threshold.path
+            ));
+        }
+        let branches = percentage(summary.branch_hits, summary.branch_count);
+        if let Some(min) = threshold.min_branches.filter(|min| branches < *min) {
+            violations.push(format!(
+                "{}: {branches:.2}% of branches are covered, the minimum is {min:.2}%",
+                threshold.path
+            ));
+        }
+    }
+    violations
+}
+
 /// A super verbose reporter for debugging coverage while it is still unstable.
 pub struct DebugReporter;
 
@@ -168,3 +657,121 @@ impl CoverageReporter for DebugReporter {
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn item(kind: CoverageItemKind, line: usize, hits: u64) -> CoverageItem {
+        CoverageItem {
+            kind,
+            loc: SourceLocation {
+                source_id: 0,
+                contract_name: "Counter".to_string(),
+                start: 0,
+                length: None,
+                line,
+            },
+            hits,
+        }
+    }
+
+    fn report(items: Vec<CoverageItem>) -> CoverageReport {
+        let version = semver::Version::new(1, 1, 0);
+        let mut report = CoverageReport::default();
+        report.add_source(version.clone(), 0, "src/Counter.sol".to_string());
+        report.add_items(version, items);
+        report
+    }
+
+    #[test]
+    fn can_find_newly_uncovered_lines() {
+        let baseline = CoverageBaseline::with_sources(
+            &report(vec![item(CoverageItemKind::Line, 3, 0), item(CoverageItemKind::Line, 4, 1)]),
+            |_| Some("a\nb;\nc;\nd;\n".to_string()),
+        );
+        assert_eq!(baseline.files["src/Counter.sol"].uncovered_lines[&3], "c;");
+
+        // a line was inserted above, `c;` moved to line 4 and is still uncovered
+        let current = CoverageBaseline::with_sources(
+            &report(vec![
+                item(CoverageItemKind::Line, 4, 0),
+                item(CoverageItemKind::Line, 5, 0),
+                item(CoverageItemKind::Line, 2, 2),
+            ]),
+            |_| Some("a\n  new;\nb;\n  c;\nd;\n".to_string()),
+        );
+
+        let file = &current.files["src/Counter.sol"];
+        assert_eq!(file.summary.line_count, 3);
+        assert_eq!(file.summary.line_hits, 1);
+        let uncovered = current.newly_uncovered_lines(&baseline).collect::<Vec<_>>();
+        assert_eq!(uncovered, [(&"src/Counter.sol".to_string(), 5)]);
+        assert_eq!(current.newly_uncovered_lines(&CoverageBaseline::default()).count(), 2);
+    }
+
+    #[test]
+    fn can_check_thresholds() {
+        let report = report(vec![
+            item(CoverageItemKind::Line, 3, 0),
+            item(CoverageItemKind::Line, 4, 1),
+            item(CoverageItemKind::Branch { branch_id: 0, path_id: 0 }, 3, 1),
+            item(CoverageItemKind::Branch { branch_id: 0, path_id: 1 }, 3, 1),
+        ]);
+
+        assert!(threshold_violations(&report, &[CoverageThreshold::all(Some(50.), Some(100.))])
+            .is_empty());
+        let violations = threshold_violations(
+            &report,
+            &[
+                CoverageThreshold::all(Some(60.), None),
+                CoverageThreshold {
+                    path: "test/**".to_string(),
+                    min_lines: Some(100.),
+                    min_branches: None,
+                },
+            ],
+        );
+        assert_eq!(
+            violations,
+            [
+                "**: 50.00% of lines are covered, the minimum is 60.00%",
+                "test/**: doesn't match any source file",
+            ]
+        );
+    }
+
+    #[test]
+    fn html_pages_stay_in_report_dir() {
+        assert_eq!(page_path("src/Counter.sol"), "src/Counter.sol.html");
+        assert_eq!(page_path("../lib/Token.sol"), "__/lib/Token.sol.html");
+        assert_eq!(page_path("src/../../Token.sol"), "src/__/__/Token.sol.html");
+        assert_eq!(page_path("/usr/lib/Token.sol"), "usr/lib/Token.sol.html");
+    }
+
+    #[test]
+    fn can_render_html_file() {
+        let source =
+            "contract Counter {\n    function inc() public {\n        if (a < b) {}\n    }\n}";
+        let items = [
+            item(CoverageItemKind::Function { name: "inc".to_string() }, 2, 3),
+            item(CoverageItemKind::Line, 3, 3),
+            item(CoverageItemKind::Branch { branch_id: 0, path_id: 0 }, 3, 3),
+            item(CoverageItemKind::Branch { branch_id: 0, path_id: 1 }, 3, 0),
+        ];
+        let summary = items.iter().fold(CoverageSummary::default(), |mut summary, item| {
+            summary += item;
+            summary
+        });
+
+        let html = html_file("src/Counter.sol", source, &items, &summary);
+        assert!(html.contains(r#"<a href="../index.html">All files</a>"#));
+        assert!(html.contains("<td>Counter.inc</td>"));
+        assert!(html
+            .contains(r#"<tr id="L3" class="hit"><td class="ln">3</td><td class="hits">3</td>"#));
+        assert!(html.contains(r#"title="branch 0, path 0: taken 3 times">[+]</span>"#));
+        assert!(html.contains(r#"title="branch 0, path 1: not taken">[-]</span>"#));
+        assert!(html.contains("if (a &lt; b) {}"));
+        assert!(html.contains(r#"<td class="medium">50.00% (1/2)</td>"#));
+    }
+}
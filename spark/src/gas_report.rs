@@ -98,6 +98,26 @@ impl GasReport {
         });
     }
 
+    /// Merges the calls of another report into this one, e.g. the reports of sharded test runs.
+    ///
+    /// The merged report has to be finalized again, see [`Self::finalize`].
+    pub fn merge(&mut self, other: GasReport) {
+        for (name, other) in other.contracts {
+            let contract = self.contracts.entry(name).or_default();
+            // the contract may not have been deployed in every run
+            if !other.gas.is_zero() {
+                contract.gas = other.gas;
+                contract.size = other.size;
+            }
+            for (func, sigs) in other.functions {
+                let functions = contract.functions.entry(func).or_default();
+                for (sig, info) in sigs {
+                    functions.entry(sig).or_default().calls.extend(info.calls);
+                }
+            }
+        }
+    }
+
     #[must_use]
     pub fn finalize(mut self) -> Self {
         self.contracts.iter_mut().for_each(|(_, contract)| {
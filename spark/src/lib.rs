@@ -1,10 +1,11 @@
 use corebc::ylem::ProjectCompileOutput;
 use foxar_config::{
     validate_profiles, Config, FuzzConfig, InlineConfig, InlineConfigError, InlineConfigParser,
-    InvariantConfig, NatSpec,
+    InvariantConfig, LimitsConfig, NatSpec,
 };
 use proptest::test_runner::{RngAlgorithm, TestRng, TestRunner};
-use std::path::Path;
+use regex::Regex;
+use std::path::{Path, PathBuf};
 
 #[macro_use]
 extern crate tracing;
@@ -15,6 +16,12 @@ pub mod gas_report;
 /// Coverage reports
 pub mod coverage;
 
+/// Test result reporters
+pub mod reporter;
+
+/// Tables of arguments for parameterized tests
+pub mod table;
+
 /// The Forge test runner
 mod runner;
 pub use runner::ContractRunner;
@@ -44,6 +51,16 @@ pub struct TestOptions {
     pub inline_fuzz: InlineConfig<FuzzConfig>,
     /// Contains per-test specific "invariant" configurations.
     pub inline_invariant: InlineConfig<InvariantConfig>,
+    /// The base time limit and budgets of fuzz and invariant tests.
+    pub limits: LimitsConfig,
+    /// Contains per-test specific time limits and budgets.
+    pub inline_limits: InlineConfig<LimitsConfig>,
+    /// The directory holding the JSON and CSV tables of parameterized tests, if any.
+    pub fixtures: Option<PathBuf>,
+    /// How many times a failing test is retried. Tests that pass on a retry are marked as flaky.
+    pub retries: u32,
+    /// Patterns of quarantined tests, matched against `<contract id>:<test signature>`.
+    pub quarantine: Vec<Regex>,
 }
 
 impl TestOptions {
@@ -105,6 +122,26 @@ impl TestOptions {
         self.inline_invariant.get(contract_id, test_fn).unwrap_or(&self.invariant)
     }
 
+    /// Returns the time limit and budgets of a fuzz or invariant test. Per-test limits take
+    /// precedence over the base limits.
+    ///
+    /// - `contract_id` is the id of the test contract, expressed as a relative path from the
+    ///   project root.
+    /// - `test_fn` is the name of the test function declared inside the test contract.
+    pub fn limits<S>(&self, contract_id: S, test_fn: S) -> LimitsConfig
+    where
+        S: Into<String>,
+    {
+        *self.inline_limits.get(contract_id, test_fn).unwrap_or(&self.limits)
+    }
+
+    /// Returns whether the test of the given contract is quarantined, i.e. its failure doesn't
+    /// fail the test run.
+    pub fn is_quarantined(&self, contract_id: &str, test: &str) -> bool {
+        let id = format!("{contract_id}:{test}");
+        self.quarantine.iter().any(|re| re.is_match(&id))
+    }
+
     pub fn fuzzer_with_cases(&self, cases: u32) -> TestRunner {
         // TODO: Add Options to modify the persistence
         let cfg = proptest::test_runner::Config {
@@ -127,8 +164,15 @@ impl TestOptions {
     }
 }
 
-impl<'a, P> TryFrom<(&'a ProjectCompileOutput, &'a P, Vec<String>, FuzzConfig, InvariantConfig)>
-    for TestOptions
+impl<'a, P>
+    TryFrom<(
+        &'a ProjectCompileOutput,
+        &'a P,
+        Vec<String>,
+        FuzzConfig,
+        InvariantConfig,
+        LimitsConfig,
+    )> for TestOptions
 where
     P: AsRef<Path>,
 {
@@ -143,24 +187,35 @@ where
     /// runtime. 3. List of available configuration profiles
     /// 4. Reference to a fuzz base configuration.
     /// 5. Reference to an invariant base configuration.
+    /// 6. Reference to a base time limit and budgets configuration.
     fn try_from(
-        value: (&'a ProjectCompileOutput, &'a P, Vec<String>, FuzzConfig, InvariantConfig),
+        value: (
+            &'a ProjectCompileOutput,
+            &'a P,
+            Vec<String>,
+            FuzzConfig,
+            InvariantConfig,
+            LimitsConfig,
+        ),
     ) -> Result<Self, Self::Error> {
         let output = value.0;
         let root = value.1;
         let profiles = &value.2;
         let base_fuzz: FuzzConfig = value.3;
         let base_invariant: InvariantConfig = value.4;
+        let base_limits: LimitsConfig = value.5;
 
         let natspecs: Vec<NatSpec> = NatSpec::parse(output, root);
         let mut inline_invariant = InlineConfig::<InvariantConfig>::default();
         let mut inline_fuzz = InlineConfig::<FuzzConfig>::default();
+        let mut inline_limits = InlineConfig::<LimitsConfig>::default();
 
         for natspec in natspecs {
             // Perform general validation
             validate_profiles(&natspec, profiles)?;
             FuzzConfig::validate_configs(&natspec)?;
             InvariantConfig::validate_configs(&natspec)?;
+            LimitsConfig::validate_configs(&natspec)?;
 
             // Apply in-line configurations for the current profile
             let configs: Vec<String> = natspec.current_profile_configs();
@@ -179,9 +234,25 @@ where
                 Err(e) => Err(InlineConfigError { line: line.clone(), source: e })?,
                 _ => { /* No inline config found, do nothing */ }
             }
+
+            match base_limits.try_merge(&configs) {
+                Ok(Some(conf)) => inline_limits.insert(c, f, conf),
+                Err(e) => Err(InlineConfigError { line: line.clone(), source: e })?,
+                _ => { /* No inline config found, do nothing */ }
+            }
         }
 
-        Ok(Self { fuzz: base_fuzz, invariant: base_invariant, inline_fuzz, inline_invariant })
+        Ok(Self {
+            fuzz: base_fuzz,
+            invariant: base_invariant,
+            inline_fuzz,
+            inline_invariant,
+            limits: base_limits,
+            inline_limits,
+            fixtures: None,
+            retries: 0,
+            quarantine: vec![],
+        })
     }
 }
 
@@ -190,8 +261,12 @@ where
 pub struct TestOptionsBuilder {
     fuzz: Option<FuzzConfig>,
     invariant: Option<InvariantConfig>,
+    limits: Option<LimitsConfig>,
     profiles: Option<Vec<String>>,
     output: Option<ProjectCompileOutput>,
+    fixtures: Option<PathBuf>,
+    retries: u32,
+    quarantine: Vec<Regex>,
 }
 
 impl TestOptionsBuilder {
@@ -209,6 +284,12 @@ impl TestOptionsBuilder {
         self
     }
 
+    /// Sets the base time limit and budgets of fuzz and invariant tests.
+    pub fn limits(mut self, limits: LimitsConfig) -> Self {
+        self.limits = Some(limits);
+        self
+    }
+
     /// Sets available configuration profiles. Profiles are useful to validate existing in-line
     /// configurations. This argument is necessary in case a `compile_output`is provided.
     pub fn profiles(mut self, p: Vec<String>) -> Self {
@@ -224,6 +305,24 @@ impl TestOptionsBuilder {
         self
     }
 
+    /// Sets the directory holding the JSON and CSV tables of parameterized tests.
+    pub fn fixtures(mut self, dir: impl Into<PathBuf>) -> Self {
+        self.fixtures = Some(dir.into());
+        self
+    }
+
+    /// Sets how many times a failing test is retried.
+    pub fn retries(mut self, retries: u32) -> Self {
+        self.retries = retries;
+        self
+    }
+
+    /// Sets the patterns of quarantined tests.
+    pub fn quarantine(mut self, patterns: impl IntoIterator<Item = impl Into<Regex>>) -> Self {
+        self.quarantine = patterns.into_iter().map(Into::into).collect();
+        self
+    }
+
     /// Creates an instance of [`TestOptions`]. This takes care of creating "fuzz" and
     /// "invariant" fallbacks, and extracting all inline test configs, if available.
     ///
@@ -235,21 +334,34 @@ impl TestOptionsBuilder {
         let profiles: Vec<String> = self.profiles.unwrap_or(default_profiles);
         let base_fuzz = self.fuzz.unwrap_or_default();
         let base_invariant = self.invariant.unwrap_or_default();
+        let base_limits = self.limits.unwrap_or_default();
 
-        match self.output {
-            Some(compile_output) => Ok(TestOptions::try_from((
+        let options = match self.output {
+            Some(compile_output) => TestOptions::try_from((
                 &compile_output,
                 &root,
                 profiles,
                 base_fuzz,
                 base_invariant,
-            ))?),
-            None => Ok(TestOptions {
+                base_limits,
+            ))?,
+            None => TestOptions {
                 fuzz: base_fuzz,
                 invariant: base_invariant,
                 inline_fuzz: InlineConfig::default(),
                 inline_invariant: InlineConfig::default(),
-            }),
-        }
+                limits: base_limits,
+                inline_limits: InlineConfig::default(),
+                fixtures: None,
+                retries: 0,
+                quarantine: vec![],
+            },
+        };
+        Ok(TestOptions {
+            fixtures: self.fixtures,
+            retries: self.retries,
+            quarantine: self.quarantine,
+            ..options
+        })
     }
 }
@@ -60,8 +60,11 @@ impl MultiContractRunner {
                 filter.matches_path(id.source.to_string_lossy()) &&
                     filter.matches_contract(&id.name)
             })
-            .flat_map(|(_, (abi, _, _))| {
-                abi.functions().filter(|func| filter.matches_test(func.signature()))
+            .flat_map(|(id, (abi, _, _))| {
+                abi.functions().filter(move |func| {
+                    filter.matches_test(func.signature()) &&
+                        filter.matches_contract_test(&id.identifier(), &func.signature())
+                })
             })
             .count()
     }
@@ -94,10 +97,12 @@ impl MultiContractRunner {
             .map(|(id, (abi, _, _))| {
                 let source = id.source.as_path().display().to_string();
                 let name = id.name.clone();
+                let identifier = id.identifier();
                 let tests = abi
                     .functions()
                     .filter(|func| func.name.is_test())
                     .filter(|func| filter.matches_test(func.signature()))
+                    .filter(|func| filter.matches_contract_test(&identifier, &func.signature()))
                     .map(|func| func.name.clone())
                     .collect::<Vec<_>>();
 
@@ -133,7 +138,13 @@ impl MultiContractRunner {
                 filter.matches_path(id.source.to_string_lossy()) &&
                     filter.matches_contract(&id.name)
             })
-            .filter(|(_, (abi, _, _))| abi.functions().any(|func| filter.matches_test(&func.name)))
+            .filter(|(id, (abi, _, _))| {
+                let identifier = id.identifier();
+                abi.functions().any(|func| {
+                    filter.matches_test(&func.name) &&
+                        filter.matches_contract_test(&identifier, &func.signature())
+                })
+            })
             .map_with(stream_result, |stream_result, (id, (abi, deploy_code, libs))| {
                 let executor = ExecutorBuilder::default()
                     .with_cheatcodes(self.cheats_config.clone())
@@ -0,0 +1,361 @@
+use crate::result::{SuiteResult, TestKindReport, TestResult, TestStatus};
+use foxar_evm::fuzz::CounterExample;
+use std::{collections::BTreeMap, io::Write, time::Duration};
+
+/// A test result reporter.
+///
+/// Reporters write the results of all test suites, keyed by suite name, to their destination.
+pub trait TestReporter {
+    fn report(self, results: &BTreeMap<String, SuiteResult>) -> eyre::Result<()>;
+}
+
+/// Writes the test results as JSON, mirroring the serialized [`SuiteResult`]s.
+pub struct JsonReporter<'a> {
+    /// Destination buffer
+    destination: &'a mut (dyn Write + 'a),
+}
+
+impl<'a> JsonReporter<'a> {
+    pub fn new(destination: &'a mut (dyn Write + 'a)) -> JsonReporter<'a> {
+        Self { destination }
+    }
+}
+
+impl<'a> TestReporter for JsonReporter<'a> {
+    fn report(self, results: &BTreeMap<String, SuiteResult>) -> eyre::Result<()> {
+        writeln!(self.destination, "{}", serde_json::to_string(results)?)?;
+        Ok(())
+    }
+}
+
+/// Writes the test results in the JUnit XML format.
+///
+/// Every test contract is a `<testsuite>`, and every test function a `<testcase>` of it.
+pub struct JunitReporter<'a> {
+    /// Destination buffer
+    destination: &'a mut (dyn Write + 'a),
+}
+
+impl<'a> JunitReporter<'a> {
+    pub fn new(destination: &'a mut (dyn Write + 'a)) -> JunitReporter<'a> {
+        Self { destination }
+    }
+}
+
+impl<'a> TestReporter for JunitReporter<'a> {
+    fn report(self, results: &BTreeMap<String, SuiteResult>) -> eyre::Result<()> {
+        let out = self.destination;
+        let count = |status: TestStatus| {
+            results
+                .values()
+                .flat_map(|suite| suite.tests())
+                .filter(|(_, t)| t.status == status)
+                .count()
+        };
+        let total_duration = results.values().map(|suite| suite.duration).sum::<Duration>();
+
+        writeln!(out, r#"<?xml version="1.0" encoding="UTF-8"?>"#)?;
+        writeln!(
+            out,
+            r#"<testsuites name="spark" tests="{}" failures="{}" skipped="{}" time="{}">"#,
+            results.values().map(SuiteResult::len).sum::<usize>(),
+            count(TestStatus::Failure),
+            count(TestStatus::Skipped),
+            format_seconds(total_duration),
+        )?;
+
+        for (suite_name, suite) in results {
+            writeln!(
+                out,
+                r#"  <testsuite name="{}" tests="{}" failures="{}" skipped="{}" time="{}">"#,
+                escape_xml(suite_name),
+                suite.len(),
+                suite.failures().count(),
+                suite.tests().filter(|(_, t)| t.status == TestStatus::Skipped).count(),
+                format_seconds(suite.duration),
+            )?;
+            for warning in &suite.warnings {
+                writeln!(out, "    <system-err>{}</system-err>", escape_xml(warning))?;
+            }
+
+            for (name, result) in suite.tests() {
+                writeln!(
+                    out,
+                    r#"    <testcase name="{}" classname="{}" time="{}">"#,
+                    escape_xml(name),
+                    escape_xml(suite_name),
+                    format_seconds(result.duration),
+                )?;
+
+                writeln!(out, "      <properties>")?;
+                for (key, value) in kind_properties(&result.kind.report()) {
+                    writeln!(out, r#"        <property name="{key}" value="{value}"/>"#)?;
+                }
+                if result.flaky {
+                    writeln!(out, r#"        <property name="flaky" value="true"/>"#)?;
+                }
+                if result.quarantined {
+                    writeln!(out, r#"        <property name="quarantined" value="true"/>"#)?;
+                }
+                writeln!(out, "      </properties>")?;
+
+                match result.status {
+                    TestStatus::Success => {}
+                    TestStatus::Skipped => writeln!(out, "      <skipped/>")?,
+                    TestStatus::Failure => {
+                        let reason = failure_reason(result);
+                        match result.counterexample.as_ref() {
+                            Some(counterexample) => writeln!(
+                                out,
+                                r#"      <failure message="{}">{}</failure>"#,
+                                escape_xml(&reason),
+                                escape_xml(&format_counterexample(counterexample))
+                            )?,
+                            None => writeln!(
+                                out,
+                                r#"      <failure message="{}"/>"#,
+                                escape_xml(&reason)
+                            )?,
+                        }
+                    }
+                }
+
+                if !result.decoded_logs.is_empty() {
+                    writeln!(
+                        out,
+                        "      <system-out>{}</system-out>",
+                        escape_xml(&result.decoded_logs.join("\n"))
+                    )?;
+                }
+                writeln!(out, "    </testcase>")?;
+            }
+            writeln!(out, "  </testsuite>")?;
+        }
+        writeln!(out, "</testsuites>")?;
+        Ok(())
+    }
+}
+
+/// Writes the test results in the Test Anything Protocol (TAP) version 13 format.
+///
+/// The details of every executed test, including the failure reason, are written as a YAML
+/// diagnostic block after the test line.
+pub struct TapReporter<'a> {
+    /// Destination buffer
+    destination: &'a mut (dyn Write + 'a),
+}
+
+impl<'a> TapReporter<'a> {
+    pub fn new(destination: &'a mut (dyn Write + 'a)) -> TapReporter<'a> {
+        Self { destination }
+    }
+}
+
+impl<'a> TestReporter for TapReporter<'a> {
+    fn report(self, results: &BTreeMap<String, SuiteResult>) -> eyre::Result<()> {
+        let out = self.destination;
+        writeln!(out, "TAP version 13")?;
+        writeln!(out, "1..{}", results.values().map(SuiteResult::len).sum::<usize>())?;
+
+        let tests = results
+            .iter()
+            .flat_map(|(suite_name, suite)| suite.tests().map(move |test| (suite_name, test)));
+        for (i, (suite_name, (name, result))) in tests.enumerate() {
+            let id = i + 1;
+            let description = format!("{suite_name}::{name}");
+            match result.status {
+                TestStatus::Success => writeln!(out, "ok {id} - {description}")?,
+                TestStatus::Skipped => writeln!(out, "ok {id} - {description} # SKIP")?,
+                // quarantined failures are reported as TODO, which doesn't fail the TAP run
+                TestStatus::Failure if result.quarantined => {
+                    writeln!(out, "not ok {id} - {description} # TODO quarantined")?
+                }
+                TestStatus::Failure => writeln!(out, "not ok {id} - {description}")?,
+            }
+
+            // the details of executed tests are in a diagnostic block, as TAP consumers read
+            // anything after `#` in the test line as a directive
+            if result.status == TestStatus::Skipped {
+                continue
+            }
+            writeln!(out, "  ---")?;
+            if result.status == TestStatus::Failure {
+                writeln!(out, "  message: {}", yaml_string(&failure_reason(result)))?;
+            }
+            if result.flaky {
+                writeln!(out, "  flaky: true")?;
+            }
+            if let Some(counterexample) = result.counterexample.as_ref() {
+                writeln!(
+                    out,
+                    "  counterexample: {}",
+                    yaml_string(&format_counterexample(counterexample))
+                )?;
+            }
+            for (key, value) in kind_properties(&result.kind.report()) {
+                writeln!(out, "  {key}: {value}")?;
+            }
+            writeln!(out, "  duration_ms: {}", result.duration.as_millis())?;
+            if !result.decoded_logs.is_empty() {
+                writeln!(out, "  logs:")?;
+                for log in &result.decoded_logs {
+                    writeln!(out, "    - {}", yaml_string(log))?;
+                }
+            }
+            writeln!(out, "  ...")?;
+        }
+        Ok(())
+    }
+}
+
+/// Returns the details of a [`TestKindReport`] as key value pairs.
+fn kind_properties(report: &TestKindReport) -> Vec<(&'static str, u64)> {
+    match *report {
+        TestKindReport::Standard { gas } => vec![("gas", gas)],
+        TestKindReport::Fuzz { runs, mean_gas, median_gas } => {
+            vec![("runs", runs as u64), ("mean_gas", mean_gas), ("median_gas", median_gas)]
+        }
+        TestKindReport::Invariant { runs, calls, reverts } => {
+            vec![("runs", runs as u64), ("calls", calls as u64), ("reverts", reverts as u64)]
+        }
+    }
+}
+
+/// Returns the failure reason of a test, defaulting to a failed assertion.
+fn failure_reason(result: &TestResult) -> String {
+    result.reason.clone().unwrap_or_else(|| "Assertion failed.".to_string())
+}
+
+fn format_counterexample(counterexample: &CounterExample) -> String {
+    match counterexample {
+        CounterExample::Single(counterexample) => counterexample.to_string(),
+        CounterExample::Sequence(sequence) => {
+            sequence.iter().map(ToString::to_string).collect::<Vec<_>>().join("\n")
+        }
+    }
+}
+
+fn format_seconds(duration: Duration) -> String {
+    format!("{:.3}", duration.as_secs_f64())
+}
+
+/// Escapes a string for use in XML attributes and text, dropping characters XML 1.0 can't
+/// represent.
+fn escape_xml(s: &str) -> String {
+    let mut escaped = String::with_capacity(s.len());
+    for c in s.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&apos;"),
+            '\n' | '\r' | '\t' => escaped.push(c),
+            c if c.is_control() => {}
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+/// Formats a string as a double quoted YAML scalar.
+fn yaml_string(s: &str) -> String {
+    // JSON strings are valid YAML double quoted scalars
+    serde_json::to_string(s).expect("strings can always be serialized")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::result::TestKind;
+
+    fn results() -> BTreeMap<String, SuiteResult> {
+        let success = TestResult {
+            status: TestStatus::Success,
+            kind: TestKind::Standard(1234),
+            duration: Duration::from_millis(1500),
+            ..Default::default()
+        };
+        let failure = TestResult {
+            status: TestStatus::Failure,
+            reason: Some("a < b".to_string()),
+            kind: TestKind::Invariant { runs: 256, calls: 3840, reverts: 12 },
+            decoded_logs: vec!["log".to_string()],
+            ..Default::default()
+        };
+        let skipped = TestResult { status: TestStatus::Skipped, ..Default::default() };
+
+        BTreeMap::from([(
+            "test/A.t.sol:ATest".to_string(),
+            SuiteResult::new(
+                Duration::from_secs(2),
+                BTreeMap::from([
+                    ("testA()".to_string(), success),
+                    ("invariantB()".to_string(), failure),
+                    ("testC()".to_string(), skipped),
+                ]),
+                Vec::new(),
+            ),
+        )])
+    }
+
+    fn report(reporter: impl FnOnce(&mut Vec<u8>) -> eyre::Result<()>) -> String {
+        let mut out = Vec::new();
+        reporter(&mut out).unwrap();
+        String::from_utf8(out).unwrap()
+    }
+
+    #[test]
+    fn can_report_junit() {
+        let results = results();
+        let xml = report(|out| JunitReporter::new(out).report(&results));
+        assert!(xml.starts_with(r#"<?xml version="1.0" encoding="UTF-8"?>"#));
+        assert!(xml.contains(
+            r#"<testsuites name="spark" tests="3" failures="1" skipped="1" time="2.000">"#
+        ));
+        assert!(xml
+            .contains(r#"<testcase name="testA()" classname="test/A.t.sol:ATest" time="1.500">"#));
+        assert!(xml.contains(r#"<property name="gas" value="1234"/>"#));
+        assert!(xml.contains(r#"<property name="reverts" value="12"/>"#));
+        assert!(xml.contains(r#"<failure message="a &lt; b"/>"#));
+        assert!(xml.contains("<skipped/>"));
+        assert!(xml.contains("<system-out>log</system-out>"));
+    }
+
+    #[test]
+    fn can_report_tap() {
+        let results = results();
+        let tap = report(|out| TapReporter::new(out).report(&results));
+        let lines = tap.lines().collect::<Vec<_>>();
+        assert_eq!(lines[0], "TAP version 13");
+        assert_eq!(lines[1], "1..3");
+        assert_eq!(lines[2], "not ok 1 - test/A.t.sol:ATest::invariantB()");
+        assert!(lines.contains(&r#"  message: "a < b""#));
+        assert!(lines.contains(&"  calls: 3840"));
+        let ok =
+            lines.iter().position(|line| *line == "ok 2 - test/A.t.sol:ATest::testA()").unwrap();
+        assert_eq!(lines[ok + 1..ok + 5], ["  ---", "  gas: 1234", "  duration_ms: 1500", "  ..."]);
+        assert_eq!(lines[ok + 5], "ok 3 - test/A.t.sol:ATest::testC() # SKIP");
+        assert_eq!(lines.len(), ok + 6);
+    }
+
+    #[test]
+    fn can_report_flaky_and_quarantined_tap() {
+        let mut results = results();
+        let suite = results.get_mut("test/A.t.sol:ATest").unwrap();
+        suite.test_results.get_mut("testA()").unwrap().flaky = true;
+        suite.test_results.get_mut("invariantB()").unwrap().quarantined = true;
+
+        let tap = report(|out| TapReporter::new(out).report(&results));
+        let lines = tap.lines().collect::<Vec<_>>();
+        assert_eq!(lines[2], "not ok 1 - test/A.t.sol:ATest::invariantB() # TODO quarantined");
+        let ok =
+            lines.iter().position(|line| *line == "ok 2 - test/A.t.sol:ATest::testA()").unwrap();
+        assert_eq!(lines[ok + 2], "  flaky: true");
+    }
+
+    #[test]
+    fn can_escape_xml() {
+        assert_eq!(escape_xml("<a href=\"&\">\u{1}"), "&lt;a href=&quot;&amp;&quot;&gt;");
+    }
+}
@@ -1,7 +1,7 @@
 //! test outcomes
 
 use crate::Address;
-use corebc::prelude::Log;
+use corebc::{prelude::Log, types::Bytes};
 use foxar_common::evm::Breakpoints;
 use foxar_evm::{
     coverage::HitMaps,
@@ -13,7 +13,7 @@ use serde::{Deserialize, Serialize};
 use std::{collections::BTreeMap, fmt, time::Duration};
 
 /// Results and duration for a set of tests included in the same test contract
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct SuiteResult {
     /// Total duration of the test run for this block of tests
     pub duration: Duration,
@@ -104,6 +104,23 @@ pub struct TestResult {
 
     /// pc breakpoint char map
     pub breakpoints: Breakpoints,
+
+    /// The time it took to execute the test
+    #[serde(default)]
+    pub duration: Duration,
+
+    /// Energy snapshots recorded with `startSnapshotEnergy`/`stopSnapshotEnergy` and
+    /// `snapshotValue`. `name -> value`
+    #[serde(default)]
+    pub energy_snapshots: BTreeMap<String, u64>,
+
+    /// Whether the test failed, but passed when it was retried
+    #[serde(default)]
+    pub flaky: bool,
+
+    /// Whether the test is quarantined, i.e. its failure doesn't fail the test run
+    #[serde(default)]
+    pub quarantined: bool,
 }
 
 impl TestResult {
@@ -111,6 +128,11 @@ impl TestResult {
         Self { status: TestStatus::Failure, reason: Some(reason), ..Default::default() }
     }
 
+    /// Returns `true` if the test failed and is not quarantined
+    pub fn is_failure(&self) -> bool {
+        self.status == TestStatus::Failure && !self.quarantined
+    }
+
     /// Returns `true` if this is the result of a fuzz test
     pub fn is_fuzz(&self) -> bool {
         matches!(self.kind, TestKind::Fuzz { .. })
@@ -171,6 +193,15 @@ pub enum TestKind {
     },
     /// A solidity invariant test, that stores all test cases
     Invariant { runs: usize, calls: usize, reverts: usize },
+    /// A single row of a parameterized test
+    Table {
+        /// the index of the row in the table
+        row: usize,
+        /// the consumed gas
+        gas: u64,
+        /// we keep this for the debugger
+        calldata: Bytes,
+    },
 }
 
 impl Default for TestKind {
@@ -183,7 +214,9 @@ impl TestKind {
     /// The gas consumed by this test
     pub fn report(&self) -> TestKindReport {
         match self {
-            TestKind::Standard(gas) => TestKindReport::Standard { gas: *gas },
+            TestKind::Standard(gas) | TestKind::Table { gas, .. } => {
+                TestKindReport::Standard { gas: *gas }
+            }
             TestKind::Fuzz { runs, mean_gas, median_gas, .. } => {
                 TestKindReport::Fuzz { runs: *runs, mean_gas: *mean_gas, median_gas: *median_gas }
             }
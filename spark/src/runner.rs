@@ -1,17 +1,18 @@
 use crate::{
     result::{SuiteResult, TestKind, TestResult, TestSetup, TestStatus},
+    table::{self, Table},
     TestFilter, TestOptions,
 };
 use corebc::{
-    abi::{Abi, Function},
+    abi::{Abi, Function, Token},
     types::{Address, Bytes, U256},
 };
 use eyre::Result;
 use foxar_common::{
-    contracts::{ContractsByAddress, ContractsByArtifact},
+    contracts::{get_contract_name, ContractsByAddress, ContractsByArtifact},
     TestFunctionExt,
 };
-use foxar_config::{FuzzConfig, InvariantConfig};
+use foxar_config::{FuzzConfig, InvariantConfig, LimitsConfig};
 use foxar_evm::{
     decode::decode_console_logs,
     executor::{CallResult, EvmError, ExecutionErr, Executor},
@@ -28,6 +29,7 @@ use proptest::test_runner::{TestError, TestRunner};
 use rayon::prelude::*;
 use std::{
     collections::{BTreeMap, HashMap},
+    path::Path,
     time::Instant,
 };
 
@@ -235,6 +237,10 @@ impl<'a> ContractRunner<'a> {
                         coverage: None,
                         labeled_addresses: setup.labeled_addresses,
                         breakpoints: Default::default(),
+                        energy_snapshots: Default::default(),
+                        duration: Default::default(),
+                        flaky: false,
+                        quarantined: false,
                     },
                 )]
                 .into(),
@@ -247,17 +253,39 @@ impl<'a> ContractRunner<'a> {
             .functions
             .par_iter()
             .flat_map(|(_, f)| f)
-            .filter(|&func| func.is_test() && filter.matches_test(func.signature()))
-            .map(|func| {
-                let should_fail = func.is_test_fail();
-                let res = if func.is_fuzz_test() {
-                    let runner = test_options.fuzz_runner(self.name, &func.name);
-                    let fuzz_config = test_options.fuzz_config(self.name, &func.name);
-                    self.run_fuzz_test(func, should_fail, runner, setup.clone(), *fuzz_config)
-                } else {
-                    self.clone().run_test(func, should_fail, setup.clone())
-                };
-                (func.signature(), res)
+            .filter(|&func| {
+                func.is_test() &&
+                    filter.matches_test(func.signature()) &&
+                    filter.matches_contract_test(self.name, &func.signature())
+            })
+            .flat_map(|func| {
+                run_with_retries(test_options.retries, || {
+                    let should_fail = func.is_test_fail();
+                    if let Some(table) =
+                        self.load_table(func, setup.address, test_options.fixtures.as_deref())
+                    {
+                        return self.run_table_test(func, should_fail, table, setup.clone())
+                    }
+
+                    let start = Instant::now();
+                    let mut res = if func.is_fuzz_test() {
+                        let runner = test_options.fuzz_runner(self.name, &func.name);
+                        let fuzz_config = test_options.fuzz_config(self.name, &func.name);
+                        let limits = test_options.limits(self.name, &func.name);
+                        self.run_fuzz_test(
+                            func,
+                            should_fail,
+                            runner,
+                            setup.clone(),
+                            *fuzz_config,
+                            limits,
+                        )
+                    } else {
+                        self.clone().run_test(func, &[], should_fail, setup.clone())
+                    };
+                    res.duration = start.elapsed();
+                    vec![(func.signature(), res)]
+                })
             })
             .collect::<BTreeMap<_, _>>();
 
@@ -265,27 +293,43 @@ impl<'a> ContractRunner<'a> {
             let identified_contracts = load_contracts(setup.traces.clone(), known_contracts);
 
             // TODO: par_iter ?
-            let functions = self
-                .contract
-                .functions()
-                .filter(|&func| func.is_invariant_test() && filter.matches_test(func.signature()));
+            let functions = self.contract.functions().filter(|&func| {
+                func.is_invariant_test() &&
+                    filter.matches_test(func.signature()) &&
+                    filter.matches_contract_test(self.name, &func.signature())
+            });
             for func in functions {
-                let runner = test_options.invariant_runner(self.name, &func.name);
-                let invariant_config = test_options.invariant_config(self.name, &func.name);
-                let results = self.run_invariant_test(
-                    runner,
-                    setup.clone(),
-                    *invariant_config,
-                    vec![func],
-                    known_contracts,
-                    identified_contracts.clone(),
-                );
-                for result in results {
-                    test_results.insert(func.signature(), result);
-                }
+                let results = run_with_retries(test_options.retries, || {
+                    let runner = test_options.invariant_runner(self.name, &func.name);
+                    let invariant_config = test_options.invariant_config(self.name, &func.name);
+                    let limits = test_options.limits(self.name, &func.name);
+                    let start = Instant::now();
+                    let results = self.run_invariant_test(
+                        runner,
+                        setup.clone(),
+                        *invariant_config,
+                        limits,
+                        vec![func],
+                        known_contracts,
+                        identified_contracts.clone(),
+                    );
+                    let duration = start.elapsed();
+                    results
+                        .into_iter()
+                        .map(|mut result| {
+                            result.duration = duration;
+                            (func.signature(), result)
+                        })
+                        .collect()
+                });
+                test_results.extend(results);
             }
         }
 
+        for (name, result) in test_results.iter_mut() {
+            result.quarantined = test_options.is_quarantined(self.name, name);
+        }
+
         let duration = start.elapsed();
         if !test_results.is_empty() {
             let successful =
@@ -301,22 +345,111 @@ impl<'a> ContractRunner<'a> {
         SuiteResult::new(duration, test_results, warnings)
     }
 
+    /// Loads the table of a parameterized test
+    ///
+    /// Returns `None` if the function takes no arguments or no table was provided for it, in which
+    /// case it is run as a standard or fuzz test.
+    fn load_table(
+        &self,
+        func: &Function,
+        address: Address,
+        fixtures: Option<&Path>,
+    ) -> Option<Result<Table>> {
+        if func.inputs.is_empty() {
+            return None
+        }
+
+        if let Some(provider) =
+            self.contract.functions.get(&table::provider_name(func)).and_then(|f| f.first())
+        {
+            let table = provider.encode_input(&[]).map_err(Into::into).and_then(|calldata| {
+                let call =
+                    self.executor.call_raw(self.sender, address, calldata.into(), 0.into())?;
+                if call.reverted {
+                    eyre::bail!("`{}` reverted", provider.signature())
+                }
+                table::decode_table(func, provider, &call.result)
+            });
+            return Some(table)
+        }
+
+        let file = table::fixture_file(fixtures?, get_contract_name(self.name), func)?;
+        Some(table::read_fixture_file(func, &file))
+    }
+
+    /// Runs a parameterized test once for every row of its table
+    ///
+    /// Every row is reported as its own test result, named after the test signature and the index
+    /// of the row, e.g. `testAdd(uint256,uint256)[0]`.
+    #[instrument(name = "table-test", skip_all, fields(name = %func.signature(), %should_fail))]
+    pub fn run_table_test(
+        &self,
+        func: &Function,
+        should_fail: bool,
+        table: Result<Table>,
+        setup: TestSetup,
+    ) -> Vec<(String, TestResult)> {
+        let table = match table {
+            Ok(table) => table,
+            Err(err) => {
+                return vec![(
+                    func.signature(),
+                    TestResult::fail(format!("Failed to load the table: {err:#}")),
+                )]
+            }
+        };
+
+        table
+            .into_iter()
+            .enumerate()
+            .map(|(row, args)| {
+                let start = Instant::now();
+                let calldata = func.encode_input(&args).unwrap_or_default().into();
+                let mut res = self.clone().run_test(func, &args, should_fail, setup.clone());
+                if let TestKind::Standard(gas) = res.kind {
+                    res.kind = TestKind::Table { row, gas, calldata };
+                }
+                res.duration = start.elapsed();
+                (format!("{}[{row}]", func.signature()), res)
+            })
+            .collect()
+    }
+
     /// Runs a single test
     ///
-    /// Calls the given functions and returns the `TestResult`.
+    /// Calls the given functions with the given arguments and returns the `TestResult`.
     ///
     /// State modifications are not committed to the evm database but discarded after the call,
     /// similar to `eth_call`.
     #[instrument(name = "test", skip_all, fields(name = %func.signature(), %should_fail))]
-    pub fn run_test(mut self, func: &Function, should_fail: bool, setup: TestSetup) -> TestResult {
+    pub fn run_test(
+        mut self,
+        func: &Function,
+        args: &[Token],
+        should_fail: bool,
+        setup: TestSetup,
+    ) -> TestResult {
         let TestSetup { address, mut logs, mut traces, mut labeled_addresses, .. } = setup;
 
         // Run unit test
         let start = Instant::now();
-        let (reverted, reason, gas, stipend, coverage, state_changeset, breakpoints) = match self
-            .executor
-            .execute_test::<(), _, _>(self.sender, address, func.clone(), (), 0.into(), self.errors)
-        {
+        let (
+            reverted,
+            reason,
+            gas,
+            stipend,
+            coverage,
+            state_changeset,
+            breakpoints,
+            energy_snapshots,
+        ) = match self.executor.execute_test::<(), _, _>(
+            self.sender,
+            address,
+            func.clone(),
+            args,
+            0.into(),
+            self.errors,
+        ) {
             Ok(CallResult {
                 reverted,
                 energy_used: gas,
@@ -327,12 +460,22 @@ impl<'a> ContractRunner<'a> {
                 labels: new_labels,
                 state_changeset,
                 breakpoints,
+                energy_snapshots,
                 ..
             }) => {
                 traces.extend(execution_trace.map(|traces| (TraceKind::Execution, traces)));
                 labeled_addresses.extend(new_labels);
                 logs.extend(execution_logs);
-                (reverted, None, gas, stipend, coverage, state_changeset, breakpoints)
+                (
+                    reverted,
+                    None,
+                    gas,
+                    stipend,
+                    coverage,
+                    state_changeset,
+                    breakpoints,
+                    energy_snapshots,
+                )
             }
             Err(EvmError::Execution(err)) => {
                 traces.extend(err.traces.map(|traces| (TraceKind::Execution, traces)));
@@ -346,6 +489,7 @@ impl<'a> ContractRunner<'a> {
                     None,
                     err.state_changeset,
                     HashMap::new(),
+                    BTreeMap::new(),
                 )
             }
             Err(EvmError::SkipError) => {
@@ -400,6 +544,10 @@ impl<'a> ContractRunner<'a> {
             coverage,
             labeled_addresses,
             breakpoints,
+            energy_snapshots,
+            duration: Default::default(),
+            flaky: false,
+            quarantined: false,
         }
     }
 
@@ -409,6 +557,7 @@ impl<'a> ContractRunner<'a> {
         runner: TestRunner,
         setup: TestSetup,
         invariant_config: InvariantConfig,
+        limits: LimitsConfig,
         functions: Vec<&Function>,
         known_contracts: Option<&ContractsByArtifact>,
         identified_contracts: ContractsByAddress,
@@ -444,13 +593,19 @@ impl<'a> ContractRunner<'a> {
             invariant_config,
             &identified_contracts,
             project_contracts,
-        );
+        )
+        .with_limits(limits);
 
         let invariant_contract =
             InvariantContract { address, invariant_functions: functions, abi: self.contract };
 
-        let Ok(InvariantFuzzTestResult { invariants, cases, reverts, mut last_call_results }) =
-            evm.invariant_fuzz(invariant_contract)
+        let Ok(InvariantFuzzTestResult {
+            invariants,
+            cases,
+            reverts,
+            mut last_call_results,
+            limit_exceeded,
+        }) = evm.invariant_fuzz(invariant_contract)
         else {
             return vec![];
         };
@@ -462,11 +617,17 @@ impl<'a> ContractRunner<'a> {
                 let mut logs = logs.clone();
                 let mut traces = traces.clone();
 
-                let success = test_error.is_none();
-                let reason = test_error.as_ref().and_then(|err| {
+                let mut success = test_error.is_none();
+                let mut reason = test_error.as_ref().and_then(|err| {
                     (!err.revert_reason.is_empty()).then(|| err.revert_reason.clone())
                 });
 
+                // invariants that held until a limit was hit are reported as failed
+                if success && limit_exceeded.is_some() {
+                    success = false;
+                    reason = limit_exceeded.clone();
+                }
+
                 match test_error {
                     // If invariants were broken, replay the error to collect logs and traces
                     Some(error @ InvariantFuzzError { test_error: TestError::Fail(_, _), .. }) => {
@@ -524,6 +685,10 @@ impl<'a> ContractRunner<'a> {
                     traces,
                     labeled_addresses: labeled_addresses.clone(),
                     breakpoints: Default::default(),
+                    energy_snapshots: Default::default(),
+                    duration: Default::default(),
+                    flaky: false,
+                    quarantined: false,
                 }
             })
             .collect()
@@ -537,12 +702,14 @@ impl<'a> ContractRunner<'a> {
         runner: TestRunner,
         setup: TestSetup,
         fuzz_config: FuzzConfig,
+        limits: LimitsConfig,
     ) -> TestResult {
         let TestSetup { address, mut logs, mut traces, mut labeled_addresses, .. } = setup;
 
         // Run fuzz test
         let start = Instant::now();
         let mut result = FuzzedExecutor::new(&self.executor, runner, self.sender, fuzz_config)
+            .with_limits(limits)
             .fuzz(func, address, should_fail, self.errors);
 
         // Check the last test result and skip the test
@@ -591,6 +758,38 @@ impl<'a> ContractRunner<'a> {
             coverage: result.coverage,
             labeled_addresses,
             breakpoints: Default::default(),
+            energy_snapshots: Default::default(),
+            duration: Default::default(),
+            flaky: false,
+            quarantined: false,
+        }
+    }
+}
+
+/// Runs a test and retries it up to `retries` times while any of its results failed.
+///
+/// Results that failed but passed on a retry are replaced by the passing result and marked as
+/// flaky, their duration includes all attempts.
+fn run_with_retries(
+    retries: u32,
+    mut run: impl FnMut() -> Vec<(String, TestResult)>,
+) -> Vec<(String, TestResult)> {
+    let mut results = run();
+    for _ in 0..retries {
+        if results.iter().all(|(_, res)| res.status != TestStatus::Failure) {
+            break
+        }
+        let mut retried = run().into_iter().collect::<BTreeMap<_, _>>();
+        for (name, res) in results.iter_mut() {
+            let Some(mut retry) = retried.remove(name) else { continue };
+            if res.status == TestStatus::Failure {
+                retry.duration += res.duration;
+                if retry.status == TestStatus::Success {
+                    retry.flaky = true;
+                }
+                *res = retry;
+            }
         }
     }
+    results
 }
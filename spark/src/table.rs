@@ -0,0 +1,281 @@
+//! Tables of arguments for parameterized tests
+//!
+//! A test function with arguments is run once per row of a table instead of being fuzzed if a
+//! table is provided for it, either by a `table_<test name>()` function of the test contract, or
+//! by a `<test name>.json` or `<test name>.csv` file in the `<fixtures>/<contract name>`
+//! directory.
+
+use corebc::abi::{Function, Token};
+use eyre::{bail, ensure, Result, WrapErr};
+use foxar_common::abi::parse_tokens;
+use serde_json::Value;
+use std::path::{Path, PathBuf};
+
+/// The rows of a table, each row holds the arguments of a single test call.
+pub type Table = Vec<Vec<Token>>;
+
+/// Returns the name of the function that provides the table for the given test function.
+pub fn provider_name(func: &Function) -> String {
+    format!("table_{}", func.name)
+}
+
+/// Returns the fixture file of the given test function, if any.
+///
+/// JSON fixtures take precedence over CSV fixtures.
+pub fn fixture_file(fixtures: &Path, contract: &str, func: &Function) -> Option<PathBuf> {
+    let dir = fixtures.join(contract);
+    ["json", "csv"]
+        .into_iter()
+        .map(|ext| dir.join(format!("{}.{ext}", func.name)))
+        .find(|path| path.is_file())
+}
+
+/// Decodes the output of a table provider function into the rows of the table.
+///
+/// A provider can either return an array of rows, where every row is a struct holding the
+/// arguments of the test (or the argument itself if the test only takes one), or an array per
+/// argument, where the i-th elements of all arrays form the i-th row.
+pub fn decode_table(func: &Function, provider: &Function, output: &[u8]) -> Result<Table> {
+    let tokens = provider
+        .decode_output(output)
+        .wrap_err_with(|| format!("Failed to decode the output of `{}`", provider.signature()))?;
+
+    let table = match tokens.as_slice() {
+        [Token::Array(rows)] | [Token::FixedArray(rows)] if func.inputs.len() == 1 => {
+            rows.iter().map(|row| vec![row.clone()]).collect()
+        }
+        [Token::Array(rows)] | [Token::FixedArray(rows)] => rows
+            .iter()
+            .map(|row| match row {
+                Token::Tuple(args) => Ok(args.clone()),
+                _ => bail!("Rows of `{}` must be structs", provider.signature()),
+            })
+            .collect::<Result<_>>()?,
+        columns if columns.len() == func.inputs.len() => {
+            let columns = columns
+                .iter()
+                .map(|column| match column {
+                    Token::Array(values) | Token::FixedArray(values) => Ok(values.as_slice()),
+                    _ => bail!("Columns of `{}` must be arrays", provider.signature()),
+                })
+                .collect::<Result<Vec<_>>>()?;
+            let rows = columns[0].len();
+            ensure!(
+                columns.iter().all(|column| column.len() == rows),
+                "Columns of `{}` must have the same length",
+                provider.signature()
+            );
+            (0..rows)
+                .map(|row| columns.iter().map(|column| column[row].clone()).collect())
+                .collect()
+        }
+        _ => bail!(
+            "`{}` must return an array of rows, or an array for every argument of `{}`",
+            provider.signature(),
+            func.signature()
+        ),
+    };
+
+    validate_table(func, table)
+}
+
+/// Reads the rows of a table from a JSON or CSV fixture file.
+pub fn read_fixture_file(func: &Function, path: &Path) -> Result<Table> {
+    let content = foxar_common::fs::read_to_string(path)?;
+    let rows = match path.extension().and_then(|ext| ext.to_str()) {
+        Some("csv") => parse_csv(func, &content),
+        _ => parse_json(func, &content),
+    }
+    .wrap_err_with(|| format!("Failed to read the table of `{}`", func.signature()))?;
+
+    let table = rows
+        .iter()
+        .enumerate()
+        .map(|(i, row)| {
+            parse_tokens(
+                func.inputs.iter().map(|param| &param.kind).zip(row.iter().map(String::as_str)),
+                true,
+            )
+            .wrap_err_with(|| format!("Invalid row {i} in {}", path.display()))
+        })
+        .collect::<Result<_>>()?;
+
+    validate_table(func, table)
+}
+
+/// Parses a JSON fixture.
+///
+/// The fixture is an array of rows, every row is either an array of the arguments, or an object
+/// mapping the argument names to their values.
+fn parse_json(func: &Function, content: &str) -> Result<Vec<Vec<String>>> {
+    let rows: Vec<Value> = serde_json::from_str(content)?;
+    rows.into_iter()
+        .enumerate()
+        .map(|(i, row)| match row {
+            Value::Array(values) => {
+                ensure!(
+                    values.len() == func.inputs.len(),
+                    "Row {i} has {} values, expected {}",
+                    values.len(),
+                    func.inputs.len()
+                );
+                Ok(values.iter().map(json_value_to_string).collect())
+            }
+            Value::Object(mut values) => func
+                .inputs
+                .iter()
+                .map(|param| match values.remove(&param.name) {
+                    Some(value) => Ok(json_value_to_string(&value)),
+                    None => bail!("Row {i} is missing the argument `{}`", param.name),
+                })
+                .collect(),
+            _ => bail!("Row {i} must be an array or an object"),
+        })
+        .collect()
+}
+
+fn json_value_to_string(value: &Value) -> String {
+    match value {
+        Value::String(s) => s.clone(),
+        value => value.to_string(),
+    }
+}
+
+/// Parses a CSV fixture.
+///
+/// The first line is a header naming the arguments, in any order. Values containing commas, like
+/// arrays, have to be enclosed in double quotes.
+fn parse_csv(func: &Function, content: &str) -> Result<Vec<Vec<String>>> {
+    let mut lines = content.lines().filter(|line| !line.trim().is_empty());
+    let Some(header) = lines.next() else { bail!("Missing CSV header") };
+    let header = split_csv_line(header)?;
+    let columns = func
+        .inputs
+        .iter()
+        .map(|param| {
+            header
+                .iter()
+                .position(|name| name == &param.name)
+                .ok_or_else(|| eyre::eyre!("Missing column for the argument `{}`", param.name))
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+    lines
+        .enumerate()
+        .map(|(i, line)| {
+            let values = split_csv_line(line)?;
+            ensure!(
+                values.len() == header.len(),
+                "Row {i} has {} values, expected {}",
+                values.len(),
+                header.len()
+            );
+            Ok(columns.iter().map(|&column| values[column].clone()).collect())
+        })
+        .collect()
+}
+
+/// Splits a CSV line into its trimmed values, honoring double quoted values.
+fn split_csv_line(line: &str) -> Result<Vec<String>> {
+    let mut values = Vec::new();
+    let mut value = String::new();
+    let mut quoted = false;
+    let mut chars = line.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            // an escaped quote inside a quoted value
+            '"' if quoted && chars.peek() == Some(&'"') => {
+                value.push('"');
+                chars.next();
+            }
+            '"' => quoted = !quoted,
+            ',' if !quoted => values.push(std::mem::take(&mut value).trim().to_string()),
+            c => value.push(c),
+        }
+    }
+    ensure!(!quoted, "Unterminated quoted value in `{line}`");
+    values.push(value.trim().to_string());
+    Ok(values)
+}
+
+/// Ensures that the table is not empty and every row matches the arguments of the test.
+fn validate_table(func: &Function, table: Table) -> Result<Table> {
+    ensure!(!table.is_empty(), "The table of `{}` is empty", func.signature());
+    let kinds = func.inputs.iter().map(|param| param.kind.clone()).collect::<Vec<_>>();
+    for (i, row) in table.iter().enumerate() {
+        ensure!(
+            Token::types_check(row, &kinds),
+            "Row {i} of the table does not match the arguments of `{}`",
+            func.signature()
+        );
+    }
+    Ok(table)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use corebc::{
+        abi::{encode, HumanReadableParser},
+        types::U256,
+    };
+
+    fn add() -> Function {
+        HumanReadableParser::parse_function("function testAdd(uint256 a, bool b)").unwrap()
+    }
+
+    fn row(a: u64, b: bool) -> Vec<Token> {
+        vec![Token::Uint(U256::from(a)), Token::Bool(b)]
+    }
+
+    #[test]
+    fn can_decode_rows() {
+        let provider = HumanReadableParser::parse_function(
+            "function table_testAdd() returns ((uint256,bool)[])",
+        )
+        .unwrap();
+        let output =
+            encode(&[Token::Array(vec![Token::Tuple(row(1, true)), Token::Tuple(row(2, false))])]);
+        assert_eq!(
+            decode_table(&add(), &provider, &output).unwrap(),
+            vec![row(1, true), row(2, false)]
+        );
+    }
+
+    #[test]
+    fn can_decode_columns() {
+        let provider = HumanReadableParser::parse_function(
+            "function table_testAdd() returns (uint256[], bool[])",
+        )
+        .unwrap();
+        let output = encode(&[
+            Token::Array(vec![Token::Uint(1.into()), Token::Uint(2.into())]),
+            Token::Array(vec![Token::Bool(true), Token::Bool(false)]),
+        ]);
+        assert_eq!(
+            decode_table(&add(), &provider, &output).unwrap(),
+            vec![row(1, true), row(2, false)]
+        );
+
+        let output = encode(&[
+            Token::Array(vec![Token::Uint(1.into())]),
+            Token::Array(vec![Token::Bool(true), Token::Bool(false)]),
+        ]);
+        assert!(decode_table(&add(), &provider, &output).is_err());
+    }
+
+    #[test]
+    fn can_parse_json() {
+        let rows = parse_json(&add(), r#"[[1, true], {"b": false, "a": "0x02"}]"#).unwrap();
+        assert_eq!(rows, vec![vec!["1", "true"], vec!["0x02", "false"]]);
+        assert!(parse_json(&add(), r#"[{"a": 1}]"#).is_err());
+    }
+
+    #[test]
+    fn can_parse_csv() {
+        let rows = parse_csv(&add(), "b, a\ntrue, 1\n\nfalse, \"2\"\n").unwrap();
+        assert_eq!(rows, vec![vec!["1", "true"], vec!["2", "false"]]);
+        assert_eq!(split_csv_line(r#"a,"[1,2]","""q""""#).unwrap(), vec!["a", "[1,2]", "\"q\""]);
+        assert!(parse_csv(&add(), "a\n1").is_err());
+    }
+}
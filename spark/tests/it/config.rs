@@ -128,6 +128,11 @@ pub fn test_opts() -> TestOptions {
         },
         inline_fuzz: Default::default(),
         inline_invariant: Default::default(),
+        limits: Default::default(),
+        inline_limits: Default::default(),
+        fixtures: Some(manifest_root().join("testdata/fixtures/Table")),
+        retries: 0,
+        quarantine: vec![],
     }
 }
 
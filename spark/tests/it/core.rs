@@ -2,7 +2,7 @@
 
 use crate::{config::*, test_helpers::filter::Filter};
 
-use spark::result::SuiteResult;
+use spark::result::{SuiteResult, TestKind};
 
 use foxar_evm::trace::TraceKind;
 use std::{collections::BTreeMap, env};
@@ -735,3 +735,53 @@ async fn test_trace() {
         }
     }
 }
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_table() {
+    let mut runner = runner().await;
+    let results = runner.test(&Filter::new(".*", ".*", ".*table"), None, test_opts()).await;
+
+    assert_multiple(
+        &results,
+        BTreeMap::from([(
+            "table/Table.t.sol:TableTest",
+            vec![
+                ("testAdd(uint256,uint256,uint256)[0]", true, None, None, None),
+                ("testAdd(uint256,uint256,uint256)[1]", false, None, None, None),
+                ("testAdd(uint256,uint256,uint256)[2]", true, None, None, None),
+                ("testIsEven(uint256,bool)[0]", true, None, None, None),
+                ("testIsEven(uint256,bool)[1]", true, None, None, None),
+                ("testMulFromJson(uint256,uint256,uint256)[0]", true, None, None, None),
+                ("testMulFromJson(uint256,uint256,uint256)[1]", true, None, None, None),
+                ("testDivFromCsv(uint256,uint256,uint256)[0]", true, None, None, None),
+                ("testDivFromCsv(uint256,uint256,uint256)[1]", true, None, None, None),
+                (
+                    "testDivFromCsv(uint256,uint256,uint256)[2]",
+                    false,
+                    Some("Division or modulo by 0".to_string()),
+                    None,
+                    None,
+                ),
+                (
+                    "testEmpty(uint256)",
+                    false,
+                    Some(
+                        "Failed to load the table: The table of `testEmpty(uint256)` is empty"
+                            .to_string(),
+                    ),
+                    None,
+                    None,
+                ),
+            ],
+        )]),
+    );
+
+    // every row is measured on its own
+    let suite = &results["table/Table.t.sol:TableTest"];
+    for (name, result) in suite.tests() {
+        if let TestKind::Table { row, gas, .. } = result.kind {
+            assert!(name.ends_with(&format!("[{row}]")));
+            assert!(gas > 0);
+        }
+    }
+}
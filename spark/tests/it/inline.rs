@@ -6,7 +6,7 @@ mod tests {
     };
     use foxar_config::{FuzzConfig, InvariantConfig};
     use spark::{
-        result::{SuiteResult, TestKind, TestResult},
+        result::{SuiteResult, TestKind, TestResult, TestStatus},
         TestOptions, TestOptionsBuilder,
     };
 
@@ -34,6 +34,78 @@ mod tests {
         }
     }
 
+    #[tokio::test(flavor = "multi_thread")]
+    async fn inline_config_run_limits() {
+        const ROOT: &str = "inline/LimitsInlineConf.t.sol";
+
+        let opts = test_options();
+
+        let filter = Filter::new(".*", ".*", ".*inline/LimitsInlineConf.t.sol");
+
+        let mut runner = runner().await;
+        runner.test_options = opts.clone();
+
+        let result = runner.test(&filter, None, opts).await;
+        let suite_result: &SuiteResult = result.get(&format!("{ROOT}:LimitsInlineConf")).unwrap();
+
+        let test_result: &TestResult =
+            suite_result.test_results.get("testInlineConfTimeout(uint256)").unwrap();
+        assert_eq!(test_result.status, TestStatus::Failure);
+        assert_eq!(test_result.reason.as_deref(), Some("Timed out after 1s"));
+        // the statistics of the runs until the timeout are reported
+        match &test_result.kind {
+            TestKind::Fuzz { runs, .. } => {
+                assert!(*runs > 0 && *runs < 100000000);
+            }
+            _ => {
+                unreachable!()
+            }
+        }
+
+        let test_result: &TestResult =
+            suite_result.test_results.get("testInlineConfStepBudget(uint256)").unwrap();
+        assert_eq!(test_result.status, TestStatus::Failure);
+        assert_eq!(test_result.reason.as_deref(), Some("Exceeded the step budget of 10 calls"));
+        match &test_result.kind {
+            TestKind::Fuzz { runs, .. } => {
+                assert_eq!(runs, &10);
+            }
+            _ => {
+                unreachable!()
+            }
+        }
+
+        let test_result: &TestResult =
+            suite_result.test_results.get("testInlineConfEnergyBudget(uint256)").unwrap();
+        assert_eq!(test_result.status, TestStatus::Failure);
+        assert_eq!(test_result.reason.as_deref(), Some("Exceeded the energy budget of 1000000"));
+        match &test_result.kind {
+            TestKind::Fuzz { runs, .. } => {
+                assert!(*runs > 0 && *runs < 1000);
+            }
+            _ => {
+                unreachable!()
+            }
+        }
+
+        let suite_result: &SuiteResult =
+            result.get(&format!("{ROOT}:LimitsInlineConfInvariant")).unwrap();
+        let test_result: &TestResult =
+            suite_result.test_results.get("invariant_stepBudget()").unwrap();
+        assert_eq!(test_result.status, TestStatus::Failure);
+        assert_eq!(test_result.reason.as_deref(), Some("Exceeded the step budget of 25 calls"));
+        // the last run is cut short once the budget is used up
+        match &test_result.kind {
+            TestKind::Invariant { runs, calls, .. } => {
+                assert_eq!(runs, &3);
+                assert_eq!(calls, &25);
+            }
+            _ => {
+                unreachable!()
+            }
+        }
+    }
+
     #[tokio::test(flavor = "multi_thread")]
     async fn inline_config_run_invariant() {
         const ROOT: &str = "inline/InvariantInlineConf.t.sol";
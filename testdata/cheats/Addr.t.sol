@@ -7,12 +7,12 @@ import "./Cheats.sol";
 contract AddrTest is DSTest {
     Cheats constant cheats = Cheats(HEVM_ADDRESS);
 
-    function testFailPrivKeyZero() public {
-        cheats.addr(0);
+    function testFailPrivKeyWrongLength() public {
+        cheats.addr("0x01");
     }
 
     function testAddr() public {
-        uint256 pk = 77814517325470205911140941194401928579557062014761831930645393041380819009408;
+        string memory pk = "0x000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000001";
         address expected = 0xcb58e5dd06163a480c22d540ec763325a0b5860fb56c;
 
         assertEq(cheats.addr(pk), expected, "expected address did not match");
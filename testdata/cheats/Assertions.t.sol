@@ -0,0 +1,95 @@
+// SPDX-License-Identifier: Unlicense
+pragma solidity 1.1.0;
+
+import "ds-test/test.sol";
+import "./Cheats.sol";
+
+contract AssertionsTest is DSTest {
+    Cheats constant cheats = Cheats(HEVM_ADDRESS);
+
+    function testAssertTrue() public {
+        cheats.assertTrue(true);
+        cheats.assertFalse(false);
+    }
+
+    function testAssertTrueFails() public {
+        cheats.expectRevert("assertion failed");
+        cheats.assertTrue(false);
+    }
+
+    function testAssertTrueCustomError() public {
+        cheats.expectRevert("custom error");
+        cheats.assertTrue(false, "custom error");
+    }
+
+    function testAssertEq() public {
+        cheats.assertEq(uint256(1), uint256(1));
+        cheats.assertEq(int256(-1), int256(-1));
+        cheats.assertEq(true, true);
+        cheats.assertEq(address(this), address(this));
+        cheats.assertEq(bytes32("gm"), bytes32("gm"));
+        cheats.assertEq("gm", "gm");
+        cheats.assertEq(bytes("gm"), bytes("gm"));
+        cheats.assertNotEq(uint256(1), uint256(2));
+    }
+
+    function testAssertEqArrays() public {
+        uint256[] memory left = new uint256[](2);
+        left[0] = 1;
+        left[1] = 2;
+        uint256[] memory right = new uint256[](2);
+        right[0] = 1;
+        right[1] = 2;
+        cheats.assertEq(left, right);
+
+        right[1] = 3;
+        cheats.expectRevert("assertion failed: [1, 2] != [1, 3]");
+        cheats.assertEq(left, right);
+    }
+
+    function testAssertEqIntFails() public {
+        cheats.expectRevert("assertion failed: -1 != 1");
+        cheats.assertEq(int256(-1), int256(1));
+    }
+
+    function testAssertEqCustomError() public {
+        cheats.expectRevert("amounts: 1 != 2");
+        cheats.assertEq(uint256(1), uint256(2), "amounts");
+    }
+
+    function testAssertEqStringFails() public {
+        cheats.expectRevert("assertion failed: gm != gn");
+        cheats.assertEq("gm", "gn");
+    }
+
+    function testAssertOrdering() public {
+        cheats.assertLt(uint256(1), uint256(2));
+        cheats.assertGt(int256(1), int256(-2));
+        cheats.assertLe(uint256(2), uint256(2));
+        cheats.assertGe(int256(-2), int256(-2));
+    }
+
+    function testAssertLtFails() public {
+        cheats.expectRevert("assertion failed: -1 >= -2");
+        cheats.assertLt(int256(-1), int256(-2));
+    }
+
+    function testAssertApproxEq() public {
+        cheats.assertApproxEqAbs(uint256(100), uint256(105), uint256(5));
+        cheats.assertApproxEqAbs(int256(-2), int256(2), uint256(4));
+        // 1e16 is 1%
+        cheats.assertApproxEqRel(uint256(101), uint256(100), uint256(1e16));
+    }
+
+    function testAssertApproxEqAbsFails() public {
+        cheats.expectRevert("assertion failed: 1 !~= 10 (max delta: 5, real delta: 9)");
+        cheats.assertApproxEqAbs(uint256(1), uint256(10), uint256(5));
+    }
+
+    function testAssertApproxEqRelFails() public {
+        cheats.expectRevert(
+            "assertion failed: 102 !~= 100 (max delta: 1.0000000000000000%, real delta: 2.0000000000000000%)"
+        );
+        cheats.assertApproxEqRel(uint256(102), uint256(100), uint256(1e16));
+    }
+}
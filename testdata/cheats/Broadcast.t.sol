@@ -61,10 +61,10 @@ contract BroadcastTest is DSTest {
         string
             memory mnemonic = "test test test test test test test test test test test junk";
 
-        uint256 privateKey = cheats.deriveKey(mnemonic, 3);
+        string memory privateKey = cheats.deriveKey(mnemonic, 3);
         assertEq(
             privateKey,
-            0x000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000004
+            "0x000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000004"
         );
 
         cheats.broadcast(privateKey);
@@ -79,10 +79,10 @@ contract BroadcastTest is DSTest {
         string
             memory mnemonic = "test test test test test test test test test test test junk";
 
-        uint256 privateKey = cheats.deriveKey(mnemonic, 3);
+        string memory privateKey = cheats.deriveKey(mnemonic, 3);
         assertEq(
             privateKey,
-            0x000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000004
+            "0x000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000004"
         );
 
         address thisAddress = cheats.rememberKey(privateKey);
@@ -99,7 +99,7 @@ contract BroadcastTest is DSTest {
         string
             memory mnemonic = "test test test test test test test test test test test junk";
 
-        uint256 privateKey = cheats.deriveKey(mnemonic, 3);
+        string memory privateKey = cheats.deriveKey(mnemonic, 3);
         address thisAddress = cheats.rememberKey(privateKey);
 
         cheats.broadcast(thisAddress);
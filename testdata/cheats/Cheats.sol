@@ -24,6 +24,13 @@ interface Cheats {
         string url;
     }
 
+    // Returned by createWallet
+    struct Wallet {
+        address addr;
+        bytes publicKey;
+        string privateKey;
+    }
+
     // Used in readDir
     struct DirEntry {
         string errorMessage;
@@ -69,28 +76,28 @@ interface Cheats {
     // Stores a value to an address' storage slot, (who, slot, value)
     function store(address, bytes32, bytes32) external;
 
-    // Signs data, (privateKey, digest) => (v, r, s)
-    function sign(uint256, bytes32) external returns (uint8, bytes32, bytes32);
+    // Signs data with an ED448 private key, (privateKey, digest) => (signature)
+    function sign(string calldata, bytes32) external returns (bytes memory);
 
-    // Gets address for a given private key, (privateKey) => (address)
-    function addr(uint256) external returns (address);
+    // Gets the ICAN address for a given ED448 private key, (privateKey) => (address)
+    function addr(string calldata) external returns (address);
 
     // Derive a private key from a provided English mnemonic string (or mnemonic file path) at the derivation path m/44'/60'/0'/0/{index}
-    function deriveKey(string calldata, uint32) external returns (uint256);
+    function deriveKey(string calldata, uint32) external returns (string memory);
 
     // Derive a private key from a provided English mnemonic string (or mnemonic file path) at the derivation path {path}{index}
     function deriveKey(
         string calldata,
         string calldata,
         uint32
-    ) external returns (uint256);
+    ) external returns (string memory);
 
     // Derive a private key from a provided mnemonic string (or mnemonic file path) of specified language at the derivation path m/44'/60'/0'/0/{index}
     function deriveKey(
         string calldata,
         uint32,
         string calldata
-    ) external returns (uint256);
+    ) external returns (string memory);
 
     // Derive a private key from a provided mnemonic string (or mnemonic file path) of specified language at the derivation path {path}{index}
     function deriveKey(
@@ -98,10 +105,13 @@ interface Cheats {
         string calldata,
         uint32,
         string calldata
-    ) external returns (uint256);
+    ) external returns (string memory);
 
     // Adds a private key to the local spark wallet and returns the address
-    function rememberKey(uint256) external returns (address);
+    function rememberKey(string calldata) external returns (address);
+
+    // Creates a labeled wallet with an ED448 key pair derived from the label
+    function createWallet(string calldata) external returns (Wallet memory);
 
     // Performs a foreign function call via terminal, (stringInputs) => (result)
     function ffi(string[] calldata) external returns (bytes memory);
@@ -258,6 +268,27 @@ interface Cheats {
 
     function expectRevert(bytes4) external;
 
+    // Expects `count` reverts from the upcoming calls
+    function expectRevert(uint64 count) external;
+
+    // Expects an error on next call, reverted by the given contract
+    function expectRevert(address reverter) external;
+
+    // Expects `count` reverts with the given error from the upcoming calls
+    function expectRevert(bytes calldata, uint64 count) external;
+
+    function expectRevert(bytes4, uint64 count) external;
+
+    // Expects an error on next call, reverted by the given contract with the given error
+    function expectRevert(bytes calldata, address reverter) external;
+
+    function expectRevert(bytes4, address reverter) external;
+
+    // Expects an error on next call, only matching the selector of the custom error
+    function expectPartialRevert(bytes4) external;
+
+    function expectPartialRevert(bytes4, address reverter) external;
+
     // Record all storage reads and writes
     function record() external;
 
@@ -289,6 +320,27 @@ interface Cheats {
 
     function expectEmit(bool, bool, bool, bool, address) external;
 
+    // Same as the above, but expects the log to be emitted exactly `count` times.
+    function expectEmit(uint64 count) external;
+
+    function expectEmit(address, uint64 count) external;
+
+    function expectEmit(bool, bool, bool, bool, uint64 count) external;
+
+    function expectEmit(bool, bool, bool, bool, address, uint64 count) external;
+
+    // Prepare an expected anonymous log with all five checks enabled.
+    // Second form also checks supplied address against emitting contract.
+    function expectEmitAnonymous() external;
+
+    function expectEmitAnonymous(address) external;
+
+    // Prepare an expected anonymous log with (bool checkTopic0, bool checkTopic1, bool checkTopic2, bool checkTopic3, bool checkData).
+    // Second form also checks supplied address against emitting contract.
+    function expectEmitAnonymous(bool, bool, bool, bool, bool) external;
+
+    function expectEmitAnonymous(bool, bool, bool, bool, bool, address) external;
+
     // Mocks a call to an address, returning specified data.
     // Calldata can either be strict or a partial match, e.g. if you only
     // pass a Solidity selector to the expected calldata, then the entire Solidity
@@ -369,6 +421,184 @@ interface Cheats {
     // If any other memory is written to, the test will fail.
     function expectSafeMemoryCall(uint64, uint64) external;
 
+    // Asserts that the condition is true, reverting with the optional custom error message otherwise
+    function assertTrue(bool) external;
+
+    function assertTrue(bool, string calldata) external;
+
+    // Asserts that the condition is false
+    function assertFalse(bool) external;
+
+    function assertFalse(bool, string calldata) external;
+
+    // Asserts that two values are equal
+    function assertEq(bool, bool) external;
+
+    function assertEq(bool, bool, string calldata) external;
+
+    function assertEq(uint256, uint256) external;
+
+    function assertEq(uint256, uint256, string calldata) external;
+
+    function assertEq(int256, int256) external;
+
+    function assertEq(int256, int256, string calldata) external;
+
+    function assertEq(address, address) external;
+
+    function assertEq(address, address, string calldata) external;
+
+    function assertEq(bytes32, bytes32) external;
+
+    function assertEq(bytes32, bytes32, string calldata) external;
+
+    function assertEq(string calldata, string calldata) external;
+
+    function assertEq(string calldata, string calldata, string calldata) external;
+
+    function assertEq(bytes calldata, bytes calldata) external;
+
+    function assertEq(bytes calldata, bytes calldata, string calldata) external;
+
+    function assertEq(bool[] calldata, bool[] calldata) external;
+
+    function assertEq(bool[] calldata, bool[] calldata, string calldata) external;
+
+    function assertEq(uint256[] calldata, uint256[] calldata) external;
+
+    function assertEq(uint256[] calldata, uint256[] calldata, string calldata) external;
+
+    function assertEq(int256[] calldata, int256[] calldata) external;
+
+    function assertEq(int256[] calldata, int256[] calldata, string calldata) external;
+
+    function assertEq(address[] calldata, address[] calldata) external;
+
+    function assertEq(address[] calldata, address[] calldata, string calldata) external;
+
+    function assertEq(bytes32[] calldata, bytes32[] calldata) external;
+
+    function assertEq(bytes32[] calldata, bytes32[] calldata, string calldata) external;
+
+    function assertEq(string[] calldata, string[] calldata) external;
+
+    function assertEq(string[] calldata, string[] calldata, string calldata) external;
+
+    function assertEq(bytes[] calldata, bytes[] calldata) external;
+
+    function assertEq(bytes[] calldata, bytes[] calldata, string calldata) external;
+
+    // Asserts that two values are not equal
+    function assertNotEq(bool, bool) external;
+
+    function assertNotEq(bool, bool, string calldata) external;
+
+    function assertNotEq(uint256, uint256) external;
+
+    function assertNotEq(uint256, uint256, string calldata) external;
+
+    function assertNotEq(int256, int256) external;
+
+    function assertNotEq(int256, int256, string calldata) external;
+
+    function assertNotEq(address, address) external;
+
+    function assertNotEq(address, address, string calldata) external;
+
+    function assertNotEq(bytes32, bytes32) external;
+
+    function assertNotEq(bytes32, bytes32, string calldata) external;
+
+    function assertNotEq(string calldata, string calldata) external;
+
+    function assertNotEq(string calldata, string calldata, string calldata) external;
+
+    function assertNotEq(bytes calldata, bytes calldata) external;
+
+    function assertNotEq(bytes calldata, bytes calldata, string calldata) external;
+
+    function assertNotEq(bool[] calldata, bool[] calldata) external;
+
+    function assertNotEq(bool[] calldata, bool[] calldata, string calldata) external;
+
+    function assertNotEq(uint256[] calldata, uint256[] calldata) external;
+
+    function assertNotEq(uint256[] calldata, uint256[] calldata, string calldata) external;
+
+    function assertNotEq(int256[] calldata, int256[] calldata) external;
+
+    function assertNotEq(int256[] calldata, int256[] calldata, string calldata) external;
+
+    function assertNotEq(address[] calldata, address[] calldata) external;
+
+    function assertNotEq(address[] calldata, address[] calldata, string calldata) external;
+
+    function assertNotEq(bytes32[] calldata, bytes32[] calldata) external;
+
+    function assertNotEq(bytes32[] calldata, bytes32[] calldata, string calldata) external;
+
+    function assertNotEq(string[] calldata, string[] calldata) external;
+
+    function assertNotEq(string[] calldata, string[] calldata, string calldata) external;
+
+    function assertNotEq(bytes[] calldata, bytes[] calldata) external;
+
+    function assertNotEq(bytes[] calldata, bytes[] calldata, string calldata) external;
+
+    // Asserts that the first value is less than the second
+    function assertLt(uint256, uint256) external;
+
+    function assertLt(uint256, uint256, string calldata) external;
+
+    function assertLt(int256, int256) external;
+
+    function assertLt(int256, int256, string calldata) external;
+
+    // Asserts that the first value is greater than the second
+    function assertGt(uint256, uint256) external;
+
+    function assertGt(uint256, uint256, string calldata) external;
+
+    function assertGt(int256, int256) external;
+
+    function assertGt(int256, int256, string calldata) external;
+
+    // Asserts that the first value is less than or equal to the second
+    function assertLe(uint256, uint256) external;
+
+    function assertLe(uint256, uint256, string calldata) external;
+
+    function assertLe(int256, int256) external;
+
+    function assertLe(int256, int256, string calldata) external;
+
+    // Asserts that the first value is greater than or equal to the second
+    function assertGe(uint256, uint256) external;
+
+    function assertGe(uint256, uint256, string calldata) external;
+
+    function assertGe(int256, int256) external;
+
+    function assertGe(int256, int256, string calldata) external;
+
+    // Asserts that two values differ by at most the given absolute delta
+    function assertApproxEqAbs(uint256, uint256, uint256) external;
+
+    function assertApproxEqAbs(uint256, uint256, uint256, string calldata) external;
+
+    function assertApproxEqAbs(int256, int256, uint256) external;
+
+    function assertApproxEqAbs(int256, int256, uint256, string calldata) external;
+
+    // Asserts that two values differ by at most the given relative delta, where 1e18 is 100%
+    function assertApproxEqRel(uint256, uint256, uint256) external;
+
+    function assertApproxEqRel(uint256, uint256, uint256, string calldata) external;
+
+    function assertApproxEqRel(int256, int256, uint256) external;
+
+    function assertApproxEqRel(int256, int256, uint256, string calldata) external;
+
     // Gets the bytecode from an artifact file. Takes in the relative path to the json file
     function getCode(string calldata) external returns (bytes memory);
 
@@ -406,7 +636,7 @@ interface Cheats {
     function broadcast(address) external;
 
     // Has the next call (at this call depth only) create a transaction with the private key provided as the sender that can later be signed and sent onchain
-    function broadcast(uint256) external;
+    function broadcast(string calldata) external;
 
     // Using the address that calls the test contract, has the all subsequent calls (at this call depth only) create transactions that can later be signed and sent onchain
     function startBroadcast() external;
@@ -415,7 +645,7 @@ interface Cheats {
     function startBroadcast(address) external;
 
     // Has all subsequent calls (at this call depth only) create transactions with the private key provided that can later be signed and sent onchain
-    function startBroadcast(uint256) external;
+    function startBroadcast(string calldata) external;
 
     // Stops collecting onchain transactions
     function stopBroadcast() external;
@@ -423,6 +653,15 @@ interface Cheats {
     // Get the path of the current project root
     function projectRoot() external returns (string memory);
 
+    // Saves the address as a named deployment, written to the deployment registry once broadcast
+    function saveDeployment(string calldata, address) external;
+
+    // Gets the address of a named deployment on the current network
+    function getDeployment(string calldata) external returns (address);
+
+    // Gets the address of a named deployment on the given network
+    function getDeployment(string calldata, string calldata) external returns (address);
+
     // Reads the entire content of file to string. Path is relative to the project root.
     // (path) => (data)
     function readFile(string calldata) external returns (string memory);
@@ -547,6 +786,17 @@ interface Cheats {
     // This deletes the snapshot and all snapshots taken after the given snapshot id.
     function revertTo(uint256) external returns (bool);
 
+    // Starts measuring the energy used by the caller under the given name.
+    // Must be stopped in the same call frame with `stopSnapshotEnergy`.
+    function startSnapshotEnergy(string calldata name) external;
+
+    // Stops measuring the energy started with `startSnapshotEnergy`.
+    // Records and returns the energy used since the snapshot was started.
+    function stopSnapshotEnergy(string calldata name) external returns (uint256);
+
+    // Records an arbitrary value under the given name in the energy snapshots.
+    function snapshotValue(string calldata name, uint256 value) external;
+
     // Creates a new fork with the given endpoint and block and returns the identifier of the fork
     function createFork(string calldata, uint256) external returns (uint256);
 
@@ -705,6 +955,18 @@ interface Cheats {
         string calldata
     ) external returns (bytes32[] memory);
 
+    // Returns the keys of the JSON object found at the given key
+    function parseJsonKeys(
+        string calldata,
+        string calldata
+    ) external returns (string[] memory);
+
+    // Returns true if the given key exists in the JSON document
+    function keyExistsJson(
+        string calldata,
+        string calldata
+    ) external returns (bool);
+
     function serializeBool(
         string calldata,
         string calldata,
@@ -797,6 +1059,105 @@ interface Cheats {
         string calldata
     ) external;
 
+    function parseToml(
+        string calldata,
+        string calldata
+    ) external returns (bytes memory);
+
+    function parseToml(string calldata) external returns (bytes memory);
+
+    function parseTomlUint(
+        string calldata,
+        string calldata
+    ) external returns (uint256);
+
+    function parseTomlUintArray(
+        string calldata,
+        string calldata
+    ) external returns (uint256[] memory);
+
+    function parseTomlInt(
+        string calldata,
+        string calldata
+    ) external returns (int256);
+
+    function parseTomlIntArray(
+        string calldata,
+        string calldata
+    ) external returns (int256[] memory);
+
+    function parseTomlBool(
+        string calldata,
+        string calldata
+    ) external returns (bool);
+
+    function parseTomlBoolArray(
+        string calldata,
+        string calldata
+    ) external returns (bool[] memory);
+
+    function parseTomlAddress(
+        string calldata,
+        string calldata
+    ) external returns (address);
+
+    function parseTomlAddressArray(
+        string calldata,
+        string calldata
+    ) external returns (address[] memory);
+
+    function parseTomlString(
+        string calldata,
+        string calldata
+    ) external returns (string memory);
+
+    function parseTomlStringArray(
+        string calldata,
+        string calldata
+    ) external returns (string[] memory);
+
+    function parseTomlBytes(
+        string calldata,
+        string calldata
+    ) external returns (bytes memory);
+
+    function parseTomlBytesArray(
+        string calldata,
+        string calldata
+    ) external returns (bytes[] memory);
+
+    function parseTomlBytes32(
+        string calldata,
+        string calldata
+    ) external returns (bytes32);
+
+    function parseTomlBytes32Array(
+        string calldata,
+        string calldata
+    ) external returns (bytes32[] memory);
+
+    // Returns the keys of the TOML table found at the given key
+    function parseTomlKeys(
+        string calldata,
+        string calldata
+    ) external returns (string[] memory);
+
+    // Returns true if the given key exists in the TOML document
+    function keyExistsToml(
+        string calldata,
+        string calldata
+    ) external returns (bool);
+
+    // Writes a stringified JSON object to a TOML file
+    function writeToml(string calldata, string calldata) external;
+
+    // Replaces the value at the given key of an existing TOML file with a stringified JSON object
+    function writeToml(
+        string calldata,
+        string calldata,
+        string calldata
+    ) external;
+
     // Pauses gas metering (gas usage will not be counted)
     function pauseGasMetering() external;
 
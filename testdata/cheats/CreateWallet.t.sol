@@ -0,0 +1,46 @@
+// SPDX-License-Identifier: Unlicense
+pragma solidity 1.1.0;
+
+import "ds-test/test.sol";
+import "./Cheats.sol";
+
+contract CreateWalletTest is DSTest {
+    Cheats constant cheats = Cheats(HEVM_ADDRESS);
+
+    function testCreateWallet() public {
+        Cheats.Wallet memory wallet = cheats.createWallet("alice");
+
+        // 57 bytes ED448 keys
+        assertEq(wallet.publicKey.length, 57);
+        assertEq(bytes(wallet.privateKey).length, 116);
+
+        assertEq(cheats.addr(wallet.privateKey), wallet.addr);
+        assertEq(cheats.getLabel(wallet.addr), "alice");
+
+        // the private key is sha3("alice") followed by the first 25 bytes of sha3(sha3("alice"))
+        assertEq(
+            wallet.privateKey,
+            "0xa7dcef9aef26202fce82a7c7d6672afb3a149db207d90a07e437d5abc7fc99edc54a564338481b893bb1f8759a3432d2fa40534892997e8e65"
+        );
+        assertEq(
+            wallet.publicKey,
+            hex"21f157c3abc2e5f919d367079587c81244fc5e7f2f5a674fd815e5cd0158f454ffa75ebd86c41bfd253a3beab0fa59ddf88d60e7549c65e080"
+        );
+        assertEq(wallet.addr, 0xcb94a350a1675e4cf53356876cf959b2d5cb1a966750);
+    }
+
+    function testCreateWalletIsDeterministic() public {
+        Cheats.Wallet memory first = cheats.createWallet("bob");
+        Cheats.Wallet memory second = cheats.createWallet("bob");
+        assertEq(first.addr, second.addr);
+        assertEq(first.privateKey, second.privateKey);
+
+        Cheats.Wallet memory other = cheats.createWallet("carol");
+        assertTrue(first.addr != other.addr);
+    }
+
+    function testCreateWalletCanBroadcast() public {
+        Cheats.Wallet memory wallet = cheats.createWallet("deployer");
+        assertEq(cheats.rememberKey(wallet.privateKey), wallet.addr);
+    }
+}
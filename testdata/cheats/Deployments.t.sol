@@ -0,0 +1,30 @@
+// SPDX-License-Identifier: Unlicense
+pragma solidity 1.1.0;
+
+import "ds-test/test.sol";
+import "./Cheats.sol";
+
+contract DeploymentsTest is DSTest {
+    Cheats constant cheats = Cheats(HEVM_ADDRESS);
+
+    function testSaveDeployment() public {
+        address counter = address(0xbeef);
+        cheats.saveDeployment("Counter", counter);
+        assertEq(cheats.getDeployment("Counter"), counter);
+
+        cheats.saveDeployment("Counter", address(this));
+        assertEq(cheats.getDeployment("Counter"), address(this));
+    }
+
+    function testGetMissingDeployment() public {
+        cheats.saveDeployment("Counter", address(0xbeef));
+
+        cheats.expectRevert(bytes("No deployment named `Counter` on network `nonexistent`"));
+        cheats.getDeployment("Counter", "nonexistent");
+    }
+
+    function testInvalidDeploymentName() public {
+        cheats.expectRevert(bytes("Invalid deployment name `../Counter`"));
+        cheats.saveDeployment("../Counter", address(0xbeef));
+    }
+}
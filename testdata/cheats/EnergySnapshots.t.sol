@@ -0,0 +1,59 @@
+// SPDX-License-Identifier: Unlicense
+pragma solidity 1.1.0;
+
+import "ds-test/test.sol";
+import "./Cheats.sol";
+
+contract Counter {
+    uint256 public count;
+
+    function increment() public {
+        count += 1;
+    }
+}
+
+contract EnergySnapshotsTest is DSTest {
+    Cheats constant cheats = Cheats(HEVM_ADDRESS);
+
+    Counter counter;
+
+    function setUp() public {
+        counter = new Counter();
+    }
+
+    function testSnapshotEnergy() public {
+        cheats.startSnapshotEnergy("increment");
+        counter.increment();
+        uint256 cold = cheats.stopSnapshotEnergy("increment");
+
+        cheats.startSnapshotEnergy("incrementWarm");
+        counter.increment();
+        uint256 warm = cheats.stopSnapshotEnergy("incrementWarm");
+
+        assertGt(cold, 0);
+        assertGt(cold, warm);
+    }
+
+    function testSnapshotEmptySection() public {
+        cheats.startSnapshotEnergy("empty");
+        uint256 used = cheats.stopSnapshotEnergy("empty");
+        cheats.startSnapshotEnergy("increment");
+        counter.increment();
+        assertGt(cheats.stopSnapshotEnergy("increment"), used);
+    }
+
+    function testSnapshotValue() public {
+        cheats.snapshotValue("count", counter.count());
+    }
+
+    function testStopUnknownSnapshotFails() public {
+        cheats.expectRevert("Energy snapshot `unknown` has not been started");
+        cheats.stopSnapshotEnergy("unknown");
+    }
+
+    function testStartSnapshotTwiceFails() public {
+        cheats.startSnapshotEnergy("twice");
+        cheats.expectRevert("Energy snapshot `twice` has already been started");
+        cheats.startSnapshotEnergy("twice");
+    }
+}
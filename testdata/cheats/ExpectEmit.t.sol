@@ -19,6 +19,8 @@ contract Emitter {
 
     event SomethingNonIndexed(uint256 data);
 
+    event SomethingAnonymous(uint256 indexed topic0, uint256 data) anonymous;
+
     function emitEvent(uint256 topic1, uint256 topic2, uint256 topic3, uint256 data) public {
         emit Something(topic1, topic2, topic3, data);
     }
@@ -60,6 +62,16 @@ contract Emitter {
         thing = num;
     }
 
+    function emitRepeatedly(uint256 times) public {
+        for (uint256 i = 0; i < times; i++) {
+            emit Something(1, 2, 3, 4);
+        }
+    }
+
+    function emitAnonymous(uint256 topic0, uint256 data) public {
+        emit SomethingAnonymous(topic0, data);
+    }
+
     /// Ref: issue #760
     function emitSomethingElse(uint256 data) public {
         emit SomethingElse(data);
@@ -85,6 +97,8 @@ contract ExpectEmitTest is DSTest {
 
     event SomethingNonIndexed(uint256 data);
 
+    event SomethingAnonymous(uint256 indexed topic0, uint256 data) anonymous;
+
     function setUp() public {
         emitter = new Emitter();
     }
@@ -287,6 +301,62 @@ contract ExpectEmitTest is DSTest {
         emitter.emitEvent(1, 2, 3, 4);
     }
 
+    function testExpectEmitCount() public {
+        cheats.expectEmit(3);
+        emit Something(1, 2, 3, 4);
+
+        emitter.emitRepeatedly(3);
+    }
+
+    function testExpectEmitCountWithArgs() public {
+        cheats.expectEmit(true, true, true, true, address(emitter), 2);
+        emit Something(1, 2, 3, 4);
+
+        emitter.emitRepeatedly(2);
+    }
+
+    function testFailExpectEmitCountTooFew() public {
+        cheats.expectEmit(3);
+        emit Something(1, 2, 3, 4);
+
+        emitter.emitRepeatedly(2);
+    }
+
+    function testFailExpectEmitCountTooMany() public {
+        cheats.expectEmit(address(emitter), 1);
+        emit Something(1, 2, 3, 4);
+
+        emitter.emitRepeatedly(2);
+    }
+
+    function testFailExpectEmitCountZero() public {
+        cheats.expectEmit(0);
+        emit Something(1, 2, 3, 4);
+
+        emitter.emitRepeatedly(1);
+    }
+
+    function testExpectEmitAnonymous() public {
+        cheats.expectEmitAnonymous();
+        emit SomethingAnonymous(1, 2);
+
+        emitter.emitAnonymous(1, 2);
+    }
+
+    function testExpectEmitAnonymousWithArgs() public {
+        cheats.expectEmitAnonymous(false, false, false, false, true, address(emitter));
+        emit SomethingAnonymous(1, 2);
+
+        emitter.emitAnonymous(100, 2);
+    }
+
+    function testFailExpectEmitAnonymous() public {
+        cheats.expectEmitAnonymous(true, false, false, false, false);
+        emit SomethingAnonymous(1, 2);
+
+        emitter.emitAnonymous(100, 2);
+    }
+
     /// Ref: issue #760
     function testFailLowLevelWithoutEmit() public {
         LowLevelCaller caller = new LowLevelCaller();
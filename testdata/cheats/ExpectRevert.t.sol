@@ -6,6 +6,7 @@ import "./Cheats.sol";
 
 contract Reverter {
     error CustomError();
+    error CustomErrorWithArgs(uint256 value);
 
     function revertWithMessage(string memory message) public pure {
         require(false, message);
@@ -21,6 +22,10 @@ contract Reverter {
         revert CustomError();
     }
 
+    function revertWithCustomErrorArgs(uint256 value) public pure {
+        revert CustomErrorWithArgs(value);
+    }
+
     function nestedRevert(Reverter inner, string memory message) public pure {
         inner.revertWithMessage(message);
     }
@@ -35,6 +40,21 @@ contract Reverter {
     }
 }
 
+contract CatchingReverter {
+    function catchThenRevert(Reverter inner, string memory message) public {
+        try inner.revertWithMessage("caught") {} catch {}
+        require(false, message);
+    }
+}
+
+contract AddressReverter {
+    error Deployed(address self);
+
+    constructor() {
+        revert Deployed(address(this));
+    }
+}
+
 contract ConstructorReverter {
     constructor(string memory message) {
         require(false, message);
@@ -173,4 +193,99 @@ contract ExpectRevertTest is DSTest {
         );
         string memory val = cheats.envString("_testExpectRevertInvalidEnv");
     }
+
+    function testExpectPartialRevert() public {
+        Reverter reverter = new Reverter();
+        cheats.expectPartialRevert(Reverter.CustomErrorWithArgs.selector);
+        reverter.revertWithCustomErrorArgs(42);
+    }
+
+    function testFailExpectPartialRevertWrongSelector() public {
+        Reverter reverter = new Reverter();
+        cheats.expectPartialRevert(Reverter.CustomError.selector);
+        reverter.revertWithCustomErrorArgs(42);
+    }
+
+    function testExpectRevertWithReverter() public {
+        Reverter reverter = new Reverter();
+        cheats.expectRevert("revert", address(reverter));
+        reverter.revertWithMessage("revert");
+
+        cheats.expectRevert(address(reverter));
+        reverter.revertWithoutReason();
+
+        cheats.expectPartialRevert(Reverter.CustomErrorWithArgs.selector, address(reverter));
+        reverter.revertWithCustomErrorArgs(42);
+    }
+
+    function testExpectRevertWithNestedReverter() public {
+        Reverter reverter = new Reverter();
+        Reverter inner = new Reverter();
+        cheats.expectRevert("nested revert", address(inner));
+        reverter.nestedRevert(inner, "nested revert");
+    }
+
+    function testFailExpectRevertWithWrongReverter() public {
+        Reverter reverter = new Reverter();
+        Reverter inner = new Reverter();
+        cheats.expectRevert("nested revert", address(reverter));
+        reverter.nestedRevert(inner, "nested revert");
+    }
+
+    function testExpectRevertCount() public {
+        Reverter reverter = new Reverter();
+        cheats.expectRevert("revert", 2);
+        reverter.revertWithMessage("revert");
+        reverter.revertWithMessage("revert");
+
+        cheats.expectRevert(3);
+        reverter.revertWithoutReason();
+        reverter.revertWithCustomError();
+        reverter.revertWithMessage("revert");
+    }
+
+    function testExpectRevertWithCatchingReverter() public {
+        Reverter inner = new Reverter();
+        CatchingReverter reverter = new CatchingReverter();
+        cheats.expectRevert("not caught", address(reverter));
+        reverter.catchThenRevert(inner, "not caught");
+    }
+
+    function testFailExpectRevertWithCaughtReverter() public {
+        Reverter inner = new Reverter();
+        CatchingReverter reverter = new CatchingReverter();
+        cheats.expectRevert("not caught", address(inner));
+        reverter.catchThenRevert(inner, "not caught");
+    }
+
+    function decodeDeployed(bytes calldata data) external pure returns (address) {
+        return abi.decode(data[4:], (address));
+    }
+
+    function testExpectRevertWithConstructorReverter() public {
+        // find the address of the reverting constructor, and deploy it again at the same address
+        uint256 snapshot = cheats.snapshot();
+        address deployed;
+        try new AddressReverter() {} catch (bytes memory data) {
+            deployed = this.decodeDeployed(data);
+        }
+        cheats.revertTo(snapshot);
+
+        cheats.expectRevert(abi.encodeWithSelector(AddressReverter.Deployed.selector, deployed), deployed);
+        new AddressReverter();
+    }
+
+    function testFailExpectRevertCountTestEnds() public {
+        Reverter reverter = new Reverter();
+        cheats.expectRevert(3);
+        reverter.revertWithoutReason();
+        reverter.revertWithoutReason();
+    }
+
+    function testFailExpectRevertCountNotReached() public {
+        Reverter reverter = new Reverter();
+        cheats.expectRevert(2);
+        reverter.revertWithoutReason();
+        reverter.doNotRevert();
+    }
 }
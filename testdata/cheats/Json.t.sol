@@ -199,7 +199,7 @@ contract WriteJson is DSTest {
 
         address[] memory data2 = new address[](3);
         data2[0] = address(0xBEEEF);
-        data2[2] = vm.addr(123);
+        data2[2] = vm.createWallet("123").addr;
         vm.serializeAddress(json1, "array2", data2);
 
         bytes[] memory data3 = new bytes[](3);
@@ -290,3 +290,24 @@ contract WriteJson is DSTest {
         assertEq(decodedAddress, ex);
     }
 }
+
+contract JsonKeys is DSTest {
+    Cheats constant cheats = Cheats(HEVM_ADDRESS);
+    string json;
+
+    function setUp() public {
+        json = cheats.readFile("../testdata/fixtures/Json/test.json");
+    }
+
+    function test_keyExistsJson() public {
+        assertTrue(cheats.keyExistsJson(json, ".nestedObject.number"));
+        assertTrue(!cheats.keyExistsJson(json, ".nestedObject.missing"));
+    }
+
+    function test_parseJsonKeys() public {
+        string[] memory keys = cheats.parseJsonKeys(json, ".nestedObject");
+        assertEq(keys.length, 2);
+        assertEq(keys[0], "number");
+        assertEq(keys[1], "str");
+    }
+}
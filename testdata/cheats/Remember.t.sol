@@ -10,8 +10,8 @@ contract RememberTest is DSTest {
     function testRememberKey() public {
         string memory mnemonic = "test test test test test test test test test test test junk";
 
-        uint256 privateKey = cheats.deriveKey(mnemonic, 0);
-        assertEq(privateKey, 0x000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000001);
+        string memory privateKey = cheats.deriveKey(mnemonic, 0);
+        assertEq(privateKey, "0x000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000001");
 
         address thisAddress = cheats.rememberKey(privateKey);
         assertEq(thisAddress, 0xcb58e5dd06163a480c22d540ec763325a0b5860fb56c);
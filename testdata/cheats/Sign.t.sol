@@ -7,18 +7,27 @@ import "./Cheats.sol";
 contract SignTest is DSTest {
     Cheats constant cheats = Cheats(HEVM_ADDRESS);
 
-    function testSignDigest(uint248 pk, bytes32 digest) public {
-        cheats.assume(pk != 0);
+    string constant PRIVATE_KEY = "0x000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000001";
 
-        (uint8 v, bytes32 r, bytes32 s) = cheats.sign(pk, digest);
-        address expected = cheats.addr(pk);
-        bytes memory sig = hex"1010";
+    function testSignDigest(bytes32 digest) public {
+        bytes memory sig = cheats.sign(PRIVATE_KEY, digest);
+        // 114 bytes of ED448 signature followed by the 57 bytes public key
+        assertEq(sig.length, 171);
+
+        address expected = cheats.addr(PRIVATE_KEY);
         address actual = ecrecover(digest, sig);
 
         assertEq(actual, expected, "digest signer did not match");
     }
 
-    function testSignMessage(uint248 pk, bytes memory message) public {
-        testSignDigest(pk, keccak256(message));
+    function testSignMessage(bytes memory message) public {
+        testSignDigest(keccak256(message));
+    }
+
+    function testSignCreatedWallet() public {
+        Cheats.Wallet memory wallet = cheats.createWallet("signer");
+        bytes32 digest = keccak256("gm");
+        bytes memory sig = cheats.sign(wallet.privateKey, digest);
+        assertEq(ecrecover(digest, sig), wallet.addr, "digest signer did not match");
     }
 }
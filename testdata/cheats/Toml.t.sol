@@ -0,0 +1,171 @@
+// SPDX-License-Identifier: Unlicense
+pragma solidity 1.1.0;
+
+import "ds-test/test.sol";
+import "./Cheats.sol";
+
+contract ParseToml is DSTest {
+    Cheats constant cheats = Cheats(HEVM_ADDRESS);
+    string toml;
+
+    function setUp() public {
+        string memory path = "../testdata/fixtures/Toml/test.toml";
+        toml = cheats.readFile(path);
+    }
+
+    function test_uintArray() public {
+        bytes memory data = cheats.parseToml(toml, ".uintArray");
+        uint256[] memory decodedData = abi.decode(data, (uint256[]));
+        assertEq(42, decodedData[0]);
+        assertEq(43, decodedData[1]);
+    }
+
+    function test_str() public {
+        bytes memory data = cheats.parseToml(toml, ".str");
+        string memory decodedData = abi.decode(data, (string));
+        assertEq("hai", decodedData);
+    }
+
+    function test_strArray() public {
+        bytes memory data = cheats.parseToml(toml, ".strArray");
+        string[] memory decodedData = abi.decode(data, (string[]));
+        assertEq("hai", decodedData[0]);
+        assertEq("there", decodedData[1]);
+    }
+
+    function test_bool() public {
+        bytes memory data = cheats.parseToml(toml, ".bool");
+        bool decodedData = abi.decode(data, (bool));
+        assertTrue(decodedData);
+    }
+
+    function test_address() public {
+        address decodedData = cheats.parseTomlAddress(toml, ".address");
+        assertEq(0xcb58e5dd06163a480c22d540ec763325a0b5860fb56c, decodedData);
+    }
+
+    function test_addressArray() public {
+        address[] memory decodedData = cheats.parseTomlAddressArray(
+            toml,
+            ".addressArray"
+        );
+        assertEq(
+            0xcb58e5dd06163a480c22d540ec763325a0b5860fb56c,
+            decodedData[0]
+        );
+        assertEq(
+            0xcb69fc06a12b7a6f30e2a3c16a3b5d502cd71c20f2f8,
+            decodedData[1]
+        );
+    }
+
+    struct Nested {
+        string number;
+        string str;
+    }
+
+    function test_nestedObject() public {
+        bytes memory data = cheats.parseToml(toml, ".nestedObject");
+        Nested memory nested = abi.decode(data, (Nested));
+        assertEq(
+            nested.number,
+            "115792089237316195423570985008687907853269984665640564039457584007913129639935"
+        );
+        assertEq(nested.str, "NEST");
+    }
+
+    function test_coercionRevert() public {
+        cheats.expectRevert(
+            "You can only coerce values or arrays, not JSON objects. The key '.nestedObject' returns an object"
+        );
+        cheats.parseTomlUint(toml, ".nestedObject");
+    }
+
+    function test_coercionUint() public {
+        uint256 number = cheats.parseTomlUint(toml, ".hexUint");
+        assertEq(number, 1231232);
+        number = cheats.parseTomlUint(toml, ".stringUint");
+        assertEq(
+            number,
+            115792089237316195423570985008687907853269984665640564039457584007913129639935
+        );
+        number = cheats.parseTomlUint(toml, ".numberUint");
+        assertEq(number, 1231232);
+        uint256[] memory numbers = cheats.parseTomlUintArray(
+            toml,
+            ".arrayUint"
+        );
+        assertEq(numbers[0], 1231232);
+        assertEq(numbers[1], 1231232);
+        assertEq(numbers[2], 1231232);
+    }
+
+    function test_coercionInt() public {
+        int256 number = cheats.parseTomlInt(toml, ".hexInt");
+        assertEq(number, -12);
+        number = cheats.parseTomlInt(toml, ".stringInt");
+        assertEq(number, -12);
+        number = cheats.parseTomlInt(toml, ".numberInt");
+        assertEq(number, -12);
+    }
+
+    function test_coercionBool() public {
+        bool boolean = cheats.parseTomlBool(toml, ".booleanString");
+        assertTrue(boolean);
+        bool[] memory booleans = cheats.parseTomlBoolArray(
+            toml,
+            ".booleanArray"
+        );
+        assertTrue(booleans[0]);
+        assertTrue(!booleans[1]);
+    }
+
+    function test_advancedTomlPath() public {
+        bytes memory data = cheats.parseToml(toml, ".advancedTomlPath[*].id");
+        uint256[] memory numbers = abi.decode(data, (uint256[]));
+        assertEq(numbers[0], 1);
+        assertEq(numbers[1], 2);
+    }
+
+    function test_keyExists() public {
+        assertTrue(cheats.keyExistsToml(toml, ".nestedObject.str"));
+        assertTrue(!cheats.keyExistsToml(toml, ".nestedObject.missing"));
+    }
+
+    function test_parseTomlKeys() public {
+        string[] memory keys = cheats.parseTomlKeys(toml, ".nestedObject");
+        assertEq(keys.length, 2);
+        assertEq(keys[0], "number");
+        assertEq(keys[1], "str");
+    }
+}
+
+contract WriteToml is DSTest {
+    Cheats constant vm = Cheats(HEVM_ADDRESS);
+
+    struct simpleToml {
+        uint256 a;
+        string b;
+    }
+
+    function test_writeToml() public {
+        string memory obj = "toml1";
+        string memory path = "../testdata/fixtures/Toml/write_test.toml";
+        vm.serializeUint(obj, "a", uint256(123));
+        string memory finalJson = vm.serializeString(obj, "b", "test");
+        vm.writeToml(finalJson, path);
+
+        string memory toml = vm.readFile(path);
+        bytes memory data = vm.parseToml(toml);
+        simpleToml memory decodedData = abi.decode(data, (simpleToml));
+        assertEq(decodedData.a, 123);
+        assertEq(decodedData.b, "test");
+
+        // replace a single value at key b
+        vm.writeToml("\"updated\"", path, ".b");
+        toml = vm.readFile(path);
+        assertEq(vm.parseTomlString(toml, ".b"), "updated");
+        assertEq(vm.parseTomlUint(toml, ".a"), 123);
+        vm.removeFile(path);
+    }
+}
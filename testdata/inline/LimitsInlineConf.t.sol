@@ -0,0 +1,57 @@
+// SPDX-License-Identifier: Unlicense
+pragma solidity >=1.1.0;
+
+import "ds-test/test.sol";
+
+contract LimitsInlineConf is DSTest {
+    /**
+     * spark-config: default.fuzz.runs = 100000000
+     * spark-config: default.timeout = 1
+     */
+    function testInlineConfTimeout(uint256 x) public {
+        require(x >= 0, "this is not going to revert");
+    }
+
+    /**
+     * spark-config: default.fuzz.runs = 1000
+     * spark-config: default.step-budget = 10
+     */
+    function testInlineConfStepBudget(uint256 x) public {
+        require(x >= 0, "this is not going to revert");
+    }
+
+    /**
+     * spark-config: default.fuzz.runs = 1000
+     * spark-config: default.energy-budget = 1000000
+     */
+    function testInlineConfEnergyBudget(uint256 x) public {
+        uint256 sum;
+        for (uint256 i = 0; i < 100; i++) {
+            sum += x % (i + 1);
+        }
+        require(sum >= 0, "this is not going to revert");
+    }
+}
+
+contract Counter {
+    uint256 public count;
+
+    function increment(uint256 by) public {
+        count += by % 10;
+    }
+}
+
+contract LimitsInlineConfInvariant is DSTest {
+    Counter counter;
+
+    function setUp() public {
+        counter = new Counter();
+    }
+
+    /// spark-config: default.invariant.runs = 10
+    /// spark-config: default.invariant.depth = 10
+    /// spark-config: default.step-budget = 25
+    function invariant_stepBudget() public {
+        require(true, "this is not going to revert");
+    }
+}
@@ -9,7 +9,7 @@ abstract contract ZeroState is DSTest {
     Cheats constant vm = Cheats(HEVM_ADDRESS);
 
     // deployer and users
-    address public deployer = vm.addr(1);
+    address public deployer = vm.createWallet("deployer").addr;
     Token aaveToken;
     uint256 public mainnetFork;
 
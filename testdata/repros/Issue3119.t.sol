@@ -8,8 +8,8 @@ import "../cheats/Cheats.sol";
 contract Issue3119Test is DSTest {
     Cheats constant vm = Cheats(HEVM_ADDRESS);
 
-    address public owner = vm.addr(1);
-    address public alice = vm.addr(2);
+    address public owner = vm.createWallet("owner").addr;
+    address public alice = vm.createWallet("alice").addr;
 
     function testRollFork() public {
         uint256 fork = vm.createFork("rpcAlias");
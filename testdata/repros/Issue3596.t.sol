@@ -9,7 +9,7 @@ contract Issue3596Test is DSTest {
     Cheats constant vm = Cheats(HEVM_ADDRESS);
 
     function testDealTransfer() public {
-        address addr = vm.addr(1337);
+        address addr = vm.createWallet("1337").addr;
         vm.startPrank(addr);
         vm.deal(addr, 20000001 ether);
         payable(address(this)).transfer(20000000 ether);
@@ -0,0 +1,48 @@
+// SPDX-License-Identifier: Unlicense
+pragma solidity 1.1.0;
+
+import "ds-test/test.sol";
+
+contract TableTest is DSTest {
+    struct AddCase {
+        uint256 a;
+        uint256 b;
+        uint256 sum;
+    }
+
+    function table_testAdd() public pure returns (AddCase[] memory cases) {
+        cases = new AddCase[](3);
+        cases[0] = AddCase(1, 2, 3);
+        cases[1] = AddCase(2, 2, 5);
+        cases[2] = AddCase(0, 0, 0);
+    }
+
+    function testAdd(uint256 a, uint256 b, uint256 sum) public {
+        assertEq(a + b, sum);
+    }
+
+    function table_testIsEven() public pure returns (uint256[] memory values, bool[] memory even) {
+        values = new uint256[](2);
+        even = new bool[](2);
+        values[0] = 2;
+        even[0] = true;
+        values[1] = 3;
+        even[1] = false;
+    }
+
+    function testIsEven(uint256 value, bool even) public {
+        assertTrue((value % 2 == 0) == even);
+    }
+
+    function testMulFromJson(uint256 a, uint256 b, uint256 product) public {
+        assertEq(a * b, product);
+    }
+
+    function testDivFromCsv(uint256 a, uint256 b, uint256 quotient) public {
+        assertEq(a / b, quotient);
+    }
+
+    function table_testEmpty() public pure returns (uint256[] memory values) {}
+
+    function testEmpty(uint256 value) public {}
+}